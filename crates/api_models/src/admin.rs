@@ -11,7 +11,7 @@ use super::payments::AddressDetails;
 use crate::{
     enums,
     enums::{self as api_enums},
-    payment_methods,
+    payment_methods, routing,
 };
 
 #[derive(Clone, Debug, Deserialize, ToSchema)]
@@ -436,6 +436,12 @@ pub mod payout_routing_algorithm {
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
     Single(api_enums::RoutableConnectors),
+    /// Connectors are tried in the given order
+    Priority(Vec<api_enums::RoutableConnectors>),
+    /// Traffic is distributed across connectors based on the configured percentages
+    VolumeSplit(Vec<routing::ConnectorVolumeSplit>),
+    /// Connectors are picked based on rules evaluated against the payment attributes
+    Advanced(routing::RoutingRules),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -558,8 +564,8 @@ pub struct MerchantConnectorCreate {
     #[schema(value_type = ConnectorType, example = "payment_processor")]
     pub connector_type: api_enums::ConnectorType,
     /// Name of the Connector
-    #[schema(value_type = Connector, example = "stripe")]
-    pub connector_name: api_enums::Connector,
+    #[schema(value_type = MerchantConnectorName, example = "stripe")]
+    pub connector_name: api_enums::MerchantConnectorName,
     // /// Connector label for specific country and Business
     #[serde(skip_deserializing)]
     #[schema(example = "stripe_US_travel")]
@@ -633,6 +639,24 @@ pub struct MerchantConnectorCreate {
     pub connector_webhook_details: Option<MerchantConnectorWebhookDetails>,
     /// Identifier for the business profile, if not provided default will be chosen from merchant account
     pub profile_id: Option<String>,
+
+    /// Details of the wallets of the connector which are stored encrypted, such as the keys to decrypt the wallet payment tokens with
+    pub connector_wallets_details: Option<ConnectorWalletsDetails>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorWalletsDetails {
+    /// The details to decrypt the Google Pay payment tokens with, before passing them to the connector
+    pub google_pay: Option<GooglePayDecryptionDetails>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GooglePayDecryptionDetails {
+    /// The base64 encoded PKCS #8 private key, whose public key is registered with Google Pay
+    #[schema(value_type = String)]
+    pub private_key: Secret<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -805,6 +829,9 @@ pub struct MerchantConnectorUpdate {
         }
     }))]
     pub connector_webhook_details: Option<MerchantConnectorWebhookDetails>,
+
+    /// Details of the wallets of the connector which are stored encrypted, such as the keys to decrypt the wallet payment tokens with
+    pub connector_wallets_details: Option<ConnectorWalletsDetails>,
 }
 
 ///Details of FrmConfigs are mentioned here... it should be passed in payment connector create api call, and stored in merchant_connector_table
@@ -919,6 +946,49 @@ pub struct ToggleKVRequest {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct VaultDataKeyRotationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Version of the data key that is now active for the merchant in the built-in vault. The
+    /// cards of the merchant are re-encrypted with it in the background.
+    #[schema(example = 2)]
+    pub key_version: i32,
+}
+
+/// The entities of the merchant re-encrypted with the new data key, in the order they are
+/// re-encrypted in
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DataKeyRotationStage {
+    MerchantAccount,
+    MerchantConnectorAccounts,
+    Customers,
+    Addresses,
+    PaymentMethods,
+    Authentications,
+    Events,
+    WebhookDeliveryAttempts,
+    Completed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DataKeyRotationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Version of the data key that is now active for the merchant
+    #[schema(example = 2)]
+    pub key_version: i32,
+    /// The entities being re-encrypted with the new data key in the background
+    #[schema(example = "customers")]
+    pub stage: DataKeyRotationStage,
+    /// Number of records re-encrypted with the new data key so far
+    #[schema(example = 10)]
+    pub re_encrypted_count: u64,
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
     /// Creds Identifier is to uniquely identify the credentials. Do not send any sensitive info in this field. And do not send the string "null".
@@ -1016,6 +1086,12 @@ pub struct BusinessProfileCreate {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// The branding of the hosted checkout page of the payment links of this profile
+    pub payment_link_config: Option<PaymentLinkConfig>,
+
+    /// The surcharge levied on the payments of this profile, based on the payment method used
+    pub surcharge_config: Option<payment_methods::SurchargeConfig>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -1080,6 +1156,12 @@ pub struct BusinessProfileResponse {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// The branding of the hosted checkout page of the payment links of this profile
+    pub payment_link_config: Option<PaymentLinkConfig>,
+
+    /// The surcharge levied on the payments of this profile, based on the payment method used
+    pub surcharge_config: Option<payment_methods::SurchargeConfig>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema)]
@@ -1137,4 +1219,30 @@ pub struct BusinessProfileUpdate {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// The branding of the hosted checkout page of the payment links of this profile
+    pub payment_link_config: Option<PaymentLinkConfig>,
+
+    /// The surcharge levied on the payments of this profile, based on the payment method used
+    pub surcharge_config: Option<payment_methods::SurchargeConfig>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkConfig {
+    /// The URL of the logo displayed on the hosted checkout page
+    #[schema(value_type = Option<String>, max_length = 255, example = "https://www.example.com/logo.png")]
+    pub logo: Option<url::Url>,
+
+    /// The name of the merchant displayed on the hosted checkout page, defaults to the name of the merchant account
+    #[schema(max_length = 64, example = "Juspay")]
+    pub display_name: Option<String>,
+
+    /// The hex code of the color of the buttons and highlights on the hosted checkout page
+    #[schema(max_length = 7, example = "#006DF9")]
+    pub primary_color: Option<String>,
+
+    /// The hex code of the background color of the hosted checkout page
+    #[schema(max_length = 7, example = "#F8F9FB")]
+    pub background_color: Option<String>,
 }
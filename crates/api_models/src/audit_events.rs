@@ -0,0 +1,87 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The constraints that are applicable when listing the audit events of a merchant.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuditEventListConstraints {
+    /// Only list the events of this merchant. Only applicable when authenticating with the admin
+    /// API key, the events of the authenticated merchant are listed otherwise.
+    pub merchant_id: Option<String>,
+
+    /// Only list the events of actions performed by this kind of actor
+    #[schema(value_type = Option<AuditActorType>)]
+    pub actor_type: Option<api_enums::AuditActorType>,
+
+    /// Only list the events of actions performed using this API key
+    pub actor_id: Option<String>,
+
+    /// Only list the events of this action
+    #[schema(value_type = Option<AuditAction>)]
+    pub action: Option<api_enums::AuditAction>,
+
+    /// Only list the events of actions that changed this kind of entity
+    #[schema(value_type = Option<AuditTargetType>)]
+    pub target_type: Option<api_enums::AuditTargetType>,
+
+    /// Only list the events of actions that changed this entity, such as a merchant connector ID
+    pub target_id: Option<String>,
+
+    /// Only list the events recorded at or after this time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub start_time: Option<PrimitiveDateTime>,
+
+    /// Only list the events recorded at or before this time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub end_time: Option<PrimitiveDateTime>,
+
+    /// The maximum number of events to include in the response
+    pub limit: Option<i64>,
+
+    /// The number of events to skip when retrieving the list of events
+    pub offset: Option<i64>,
+}
+
+/// An administrative action recorded in the audit log
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AuditEventResponse {
+    /// The identifier for the audit event
+    pub event_id: String,
+
+    /// The merchant whose configuration was changed, not set for global configuration changes
+    pub merchant_id: Option<String>,
+
+    #[schema(value_type = AuditActorType)]
+    pub actor_type: api_enums::AuditActorType,
+
+    /// The identifier of the API key used to perform the action, not set for the admin API key
+    pub actor_id: Option<String>,
+
+    #[schema(value_type = AuditAction)]
+    pub action: api_enums::AuditAction,
+
+    #[schema(value_type = AuditTargetType)]
+    pub target_type: api_enums::AuditTargetType,
+
+    /// The identifier of the entity changed by the action
+    pub target_id: String,
+
+    /// The fields changed by the action, along with their values before and after the action.
+    /// The values of sensitive fields such as connector credentials are masked.
+    #[schema(value_type = Option<Object>)]
+    pub diff: Option<serde_json::Value>,
+
+    /// The identifier of the request which performed the action
+    pub request_id: Option<String>,
+
+    /// Time at which the action was performed
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
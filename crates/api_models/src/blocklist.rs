@@ -0,0 +1,47 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The data to be added to or removed from the blocklist of a merchant
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type", content = "data")]
+pub enum BlocklistRequest {
+    /// The first 6 digits of the card number
+    CardBin(String),
+    /// The first 8 digits of the card number
+    ExtendedCardBin(String),
+    /// The fingerprint of a payment method, as returned in the payments response
+    Fingerprint(String),
+}
+
+/// An entry in the blocklist of a merchant
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct BlocklistResponse {
+    /// The fingerprint, card bin or extended card bin that is blocked
+    pub fingerprint_id: String,
+
+    #[schema(value_type = BlocklistDataKind)]
+    pub data_kind: api_enums::BlocklistDataKind,
+
+    /// Time at which the entry was added to the blocklist
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The constraints that are applicable when listing the entries in the blocklist of a merchant
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListBlocklistQuery {
+    #[schema(value_type = BlocklistDataKind)]
+    pub data_kind: api_enums::BlocklistDataKind,
+
+    /// The maximum number of entries to include in the response
+    pub limit: Option<i64>,
+
+    /// The number of entries to skip when retrieving the list of entries
+    pub offset: Option<i64>,
+}
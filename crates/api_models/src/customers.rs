@@ -96,6 +96,53 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+/// Constraints for listing the customers of a merchant.
+///
+/// The customers are searched by the exact email, phone or name. Customers created before search
+/// was supported become searchable once the search index backfill has run for the merchant.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerListConstraints {
+    /// The maximum number of customers to return, 10 by default and at most 100
+    pub limit: Option<u32>,
+    /// The customer ID of the customer after which the customers are listed, from the previous page
+    pub starting_after: Option<String>,
+    /// Only list customers with this email
+    #[schema(value_type = Option<String>)]
+    pub email: Option<pii::Email>,
+    /// Only list customers with this phone number
+    #[schema(value_type = Option<String>)]
+    pub phone: Option<Secret<String>>,
+    /// Only list customers with this name
+    #[schema(value_type = Option<String>)]
+    pub name: Option<Secret<String>>,
+    /// Whether to include the number of saved payment methods and mandates of each customer
+    #[serde(default)]
+    pub include_counts: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerListResponse {
+    /// The number of customers included in the list
+    pub size: usize,
+    /// Whether there are more customers after the ones in the list
+    pub has_more: bool,
+    /// The list of customers, most recently created first
+    pub data: Vec<CustomerListItem>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerListItem {
+    #[serde(flatten)]
+    pub customer: CustomerResponse,
+    /// The number of saved payment methods of the customer, if counts were requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_methods_count: Option<i64>,
+    /// The number of mandates of the customer, if counts were requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandates_count: Option<i64>,
+}
+
 pub fn generate_customer_id() -> String {
     common_utils::generate_id(consts::ID_LENGTH, "cus")
 }
@@ -104,6 +104,8 @@ pub struct DisputeEvidenceBlock {
 pub struct DisputeListConstraints {
     /// limit on the number of objects to return
     pub limit: Option<i64>,
+    /// The dispute ID of the dispute after which the disputes are listed, from the previous page
+    pub starting_after: Option<String>,
     /// status of the dispute
     pub dispute_status: Option<DisputeStatus>,
     /// stage of the dispute
@@ -133,6 +135,14 @@ pub struct DisputeListConstraints {
     pub received_time_gte: Option<PrimitiveDateTime>,
 }
 
+/// A page of the disputes of a merchant, listed after the `starting_after` cursor
+#[derive(Clone, Debug, Serialize)]
+pub struct DisputeListPage {
+    pub data: Vec<DisputeResponse>,
+    /// Whether more disputes follow this page
+    pub has_more: bool,
+}
+
 #[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct SubmitEvidenceRequest {
     ///Dispute Id
@@ -238,6 +238,62 @@ pub enum RoutableConnectors {
     Zen,
 }
 
+/// Connectors which authenticate the customer on behalf of the merchant, such as 3DS servers,
+/// rather than process payments
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthenticationConnectors {
+    Threedsecureio,
+}
+
+/// The name of the connector of a merchant connector account, either a payment connector or an
+/// authentication connector
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum MerchantConnectorName {
+    Connector(Connector),
+    AuthenticationConnector(AuthenticationConnectors),
+}
+
+impl std::fmt::Display for MerchantConnectorName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connector(connector) => connector.fmt(f),
+            Self::AuthenticationConnector(connector) => connector.fmt(f),
+        }
+    }
+}
+
+impl<'a> ToSchema<'a> for MerchantConnectorName {
+    fn schema() -> (
+        &'a str,
+        utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+    ) {
+        use utoipa::openapi::{OneOfBuilder, Ref};
+
+        (
+            "MerchantConnectorName",
+            OneOfBuilder::new()
+                .item(Ref::from_schema_name("Connector"))
+                .item(Ref::from_schema_name("AuthenticationConnectors"))
+                .into(),
+        )
+    }
+}
+
 #[cfg(feature = "payouts")]
 #[derive(
     Clone,
@@ -471,7 +527,15 @@ pub enum BankNames {
 }
 
 #[derive(
-    Clone, Debug, serde::Deserialize, serde::Serialize, strum::Display, strum::EnumString, ToSchema,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -479,10 +543,19 @@ pub enum FrmAction {
     CancelTxn,
     AutoRefund,
     ManualReview,
+    ThreeDs,
 }
 
 #[derive(
-    Clone, Debug, serde::Deserialize, serde::Serialize, strum::Display, strum::EnumString, ToSchema,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -1,7 +1,9 @@
 #![forbid(unsafe_code)]
 pub mod admin;
 pub mod api_keys;
+pub mod audit_events;
 pub mod bank_accounts;
+pub mod blocklist;
 pub mod cards_info;
 pub mod customers;
 pub mod disputes;
@@ -16,5 +18,7 @@ pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
 pub mod refunds;
+pub mod routing;
 pub mod verifications;
+pub mod webhook_events;
 pub mod webhooks;
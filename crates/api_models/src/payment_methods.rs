@@ -236,6 +236,75 @@ pub struct CardNetworkTypes {
     /// The list of eligible connectors for a given card network
     #[schema(example = json!(["stripe", "adyen"]))]
     pub eligible_connectors: Vec<String>,
+
+    /// The surcharge applicable on a payment made with the card network, if any
+    pub surcharge_details: Option<SurchargeDetailsResponse>,
+}
+
+/// The surcharge levied on a payment made with a payment method
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case", tag = "type", content = "value")]
+pub enum Surcharge {
+    /// A fixed amount, in the lowest denomination of the currency
+    Fixed(i64),
+    /// A percentage of the payment amount, in basis points (150 for 1.5%)
+    Rate(u32),
+}
+
+/// The surcharge rules of a business profile. The first rule matching a payment method is applied.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeConfig {
+    /// The surcharge rules, in the order of their precedence
+    pub rules: Vec<SurchargeRule>,
+}
+
+/// A surcharge which applies to the payment methods matching all the criteria specified
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeRule {
+    /// The payment method type the surcharge applies to, all of them if not specified
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The card network the surcharge applies to, all of them if not specified
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<api_enums::CardNetwork>,
+
+    /// The billing country the surcharge applies to, all of them if not specified
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub country: Option<api_enums::CountryAlpha2>,
+
+    /// The surcharge levied on the payment
+    pub surcharge: Surcharge,
+
+    /// The tax levied on the surcharge, in basis points (1800 for 18%)
+    #[schema(example = 1800)]
+    pub tax_on_surcharge: Option<u32>,
+}
+
+/// The surcharge and the tax on it, which are added to the amount of a payment made with a
+/// payment method
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+pub struct SurchargeDetailsResponse {
+    /// The surcharge configured for the payment method
+    pub surcharge: Surcharge,
+
+    /// The tax levied on the surcharge, in basis points (1800 for 18%)
+    #[schema(example = 1800)]
+    pub tax_on_surcharge: Option<u32>,
+
+    /// The surcharge amount, in the lowest denomination of the currency
+    #[schema(example = 200)]
+    pub surcharge_amount: i64,
+
+    /// The tax on the surcharge amount, in the lowest denomination of the currency
+    #[schema(example = 36)]
+    pub tax_on_surcharge_amount: i64,
+
+    /// The amount of the payment along with the surcharge and the tax on it
+    #[schema(example = 10236)]
+    pub final_amount: i64,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
@@ -265,6 +334,10 @@ pub struct ResponsePaymentMethodTypes {
 
     /// Required fields for the payment_method_type.
     pub required_fields: Option<HashMap<String, RequiredFieldInfo>>,
+
+    /// The surcharge applicable on a payment made with the payment method type, if any. For
+    /// cards, the surcharge is returned for each of the card networks instead.
+    pub surcharge_details: Option<SurchargeDetailsResponse>,
 }
 
 /// Required fields info used while listing the payment_method_data
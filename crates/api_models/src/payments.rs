@@ -298,6 +298,21 @@ pub struct PaymentsRequest {
     /// The business profile to use for this payment, if not passed the default business profile
     /// associated with the merchant account will be used.
     pub profile_id: Option<String>,
+
+    /// Request for the authorized amount of the payment to be increased before it is captured,
+    /// applicable only when the payment is to be captured manually
+    #[schema(example = true)]
+    pub request_incremental_authorization: Option<bool>,
+
+    /// Whether to generate a link to a hosted checkout page, which can be shared with the customer
+    /// to pay for this payment
+    #[schema(default = false, example = true)]
+    pub payment_link: Option<bool>,
+
+    /// The time in seconds after which the payment link expires. It cannot exceed the intent
+    /// fulfillment time of the merchant, which is also the default
+    #[schema(example = 900)]
+    pub payment_link_expiry: Option<u32>,
 }
 
 #[derive(Default, Debug, Clone, Copy)]
@@ -1916,6 +1931,23 @@ pub struct PaymentsResponse {
 
     /// Denotes the action(approve or reject) taken by merchant in case of manual review. Manual review can occur when the transaction is marked as risky by the frm_processor, payment processor or when there is underpayment/over payment incase of crypto payment
     pub merchant_decision: Option<String>,
+
+    /// Indicates whether the authorized amount of the payment can be increased before it is captured
+    pub incremental_authorization_allowed: Option<bool>,
+
+    /// List of incremental authorizations made on the payment
+    #[schema(value_type = Option<Vec<IncrementalAuthorizationResponse>>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incremental_authorizations: Option<Vec<IncrementalAuthorizationResponse>>,
+
+    /// The fingerprint of the card used for the payment, which can be added to the blocklist of the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+
+    /// The payment link generated for the payment, if it was requested
+    #[schema(value_type = Option<PaymentLinkResponse>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_link: Option<PaymentLinkResponse>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema)]
@@ -2682,6 +2714,87 @@ pub struct PaymentsRejectRequest {
     pub payment_id: String,
 }
 
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsIncrementalAuthorizationRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The total amount to be authorized for the payment, including the amount authorized so
+    /// far. Amount in the lowest denomination of the currency, (i.e) in cents for USD.
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: i64,
+    /// The reason for increasing the authorized amount
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct IncrementalAuthorizationResponse {
+    /// The unique identifier of the incremental authorization
+    pub authorization_id: String,
+    /// The total amount authorized for the payment, if this authorization succeeds
+    pub amount: i64,
+    /// The status of the incremental authorization
+    #[schema(value_type = AuthorizationStatus)]
+    pub status: enums::AuthorizationStatus,
+    /// If there was an error while calling the connector the code is received here
+    pub error_code: Option<String>,
+    /// If there was an error while calling the connector the error message is received here
+    pub error_message: Option<String>,
+    /// The amount that was authorized before this incremental authorization was made
+    pub previously_authorized_amount: i64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentLinkResponse {
+    /// The identifier of the payment link
+    pub payment_link_id: String,
+    /// The link to the hosted checkout page of the payment
+    #[schema(
+        example = "https://sandbox.hyperswitch.io/payment_link/merchant_1/pay_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub link: String,
+    /// The time at which the payment link expires
+    #[schema(example = "2022-09-10T10:26:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expiry: PrimitiveDateTime,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    Active,
+    Expired,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RetrievePaymentLinkResponse {
+    /// The identifier of the payment link
+    pub payment_link_id: String,
+    /// The identifier of the payment the link was generated for
+    pub payment_id: String,
+    /// The identifier for the Merchant Account
+    pub merchant_id: String,
+    /// The link to the hosted checkout page of the payment
+    pub link_to_pay: String,
+    /// The amount of the payment at the time the link was generated
+    pub amount: i64,
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<enums::Currency>,
+    /// The time at which the payment link was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the payment link expires
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expiry: PrimitiveDateTime,
+    /// Whether the payment link can still be used to pay
+    #[schema(value_type = PaymentLinkStatus, example = "active")]
+    pub status: PaymentLinkStatus,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "requires_payment_method")]
+    pub payment_status: enums::IntentStatus,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, ToSchema, Clone)]
 pub struct PaymentsStartRequest {
     /// Unique identifier for the payment. This ensures idempotency for multiple payments
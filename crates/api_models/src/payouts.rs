@@ -413,3 +413,53 @@ pub struct PayoutActionRequest {
     )]
     pub payout_id: String,
 }
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PayoutListFilterConstraints {
+    /// The identifier for payout
+    pub payout_id: Option<String>,
+    /// The identifier for the customer the payouts were made to
+    pub customer_id: Option<String>,
+    /// The limit on the number of objects. The default limit is 10 and max limit is 20
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+    /// The starting point within a list of objects
+    pub offset: Option<u32>,
+    /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc).
+    #[serde(flatten)]
+    pub time_range: Option<payments::TimeRange>,
+    /// The list of connectors to filter payouts list
+    pub connector: Option<Vec<api_enums::PayoutConnectors>>,
+    /// The list of currencies to filter payouts list
+    pub currency: Option<Vec<api_enums::Currency>>,
+    /// The list of payout statuses to filter payouts list
+    pub status: Option<Vec<api_enums::PayoutStatus>>,
+    /// The list of payout types to filter payouts list
+    pub payout_type: Option<Vec<api_enums::PayoutType>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PayoutListResponse {
+    /// The number of payouts included in the list for given constraints
+    pub count: usize,
+    /// The total number of available payouts for given constraints
+    pub total_count: i64,
+    /// The list of payouts response objects
+    pub data: Vec<PayoutCreateResponse>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PayoutListFilters {
+    /// The list of available connector filters
+    pub connector: Vec<String>,
+    /// The list of available currency filters
+    pub currency: Vec<api_enums::Currency>,
+    /// The list of available payout status filters
+    pub status: Vec<api_enums::PayoutStatus>,
+    /// The list of available payout type filters
+    pub payout_type: Vec<api_enums::PayoutType>,
+}
+
+fn default_limit() -> u32 {
+    10
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::enums as api_enums;
+
+/// A connector along with the share of traffic that should be routed to it
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    pub connector: api_enums::RoutableConnectors,
+    /// Percentage of the traffic to be routed to this connector
+    pub split: u8,
+}
+
+/// The connectors picked when a rule matches
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ConnectorSelection {
+    Priority(Vec<api_enums::RoutableConnectors>),
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+}
+
+/// Rule based routing configuration. Rules are evaluated in order and the first rule whose
+/// conditions are all satisfied decides the connectors, otherwise `default_selection` is used
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRules {
+    pub rules: Vec<RoutingRule>,
+    pub default_selection: ConnectorSelection,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    /// A name to identify the rule
+    pub name: String,
+    /// All the conditions must be satisfied for the rule to match
+    pub conditions: Vec<RoutingCondition>,
+    pub connector_selection: ConnectorSelection,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberComparison {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanEqual,
+    GreaterThan,
+    GreaterThanEqual,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipComparison {
+    In,
+    NotIn,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MembershipCondition<T> {
+    pub comparison: MembershipComparison,
+    pub values: Vec<T>,
+}
+
+/// A condition on a payment attribute
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "field", rename_all = "snake_case")]
+pub enum RoutingCondition {
+    Amount {
+        comparison: NumberComparison,
+        value: i64,
+    },
+    Currency(MembershipCondition<api_enums::Currency>),
+    CardNetwork(MembershipCondition<api_enums::CardNetwork>),
+    PaymentMethod(MembershipCondition<api_enums::PaymentMethod>),
+    PaymentMethodType(MembershipCondition<api_enums::PaymentMethodType>),
+    BillingCountry(MembershipCondition<api_enums::CountryAlpha2>),
+    /// Matches if the payment metadata has the given key set to the given value
+    Metadata {
+        key: String,
+        value: String,
+    },
+}
@@ -0,0 +1,98 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The constraints that are applicable when listing the events delivered to a merchant.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventListConstraints {
+    /// Only list the events raised for this object, such as a payment ID or a refund ID
+    pub object_id: Option<String>,
+
+    /// The maximum number of events to include in the response, 10 by default and at most 100
+    pub limit: Option<i64>,
+
+    /// The number of events to skip when retrieving the list of events
+    pub offset: Option<i64>,
+
+    /// The event ID of the event after which the events are listed, from the previous page
+    pub starting_after: Option<String>,
+}
+
+/// A page of the events raised for a merchant, listed after the `starting_after` cursor
+#[derive(Clone, Debug, Serialize)]
+pub struct EventListPage {
+    pub data: Vec<EventListItemResponse>,
+
+    /// Whether more events follow this page
+    pub has_more: bool,
+}
+
+/// An outgoing webhook event raised for a merchant
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventListItemResponse {
+    /// The identifier for the event
+    pub event_id: String,
+
+    /// The identifier for the merchant account
+    pub merchant_id: String,
+
+    #[schema(value_type = EventType)]
+    pub event_type: api_enums::EventType,
+
+    /// The identifier of the object for which the event was raised
+    pub object_id: String,
+
+    /// Whether the merchant acknowledged the webhook sent for this event
+    pub is_webhook_notified: bool,
+
+    /// Time at which the event was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// An outgoing webhook event along with the history of its delivery attempts
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventRetrieveResponse {
+    #[serde(flatten)]
+    pub event: EventListItemResponse,
+
+    /// The delivery attempts made for the event, ordered by attempt number
+    pub delivery_attempts: Vec<WebhookDeliveryAttemptResponse>,
+}
+
+/// A single attempt at delivering an outgoing webhook to the merchant
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryAttemptResponse {
+    pub attempt_number: i16,
+
+    #[schema(value_type = WebhookDeliveryType)]
+    pub delivery_type: api_enums::WebhookDeliveryType,
+
+    /// Whether the merchant responded with a 2xx status code
+    pub is_delivered: bool,
+
+    /// The body sent to the merchant webhook endpoint
+    pub request_body: Option<String>,
+
+    /// The status code returned by the merchant webhook endpoint
+    pub response_status_code: Option<i16>,
+
+    /// The beginning of the body returned by the merchant webhook endpoint
+    pub response_body: Option<String>,
+
+    /// Time taken by the merchant webhook endpoint to respond, in milliseconds
+    pub latency_ms: Option<i64>,
+
+    /// The reason the webhook could not be sent, if the request itself failed
+    pub error_message: Option<String>,
+
+    /// Time at which the attempt was made
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
@@ -13,6 +13,10 @@ pub enum IncomingWebhookEvent {
     PaymentIntentProcessing,
     PaymentIntentPartiallyFunded,
     PaymentActionRequired,
+    /// An incremental authorization of the payment has been accepted by the issuer
+    PaymentIntentAuthorizationSuccess,
+    /// An incremental authorization of the payment has been declined by the issuer
+    PaymentIntentAuthorizationFailure,
     EventNotSupported,
     SourceChargeable,
     SourceTransactionCreated,
@@ -37,6 +41,7 @@ pub enum WebhookFlow {
     Subscription,
     ReturnResponse,
     BankTransfer,
+    IncrementalAuthorization,
 }
 
 impl From<IncomingWebhookEvent> for WebhookFlow {
@@ -47,6 +52,10 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             | IncomingWebhookEvent::PaymentIntentProcessing
             | IncomingWebhookEvent::PaymentActionRequired
             | IncomingWebhookEvent::PaymentIntentPartiallyFunded => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentAuthorizationSuccess
+            | IncomingWebhookEvent::PaymentIntentAuthorizationFailure => {
+                Self::IncrementalAuthorization
+            }
             IncomingWebhookEvent::EventNotSupported => Self::ReturnResponse,
             IncomingWebhookEvent::RefundSuccess | IncomingWebhookEvent::RefundFailure => {
                 Self::Refund
@@ -208,6 +208,8 @@ pub enum ConnectorType {
     NonBankingFinance,
     /// Acquirers, Gateways etc
     PayoutProcessor,
+    /// 3DS servers and other customer authentication providers
+    AuthenticationProcessor,
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -1657,6 +1659,7 @@ pub enum PayoutStatus {
     Debug,
     Default,
     Eq,
+    Hash,
     PartialEq,
     ToSchema,
     serde::Deserialize,
@@ -1796,3 +1799,286 @@ pub enum ReconStatus {
     Active,
     Disabled,
 }
+
+/// Indicates what triggered the delivery of an outgoing webhook to the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryType {
+    /// The first delivery made as soon as the event occurred
+    InitialAttempt,
+    /// A redelivery scheduled after a failed delivery
+    AutomaticRetry,
+    /// A redelivery requested through the API
+    ManualRetry,
+}
+
+/// The status of an incremental authorization made on a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationStatus {
+    Success,
+    Failure,
+    #[default]
+    Processing,
+    Unresolved,
+}
+
+/// The kind of data against which an entry of the blocklist of a merchant is matched
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BlocklistDataKind {
+    /// The fingerprint of a card
+    PaymentMethod,
+    /// The first six digits of a card number, also known as the ISIN
+    CardBin,
+    /// The first eight digits of a card number
+    ExtendedCardBin,
+}
+
+/// The kind of actor which performed an audited administrative action
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    /// The admin API key
+    AdminApiKey,
+    /// An API key of the merchant
+    ApiKey,
+    /// A user of the dashboard of the merchant
+    DashboardUser,
+}
+
+/// An administrative action recorded in the audit log of a merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    MerchantAccountUpdate,
+    PaymentConnectorCreate,
+    PaymentConnectorUpdate,
+    ApiKeyCreate,
+    ApiKeyRevoke,
+    MerchantKvToggle,
+    ConfigCreate,
+    ConfigUpdate,
+    BusinessProfileUpdate,
+    MerchantDataKeyRotate,
+    VaultDataKeyRotate,
+}
+
+/// The kind of entity changed by an audited administrative action
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditTargetType {
+    MerchantAccount,
+    MerchantConnectorAccount,
+    ApiKey,
+    Config,
+    BusinessProfile,
+    MerchantKeyStore,
+    VaultDataKey,
+}
+
+/// The status of the 3DS authentication of a payment performed through an authentication connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthenticationStatus {
+    /// The card has been looked up with the 3DS server, the authentication request is yet to be
+    /// sent to the issuer
+    #[default]
+    Started,
+    /// The issuer has requested a challenge, which the customer is yet to complete
+    Pending,
+    Success,
+    Failed,
+}
+
+impl AuthenticationStatus {
+    pub fn is_terminal_status(self) -> bool {
+        matches!(self, Self::Success | Self::Failed)
+    }
+}
+
+/// The outcome of a 3DS authentication, the `transStatus` of the EMV 3-D Secure specification
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum TransactionStatus {
+    /// Authentication or account verification successful
+    #[serde(rename = "Y")]
+    #[strum(serialize = "Y")]
+    Success,
+    /// Not authenticated, transaction denied
+    #[serde(rename = "N")]
+    #[strum(serialize = "N")]
+    Failure,
+    /// Authentication or account verification could not be performed
+    #[serde(rename = "U")]
+    #[strum(serialize = "U")]
+    VerificationNotPerformed,
+    /// Not authenticated, but a proof of the attempted authentication is provided
+    #[serde(rename = "A")]
+    #[strum(serialize = "A")]
+    NotVerified,
+    /// Authentication or account verification rejected by the issuer
+    #[serde(rename = "R")]
+    #[strum(serialize = "R")]
+    Rejected,
+    /// A challenge is required to complete the authentication
+    #[serde(rename = "C")]
+    #[strum(serialize = "C")]
+    ChallengeRequired,
+    /// A decoupled challenge is required to complete the authentication
+    #[serde(rename = "D")]
+    #[strum(serialize = "D")]
+    ChallengeRequiredDecoupledAuthentication,
+    /// Informational only, the 3DS requestor challenge preference has been acknowledged
+    #[serde(rename = "I")]
+    #[strum(serialize = "I")]
+    InformationOnly,
+}
+
+impl TransactionStatus {
+    /// Whether the authentication shifts the liability of the payment to the issuer, allowing the
+    /// payment to be authorized using the authentication value
+    pub fn is_authenticated(self) -> bool {
+        matches!(self, Self::Success | Self::NotVerified)
+    }
+
+    pub fn is_challenge_required(self) -> bool {
+        matches!(
+            self,
+            Self::ChallengeRequired | Self::ChallengeRequiredDecoupledAuthentication
+        )
+    }
+}
+
+/// Whether the 3DS method was completed, as reported in the authentication request
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ThreeDsCompletionIndicator {
+    /// The 3DS method was completed successfully
+    #[serde(rename = "Y")]
+    #[strum(serialize = "Y")]
+    Success,
+    /// The 3DS method did not complete in time
+    #[serde(rename = "N")]
+    #[strum(serialize = "N")]
+    Failure,
+    /// The issuer does not provide a 3DS method URL for the card
+    #[serde(rename = "U")]
+    #[strum(serialize = "U")]
+    NotAvailable,
+}
@@ -23,3 +23,17 @@ pub static FRM_CONFIGS_EG: &str = r#"
 pub const PAYMENTS_LIST_MAX_LIMIT_V1: u32 = 100;
 /// Maximum limit for payments list post api with filters
 pub const PAYMENTS_LIST_MAX_LIMIT_V2: u32 = 20;
+/// Maximum limit for payouts list api
+pub const PAYOUTS_LIST_MAX_LIMIT: u32 = 20;
+/// Default limit for customers list api
+pub const CUSTOMERS_LIST_DEFAULT_LIMIT: u32 = 10;
+/// Maximum limit for customers list api
+pub const CUSTOMERS_LIST_MAX_LIMIT: u32 = 100;
+/// Default limit for webhook events list api
+pub const EVENTS_LIST_DEFAULT_LIMIT: u32 = 10;
+/// Maximum limit for webhook events list api
+pub const EVENTS_LIST_MAX_LIMIT: u32 = 100;
+/// Default limit for disputes list api, when paginated with a cursor
+pub const DISPUTES_LIST_DEFAULT_LIMIT: u32 = 10;
+/// Maximum limit for disputes list api, when paginated with a cursor
+pub const DISPUTES_LIST_MAX_LIMIT: u32 = 100;
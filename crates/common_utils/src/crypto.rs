@@ -452,6 +452,22 @@ impl<T: Clone> Encryptable<T> {
     pub fn into_encrypted(self) -> Secret<Vec<u8>, EncryptionStratergy> {
         self.encrypted
     }
+
+    ///
+    /// Get the reference to the inner encrypted data
+    ///
+    #[inline]
+    pub fn get_encrypted(&self) -> &Secret<Vec<u8>, EncryptionStratergy> {
+        &self.encrypted
+    }
+
+    ///
+    /// Deconstruct the type into the inner data and the inner encrypted data
+    ///
+    #[inline]
+    pub fn deconstruct(self) -> (T, Secret<Vec<u8>, EncryptionStratergy>) {
+        (self.inner, self.encrypted)
+    }
 }
 
 impl<T: Clone> Deref for Encryptable<Secret<T>> {
@@ -105,6 +105,9 @@ pub struct PaymentIntent {
     // Manual review can occur when the transaction is marked as risky by the frm_processor, payment processor or when there is underpayment/over payment incase of crypto payment
     pub merchant_decision: Option<String>,
     pub payment_confirm_source: Option<storage_enums::PaymentSource>,
+    pub request_incremental_authorization: Option<bool>,
+    pub fingerprint_id: Option<String>,
+    pub payment_link_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
@@ -144,6 +147,9 @@ pub struct PaymentIntentNew {
     pub profile_id: Option<String>,
     pub merchant_decision: Option<String>,
     pub payment_confirm_source: Option<storage_enums::PaymentSource>,
+    pub request_incremental_authorization: Option<bool>,
+    pub fingerprint_id: Option<String>,
+    pub payment_link_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -188,6 +194,7 @@ pub enum PaymentIntentUpdate {
         order_details: Option<Vec<pii::SecretSerdeValue>>,
         metadata: Option<pii::SecretSerdeValue>,
         payment_confirm_source: Option<storage_enums::PaymentSource>,
+        fingerprint_id: Option<String>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -205,6 +212,9 @@ pub enum PaymentIntentUpdate {
         status: storage_enums::IntentStatus,
         merchant_decision: Option<String>,
     },
+    IncrementalAuthorizationAmountUpdate {
+        amount: i64,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -233,6 +243,7 @@ pub struct PaymentIntentUpdateInternal {
     // Manual review can occur when the transaction is marked as risky by the frm_processor, payment processor or when there is underpayment/over payment incase of crypto payment
     pub merchant_decision: Option<String>,
     pub payment_confirm_source: Option<storage_enums::PaymentSource>,
+    pub fingerprint_id: Option<String>,
 }
 
 impl PaymentIntentUpdate {
@@ -258,6 +269,7 @@ impl PaymentIntentUpdate {
                 .or(source.shipping_address_id),
             modified_at: common_utils::date_time::now(),
             order_details: internal_update.order_details.or(source.order_details),
+            fingerprint_id: internal_update.fingerprint_id.or(source.fingerprint_id),
             ..source
         }
     }
@@ -283,6 +295,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_details,
                 metadata,
                 payment_confirm_source,
+                fingerprint_id,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -301,6 +314,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_details,
                 metadata,
                 payment_confirm_source,
+                fingerprint_id,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate { metadata } => Self {
@@ -386,6 +400,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_decision,
                 ..Default::default()
             },
+            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
+                amount: Some(amount),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
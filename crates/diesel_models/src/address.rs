@@ -1,9 +1,10 @@
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{encryption::Encryption, enums, schema::address};
 
-#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
 #[diesel(table_name = address)]
 pub struct AddressNew {
     pub address_id: String,
@@ -20,11 +21,13 @@ pub struct AddressNew {
     pub country_code: Option<String>,
     pub customer_id: String,
     pub merchant_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub modified_at: PrimitiveDateTime,
 }
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = address)]
 pub struct Address {
     pub id: i32,
@@ -40,13 +43,15 @@ pub struct Address {
     pub last_name: Option<Encryption>,
     pub phone_number: Option<Encryption>,
     pub country_code: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub modified_at: PrimitiveDateTime,
     pub customer_id: String,
     pub merchant_id: String,
 }
 
-#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay, Serialize, Deserialize)]
 #[diesel(table_name = address)]
 pub struct AddressUpdateInternal {
     pub city: Option<String>,
@@ -60,23 +65,25 @@ pub struct AddressUpdateInternal {
     pub last_name: Option<Encryption>,
     pub phone_number: Option<Encryption>,
     pub country_code: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub modified_at: PrimitiveDateTime,
 }
 
 impl AddressUpdateInternal {
     pub fn create_address(self, source: Address) -> Address {
+        // Fields which are not set are left unchanged, as in the update made in Postgres
         Address {
-            city: self.city,
-            country: self.country,
-            line1: self.line1,
-            line2: self.line2,
-            line3: self.line3,
-            state: self.state,
-            zip: self.zip,
-            first_name: self.first_name,
-            last_name: self.last_name,
-            phone_number: self.phone_number,
-            country_code: self.country_code,
+            city: self.city.or(source.city),
+            country: self.country.or(source.country),
+            line1: self.line1.or(source.line1),
+            line2: self.line2.or(source.line2),
+            line3: self.line3.or(source.line3),
+            state: self.state.or(source.state),
+            zip: self.zip.or(source.zip),
+            first_name: self.first_name.or(source.first_name),
+            last_name: self.last_name.or(source.last_name),
+            phone_number: self.phone_number.or(source.phone_number),
+            country_code: self.country_code.or(source.country_code),
             modified_at: self.modified_at,
 
             ..source
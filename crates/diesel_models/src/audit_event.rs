@@ -0,0 +1,42 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::audit_event};
+
+/// An administrative action recorded in the audit log. Audit events are append-only, and are
+/// never updated or deleted once inserted.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = audit_event)]
+pub struct AuditEvent {
+    #[serde(skip)]
+    pub id: i32,
+    pub event_id: String,
+    /// The merchant whose configuration was changed, not set for global configuration changes
+    pub merchant_id: Option<String>,
+    pub actor_type: storage_enums::AuditActorType,
+    /// The identifier of the API key used to perform the action, not set for the admin API key
+    pub actor_id: Option<String>,
+    pub action: storage_enums::AuditAction,
+    pub target_type: storage_enums::AuditTargetType,
+    pub target_id: String,
+    /// The fields of the target changed by the action, with sensitive values masked
+    pub diff: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = audit_event)]
+pub struct AuditEventNew {
+    pub event_id: String,
+    pub merchant_id: Option<String>,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub action: storage_enums::AuditAction,
+    pub target_type: storage_enums::AuditTargetType,
+    pub target_id: String,
+    pub diff: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+}
@@ -0,0 +1,192 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, enums as storage_enums, schema::authentication};
+
+/// A 3DS authentication performed through an external authentication connector (3DS server),
+/// on behalf of a payment attempt.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = authentication)]
+#[diesel(primary_key(authentication_id))]
+pub struct Authentication {
+    pub authentication_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub authentication_connector: String,
+    /// The transaction identifier assigned by the 3DS server (`threeDSServerTransID`)
+    pub connector_authentication_id: Option<String>,
+    pub authentication_status: storage_enums::AuthenticationStatus,
+    pub trans_status: Option<storage_enums::TransactionStatus>,
+    pub message_version: Option<String>,
+    pub three_ds_method_url: Option<String>,
+    pub three_ds_method_data: Option<String>,
+    /// Whether the 3DS method completed in the customer's browser (`threeDSCompInd`)
+    pub three_ds_method_completion_ind: Option<storage_enums::ThreeDsCompletionIndicator>,
+    pub acs_url: Option<String>,
+    /// The base64url encoded challenge request (`CReq`) to be posted to the ACS
+    pub challenge_request: Option<String>,
+    /// The cardholder authentication verification value (CAVV or AAV), encrypted with the key of
+    /// the merchant
+    pub authentication_value: Option<Encryption>,
+    pub eci: Option<String>,
+    pub ds_trans_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = authentication)]
+pub struct AuthenticationNew {
+    pub authentication_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub authentication_connector: String,
+    pub connector_authentication_id: Option<String>,
+    pub authentication_status: storage_enums::AuthenticationStatus,
+    pub message_version: Option<String>,
+    pub three_ds_method_url: Option<String>,
+    pub three_ds_method_data: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AuthenticationUpdate {
+    ThreeDsMethodUpdate {
+        three_ds_method_completion_ind: storage_enums::ThreeDsCompletionIndicator,
+    },
+    AuthenticationResponseUpdate {
+        authentication_status: storage_enums::AuthenticationStatus,
+        trans_status: storage_enums::TransactionStatus,
+        acs_url: Option<String>,
+        challenge_request: Option<String>,
+        authentication_value: Option<Encryption>,
+        eci: Option<String>,
+        ds_trans_id: Option<String>,
+    },
+    PostAuthenticationUpdate {
+        authentication_status: storage_enums::AuthenticationStatus,
+        trans_status: storage_enums::TransactionStatus,
+        authentication_value: Option<Encryption>,
+        eci: Option<String>,
+    },
+    ErrorUpdate {
+        authentication_status: storage_enums::AuthenticationStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+    /// Replaces the authentication value, after it is re-encrypted with another key
+    AuthenticationValueUpdate {
+        authentication_value: Option<Encryption>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = authentication)]
+pub struct AuthenticationUpdateInternal {
+    pub authentication_status: Option<storage_enums::AuthenticationStatus>,
+    pub three_ds_method_completion_ind: Option<storage_enums::ThreeDsCompletionIndicator>,
+    pub trans_status: Option<storage_enums::TransactionStatus>,
+    pub acs_url: Option<String>,
+    pub challenge_request: Option<String>,
+    pub authentication_value: Option<Encryption>,
+    pub eci: Option<String>,
+    pub ds_trans_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl AuthenticationUpdate {
+    pub fn apply_changeset(self, source: Authentication) -> Authentication {
+        let update: AuthenticationUpdateInternal = self.into();
+        Authentication {
+            authentication_status: update
+                .authentication_status
+                .unwrap_or(source.authentication_status),
+            three_ds_method_completion_ind: update
+                .three_ds_method_completion_ind
+                .or(source.three_ds_method_completion_ind),
+            trans_status: update.trans_status.or(source.trans_status),
+            acs_url: update.acs_url.or(source.acs_url),
+            challenge_request: update.challenge_request.or(source.challenge_request),
+            authentication_value: update.authentication_value.or(source.authentication_value),
+            eci: update.eci.or(source.eci),
+            ds_trans_id: update.ds_trans_id.or(source.ds_trans_id),
+            error_code: update.error_code.or(source.error_code),
+            error_message: update.error_message.or(source.error_message),
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
+
+impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
+    fn from(authentication_update: AuthenticationUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match authentication_update {
+            AuthenticationUpdate::ThreeDsMethodUpdate {
+                three_ds_method_completion_ind,
+            } => Self {
+                three_ds_method_completion_ind: Some(three_ds_method_completion_ind),
+                modified_at,
+                ..Default::default()
+            },
+            AuthenticationUpdate::AuthenticationResponseUpdate {
+                authentication_status,
+                trans_status,
+                acs_url,
+                challenge_request,
+                authentication_value,
+                eci,
+                ds_trans_id,
+            } => Self {
+                authentication_status: Some(authentication_status),
+                trans_status: Some(trans_status),
+                acs_url,
+                challenge_request,
+                authentication_value,
+                eci,
+                ds_trans_id,
+                modified_at,
+                ..Default::default()
+            },
+            AuthenticationUpdate::PostAuthenticationUpdate {
+                authentication_status,
+                trans_status,
+                authentication_value,
+                eci,
+            } => Self {
+                authentication_status: Some(authentication_status),
+                trans_status: Some(trans_status),
+                authentication_value,
+                eci,
+                modified_at,
+                ..Default::default()
+            },
+            AuthenticationUpdate::ErrorUpdate {
+                authentication_status,
+                error_code,
+                error_message,
+            } => Self {
+                authentication_status: Some(authentication_status),
+                error_code,
+                error_message,
+                modified_at,
+                ..Default::default()
+            },
+            AuthenticationUpdate::AuthenticationValueUpdate {
+                authentication_value,
+            } => Self {
+                authentication_value,
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
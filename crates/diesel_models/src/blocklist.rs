@@ -0,0 +1,28 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::blocklist};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = blocklist)]
+pub struct Blocklist {
+    #[serde(skip)]
+    pub id: i32,
+    pub merchant_id: String,
+    /// The fingerprint of the card for entries of kind `payment_method`, the BIN otherwise
+    pub fingerprint_id: String,
+    pub data_kind: storage_enums::BlocklistDataKind,
+    pub metadata: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = blocklist)]
+pub struct BlocklistNew {
+    pub merchant_id: String,
+    pub fingerprint_id: String,
+    pub data_kind: storage_enums::BlocklistDataKind,
+    pub metadata: Option<serde_json::Value>,
+}
@@ -0,0 +1,52 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::blocklist_fingerprint;
+
+/// The number of disputes and fraud-flagged payments recorded against a card fingerprint
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = blocklist_fingerprint)]
+#[diesel(primary_key(merchant_id, fingerprint_id))]
+pub struct BlocklistFingerprint {
+    pub merchant_id: String,
+    pub fingerprint_id: String,
+    pub dispute_count: i32,
+    pub fraud_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = blocklist_fingerprint)]
+pub struct BlocklistFingerprintNew {
+    pub merchant_id: String,
+    pub fingerprint_id: String,
+    pub dispute_count: i32,
+    pub fraud_count: i32,
+}
+
+/// The counts of a fingerprint are incremented in the database, so that events recorded
+/// concurrently are not lost
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum BlocklistFingerprintUpdate {
+    DisputeCountIncrement,
+    FraudCountIncrement,
+}
+
+impl BlocklistFingerprintUpdate {
+    pub fn apply_changeset(self, source: BlocklistFingerprint) -> BlocklistFingerprint {
+        let (dispute_count, fraud_count) = match self {
+            Self::DisputeCountIncrement => (source.dispute_count + 1, source.fraud_count),
+            Self::FraudCountIncrement => (source.dispute_count, source.fraud_count + 1),
+        };
+        BlocklistFingerprint {
+            dispute_count,
+            fraud_count,
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
@@ -32,6 +32,8 @@ pub struct BusinessProfile {
     pub is_recon_enabled: bool,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub surcharge_config: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -55,6 +57,8 @@ pub struct BusinessProfileNew {
     pub is_recon_enabled: bool,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub surcharge_config: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -75,6 +79,8 @@ pub struct BusinessProfileUpdateInternal {
     pub is_recon_enabled: Option<bool>,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub surcharge_config: Option<serde_json::Value>,
 }
 
 impl From<BusinessProfileNew> for BusinessProfile {
@@ -97,6 +103,8 @@ impl From<BusinessProfileNew> for BusinessProfile {
             payout_routing_algorithm: new.payout_routing_algorithm,
             is_recon_enabled: new.is_recon_enabled,
             applepay_verified_domains: new.applepay_verified_domains,
+            payment_link_config: new.payment_link_config,
+            surcharge_config: new.surcharge_config,
         }
     }
 }
@@ -122,6 +130,8 @@ impl BusinessProfileUpdateInternal {
             payout_routing_algorithm: self.payout_routing_algorithm,
             is_recon_enabled: self.is_recon_enabled.unwrap_or(source.is_recon_enabled),
             applepay_verified_domains: self.applepay_verified_domains,
+            payment_link_config: self.payment_link_config.or(source.payment_link_config),
+            surcharge_config: self.surcharge_config.or(source.surcharge_config),
             ..source
         }
     }
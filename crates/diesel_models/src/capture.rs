@@ -89,6 +89,12 @@ impl CaptureUpdate {
             error_message: capture_update.error_message.or(source.error_message),
             error_code: capture_update.error_code.or(source.error_code),
             error_reason: capture_update.error_reason.or(source.error_reason),
+            connector_capture_id: capture_update
+                .connector_capture_id
+                .or(source.connector_capture_id),
+            connector_response_reference_id: capture_update
+                .connector_response_reference_id
+                .or(source.connector_response_reference_id),
             modified_at: common_utils::date_time::now(),
             ..source
         }
@@ -1,10 +1,11 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{encryption::Encryption, schema::customers};
 
-#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
 #[diesel(table_name = customers)]
 pub struct CustomerNew {
     pub customer_id: String,
@@ -16,11 +17,16 @@ pub struct CustomerNew {
     pub phone_country_code: Option<String>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_customer: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub modified_at: PrimitiveDateTime,
+    pub email_hash: Option<String>,
+    pub phone_hash: Option<String>,
+    pub name_hash: Option<String>,
 }
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = customers)]
 pub struct Customer {
     pub id: i32,
@@ -31,13 +37,20 @@ pub struct Customer {
     pub phone: Option<Encryption>,
     pub phone_country_code: Option<String>,
     pub description: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_customer: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub modified_at: PrimitiveDateTime,
+    pub email_hash: Option<String>,
+    pub phone_hash: Option<String>,
+    pub name_hash: Option<String>,
 }
 
-#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[derive(
+    Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
 #[diesel(table_name = customers)]
 pub struct CustomerUpdateInternal {
     pub name: Option<Encryption>,
@@ -46,6 +59,54 @@ pub struct CustomerUpdateInternal {
     pub description: Option<String>,
     pub phone_country_code: Option<String>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub modified_at: Option<PrimitiveDateTime>,
     pub connector_customer: Option<serde_json::Value>,
+    pub email_hash: Option<String>,
+    pub phone_hash: Option<String>,
+    pub name_hash: Option<String>,
+}
+
+impl CustomerUpdateInternal {
+    pub fn apply_changeset(self, source: Customer) -> Customer {
+        Customer {
+            name: self.name.or(source.name),
+            email: self.email.or(source.email),
+            phone: self.phone.or(source.phone),
+            description: self.description.or(source.description),
+            phone_country_code: self.phone_country_code.or(source.phone_country_code),
+            metadata: self.metadata.or(source.metadata),
+            modified_at: self
+                .modified_at
+                .unwrap_or_else(common_utils::date_time::now),
+            connector_customer: self.connector_customer.or(source.connector_customer),
+            email_hash: self.email_hash.or(source.email_hash),
+            phone_hash: self.phone_hash.or(source.phone_hash),
+            name_hash: self.name_hash.or(source.name_hash),
+            ..source
+        }
+    }
+}
+
+/// Constraints for listing the customers of a merchant
+#[derive(Clone, Debug, Default)]
+pub struct CustomerListConstraints {
+    pub limit: i64,
+    /// Customer ID of the customer after which the customers are listed, in creation order
+    pub starting_after: Option<String>,
+    /// Blind indexes of the searched value, one for each version of the data key of the merchant
+    pub email_hash: Option<Vec<String>>,
+    pub phone_hash: Option<Vec<String>>,
+    pub name_hash: Option<Vec<String>>,
+    /// Blind indexes of the name of redacted customers, which are excluded from the list
+    pub redacted_name_hash: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomerBlindIndexBackfillTrackingData {
+    pub merchant_id: String,
+    /// The `id` of the last customer whose blind indexes were computed
+    pub last_processed_id: Option<i32>,
+    #[serde(default)]
+    pub backfilled_count: u64,
 }
@@ -3,7 +3,7 @@ use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as storage_enums, schema::events};
+use crate::{encryption::Encryption, enums as storage_enums, schema::events};
 
 #[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
 #[diesel(table_name = events)]
@@ -16,6 +16,7 @@ pub struct EventNew {
     pub intent_reference_id: Option<String>,
     pub primary_object_id: String,
     pub primary_object_type: storage_enums::EventObjectType,
+    pub merchant_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -43,6 +44,7 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub merchant_id: Option<String>,
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -56,3 +58,13 @@ impl From<EventUpdate> for EventUpdateInternal {
         }
     }
 }
+
+/// Tracking data of the process tracker task which retries the delivery of an outgoing webhook
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub event_id: String,
+    /// The body of the webhook, encrypted with the data key of the merchant
+    pub request_body: Encryption,
+    pub signature: Option<String>,
+}
@@ -0,0 +1,96 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::incremental_authorization};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = incremental_authorization)]
+#[diesel(primary_key(authorization_id))]
+pub struct IncrementalAuthorization {
+    pub authorization_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    /// The total amount authorized for the payment, if this authorization succeeds
+    pub amount: i64,
+    pub previously_authorized_amount: i64,
+    pub status: storage_enums::AuthorizationStatus,
+    pub reason: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub connector_authorization_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = incremental_authorization)]
+pub struct IncrementalAuthorizationNew {
+    pub authorization_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub amount: i64,
+    pub previously_authorized_amount: i64,
+    pub status: storage_enums::AuthorizationStatus,
+    pub reason: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub connector_authorization_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IncrementalAuthorizationUpdate {
+    StatusUpdate {
+        status: storage_enums::AuthorizationStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+        connector_authorization_id: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incremental_authorization)]
+pub struct IncrementalAuthorizationUpdateInternal {
+    pub status: Option<storage_enums::AuthorizationStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub connector_authorization_id: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl IncrementalAuthorizationUpdate {
+    pub fn apply_changeset(self, source: IncrementalAuthorization) -> IncrementalAuthorization {
+        let update: IncrementalAuthorizationUpdateInternal = self.into();
+        IncrementalAuthorization {
+            status: update.status.unwrap_or(source.status),
+            error_code: update.error_code.or(source.error_code),
+            error_message: update.error_message.or(source.error_message),
+            connector_authorization_id: update
+                .connector_authorization_id
+                .or(source.connector_authorization_id),
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
+
+impl From<IncrementalAuthorizationUpdate> for IncrementalAuthorizationUpdateInternal {
+    fn from(incremental_authorization_update: IncrementalAuthorizationUpdate) -> Self {
+        match incremental_authorization_update {
+            IncrementalAuthorizationUpdate::StatusUpdate {
+                status,
+                error_code,
+                error_message,
+                connector_authorization_id,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                connector_authorization_id,
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
@@ -2,10 +2,16 @@ use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    address::{Address, AddressNew, AddressUpdateInternal},
+    capture::{Capture, CaptureNew, CaptureUpdate},
+    connector_response::{ConnectorResponse, ConnectorResponseNew, ConnectorResponseUpdate},
+    customers::{Customer, CustomerNew, CustomerUpdateInternal},
     errors,
+    mandate::{Mandate, MandateNew, MandateUpdate},
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::{PaymentIntent, PaymentIntentNew, PaymentIntentUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
+    reverse_lookup::ReverseLookupNew,
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,7 +19,7 @@ use crate::{
 pub enum DBOperation {
     Insert { insertable: Insertable },
     Update { updatable: Updateable },
-    Delete,
+    Delete { deletable: Deletable },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,6 +45,12 @@ pub enum Insertable {
     PaymentIntent(PaymentIntentNew),
     PaymentAttempt(PaymentAttemptNew),
     Refund(RefundNew),
+    Address(AddressNew),
+    Customer(CustomerNew),
+    ConnectorResponse(ConnectorResponseNew),
+    Capture(CaptureNew),
+    ReverseLookUp(ReverseLookupNew),
+    Mandate(MandateNew),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -47,6 +59,20 @@ pub enum Updateable {
     PaymentIntentUpdate(PaymentIntentUpdateMems),
     PaymentAttemptUpdate(PaymentAttemptUpdateMems),
     RefundUpdate(RefundUpdateMems),
+    AddressUpdate(AddressUpdateMems),
+    CustomerUpdate(CustomerUpdateMems),
+    ConnectorResponseUpdate(ConnectorResponseUpdateMems),
+    CaptureUpdate(CaptureUpdateMems),
+    MandateUpdate(MandateUpdateMems),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    Customer {
+        customer_id: String,
+        merchant_id: String,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -66,3 +92,33 @@ pub struct RefundUpdateMems {
     pub orig: Refund,
     pub update_data: RefundUpdate,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddressUpdateMems {
+    pub orig: Address,
+    pub update_data: AddressUpdateInternal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomerUpdateMems {
+    pub orig: Customer,
+    pub update_data: CustomerUpdateInternal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectorResponseUpdateMems {
+    pub orig: ConnectorResponse,
+    pub update_data: ConnectorResponseUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CaptureUpdateMems {
+    pub orig: Capture,
+    pub update_data: CaptureUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MandateUpdateMems {
+    pub orig: Mandate,
+    pub update_data: MandateUpdate,
+}
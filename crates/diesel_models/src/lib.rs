@@ -1,5 +1,9 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_event;
+pub mod authentication;
+pub mod blocklist;
+pub mod blocklist_fingerprint;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
@@ -15,6 +19,7 @@ pub mod events;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
+pub mod incremental_authorization;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -25,6 +30,7 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
@@ -34,16 +40,22 @@ pub mod refund;
 pub mod reverse_lookup;
 #[allow(unused_qualifications)]
 pub mod schema;
+pub mod vault;
+#[allow(unused_qualifications)]
+pub mod vault_schema;
+pub mod webhook_delivery_attempt;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
 pub type StorageResult<T> = error_stack::Result<T, errors::DatabaseError>;
 pub type PgPooledConn = async_bb8_diesel::Connection<diesel::PgConnection>;
 pub use self::{
-    address::*, api_keys::*, cards_info::*, configs::*, connector_response::*, customers::*,
-    dispute::*, ephemeral_key::*, events::*, file::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, payment_attempt::*, payment_intent::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*,
+    address::*, api_keys::*, audit_event::*, authentication::*, blocklist::*,
+    blocklist_fingerprint::*, cards_info::*, configs::*, connector_response::*, customers::*,
+    dispute::*, ephemeral_key::*, events::*, file::*, incremental_authorization::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    payment_attempt::*, payment_intent::*, payment_link::*, payment_method::*, process_tracker::*,
+    refund::*, reverse_lookup::*, webhook_delivery_attempt::*,
 };
 
 /// The types and implementations provided by this module are required for the schema generated by
@@ -1,11 +1,12 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::mandate};
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = mandate)]
 pub struct Mandate {
     pub id: i32,
@@ -15,25 +16,36 @@ pub struct Mandate {
     pub payment_method_id: String,
     pub mandate_status: storage_enums::MandateStatus,
     pub mandate_type: storage_enums::MandateType,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub customer_accepted_at: Option<PrimitiveDateTime>,
     pub customer_ip_address: Option<Secret<String, pii::IpAddress>>,
     pub customer_user_agent: Option<String>,
     pub network_transaction_id: Option<String>,
     pub previous_attempt_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
     pub mandate_amount: Option<i64>,
     pub mandate_currency: Option<storage_enums::Currency>,
     pub amount_captured: Option<i64>,
     pub connector: String,
     pub connector_mandate_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub start_date: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
 }
 
 #[derive(
-    router_derive::Setter, Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay,
+    router_derive::Setter,
+    Clone,
+    Debug,
+    Default,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
 )]
 #[diesel(table_name = mandate)]
 pub struct MandateNew {
@@ -43,24 +55,28 @@ pub struct MandateNew {
     pub payment_method_id: String,
     pub mandate_status: storage_enums::MandateStatus,
     pub mandate_type: storage_enums::MandateType,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub customer_accepted_at: Option<PrimitiveDateTime>,
     pub customer_ip_address: Option<Secret<String, pii::IpAddress>>,
     pub customer_user_agent: Option<String>,
     pub network_transaction_id: Option<String>,
     pub previous_attempt_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub created_at: Option<PrimitiveDateTime>,
     pub mandate_amount: Option<i64>,
     pub mandate_currency: Option<storage_enums::Currency>,
     pub amount_captured: Option<i64>,
     pub connector: String,
     pub connector_mandate_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub start_date: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum MandateUpdate {
     StatusUpdate {
         mandate_status: storage_enums::MandateStatus,
@@ -87,6 +103,22 @@ pub struct MandateUpdateInternal {
     connector_mandate_ids: Option<pii::SecretSerdeValue>,
 }
 
+impl MandateUpdate {
+    pub fn apply_changeset(self, source: Mandate) -> Mandate {
+        let mandate_update: MandateUpdateInternal = self.into();
+        Mandate {
+            mandate_status: mandate_update
+                .mandate_status
+                .unwrap_or(source.mandate_status),
+            amount_captured: mandate_update.amount_captured.or(source.amount_captured),
+            connector_mandate_ids: mandate_update
+                .connector_mandate_ids
+                .or(source.connector_mandate_ids),
+            ..source
+        }
+    }
+}
+
 impl From<MandateUpdate> for MandateUpdateInternal {
     fn from(mandate_update: MandateUpdate) -> Self {
         match mandate_update {
@@ -41,6 +41,7 @@ pub struct MerchantConnectorAccount {
     pub profile_id: Option<String>,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub connector_wallets_details: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -68,6 +69,7 @@ pub struct MerchantConnectorAccountNew {
     pub profile_id: Option<String>,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub connector_wallets_details: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -89,6 +91,7 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub frm_config: Option<Vec<Secret<serde_json::Value>>>,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub connector_wallets_details: Option<Encryption>,
 }
 
 impl MerchantConnectorAccountUpdateInternal {
@@ -110,6 +113,9 @@ impl MerchantConnectorAccountUpdateInternal {
             payment_methods_enabled: self.payment_methods_enabled,
             frm_config: self.frm_config,
             modified_at: self.modified_at.unwrap_or(source.modified_at),
+            connector_wallets_details: self
+                .connector_wallets_details
+                .or(source.connector_wallets_details),
 
             ..source
         }
@@ -2,7 +2,10 @@ use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
 
-use crate::{encryption::Encryption, schema::merchant_key_store};
+use crate::{
+    encryption::Encryption,
+    schema::{merchant_key_store, merchant_key_versions},
+};
 
 #[derive(
     Clone,
@@ -20,6 +23,7 @@ pub struct MerchantKeyStore {
     pub key: Encryption,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
 }
 
 #[derive(
@@ -30,6 +34,7 @@ pub struct MerchantKeyStoreNew {
     pub merchant_id: String,
     pub key: Encryption,
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
 }
 
 #[derive(
@@ -37,6 +42,81 @@ pub struct MerchantKeyStoreNew {
 )]
 #[diesel(table_name = merchant_key_store)]
 pub struct MerchantKeyStoreUpdateInternal {
+    pub key: Encryption,
+    pub key_version: i32,
+}
+
+#[derive(Debug)]
+pub enum MerchantKeyStoreUpdate {
+    KeyRotated { key: Encryption, key_version: i32 },
+}
+
+impl From<MerchantKeyStoreUpdate> for MerchantKeyStoreUpdateInternal {
+    fn from(merchant_key_store_update: MerchantKeyStoreUpdate) -> Self {
+        match merchant_key_store_update {
+            MerchantKeyStoreUpdate::KeyRotated { key, key_version } => Self { key, key_version },
+        }
+    }
+}
+
+/// A previous version of the data key of a merchant, retained to decrypt the values which are not
+/// re-encrypted with the current version yet.
+#[derive(
+    Clone,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    Identifiable,
+    Queryable,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = merchant_key_versions)]
+#[diesel(primary_key(merchant_id, key_version))]
+pub struct MerchantKeyVersion {
     pub merchant_id: String,
+    pub key_version: i32,
     pub key: Encryption,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, serde::Serialize, serde::Deserialize, Insertable, router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = merchant_key_versions)]
+pub struct MerchantKeyVersionNew {
+    pub merchant_id: String,
+    pub key_version: i32,
+    pub key: Encryption,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The entities re-encrypted by the merchant key rotation workflow, in the order they are
+/// re-encrypted in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationStage {
+    MerchantAccount,
+    MerchantConnectorAccounts,
+    Customers,
+    Addresses,
+    PaymentMethods,
+    Authentications,
+    /// The bodies of the outgoing webhooks of the events, held by their pending retry tasks
+    Events,
+    WebhookDeliveryAttempts,
+    Completed,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MerchantKeyRotationTrackingData {
+    pub merchant_id: String,
+    pub key_version: i32,
+    pub stage: KeyRotationStage,
+    /// The `id` of the last row re-encrypted in the current stage
+    pub last_processed_id: Option<i32>,
+    /// The `authentication_id` of the last authentication re-encrypted in the `Authentications`
+    /// stage, as authentications have no `id`
+    pub last_processed_authentication_id: Option<String>,
+    pub re_encrypted_count: u64,
 }
@@ -57,6 +57,7 @@ pub struct PaymentAttempt {
     // reference to the payment at connector side
     pub connector_response_reference_id: Option<String>,
     pub amount_capturable: i64,
+    pub fallback_connectors: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Queryable, Serialize, Deserialize)]
@@ -115,6 +116,7 @@ pub struct PaymentAttemptNew {
     pub connector_response_reference_id: Option<String>,
     pub multiple_capture_count: Option<i16>,
     pub amount_capturable: i64,
+    pub fallback_connectors: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -138,6 +140,7 @@ pub enum PaymentAttemptUpdate {
         connector: Option<String>,
         straight_through_algorithm: Option<serde_json::Value>,
         amount_capturable: Option<i64>,
+        fallback_connectors: Option<serde_json::Value>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -159,6 +162,10 @@ pub enum PaymentAttemptUpdate {
         error_code: Option<Option<String>>,
         error_message: Option<Option<String>>,
         amount_capturable: Option<i64>,
+        fallback_connectors: Option<serde_json::Value>,
+        capture_method: Option<storage_enums::CaptureMethod>,
+        surcharge_amount: Option<i64>,
+        tax_amount: Option<i64>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -220,6 +227,10 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         connector_response_reference_id: Option<String>,
     },
+    IncrementalAuthorizationAmountUpdate {
+        amount: i64,
+        amount_capturable: i64,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -253,6 +264,9 @@ pub struct PaymentAttemptUpdateInternal {
     connector_response_reference_id: Option<String>,
     multiple_capture_count: Option<i16>,
     amount_capturable: Option<i64>,
+    fallback_connectors: Option<serde_json::Value>,
+    surcharge_amount: Option<i64>,
+    tax_amount: Option<i64>,
 }
 
 impl PaymentAttemptUpdate {
@@ -279,6 +293,13 @@ impl PaymentAttemptUpdate {
             preprocessing_step_id: pa_update
                 .preprocessing_step_id
                 .or(source.preprocessing_step_id),
+            fallback_connectors: pa_update.fallback_connectors.or(source.fallback_connectors),
+            amount_capturable: pa_update
+                .amount_capturable
+                .unwrap_or(source.amount_capturable),
+            surcharge_amount: pa_update.surcharge_amount.or(source.surcharge_amount),
+            tax_amount: pa_update.tax_amount.or(source.tax_amount),
+            capture_method: pa_update.capture_method.or(source.capture_method),
             ..source
         }
     }
@@ -342,6 +363,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_code,
                 error_message,
                 amount_capturable,
+                fallback_connectors,
+                capture_method,
+                surcharge_amount,
+                tax_amount,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -360,6 +385,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_code,
                 error_message,
                 amount_capturable,
+                fallback_connectors,
+                capture_method,
+                surcharge_amount,
+                tax_amount,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -437,11 +466,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector,
                 straight_through_algorithm,
                 amount_capturable,
+                fallback_connectors,
             } => Self {
                 payment_token,
                 connector,
                 straight_through_algorithm,
                 amount_capturable,
+                fallback_connectors,
                 ..Default::default()
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
@@ -496,6 +527,15 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 amount_capturable: Some(amount_capturable),
                 ..Default::default()
             },
+            PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+            } => Self {
+                amount: Some(amount),
+                amount_capturable: Some(amount_capturable),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -47,6 +47,9 @@ pub struct PaymentIntent {
     // Manual review can occur when the transaction is marked as risky by the frm_processor, payment processor or when there is underpayment/over payment incase of crypto payment
     pub merchant_decision: Option<String>,
     pub payment_confirm_source: Option<storage_enums::PaymentSource>,
+    pub request_incremental_authorization: Option<bool>,
+    pub fingerprint_id: Option<String>,
+    pub payment_link_id: Option<String>,
 }
 
 #[derive(
@@ -98,6 +101,9 @@ pub struct PaymentIntentNew {
     pub profile_id: Option<String>,
     pub merchant_decision: Option<String>,
     pub payment_confirm_source: Option<storage_enums::PaymentSource>,
+    pub request_incremental_authorization: Option<bool>,
+    pub fingerprint_id: Option<String>,
+    pub payment_link_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -142,6 +148,7 @@ pub enum PaymentIntentUpdate {
         order_details: Option<Vec<pii::SecretSerdeValue>>,
         metadata: Option<pii::SecretSerdeValue>,
         payment_confirm_source: Option<storage_enums::PaymentSource>,
+        fingerprint_id: Option<String>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -159,6 +166,9 @@ pub enum PaymentIntentUpdate {
         status: storage_enums::IntentStatus,
         merchant_decision: Option<String>,
     },
+    IncrementalAuthorizationAmountUpdate {
+        amount: i64,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -189,6 +199,7 @@ pub struct PaymentIntentUpdateInternal {
     pub profile_id: Option<String>,
     merchant_decision: Option<String>,
     payment_confirm_source: Option<storage_enums::PaymentSource>,
+    fingerprint_id: Option<String>,
 }
 
 impl PaymentIntentUpdate {
@@ -214,6 +225,7 @@ impl PaymentIntentUpdate {
                 .or(source.shipping_address_id),
             modified_at: common_utils::date_time::now(),
             order_details: internal_update.order_details.or(source.order_details),
+            fingerprint_id: internal_update.fingerprint_id.or(source.fingerprint_id),
             ..source
         }
     }
@@ -239,6 +251,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_details,
                 metadata,
                 payment_confirm_source,
+                fingerprint_id,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -257,6 +270,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_details,
                 metadata,
                 payment_confirm_source,
+                fingerprint_id,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate { metadata } => Self {
@@ -342,6 +356,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_decision,
                 ..Default::default()
             },
+            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
+                amount: Some(amount),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
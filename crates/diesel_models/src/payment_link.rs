@@ -0,0 +1,36 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_link};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payment_link)]
+#[diesel(primary_key(payment_link_id))]
+pub struct PaymentLink {
+    pub payment_link_id: String,
+    pub payment_id: String,
+    pub link_to_pay: String,
+    pub merchant_id: String,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+    /// The time after which the link can no longer be used to pay
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub fulfilment_time: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkNew {
+    pub payment_link_id: String,
+    pub payment_id: String,
+    pub link_to_pay: String,
+    pub merchant_id: String,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub fulfilment_time: PrimitiveDateTime,
+}
@@ -99,27 +99,47 @@ pub struct TokenizeCoreWorkflow {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub enum PaymentMethodUpdate {
-    MetadataUpdate { metadata: Option<serde_json::Value> },
+    MetadataUpdate {
+        metadata: Option<serde_json::Value>,
+    },
+    PaymentMethodDataUpdate {
+        payment_method_data: Option<Encryption>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = payment_methods)]
 pub struct PaymentMethodUpdateInternal {
     metadata: Option<serde_json::Value>,
+    payment_method_data: Option<Encryption>,
 }
 
 impl PaymentMethodUpdateInternal {
     pub fn create_payment_method(self, source: PaymentMethod) -> PaymentMethod {
-        let metadata = self.metadata.map(Secret::new);
+        let metadata = self.metadata.map(Secret::new).or(source.metadata);
+        let payment_method_data = self.payment_method_data.or(source.payment_method_data);
 
-        PaymentMethod { metadata, ..source }
+        PaymentMethod {
+            metadata,
+            payment_method_data,
+            ..source
+        }
     }
 }
 
 impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
     fn from(payment_method_update: PaymentMethodUpdate) -> Self {
         match payment_method_update {
-            PaymentMethodUpdate::MetadataUpdate { metadata } => Self { metadata },
+            PaymentMethodUpdate::MetadataUpdate { metadata } => Self {
+                metadata,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::PaymentMethodDataUpdate {
+                payment_method_data,
+            } => Self {
+                payment_method_data,
+                ..Default::default()
+            },
         }
     }
 }
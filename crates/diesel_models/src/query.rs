@@ -1,5 +1,9 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_event;
+pub mod authentication;
+pub mod blocklist;
+pub mod blocklist_fingerprint;
 pub mod business_profile;
 mod capture;
 pub mod cards_info;
@@ -11,6 +15,7 @@ pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod generics;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -18,9 +23,12 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod vault;
+pub mod webhook_delivery_attempt;
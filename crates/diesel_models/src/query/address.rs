@@ -17,6 +17,27 @@ impl AddressNew {
 }
 
 impl Address {
+    /// Finds a batch of the rows of the merchant with an `id` greater than `id`, in the ascending
+    /// order of `id`.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.gt(id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn update_by_address_id(
         conn: &PgPooledConn,
@@ -0,0 +1,104 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    authentication::{
+        Authentication, AuthenticationNew, AuthenticationUpdate, AuthenticationUpdateInternal,
+    },
+    errors,
+    schema::authentication::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AuthenticationNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Authentication> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Authentication {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_authentication_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        authentication_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::authentication_id.eq(authentication_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_latest_by_merchant_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+            Some(1),
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+        .map(|authentications| authentications.into_iter().next())
+    }
+
+    /// Finds a batch of the authentications of the merchant with an `authentication_id` greater
+    /// than `authentication_id`, in the ascending order of `authentication_id`.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_after_authentication_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        authentication_id: &str,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::authentication_id.gt(authentication_id.to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::authentication_id.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_merchant_id_authentication_id(
+        self,
+        conn: &PgPooledConn,
+        authentication_update: AuthenticationUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::authentication_id
+                .eq(self.authentication_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
+            AuthenticationUpdateInternal::from(authentication_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    blocklist_fingerprint::{
+        BlocklistFingerprint, BlocklistFingerprintNew, BlocklistFingerprintUpdate,
+    },
+    schema::blocklist_fingerprint::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl BlocklistFingerprintNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BlocklistFingerprint> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BlocklistFingerprint {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::fingerprint_id.eq(fingerprint_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        blocklist_fingerprint: BlocklistFingerprintUpdate,
+    ) -> StorageResult<Self> {
+        let predicate = dsl::merchant_id
+            .eq(self.merchant_id.to_owned())
+            .and(dsl::fingerprint_id.eq(self.fingerprint_id.to_owned()));
+        let modified_at = dsl::modified_at.eq(common_utils::date_time::now());
+        match blocklist_fingerprint {
+            BlocklistFingerprintUpdate::DisputeCountIncrement => {
+                generics::generic_update_with_unique_predicate_get_result::<
+                    <Self as HasTable>::Table,
+                    _,
+                    _,
+                    _,
+                >(
+                    conn,
+                    predicate,
+                    (dsl::dispute_count.eq(dsl::dispute_count + 1), modified_at),
+                )
+                .await
+            }
+            BlocklistFingerprintUpdate::FraudCountIncrement => {
+                generics::generic_update_with_unique_predicate_get_result::<
+                    <Self as HasTable>::Table,
+                    _,
+                    _,
+                    _,
+                >(
+                    conn,
+                    predicate,
+                    (dsl::fraud_count.eq(dsl::fraud_count + 1), modified_at),
+                )
+                .await
+            }
+        }
+    }
+}
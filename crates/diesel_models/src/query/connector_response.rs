@@ -41,6 +41,38 @@ impl ConnectorResponse {
         }
     }
 
+    /// Updates the connector response identified by its payment, merchant and attempt IDs rather
+    /// than its primary key, which is not known for connector responses written in KV mode
+    #[instrument(skip(conn))]
+    pub async fn update_by_payment_id_merchant_id_attempt_id(
+        self,
+        conn: &PgPooledConn,
+        connector_response: ConnectorResponseUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id.eq(self.merchant_id.to_owned()).and(
+                dsl::payment_id
+                    .eq(self.payment_id.to_owned())
+                    .and(dsl::attempt_id.eq(self.attempt_id.to_owned())),
+            ),
+            ConnectorResponseUpdateInternal::from(connector_response),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_payment_id_merchant_id_attempt_id(
         conn: &PgPooledConn,
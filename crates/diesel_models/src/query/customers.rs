@@ -1,10 +1,15 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
 
 use super::generics;
 use crate::{
-    customers::{Customer, CustomerNew, CustomerUpdateInternal},
+    customers::{Customer, CustomerListConstraints, CustomerNew, CustomerUpdateInternal},
     errors,
+    query::generics::db_metrics,
     schema::customers::dsl,
     PgPooledConn, StorageResult,
 };
@@ -17,6 +22,27 @@ impl CustomerNew {
 }
 
 impl Customer {
+    /// Finds a batch of the rows of the merchant with an `id` greater than `id`, in the ascending
+    /// order of `id`.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.gt(id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn update_by_customer_id_merchant_id(
         conn: &PgPooledConn,
@@ -85,4 +111,55 @@ impl Customer {
         )
         .await
     }
+
+    /// Lists the customers of a merchant, most recently created first
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: CustomerListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::id.desc())
+            .limit(constraints.limit)
+            .into_boxed();
+
+        if let Some(starting_after) = constraints.starting_after {
+            let cursor =
+                Self::find_by_customer_id_merchant_id(conn, &starting_after, merchant_id).await?;
+            query = query.filter(dsl::id.lt(cursor.id));
+        }
+
+        if let Some(email_hash) = constraints.email_hash {
+            query = query.filter(dsl::email_hash.eq_any(email_hash));
+        }
+
+        if let Some(phone_hash) = constraints.phone_hash {
+            query = query.filter(dsl::phone_hash.eq_any(phone_hash));
+        }
+
+        if let Some(name_hash) = constraints.name_hash {
+            query = query.filter(dsl::name_hash.eq_any(name_hash));
+        }
+
+        if !constraints.redacted_name_hash.is_empty() {
+            query = query.filter(
+                dsl::name_hash
+                    .is_null()
+                    .or(dsl::name_hash.ne_all(constraints.redacted_name_hash)),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering customers by constraints")
+    }
 }
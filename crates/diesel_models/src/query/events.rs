@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use router_env::{instrument, tracing};
 
 use super::generics;
@@ -16,6 +16,79 @@ impl EventNew {
 }
 
 impl Event {
+    #[instrument(skip(conn))]
+    pub async fn find_by_event_id(conn: &PgPooledConn, event_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::event_id.eq(event_id.to_owned()),
+        )
+        .await
+    }
+
+    /// Finds a batch of the rows of the merchant with an `id` greater than `id`, in the ascending
+    /// order of `id`.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.gt(id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
+    /// Lists the events of the merchant, most recent first, after the event with the
+    /// `starting_after_id` ID when given
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        primary_object_id: Option<&str>,
+        starting_after_id: Option<i32>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        // The events are ordered by their ID, which follows their creation time, so that the ID of
+        // the last event of a page can be used as a cursor
+        let before_id = starting_after_id.unwrap_or(i32::MAX);
+        match primary_object_id {
+            Some(primary_object_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::primary_object_id.eq(primary_object_id.to_owned()))
+                        .and(dsl::id.lt(before_id)),
+                    limit,
+                    offset,
+                    Some(dsl::id.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::id.lt(before_id)),
+                    limit,
+                    offset,
+                    Some(dsl::id.desc()),
+                )
+                .await
+            }
+        }
+    }
+
     #[instrument(skip(conn))]
     pub async fn update(
         conn: &PgPooledConn,
@@ -1,9 +1,16 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::{report, IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::generics;
-use crate::{errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult};
+use crate::{
+    errors, mandate::*, query::generics::db_metrics, schema::mandate::dsl, PgPooledConn,
+    StorageResult,
+};
 
 impl MandateNew {
     #[instrument(skip(conn))]
@@ -70,4 +77,29 @@ impl Mandate {
                 .attach_printable("Error while updating mandate")
         })
     }
+
+    /// Counts the mandates of each of the given customers of a merchant
+    #[instrument(skip(conn))]
+    pub async fn get_count_by_merchant_id_customer_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_ids: Vec<String>,
+    ) -> StorageResult<Vec<(String, i64)>> {
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::customer_id.eq_any(customer_ids))
+            .group_by(dsl::customer_id)
+            .select((dsl::customer_id, diesel::dsl::count_star()));
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(String, i64)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting mandates by customer")
+    }
 }
@@ -1,10 +1,13 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use router_env::{instrument, tracing};
 
 use super::generics;
 use crate::{
-    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew},
-    schema::merchant_key_store::dsl,
+    merchant_key_store::{
+        MerchantKeyStore, MerchantKeyStoreNew, MerchantKeyStoreUpdate,
+        MerchantKeyStoreUpdateInternal, MerchantKeyVersion, MerchantKeyVersionNew,
+    },
+    schema::{merchant_key_store::dsl, merchant_key_versions::dsl as versions_dsl},
     PgPooledConn, StorageResult,
 };
 
@@ -27,4 +30,52 @@ impl MerchantKeyStore {
         )
         .await
     }
+
+    /// Updates the key store only if its key version is still `key_version`, so that concurrent
+    /// key rotations do not overwrite each other.
+    #[instrument(skip(conn))]
+    pub async fn update_by_merchant_id_key_version(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        key_version: i32,
+        merchant_key_store_update: MerchantKeyStoreUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::key_version.eq(key_version)),
+            MerchantKeyStoreUpdateInternal::from(merchant_key_store_update),
+        )
+        .await
+    }
+}
+
+impl MerchantKeyVersionNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MerchantKeyVersion> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl MerchantKeyVersion {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            versions_dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(versions_dsl::key_version.asc()),
+        )
+        .await
+    }
 }
@@ -1,10 +1,16 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::generics;
 use crate::{
     errors,
     payment_method::{self, PaymentMethod, PaymentMethodNew},
+    query::generics::db_metrics,
     schema::payment_methods::dsl,
     PgPooledConn, StorageResult,
 };
@@ -17,6 +23,27 @@ impl PaymentMethodNew {
 }
 
 impl PaymentMethod {
+    /// Finds a batch of the rows of the merchant with an `id` greater than `id`, in the ascending
+    /// order of `id`.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.gt(id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn delete_by_payment_method_id(
         conn: &PgPooledConn,
@@ -123,4 +150,29 @@ impl PaymentMethod {
             result => result,
         }
     }
+
+    /// Counts the payment methods of each of the given customers of a merchant
+    #[instrument(skip(conn))]
+    pub async fn get_count_by_merchant_id_customer_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_ids: Vec<String>,
+    ) -> StorageResult<Vec<(String, i64)>> {
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::customer_id.eq_any(customer_ids))
+            .group_by(dsl::customer_id)
+            .select((dsl::customer_id, diesel::dsl::count_star()));
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(String, i64)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting payment methods by customer")
+    }
 }
@@ -1,4 +1,6 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl, Table};
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
@@ -136,4 +138,54 @@ impl ProcessTracker {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_status_updated_before(
+        conn: &PgPooledConn,
+        status: enums::ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::status
+                .eq(status)
+                .and(dsl::updated_at.lt(updated_before)),
+            Some(limit),
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Deletes at most `limit` finished processes which were last updated before
+    /// `updated_before`, returning the number of processes deleted
+    #[instrument(skip(conn))]
+    pub async fn delete_finished_processes_before(
+        conn: &PgPooledConn,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<usize> {
+        // Postgres does not support `LIMIT` on `DELETE`, so the rows are selected in a subquery
+        let ids = dsl::process_tracker
+            .select(dsl::id)
+            .filter(
+                dsl::status
+                    .eq(enums::ProcessTrackerStatus::Finish)
+                    .and(dsl::updated_at.lt(updated_before)),
+            )
+            .limit(limit);
+
+        diesel::delete(<Self as HasTable>::table().filter(dsl::id.eq_any(ids)))
+            .execute_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while deleting finished processes")
+    }
 }
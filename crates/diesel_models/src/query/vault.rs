@@ -0,0 +1,205 @@
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl, ConnectionError};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    errors,
+    vault::{
+        VaultAccessLog, VaultAccessLogNew, VaultCard, VaultCardNew, VaultCardUpdate,
+        VaultCardUpdateInternal, VaultDataKey, VaultDataKeyNew, VaultDataKeyUpdate,
+        VaultDataKeyUpdateInternal,
+    },
+    vault_schema::vault::{cards, data_keys},
+    PgPooledConn, StorageResult,
+};
+
+impl VaultDataKeyNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultDataKey> {
+        generics::generic_insert(conn, self).await
+    }
+
+    /// Inserts the data key as the active data key of its merchant and deactivates the data key
+    /// which was active until now, in a single transaction
+    #[instrument(skip(conn))]
+    pub async fn insert_as_active(
+        self,
+        conn: &PgPooledConn,
+        rotated_at: PrimitiveDateTime,
+    ) -> StorageResult<VaultDataKey> {
+        let merchant_id = self.merchant_id.clone();
+        let result = conn
+            .transaction_async(|conn| async move {
+                diesel::update(data_keys::table)
+                    .filter(
+                        data_keys::dsl::merchant_id
+                            .eq(merchant_id)
+                            .and(data_keys::dsl::is_active.eq(true)),
+                    )
+                    .set(VaultDataKeyUpdateInternal::from(
+                        VaultDataKeyUpdate::Rotated { rotated_at },
+                    ))
+                    .execute_async(&conn)
+                    .await?;
+
+                diesel::insert_into(data_keys::table)
+                    .values(self)
+                    .get_result_async::<VaultDataKey>(&conn)
+                    .await
+            })
+            .await
+            .into_report();
+
+        match result {
+            Ok(data_key) => Ok(data_key),
+            Err(err) => match err.current_context() {
+                ConnectionError::Query(diesel::result::Error::DatabaseError(
+                    diesel::result::DatabaseErrorKind::UniqueViolation,
+                    _,
+                )) => Err(err).change_context(errors::DatabaseError::UniqueViolation),
+                _ => Err(err).change_context(errors::DatabaseError::Others),
+            },
+        }
+        .attach_printable("Failed to rotate the vault data key")
+    }
+}
+
+impl VaultDataKey {
+    /// Finds the active data key of the merchant. Should several data keys be active, the latest
+    /// version is returned.
+    #[instrument(skip(conn))]
+    pub async fn find_optional_active_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            data_keys::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(data_keys::dsl::is_active.eq(true)),
+            Some(1),
+            None,
+            Some(data_keys::dsl::key_version.desc()),
+        )
+        .await
+        .map(|data_keys| data_keys.into_iter().next())
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_key_version(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        key_version: i32,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            data_keys::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(data_keys::dsl::key_version.eq(key_version)),
+        )
+        .await
+    }
+}
+
+impl VaultCardNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultCard> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultCard {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_card_reference(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            cards::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(cards::dsl::card_reference.eq(card_reference.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_merchant_id_customer_id_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        fingerprint: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            cards::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(cards::dsl::customer_id.eq(customer_id.to_owned()))
+                .and(cards::dsl::fingerprint.eq(fingerprint.to_owned())),
+        )
+        .await
+    }
+
+    /// Finds the cards of a merchant which are not encrypted with the given version of the data key
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_key_version_not(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        key_version: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            cards::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(cards::dsl::key_version.ne(key_version)),
+            Some(limit),
+            None,
+            Some(cards::dsl::id.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(self, conn: &PgPooledConn, card: VaultCardUpdate) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            cards::dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(cards::dsl::card_reference.eq(self.card_reference.to_owned())),
+            VaultCardUpdateInternal::from(card),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_card_reference(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            cards::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(cards::dsl::card_reference.eq(card_reference.to_owned())),
+        )
+        .await
+    }
+}
+
+impl VaultAccessLogNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultAccessLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
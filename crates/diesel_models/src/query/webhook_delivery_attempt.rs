@@ -0,0 +1,73 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    schema::webhook_delivery_attempt::dsl,
+    webhook_delivery_attempt::{
+        WebhookDeliveryAttempt, WebhookDeliveryAttemptNew, WebhookDeliveryAttemptUpdate,
+        WebhookDeliveryAttemptUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookDeliveryAttemptNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookDeliveryAttempt> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookDeliveryAttempt {
+    #[instrument(skip(conn))]
+    pub async fn find_by_event_id(conn: &PgPooledConn, event_id: &str) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::event_id.eq(event_id.to_owned()),
+            None,
+            None,
+            Some(dsl::attempt_number.asc()),
+        )
+        .await
+    }
+
+    /// Finds a batch of the rows of the merchant with an `id` greater than `id`, in the ascending
+    /// order of `id`.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.gt(id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        delivery_attempt: WebhookDeliveryAttemptUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(self.id),
+            WebhookDeliveryAttemptUpdateInternal::from(delivery_attempt),
+        )
+        .await
+    }
+}
@@ -53,6 +53,109 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_event (id) {
+        id -> Int4,
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        actor_type -> Varchar,
+        #[max_length = 64]
+        actor_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        action -> Varchar,
+        #[max_length = 64]
+        target_type -> Varchar,
+        #[max_length = 255]
+        target_id -> Varchar,
+        diff -> Nullable<Jsonb>,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    authentication (authentication_id) {
+        #[max_length = 64]
+        authentication_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        authentication_connector -> Varchar,
+        #[max_length = 64]
+        connector_authentication_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        authentication_status -> Varchar,
+        #[max_length = 8]
+        trans_status -> Nullable<Varchar>,
+        #[max_length = 16]
+        message_version -> Nullable<Varchar>,
+        three_ds_method_url -> Nullable<Text>,
+        three_ds_method_data -> Nullable<Text>,
+        #[max_length = 8]
+        three_ds_method_completion_ind -> Nullable<Varchar>,
+        acs_url -> Nullable<Text>,
+        challenge_request -> Nullable<Text>,
+        #[max_length = 255]
+        authentication_value -> Nullable<Bytea>,
+        #[max_length = 8]
+        eci -> Nullable<Varchar>,
+        #[max_length = 64]
+        ds_trans_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    blocklist (id) {
+        id -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 128]
+        fingerprint_id -> Varchar,
+        #[max_length = 32]
+        data_kind -> Varchar,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    blocklist_fingerprint (merchant_id, fingerprint_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 128]
+        fingerprint_id -> Varchar,
+        dispute_count -> Int4,
+        fraud_count -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -79,6 +182,8 @@ diesel::table! {
         payout_routing_algorithm -> Nullable<Jsonb>,
         is_recon_enabled -> Bool,
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
+        payment_link_config -> Nullable<Jsonb>,
+        surcharge_config -> Nullable<Jsonb>,
     }
 }
 
@@ -197,6 +302,12 @@ diesel::table! {
         metadata -> Nullable<Json>,
         connector_customer -> Nullable<Jsonb>,
         modified_at -> Timestamp,
+        #[max_length = 64]
+        email_hash -> Nullable<Varchar>,
+        #[max_length = 64]
+        phone_hash -> Nullable<Varchar>,
+        #[max_length = 64]
+        name_hash -> Nullable<Varchar>,
     }
 }
 
@@ -258,6 +369,8 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
     }
 }
 
@@ -320,6 +433,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incremental_authorization (authorization_id) {
+        #[max_length = 64]
+        authorization_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        amount -> Int8,
+        previously_authorized_amount -> Int8,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        #[max_length = 128]
+        connector_authorization_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -472,6 +612,7 @@ diesel::table! {
         #[max_length = 64]
         profile_id -> Nullable<Varchar>,
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
+        connector_wallets_details -> Nullable<Bytea>,
     }
 }
 
@@ -484,6 +625,20 @@ diesel::table! {
         merchant_id -> Varchar,
         key -> Bytea,
         created_at -> Timestamp,
+        key_version -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_key_versions (merchant_id, key_version) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        key_version -> Int4,
+        key -> Bytea,
+        created_at -> Timestamp,
     }
 }
 
@@ -547,6 +702,7 @@ diesel::table! {
         #[max_length = 128]
         connector_response_reference_id -> Nullable<Varchar>,
         amount_capturable -> Int8,
+        fallback_connectors -> Nullable<Jsonb>,
     }
 }
 
@@ -603,6 +759,32 @@ diesel::table! {
         #[max_length = 64]
         merchant_decision -> Nullable<Varchar>,
         payment_confirm_source -> Nullable<PaymentSource>,
+        request_incremental_authorization -> Nullable<Bool>,
+        #[max_length = 128]
+        fingerprint_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        payment_link_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_link (payment_link_id) {
+        #[max_length = 255]
+        payment_link_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 255]
+        link_to_pay -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+        fulfilment_time -> Timestamp,
     }
 }
 
@@ -805,9 +987,37 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_delivery_attempt (id) {
+        id -> Int4,
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        attempt_number -> Int2,
+        #[max_length = 32]
+        delivery_type -> Varchar,
+        is_delivered -> Bool,
+        response_status_code -> Nullable<Int2>,
+        latency_ms -> Nullable<Int8>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        request_body -> Nullable<Bytea>,
+        request_signature -> Nullable<Text>,
+        response_body -> Nullable<Bytea>,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    audit_event,
+    authentication,
+    blocklist,
+    blocklist_fingerprint,
     business_profile,
     captures,
     cards_info,
@@ -818,17 +1028,21 @@ diesel::allow_tables_to_appear_in_same_query!(
     events,
     file_metadata,
     fraud_check,
+    incremental_authorization,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
     merchant_key_store,
+    merchant_key_versions,
     payment_attempt,
     payment_intent,
+    payment_link,
     payment_methods,
     payout_attempt,
     payouts,
     process_tracker,
     refund,
     reverse_lookup,
+    webhook_delivery_attempt,
 );
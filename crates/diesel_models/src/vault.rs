@@ -0,0 +1,142 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    encryption::Encryption,
+    vault_schema::vault::{access_log, cards, data_keys},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_keys)]
+pub struct VaultDataKey {
+    pub id: i32,
+    pub merchant_id: String,
+    pub key_version: i32,
+    pub wrapped_key: Encryption,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub rotated_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_keys)]
+pub struct VaultDataKeyNew {
+    pub merchant_id: String,
+    pub key_version: i32,
+    pub wrapped_key: Encryption,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_keys)]
+pub struct VaultDataKeyUpdateInternal {
+    pub is_active: bool,
+    pub rotated_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum VaultDataKeyUpdate {
+    /// The data key was replaced by a newer version, and is only used to decrypt the cards which
+    /// have not been re-encrypted yet
+    Rotated { rotated_at: PrimitiveDateTime },
+}
+
+impl From<VaultDataKeyUpdate> for VaultDataKeyUpdateInternal {
+    fn from(value: VaultDataKeyUpdate) -> Self {
+        match value {
+            VaultDataKeyUpdate::Rotated { rotated_at } => Self {
+                is_active: false,
+                rotated_at: Some(rotated_at),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = cards)]
+pub struct VaultCard {
+    pub id: i32,
+    pub card_reference: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub fingerprint: String,
+    pub key_version: i32,
+    pub enc_data: Encryption,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = cards)]
+pub struct VaultCardNew {
+    pub card_reference: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub fingerprint: String,
+    pub key_version: i32,
+    pub enc_data: Encryption,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = cards)]
+pub struct VaultCardUpdateInternal {
+    pub key_version: i32,
+    pub enc_data: Encryption,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum VaultCardUpdate {
+    /// The card data was updated, or re-encrypted with another version of the data key
+    EncryptedDataUpdate {
+        key_version: i32,
+        enc_data: Encryption,
+    },
+}
+
+impl From<VaultCardUpdate> for VaultCardUpdateInternal {
+    fn from(value: VaultCardUpdate) -> Self {
+        match value {
+            VaultCardUpdate::EncryptedDataUpdate {
+                key_version,
+                enc_data,
+            } => Self {
+                key_version,
+                enc_data,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = access_log)]
+pub struct VaultAccessLog {
+    pub id: i32,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_reference: String,
+    pub flow: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = access_log)]
+pub struct VaultAccessLogNew {
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_reference: String,
+    pub flow: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct VaultKeyRotationTrackingData {
+    pub merchant_id: String,
+    /// Version of the data key the cards of the merchant are re-encrypted with
+    pub key_version: i32,
+    pub re_encrypted_count: u64,
+}
@@ -0,0 +1,74 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, enums as storage_enums, schema::webhook_delivery_attempt};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_delivery_attempt)]
+pub struct WebhookDeliveryAttemptNew {
+    pub event_id: String,
+    pub merchant_id: String,
+    /// Assigned when the attempt is recorded, following the attempts recorded for the event so far
+    pub attempt_number: i16,
+    pub delivery_type: storage_enums::WebhookDeliveryType,
+    pub is_delivered: bool,
+    pub response_status_code: Option<i16>,
+    pub latency_ms: Option<i64>,
+    pub error_message: Option<String>,
+    pub request_body: Option<Encryption>,
+    pub request_signature: Option<String>,
+    pub response_body: Option<Encryption>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = webhook_delivery_attempt)]
+pub struct WebhookDeliveryAttempt {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub event_id: String,
+    pub merchant_id: String,
+    pub attempt_number: i16,
+    pub delivery_type: storage_enums::WebhookDeliveryType,
+    pub is_delivered: bool,
+    pub response_status_code: Option<i16>,
+    pub latency_ms: Option<i64>,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub request_body: Option<Encryption>,
+    #[serde(skip_serializing)]
+    pub request_signature: Option<String>,
+    pub response_body: Option<Encryption>,
+}
+
+#[derive(Debug)]
+pub enum WebhookDeliveryAttemptUpdate {
+    /// Replaces the bodies of the attempt, after they are re-encrypted with another key
+    BodiesUpdate {
+        request_body: Option<Encryption>,
+        response_body: Option<Encryption>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_delivery_attempt)]
+pub struct WebhookDeliveryAttemptUpdateInternal {
+    pub request_body: Option<Encryption>,
+    pub response_body: Option<Encryption>,
+}
+
+impl From<WebhookDeliveryAttemptUpdate> for WebhookDeliveryAttemptUpdateInternal {
+    fn from(delivery_attempt_update: WebhookDeliveryAttemptUpdate) -> Self {
+        match delivery_attempt_update {
+            WebhookDeliveryAttemptUpdate::BodiesUpdate {
+                request_body,
+                response_body,
+            } => Self {
+                request_body,
+                response_body,
+            },
+        }
+    }
+}
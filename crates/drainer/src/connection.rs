@@ -1,11 +1,13 @@
 use bb8::PooledConnection;
 use diesel::PgConnection;
+use diesel_models::errors::DatabaseError;
+use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "kms")]
 use external_services::kms::{self, decrypt::KmsDecrypt};
 #[cfg(not(feature = "kms"))]
 use masking::PeekInterface;
 
-use crate::settings::Database;
+use crate::{errors, settings::Database};
 
 pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
 
@@ -52,11 +54,15 @@ pub async fn diesel_make_pg_pool(
         .expect("Failed to create PostgreSQL connection pool")
 }
 
-#[allow(clippy::expect_used)]
 pub async fn pg_connection(
     pool: &PgPool,
-) -> PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>> {
+) -> errors::DrainerResult<PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>>
+{
     pool.get()
         .await
-        .expect("Couldn't retrieve PostgreSQL connection")
+        .into_report()
+        .change_context(DatabaseError::DatabaseConnectionError)
+        .attach_printable("Couldn't retrieve PostgreSQL connection")
+        .map_err(errors::DrainerError::from)
+        .into_report()
 }
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, sync::Arc};
+
+use error_stack::IntoReport;
+use redis_interface as redis;
+
+use crate::{
+    errors::{self, DrainerError},
+    logger, metrics, services,
+    settings::DeadLetterCommand,
+    utils,
+};
+
+/// Prefix of the fields added to a dead-lettered entry, alongside the fields of the original entry
+const DEAD_LETTER_FIELD_PREFIX: &str = "dead_letter_";
+
+/// Number of entries replayed per read from the dead letter stream
+const REPLAY_BATCH_SIZE: u64 = 100;
+
+pub fn get_dead_letter_stream_name(stream_name: &str) -> String {
+    // Example: {shard_5}_drainer_stream_dead_letter
+    format!("{stream_name}_dead_letter")
+}
+
+/// Moves an entry which could not be drained to the dead letter stream of its partition, along
+/// with the ID it had in the drainer stream and the error it failed with.
+pub async fn move_to_dead_letter_stream(
+    stream_name: &str,
+    entry_id: &str,
+    entry: &HashMap<String, String>,
+    error: &error_stack::Report<DrainerError>,
+    redis: &redis::RedisConnectionPool,
+) -> errors::DrainerResult<()> {
+    let mut fields = entry
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Vec<_>>();
+    fields.extend([
+        (
+            format!("{DEAD_LETTER_FIELD_PREFIX}source_entry_id"),
+            entry_id.to_owned(),
+        ),
+        (
+            format!("{DEAD_LETTER_FIELD_PREFIX}error"),
+            error.current_context().to_string(),
+        ),
+        (
+            format!("{DEAD_LETTER_FIELD_PREFIX}failed_at"),
+            common_utils::date_time::now().to_string(),
+        ),
+    ]);
+
+    redis
+        .stream_append_entry(
+            &get_dead_letter_stream_name(stream_name),
+            &redis::RedisEntryId::AutoGeneratedID,
+            fields,
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    metrics::ENTRIES_DEAD_LETTERED.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+    );
+
+    Ok(())
+}
+
+pub async fn run_command(
+    store: Arc<services::Store>,
+    command: DeadLetterCommand,
+) -> errors::DrainerResult<()> {
+    match command {
+        DeadLetterCommand::List { partition, count } => {
+            let stream_name = get_partition_stream_name(store.clone(), partition)?;
+            let entries = store
+                .redis_conn
+                .stream_read_range(
+                    &get_dead_letter_stream_name(&stream_name),
+                    "-",
+                    "+",
+                    Some(count),
+                )
+                .await
+                .map_err(DrainerError::from)
+                .into_report()?;
+
+            for (entry_id, entry) in entries {
+                let entry = serde_json::to_string(&entry)
+                    .map_err(|error| DrainerError::ParsingError(error.to_string()))
+                    .into_report()?;
+                println!("{entry_id} {entry}");
+            }
+        }
+        DeadLetterCommand::Replay {
+            partition,
+            entry_id,
+        } => {
+            let stream_name = get_partition_stream_name(store.clone(), partition)?;
+            let replayed_count = match entry_id {
+                Some(entry_id) => {
+                    replay_entries(&store, &stream_name, &entry_id, &entry_id, 1).await?
+                }
+                None => {
+                    // Bounded by the initial length of the dead letter stream, as replayed entries
+                    // may be dead-lettered again while the stream is being replayed
+                    let dead_letter_count = store
+                        .redis_conn
+                        .stream_get_length(get_dead_letter_stream_name(&stream_name).as_str())
+                        .await
+                        .map_err(DrainerError::from)
+                        .into_report()?;
+
+                    let mut replayed_count = 0;
+                    while replayed_count < dead_letter_count {
+                        let count = replay_entries(
+                            &store,
+                            &stream_name,
+                            "-",
+                            "+",
+                            REPLAY_BATCH_SIZE.min(
+                                u64::try_from(dead_letter_count - replayed_count)
+                                    .unwrap_or(REPLAY_BATCH_SIZE),
+                            ),
+                        )
+                        .await?;
+                        if count == 0 {
+                            break;
+                        }
+                        replayed_count += count;
+                    }
+                    replayed_count
+                }
+            };
+            println!("Replayed {replayed_count} entries to {stream_name}");
+        }
+        DeadLetterCommand::Discard {
+            partition,
+            entry_id,
+        } => {
+            let stream_name = get_partition_stream_name(store.clone(), partition)?;
+            let deleted_count = store
+                .redis_conn
+                .stream_delete_entries(&get_dead_letter_stream_name(&stream_name), entry_id)
+                .await
+                .map_err(DrainerError::from)
+                .into_report()?;
+            println!("Discarded {deleted_count} entries from {stream_name}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends the dead-lettered entries in the given range back to the drainer stream, without the
+/// fields added when they were dead-lettered, and removes them from the dead letter stream.
+/// Returns the number of entries replayed.
+async fn replay_entries(
+    store: &services::Store,
+    stream_name: &str,
+    start: &str,
+    end: &str,
+    count: u64,
+) -> errors::DrainerResult<usize> {
+    let dead_letter_stream_name = get_dead_letter_stream_name(stream_name);
+    let entries = store
+        .redis_conn
+        .stream_read_range(&dead_letter_stream_name, start, end, Some(count))
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    for (entry_id, entry) in &entries {
+        let fields = entry
+            .iter()
+            .filter(|(key, _)| !key.starts_with(DEAD_LETTER_FIELD_PREFIX))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<Vec<_>>();
+
+        store
+            .redis_conn
+            .stream_append_entry(stream_name, &redis::RedisEntryId::AutoGeneratedID, fields)
+            .await
+            .map_err(DrainerError::from)
+            .into_report()?;
+        store
+            .redis_conn
+            .stream_delete_entries(&dead_letter_stream_name, entry_id.as_str())
+            .await
+            .map_err(DrainerError::from)
+            .into_report()?;
+        logger::info!(%entry_id, %stream_name, "Replayed dead-lettered entry");
+    }
+
+    Ok(entries.len())
+}
+
+fn get_partition_stream_name(
+    store: Arc<services::Store>,
+    partition: u8,
+) -> errors::DrainerResult<String> {
+    if partition >= store.config.drainer_num_partitions {
+        return Err(DrainerError::InvalidArgument(format!(
+            "partition must be less than the number of partitions ({})",
+            store.config.drainer_num_partitions
+        )))
+        .into_report();
+    }
+
+    Ok(utils::get_drainer_stream_name(store, partition))
+}
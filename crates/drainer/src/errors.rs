@@ -1,3 +1,4 @@
+use diesel_models::errors::DatabaseError;
 use redis_interface as redis;
 use thiserror::Error;
 
@@ -7,6 +8,12 @@ pub enum DrainerError {
     ConfigParsingError(String),
     #[error("Error during redis operation : {0:?}")]
     RedisError(error_stack::Report<redis::errors::RedisError>),
+    #[error("Error during database operation : {0:?}")]
+    DatabaseError(error_stack::Report<DatabaseError>),
+    #[error("Error in parsing stream entry : {0}")]
+    ParsingError(String),
+    #[error("Invalid command line argument : {0}")]
+    InvalidArgument(String),
     #[error("Application configuration error: {0}")]
     ConfigurationError(config::ConfigError),
     #[error("Error while configuring signals: {0}")]
@@ -17,6 +24,19 @@ pub enum DrainerError {
 
 pub type DrainerResult<T> = error_stack::Result<T, DrainerError>;
 
+impl DrainerError {
+    /// Whether the error may not recur if the failed operation is retried
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::DatabaseError(error) => matches!(
+                error.current_context(),
+                DatabaseError::DatabaseConnectionError | DatabaseError::Others
+            ),
+            _ => false,
+        }
+    }
+}
+
 impl From<config::ConfigError> for DrainerError {
     fn from(err: config::ConfigError) -> Self {
         Self::ConfigurationError(err)
@@ -28,3 +48,9 @@ impl From<error_stack::Report<redis::errors::RedisError>> for DrainerError {
         Self::RedisError(err)
     }
 }
+
+impl From<error_stack::Report<DatabaseError>> for DrainerError {
+    fn from(err: error_stack::Report<DatabaseError>) -> Self {
+        Self::DatabaseError(err)
+    }
+}
@@ -1,14 +1,18 @@
 mod connection;
+pub mod dead_letter;
 pub mod errors;
 pub mod logger;
 pub(crate) mod metrics;
 pub mod services;
 pub mod settings;
 mod utils;
-use std::sync::{atomic, Arc};
+use std::{
+    collections::HashMap,
+    sync::{atomic, Arc},
+};
 
 use common_utils::signals::get_allowed_signals;
-use diesel_models::kv;
+use diesel_models::{address::Address, customers::Customer, kv, mandate::Mandate};
 use error_stack::{IntoReport, ResultExt};
 use tokio::sync::mpsc;
 
@@ -121,7 +125,7 @@ async fn drainer(
         utils::read_from_stream(stream_name, max_read_count, store.redis_conn.as_ref()).await?; // this returns the error.
 
     // parse_stream_entries returns error if no entries is found, handle it
-    let (entries, last_entry_id) = utils::parse_stream_entries(&stream_read, stream_name)?;
+    let (entries, _last_entry_id) = utils::parse_stream_entries(&stream_read, stream_name)?;
     let read_count = entries.len();
 
     metrics::JOBS_PICKED_PER_STREAM.add(
@@ -133,113 +137,272 @@ async fn drainer(
         }],
     );
 
-    // TODO: Handle errors when deserialization fails and when DB error occurs
-    for entry in entries {
-        let typed_sql = entry.1.get("typed_sql").map_or(String::new(), Clone::clone);
-        let result = serde_json::from_str::<kv::DBOperation>(&typed_sql);
-        let db_op = match result {
-            Ok(f) => f,
-            Err(_err) => continue, // TODO: handle error
-        };
-
-        let conn = pg_connection(&store.master_pool).await;
-        let insert_op = "insert";
-        let update_op = "update";
-        let payment_intent = "payment_intent";
-        let payment_attempt = "payment_attempt";
-        let refund = "refund";
-        match db_op {
-            // TODO: Handle errors
-            kv::DBOperation::Insert { insertable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match insertable {
-                        kv::Insertable::PaymentIntent(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Insertable::PaymentAttempt(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Insertable::Refund(a) => {
-                            macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: insert_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Update { updatable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match updatable {
-                        kv::Updateable::PaymentIntentUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Updateable::PaymentAttemptUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update_with_attempt_id(&conn, a.update_data).await,
-                                update_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Updateable::RefundUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                refund
-                            )
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: update_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Delete => {
-                // [#224]: Implement this
-                logger::error!("Not implemented!");
+    // Entries which cannot be drained are moved to the dead letter stream, so that they are not
+    // lost when the drained entries are trimmed from the stream
+    let mut drained_count = 0;
+    let mut last_drained_entry_id = None;
+    for (entry_id, entry) in entries {
+        if let Err(error) = drain_entry(&store, entry).await {
+            logger::error!(?error, %entry_id, "Moving entry to the dead letter stream");
+            if let Err(error) = dead_letter::move_to_dead_letter_stream(
+                stream_name,
+                entry_id,
+                entry,
+                &error,
+                &store.redis_conn,
+            )
+            .await
+            {
+                // The entry and the ones after it are retained in the stream, to be drained again
+                logger::error!(?error, %entry_id, "Failed to move entry to the dead letter stream");
+                break;
             }
-        };
+        }
+        drained_count += 1;
+        last_drained_entry_id = Some(entry_id);
     }
 
-    let entries_trimmed =
-        utils::trim_from_stream(stream_name, last_entry_id.as_str(), &store.redis_conn).await?;
+    let last_drained_entry_id = match last_drained_entry_id {
+        Some(entry_id) => entry_id,
+        None => return Ok(()),
+    };
 
-    if read_count != entries_trimmed {
+    let entries_trimmed = utils::trim_from_stream(
+        stream_name,
+        last_drained_entry_id.as_str(),
+        &store.redis_conn,
+    )
+    .await?;
+
+    if drained_count != entries_trimmed {
         logger::error!(
             read_entries = %read_count,
+            drained_entries = %drained_count,
             trimmed_entries = %entries_trimmed,
             ?entries,
-            "Assertion Failed no. of entries read from the stream doesn't match no. of entries trimmed"
+            "Assertion Failed no. of entries drained from the stream doesn't match no. of entries trimmed"
         );
     }
 
     Ok(())
 }
 
+/// Executes the database operation of a stream entry, retrying it with a linear backoff while it
+/// fails with a transient error, up to the configured number of retries
+async fn drain_entry(store: &Store, entry: &HashMap<String, String>) -> errors::DrainerResult<()> {
+    let typed_sql = entry
+        .get("typed_sql")
+        .ok_or_else(|| errors::DrainerError::ParsingError("typed_sql field not found".into()))
+        .into_report()?;
+
+    let mut retries = 0;
+    loop {
+        let db_op = serde_json::from_str::<kv::DBOperation>(typed_sql)
+            .map_err(|error| errors::DrainerError::ParsingError(error.to_string()))
+            .into_report()?;
+
+        match execute_db_operation(store, db_op).await {
+            Err(error)
+                if error.current_context().is_transient() && retries < store.config.max_retries =>
+            {
+                retries += 1;
+                logger::warn!(?error, retries, "Retrying database operation");
+                metrics::QUERY_EXECUTION_RETRIES.add(&metrics::CONTEXT, 1, &[]);
+                tokio::time::sleep(std::time::Duration::from_millis(
+                    u64::from(store.config.retry_interval) * u64::from(retries),
+                ))
+                .await;
+            }
+            result => return result,
+        }
+    }
+}
+
+async fn execute_db_operation(store: &Store, db_op: kv::DBOperation) -> errors::DrainerResult<()> {
+    let conn = pg_connection(&store.master_pool).await?;
+    let insert_op = "insert";
+    let update_op = "update";
+    let delete_op = "delete";
+    let payment_intent = "payment_intent";
+    let payment_attempt = "payment_attempt";
+    let refund = "refund";
+    let address = "address";
+    let customer = "customer";
+    let connector_response = "connector_response";
+    let capture = "capture";
+    let reverse_lookup = "reverse_lookup";
+    let mandate = "mandate";
+    let result = match db_op {
+        kv::DBOperation::Insert { insertable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match insertable {
+                    kv::Insertable::PaymentIntent(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, payment_intent)
+                    }
+                    kv::Insertable::PaymentAttempt(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, payment_attempt)
+                    }
+                    kv::Insertable::Refund(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
+                    }
+                    kv::Insertable::Address(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, address)
+                    }
+                    kv::Insertable::Customer(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, customer)
+                    }
+                    kv::Insertable::ConnectorResponse(a) => {
+                        macro_util::handle_resp!(
+                            a.insert(&conn).await,
+                            insert_op,
+                            connector_response
+                        )
+                    }
+                    kv::Insertable::Capture(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, capture)
+                    }
+                    kv::Insertable::ReverseLookUp(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, reverse_lookup)
+                    }
+                    kv::Insertable::Mandate(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, mandate)
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: insert_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Update { updatable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match updatable {
+                    kv::Updateable::PaymentIntentUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            payment_intent
+                        )
+                    }
+                    kv::Updateable::PaymentAttemptUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update_with_attempt_id(&conn, a.update_data).await,
+                            update_op,
+                            payment_attempt
+                        )
+                    }
+                    kv::Updateable::RefundUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            refund
+                        )
+                    }
+                    kv::Updateable::AddressUpdate(a) => {
+                        macro_util::handle_resp!(
+                            Address::update_by_address_id(&conn, a.orig.address_id, a.update_data)
+                                .await,
+                            update_op,
+                            address
+                        )
+                    }
+                    kv::Updateable::CustomerUpdate(a) => {
+                        macro_util::handle_resp!(
+                            Customer::update_by_customer_id_merchant_id(
+                                &conn,
+                                a.orig.customer_id,
+                                a.orig.merchant_id,
+                                a.update_data
+                            )
+                            .await,
+                            update_op,
+                            customer
+                        )
+                    }
+                    kv::Updateable::ConnectorResponseUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig
+                                .update_by_payment_id_merchant_id_attempt_id(&conn, a.update_data)
+                                .await,
+                            update_op,
+                            connector_response
+                        )
+                    }
+                    kv::Updateable::CaptureUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update_with_capture_id(&conn, a.update_data).await,
+                            update_op,
+                            capture
+                        )
+                    }
+                    kv::Updateable::MandateUpdate(a) => {
+                        macro_util::handle_resp!(
+                            Mandate::update_by_merchant_id_mandate_id(
+                                &conn,
+                                &a.orig.merchant_id,
+                                &a.orig.mandate_id,
+                                a.update_data
+                            )
+                            .await,
+                            update_op,
+                            mandate
+                        )
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: update_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Delete { deletable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match deletable {
+                    kv::Deletable::Customer {
+                        customer_id,
+                        merchant_id,
+                    } => {
+                        macro_util::handle_resp!(
+                            Customer::delete_by_customer_id_merchant_id(
+                                &conn,
+                                &customer_id,
+                                &merchant_id
+                            )
+                            .await,
+                            delete_op,
+                            customer
+                        )
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: delete_op.into(),
+                }],
+            );
+            result
+        }
+    };
+
+    result.map_err(errors::DrainerError::from).into_report()
+}
+
 mod macro_util {
 
     macro_rules! handle_resp {
@@ -253,6 +416,7 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Ok(())
                 }
                 Err(err) => {
                     logger::error!(operation = %$op_type, table = %$table, ?err);
@@ -262,6 +426,7 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Err(err)
                 }
             }
         };
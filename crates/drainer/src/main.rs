@@ -1,4 +1,6 @@
-use drainer::{errors::DrainerResult, logger::logger, services, settings, start_drainer};
+use drainer::{
+    dead_letter, errors::DrainerResult, logger::logger, services, settings, start_drainer,
+};
 
 #[tokio::main]
 async fn main() -> DrainerResult<()> {
@@ -26,6 +28,10 @@ async fn main() -> DrainerResult<()> {
         [router_env::service_name!()],
     );
 
+    if let Some(settings::DrainerCommand::DeadLetter(command)) = cmd_line.command {
+        return dead_letter::run_command(store, command).await;
+    }
+
     logger::info!("Drainer started [{:?}] [{:?}]", conf.drainer, conf.log);
 
     start_drainer(
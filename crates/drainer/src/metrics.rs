@@ -11,6 +11,8 @@ counter_metric!(ERRORS_WHILE_QUERY_EXECUTION, DRAINER_METER);
 counter_metric!(SUCCESSFUL_QUERY_EXECUTION, DRAINER_METER);
 counter_metric!(SHUTDOWN_SIGNAL_RECEIVED, DRAINER_METER);
 counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
+counter_metric!(QUERY_EXECUTION_RETRIES, DRAINER_METER);
+counter_metric!(ENTRIES_DEAD_LETTERED, DRAINER_METER);
 
 histogram_metric!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
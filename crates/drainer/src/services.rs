@@ -13,22 +13,29 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub max_retries: u8,
+    pub retry_interval: u32,
 }
 
 impl Store {
+    #[allow(clippy::expect_used)]
     pub async fn new(config: &crate::settings::Settings, test_transaction: bool) -> Self {
         Self {
             master_pool: diesel_make_pg_pool(
                 &config.master_database,
                 test_transaction,
                 #[cfg(feature = "kms")]
-                external_services::kms::get_kms_client(&config.kms).await,
+                external_services::kms::get_kms_client(&config.kms)
+                    .await
+                    .expect("Failed to initialize the KMS client"),
             )
             .await,
             redis_conn: Arc::new(crate::connection::redis_connection(config).await),
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                max_retries: config.drainer.max_retries,
+                retry_interval: config.drainer.retry_interval,
             },
         }
     }
@@ -23,6 +23,48 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<DrainerCommand>,
+}
+
+#[derive(clap::Subcommand)]
+pub enum DrainerCommand {
+    /// Inspect, replay or discard the entries which could not be drained from a partition.
+    #[command(subcommand)]
+    DeadLetter(DeadLetterCommand),
+}
+
+#[derive(clap::Subcommand)]
+pub enum DeadLetterCommand {
+    /// List the dead-lettered entries of a partition, oldest first.
+    List {
+        /// Index of the partition of the drainer stream.
+        #[arg(short, long)]
+        partition: u8,
+        /// Maximum number of entries to list.
+        #[arg(short, long, default_value_t = 10)]
+        count: u64,
+    },
+    /// Append dead-lettered entries back to the drainer stream of their partition, for them to be
+    /// drained again.
+    Replay {
+        /// Index of the partition of the drainer stream.
+        #[arg(short, long)]
+        partition: u8,
+        /// ID of the entry to replay. All entries of the partition are replayed if not specified.
+        #[arg(short, long)]
+        entry_id: Option<String>,
+    },
+    /// Remove a dead-lettered entry from a partition without draining it.
+    Discard {
+        /// Index of the partition of the drainer stream.
+        #[arg(short, long)]
+        partition: u8,
+        /// ID of the entry to discard.
+        #[arg(short, long)]
+        entry_id: String,
+    },
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -56,6 +98,8 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub max_retries: u8,
+    pub retry_interval: u32, // in milliseconds
 }
 
 impl Default for Database {
@@ -80,6 +124,8 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 500,      // in milliseconds
+            max_retries: 3,
+            retry_interval: 100, // in milliseconds
         }
     }
 }
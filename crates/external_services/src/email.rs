@@ -1,17 +1,21 @@
-//! Interactions with the AWS SES SDK
-
-use aws_config::meta::region::RegionProviderChain;
-use aws_sdk_sesv2::{
-    config::Region,
-    operation::send_email::SendEmailError,
-    types::{Body, Content, Destination, EmailContent, Message},
-    Client,
-};
+//! Interactions with the email service used to send emails.
+//!
+//! The email backend is selected using [`EmailSettings::active_email_client`], and can be AWS SES,
+//! an SMTP server, or a local directory the rendered emails are written to.
+
+use std::sync::Arc;
+
 use common_utils::{errors::CustomResult, pii};
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
 use serde::Deserialize;
 
+pub mod file;
+pub mod ses;
+pub mod smtp;
+
+pub use self::{file::FileSink, ses::AwsSes, smtp::SmtpServer};
+
 /// Custom Result type alias for Email operations.
 pub type EmailResult<T> = CustomResult<T, EmailError>;
 
@@ -25,10 +29,88 @@ pub trait EmailClient: Sync + Send + dyn_clone::DynClone {
         subject: String,
         body: String,
     ) -> EmailResult<()>;
+
+    /// Renders the provided email template and sends the resulting email.
+    async fn compose_and_send_email(
+        &self,
+        email_data: Box<dyn EmailData + Send>,
+    ) -> EmailResult<()> {
+        let EmailContents {
+            subject,
+            body,
+            recipient,
+        } = email_data.get_email_data()?;
+
+        self.send_email(recipient, subject, body).await
+    }
 }
 
 dyn_clone::clone_trait_object!(EmailClient);
 
+/// The contents of a rendered email.
+#[derive(Debug, Clone)]
+pub struct EmailContents {
+    /// The subject of the email.
+    pub subject: String,
+
+    /// The plaintext body of the email.
+    pub body: String,
+
+    /// The recipient of the email.
+    pub recipient: pii::Email,
+}
+
+/// A trait implemented by email templates, which renders the contents of an email.
+pub trait EmailData {
+    /// Renders the subject and body of the email for its recipient.
+    fn get_email_data(&self) -> EmailResult<EmailContents>;
+}
+
+/// Renders a template, replacing each `{{ name }}` placeholder with the value of the variable
+/// with the same name. Fails if the template references a variable that was not provided.
+pub fn render_template(template: &str, variables: &[(&str, &str)]) -> EmailResult<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut remaining = template;
+
+    while let Some(start) = remaining.find("{{") {
+        rendered.push_str(&remaining[..start]);
+        let after_start = &remaining[start + 2..];
+
+        let end = after_start
+            .find("}}")
+            .ok_or(EmailError::TemplateRenderingFailure)
+            .into_report()
+            .attach_printable("Unterminated placeholder in email template")?;
+        let name = after_start[..end].trim();
+
+        let value = variables
+            .iter()
+            .find_map(|(key, value)| (*key == name).then_some(*value))
+            .ok_or(EmailError::TemplateRenderingFailure)
+            .into_report()
+            .attach_printable_lazy(|| format!("Missing value for template variable `{name}`"))?;
+        rendered.push_str(value);
+
+        remaining = &after_start[end + 2..];
+    }
+    rendered.push_str(remaining);
+
+    Ok(rendered)
+}
+
+/// The email backends that can be used to send emails.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailClientBackend {
+    /// AWS SES
+    #[default]
+    Ses,
+    /// An SMTP server
+    Smtp,
+    /// A local directory the emails are written to, for development and testing
+    File,
+}
+
 /// Struct that contains the settings required to construct an EmailClient.
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct EmailSettings {
@@ -40,66 +122,73 @@ pub struct EmailSettings {
 
     /// Base-url used when adding links that should redirect to self
     pub base_url: String,
-}
 
-/// Client for AWS SES operation
-#[derive(Debug, Clone)]
-pub struct AwsSes {
-    ses_client: Client,
-    from_email: String,
+    /// The email backend used to send emails.
+    #[serde(default)]
+    pub active_email_client: EmailClientBackend,
+
+    /// Configuration of the SMTP backend.
+    #[serde(default)]
+    pub smtp: smtp::SmtpServerConfig,
+
+    /// Configuration of the file sink backend.
+    #[serde(default)]
+    pub file: file::FileSinkConfig,
 }
 
-impl AwsSes {
-    /// Constructs a new AwsSes client
-    pub async fn new(conf: &EmailSettings) -> Self {
-        let region_provider = RegionProviderChain::first_try(Region::new(conf.aws_region.clone()));
-        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+impl EmailSettings {
+    /// Verifies that the email client configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.from_email.is_default_or_empty(), || {
+            Err("Sender email must not be empty")
+        })?;
 
-        Self {
-            ses_client: Client::new(&sdk_config),
-            from_email: conf.from_email.clone(),
+        match self.active_email_client {
+            EmailClientBackend::Ses => Ok(()),
+            EmailClientBackend::Smtp => self.smtp.validate(),
+            EmailClientBackend::File => self.file.validate(),
         }
     }
 }
 
-#[async_trait::async_trait]
-impl EmailClient for AwsSes {
-    async fn send_email(
-        &self,
-        recipient: pii::Email,
-        subject: String,
-        body: String,
-    ) -> EmailResult<()> {
-        self.ses_client
-            .send_email()
-            .from_email_address(self.from_email.to_owned())
-            .destination(
-                Destination::builder()
-                    .to_addresses(recipient.peek())
-                    .build(),
-            )
-            .content(
-                EmailContent::builder()
-                    .simple(
-                        Message::builder()
-                            .subject(Content::builder().data(subject).build())
-                            .body(
-                                Body::builder()
-                                    .text(Content::builder().data(body).charset("UTF-8").build())
-                                    .build(),
-                            )
-                            .build(),
-                    )
-                    .build(),
-            )
-            .send()
-            .await
-            .map_err(AwsSesError::SendingFailure)
-            .into_report()
-            .change_context(EmailError::EmailSendingFailure)?;
+/// Constructs the email client for the configured email backend.
+pub async fn create_email_client(conf: &EmailSettings) -> EmailResult<Arc<dyn EmailClient>> {
+    Ok(match conf.active_email_client {
+        EmailClientBackend::Ses => Arc::new(AwsSes::new(conf).await),
+        EmailClientBackend::Smtp => Arc::new(SmtpServer::new(&conf.smtp, conf.from_email.clone())?),
+        EmailClientBackend::File => Arc::new(FileSink::new(&conf.file, conf.from_email.clone())?),
+    })
+}
 
-        Ok(())
-    }
+/// Builds a plaintext email message, for the backends which construct the message themselves.
+fn build_message(
+    from_email: &str,
+    recipient: &pii::Email,
+    subject: String,
+    body: String,
+) -> EmailResult<lettre::Message> {
+    let from = from_email
+        .parse::<lettre::message::Mailbox>()
+        .into_report()
+        .change_context(EmailError::InvalidEmailAddress)
+        .attach_printable("Invalid sender email address")?;
+    let to = recipient
+        .peek()
+        .parse::<lettre::message::Mailbox>()
+        .into_report()
+        .change_context(EmailError::InvalidEmailAddress)
+        .attach_printable("Invalid recipient email address")?;
+
+    lettre::Message::builder()
+        .from(from)
+        .to(to)
+        .subject(subject)
+        .header(lettre::message::header::ContentType::TEXT_PLAIN)
+        .body(body)
+        .into_report()
+        .change_context(EmailError::MessageBuildingFailure)
 }
 
 #[allow(missing_docs)]
@@ -116,12 +205,13 @@ pub enum EmailError {
     TokenGenerationFailure,
     #[error("Feature not implemented")]
     NotImplemented,
-}
-
-/// Errors that could occur during SES operations.
-#[derive(Debug, thiserror::Error)]
-pub enum AwsSesError {
-    /// An error occurred in the SDK while sending email.
-    #[error("Failed to Send Email {0:?}")]
-    SendingFailure(aws_smithy_client::SdkError<SendEmailError>),
+    /// An error occurred when rendering an email template.
+    #[error("Failed to render email template")]
+    TemplateRenderingFailure,
+    /// The sender or recipient email address is invalid.
+    #[error("Invalid email address")]
+    InvalidEmailAddress,
+    /// An error occurred when building the email message.
+    #[error("Failed to build email message")]
+    MessageBuildingFailure,
 }
@@ -0,0 +1,72 @@
+//! Email client writing emails to a local directory, for development and testing
+
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use lettre::{AsyncFileTransport, AsyncTransport, Tokio1Executor};
+
+use super::{EmailClient, EmailError, EmailResult};
+
+/// Configuration parameters required for constructing a [`FileSink`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct FileSinkConfig {
+    /// The directory the emails are written to, one `.eml` file per email. The directory is
+    /// created if it does not exist.
+    pub directory: String,
+}
+
+impl FileSinkConfig {
+    /// Verifies that the [`FileSink`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.directory.is_default_or_empty(), || {
+            Err("Email file sink directory must not be empty")
+        })
+    }
+}
+
+/// Client which writes the rendered emails to disk instead of sending them
+#[derive(Clone, Debug)]
+pub struct FileSink {
+    transport: AsyncFileTransport<Tokio1Executor>,
+    from_email: String,
+}
+
+impl FileSink {
+    /// Constructs a new file sink, creating the configured directory if required.
+    pub fn new(conf: &FileSinkConfig, from_email: String) -> EmailResult<Self> {
+        std::fs::create_dir_all(&conf.directory)
+            .into_report()
+            .change_context(EmailError::ClientBuildingFailure)
+            .attach_printable_lazy(|| {
+                format!("Failed to create email directory {}", conf.directory)
+            })?;
+
+        Ok(Self {
+            transport: AsyncFileTransport::<Tokio1Executor>::new(&conf.directory),
+            from_email,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for FileSink {
+    async fn send_email(
+        &self,
+        recipient: pii::Email,
+        subject: String,
+        body: String,
+    ) -> EmailResult<()> {
+        let message = super::build_message(&self.from_email, &recipient, subject, body)?;
+
+        self.transport
+            .send(message)
+            .await
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)
+            .attach_printable("Failed to write email to disk")?;
+
+        Ok(())
+    }
+}
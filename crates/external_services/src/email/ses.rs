@@ -0,0 +1,82 @@
+//! Interactions with the AWS SES SDK
+
+use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_sesv2::{
+    config::Region,
+    operation::send_email::SendEmailError,
+    types::{Body, Content, Destination, EmailContent, Message},
+    Client,
+};
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+
+use super::{EmailClient, EmailError, EmailResult, EmailSettings};
+
+/// Client for AWS SES operation
+#[derive(Debug, Clone)]
+pub struct AwsSes {
+    ses_client: Client,
+    from_email: String,
+}
+
+impl AwsSes {
+    /// Constructs a new AwsSes client
+    pub async fn new(conf: &EmailSettings) -> Self {
+        let region_provider = RegionProviderChain::first_try(Region::new(conf.aws_region.clone()));
+        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+
+        Self {
+            ses_client: Client::new(&sdk_config),
+            from_email: conf.from_email.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for AwsSes {
+    async fn send_email(
+        &self,
+        recipient: pii::Email,
+        subject: String,
+        body: String,
+    ) -> EmailResult<()> {
+        self.ses_client
+            .send_email()
+            .from_email_address(self.from_email.to_owned())
+            .destination(
+                Destination::builder()
+                    .to_addresses(recipient.peek())
+                    .build(),
+            )
+            .content(
+                EmailContent::builder()
+                    .simple(
+                        Message::builder()
+                            .subject(Content::builder().data(subject).build())
+                            .body(
+                                Body::builder()
+                                    .text(Content::builder().data(body).charset("UTF-8").build())
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(AwsSesError::SendingFailure)
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)?;
+
+        Ok(())
+    }
+}
+
+/// Errors that could occur during SES operations.
+#[derive(Debug, thiserror::Error)]
+pub enum AwsSesError {
+    /// An error occurred in the SDK while sending email.
+    #[error("Failed to Send Email {0:?}")]
+    SendingFailure(aws_smithy_client::SdkError<SendEmailError>),
+}
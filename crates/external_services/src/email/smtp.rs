@@ -0,0 +1,154 @@
+//! Email client sending emails through an SMTP server, for deployments which cannot use AWS SES
+
+use std::time::Duration;
+
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use lettre::{
+    transport::smtp::authentication::Credentials, AsyncSmtpTransport, AsyncTransport,
+    Tokio1Executor,
+};
+use masking::{PeekInterface, Secret};
+
+use super::{EmailClient, EmailError, EmailResult};
+
+/// The kind of connection established with the SMTP server.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpConnection {
+    /// Upgrade a plaintext connection to TLS using the `STARTTLS` command.
+    #[default]
+    StartTls,
+    /// Connect using implicit TLS.
+    Tls,
+    /// Connect without any encryption. Must only be used with local relays.
+    Plaintext,
+}
+
+/// Configuration parameters required for constructing a [`SmtpServer`].
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct SmtpServerConfig {
+    /// The hostname of the SMTP server.
+    pub host: String,
+
+    /// The port of the SMTP server.
+    pub port: u16,
+
+    /// The username used to authenticate with the SMTP server. Authentication is skipped if
+    /// empty.
+    pub username: String,
+
+    /// The password used to authenticate with the SMTP server.
+    pub password: Secret<String>,
+
+    /// The kind of connection established with the SMTP server.
+    pub connection: SmtpConnection,
+
+    /// Timeout for SMTP operations, in seconds.
+    pub timeout: u64,
+}
+
+impl Default for SmtpServerConfig {
+    fn default() -> Self {
+        Self {
+            host: String::new(),
+            port: 587,
+            username: String::new(),
+            password: Secret::default(),
+            connection: SmtpConnection::default(),
+            timeout: 30,
+        }
+    }
+}
+
+impl SmtpServerConfig {
+    /// Verifies that the [`SmtpServer`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.host.is_default_or_empty(), || {
+            Err("SMTP server host must not be empty")
+        })?;
+
+        when(self.port == 0, || Err("SMTP server port must not be zero"))?;
+
+        when(
+            !self.username.is_empty() && self.password.peek().is_default_or_empty(),
+            || Err("SMTP server password must not be empty when a username is provided"),
+        )
+    }
+}
+
+/// Client for sending emails through an SMTP server
+#[derive(Clone)]
+pub struct SmtpServer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from_email: String,
+}
+
+impl std::fmt::Debug for SmtpServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmtpServer")
+            .field("from_email", &self.from_email)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SmtpServer {
+    /// Constructs a new SMTP client. No connection is established until an email is sent.
+    pub fn new(conf: &SmtpServerConfig, from_email: String) -> EmailResult<Self> {
+        let builder = match conf.connection {
+            SmtpConnection::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&conf.host)
+                    .into_report()
+                    .change_context(EmailError::ClientBuildingFailure)
+                    .attach_printable("Failed to set up STARTTLS parameters")?
+            }
+            SmtpConnection::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&conf.host)
+                .into_report()
+                .change_context(EmailError::ClientBuildingFailure)
+                .attach_printable("Failed to set up TLS parameters")?,
+            SmtpConnection::Plaintext => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&conf.host)
+            }
+        }
+        .port(conf.port)
+        .timeout(Some(Duration::from_secs(conf.timeout)));
+
+        let builder = if conf.username.is_empty() {
+            builder
+        } else {
+            builder.credentials(Credentials::new(
+                conf.username.clone(),
+                conf.password.peek().clone(),
+            ))
+        };
+
+        Ok(Self {
+            transport: builder.build(),
+            from_email,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for SmtpServer {
+    async fn send_email(
+        &self,
+        recipient: pii::Email,
+        subject: String,
+        body: String,
+    ) -> EmailResult<()> {
+        let message = super::build_message(&self.from_email, &recipient, subject, body)?;
+
+        self.transport
+            .send(message)
+            .await
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)
+            .attach_printable("Failed to send email through the SMTP server")?;
+
+        Ok(())
+    }
+}
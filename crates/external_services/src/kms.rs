@@ -1,114 +1,155 @@
-//! Interactions with the AWS KMS SDK
+//! Interactions with the key management service used to encrypt or decrypt data.
+//!
+//! The key management backend is selected using [`KmsConfig::backend`], and can be AWS KMS,
+//! the transit secrets engine of HashiCorp Vault, GCP Cloud KMS or a key read from a local file.
 
-use std::time::Instant;
-
-use aws_config::meta::region::RegionProviderChain;
-use aws_sdk_kms::{config::Region, primitives::Blob, Client};
 use base64::Engine;
 use common_utils::errors::CustomResult;
 use error_stack::{IntoReport, ResultExt};
 use masking::{PeekInterface, Secret};
-use router_env::logger;
-/// decrypting data using the AWS KMS SDK.
+
+pub mod aws;
+/// decrypting data using the configured key management backend.
 pub mod decrypt;
+pub mod gcp;
+pub mod hashicorp_vault;
+pub mod local;
 
-use crate::{consts, metrics};
+use crate::consts;
 
 static KMS_CLIENT: tokio::sync::OnceCell<KmsClient> = tokio::sync::OnceCell::const_new();
 
 /// Returns a shared KMS client, or initializes a new one if not previously initialized.
 #[inline]
-pub async fn get_kms_client(config: &KmsConfig) -> &'static KmsClient {
-    KMS_CLIENT.get_or_init(|| KmsClient::new(config)).await
+pub async fn get_kms_client(config: &KmsConfig) -> CustomResult<&'static KmsClient, KmsError> {
+    KMS_CLIENT.get_or_try_init(|| KmsClient::new(config)).await
+}
+
+/// A key management backend, which encrypts or decrypts data with a key that does not leave the
+/// backend.
+#[async_trait::async_trait]
+pub trait KeyManager: std::fmt::Debug + Send + Sync {
+    /// Encrypts the provided plaintext, returning the ciphertext.
+    async fn encrypt(&self, plaintext: &[u8]) -> CustomResult<Vec<u8>, KmsError>;
+
+    /// Decrypts the provided ciphertext, returning the plaintext.
+    async fn decrypt(&self, ciphertext: &[u8]) -> CustomResult<Vec<u8>, KmsError>;
+}
+
+/// The key management backends that can be used to encrypt or decrypt data.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyManagerBackend {
+    /// AWS KMS
+    #[default]
+    AwsKms,
+    /// The transit secrets engine of HashiCorp Vault
+    HashicorpVault,
+    /// GCP Cloud KMS
+    GcpKms,
+    /// A key read from a local file
+    Local,
 }
 
 /// Configuration parameters required for constructing a [`KmsClient`].
 #[derive(Clone, Debug, Default, serde::Deserialize)]
 #[serde(default)]
 pub struct KmsConfig {
+    /// The key management backend used to encrypt or decrypt data.
+    pub backend: KeyManagerBackend,
+
     /// The AWS key identifier of the KMS key used to encrypt or decrypt data.
     pub key_id: String,
 
     /// The AWS region to send KMS requests to.
     pub region: String,
+
+    /// Configuration of the HashiCorp Vault backend.
+    pub hashicorp_vault: hashicorp_vault::HashicorpVaultConfig,
+
+    /// Configuration of the GCP Cloud KMS backend.
+    pub gcp_kms: gcp::GcpKmsConfig,
+
+    /// Configuration of the local key manager backend.
+    pub local: local::LocalKeyManagerConfig,
+
+    /// Whether the data keys of merchants are wrapped with the key management backend before
+    /// being stored, in addition to the master key.
+    pub wrap_merchant_keys: bool,
 }
 
-/// Client for KMS operations.
+/// Client for KMS operations, using the configured key management backend.
 #[derive(Debug)]
 pub struct KmsClient {
-    inner_client: Client,
-    key_id: String,
+    key_manager: Box<dyn KeyManager>,
 }
 
 impl KmsClient {
-    /// Constructs a new KMS client.
-    pub async fn new(config: &KmsConfig) -> Self {
-        let region_provider = RegionProviderChain::first_try(Region::new(config.region.clone()));
-        let sdk_config = aws_config::from_env().region(region_provider).load().await;
-
-        Self {
-            inner_client: Client::new(&sdk_config),
-            key_id: config.key_id.clone(),
-        }
+    /// Constructs a new KMS client for the configured key management backend.
+    pub async fn new(config: &KmsConfig) -> CustomResult<Self, KmsError> {
+        let key_manager: Box<dyn KeyManager> = match config.backend {
+            KeyManagerBackend::AwsKms => Box::new(aws::AwsKmsClient::new(config).await),
+            KeyManagerBackend::HashicorpVault => Box::new(
+                hashicorp_vault::HashicorpVaultClient::new(&config.hashicorp_vault),
+            ),
+            KeyManagerBackend::GcpKms => Box::new(gcp::GcpKmsClient::new(&config.gcp_kms)),
+            KeyManagerBackend::Local => Box::new(
+                local::LocalKeyManager::new(&config.local)
+                    .attach_printable("Failed to initialize the local key manager")?,
+            ),
+        };
+
+        Ok(Self { key_manager })
+    }
+
+    /// Returns the key management backend used by the client.
+    pub fn key_manager(&self) -> &dyn KeyManager {
+        self.key_manager.as_ref()
+    }
+
+    /// Encrypts the provided data using the configured key management backend, returning the
+    /// base64-encoded ciphertext.
+    pub async fn encrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<String, KmsError> {
+        self.key_manager
+            .encrypt(data.as_ref())
+            .await
+            .map(|ciphertext| consts::BASE64_ENGINE.encode(ciphertext))
     }
 
-    /// Decrypts the provided base64-encoded encrypted data using the AWS KMS SDK. We assume that
-    /// the SDK has the values required to interact with the AWS KMS APIs (`AWS_ACCESS_KEY_ID` and
-    /// `AWS_SECRET_ACCESS_KEY`) either set in environment variables, or that the SDK is running in
-    /// a machine that is able to assume an IAM role.
+    /// Decrypts the provided base64-encoded encrypted data using the configured key management
+    /// backend.
     pub async fn decrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<String, KmsError> {
-        let start = Instant::now();
         let data = consts::BASE64_ENGINE
             .decode(data)
             .into_report()
             .change_context(KmsError::Base64DecodingFailed)?;
-        let ciphertext_blob = Blob::new(data);
-
-        let decrypt_output = self
-            .inner_client
-            .decrypt()
-            .key_id(&self.key_id)
-            .ciphertext_blob(ciphertext_blob)
-            .send()
-            .await
-            .map_err(|error| {
-                // Logging using `Debug` representation of the error as the `Display`
-                // representation does not hold sufficient information.
-                logger::error!(kms_sdk_error=?error, "Failed to KMS decrypt data");
-                metrics::AWS_KMS_FAILURES.add(&metrics::CONTEXT, 1, &[]);
-                error
-            })
-            .into_report()
-            .change_context(KmsError::DecryptionFailed)?;
 
-        let output = decrypt_output
-            .plaintext
-            .ok_or(KmsError::MissingPlaintextDecryptionOutput)
+        let plaintext = self.key_manager.decrypt(&data).await?;
+        String::from_utf8(plaintext)
             .into_report()
-            .and_then(|blob| {
-                String::from_utf8(blob.into_inner())
-                    .into_report()
-                    .change_context(KmsError::Utf8DecodingFailed)
-            })?;
-
-        let time_taken = start.elapsed();
-        metrics::AWS_KMS_DECRYPT_TIME.record(&metrics::CONTEXT, time_taken.as_secs_f64(), &[]);
-
-        Ok(output)
+            .change_context(KmsError::Utf8DecodingFailed)
     }
 }
 
 /// Errors that could occur during KMS operations.
-#[derive(Debug, thiserror::Error)]
+#[derive(Clone, Copy, Debug, thiserror::Error)]
 pub enum KmsError {
     /// An error occurred when base64 decoding input data.
     #[error("Failed to base64 decode input data")]
     Base64DecodingFailed,
 
+    /// An error occurred when KMS encrypting input data.
+    #[error("Failed to KMS encrypt input data")]
+    EncryptionFailed,
+
     /// An error occurred when KMS decrypting input data.
     #[error("Failed to KMS decrypt input data")]
     DecryptionFailed,
 
+    /// The KMS encrypted output does not include a ciphertext output.
+    #[error("Missing ciphertext KMS encryption output")]
+    MissingCiphertextEncryptionOutput,
+
     /// The KMS decrypted output does not include a plaintext output.
     #[error("Missing plaintext KMS decryption output")]
     MissingPlaintextDecryptionOutput,
@@ -120,6 +161,10 @@ pub enum KmsError {
     /// The KMS client has not been initialized.
     #[error("The KMS client has not been initialized")]
     KmsClientNotInitialized,
+
+    /// An error occurred when loading the key of the local key manager.
+    #[error("Failed to load the local key manager key")]
+    KeyLoadingFailed,
 }
 
 impl KmsConfig {
@@ -127,13 +172,20 @@ impl KmsConfig {
     pub fn validate(&self) -> Result<(), &'static str> {
         use common_utils::{ext_traits::ConfigExt, fp_utils::when};
 
-        when(self.key_id.is_default_or_empty(), || {
-            Err("KMS AWS key ID must not be empty")
-        })?;
-
-        when(self.region.is_default_or_empty(), || {
-            Err("KMS AWS region must not be empty")
-        })
+        match self.backend {
+            KeyManagerBackend::AwsKms => {
+                when(self.key_id.is_default_or_empty(), || {
+                    Err("KMS AWS key ID must not be empty")
+                })?;
+
+                when(self.region.is_default_or_empty(), || {
+                    Err("KMS AWS region must not be empty")
+                })
+            }
+            KeyManagerBackend::HashicorpVault => self.hashicorp_vault.validate(),
+            KeyManagerBackend::GcpKms => self.gcp_kms.validate(),
+            KeyManagerBackend::Local => self.local.validate(),
+        }
     }
 }
 
@@ -147,3 +199,45 @@ impl common_utils::ext_traits::ConfigExt for KmsValue {
         self.0.peek().is_empty_after_trim()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[tokio::test]
+    async fn test_kms_client_local_backend() {
+        let key_file = std::env::temp_dir().join(format!("kms_client_key_{}", std::process::id()));
+        std::fs::write(&key_file, hex::encode([7_u8; 32])).unwrap();
+        let config = KmsConfig {
+            backend: KeyManagerBackend::Local,
+            local: local::LocalKeyManagerConfig {
+                key_file: key_file.to_string_lossy().into_owned(),
+            },
+            ..Default::default()
+        };
+        let kms_client = KmsClient::new(&config).await.unwrap();
+
+        let ciphertext = kms_client.encrypt("master encryption key").await.unwrap();
+        let plaintext = kms_client.decrypt(ciphertext).await.unwrap();
+        assert_eq!(plaintext, "master encryption key");
+    }
+
+    #[tokio::test]
+    async fn test_kms_client_invalid_local_key() {
+        let config = KmsConfig {
+            backend: KeyManagerBackend::Local,
+            local: local::LocalKeyManagerConfig {
+                key_file: "/nonexistent/kms_client.key".to_string(),
+            },
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            KmsClient::new(&config)
+                .await
+                .map_err(|error| *error.current_context()),
+            Err(KmsError::KeyLoadingFailed)
+        ));
+    }
+}
@@ -0,0 +1,94 @@
+//! Interactions with the AWS KMS SDK
+
+use std::time::Instant;
+
+use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_kms::{config::Region, primitives::Blob, Client};
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+
+use super::{KeyManager, KmsConfig, KmsError};
+use crate::metrics;
+
+/// Key manager backed by AWS KMS.
+#[derive(Debug)]
+pub struct AwsKmsClient {
+    inner_client: Client,
+    key_id: String,
+}
+
+impl AwsKmsClient {
+    /// Constructs a new AWS KMS client.
+    pub async fn new(config: &KmsConfig) -> Self {
+        let region_provider = RegionProviderChain::first_try(Region::new(config.region.clone()));
+        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+
+        Self {
+            inner_client: Client::new(&sdk_config),
+            key_id: config.key_id.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyManager for AwsKmsClient {
+    /// Encrypts the provided data using the AWS KMS SDK.
+    async fn encrypt(&self, plaintext: &[u8]) -> CustomResult<Vec<u8>, KmsError> {
+        let encrypt_output = self
+            .inner_client
+            .encrypt()
+            .key_id(&self.key_id)
+            .plaintext(Blob::new(plaintext))
+            .send()
+            .await
+            .map_err(|error| {
+                logger::error!(kms_sdk_error=?error, "Failed to KMS encrypt data");
+                metrics::AWS_KMS_FAILURES.add(&metrics::CONTEXT, 1, &[]);
+                error
+            })
+            .into_report()
+            .change_context(KmsError::EncryptionFailed)?;
+
+        encrypt_output
+            .ciphertext_blob
+            .map(Blob::into_inner)
+            .ok_or(KmsError::MissingCiphertextEncryptionOutput)
+            .into_report()
+    }
+
+    /// Decrypts the provided data using the AWS KMS SDK. We assume that the SDK has the values
+    /// required to interact with the AWS KMS APIs (`AWS_ACCESS_KEY_ID` and
+    /// `AWS_SECRET_ACCESS_KEY`) either set in environment variables, or that the SDK is running in
+    /// a machine that is able to assume an IAM role.
+    async fn decrypt(&self, ciphertext: &[u8]) -> CustomResult<Vec<u8>, KmsError> {
+        let start = Instant::now();
+        let decrypt_output = self
+            .inner_client
+            .decrypt()
+            .key_id(&self.key_id)
+            .ciphertext_blob(Blob::new(ciphertext))
+            .send()
+            .await
+            .map_err(|error| {
+                // Logging using `Debug` representation of the error as the `Display`
+                // representation does not hold sufficient information.
+                logger::error!(kms_sdk_error=?error, "Failed to KMS decrypt data");
+                metrics::AWS_KMS_FAILURES.add(&metrics::CONTEXT, 1, &[]);
+                error
+            })
+            .into_report()
+            .change_context(KmsError::DecryptionFailed)?;
+
+        let output = decrypt_output
+            .plaintext
+            .map(Blob::into_inner)
+            .ok_or(KmsError::MissingPlaintextDecryptionOutput)
+            .into_report()?;
+
+        let time_taken = start.elapsed();
+        metrics::AWS_KMS_DECRYPT_TIME.record(&metrics::CONTEXT, time_taken.as_secs_f64(), &[]);
+
+        Ok(output)
+    }
+}
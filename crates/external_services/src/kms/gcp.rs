@@ -0,0 +1,252 @@
+//! Interactions with the GCP Cloud KMS REST API
+
+use base64::Engine;
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+use super::{KeyManager, KmsError};
+use crate::consts;
+
+/// The metadata server endpoint returning access tokens for the service account of the instance.
+const METADATA_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+/// Configuration parameters required for constructing a [`GcpKmsClient`].
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct GcpKmsConfig {
+    /// The resource name of the crypto key used to encrypt or decrypt data, of the form
+    /// `projects/*/locations/*/keyRings/*/cryptoKeys/*`.
+    pub key_name: String,
+
+    /// The Cloud KMS API endpoint to send requests to.
+    pub endpoint: String,
+
+    /// The OAuth 2.0 access token used to authenticate with the Cloud KMS API. If not provided,
+    /// an access token for the service account of the instance is fetched from the metadata
+    /// server.
+    pub access_token: Option<Secret<String>>,
+}
+
+impl Default for GcpKmsConfig {
+    fn default() -> Self {
+        Self {
+            key_name: String::new(),
+            endpoint: "https://cloudkms.googleapis.com".to_string(),
+            access_token: None,
+        }
+    }
+}
+
+impl GcpKmsConfig {
+    /// Verifies that the [`GcpKmsClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.key_name.is_default_or_empty(), || {
+            Err("GCP KMS key name must not be empty")
+        })?;
+
+        when(self.endpoint.is_default_or_empty(), || {
+            Err("GCP KMS endpoint must not be empty")
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EncryptRequest {
+    plaintext: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DecryptRequest {
+    ciphertext: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EncryptResponse {
+    ciphertext: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct DecryptResponse {
+    plaintext: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AccessTokenResponse {
+    access_token: Secret<String>,
+}
+
+/// Key manager backed by GCP Cloud KMS.
+#[derive(Debug)]
+pub struct GcpKmsClient {
+    client: reqwest::Client,
+    config: GcpKmsConfig,
+}
+
+impl GcpKmsClient {
+    /// Constructs a new GCP Cloud KMS client.
+    pub fn new(config: &GcpKmsConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            config: config.clone(),
+        }
+    }
+
+    async fn get_access_token(&self, error: KmsError) -> CustomResult<Secret<String>, KmsError> {
+        if let Some(access_token) = &self.config.access_token {
+            return Ok(access_token.clone());
+        }
+
+        self.client
+            .get(METADATA_TOKEN_URL)
+            .header("Metadata-Flavor", "Google")
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .into_report()
+            .change_context(error)
+            .attach_printable("Failed to fetch an access token from the GCP metadata server")?
+            .json::<AccessTokenResponse>()
+            .await
+            .into_report()
+            .change_context(error)
+            .attach_printable("Failed to deserialize the GCP metadata server response")
+            .map(|response| response.access_token)
+    }
+
+    async fn send<B, R>(
+        &self,
+        operation: &str,
+        body: &B,
+        error: KmsError,
+    ) -> CustomResult<R, KmsError>
+    where
+        B: serde::Serialize + Sync,
+        R: serde::de::DeserializeOwned,
+    {
+        let access_token = self.get_access_token(error).await?;
+        let url = format!(
+            "{}/v1/{}:{operation}",
+            self.config.endpoint.trim_end_matches('/'),
+            self.config.key_name
+        );
+
+        self.client
+            .post(url)
+            .bearer_auth(access_token.peek())
+            .json(body)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|gcp_kms_error| {
+                logger::error!(?gcp_kms_error, "Failed to GCP KMS {operation} data");
+                gcp_kms_error
+            })
+            .into_report()
+            .change_context(error)?
+            .json::<R>()
+            .await
+            .into_report()
+            .change_context(error)
+            .attach_printable("Failed to deserialize the GCP KMS response")
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyManager for GcpKmsClient {
+    async fn encrypt(&self, plaintext: &[u8]) -> CustomResult<Vec<u8>, KmsError> {
+        let request = EncryptRequest {
+            plaintext: consts::BASE64_ENGINE.encode(plaintext),
+        };
+        let response = self
+            .send::<_, EncryptResponse>("encrypt", &request, KmsError::EncryptionFailed)
+            .await?;
+
+        consts::BASE64_ENGINE
+            .decode(response.ciphertext)
+            .into_report()
+            .change_context(KmsError::Base64DecodingFailed)
+    }
+
+    async fn decrypt(&self, ciphertext: &[u8]) -> CustomResult<Vec<u8>, KmsError> {
+        let request = DecryptRequest {
+            ciphertext: consts::BASE64_ENGINE.encode(ciphertext),
+        };
+        let response = self
+            .send::<_, DecryptResponse>("decrypt", &request, KmsError::DecryptionFailed)
+            .await?;
+
+        consts::BASE64_ENGINE
+            .decode(response.plaintext)
+            .into_report()
+            .change_context(KmsError::Base64DecodingFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_gcp_kms_requests() {
+        let encrypt_request = EncryptRequest {
+            plaintext: consts::BASE64_ENGINE.encode(b"merchant data key"),
+        };
+        assert_eq!(
+            serde_json::to_value(encrypt_request).unwrap(),
+            serde_json::json!({ "plaintext": "bWVyY2hhbnQgZGF0YSBrZXk=" })
+        );
+
+        let decrypt_request = DecryptRequest {
+            ciphertext: consts::BASE64_ENGINE.encode(b"ciphertext"),
+        };
+        assert_eq!(
+            serde_json::to_value(decrypt_request).unwrap(),
+            serde_json::json!({ "ciphertext": "Y2lwaGVydGV4dA==" })
+        );
+    }
+
+    #[test]
+    fn test_gcp_kms_responses() {
+        let encrypt_response: EncryptResponse = serde_json::from_str(
+            r#"{"name":"projects/p/locations/l/keyRings/r/cryptoKeys/k","ciphertext":"Y2lwaGVydGV4dA=="}"#,
+        )
+        .unwrap();
+        assert_eq!(encrypt_response.ciphertext, "Y2lwaGVydGV4dA==");
+
+        let decrypt_response: DecryptResponse =
+            serde_json::from_str(r#"{"plaintext":"bWVyY2hhbnQgZGF0YSBrZXk="}"#).unwrap();
+        assert_eq!(decrypt_response.plaintext, "bWVyY2hhbnQgZGF0YSBrZXk=");
+    }
+
+    #[tokio::test]
+    async fn test_gcp_kms_configured_access_token() {
+        let client = GcpKmsClient::new(&GcpKmsConfig {
+            access_token: Some(Secret::new("token".to_string())),
+            ..Default::default()
+        });
+
+        // A configured access token is used as is, without contacting the metadata server
+        let access_token = client
+            .get_access_token(KmsError::EncryptionFailed)
+            .await
+            .unwrap();
+        assert_eq!(access_token.peek(), "token");
+    }
+
+    #[test]
+    fn test_gcp_kms_config_validation() {
+        assert!(GcpKmsConfig::default().validate().is_err());
+
+        let config = GcpKmsConfig {
+            key_name: "projects/p/locations/l/keyRings/r/cryptoKeys/k".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,249 @@
+//! Interactions with the transit secrets engine of HashiCorp Vault
+
+use base64::Engine;
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+use super::{KeyManager, KmsError};
+use crate::consts;
+
+/// Configuration parameters required for constructing a [`HashicorpVaultClient`].
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct HashicorpVaultConfig {
+    /// The address of the Vault server, for example `https://vault.example.com:8200`.
+    pub url: String,
+
+    /// The token used to authenticate with the Vault server.
+    pub token: Secret<String>,
+
+    /// The Vault namespace the transit secrets engine is mounted in, if any.
+    pub namespace: Option<String>,
+
+    /// The path the transit secrets engine is mounted at.
+    pub mount_path: String,
+
+    /// The name of the transit key used to encrypt or decrypt data.
+    pub key_name: String,
+}
+
+impl Default for HashicorpVaultConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            token: Secret::new(String::new()),
+            namespace: None,
+            mount_path: "transit".to_string(),
+            key_name: String::new(),
+        }
+    }
+}
+
+impl HashicorpVaultConfig {
+    /// Verifies that the [`HashicorpVaultClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.url.is_default_or_empty(), || {
+            Err("HashiCorp Vault URL must not be empty")
+        })?;
+
+        when(self.token.peek().is_default_or_empty(), || {
+            Err("HashiCorp Vault token must not be empty")
+        })?;
+
+        when(self.mount_path.is_default_or_empty(), || {
+            Err("HashiCorp Vault transit mount path must not be empty")
+        })?;
+
+        when(self.key_name.is_default_or_empty(), || {
+            Err("HashiCorp Vault transit key name must not be empty")
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EncryptRequest {
+    plaintext: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DecryptRequest<'a> {
+    ciphertext: &'a str,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TransitResponse<T> {
+    data: T,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EncryptResponseData {
+    ciphertext: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct DecryptResponseData {
+    plaintext: String,
+}
+
+/// Key manager backed by the transit secrets engine of HashiCorp Vault.
+///
+/// The ciphertext produced by this backend is the UTF-8 encoded ciphertext string returned by
+/// Vault, of the form `vault:v1:...`.
+#[derive(Debug)]
+pub struct HashicorpVaultClient {
+    client: reqwest::Client,
+    config: HashicorpVaultConfig,
+}
+
+impl HashicorpVaultClient {
+    /// Constructs a new HashiCorp Vault transit client.
+    pub fn new(config: &HashicorpVaultConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            config: config.clone(),
+        }
+    }
+
+    async fn send<B, R>(
+        &self,
+        operation: &str,
+        body: &B,
+        error: KmsError,
+    ) -> CustomResult<R, KmsError>
+    where
+        B: serde::Serialize + Sync,
+        R: serde::de::DeserializeOwned,
+    {
+        let url = format!(
+            "{}/v1/{}/{operation}/{}",
+            self.config.url.trim_end_matches('/'),
+            self.config.mount_path.trim_matches('/'),
+            self.config.key_name
+        );
+        let mut request = self
+            .client
+            .post(url)
+            .header("X-Vault-Token", self.config.token.peek())
+            .json(body);
+        if let Some(namespace) = &self.config.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+
+        request
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|vault_error| {
+                logger::error!(
+                    ?vault_error,
+                    "Failed to {operation} data with HashiCorp Vault"
+                );
+                vault_error
+            })
+            .into_report()
+            .change_context(error)?
+            .json::<TransitResponse<R>>()
+            .await
+            .into_report()
+            .change_context(error)
+            .attach_printable("Failed to deserialize the HashiCorp Vault response")
+            .map(|response| response.data)
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyManager for HashicorpVaultClient {
+    async fn encrypt(&self, plaintext: &[u8]) -> CustomResult<Vec<u8>, KmsError> {
+        let request = EncryptRequest {
+            plaintext: consts::BASE64_ENGINE.encode(plaintext),
+        };
+        self.send::<_, EncryptResponseData>("encrypt", &request, KmsError::EncryptionFailed)
+            .await
+            .map(|data| data.ciphertext.into_bytes())
+    }
+
+    async fn decrypt(&self, ciphertext: &[u8]) -> CustomResult<Vec<u8>, KmsError> {
+        let ciphertext = std::str::from_utf8(ciphertext)
+            .into_report()
+            .change_context(KmsError::DecryptionFailed)
+            .attach_printable("HashiCorp Vault ciphertext is not valid UTF-8")?;
+        let data = self
+            .send::<_, DecryptResponseData>(
+                "decrypt",
+                &DecryptRequest { ciphertext },
+                KmsError::DecryptionFailed,
+            )
+            .await?;
+
+        consts::BASE64_ENGINE
+            .decode(data.plaintext)
+            .into_report()
+            .change_context(KmsError::Base64DecodingFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_hashicorp_vault_encrypt_request() {
+        let request = EncryptRequest {
+            plaintext: consts::BASE64_ENGINE.encode(b"merchant data key"),
+        };
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({ "plaintext": "bWVyY2hhbnQgZGF0YSBrZXk=" })
+        );
+    }
+
+    #[test]
+    fn test_hashicorp_vault_transit_responses() {
+        let encrypt_response: TransitResponse<EncryptResponseData> =
+            serde_json::from_str(r#"{"request_id":"1","data":{"ciphertext":"vault:v1:abc"}}"#)
+                .unwrap();
+        assert_eq!(encrypt_response.data.ciphertext, "vault:v1:abc");
+
+        let decrypt_response: TransitResponse<DecryptResponseData> = serde_json::from_str(
+            r#"{"request_id":"2","data":{"plaintext":"bWVyY2hhbnQgZGF0YSBrZXk="}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            consts::BASE64_ENGINE
+                .decode(decrypt_response.data.plaintext)
+                .unwrap(),
+            b"merchant data key"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hashicorp_vault_decrypt_invalid_ciphertext() {
+        let client = HashicorpVaultClient::new(&HashicorpVaultConfig::default());
+
+        // Vault ciphertexts are strings, so no request is sent for a ciphertext which is not UTF-8
+        let result = client.decrypt(&[0xff, 0xfe]).await;
+        assert!(matches!(
+            result.map_err(|error| *error.current_context()),
+            Err(KmsError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn test_hashicorp_vault_config_validation() {
+        assert!(HashicorpVaultConfig::default().validate().is_err());
+
+        let config = HashicorpVaultConfig {
+            url: "https://vault.example.com:8200".to_string(),
+            token: Secret::new("token".to_string()),
+            key_name: "hyperswitch".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+}
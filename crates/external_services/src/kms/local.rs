@@ -0,0 +1,145 @@
+//! Key manager using a key read from a local file, for on-premise deployments and testing
+
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    errors::CustomResult,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, StrongSecret};
+
+use super::{KeyManager, KmsError};
+
+/// Configuration parameters required for constructing a [`LocalKeyManager`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LocalKeyManagerConfig {
+    /// Path to the file containing the hex encoded 256-bit key used to encrypt or decrypt data.
+    /// The file may be a mounted secret, or a file exported from the keyring of the host.
+    pub key_file: String,
+}
+
+impl LocalKeyManagerConfig {
+    /// Verifies that the [`LocalKeyManager`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.key_file.is_default_or_empty(), || {
+            Err("Local key manager key file must not be empty")
+        })
+    }
+}
+
+/// Key manager which encrypts or decrypts data with AES-256-GCM, using a key read from a local
+/// file. It does not make any network calls.
+#[derive(Debug)]
+pub struct LocalKeyManager {
+    key: StrongSecret<Vec<u8>>,
+}
+
+impl LocalKeyManager {
+    /// Constructs a new local key manager, reading the key from the configured key file.
+    pub fn new(config: &LocalKeyManagerConfig) -> CustomResult<Self, KmsError> {
+        let contents = std::fs::read_to_string(&config.key_file)
+            .into_report()
+            .change_context(KmsError::KeyLoadingFailed)
+            .attach_printable_lazy(|| format!("Failed to read key file {}", config.key_file))?;
+        let key = hex::decode(contents.trim())
+            .into_report()
+            .change_context(KmsError::KeyLoadingFailed)
+            .attach_printable("Failed to hex decode the key")?;
+
+        if key.len() != 32 {
+            return Err(KmsError::KeyLoadingFailed)
+                .into_report()
+                .attach_printable("The key must be 256 bits long");
+        }
+
+        Ok(Self {
+            key: StrongSecret::new(key),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyManager for LocalKeyManager {
+    async fn encrypt(&self, plaintext: &[u8]) -> CustomResult<Vec<u8>, KmsError> {
+        GcmAes256
+            .encode_message(self.key.peek(), plaintext)
+            .change_context(KmsError::EncryptionFailed)
+    }
+
+    async fn decrypt(&self, ciphertext: &[u8]) -> CustomResult<Vec<u8>, KmsError> {
+        GcmAes256
+            .decode_message(self.key.peek(), ciphertext.to_vec().into())
+            .change_context(KmsError::DecryptionFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn write_key_file(name: &str, contents: &str) -> LocalKeyManagerConfig {
+        let key_file = std::env::temp_dir().join(format!("{name}_{}", std::process::id()));
+        std::fs::write(&key_file, contents).unwrap();
+
+        LocalKeyManagerConfig {
+            key_file: key_file.to_string_lossy().into_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_local_key_manager_encrypt_decrypt() {
+        let config = write_key_file("local_key_manager_roundtrip", &hex::encode([7_u8; 32]));
+        let key_manager = LocalKeyManager::new(&config).unwrap();
+
+        let ciphertext = key_manager.encrypt(b"merchant data key").await.unwrap();
+        assert_ne!(ciphertext, b"merchant data key");
+
+        let plaintext = key_manager.decrypt(&ciphertext).await.unwrap();
+        assert_eq!(plaintext, b"merchant data key");
+    }
+
+    #[tokio::test]
+    async fn test_local_key_manager_decrypt_with_other_key() {
+        let config = write_key_file("local_key_manager_key", &hex::encode([7_u8; 32]));
+        let other_config = write_key_file("local_key_manager_other_key", &hex::encode([8_u8; 32]));
+        let ciphertext = LocalKeyManager::new(&config)
+            .unwrap()
+            .encrypt(b"merchant data key")
+            .await
+            .unwrap();
+
+        let result = LocalKeyManager::new(&other_config)
+            .unwrap()
+            .decrypt(&ciphertext)
+            .await;
+        assert!(matches!(
+            result.map_err(|error| *error.current_context()),
+            Err(KmsError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn test_local_key_manager_invalid_key_length() {
+        let config = write_key_file("local_key_manager_short_key", &hex::encode([7_u8; 16]));
+
+        assert!(matches!(
+            LocalKeyManager::new(&config).map_err(|error| *error.current_context()),
+            Err(KmsError::KeyLoadingFailed)
+        ));
+    }
+
+    #[test]
+    fn test_local_key_manager_missing_key_file() {
+        let config = LocalKeyManagerConfig {
+            key_file: "/nonexistent/local_key_manager.key".to_string(),
+        };
+
+        assert!(matches!(
+            LocalKeyManager::new(&config).map_err(|error| *error.current_context()),
+            Err(KmsError::KeyLoadingFailed)
+        ));
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //!
 
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 use common_utils::{
     errors::CustomResult,
@@ -38,7 +38,7 @@ impl super::RedisConnectionPool {
     {
         self.pool
             .set(
-                key,
+                self.add_prefix(key),
                 value,
                 Some(Expiration::EX(self.config.default_ttl.into())),
                 None,
@@ -51,12 +51,17 @@ impl super::RedisConnectionPool {
 
     pub async fn set_multiple_keys_if_not_exist<V>(
         &self,
-        value: V,
+        value: &[(&str, V)],
     ) -> CustomResult<MsetnxReply, errors::RedisError>
     where
-        V: TryInto<RedisMap> + Debug + Send + Sync,
+        V: TryInto<RedisValue> + Debug + Clone + Send + Sync,
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
+        let value = value
+            .iter()
+            .map(|(key, value)| (self.add_prefix(key), value.clone()))
+            .collect::<Vec<_>>();
+
         self.pool
             .msetnx(value)
             .await
@@ -94,7 +99,7 @@ impl super::RedisConnectionPool {
 
         self.pool
             .set(
-                key,
+                self.add_prefix(key),
                 serialized.as_slice(),
                 Some(Expiration::EX(seconds)),
                 None,
@@ -111,7 +116,7 @@ impl super::RedisConnectionPool {
         V: FromRedis + Unpin + Send + 'static,
     {
         self.pool
-            .get(key)
+            .get(self.add_prefix(key))
             .await
             .into_report()
             .change_context(errors::RedisError::GetFailed)
@@ -123,7 +128,7 @@ impl super::RedisConnectionPool {
         V: Into<MultipleKeys> + Unpin + Send + 'static,
     {
         self.pool
-            .exists(key)
+            .exists(self.add_prefix(key))
             .await
             .into_report()
             .change_context(errors::RedisError::GetFailed)
@@ -150,7 +155,7 @@ impl super::RedisConnectionPool {
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn delete_key(&self, key: &str) -> CustomResult<DelReply, errors::RedisError> {
         self.pool
-            .del(key)
+            .del(self.add_prefix(key))
             .await
             .into_report()
             .change_context(errors::RedisError::DeleteFailed)
@@ -168,7 +173,13 @@ impl super::RedisConnectionPool {
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
         self.pool
-            .set(key, value, Some(Expiration::EX(seconds)), None, false)
+            .set(
+                self.add_prefix(key),
+                value,
+                Some(Expiration::EX(seconds)),
+                None,
+                false,
+            )
             .await
             .into_report()
             .change_context(errors::RedisError::SetExFailed)
@@ -187,7 +198,7 @@ impl super::RedisConnectionPool {
     {
         self.pool
             .set(
-                key,
+                self.add_prefix(key),
                 value,
                 Some(Expiration::EX(
                     seconds.unwrap_or(self.config.default_ttl.into()),
@@ -207,7 +218,7 @@ impl super::RedisConnectionPool {
         seconds: i64,
     ) -> CustomResult<(), errors::RedisError> {
         self.pool
-            .expire(key, seconds)
+            .expire(self.add_prefix(key), seconds)
             .await
             .into_report()
             .change_context(errors::RedisError::SetExpiryFailed)
@@ -220,7 +231,7 @@ impl super::RedisConnectionPool {
         timestamp: i64,
     ) -> CustomResult<(), errors::RedisError> {
         self.pool
-            .expire_at(key, timestamp)
+            .expire_at(self.add_prefix(key), timestamp)
             .await
             .into_report()
             .change_context(errors::RedisError::SetExpiryFailed)
@@ -238,7 +249,7 @@ impl super::RedisConnectionPool {
     {
         let output: Result<(), _> = self
             .pool
-            .hset(key, values)
+            .hset(self.add_prefix(key), values)
             .await
             .into_report()
             .change_context(errors::RedisError::SetHashFailed);
@@ -262,7 +273,7 @@ impl super::RedisConnectionPool {
     {
         let output: Result<HsetnxReply, _> = self
             .pool
-            .hsetnx(key, field, value)
+            .hsetnx(self.add_prefix(key), field, value)
             .await
             .into_report()
             .change_context(errors::RedisError::SetHashFieldFailed);
@@ -294,14 +305,18 @@ impl super::RedisConnectionPool {
     }
 
     #[instrument(level = "DEBUG", skip(self))]
-    pub async fn get_multiple_keys<K, V>(
+    pub async fn get_multiple_keys<V>(
         &self,
-        keys: K,
+        keys: &[&str],
     ) -> CustomResult<Vec<Option<V>>, errors::RedisError>
     where
         V: FromRedis + Unpin + Send + 'static,
-        K: Into<MultipleKeys> + Send + Debug,
     {
+        let keys = keys
+            .iter()
+            .map(|key| self.add_prefix(key))
+            .collect::<Vec<_>>();
+
         self.pool
             .mget(keys)
             .await
@@ -310,16 +325,15 @@ impl super::RedisConnectionPool {
     }
 
     #[instrument(level = "DEBUG", skip(self))]
-    pub async fn get_and_deserialize_multiple_keys<K, V>(
+    pub async fn get_and_deserialize_multiple_keys<V>(
         &self,
-        keys: K,
+        keys: &[&str],
         type_name: &'static str,
     ) -> CustomResult<Vec<Option<V>>, errors::RedisError>
     where
-        K: Into<MultipleKeys> + Send + Debug,
         V: serde::de::DeserializeOwned,
     {
-        let data = self.get_multiple_keys::<K, Vec<u8>>(keys).await?;
+        let data = self.get_multiple_keys::<Vec<u8>>(keys).await?;
         data.into_iter()
             .map(|value_bytes| {
                 value_bytes
@@ -361,7 +375,7 @@ impl super::RedisConnectionPool {
     ) -> CustomResult<Vec<String>, errors::RedisError> {
         Ok(self
             .pool
-            .hscan::<&str, &str>(key, pattern, count)
+            .hscan::<String, &str>(self.add_prefix(key), pattern, count)
             .filter_map(|value| async move {
                 match value {
                     Ok(mut v) => {
@@ -412,7 +426,7 @@ impl super::RedisConnectionPool {
         V: FromRedis + Unpin + Send + 'static,
     {
         self.pool
-            .hget(key, field)
+            .hget(self.add_prefix(key), field)
             .await
             .into_report()
             .change_context(errors::RedisError::GetHashFieldFailed)
@@ -542,6 +556,21 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::StreamReadFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &str,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<(String, HashMap<String, String>)>, errors::RedisError> {
+        self.pool
+            .xrange_values(stream, start, end, count)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_read_with_options<K, Ids>(
         &self,
@@ -663,6 +692,23 @@ impl super::RedisConnectionPool {
             .into_report()
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
     }
+
+    // the entries delivered to the group which have not been acknowledged for at least
+    // `min_idle_time` milliseconds, as (entry ID, consumer, idle time, delivery count) tuples
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn consumer_group_get_pending_entries(
+        &self,
+        stream: &str,
+        group: &str,
+        min_idle_time: u64,
+        count: u64,
+    ) -> CustomResult<Vec<(String, String, u64, u64)>, errors::RedisError> {
+        self.pool
+            .xpending(stream, group, (min_idle_time, "-", "+", count))
+            .await
+            .into_report()
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
 }
 
 #[cfg(test)]
@@ -32,12 +32,16 @@ pub use self::{commands::*, types::*};
 
 pub struct RedisConnectionPool {
     pub pool: fred::pool::RedisPool,
+    /// Prefix applied to every key read or written through this pool. Stream names are not
+    /// prefixed, since streams are consumed outside of the pool by the drainer.
+    key_prefix: String,
     config: RedisConfig,
     pub subscriber: SubscriberClient,
     pub publisher: RedisClient,
     pub is_redis_available: Arc<atomic::AtomicBool>,
 }
 
+#[derive(Clone)]
 pub struct RedisClient {
     inner: fred::prelude::RedisClient,
 }
@@ -65,6 +69,7 @@ impl RedisClient {
     }
 }
 
+#[derive(Clone)]
 pub struct SubscriberClient {
     inner: fred::clients::SubscriberClient,
 }
@@ -145,6 +150,7 @@ impl RedisConnectionPool {
 
         Ok(Self {
             pool,
+            key_prefix: String::new(),
             config,
             is_redis_available: Arc::new(atomic::AtomicBool::new(true)),
             subscriber,
@@ -152,6 +158,28 @@ impl RedisConnectionPool {
         })
     }
 
+    /// Create a pool sharing the connections of `self`, with all keys namespaced under
+    /// `key_prefix`
+    pub fn with_key_prefix(&self, key_prefix: &str) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            key_prefix: key_prefix.to_string(),
+            config: self.config.clone(),
+            subscriber: self.subscriber.clone(),
+            publisher: self.publisher.clone(),
+            is_redis_available: self.is_redis_available.clone(),
+        }
+    }
+
+    /// Namespace `key` under the key prefix of the pool
+    pub fn add_prefix(&self, key: &str) -> String {
+        if self.key_prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}:{}", self.key_prefix, key)
+        }
+    }
+
     pub async fn on_error(&self, tx: tokio::sync::oneshot::Sender<()>) {
         while let Ok(redis_error) = self.pool.on_error().recv().await {
             logger::error!(?redis_error, "Redis protocol or connection error");
@@ -168,6 +196,7 @@ impl RedisConnectionPool {
     }
 }
 
+#[derive(Clone)]
 struct RedisConfig {
     default_ttl: u32,
     default_stream_read_count: u64,
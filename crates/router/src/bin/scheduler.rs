@@ -7,7 +7,9 @@ use error_stack::ResultExt;
 use router::{
     configs::settings::{CmdLineConf, Settings},
     core::errors::{self, CustomResult},
-    logger, routes, services,
+    logger,
+    routes::{self, app::AppStateInfo},
+    services,
     types::storage::ProcessTrackerExt,
     workflows,
 };
@@ -41,12 +43,6 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
     // channel for listening to redis disconnect events
     let (redis_shutdown_signal_tx, redis_shutdown_signal_rx) = oneshot::channel();
     let state = routes::AppState::new(conf, redis_shutdown_signal_tx, api_client).await;
-    // channel to shutdown scheduler gracefully
-    let (tx, rx) = mpsc::channel(1);
-    tokio::spawn(router::receiver_for_error(
-        redis_shutdown_signal_rx,
-        tx.clone(),
-    ));
 
     #[allow(clippy::expect_used)]
     let scheduler_flow_str =
@@ -63,7 +59,7 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 
     logger::debug!(startup_config=?state.conf);
 
-    start_scheduler(&state, scheduler_flow, (tx, rx)).await?;
+    start_scheduler(&state, scheduler_flow, redis_shutdown_signal_rx).await?;
 
     eprintln!("Scheduler shut down");
     Ok(())
@@ -76,6 +72,12 @@ pub enum PTRunner {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
+    OutgoingWebhookRetryWorkflow,
+    MerchantKeyRotationWorkflow,
+    CustomerBlindIndexBackfillWorkflow,
+    VaultKeyRotationWorkflow,
+    #[cfg(feature = "email")]
+    ApiKeyExpiryWorkflow,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -100,6 +102,22 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
             Some(PTRunner::DeleteTokenizeDataWorkflow) => {
                 Box::new(workflows::tokenized_data::DeleteTokenizeDataWorkflow)
             }
+            Some(PTRunner::OutgoingWebhookRetryWorkflow) => {
+                Box::new(workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow)
+            }
+            Some(PTRunner::MerchantKeyRotationWorkflow) => {
+                Box::new(workflows::merchant_key_rotation::MerchantKeyRotationWorkflow)
+            }
+            Some(PTRunner::CustomerBlindIndexBackfillWorkflow) => Box::new(
+                workflows::customer_blind_index_backfill::CustomerBlindIndexBackfillWorkflow,
+            ),
+            Some(PTRunner::VaultKeyRotationWorkflow) => {
+                Box::new(workflows::vault_key_rotation::VaultKeyRotationWorkflow)
+            }
+            #[cfg(feature = "email")]
+            Some(PTRunner::ApiKeyExpiryWorkflow) => {
+                Box::new(workflows::api_key_expiry::ApiKeyExpiryWorkflow)
+            }
             _ => Err(ProcessTrackerError::UnexpectedFlow)?,
         };
         let app_state = &state.clone();
@@ -129,24 +147,66 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
     }
 }
 
+/// Start the scheduler flow for the default store and for the store of every tenant. The process
+/// tracker tasks of a tenant are held in the database schema of the tenant, so every tenant is
+/// scheduled separately, through a stream namespaced under the redis key prefix of the tenant.
 async fn start_scheduler(
     state: &routes::AppState,
     scheduler_flow: scheduler::SchedulerFlow,
-    channel: (mpsc::Sender<()>, mpsc::Receiver<()>),
+    redis_shutdown_signal_rx: oneshot::Receiver<()>,
 ) -> CustomResult<(), ProcessTrackerError> {
     let scheduler_settings = state
         .conf
         .scheduler
         .clone()
         .ok_or(errors::ProcessTrackerError::ConfigurationError)?;
-    scheduler::start_process_tracker(
-        state,
-        scheduler_flow,
-        Arc::new(scheduler_settings),
-        channel,
-        WorkflowRunner {},
-    )
-    .await
+
+    let mut schedulers = vec![(state.clone(), scheduler_settings.clone())];
+    for tenant_id in state.tenant_stores.keys() {
+        let tenant = state
+            .conf
+            .multitenancy
+            .tenants
+            .get(tenant_id)
+            .ok_or(errors::ProcessTrackerError::ConfigurationError)?;
+
+        let mut tenant_state = state.clone();
+        tenant_state
+            .add_tenant(Some(tenant_id.clone()))
+            .change_context(errors::ProcessTrackerError::ConfigurationError)?;
+
+        let mut tenant_settings = scheduler_settings.clone();
+        tenant_settings.stream = format!("{}:{}", tenant.redis_key_prefix, tenant_settings.stream);
+
+        schedulers.push((tenant_state, tenant_settings));
+    }
+
+    // channels to shutdown the schedulers gracefully
+    let (shutdown_senders, schedulers): (Vec<_>, Vec<_>) = schedulers
+        .into_iter()
+        .map(|(state, settings)| {
+            let (tx, rx) = mpsc::channel(1);
+            let shutdown_sender = tx.clone();
+            let scheduler = async move {
+                scheduler::start_process_tracker(
+                    &state,
+                    scheduler_flow,
+                    Arc::new(settings),
+                    (tx, rx),
+                    WorkflowRunner {},
+                )
+                .await
+            };
+            (shutdown_sender, scheduler)
+        })
+        .unzip();
+    tokio::spawn(router::receiver_for_error(
+        redis_shutdown_signal_rx,
+        shutdown_senders,
+    ));
+
+    futures::future::try_join_all(schedulers).await?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
 pub mod app;
 pub mod customers;
+pub mod disputes;
+pub mod events;
 pub mod payment_intents;
+pub mod payment_methods;
 pub mod refunds;
 pub mod setup_intents;
 pub mod webhooks;
@@ -14,13 +17,19 @@ impl StripeApis {
     pub fn server(state: routes::AppState) -> Scope {
         let max_depth = 10;
         let strict = false;
-        web::scope("/vs/v1")
+        let mut route = web::scope("/vs/v1")
             .app_data(web::Data::new(serde_qs::Config::new(max_depth, strict)))
             .service(app::SetupIntents::server(state.clone()))
             .service(app::PaymentIntents::server(state.clone()))
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
-            .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::Webhooks::server(state.clone()));
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(app::Disputes::server(state.clone()))
+                .service(app::Events::server(state.clone()));
+        }
+        route.service(app::PaymentMethods::server(state))
     }
 }
@@ -1,7 +1,10 @@
 use actix_web::{web, Scope};
 
-use super::{customers::*, payment_intents::*, refunds::*, setup_intents::*, webhooks::*};
-use crate::routes::{self, mandates, webhooks};
+use super::{
+    customers::*, disputes::*, events::*, payment_intents::*, payment_methods::*, refunds::*,
+    setup_intents::*, webhooks::*,
+};
+use crate::routes::{self, webhooks};
 
 pub struct PaymentIntents;
 
@@ -33,6 +36,10 @@ impl PaymentIntents {
             )
             .service(
                 web::resource("/{payment_id}/cancel").route(web::post().to(payment_intents_cancel)),
+            )
+            .service(
+                web::resource("/{payment_id}/verify_microdeposits")
+                    .route(web::post().to(payment_intents_verify_microdeposits)),
             );
         route
     }
@@ -60,8 +67,12 @@ pub struct Refunds;
 
 impl Refunds {
     pub fn server(config: routes::AppState) -> Scope {
-        web::scope("/refunds")
-            .app_data(web::Data::new(config))
+        let mut route = web::scope("/refunds").app_data(web::Data::new(config));
+        #[cfg(feature = "olap")]
+        {
+            route = route.service(web::resource("").route(web::get().to(refund_list)))
+        }
+        route = route
             .service(web::resource("").route(web::post().to(refund_create)))
             .service(
                 web::resource("/sync").route(web::post().to(refund_retrieve_with_gateway_creds)),
@@ -70,7 +81,8 @@ impl Refunds {
                 web::resource("/{refund_id}")
                     .route(web::get().to(refund_retrieve))
                     .route(web::post().to(refund_update)),
-            )
+            );
+        route
     }
 }
 
@@ -112,12 +124,43 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
-            .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
+            .service(
+                web::resource("/{payment_method_id}/attach")
+                    .route(web::post().to(payment_method_attach)),
+            )
+            .service(
+                web::resource("/{payment_method_id}/detach")
+                    .route(web::post().to(payment_method_detach)),
+            )
+    }
+}
+
+pub struct Disputes;
+
+#[cfg(feature = "olap")]
+impl Disputes {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/disputes")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(dispute_list)))
+            .service(web::resource("/{dispute_id}").route(web::get().to(dispute_retrieve)))
+            .service(web::resource("/{dispute_id}/close").route(web::post().to(dispute_close)))
+    }
+}
+
+pub struct Events;
+
+#[cfg(feature = "olap")]
+impl Events {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(event_list)))
     }
 }
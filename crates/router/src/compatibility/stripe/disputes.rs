@@ -0,0 +1,124 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::disputes as dispute_models;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{
+        stripe::{errors, webhooks::StripeDisputeResponse},
+        wrap,
+    },
+    core::disputes,
+    routes,
+    services::{api, authentication as auth},
+    types::api::disputes as dispute_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        StripeDisputeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        dispute_id,
+        |state, auth, req| disputes::retrieve_dispute(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+pub async fn dispute_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripeDisputeListConstraints>,
+) -> HttpResponse {
+    let payload = match dispute_models::DisputeListConstraints::try_from(query_payload.into_inner())
+    {
+        Ok(payload) => payload,
+        Err(err) => {
+            let error_code = errors::StripeErrorCode::from(err.current_context().clone());
+            return api::log_and_return_error_response(err.change_context(error_code));
+        }
+    };
+
+    let flow = Flow::DisputesList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| disputes::retrieve_disputes_page(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+    ))
+    .await
+}
+
+/// Closing a dispute in Stripe concedes it to the customer, which maps onto accepting the dispute
+/// with the connector
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_close(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        StripeDisputeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        dispute_id,
+        |state, auth, req| {
+            disputes::accept_dispute(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+    ))
+    .await
+}
@@ -0,0 +1,67 @@
+use api_models::disputes;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::{
+        payment_intents::types::from_timestamp_to_datetime, webhooks::StripeDisputeResponse,
+    },
+    core::errors,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeDisputeListConstraints {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub starting_after: Option<String>,
+    pub created: Option<i64>,
+    #[serde(rename = "created[lt]")]
+    pub created_lt: Option<i64>,
+    #[serde(rename = "created[gt]")]
+    pub created_gt: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeDisputeListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeDisputeResponse>,
+}
+
+impl TryFrom<StripeDisputeListConstraints> for disputes::DisputeListConstraints {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeDisputeListConstraints) -> Result<Self, Self::Error> {
+        Ok(Self {
+            limit: Some(item.limit),
+            starting_after: item.starting_after,
+            dispute_status: None,
+            dispute_stage: None,
+            reason: None,
+            connector: None,
+            received_time: from_timestamp_to_datetime(item.created)?,
+            received_time_lt: from_timestamp_to_datetime(item.created_lt)?,
+            received_time_gt: from_timestamp_to_datetime(item.created_gt)?,
+            received_time_lte: from_timestamp_to_datetime(item.created_lte)?,
+            received_time_gte: from_timestamp_to_datetime(item.created_gte)?,
+        })
+    }
+}
+
+impl From<disputes::DisputeListPage> for StripeDisputeListResponse {
+    fn from(page: disputes::DisputeListPage) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/disputes",
+            has_more: page.has_more,
+            data: page.data.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -51,6 +51,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::CardError, code = "invalid_card_type", message = "Card data is invalid")]
     InvalidCardType,
 
+    #[error(error_type = StripeErrorType::CardError, code = "card_declined", message = "The payment method used is blocked by the merchant")]
+    PaymentBlocked,
+
     #[error(error_type = StripeErrorType::ApiError, code = "refund_failed", message = "refund has failed")]
     RefundFailed, // stripe error code
 
@@ -84,6 +87,12 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "duplicate_resource", message = "Duplicate config")]
     DuplicateConfig,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "A request with the same idempotency key is still being processed")]
+    IdempotencyKeyInUse,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyKeyReused,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payment")]
     PaymentNotFound,
 
@@ -228,6 +237,8 @@ pub enum StripeErrorCode {
     WebhookProcessingError,
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "payment_method_unactivated", message = "The operation cannot be performed as the payment method used has not been activated. Activate the payment method in the Dashboard, then try again.")]
     PaymentMethodUnactivated,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "Verifying microdeposits is not supported, the customer verifies them on the verification page of the connector")]
+    MicrodepositsVerificationNotSupported,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "{message}")]
     HyperswitchUnprocessableEntity { message: String },
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "{message}")]
@@ -450,6 +461,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::DisputeFailed { data } => Self::DisputeFailed { data },
             errors::ApiErrorResponse::InvalidCardData { data } => Self::InvalidCardType, // Maybe it is better to de generalize this router error
             errors::ApiErrorResponse::CardExpired { data } => Self::ExpiredCard,
+            errors::ApiErrorResponse::PaymentBlocked => Self::PaymentBlocked,
             errors::ApiErrorResponse::RefundNotPossible { connector } => Self::RefundFailed,
             errors::ApiErrorResponse::RefundFailed { data } => Self::RefundFailed, // Nothing at stripe to map
             errors::ApiErrorResponse::PayoutFailed { data } => Self::PayoutFailed,
@@ -477,6 +489,14 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::CustomerRedacted => Self::CustomerRedacted, //not a stripe code
             errors::ApiErrorResponse::ConfigNotFound => Self::ConfigNotFound, // not a stripe code
             errors::ApiErrorResponse::DuplicateConfig => Self::DuplicateConfig, // not a stripe code
+            errors::ApiErrorResponse::IdempotencyKeyInUse => Self::IdempotencyKeyInUse,
+            errors::ApiErrorResponse::IdempotencyKeyReused => Self::IdempotencyKeyReused,
+            errors::ApiErrorResponse::InvalidTenant { tenant_id } => Self::InvalidRequestData {
+                message: format!("The tenant '{tenant_id}' is not configured"),
+            },
+            errors::ApiErrorResponse::TenantNotResolved => Self::InvalidRequestData {
+                message: "The tenant of the request could not be resolved".to_string(),
+            },
             errors::ApiErrorResponse::DuplicateRefundRequest => Self::DuplicateRefundRequest,
             errors::ApiErrorResponse::DuplicatePayout { payout_id } => {
                 Self::DuplicatePayout { payout_id }
@@ -550,8 +570,16 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 object: "business_profile".to_owned(),
                 id,
             },
+            errors::ApiErrorResponse::EventNotFound { id } => Self::ResourceMissing {
+                object: "event".to_owned(),
+                id,
+            },
+            errors::ApiErrorResponse::PaymentLinkNotFound { id } => Self::ResourceMissing {
+                object: "payment_link".to_owned(),
+                id,
+            },
             errors::ApiErrorResponse::DisputeStatusValidationFailed { reason } => {
-                Self::InternalServerError
+                Self::PreconditionFailed { message: reason }
             }
             errors::ApiErrorResponse::FileValidationFailed { .. } => Self::FileValidationFailed,
             errors::ApiErrorResponse::MissingFile => Self::MissingFile,
@@ -598,12 +626,14 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentIntentPaymentAttemptFailed { .. }
             | Self::ExpiredCard
             | Self::InvalidCardType
+            | Self::PaymentBlocked
             | Self::DuplicateRefundRequest
             | Self::DuplicatePayout { .. }
             | Self::RefundNotFound
             | Self::CustomerNotFound
             | Self::ConfigNotFound
             | Self::DuplicateConfig
+            | Self::IdempotencyKeyReused
             | Self::ClientSecretNotFound
             | Self::PaymentNotFound
             | Self::PaymentMethodNotFound
@@ -643,6 +673,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::FileNotAvailable
             | Self::FileProviderNotSupported
             | Self::CurrencyNotSupported { .. }
+            | Self::MicrodepositsVerificationNotSupported
             | Self::PaymentMethodUnactivated => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::PayoutFailed
@@ -651,6 +682,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::CustomerRedacted
             | Self::WebhookProcessingError => StatusCode::INTERNAL_SERVER_ERROR,
             Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::IdempotencyKeyInUse => StatusCode::CONFLICT,
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
@@ -709,6 +741,14 @@ impl ErrorSwitch<StripeErrorCode> for errors::ApiErrorResponse {
     }
 }
 
+/// Allows the compatibility layer to fail requests with a Stripe error of its own, such as for the
+/// operations which are not supported
+impl ErrorSwitch<StripeErrorCode> for StripeErrorCode {
+    fn switch(&self) -> StripeErrorCode {
+        self.clone()
+    }
+}
+
 impl crate::services::EmbedError for error_stack::Report<StripeErrorCode> {}
 
 impl ErrorSwitch<StripeErrorCode> for CustomersErrorResponse {
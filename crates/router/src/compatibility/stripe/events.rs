@@ -0,0 +1,45 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::webhook_events as webhook_events_types;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::webhooks::webhook_events,
+    routes,
+    services::authentication as auth,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventsList))]
+pub async fn event_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripeEventListConstraints>,
+) -> HttpResponse {
+    let constraints: webhook_events_types::EventListConstraints = query_payload.into_inner().into();
+
+    let flow = Flow::WebhookEventsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeEventListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        constraints,
+        |state, auth, constraints| {
+            webhook_events::list_events_page(state, auth.merchant_account.merchant_id, constraints)
+        },
+        &auth::ApiKeyAuth,
+    ))
+    .await
+}
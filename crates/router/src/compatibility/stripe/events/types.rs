@@ -0,0 +1,83 @@
+use api_models::webhook_events;
+use serde::{Deserialize, Serialize};
+
+use crate::compatibility::stripe::webhooks::get_stripe_event_type;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeEventListConstraints {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub starting_after: Option<String>,
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeEventResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: &'static str,
+    pub created: i64,
+    pub pending_webhooks: u8,
+    pub data: StripeEventData,
+}
+
+/// Events are stored without the object they were raised for, so only the identifier of that
+/// object is returned
+#[derive(Debug, Serialize)]
+pub struct StripeEventData {
+    pub object: StripeEventObject,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeEventObject {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeEventListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeEventResponse>,
+}
+
+impl From<StripeEventListConstraints> for webhook_events::EventListConstraints {
+    fn from(item: StripeEventListConstraints) -> Self {
+        Self {
+            object_id: None,
+            limit: Some(item.limit),
+            offset: None,
+            starting_after: item.starting_after,
+        }
+    }
+}
+
+impl From<webhook_events::EventListItemResponse> for StripeEventResponse {
+    fn from(item: webhook_events::EventListItemResponse) -> Self {
+        Self {
+            id: item.event_id,
+            object: "event",
+            stype: get_stripe_event_type(item.event_type),
+            created: item.created.assume_utc().unix_timestamp(),
+            pending_webhooks: u8::from(!item.is_webhook_notified),
+            data: StripeEventData {
+                object: StripeEventObject { id: item.object_id },
+            },
+        }
+    }
+}
+
+impl From<webhook_events::EventListPage> for StripeEventListResponse {
+    fn from(page: webhook_events::EventListPage) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/events",
+            has_more: page.has_more,
+            data: page.data.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::payments,
     routes,
-    services::{api, authentication as auth},
+    services::{self, api, authentication as auth},
     types::api::{self as api_types},
 };
 
@@ -126,6 +126,56 @@ pub async fn payment_intents_retrieve(
     .await
 }
 
+/// Microdeposits are verified by the customer on the hosted verification page of the connector,
+/// and none of the supported connectors accept the `amounts` or `descriptor_code` sent by Stripe
+/// SDKs. The request is authenticated and validated, and then rejected, instead of silently
+/// reporting the payment as verified.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsVerifyMicrodeposits))]
+pub async fn payment_intents_verify_microdeposits(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    _path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let stripe_payload: types::StripeVerifyMicrodepositsBody = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::PaymentsVerifyMicrodeposits;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentIntentResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        stripe_payload,
+        |_state, _auth, payload| async move {
+            payload
+                .validate()
+                .map_err(|err| report!(errors::StripeErrorCode::from(err)))?;
+            Err::<services::ApplicationResponse<api_types::PaymentsResponse>, _>(report!(
+                errors::StripeErrorCode::MicrodepositsVerificationNotSupported
+            ))
+        },
+        &auth::ApiKeyAuth,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieve))]
 pub async fn payment_intents_retrieve_with_gateway_creds(
     state: web::Data<routes::AppState>,
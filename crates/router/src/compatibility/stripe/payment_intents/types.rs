@@ -620,7 +620,7 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
 }
 
 #[inline]
-fn from_timestamp_to_datetime(
+pub(crate) fn from_timestamp_to_datetime(
     time: Option<i64>,
 ) -> Result<Option<PrimitiveDateTime>, errors::ApiErrorResponse> {
     if let Some(time) = time {
@@ -826,3 +826,46 @@ pub(crate) fn into_stripe_next_action(
 pub struct StripePaymentRetrieveBody {
     pub client_secret: Option<String>,
 }
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct StripeVerifyMicrodepositsBody {
+    pub client_secret: Option<masking::Secret<String>>,
+    pub amounts: Option<Vec<i64>>,
+    pub descriptor_code: Option<String>,
+}
+
+impl StripeVerifyMicrodepositsBody {
+    /// Either the two amounts of the microdeposits or the 6 character descriptor code (starting
+    /// with `SM`) of the single microdeposit must be sent, as expected by Stripe.
+    pub fn validate(&self) -> Result<(), errors::ApiErrorResponse> {
+        match (&self.amounts, &self.descriptor_code) {
+            (Some(amounts), None) => {
+                if amounts.len() != 2 || amounts.iter().any(|amount| *amount <= 0) {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message:
+                            "amounts must contain the two positive amounts of the microdeposits"
+                                .to_string(),
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            (None, Some(descriptor_code)) => {
+                if descriptor_code.len() != 6 || !descriptor_code.starts_with("SM") {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "descriptor_code must be a 6 character code starting with SM"
+                            .to_string(),
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            (Some(_), Some(_)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only one of amounts or descriptor_code must be provided".to_string(),
+            }),
+            (None, None) => Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "amounts",
+            }),
+        }
+    }
+}
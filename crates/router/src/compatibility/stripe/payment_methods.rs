@@ -0,0 +1,233 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::mandates as mandate_types;
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        errors::{self as core_errors, StorageErrorExt},
+        mandate,
+        payment_methods::cards,
+    },
+    routes,
+    services::{self, api, authentication as auth},
+    types::{api as api_types, domain},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsAttach))]
+pub async fn payment_method_attach(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripePaymentMethodAttachRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let payment_method_id = path.into_inner();
+
+    let flow = Flow::PaymentMethodsAttach;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| {
+            attach_payment_method(
+                state,
+                auth.merchant_account,
+                &payment_method_id,
+                req.customer,
+            )
+        },
+        &auth::ApiKeyAuth,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsDelete))]
+pub async fn payment_method_detach(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let id = path.into_inner();
+
+    let flow = Flow::PaymentMethodsDelete;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodDetachResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        id,
+        |state, auth, req| detach_payment_method_or_mandate(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+    ))
+    .await
+}
+
+/// Payment methods are always saved against a customer, so attaching a payment method only
+/// verifies that it already belongs to the customer provided in the request
+async fn attach_payment_method(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_method_id: &str,
+    customer_id: String,
+) -> core_errors::RouterResponse<api_types::PaymentMethodResponse> {
+    let payment_method =
+        get_merchant_payment_method(state, &merchant_account, payment_method_id).await?;
+
+    if payment_method.customer_id.as_deref() != Some(customer_id.as_str()) {
+        return Err(core_errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The payment method {payment_method_id} is attached to a different customer"
+            ),
+        })
+        .into_report();
+    }
+
+    Ok(services::ApplicationResponse::Json(payment_method))
+}
+
+/// Stripe SDKs may detach mandates through the payment methods API, so the identifier is looked
+/// up as a mandate of the merchant first, which gets revoked, and is detached as a payment method
+/// otherwise
+async fn detach_payment_method_or_mandate(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    id: String,
+) -> core_errors::RouterResponse<types::PaymentMethodDetachResponse> {
+    let mandate = state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(core_errors::ApiErrorResponse::MandateNotFound);
+
+    let response = match mandate {
+        Ok(mandate) => types::PaymentMethodDetachResponse::Mandate(get_json_response(
+            mandate::revoke_mandate(
+                state,
+                merchant_account,
+                mandate_types::MandateId {
+                    mandate_id: mandate.mandate_id,
+                },
+            )
+            .await?,
+        )?),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                core_errors::ApiErrorResponse::MandateNotFound
+            ) =>
+        {
+            types::PaymentMethodDetachResponse::PaymentMethod(
+                detach_payment_method(
+                    state,
+                    merchant_account,
+                    api_types::PaymentMethodId {
+                        payment_method_id: id,
+                    },
+                )
+                .await?,
+            )
+        }
+        Err(error) => return Err(error),
+    };
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Detaching a payment method from its customer deletes the saved payment method, and responds
+/// with the payment method as it was before it got detached
+async fn detach_payment_method(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_method_id: api_types::PaymentMethodId,
+) -> core_errors::RouterResult<api_types::PaymentMethodResponse> {
+    let payment_method = get_merchant_payment_method(
+        state.clone(),
+        &merchant_account,
+        &payment_method_id.payment_method_id,
+    )
+    .await?;
+
+    cards::delete_payment_method(state, merchant_account, payment_method_id).await?;
+
+    Ok(api_types::PaymentMethodResponse {
+        customer_id: None,
+        ..payment_method
+    })
+}
+
+async fn get_merchant_payment_method(
+    state: routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_method_id: &str,
+) -> core_errors::RouterResult<api_types::PaymentMethodResponse> {
+    // Payment methods are looked up by their identifier alone, so ownership is verified before
+    // the card details are fetched from the locker
+    let payment_method = state
+        .store
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(core_errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.merchant_id != merchant_account.merchant_id {
+        return Err(core_errors::ApiErrorResponse::PaymentMethodNotFound).into_report();
+    }
+
+    get_json_response(
+        cards::retrieve_payment_method(
+            state,
+            api_types::PaymentMethodId {
+                payment_method_id: payment_method.payment_method_id,
+            },
+        )
+        .await?,
+    )
+}
+
+fn get_json_response<R>(
+    response: services::ApplicationResponse<R>,
+) -> core_errors::RouterResult<R> {
+    match response {
+        services::ApplicationResponse::Json(response) => Ok(response),
+        _ => Err(core_errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Failed to get the response in json"),
+    }
+}
@@ -0,0 +1,64 @@
+use common_utils::pii;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::customers::types::CardDetails,
+    types::api::{self, enums as api_enums, mandates},
+};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripePaymentMethodAttachRequest {
+    pub customer: String,
+}
+
+#[derive(Serialize)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: api_enums::PaymentMethod,
+    pub customer: Option<String>,
+    pub card: Option<CardDetails>,
+    pub created: Option<i64>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl From<api::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(item: api::PaymentMethodResponse) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            stype: item.payment_method,
+            customer: item.customer_id,
+            card: item.card.map(From::from),
+            created: item.created.map(|t| t.assume_utc().unix_timestamp()),
+            metadata: item.metadata,
+        }
+    }
+}
+
+/// The resource detached through the payment methods API, as Stripe SDKs may detach mandates
+/// through it
+#[derive(Debug, Serialize)]
+pub enum PaymentMethodDetachResponse {
+    PaymentMethod(api::PaymentMethodResponse),
+    Mandate(mandates::MandateRevokedResponse),
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum StripePaymentMethodDetachResponse {
+    PaymentMethod(StripePaymentMethodResponse),
+    Mandate(mandates::MandateRevokedResponse),
+}
+
+impl From<PaymentMethodDetachResponse> for StripePaymentMethodDetachResponse {
+    fn from(item: PaymentMethodDetachResponse) -> Self {
+        match item {
+            PaymentMethodDetachResponse::PaymentMethod(payment_method) => {
+                Self::PaymentMethod(payment_method.into())
+            }
+            PaymentMethodDetachResponse::Mandate(mandate) => Self::Mandate(mandate),
+        }
+    }
+}
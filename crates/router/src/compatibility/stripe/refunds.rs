@@ -177,3 +177,42 @@ pub async fn refund_update(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundsList))]
+#[cfg(feature = "olap")]
+pub async fn refund_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripeRefundListConstraints>,
+) -> HttpResponse {
+    let payload = match api_models::refunds::RefundListRequest::try_from(query_payload.into_inner())
+    {
+        Ok(payload) => payload,
+        Err(err) => {
+            let error_code = errors::StripeErrorCode::from(err.current_context().clone());
+            return api::log_and_return_error_response(err.change_context(error_code));
+        }
+    };
+
+    let flow = Flow::RefundsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeRefundListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| refunds::refund_list(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+    ))
+    .await
+}
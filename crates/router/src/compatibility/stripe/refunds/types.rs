@@ -3,7 +3,11 @@ use std::{convert::From, default::Default};
 use common_utils::pii;
 use serde::{Deserialize, Serialize};
 
-use crate::types::api::{admin, refunds};
+use crate::{
+    compatibility::stripe::payment_intents::types::from_timestamp_to_datetime,
+    core::errors,
+    types::api::{admin, refunds},
+};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct StripeCreateRefundRequest {
@@ -20,6 +24,21 @@ pub struct StripeUpdateRefundRequest {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeRefundListConstraints {
+    pub payment_intent: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
 #[derive(Clone, Serialize, PartialEq, Eq, Debug)]
 pub struct StripeRefundResponse {
     pub id: String,
@@ -31,6 +50,14 @@ pub struct StripeRefundResponse {
     pub metadata: pii::SecretSerdeValue,
 }
 
+#[derive(Clone, Serialize, PartialEq, Eq, Debug)]
+pub struct StripeRefundListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeRefundResponse>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeRefundStatus {
@@ -90,3 +117,45 @@ impl From<refunds::RefundResponse> for StripeRefundResponse {
         }
     }
 }
+
+impl TryFrom<StripeRefundListConstraints> for api_models::refunds::RefundListRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeRefundListConstraints) -> Result<Self, Self::Error> {
+        let created_gte = from_timestamp_to_datetime(item.created_gte)?;
+        let created_lte = from_timestamp_to_datetime(item.created_lte)?;
+        // The refunds list is filtered on a time range which always needs a start time, so an
+        // upper bound alone is treated as a range starting at the unix epoch
+        let time_range = match (created_gte, created_lte) {
+            (None, None) => None,
+            (start_time, end_time) => Some(api_models::refunds::TimeRange {
+                start_time: start_time.unwrap_or_else(|| {
+                    let epoch = time::OffsetDateTime::UNIX_EPOCH;
+                    time::PrimitiveDateTime::new(epoch.date(), epoch.time())
+                }),
+                end_time,
+            }),
+        };
+
+        Ok(Self {
+            payment_id: item.payment_intent,
+            refund_id: None,
+            limit: Some(item.limit),
+            offset: None,
+            time_range,
+            connector: None,
+            currency: None,
+            refund_status: None,
+        })
+    }
+}
+
+impl From<api_models::refunds::RefundListResponse> for StripeRefundListResponse {
+    fn from(res: api_models::refunds::RefundListResponse) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/refunds",
+            has_more: i64::try_from(res.count).map_or(false, |count| res.total_count > count),
+            data: res.data.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::{future::Future, sync::Arc, time::Instant};
 
 use actix_web::{HttpRequest, HttpResponse, Responder};
-use common_utils::errors::{CustomResult, ErrorSwitch};
+use common_utils::errors::{CustomResult, ErrorSwitch, ReportSwitchExt};
 use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
@@ -41,95 +41,105 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest, payload = ?payload);
 
-    let res = match metrics::request::record_request_time_metric(
-        api::server_wrap_util(
-            &flow,
-            state.clone().into(),
-            request,
-            payload,
-            func,
-            api_authentication,
-        ),
-        &flow,
-    )
-    .await
-    .map(|response| {
-        logger::info!(api_response =? response);
-        response
-    }) {
-        Ok(api::ApplicationResponse::Json(response)) => {
-            let response = S::try_from(response);
-            match response {
-                Ok(response) => match serde_json::to_string(&response) {
-                    Ok(res) => api::http_response_json(res),
-                    Err(_) => api::http_response_err(
-                        r#"{
+    let request_state = state.clone();
+    let res: CustomResult<HttpResponse, E> =
+        api::run_with_idempotency(&*request_state, request, || async move {
+            match metrics::request::record_request_time_metric(
+                api::server_wrap_util(
+                    &flow,
+                    state.clone().into(),
+                    request,
+                    payload,
+                    func,
+                    api_authentication,
+                ),
+                &flow,
+            )
+            .await
+            .map(|response| {
+                logger::info!(api_response =? response);
+                response
+            }) {
+                Ok(api::ApplicationResponse::Json(response)) => {
+                    let response = S::try_from(response);
+                    match response {
+                        Ok(response) => match serde_json::to_string(&response) {
+                            Ok(res) => api::http_response_json(res),
+                            Err(_) => api::http_response_err(
+                                r#"{
+                                        "error": {
+                                            "message": "Error serializing response from connector"
+                                        }
+                                    }"#,
+                            ),
+                        },
+                        Err(_) => api::http_response_err(
+                            r#"{
                                 "error": {
-                                    "message": "Error serializing response from connector"
+                                    "message": "Error converting juspay response to stripe response"
                                 }
                             }"#,
-                    ),
-                },
-                Err(_) => api::http_response_err(
-                    r#"{
-                        "error": {
-                            "message": "Error converting juspay response to stripe response"
-                        }
-                    }"#,
-                ),
-            }
-        }
-        Ok(api::ApplicationResponse::JsonWithHeaders((response, headers))) => {
-            let response = S::try_from(response);
-            match response {
-                Ok(response) => match serde_json::to_string(&response) {
-                    Ok(res) => api::http_response_json_with_headers(res, headers),
-                    Err(_) => api::http_response_err(
-                        r#"{
+                        ),
+                    }
+                }
+                Ok(api::ApplicationResponse::JsonWithHeaders((response, headers))) => {
+                    let response = S::try_from(response);
+                    match response {
+                        Ok(response) => match serde_json::to_string(&response) {
+                            Ok(res) => api::http_response_json_with_headers(res, headers),
+                            Err(_) => api::http_response_err(
+                                r#"{
+                                        "error": {
+                                            "message": "Error serializing response from connector"
+                                        }
+                                    }"#,
+                            ),
+                        },
+                        Err(_) => api::http_response_err(
+                            r#"{
                                 "error": {
-                                    "message": "Error serializing response from connector"
+                                    "message": "Error converting juspay response to stripe response"
                                 }
                             }"#,
-                    ),
-                },
-                Err(_) => api::http_response_err(
-                    r#"{
-                        "error": {
-                            "message": "Error converting juspay response to stripe response"
-                        }
-                    }"#,
-                ),
-            }
-        }
-        Ok(api::ApplicationResponse::StatusOk) => api::http_response_ok(),
-        Ok(api::ApplicationResponse::TextPlain(text)) => api::http_response_plaintext(text),
-        Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
-            api::http_response_file_data(file_data, content_type)
-        }
-        Ok(api::ApplicationResponse::JsonForRedirection(response)) => {
-            match serde_json::to_string(&response) {
-                Ok(res) => api::http_redirect_response(res, response),
-                Err(_) => api::http_response_err(
-                    r#"{
-                    "error": {
-                        "message": "Error serializing response from connector"
+                        ),
                     }
-                }"#,
-                ),
+                }
+                Ok(api::ApplicationResponse::StatusOk) => api::http_response_ok(),
+                Ok(api::ApplicationResponse::TextPlain(text)) => api::http_response_plaintext(text),
+                Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
+                    api::http_response_file_data(file_data, content_type)
+                }
+                Ok(api::ApplicationResponse::JsonForRedirection(response)) => {
+                    match serde_json::to_string(&response) {
+                        Ok(res) => api::http_redirect_response(res, response),
+                        Err(_) => api::http_response_err(
+                            r#"{
+                            "error": {
+                                "message": "Error serializing response from connector"
+                            }
+                        }"#,
+                        ),
+                    }
+                }
+                Ok(api::ApplicationResponse::Form(redirection_data)) => {
+                    let config = state.conf();
+                    api::build_redirection_form(
+                        &redirection_data.redirect_form,
+                        redirection_data.payment_method_data,
+                        redirection_data.amount,
+                        redirection_data.currency,
+                        config,
+                    )
+                    .respond_to(request)
+                    .map_into_boxed_body()
+                }
+                Err(error) => api::log_and_return_error_response(error),
             }
-        }
-        Ok(api::ApplicationResponse::Form(redirection_data)) => {
-            let config = state.conf();
-            api::build_redirection_form(
-                &redirection_data.redirect_form,
-                redirection_data.payment_method_data,
-                redirection_data.amount,
-                redirection_data.currency,
-                config,
-            )
-            .respond_to(request)
-            .map_into_boxed_body()
-        }
+        })
+        .await
+        .switch();
+    let res = match res {
+        Ok(res) => res,
         Err(error) => api::log_and_return_error_response(error),
     };
 
@@ -48,6 +48,7 @@ impl Default for super::settings::Locker {
         Self {
             host: "localhost".into(),
             mock_locker: true,
+            builtin_vault: false,
             basilisk_host: "localhost".into(),
             locker_signing_key_id: "1".into(),
         }
@@ -89,6 +90,15 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            ttl_in_secs: 24 * 60 * 60,
+            lock_ttl_in_secs: 5 * 60,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -70,6 +70,8 @@ pub struct Settings {
     pub connectors: Connectors,
     pub refund: Refund,
     pub eph_key: EphemeralConfig,
+    pub idempotency: IdempotencyConfig,
+    pub multitenancy: Multitenancy,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
     pub drainer: DrainerSettings,
@@ -95,6 +97,8 @@ pub struct Settings {
     #[cfg(feature = "payouts")]
     pub payouts: Payouts,
     pub applepay_decrypt_keys: ApplePayDecryptConifg,
+    pub google_pay_decrypt_keys: GooglePayDecryptConfig,
+    pub google_pay_decrypt_supported_connectors: GooglePayDecryptSupportedConnectors,
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: ApplepayMerchantConfigs,
     pub temp_locker_disable_config: TempLockerDisableConfig,
@@ -402,6 +406,8 @@ pub struct Secrets {
 pub struct Locker {
     pub host: String,
     pub mock_locker: bool,
+    /// Store cards in the vault built into the application, instead of an external locker
+    pub builtin_vault: bool,
     pub basilisk_host: String,
     pub locker_signing_key_id: String,
 }
@@ -419,6 +425,42 @@ pub struct EphemeralConfig {
     pub validity: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencyConfig {
+    /// Number of seconds for which the response of a request is replayed to retries with the
+    /// same idempotency key
+    pub ttl_in_secs: i64,
+    /// Number of seconds for which an idempotency key is held while its first request is being
+    /// processed
+    pub lock_ttl_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Multitenancy {
+    /// Whether requests are routed to the stores of the tenant they are resolved to
+    pub enabled: bool,
+    /// Tenants served by the application, keyed by tenant ID
+    pub tenants: HashMap<String, Tenant>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Tenant {
+    /// Hostnames whose requests are resolved to this tenant, when no tenant header is provided
+    pub hostnames: Vec<String>,
+    /// Database schema holding the tables of this tenant
+    pub schema: String,
+    /// Prefix of the redis keys of this tenant
+    pub redis_key_prefix: String,
+    /// Master database of this tenant, defaults to the master database of the application
+    pub master_database: Option<Database>,
+    /// Replica database of this tenant, defaults to the replica database of the application
+    #[cfg(feature = "olap")]
+    pub replica_database: Option<Database>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Jwekey {
@@ -475,6 +517,7 @@ impl Into<storage_impl::config::Database> for Database {
             dbname: self.dbname,
             pool_size: self.pool_size,
             connection_timeout: self.connection_timeout,
+            schema: None,
         }
     }
 }
@@ -532,9 +575,11 @@ pub struct Connectors {
     pub powertranz: ConnectorParams,
     pub rapyd: ConnectorParams,
     pub shift4: ConnectorParams,
+    pub signifyd: ConnectorParams,
     pub square: ConnectorParams,
     pub stax: ConnectorParams,
     pub stripe: ConnectorParamsWithFileUploadUrl,
+    pub threedsecureio: ConnectorParams,
     pub trustpay: ConnectorParamsWithMoreUrls,
     pub tsys: ConnectorParams,
     pub wise: ConnectorParams,
@@ -638,6 +683,19 @@ pub struct ApplePayDecryptConifg {
     pub apple_pay_merchant_cert_key: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct GooglePayDecryptConfig {
+    /// The signing keys of Google Pay, as published by Google in JSON
+    pub google_pay_root_signing_keys: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct GooglePayDecryptSupportedConnectors {
+    /// The connectors accepting the decrypted Google Pay payment tokens
+    #[serde(deserialize_with = "connector_deser")]
+    pub supported_connectors: HashSet<api_models::enums::Connector>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorRequestReferenceIdConfig {
     pub merchant_ids_send_payment_id_as_connector_request_id: HashSet<String>,
@@ -733,10 +791,22 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.validate()?;
+        self.multitenancy.validate()?;
+        // The tables of the built-in vault are qualified with the `vault` schema rather than the
+        // schema of each tenant, and would hence be shared by all the tenants
+        if self.multitenancy.enabled && self.locker.builtin_vault {
+            return Err(ApplicationError::InvalidConfigurationValueError(
+                "built-in vault must not be enabled along with multitenancy".into(),
+            ));
+        }
         #[cfg(feature = "kms")]
         self.kms
             .validate()
             .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
+        #[cfg(feature = "email")]
+        self.email
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
         #[cfg(feature = "s3")]
         self.file_upload_config.validate()?;
         Ok(())
@@ -49,17 +49,21 @@ impl super::settings::Locker {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        when(!self.mock_locker && self.host.is_default_or_empty(), || {
+        let external_locker = !self.mock_locker && !self.builtin_vault;
+
+        when(external_locker && self.host.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "locker host must not be empty when mock locker is disabled".into(),
+                "locker host must not be empty when mock locker and built-in vault are disabled"
+                    .into(),
             ))
         })?;
 
         when(
-            !self.mock_locker && self.basilisk_host.is_default_or_empty(),
+            external_locker && self.basilisk_host.is_default_or_empty(),
             || {
                 Err(ApplicationError::InvalidConfigurationValueError(
-                    "basilisk host must not be empty when mock locker is disabled".into(),
+                    "basilisk host must not be empty when mock locker and built-in vault are disabled"
+                        .into(),
                 ))
             },
         )
@@ -165,3 +169,73 @@ impl super::settings::ApiKeys {
         })
     }
 }
+
+impl super::settings::Multitenancy {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use std::collections::HashSet;
+
+        use common_utils::fp_utils::when;
+
+        let mut schemas = HashSet::new();
+        let mut redis_key_prefixes = HashSet::new();
+        let mut hostnames = HashSet::new();
+
+        for (tenant_id, tenant) in &self.tenants {
+            when(
+                tenant.schema.is_empty()
+                    || !tenant
+                        .schema
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_'),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "schema of tenant {tenant_id} must be a non-empty alphanumeric identifier"
+                    )))
+                },
+            )?;
+
+            when(tenant.redis_key_prefix.is_default_or_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "redis key prefix of tenant {tenant_id} must not be empty"
+                )))
+            })?;
+
+            when(!schemas.insert(tenant.schema.as_str()), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "schema of tenant {tenant_id} is shared with another tenant"
+                )))
+            })?;
+
+            when(
+                !redis_key_prefixes.insert(tenant.redis_key_prefix.as_str()),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "redis key prefix of tenant {tenant_id} is shared with another tenant"
+                    )))
+                },
+            )?;
+
+            for hostname in &tenant.hostnames {
+                when(!hostnames.insert(hostname.to_lowercase()), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "hostname {hostname} is configured for more than one tenant"
+                    )))
+                })?;
+            }
+
+            tenant
+                .master_database
+                .as_ref()
+                .map(|database| database.validate())
+                .transpose()?;
+            #[cfg(feature = "olap")]
+            tenant
+                .replica_database
+                .as_ref()
+                .map(|database| database.validate())
+                .transpose()?;
+        }
+
+        Ok(())
+    }
+}
@@ -38,9 +38,11 @@ pub mod payu;
 pub mod powertranz;
 pub mod rapyd;
 pub mod shift4;
+pub mod signifyd;
 pub mod square;
 pub mod stax;
 pub mod stripe;
+pub mod threedsecureio;
 pub mod trustpay;
 pub mod tsys;
 pub mod utils;
@@ -59,7 +61,7 @@ pub use self::{
     globepay::Globepay, gocardless::Gocardless, helcim::Helcim, iatapay::Iatapay, klarna::Klarna,
     mollie::Mollie, multisafepay::Multisafepay, nexinets::Nexinets, nmi::Nmi, noon::Noon,
     nuvei::Nuvei, opayo::Opayo, opennode::Opennode, payeezy::Payeezy, payme::Payme, paypal::Paypal,
-    payu::Payu, powertranz::Powertranz, rapyd::Rapyd, shift4::Shift4, square::Square, stax::Stax,
-    stripe::Stripe, trustpay::Trustpay, tsys::Tsys, wise::Wise, worldline::Worldline,
-    worldpay::Worldpay, zen::Zen,
+    payu::Payu, powertranz::Powertranz, rapyd::Rapyd, shift4::Shift4, signifyd::Signifyd,
+    square::Square, stax::Stax, stripe::Stripe, threedsecureio::Threedsecureio, trustpay::Trustpay,
+    tsys::Tsys, wise::Wise, worldline::Worldline, worldpay::Worldpay, zen::Zen,
 };
@@ -3423,6 +3423,74 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncrementalAuthorizationRequest {
+    merchant_account: Secret<String>,
+    amount: Amount,
+    reference: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for AdyenIncrementalAuthorizationRequest
+{
+    type Error = Error;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            // Adyen expects the total amount to be authorized, not the increment
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.total_amount,
+            },
+            reference: item.connector_request_reference_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncrementalAuthorizationResponse {
+    psp_reference: String,
+    status: String,
+}
+
+impl
+    TryFrom<
+        types::PaymentsIncrementalAuthorizationResponseRouterData<
+            AdyenIncrementalAuthorizationResponse,
+        >,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            AdyenIncrementalAuthorizationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            // The only value returned is "received", the outcome of the amount update is
+            // available through the AUTHORISATION_ADJUSTMENT notification webhook
+            // For more info: https://docs.adyen.com/online-payments/adjust-authorisation
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status: match item.response.status.as_str() {
+                        "received" => storage_enums::AuthorizationStatus::Processing,
+                        _ => storage_enums::AuthorizationStatus::Unresolved,
+                    },
+                    connector_authorization_id: Some(item.response.psp_reference),
+                    error_code: None,
+                    error_message: None,
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
 /*
 // This is a repeated code block from Stripe inegration. Can we avoid the repetition in every integration
 #[derive(Debug, Serialize, Deserialize)]
@@ -3565,6 +3633,7 @@ pub enum WebhookEventCode {
     PrearbitrationLost,
     Capture,
     CaptureFailed,
+    AuthorisationAdjustment,
     #[serde(other)]
     Unknown,
 }
@@ -3580,6 +3649,10 @@ pub fn is_capture_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
+pub fn is_authorisation_adjustment_event(event_code: &WebhookEventCode) -> bool {
+    matches!(event_code, WebhookEventCode::AuthorisationAdjustment)
+}
+
 pub fn is_refund_event(event_code: &WebhookEventCode) -> bool {
     matches!(
         event_code,
@@ -3601,8 +3674,12 @@ pub fn is_chargeback_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
-impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for webhooks::IncomingWebhookEvent {
-    fn foreign_from((code, status): (WebhookEventCode, Option<DisputeStatus>)) -> Self {
+impl ForeignFrom<(WebhookEventCode, bool, Option<DisputeStatus>)>
+    for webhooks::IncomingWebhookEvent
+{
+    fn foreign_from(
+        (code, is_success, status): (WebhookEventCode, bool, Option<DisputeStatus>),
+    ) -> Self {
         match (code, status) {
             (WebhookEventCode::Authorisation, _) => Self::PaymentIntentSuccess,
             (WebhookEventCode::Refund, _) => Self::RefundSuccess,
@@ -3626,6 +3703,13 @@ impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for webhooks::Incomi
             (WebhookEventCode::Unknown, _) => Self::EventNotSupported,
             (WebhookEventCode::Capture, _) => Self::PaymentIntentSuccess,
             (WebhookEventCode::CaptureFailed, _) => Self::PaymentIntentFailure,
+            (WebhookEventCode::AuthorisationAdjustment, _) => {
+                if is_success {
+                    Self::PaymentIntentAuthorizationSuccess
+                } else {
+                    Self::PaymentIntentAuthorizationFailure
+                }
+            }
         }
     }
 }
@@ -1297,7 +1297,8 @@ impl
                 input: PaymentInput {
                     payment_method_id: match item.router_data.get_payment_method_token()? {
                         types::PaymentMethodToken::Token(token) => token,
-                        types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                        types::PaymentMethodToken::ApplePayDecrypt(_)
+                        | types::PaymentMethodToken::GooglePayDecrypt(_) => {
                             Err(errors::ConnectorError::InvalidWalletToken)?
                         }
                     },
@@ -1385,7 +1386,8 @@ fn get_braintree_redirect_form(
             .expose(),
         card_token: match payment_method_token {
             types::PaymentMethodToken::Token(token) => token,
-            types::PaymentMethodToken::ApplePayDecrypt(_) => {
+            types::PaymentMethodToken::ApplePayDecrypt(_)
+            | types::PaymentMethodToken::GooglePayDecrypt(_) => {
                 Err(errors::ConnectorError::InvalidWalletToken)?
             }
         },
@@ -208,12 +208,30 @@ pub struct PaymentsRequest {
     pub return_url: ReturnUrl,
     pub capture: bool,
     pub reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_type: Option<CheckoutAuthorizationType>,
+}
+
+/// An estimated authorization allows the authorized amount to be increased before capture
+#[derive(Debug, Serialize)]
+pub enum CheckoutAuthorizationType {
+    Final,
+    Estimated,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CheckoutThreeDS {
     enabled: bool,
     force_3ds: bool,
+    /// The fields below carry the result of a 3DS authentication performed outside of Checkout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eci: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cryptogram: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
 }
 
 impl TryFrom<&types::ConnectorAuthType> for CheckoutAuthType {
@@ -256,7 +274,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
                         source_type: CheckoutSourceTypes::Token,
                         token: match item.get_payment_method_token()? {
                             types::PaymentMethodToken::Token(token) => token,
-                            types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                            types::PaymentMethodToken::ApplePayDecrypt(_)
+                            | types::PaymentMethodToken::GooglePayDecrypt(_) => {
                                 Err(errors::ConnectorError::InvalidWalletToken)?
                             }
                         },
@@ -309,14 +328,32 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             }
         }?;
 
-        let three_ds = match item.auth_type {
-            enums::AuthenticationType::ThreeDs => CheckoutThreeDS {
+        let three_ds = match (item.auth_type, &item.request.authentication_data) {
+            (enums::AuthenticationType::ThreeDs, Some(authentication_data)) => CheckoutThreeDS {
+                enabled: true,
+                force_3ds: false,
+                eci: authentication_data.eci.clone(),
+                cryptogram: Some(authentication_data.cavv.clone()),
+                // The directory server transaction ID for 3DS 2, rather than the ID assigned by the
+                // 3DS server
+                xid: authentication_data.ds_trans_id.clone(),
+                version: Some(authentication_data.message_version.clone()),
+            },
+            (enums::AuthenticationType::ThreeDs, None) => CheckoutThreeDS {
                 enabled: true,
                 force_3ds: true,
+                eci: None,
+                cryptogram: None,
+                xid: None,
+                version: None,
             },
-            enums::AuthenticationType::NoThreeDs => CheckoutThreeDS {
+            (enums::AuthenticationType::NoThreeDs, _) => CheckoutThreeDS {
                 enabled: false,
                 force_3ds: false,
+                eci: None,
+                cryptogram: None,
+                xid: None,
+                version: None,
             },
         };
 
@@ -338,6 +375,9 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             Some(enums::CaptureMethod::Automatic)
         );
 
+        let authorization_type = (!capture && item.request.request_incremental_authorization)
+            .then_some(CheckoutAuthorizationType::Estimated);
+
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
@@ -350,6 +390,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             return_url,
             capture,
             reference: item.connector_request_reference_id.clone(),
+            authorization_type,
         })
     }
 }
@@ -736,6 +777,69 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct IncrementalAuthorizationRequest {
+    pub amount: i64,
+    pub reference: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for IncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.additional_amount,
+            reference: item.request.reason.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IncrementalAuthorizationResponse {
+    pub action_id: String,
+    pub approved: Option<bool>,
+    pub response_code: Option<String>,
+    pub response_summary: Option<String>,
+}
+
+impl
+    TryFrom<
+        types::PaymentsIncrementalAuthorizationResponseRouterData<IncrementalAuthorizationResponse>,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            IncrementalAuthorizationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let approved = item.response.approved.unwrap_or(false);
+        let (error_code, error_message) = if approved {
+            (None, None)
+        } else {
+            (item.response.response_code, item.response.response_summary)
+        };
+        Ok(Self {
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status: if approved {
+                        enums::AuthorizationStatus::Success
+                    } else {
+                        enums::AuthorizationStatus::Failure
+                    },
+                    connector_authorization_id: Some(item.response.action_id),
+                    error_code,
+                    error_message,
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RefundRequest {
     amount: Option<i64>,
@@ -4,7 +4,7 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::{self, AddressDetailsData, PhoneDetailsData, RouterData},
+    connector::utils::{self, AddressDetailsData, CardData, PhoneDetailsData, RouterData},
     consts,
     core::errors,
     pii::PeekInterface,
@@ -21,6 +21,25 @@ pub struct CybersourcePaymentsRequest {
     processing_information: ProcessingInformation,
     payment_information: PaymentInformation,
     order_information: OrderInformationWithBill,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    consumer_authentication_information: Option<CybersourceConsumerAuthInformation>,
+}
+
+/// The result of a 3DS authentication performed outside of Cybersource
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceConsumerAuthInformation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cavv: Option<Secret<String>>,
+    /// Mastercard sends the authentication value as UCAF data instead of the CAVV
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ucaf_authentication_data: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ucaf_collection_indicator: Option<String>,
+    eci_raw: Option<String>,
+    xid: String,
+    directory_server_transaction_id: Option<String>,
+    specification_version: String,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -28,6 +47,15 @@ pub struct CybersourcePaymentsRequest {
 pub struct ProcessingInformation {
     capture: bool,
     capture_options: Option<CaptureOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authorization_options: Option<AuthorizationOptions>,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizationOptions {
+    /// `0` marks the authorization as a pre-authorization, whose amount can be increased later
+    auth_indicator: String,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -133,6 +161,36 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
                     bill_to,
                 };
 
+                let consumer_authentication_information = item
+                    .request
+                    .authentication_data
+                    .as_ref()
+                    .map(|authentication_data| {
+                        let is_mastercard =
+                            matches!(ccard.get_card_issuer(), Ok(utils::CardIssuer::Master));
+                        let (cavv, ucaf_authentication_data, ucaf_collection_indicator) =
+                            if is_mastercard {
+                                (
+                                    None,
+                                    Some(authentication_data.cavv.clone()),
+                                    Some("2".to_string()),
+                                )
+                            } else {
+                                (Some(authentication_data.cavv.clone()), None, None)
+                            };
+                        CybersourceConsumerAuthInformation {
+                            cavv,
+                            ucaf_authentication_data,
+                            ucaf_collection_indicator,
+                            eci_raw: authentication_data.eci.clone(),
+                            xid: authentication_data.threeds_server_transaction_id.clone(),
+                            directory_server_transaction_id: authentication_data
+                                .ds_trans_id
+                                .clone(),
+                            specification_version: authentication_data.message_version.clone(),
+                        }
+                    });
+
                 let payment_information = PaymentInformation {
                     card: Card {
                         number: ccard.card_number,
@@ -142,18 +200,25 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
                     },
                 };
 
+                let capture = matches!(
+                    item.request.capture_method,
+                    Some(enums::CaptureMethod::Automatic) | None
+                );
                 let processing_information = ProcessingInformation {
-                    capture: matches!(
-                        item.request.capture_method,
-                        Some(enums::CaptureMethod::Automatic) | None
-                    ),
+                    capture,
                     capture_options: None,
+                    authorization_options: (!capture
+                        && item.request.request_incremental_authorization)
+                        .then(|| AuthorizationOptions {
+                            auth_indicator: "0".to_string(),
+                        }),
                 };
 
                 Ok(Self {
                     processing_information,
                     payment_information,
                     order_information,
+                    consumer_authentication_information,
                 })
             }
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
@@ -200,6 +265,43 @@ impl TryFrom<&types::RefundExecuteRouterData> for CybersourcePaymentsRequest {
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceIncrementalAuthorizationRequest {
+    order_information: IncrementalAuthorizationOrderInformation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncrementalAuthorizationOrderInformation {
+    amount_details: AdditionalAmount,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditionalAmount {
+    additional_amount: String,
+    currency: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for CybersourceIncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        value: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            order_information: IncrementalAuthorizationOrderInformation {
+                amount_details: AdditionalAmount {
+                    additional_amount: value.request.additional_amount.to_string(),
+                    currency: value.request.currency.to_string().to_uppercase(),
+                },
+            },
+        })
+    }
+}
+
 pub struct CybersourceAuthType {
     pub(super) api_key: Secret<String>,
     pub(super) merchant_account: Secret<String>,
@@ -326,6 +428,49 @@ impl<F, T>
     }
 }
 
+impl TryFrom<types::PaymentsIncrementalAuthorizationResponseRouterData<CybersourcePaymentsResponse>>
+    for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            CybersourcePaymentsResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.status {
+            CybersourcePaymentStatus::Authorized
+            | CybersourcePaymentStatus::AuthorizedPendingReview => {
+                enums::AuthorizationStatus::Success
+            }
+            CybersourcePaymentStatus::Failed | CybersourcePaymentStatus::Declined => {
+                enums::AuthorizationStatus::Failure
+            }
+            CybersourcePaymentStatus::Processing | CybersourcePaymentStatus::Pending => {
+                enums::AuthorizationStatus::Processing
+            }
+            CybersourcePaymentStatus::Succeeded
+            | CybersourcePaymentStatus::Transmitted
+            | CybersourcePaymentStatus::Voided
+            | CybersourcePaymentStatus::Reversed => enums::AuthorizationStatus::Unresolved,
+        };
+        let (error_code, error_message) = match item.response.error_information {
+            Some(error) => (Some(error.reason), Some(error.message)),
+            None => (None, None),
+        };
+        Ok(Self {
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status,
+                    connector_authorization_id: Some(item.response.id),
+                    error_code,
+                    error_message,
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CybersourceTransactionResponse {
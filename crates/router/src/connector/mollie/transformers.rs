@@ -139,7 +139,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for MolliePaymentsRequest {
                             shipping_address: get_shipping_details(item)?,
                             card_token: Some(Secret::new(match pm_token {
                                 types::PaymentMethodToken::Token(token) => token,
-                                types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                                types::PaymentMethodToken::ApplePayDecrypt(_)
+                                | types::PaymentMethodToken::GooglePayDecrypt(_) => {
                                     Err(errors::ConnectorError::InvalidWalletToken)?
                                 }
                             })),
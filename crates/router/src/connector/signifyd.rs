@@ -0,0 +1,260 @@
+pub mod transformers;
+
+use std::fmt::Debug;
+
+use base64::Engine;
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use transformers as signifyd;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult},
+    headers,
+    services::{
+        self,
+        request::{self, Mask},
+        ConnectorIntegration,
+    },
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Signifyd;
+
+impl api::fraud_check::FraudCheckCheckout for Signifyd {}
+impl api::fraud_check::FraudCheckTransaction for Signifyd {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Signifyd
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.common_get_content_type().to_string().into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+}
+
+impl ConnectorCommon for Signifyd {
+    fn id(&self) -> &'static str {
+        "signifyd"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.signifyd.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let auth = signifyd::SignifydAuthType::try_from(auth_type)?;
+        let auth_api_key = format!(
+            "Basic {}",
+            consts::BASE64_ENGINE.encode(auth.api_key.peek())
+        );
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            auth_api_key.into_masked(),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: signifyd::SignifydErrorResponse = res
+            .response
+            .parse_struct("SignifydErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse::from((response, res.status_code)))
+    }
+}
+
+impl
+    ConnectorIntegration<
+        api::fraud_check::Checkout,
+        types::FraudCheckCheckoutData,
+        types::FraudCheckResponseData,
+    > for Signifyd
+{
+    fn get_headers(
+        &self,
+        req: &types::FrmCheckoutRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FrmCheckoutRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v3/orders/events/checkouts",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FrmCheckoutRouterData,
+    ) -> CustomResult<Option<types::RequestBody>, errors::ConnectorError> {
+        let req_obj = signifyd::SignifydCheckoutRequest::try_from(req)?;
+        let signifyd_req = types::RequestBody::log_and_get_request_body(
+            &req_obj,
+            utils::Encode::<signifyd::SignifydCheckoutRequest>::encode_to_string_of_json,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(signifyd_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FrmCheckoutRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::FrmCheckoutType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FrmCheckoutType::get_headers(self, req, connectors)?)
+                .body(types::FrmCheckoutType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FrmCheckoutRouterData,
+        res: Response,
+    ) -> CustomResult<types::FrmCheckoutRouterData, errors::ConnectorError> {
+        let response: signifyd::SignifydDecisionResponse = res
+            .response
+            .parse_struct("SignifydDecisionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        api::fraud_check::Transaction,
+        types::FraudCheckTransactionData,
+        types::FraudCheckResponseData,
+    > for Signifyd
+{
+    fn get_headers(
+        &self,
+        req: &types::FrmTransactionRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FrmTransactionRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v3/orders/events/transactions",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FrmTransactionRouterData,
+    ) -> CustomResult<Option<types::RequestBody>, errors::ConnectorError> {
+        let req_obj = signifyd::SignifydTransactionRequest::try_from(req)?;
+        let signifyd_req = types::RequestBody::log_and_get_request_body(
+            &req_obj,
+            utils::Encode::<signifyd::SignifydTransactionRequest>::encode_to_string_of_json,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(signifyd_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FrmTransactionRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::FrmTransactionType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FrmTransactionType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::FrmTransactionType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FrmTransactionRouterData,
+        res: Response,
+    ) -> CustomResult<types::FrmTransactionRouterData, errors::ConnectorError> {
+        let response: signifyd::SignifydDecisionResponse = res
+            .response
+            .parse_struct("SignifydDecisionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
@@ -0,0 +1,251 @@
+use common_utils::pii::Email;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    connector::utils,
+    core::errors,
+    types::{self, storage::enums},
+};
+
+pub struct SignifydAuthType {
+    pub(super) api_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for SignifydAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydOrderChannel {
+    Web,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydPurchase {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    order_channel: SignifydOrderChannel,
+    total_price: f64,
+    currency: enums::Currency,
+    confirmation_email: Option<Email>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydDevice {
+    client_ip_address: Option<Secret<String, common_utils::pii::IpAddress>>,
+}
+
+/// The payment is checked under the ID of the payment as the order, and the ID of its attempt as
+/// the checkout
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydCheckoutRequest {
+    order_id: String,
+    checkout_id: String,
+    purchase: SignifydPurchase,
+    device: Option<SignifydDevice>,
+}
+
+impl TryFrom<&types::FrmCheckoutRouterData> for SignifydCheckoutRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FrmCheckoutRouterData) -> Result<Self, Self::Error> {
+        let currency =
+            item.request
+                .currency
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "currency",
+                })?;
+        Ok(Self {
+            order_id: item.payment_id.clone(),
+            checkout_id: item.attempt_id.clone(),
+            purchase: SignifydPurchase {
+                created_at: common_utils::date_time::now(),
+                order_channel: SignifydOrderChannel::Web,
+                total_price: utils::to_currency_base_unit_asf64(item.request.amount, currency)?,
+                currency,
+                confirmation_email: item.request.email.clone(),
+            },
+            device: item
+                .request
+                .browser_info
+                .as_ref()
+                .map(|browser_info| SignifydDevice {
+                    client_ip_address: browser_info
+                        .ip_address
+                        .map(|ip_address| Secret::new(ip_address.to_string())),
+                }),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydGatewayStatusCode {
+    Success,
+    Failure,
+    Pending,
+}
+
+impl From<enums::AttemptStatus> for SignifydGatewayStatusCode {
+    fn from(attempt_status: enums::AttemptStatus) -> Self {
+        match attempt_status {
+            enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged => Self::Success,
+            enums::AttemptStatus::Failure
+            | enums::AttemptStatus::AuthorizationFailed
+            | enums::AttemptStatus::AuthenticationFailed
+            | enums::AttemptStatus::RouterDeclined
+            | enums::AttemptStatus::Voided
+            | enums::AttemptStatus::VoidFailed
+            | enums::AttemptStatus::CaptureFailed => Self::Failure,
+            _ => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydTransaction {
+    transaction_id: Option<String>,
+    gateway_status_code: SignifydGatewayStatusCode,
+    gateway_error_code: Option<String>,
+    gateway_status_message: Option<String>,
+    amount: f64,
+    currency: enums::Currency,
+    gateway: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydTransactionRequest {
+    order_id: String,
+    checkout_id: String,
+    transactions: Vec<SignifydTransaction>,
+}
+
+impl TryFrom<&types::FrmTransactionRouterData> for SignifydTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FrmTransactionRouterData) -> Result<Self, Self::Error> {
+        let currency =
+            item.request
+                .currency
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "currency",
+                })?;
+        Ok(Self {
+            order_id: item.payment_id.clone(),
+            checkout_id: item.attempt_id.clone(),
+            transactions: vec![SignifydTransaction {
+                transaction_id: item.request.connector_transaction_id.clone(),
+                gateway_status_code: item.request.attempt_status.into(),
+                gateway_error_code: item.request.error_code.clone(),
+                gateway_status_message: item.request.error_message.clone(),
+                amount: utils::to_currency_base_unit_asf64(item.request.amount, currency)?,
+                currency,
+                gateway: item.request.gateway.clone(),
+            }],
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydCheckpointAction {
+    Accept,
+    Reject,
+    Hold,
+    /// Actions introduced after this integration are held for a review as well
+    #[serde(other)]
+    Pause,
+}
+
+impl From<SignifydCheckpointAction> for enums::FraudCheckStatus {
+    fn from(checkpoint_action: SignifydCheckpointAction) -> Self {
+        match checkpoint_action {
+            SignifydCheckpointAction::Accept => Self::Legit,
+            SignifydCheckpointAction::Reject => Self::Fraud,
+            SignifydCheckpointAction::Hold | SignifydCheckpointAction::Pause => Self::ManualReview,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydDecision {
+    pub checkpoint_action: SignifydCheckpointAction,
+    pub checkpoint_action_reason: Option<String>,
+    /// The likelihood of the order being legitimate, between 0 and 1000
+    pub score: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydDecisionResponse {
+    pub signifyd_id: i64,
+    pub order_id: String,
+    pub decision: SignifydDecision,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, SignifydDecisionResponse, T, types::FraudCheckResponseData>,
+    > for types::RouterData<F, T, types::FraudCheckResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            SignifydDecisionResponse,
+            T,
+            types::FraudCheckResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let decision = item.response.decision;
+        // The score is bounded, and hence cannot overflow
+        #[allow(clippy::as_conversions)]
+        let score = decision.score.map(|score| score.round() as i32);
+        Ok(Self {
+            response: Ok(types::FraudCheckResponseData {
+                transaction_id: Some(item.response.signifyd_id.to_string()),
+                status: decision.checkpoint_action.into(),
+                score,
+                reason: decision
+                    .checkpoint_action_reason
+                    .map(serde_json::Value::String),
+                connector_metadata: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignifydErrorResponse {
+    pub messages: Vec<String>,
+    pub errors: Option<serde_json::Value>,
+}
+
+impl From<(SignifydErrorResponse, u16)> for types::ErrorResponse {
+    fn from((error_response, status_code): (SignifydErrorResponse, u16)) -> Self {
+        Self {
+            code: status_code.to_string(),
+            message: error_response.messages.join(", "),
+            reason: error_response.errors.map(|errors| errors.to_string()),
+            status_code,
+        }
+    }
+}
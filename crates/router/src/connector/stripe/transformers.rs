@@ -447,6 +447,7 @@ pub enum StripeWallet {
     AlipayPayment(AlipayPayment),
     Cashapp(CashappPayment),
     ApplePayPredecryptToken(Box<StripeApplePayPredecrypt>),
+    GooglePayPredecryptToken(Box<StripeGooglePayPredecrypt>),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -465,6 +466,22 @@ pub struct StripeApplePayPredecrypt {
     tokenization_method: String,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeGooglePayPredecrypt {
+    #[serde(rename = "card[number]")]
+    number: Secret<String>,
+    #[serde(rename = "card[exp_year]")]
+    exp_year: Secret<u16>,
+    #[serde(rename = "card[exp_month]")]
+    exp_month: Secret<u8>,
+    #[serde(rename = "card[cryptogram]")]
+    cryptogram: Option<Secret<String>>,
+    #[serde(rename = "card[eci]")]
+    eci: Option<Secret<String>>,
+    #[serde(rename = "card[tokenization_method]")]
+    tokenization_method: String,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 pub struct StripeApplePay {
     pub pk_token: Secret<String>,
@@ -1388,11 +1405,30 @@ fn create_stripe_payment_method(
                 Some(StripePaymentMethodType::Cashapp),
                 StripeBillingAddress::default(),
             )),
-            payments::WalletData::GooglePay(gpay_data) => Ok((
-                StripePaymentMethodData::try_from(gpay_data)?,
-                Some(StripePaymentMethodType::Card),
-                StripeBillingAddress::default(),
-            )),
+            payments::WalletData::GooglePay(gpay_data) => {
+                let pmd = if let Some(types::PaymentMethodToken::GooglePayDecrypt(decrypt_data)) =
+                    payment_method_token
+                {
+                    let payment_method_details = decrypt_data.payment_method_details;
+                    StripePaymentMethodData::Wallet(StripeWallet::GooglePayPredecryptToken(
+                        Box::new(StripeGooglePayPredecrypt {
+                            number: payment_method_details.pan,
+                            exp_year: payment_method_details.expiration_year,
+                            exp_month: payment_method_details.expiration_month,
+                            cryptogram: payment_method_details.cryptogram,
+                            eci: payment_method_details.eci_indicator,
+                            tokenization_method: "google_pay".to_string(),
+                        }),
+                    ))
+                } else {
+                    StripePaymentMethodData::try_from(gpay_data)?
+                };
+                Ok((
+                    pmd,
+                    Some(StripePaymentMethodType::Card),
+                    StripeBillingAddress::default(),
+                ))
+            }
             payments::WalletData::AliPayQr(_)
             | payments::WalletData::AliPayHkRedirect(_)
             | payments::WalletData::MomoRedirect(_)
@@ -1648,7 +1684,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                     .change_context(errors::ConnectorError::RequestEncodingFailed)?;
                 let payment_method_token = match payment_method_token {
                     types::PaymentMethodToken::Token(payment_method_token) => payment_method_token,
-                    types::PaymentMethodToken::ApplePayDecrypt(_) => {
+                    types::PaymentMethodToken::ApplePayDecrypt(_)
+                    | types::PaymentMethodToken::GooglePayDecrypt(_) => {
                         Err(errors::ConnectorError::InvalidWalletToken)?
                     }
                 };
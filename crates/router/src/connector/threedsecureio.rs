@@ -0,0 +1,339 @@
+pub mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::ResultExt;
+use masking::ExposeInterface;
+use transformers as threedsecureio;
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    headers,
+    services::{
+        self,
+        request::{self, Mask},
+        ConnectorIntegration,
+    },
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Threedsecureio;
+
+impl api::authentication::ConnectorPreAuthentication for Threedsecureio {}
+impl api::authentication::ConnectorAuthentication for Threedsecureio {}
+impl api::authentication::ConnectorPostAuthentication for Threedsecureio {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Threedsecureio
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.common_get_content_type().to_string().into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+}
+
+impl ConnectorCommon for Threedsecureio {
+    fn id(&self) -> &'static str {
+        "threedsecureio"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json; charset=utf-8"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.threedsecureio.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let auth = threedsecureio::ThreedsecureioAuthType::try_from(auth_type)?;
+        Ok(vec![(
+            headers::APIKEY.to_string(),
+            auth.api_key.expose().into_masked(),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: threedsecureio::ThreedsecureioErrorResponse = res
+            .response
+            .parse_struct("ThreedsecureioErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse::from((response, res.status_code)))
+    }
+}
+
+impl
+    ConnectorIntegration<
+        api::authentication::PreAuthentication,
+        types::PreAuthNRequestData,
+        types::AuthenticationResponseData,
+    > for Threedsecureio
+{
+    fn get_headers(
+        &self,
+        req: &types::PreAuthNRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PreAuthNRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}preauth", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PreAuthNRouterData,
+    ) -> CustomResult<Option<types::RequestBody>, errors::ConnectorError> {
+        let req_obj = threedsecureio::ThreedsecureioPreAuthenticationRequest::from(req);
+        let threedsecureio_req = types::RequestBody::log_and_get_request_body(
+            &req_obj,
+            utils::Encode::<threedsecureio::ThreedsecureioPreAuthenticationRequest>::encode_to_string_of_json,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(threedsecureio_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PreAuthNRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PreAuthNType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PreAuthNType::get_headers(self, req, connectors)?)
+                .body(types::PreAuthNType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PreAuthNRouterData,
+        res: Response,
+    ) -> CustomResult<types::PreAuthNRouterData, errors::ConnectorError> {
+        let response: threedsecureio::ThreedsecureioPreAuthenticationResponse = res
+            .response
+            .parse_struct("ThreedsecureioPreAuthenticationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        api::authentication::Authentication,
+        types::ConnectorAuthenticationRequestData,
+        types::AuthenticationResponseData,
+    > for Threedsecureio
+{
+    fn get_headers(
+        &self,
+        req: &types::ConnectorAuthenticationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::ConnectorAuthenticationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}auth", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::ConnectorAuthenticationRouterData,
+    ) -> CustomResult<Option<types::RequestBody>, errors::ConnectorError> {
+        let req_obj = threedsecureio::ThreedsecureioAuthenticationRequest::try_from(req)?;
+        let threedsecureio_req = types::RequestBody::log_and_get_request_body(
+            &req_obj,
+            utils::Encode::<threedsecureio::ThreedsecureioAuthenticationRequest>::encode_to_string_of_json,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(threedsecureio_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::ConnectorAuthenticationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::ConnectorAuthenticationType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::ConnectorAuthenticationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::ConnectorAuthenticationType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::ConnectorAuthenticationRouterData,
+        res: Response,
+    ) -> CustomResult<types::ConnectorAuthenticationRouterData, errors::ConnectorError> {
+        let response: threedsecureio::ThreedsecureioAuthenticationResponse = res
+            .response
+            .parse_struct("ThreedsecureioAuthenticationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        api::authentication::PostAuthentication,
+        types::ConnectorPostAuthenticationRequestData,
+        types::AuthenticationResponseData,
+    > for Threedsecureio
+{
+    fn get_headers(
+        &self,
+        req: &types::ConnectorPostAuthenticationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::ConnectorPostAuthenticationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}postauth", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::ConnectorPostAuthenticationRouterData,
+    ) -> CustomResult<Option<types::RequestBody>, errors::ConnectorError> {
+        let req_obj = threedsecureio::ThreedsecureioPostAuthenticationRequest::from(req);
+        let threedsecureio_req = types::RequestBody::log_and_get_request_body(
+            &req_obj,
+            utils::Encode::<threedsecureio::ThreedsecureioPostAuthenticationRequest>::encode_to_string_of_json,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(threedsecureio_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::ConnectorPostAuthenticationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::ConnectorPostAuthenticationType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::ConnectorPostAuthenticationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::ConnectorPostAuthenticationType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::ConnectorPostAuthenticationRouterData,
+        res: Response,
+    ) -> CustomResult<types::ConnectorPostAuthenticationRouterData, errors::ConnectorError> {
+        let response: threedsecureio::ThreedsecureioPostAuthenticationResponse = res
+            .response
+            .parse_struct("ThreedsecureioPostAuthenticationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
@@ -0,0 +1,454 @@
+use base64::Engine;
+use common_utils::date_time;
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{self, BrowserInformationData, CardData},
+    core::errors,
+    types::{self, api, storage::enums},
+    utils::Encode,
+};
+
+/// The 3DS protocol encodes the 3DS method data and challenge request as base64url without padding
+const BASE64_ENGINE_URL_SAFE_NO_PAD: base64::engine::GeneralPurpose =
+    base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+pub struct ThreedsecureioAuthType {
+    pub(super) api_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for ThreedsecureioAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// Acquirer and 3DS requestor details configured in the merchant connector account metadata
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThreedsecureioMetaData {
+    pub mcc: String,
+    /// The ISO 3166-1 numeric country code of the merchant
+    pub merchant_country_code: String,
+    pub merchant_name: String,
+    pub acquirer_bin: String,
+    pub acquirer_merchant_id: String,
+    pub three_ds_requestor_id: String,
+    pub three_ds_requestor_name: String,
+    pub three_ds_requestor_url: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreedsecureioPreAuthenticationRequest {
+    acct_number: cards::CardNumber,
+}
+
+impl From<&types::PreAuthNRouterData> for ThreedsecureioPreAuthenticationRequest {
+    fn from(item: &types::PreAuthNRouterData) -> Self {
+        Self {
+            acct_number: item.request.card_holder_account_number.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThreedsecureioPreAuthenticationResponse {
+    #[serde(rename = "threeDSServerTransID")]
+    pub threeds_server_trans_id: String,
+    #[serde(rename = "acsEndProtocolVersion")]
+    pub acs_end_protocol_version: String,
+    #[serde(rename = "threeDSMethodURL")]
+    pub threeds_method_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ThreeDsMethodData {
+    #[serde(rename = "threeDSServerTransID")]
+    threeds_server_trans_id: String,
+    #[serde(rename = "threeDSMethodNotificationURL")]
+    threeds_method_notification_url: String,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::authentication::PreAuthentication,
+            ThreedsecureioPreAuthenticationResponse,
+            types::PreAuthNRequestData,
+            types::AuthenticationResponseData,
+        >,
+    > for types::PreAuthNRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::authentication::PreAuthentication,
+            ThreedsecureioPreAuthenticationResponse,
+            types::PreAuthNRequestData,
+            types::AuthenticationResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let three_ds_method_data = item
+            .response
+            .threeds_method_url
+            .as_ref()
+            .map(|_| {
+                let method_data = ThreeDsMethodData {
+                    threeds_server_trans_id: item.response.threeds_server_trans_id.clone(),
+                    threeds_method_notification_url: item
+                        .data
+                        .request
+                        .three_ds_method_notification_url
+                        .clone(),
+                };
+                Encode::<ThreeDsMethodData>::encode_to_string_of_json(&method_data)
+                    .change_context(errors::ConnectorError::RequestEncodingFailed)
+                    .map(|json| BASE64_ENGINE_URL_SAFE_NO_PAD.encode(json))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            response: Ok(types::AuthenticationResponseData::PreAuthNResponse {
+                threeds_server_transaction_id: item.response.threeds_server_trans_id,
+                message_version: item.response.acs_end_protocol_version,
+                three_ds_method_url: item.response.threeds_method_url,
+                three_ds_method_data,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreedsecureioAuthenticationRequest {
+    acct_number: cards::CardNumber,
+    #[serde(rename = "acquirerBIN")]
+    acquirer_bin: String,
+    #[serde(rename = "acquirerMerchantID")]
+    acquirer_merchant_id: String,
+    bill_addr_city: Option<String>,
+    bill_addr_country: Option<String>,
+    bill_addr_line1: Option<Secret<String>>,
+    bill_addr_post_code: Option<Secret<String>>,
+    bill_addr_state: Option<Secret<String>>,
+    browser_accept_header: String,
+    #[serde(rename = "browserIP")]
+    browser_ip: Secret<String, common_utils::pii::IpAddress>,
+    browser_java_enabled: bool,
+    browser_javascript_enabled: bool,
+    browser_language: String,
+    browser_color_depth: String,
+    browser_screen_height: String,
+    browser_screen_width: String,
+    #[serde(rename = "browserTZ")]
+    browser_tz: String,
+    browser_user_agent: String,
+    card_expiry_date: Secret<String>,
+    cardholder_name: Secret<String>,
+    device_channel: String,
+    email: Option<common_utils::pii::Email>,
+    mcc: String,
+    merchant_country_code: String,
+    merchant_name: String,
+    message_category: String,
+    message_type: String,
+    message_version: String,
+    #[serde(rename = "notificationURL")]
+    notification_url: String,
+    purchase_amount: String,
+    purchase_currency: String,
+    purchase_date: String,
+    purchase_exponent: u8,
+    #[serde(rename = "threeDSCompInd")]
+    three_ds_comp_ind: enums::ThreeDsCompletionIndicator,
+    #[serde(rename = "threeDSRequestorAuthenticationInd")]
+    three_ds_requestor_authentication_ind: String,
+    #[serde(rename = "threeDSRequestorID")]
+    three_ds_requestor_id: String,
+    #[serde(rename = "threeDSRequestorName")]
+    three_ds_requestor_name: String,
+    #[serde(rename = "threeDSRequestorURL")]
+    three_ds_requestor_url: String,
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: String,
+}
+
+/// The number of digits after the decimal point of the currency, as expected by the 3DS protocol
+fn get_purchase_exponent(currency: enums::Currency) -> u8 {
+    if currency.is_zero_decimal_currency() {
+        0
+    } else if currency.is_three_decimal_currency() {
+        3
+    } else {
+        2
+    }
+}
+
+impl TryFrom<&types::ConnectorAuthenticationRouterData> for ThreedsecureioAuthenticationRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorAuthenticationRouterData) -> Result<Self, Self::Error> {
+        let card = match &item.request.payment_method_data {
+            api::PaymentMethodData::Card(card) => Ok(card),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("threedsecureio"),
+            )),
+        }?;
+        let meta_data: ThreedsecureioMetaData =
+            utils::to_connector_meta_from_secret(item.connector_meta_data.clone())?;
+        let browser_details = item
+            .request
+            .browser_details
+            .as_ref()
+            .ok_or_else(utils::missing_field_err("browser_info"))?;
+        let billing_address = item
+            .address
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref());
+        let purchase_date =
+            date_time::format_date(date_time::now(), date_time::DateFormat::YYYYMMDDHHmmss)
+                .into_report()
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+        Ok(Self {
+            acct_number: card.card_number.clone(),
+            acquirer_bin: meta_data.acquirer_bin,
+            acquirer_merchant_id: meta_data.acquirer_merchant_id,
+            bill_addr_city: billing_address.and_then(|address| address.city.clone()),
+            bill_addr_country: billing_address
+                .and_then(|address| address.country)
+                .map(|country| {
+                    format!(
+                        "{:03}",
+                        common_enums::Country::from_alpha2(country).to_numeric()
+                    )
+                }),
+            bill_addr_line1: billing_address.and_then(|address| address.line1.clone()),
+            bill_addr_post_code: billing_address.and_then(|address| address.zip.clone()),
+            bill_addr_state: billing_address.and_then(|address| address.state.clone()),
+            browser_accept_header: browser_details.get_accept_header()?,
+            browser_ip: browser_details.get_ip_address()?,
+            browser_java_enabled: browser_details.get_java_enabled()?,
+            browser_javascript_enabled: browser_details.get_java_script_enabled()?,
+            browser_language: browser_details.get_language()?,
+            browser_color_depth: browser_details.get_color_depth()?.to_string(),
+            browser_screen_height: browser_details.get_screen_height()?.to_string(),
+            browser_screen_width: browser_details.get_screen_width()?.to_string(),
+            browser_tz: browser_details.get_time_zone()?.to_string(),
+            browser_user_agent: browser_details.get_user_agent()?,
+            card_expiry_date: card.get_expiry_date_as_yymm(),
+            cardholder_name: card.card_holder_name.clone(),
+            // Browser based authentication
+            device_channel: "02".to_string(),
+            email: item.request.email.clone(),
+            mcc: meta_data.mcc,
+            merchant_country_code: meta_data.merchant_country_code,
+            merchant_name: meta_data.merchant_name,
+            // Payment authentication
+            message_category: "01".to_string(),
+            message_type: "AReq".to_string(),
+            message_version: item.request.message_version.clone(),
+            notification_url: item.request.notification_url.clone(),
+            purchase_amount: item.request.amount.to_string(),
+            purchase_currency: item.request.currency.iso_4217().to_string(),
+            purchase_date,
+            purchase_exponent: get_purchase_exponent(item.request.currency),
+            three_ds_comp_ind: item.request.three_ds_method_comp_ind,
+            // Payment transaction
+            three_ds_requestor_authentication_ind: "01".to_string(),
+            three_ds_requestor_id: meta_data.three_ds_requestor_id,
+            three_ds_requestor_name: meta_data.three_ds_requestor_name,
+            three_ds_requestor_url: meta_data.three_ds_requestor_url,
+            three_ds_server_trans_id: item.request.threeds_server_transaction_id.clone(),
+        })
+    }
+}
+
+/// The authentication response (`ARes`), or an error message when the 3DS server, directory
+/// server or ACS rejected the request
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ThreedsecureioAuthenticationResponse {
+    Success(Box<ThreedsecureioAuthenticationSuccessResponse>),
+    Error(Box<ThreedsecureioErrorResponse>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreedsecureioAuthenticationSuccessResponse {
+    pub trans_status: enums::TransactionStatus,
+    pub authentication_value: Option<String>,
+    pub eci: Option<String>,
+    #[serde(rename = "acsURL")]
+    pub acs_url: Option<String>,
+    #[serde(rename = "acsTransID")]
+    pub acs_trans_id: String,
+    #[serde(rename = "dsTransID")]
+    pub ds_trans_id: Option<String>,
+    pub message_version: String,
+    #[serde(rename = "threeDSServerTransID")]
+    pub three_ds_server_trans_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreedsecureioChallengeRequest {
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: String,
+    #[serde(rename = "acsTransID")]
+    acs_trans_id: String,
+    message_type: String,
+    message_version: String,
+    challenge_window_size: String,
+}
+
+fn get_challenge_request(
+    response: &ThreedsecureioAuthenticationSuccessResponse,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    let challenge_request = ThreedsecureioChallengeRequest {
+        three_ds_server_trans_id: response.three_ds_server_trans_id.clone(),
+        acs_trans_id: response.acs_trans_id.clone(),
+        message_type: "CReq".to_string(),
+        message_version: response.message_version.clone(),
+        // Full screen challenge window
+        challenge_window_size: "05".to_string(),
+    };
+    Encode::<ThreedsecureioChallengeRequest>::encode_to_string_of_json(&challenge_request)
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+        .map(|json| BASE64_ENGINE_URL_SAFE_NO_PAD.encode(json))
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            ThreedsecureioAuthenticationResponse,
+            T,
+            types::AuthenticationResponseData,
+        >,
+    > for types::RouterData<F, T, types::AuthenticationResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            ThreedsecureioAuthenticationResponse,
+            T,
+            types::AuthenticationResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response {
+            ThreedsecureioAuthenticationResponse::Success(response) => {
+                let authn_flow_type = if response.trans_status.is_challenge_required() {
+                    let acs_url = response.acs_url.clone().ok_or(
+                        errors::ConnectorError::MissingConnectorRedirectionPayload {
+                            field_name: "acsURL",
+                        },
+                    )?;
+                    types::AuthNFlowType::Challenge(Box::new(types::ChallengeParams {
+                        acs_url,
+                        challenge_request: get_challenge_request(&response)?,
+                    }))
+                } else {
+                    types::AuthNFlowType::Frictionless
+                };
+                Ok(types::AuthenticationResponseData::AuthNResponse {
+                    authn_flow_type,
+                    trans_status: response.trans_status,
+                    authentication_value: response.authentication_value,
+                    eci: response.eci,
+                    ds_trans_id: response.ds_trans_id,
+                })
+            }
+            ThreedsecureioAuthenticationResponse::Error(error_response) => Err(
+                types::ErrorResponse::from((*error_response, item.http_code)),
+            ),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ThreedsecureioPostAuthenticationRequest {
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: String,
+}
+
+impl From<&types::ConnectorPostAuthenticationRouterData>
+    for ThreedsecureioPostAuthenticationRequest
+{
+    fn from(item: &types::ConnectorPostAuthenticationRouterData) -> Self {
+        Self {
+            three_ds_server_trans_id: item.request.threeds_server_transaction_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreedsecureioPostAuthenticationResponse {
+    pub trans_status: enums::TransactionStatus,
+    pub authentication_value: Option<String>,
+    pub eci: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            ThreedsecureioPostAuthenticationResponse,
+            T,
+            types::AuthenticationResponseData,
+        >,
+    > for types::RouterData<F, T, types::AuthenticationResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            ThreedsecureioPostAuthenticationResponse,
+            T,
+            types::AuthenticationResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AuthenticationResponseData::PostAuthNResponse {
+                trans_status: item.response.trans_status,
+                authentication_value: item.response.authentication_value,
+                eci: item.response.eci,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreedsecureioErrorResponse {
+    pub error_code: String,
+    pub error_description: String,
+    pub error_detail: Option<String>,
+}
+
+impl From<(ThreedsecureioErrorResponse, u16)> for types::ErrorResponse {
+    fn from((error_response, status_code): (ThreedsecureioErrorResponse, u16)) -> Self {
+        Self {
+            code: error_response.error_code,
+            message: error_response.error_description,
+            reason: error_response.error_detail,
+            status_code,
+        }
+    }
+}
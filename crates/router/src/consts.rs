@@ -13,6 +13,9 @@ pub(crate) const ALPHABETS: [char; 62] = [
 pub const REQUEST_TIME_OUT: u64 = 30;
 pub const REQUEST_TIMEOUT_ERROR_CODE: &str = "TIMEOUT";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE: &str = "Connector did not respond in specified time";
+pub const CONNECTION_CLOSED_ERROR_CODE: &str = "CONNECTION_CLOSED";
+pub const CONNECTION_CLOSED_ERROR_MESSAGE: &str =
+    "Connection was closed before the connector response was received";
 
 ///Payment intent fulfillment default timeout (in seconds)
 pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
@@ -30,6 +33,7 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+pub(crate) const FINGERPRINT_SECRET_LENGTH: usize = 64;
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
 
 // Apple Pay validation url
@@ -44,4 +48,8 @@ pub(crate) const QR_IMAGE_DATA_SOURCE_STRING: &str = "data:image/png;base64";
 #[cfg(feature = "kms")]
 pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32";
 
+// Sender ID and protocol version with which the Google Pay payment tokens are signed
+pub(crate) const GOOGLE_PAY_SENDER_ID: &str = "Google";
+pub(crate) const GOOGLE_PAY_PROTOCOL_VERSION: &str = "ECv2";
+
 pub(crate) const METRICS_HOST_TAG_NAME: &str = "host";
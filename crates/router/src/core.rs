@@ -1,5 +1,7 @@
 pub mod admin;
 pub mod api_keys;
+pub mod audit_events;
+pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
@@ -9,6 +11,7 @@ pub mod errors;
 pub mod files;
 pub mod mandate;
 pub mod metrics;
+pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
@@ -1,19 +1,24 @@
+use std::str::FromStr;
+
 use api_models::{admin as admin_types, enums as api_enums};
 use common_utils::{
-    crypto::{generate_cryptographically_secure_random_string, OptionalSecretValue},
+    crypto::{generate_cryptographically_secure_random_string, Encryptable, OptionalSecretValue},
     date_time,
     ext_traits::{AsyncExt, ConfigExt, Encode, ValueExt},
 };
 use data_models::MerchantStorageScheme;
 use error_stack::{report, FutureExt, ResultExt};
 use masking::{PeekInterface, Secret};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
 use uuid::Uuid;
 
 use crate::{
     consts,
     core::{
+        audit_events::{self, AuditActor, AuditTarget},
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
-        payments::helpers,
+        payment_methods::vault,
+        payments::{self, helpers},
         utils as core_utils,
     },
     db::StorageInterface,
@@ -26,11 +31,58 @@ use crate::{
             types::{self as domain_types, AsyncLift},
         },
         storage,
-        transformers::ForeignTryFrom,
+        transformers::{ForeignFrom, ForeignTryFrom},
     },
     utils::{self, OptionExt},
 };
 
+fn validate_routing_algorithm(routing_algorithm: &serde_json::Value) -> RouterResult<()> {
+    let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "routing_algorithm",
+        })
+        .attach_printable("Invalid routing algorithm given")?;
+
+    payments::routing::validate_routing_algorithm(&routing_algorithm)
+        .map_err(|error| report!(error))
+}
+
+/// The colors of the hosted checkout page are used in its styles, so only hex codes are allowed
+fn validate_payment_link_config(
+    payment_link_config: &api_models::admin::PaymentLinkConfig,
+) -> RouterResult<()> {
+    let is_hex_color = |color: &String| {
+        color.len() == 7
+            && color.starts_with('#')
+            && color.chars().skip(1).all(|c| c.is_ascii_hexdigit())
+    };
+
+    for (field_name, color) in [
+        (
+            "payment_link_config.primary_color",
+            &payment_link_config.primary_color,
+        ),
+        (
+            "payment_link_config.background_color",
+            &payment_link_config.background_color,
+        ),
+    ] {
+        utils::when(
+            color.as_ref().map_or(false, |color| !is_hex_color(color)),
+            || {
+                Err(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: field_name.to_string(),
+                    expected_format: "hex color code, e.g. #006DF9".to_string(),
+                })
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
 #[inline]
 pub fn create_merchant_publishable_key() -> String {
     format!(
@@ -85,22 +137,23 @@ pub async fn create_merchant_account(
             .transpose()?;
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        validate_routing_algorithm(routing_algorithm)?;
     }
 
     let key_store = domain::MerchantKeyStore {
         merchant_id: req.merchant_id.clone(),
-        key: domain_types::encrypt(key.to_vec().into(), master_key)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to decrypt data from key store")?,
+        key: domain_types::encrypt(
+            key.to_vec().into(),
+            domain_types::EncryptionKey::unversioned(master_key)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid master key")?,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt data from key store")?,
         created_at: date_time::now(),
+        key_version: domain_types::INITIAL_KEY_VERSION,
+        previous_keys: Default::default(),
     };
 
     let enable_payment_response_hash = req.enable_payment_response_hash.unwrap_or(true);
@@ -138,10 +191,14 @@ pub async fn create_merchant_account(
             merchant_id: req.merchant_id,
             merchant_name: req
                 .merchant_name
-                .async_lift(|inner| domain_types::encrypt_optional(inner, &key))
+                .async_lift(|inner| {
+                    domain_types::encrypt_optional(inner, key_store.encryption_key())
+                })
                 .await?,
             merchant_details: merchant_details
-                .async_lift(|inner| domain_types::encrypt_optional(inner, &key))
+                .async_lift(|inner| {
+                    domain_types::encrypt_optional(inner, key_store.encryption_key())
+                })
                 .await?,
             return_url: req.return_url.map(|a| a.to_string()),
             webhook_details,
@@ -340,13 +397,7 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        validate_routing_algorithm(routing_algorithm)?;
     }
 
     let primary_business_details = req
@@ -376,7 +427,15 @@ pub async fn merchant_account_update(
         })
         .await;
 
-    let key = key_store.key.get_inner().peek();
+    let merchant_account_before: api::MerchantAccountResponse = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    let key = key_store.encryption_key();
 
     let business_profile_id_update = if let Some(profile_id) = req.default_profile {
         if !profile_id.is_empty_after_trim() {
@@ -453,12 +512,26 @@ pub async fn merchant_account_update(
 
     // If there are any new business labels generated, create business profile
 
-    Ok(service_api::ApplicationResponse::Json(
-        response
-            .try_into()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let response: api::MerchantAccountResponse = response
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::MerchantAccountUpdate,
+        AuditTarget {
+            merchant_id: Some(merchant_id.as_str()),
+            target_type: storage::enums::AuditTargetType::MerchantAccount,
+            target_id: merchant_id,
+        },
+        Some(&merchant_account_before),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn merchant_account_delete(
@@ -550,6 +623,54 @@ fn validate_certificate_in_mca_metadata(
     Ok(())
 }
 
+/// Validates that the connector accepts the decrypted wallet payment tokens, and encrypts the
+/// wallets details, which hold the keys to decrypt them with
+async fn encrypt_connector_wallets_details(
+    state: &AppState,
+    connector_name: &str,
+    connector_wallets_details: Option<admin_types::ConnectorWalletsDetails>,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<Encryptable<Secret<serde_json::Value>>>> {
+    let connector_wallets_details = match connector_wallets_details {
+        Some(connector_wallets_details) => connector_wallets_details,
+        None => return Ok(None),
+    };
+
+    let is_google_pay_decrypt_supported = api_enums::Connector::from_str(connector_name)
+        .map(|connector| {
+            state
+                .conf
+                .google_pay_decrypt_supported_connectors
+                .supported_connectors
+                .contains(&connector)
+        })
+        .unwrap_or(false);
+    utils::when(
+        connector_wallets_details.google_pay.is_some() && !is_google_pay_decrypt_supported,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Decrypting the Google Pay payment tokens is not supported for {connector_name}"
+                ),
+            })
+        },
+    )?;
+
+    let connector_wallets_details =
+        Encode::<admin_types::ConnectorWalletsDetails>::encode_to_value(&connector_wallets_details)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the connector wallets details")?;
+
+    domain_types::encrypt(
+        Secret::new(connector_wallets_details),
+        key_store.encryption_key(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt connector wallets details")
+    .map(Some)
+}
+
 pub async fn create_payment_connector(
     state: AppState,
     req: api::MerchantConnectorCreate,
@@ -557,7 +678,22 @@ pub async fn create_payment_connector(
 ) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
     let store = state.store.as_ref();
     #[cfg(feature = "dummy_connector")]
-    validate_dummy_connector_enabled(&state, &req.connector_name).await?;
+    if let api_enums::MerchantConnectorName::Connector(connector_name) = &req.connector_name {
+        validate_dummy_connector_enabled(&state, connector_name).await?;
+    }
+    // Authentication connectors are only configured as authentication processors, and payment
+    // connectors never are
+    utils::when(
+        matches!(
+            req.connector_name,
+            api_enums::MerchantConnectorName::AuthenticationConnector(_)
+        ) != (req.connector_type == api_enums::ConnectorType::AuthenticationProcessor),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The connector type is invalid for the connector".to_string(),
+            })
+        },
+    )?;
     let key_store = store
         .get_merchant_key_store_by_merchant_id(
             merchant_id,
@@ -616,7 +752,15 @@ pub async fn create_payment_connector(
             expected_format: "auth_type and api_key".to_string(),
         })?;
 
-    validate_auth_type(req.connector_name, &auth).map_err(|err| {
+    match req.connector_name {
+        api_enums::MerchantConnectorName::Connector(connector_name) => {
+            validate_auth_type(connector_name, &auth)
+        }
+        api_enums::MerchantConnectorName::AuthenticationConnector(connector_name) => {
+            validate_authentication_connector_auth_type(connector_name, &auth)
+        }
+    }
+    .map_err(|err| {
         if err.current_context() == &errors::ConnectorError::InvalidConnectorName {
             err.change_context(errors::ApiErrorResponse::InvalidRequestData {
                 message: "The connector name is invalid".to_string(),
@@ -650,7 +794,7 @@ pub async fn create_payment_connector(
                     field_name: "connector_account_details",
                 },
             )?,
-            key_store.key.peek(),
+            key_store.encryption_key(),
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -681,6 +825,13 @@ pub async fn create_payment_connector(
         },
         profile_id: Some(profile_id.clone()),
         applepay_verified_domains: None,
+        connector_wallets_details: encrypt_connector_wallets_details(
+            &state,
+            &req.connector_name.to_string(),
+            req.connector_wallets_details,
+            &key_store,
+        )
+        .await?,
     };
 
     let mca = state
@@ -703,7 +854,22 @@ pub async fn create_payment_connector(
         ],
     );
 
-    let mca_response = mca.try_into()?;
+    let mca_response: api_models::admin::MerchantConnectorResponse = mca.try_into()?;
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::PaymentConnectorCreate,
+        AuditTarget {
+            merchant_id: Some(merchant_id.as_str()),
+            target_type: storage::enums::AuditTargetType::MerchantConnectorAccount,
+            target_id: &mca_response.merchant_connector_id,
+        },
+        None,
+        Some(&mca_response),
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
 
@@ -816,6 +982,8 @@ pub async fn update_payment_connector(
 
     let frm_configs = get_frm_config_as_secret(req.frm_configs);
 
+    let mca_before: api_models::admin::MerchantConnectorResponse = mca.clone().try_into()?;
+
     let payment_connector = storage::MerchantConnectorAccountUpdate::Update {
         merchant_id: None,
         connector_type: Some(req.connector_type),
@@ -823,9 +991,7 @@ pub async fn update_payment_connector(
         merchant_connector_id: None,
         connector_account_details: req
             .connector_account_details
-            .async_lift(|inner| {
-                domain_types::encrypt_optional(inner, key_store.key.get_inner().peek())
-            })
+            .async_lift(|inner| domain_types::encrypt_optional(inner, key_store.encryption_key()))
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed while encrypting data")?,
@@ -846,6 +1012,13 @@ pub async fn update_payment_connector(
             None => None,
         },
         applepay_verified_domains: None,
+        connector_wallets_details: encrypt_connector_wallets_details(
+            &state,
+            &mca.connector_name,
+            req.connector_wallets_details,
+            &key_store,
+        )
+        .await?,
     };
 
     let updated_mca = db
@@ -856,7 +1029,21 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
-    let response = updated_mca.try_into()?;
+    let response: api_models::admin::MerchantConnectorResponse = updated_mca.try_into()?;
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::PaymentConnectorUpdate,
+        AuditTarget {
+            merchant_id: Some(merchant_id),
+            target_type: storage::enums::AuditTargetType::MerchantConnectorAccount,
+            target_id: merchant_connector_id,
+        },
+        Some(&mca_before),
+        Some(&response),
+    )
+    .await;
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -912,6 +1099,13 @@ pub async fn kv_for_merchant(
     enable: bool,
 ) -> RouterResponse<api_models::admin::ToggleKVResponse> {
     let db = state.store.as_ref();
+
+    // The drainer only drains the streams of the default store
+    if enable && state.tenant_id.is_some() {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "KV storage is not supported for tenants".to_string(),
+        })?
+    }
     let key_store = db
         .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
         .await
@@ -923,6 +1117,14 @@ pub async fn kv_for_merchant(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    let kv_status_before = api_models::admin::ToggleKVResponse {
+        merchant_id: merchant_id.clone(),
+        kv_enabled: matches!(
+            merchant_account.storage_scheme,
+            MerchantStorageScheme::RedisKv
+        ),
+    };
+
     let updated_merchant_account = match (enable, merchant_account.storage_scheme) {
         (true, MerchantStorageScheme::RedisKv) | (false, MerchantStorageScheme::PostgresOnly) => {
             Ok(merchant_account)
@@ -958,12 +1160,26 @@ pub async fn kv_for_merchant(
         MerchantStorageScheme::RedisKv
     );
 
-    Ok(service_api::ApplicationResponse::Json(
-        api_models::admin::ToggleKVResponse {
-            merchant_id: updated_merchant_account.merchant_id,
-            kv_enabled: kv_status,
+    let response = api_models::admin::ToggleKVResponse {
+        merchant_id: updated_merchant_account.merchant_id,
+        kv_enabled: kv_status,
+    };
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::MerchantKvToggle,
+        AuditTarget {
+            merchant_id: Some(&merchant_id),
+            target_type: storage::enums::AuditTargetType::MerchantAccount,
+            target_id: &merchant_id,
         },
-    ))
+        Some(&kv_status_before),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn check_merchant_account_kv_status(
@@ -995,6 +1211,255 @@ pub async fn check_merchant_account_kv_status(
     ))
 }
 
+const VAULT_KEY_ROTATION_RUNNER: &str = "VAULT_KEY_ROTATION_WORKFLOW";
+const VAULT_KEY_ROTATION_TASK: &str = "VAULT_KEY_ROTATION";
+
+pub async fn rotate_vault_data_key(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<api_models::admin::VaultDataKeyRotationResponse> {
+    let db = state.store.as_ref();
+
+    if !state.conf.locker.builtin_vault {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Built-in vault is not enabled".to_string(),
+        })?
+    }
+
+    // check if the merchant account exists
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    db.find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let key_version = vault::Vault::rotate_builtin_vault_data_key(&state, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to rotate the vault data key")?;
+
+    let tracking_data = storage::VaultKeyRotationTrackingData {
+        merchant_id: merchant_id.clone(),
+        key_version,
+        re_encrypted_count: 0,
+    };
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        pt_utils::get_process_tracker_id(
+            VAULT_KEY_ROTATION_RUNNER,
+            VAULT_KEY_ROTATION_TASK,
+            &key_version.to_string(),
+            &merchant_id,
+        ),
+        VAULT_KEY_ROTATION_TASK,
+        VAULT_KEY_ROTATION_RUNNER,
+        tracking_data,
+        date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the vault data key rotation task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the re-encryption of the cards in the vault")?;
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::VaultDataKeyRotate,
+        AuditTarget {
+            merchant_id: Some(merchant_id.as_str()),
+            target_type: storage::enums::AuditTargetType::VaultDataKey,
+            target_id: &merchant_id,
+        },
+        None,
+        Some(&serde_json::json!({ "key_version": key_version })),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::VaultDataKeyRotationResponse {
+            merchant_id,
+            key_version,
+        },
+    ))
+}
+
+const MERCHANT_KEY_ROTATION_RUNNER: &str = "MERCHANT_KEY_ROTATION_WORKFLOW";
+const MERCHANT_KEY_ROTATION_TASK: &str = "MERCHANT_KEY_ROTATION";
+
+fn get_key_rotation_process_tracker_id(merchant_id: &str, key_version: i32) -> String {
+    pt_utils::get_process_tracker_id(
+        MERCHANT_KEY_ROTATION_RUNNER,
+        MERCHANT_KEY_ROTATION_TASK,
+        &key_version.to_string(),
+        merchant_id,
+    )
+}
+
+pub async fn rotate_merchant_data_key(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<api_models::admin::DataKeyRotationResponse> {
+    let db = state.store.as_ref();
+    let master_key = db.get_master_key();
+
+    // check if the merchant account exists
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &master_key.to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    db.find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    // The data is only ever re-encrypted with the active key, so a new rotation must wait for the
+    // re-encryption of the previous one to finish
+    let process_tracker_id =
+        get_key_rotation_process_tracker_id(&merchant_id, key_store.key_version);
+    let previous_rotation = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the previous data key rotation")?;
+    if previous_rotation.map_or(false, |process| {
+        process.status != storage::enums::ProcessTrackerStatus::Finish
+    }) {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The data of the previous data key rotation is still being re-encrypted"
+                .to_string(),
+        })?
+    }
+
+    // Retain the current key, so that the data not re-encrypted yet can still be decrypted
+    let current_key_version = storage::MerchantKeyVersionNew {
+        merchant_id: merchant_id.clone(),
+        key_version: key_store.key_version,
+        key: key_store.key.clone().into(),
+        created_at: date_time::now(),
+    };
+    match db.insert_merchant_key_version(current_key_version).await {
+        Ok(_) => (),
+        Err(error) if error.current_context().is_db_unique_violation() => (),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retain the current data key")?,
+    }
+
+    let key = services::generate_aes256_key()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to generate aes 256 key")?;
+    let key_store_update = storage::MerchantKeyStoreUpdate::KeyRotated {
+        key: domain_types::encrypt(
+            Secret::new(key.to_vec()),
+            domain_types::EncryptionKey::unversioned(master_key)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid master key")?,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the new data key")?
+        .into(),
+        key_version: key_store.key_version + 1,
+    };
+    let previous_key_version = key_store.key_version;
+    let key_store = db
+        .update_merchant_key_store_by_merchant_id_key_version(
+            &merchant_id,
+            previous_key_version,
+            key_store_update,
+            &master_key.to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The data key was rotated concurrently".to_string(),
+        })?;
+
+    let tracking_data = storage::MerchantKeyRotationTrackingData {
+        merchant_id: merchant_id.clone(),
+        key_version: key_store.key_version,
+        stage: storage::KeyRotationStage::MerchantAccount,
+        last_processed_id: None,
+        last_processed_authentication_id: None,
+        re_encrypted_count: 0,
+    };
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        get_key_rotation_process_tracker_id(&merchant_id, key_store.key_version),
+        MERCHANT_KEY_ROTATION_TASK,
+        MERCHANT_KEY_ROTATION_RUNNER,
+        tracking_data.clone(),
+        date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the data key rotation task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the re-encryption of the merchant data")?;
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::MerchantDataKeyRotate,
+        AuditTarget {
+            merchant_id: Some(merchant_id.as_str()),
+            target_type: storage::enums::AuditTargetType::MerchantKeyStore,
+            target_id: &merchant_id,
+        },
+        Some(&serde_json::json!({ "key_version": previous_key_version })),
+        Some(&serde_json::json!({ "key_version": key_store.key_version })),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::DataKeyRotationResponse::foreign_from(tracking_data),
+    ))
+}
+
+pub async fn merchant_data_key_rotation_status(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<api_models::admin::DataKeyRotationResponse> {
+    let db = state.store.as_ref();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let process = db
+        .find_process_by_id(&get_key_rotation_process_tracker_id(
+            &merchant_id,
+            key_store.key_version,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the data key rotation")?;
+
+    // The data key was never rotated, or the finished rotation task was cleaned up
+    let tracking_data = match process {
+        Some(process) => process
+            .tracking_data
+            .parse_value::<storage::MerchantKeyRotationTrackingData>(
+                "MerchantKeyRotationTrackingData",
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        None => storage::MerchantKeyRotationTrackingData {
+            merchant_id,
+            key_version: key_store.key_version,
+            stage: storage::KeyRotationStage::Completed,
+            last_processed_id: None,
+            last_processed_authentication_id: None,
+            re_encrypted_count: 0,
+        },
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::DataKeyRotationResponse::foreign_from(tracking_data),
+    ))
+}
+
 pub fn get_frm_config_as_secret(
     frm_configs: Option<Vec<api_models::admin::FrmConfigs>>,
 ) -> Option<Vec<Secret<serde_json::Value>>> {
@@ -1056,13 +1521,11 @@ pub async fn create_business_profile(
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
     if let Some(ref routing_algorithm) = request.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        validate_routing_algorithm(routing_algorithm)?;
+    }
+
+    if let Some(ref payment_link_config) = request.payment_link_config {
+        validate_payment_link_config(payment_link_config)?;
     }
 
     let business_profile =
@@ -1168,14 +1631,42 @@ pub async fn update_business_profile(
         .transpose()?;
 
     if let Some(ref routing_algorithm) = request.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
+        validate_routing_algorithm(routing_algorithm)?;
+    }
+
+    if let Some(ref payment_link_config) = request.payment_link_config {
+        validate_payment_link_config(payment_link_config)?;
+    }
+
+    let payment_link_config = request
+        .payment_link_config
+        .as_ref()
+        .map(|payment_link_config| {
+            utils::Encode::<api_models::admin::PaymentLinkConfig>::encode_to_value(
+                payment_link_config,
+            )
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
+                field_name: "payment_link_config",
             })
-            .attach_printable("Invalid routing algorithm given")?;
-    }
+        })
+        .transpose()?;
+
+    let surcharge_config = request
+        .surcharge_config
+        .as_ref()
+        .map(|surcharge_config| {
+            utils::Encode::<api_models::payment_methods::SurchargeConfig>::encode_to_value(
+                surcharge_config,
+            )
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "surcharge_config",
+            })
+        })
+        .transpose()?;
+
+    let business_profile_before =
+        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
     let business_profile_update = storage::business_profile::BusinessProfileUpdateInternal {
         profile_name: request.profile_name,
@@ -1192,6 +1683,8 @@ pub async fn update_business_profile(
         payout_routing_algorithm: request.payout_routing_algorithm,
         is_recon_enabled: None,
         applepay_verified_domains: request.applepay_verified_domains,
+        payment_link_config,
+        surcharge_config,
     };
 
     let updated_business_profile = db
@@ -1201,10 +1694,25 @@ pub async fn update_business_profile(
             id: profile_id.to_owned(),
         })?;
 
-    Ok(service_api::ApplicationResponse::Json(
+    let response =
         api_models::admin::BusinessProfileResponse::foreign_try_from(updated_business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)?,
-    ))
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::BusinessProfileUpdate,
+        AuditTarget {
+            merchant_id: Some(merchant_id),
+            target_type: storage::enums::AuditTargetType::BusinessProfile,
+            target_id: profile_id,
+        },
+        Some(&business_profile_before),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub(crate) fn validate_auth_type(
@@ -1402,9 +1910,23 @@ pub(crate) fn validate_auth_type(
             zen::transformers::ZenAuthType::try_from(val)?;
             Ok(())
         }
-        api_enums::Connector::Signifyd | api_enums::Connector::Plaid => {
-            Err(report!(errors::ConnectorError::InvalidConnectorName)
-                .attach_printable(format!("invalid connector name: {connector_name}")))
+        api_enums::Connector::Signifyd => {
+            signifyd::transformers::SignifydAuthType::try_from(val)?;
+            Ok(())
+        }
+        api_enums::Connector::Plaid => Err(report!(errors::ConnectorError::InvalidConnectorName)
+            .attach_printable(format!("invalid connector name: {connector_name}"))),
+    }
+}
+
+pub(crate) fn validate_authentication_connector_auth_type(
+    connector_name: api_enums::AuthenticationConnectors,
+    val: &types::ConnectorAuthType,
+) -> Result<(), error_stack::Report<errors::ConnectorError>> {
+    match connector_name {
+        api_enums::AuthenticationConnectors::Threedsecureio => {
+            crate::connector::threedsecureio::transformers::ThreedsecureioAuthType::try_from(val)?;
+            Ok(())
         }
     }
 }
@@ -12,7 +12,10 @@ use crate::types::storage::enums;
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        audit_events::{self, AuditActor, AuditTarget},
+        errors::{self, RouterResponse, StorageErrorExt},
+    },
     routes::{metrics, AppState},
     services::ApplicationResponse,
     types::{api, storage, transformers::ForeignInto},
@@ -195,6 +198,22 @@ pub async fn create_api_key(
         }
     }
 
+    // The plaintext API key is never recorded in the audit log
+    let created_api_key: api::RetrieveApiKeyResponse = api_key.clone().foreign_into();
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::ApiKeyCreate,
+        AuditTarget {
+            merchant_id: Some(&created_api_key.merchant_id),
+            target_type: storage::enums::AuditTargetType::ApiKey,
+            target_id: &created_api_key.key_id,
+        },
+        None,
+        Some(&created_api_key),
+    )
+    .await;
+
     Ok(ApplicationResponse::Json(
         (api_key, plaintext_api_key).foreign_into(),
     ))
@@ -210,7 +229,7 @@ pub async fn add_api_key_expiry_task(
     store: &dyn crate::db::StorageInterface,
     api_key: &ApiKey,
     expiry_reminder_days: Vec<u8>,
-) -> Result<(), errors::ProcessTrackerError> {
+) -> Result<(), scheduler::errors::ProcessTrackerError> {
     let current_time = common_utils::date_time::now();
 
     let schedule_time = expiry_reminder_days
@@ -375,7 +394,7 @@ pub async fn update_api_key_expiry_task(
     store: &dyn crate::db::StorageInterface,
     api_key: &ApiKey,
     expiry_reminder_days: Vec<u8>,
-) -> Result<(), errors::ProcessTrackerError> {
+) -> Result<(), scheduler::errors::ProcessTrackerError> {
     let current_time = common_utils::date_time::now();
 
     let schedule_time = expiry_reminder_days
@@ -466,11 +485,27 @@ pub async fn revoke_api_key(
         }
     }
 
-    Ok(ApplicationResponse::Json(api::RevokeApiKeyResponse {
+    let response = api::RevokeApiKeyResponse {
         merchant_id: merchant_id.to_owned(),
         key_id: key_id.to_owned(),
         revoked,
-    }))
+    };
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        storage::enums::AuditAction::ApiKeyRevoke,
+        AuditTarget {
+            merchant_id: Some(merchant_id),
+            target_type: storage::enums::AuditTargetType::ApiKey,
+            target_id: key_id,
+        },
+        None,
+        Some(&response),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
 }
 
 // Function to revoke api_key_expiry task in the process_tracker table when API key is revoked.
@@ -480,7 +515,7 @@ pub async fn revoke_api_key(
 pub async fn revoke_api_key_expiry_task(
     store: &dyn crate::db::StorageInterface,
     key_id: &str,
-) -> Result<(), errors::ProcessTrackerError> {
+) -> Result<(), scheduler::errors::ProcessTrackerError> {
     let task_id = generate_task_id_for_api_key_expiry_workflow(key_id);
     let task_ids = vec![task_id];
     let updated_process_tracker_data = storage::ProcessTrackerUpdate::StatusUpdate {
@@ -559,7 +594,9 @@ mod tests {
         let hash_key = get_hash_key(
             &settings.api_keys,
             #[cfg(feature = "kms")]
-            external_services::kms::get_kms_client(&settings.kms).await,
+            external_services::kms::get_kms_client(&settings.kms)
+                .await
+                .unwrap(),
         )
         .await
         .unwrap();
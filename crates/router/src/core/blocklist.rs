@@ -0,0 +1,113 @@
+pub mod utils;
+
+use api_models::blocklist as api_blocklist;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils as router_utils,
+};
+
+#[instrument(skip(state))]
+pub async fn add_entry_to_blocklist(
+    state: AppState,
+    merchant_id: String,
+    request: api_blocklist::BlocklistRequest,
+) -> RouterResponse<api_blocklist::BlocklistResponse> {
+    let (fingerprint_id, data_kind) = match request {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
+            validate_card_bin(&bin, 6)?;
+            (bin, enums::BlocklistDataKind::CardBin)
+        }
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
+            validate_card_bin(&bin, 8)?;
+            (bin, enums::BlocklistDataKind::ExtendedCardBin)
+        }
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => {
+            (fingerprint_id, enums::BlocklistDataKind::PaymentMethod)
+        }
+    };
+
+    let blocklist_entry = state
+        .store
+        .insert_blocklist_entry(storage::BlocklistNew {
+            merchant_id,
+            fingerprint_id,
+            data_kind,
+            metadata: None,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "The data is already present in the blocklist".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(blocklist_entry.foreign_into()))
+}
+
+#[instrument(skip(state))]
+pub async fn remove_entry_from_blocklist(
+    state: AppState,
+    merchant_id: String,
+    request: api_blocklist::BlocklistRequest,
+) -> RouterResponse<api_blocklist::BlocklistResponse> {
+    let fingerprint_id = match request {
+        api_blocklist::BlocklistRequest::CardBin(bin)
+        | api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => bin,
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => fingerprint_id,
+    };
+
+    let blocklist_entry = state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, &fingerprint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "The data is not present in the blocklist".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(blocklist_entry.foreign_into()))
+}
+
+#[instrument(skip(state))]
+pub async fn list_blocklist_entries(
+    state: AppState,
+    merchant_id: String,
+    query: api_blocklist::ListBlocklistQuery,
+) -> RouterResponse<Vec<api_blocklist::BlocklistResponse>> {
+    let blocklist_entries = state
+        .store
+        .list_blocklist_entries_by_merchant_id_data_kind(
+            &merchant_id,
+            query.data_kind,
+            query.limit,
+            query.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the blocklist entries")?;
+
+    Ok(ApplicationResponse::Json(
+        blocklist_entries
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+fn validate_card_bin(bin: &str, length: usize) -> RouterResult<()> {
+    router_utils::when(
+        bin.len() != length || !bin.chars().all(|c| c.is_ascii_digit()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "data".to_string(),
+                expected_format: format!("{length} digits of the card number"),
+            }))
+        },
+    )
+}
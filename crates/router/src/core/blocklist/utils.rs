@@ -0,0 +1,426 @@
+use common_utils::crypto::{self, SignMessage};
+use diesel_models::encryption::Encryption;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::PaymentData,
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api,
+        domain::{self, types as domain_types},
+        storage::{self, enums},
+    },
+    utils::{self, OptionExt},
+};
+
+/// Automatic blocking of the payment methods of a merchant, loaded with
+/// [`core_utils::get_merchant_config`], e.g. `{"dispute_threshold": 3}`. A payment method is
+/// blocked once the number of disputes or fraud-flagged payments made with it reaches the
+/// respective threshold.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct AutoBlockConfig {
+    pub dispute_threshold: Option<i32>,
+    pub fraud_threshold: Option<i32>,
+}
+
+impl AutoBlockConfig {
+    fn should_block(&self, dispute_count: i32, fraud_count: i32) -> bool {
+        self.dispute_threshold
+            .map_or(false, |threshold| dispute_count >= threshold)
+            || self
+                .fraud_threshold
+                .map_or(false, |threshold| fraud_count >= threshold)
+    }
+}
+
+async fn get_auto_block_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> Option<AutoBlockConfig> {
+    core_utils::get_merchant_config(db, "blocklist_auto_block", merchant_id, None).await
+}
+
+/// The events recorded against a payment method fingerprint, which can lead to it being blocked
+#[derive(Debug, Clone, Copy)]
+pub enum FingerprintEvent {
+    Dispute,
+    Fraud,
+}
+
+/// The prefix of the fingerprint secrets, which are stored hex encoded once encrypted
+const FINGERPRINT_SECRET_PREFIX: &str = "fs_";
+
+/// The secret used to generate the payment method fingerprints of a merchant, which is created
+/// when it is first needed. The secret is stored encrypted with the key of the merchant.
+async fn get_merchant_fingerprint_secret(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<String> {
+    let key = format!("fingerprint_secret_{merchant_id}");
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(config) if config.config.starts_with(FINGERPRINT_SECRET_PREFIX) => {
+            // Secrets created before they were encrypted are encrypted when they are next used
+            let encrypted_secret =
+                encrypt_fingerprint_secret(config.config.clone(), &key_store).await?;
+            db.update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(encrypted_secret),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the merchant fingerprint secret")?;
+            Ok(config.config)
+        }
+        Ok(config) => decrypt_fingerprint_secret(&config.config, &key_store).await,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let secret = utils::generate_id(consts::FINGERPRINT_SECRET_LENGTH, "fs");
+            let new_config = storage::ConfigNew {
+                key: key.clone(),
+                config: encrypt_fingerprint_secret(secret.clone(), &key_store).await?,
+            };
+            match db.insert_config(new_config).await {
+                Ok(_) => Ok(secret),
+                // The secret was created by a concurrent request
+                Err(error) if error.current_context().is_db_unique_violation() => {
+                    let config = db
+                        .find_config_by_key(&key)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to fetch the merchant fingerprint secret")?;
+                    decrypt_fingerprint_secret(&config.config, &key_store).await
+                }
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to create the merchant fingerprint secret"),
+            }
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the merchant fingerprint secret"),
+    }
+}
+
+async fn encrypt_fingerprint_secret(
+    secret: String,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<String> {
+    domain_types::encrypt(Secret::new(secret), key_store.encryption_key())
+        .await
+        .map(|encrypted| hex::encode(encrypted.into_encrypted().peek()))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the merchant fingerprint secret")
+}
+
+async fn decrypt_fingerprint_secret(
+    encrypted_secret: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<String> {
+    let encrypted_secret = hex::decode(encrypted_secret)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decode the merchant fingerprint secret")?;
+    domain_types::decrypt::<String, masking::WithType>(
+        Some(Encryption::new(encrypted_secret.into())),
+        key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the merchant fingerprint secret")?
+    .map(|secret| secret.into_inner().expose())
+    .get_required_value("fingerprint_secret")
+}
+
+fn generate_fingerprint(secret: &str, data: &str) -> RouterResult<String> {
+    crypto::HmacSha512
+        .sign_message(secret.as_bytes(), data.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the payment method fingerprint")
+}
+
+async fn get_card_fingerprint(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    card_number: &cards::CardNumber,
+) -> RouterResult<String> {
+    let secret = get_merchant_fingerprint_secret(db, merchant_id).await?;
+    generate_fingerprint(&secret, card_number.peek())
+}
+
+/// Fails the payment being confirmed if the card used, its bin or its extended bin is in the
+/// blocklist of the merchant. The fingerprint of the card is set on the payment otherwise.
+///
+/// Only card payments are checked against the blocklist.
+#[instrument(skip_all)]
+pub async fn validate_data_for_blocklist<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let card_number = match &payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => card.card_number.clone(),
+        _ => return Ok(()),
+    };
+
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let fingerprint_id = get_card_fingerprint(db, merchant_id, &card_number).await?;
+    let card_bin = card_number.clone().get_card_isin();
+    let extended_card_bin = card_number.get_extended_card_bin();
+
+    let blocklist_entries = db
+        .find_blocklist_entries_by_merchant_id_fingerprint_ids(
+            merchant_id,
+            vec![fingerprint_id.clone(), card_bin, extended_card_bin],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the blocklist entries")?;
+
+    payment_data.payment_intent.fingerprint_id = Some(fingerprint_id);
+
+    if blocklist_entries.is_empty() {
+        return Ok(());
+    }
+
+    logger::info!(
+        payment_id = %payment_data.payment_intent.payment_id,
+        blocked_data_kinds = ?blocklist_entries
+            .iter()
+            .map(|entry| entry.data_kind)
+            .collect::<Vec<_>>(),
+        "Payment blocked by the blocklist of the merchant"
+    );
+
+    let storage_scheme = merchant_account.storage_scheme;
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: enums::AttemptStatus::Failure,
+                error_code: Some(Some("HE_03".to_string())),
+                error_message: Some(Some(
+                    "The payment method used is in the blocklist of the merchant".to_string(),
+                )),
+                error_reason: None,
+                amount_capturable: Some(0),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Failed,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Err(report!(errors::ApiErrorResponse::PaymentBlocked))
+}
+
+/// Records a dispute or a fraud-flagged payment against a payment method fingerprint, and adds
+/// the fingerprint to the blocklist of the merchant once the threshold configured for automatic
+/// blocking is reached
+#[instrument(skip(db))]
+pub async fn record_fingerprint_event(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    fingerprint_id: &str,
+    event: FingerprintEvent,
+) -> RouterResult<()> {
+    let blocklist_fingerprint = match db
+        .find_blocklist_fingerprint_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+        .await
+    {
+        Ok(blocklist_fingerprint) => blocklist_fingerprint,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let insert_result = db
+                .insert_blocklist_fingerprint(storage::BlocklistFingerprintNew {
+                    merchant_id: merchant_id.to_owned(),
+                    fingerprint_id: fingerprint_id.to_owned(),
+                    dispute_count: 0,
+                    fraud_count: 0,
+                })
+                .await;
+            match insert_result {
+                Ok(blocklist_fingerprint) => blocklist_fingerprint,
+                // The fingerprint was inserted by a concurrent request
+                Err(error) if error.current_context().is_db_unique_violation() => db
+                    .find_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+                        merchant_id,
+                        fingerprint_id,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the blocklist fingerprint")?,
+                Err(error) => {
+                    return Err(error)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to insert the blocklist fingerprint")
+                }
+            }
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the blocklist fingerprint")
+        }
+    };
+
+    let blocklist_fingerprint_update = match event {
+        FingerprintEvent::Dispute => storage::BlocklistFingerprintUpdate::DisputeCountIncrement,
+        FingerprintEvent::Fraud => storage::BlocklistFingerprintUpdate::FraudCountIncrement,
+    };
+    let storage::BlocklistFingerprint {
+        dispute_count,
+        fraud_count,
+        ..
+    } = db
+        .update_blocklist_fingerprint(blocklist_fingerprint, blocklist_fingerprint_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the blocklist fingerprint")?;
+
+    let should_block = get_auto_block_config(db, merchant_id)
+        .await
+        .map_or(false, |config| {
+            config.should_block(dispute_count, fraud_count)
+        });
+    if !should_block {
+        return Ok(());
+    }
+
+    let insert_result = db
+        .insert_blocklist_entry(storage::BlocklistNew {
+            merchant_id: merchant_id.to_owned(),
+            fingerprint_id: fingerprint_id.to_owned(),
+            data_kind: enums::BlocklistDataKind::PaymentMethod,
+            metadata: Some(serde_json::json!({
+                "reason": "auto_block",
+                "dispute_count": dispute_count,
+                "fraud_count": fraud_count,
+            })),
+        })
+        .await;
+
+    match insert_result {
+        Ok(_) => {
+            logger::info!("Payment method fingerprint added to the blocklist automatically");
+            Ok(())
+        }
+        // The fingerprint is already in the blocklist
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add the fingerprint to the blocklist"),
+    }
+}
+
+/// Records a payment flagged by the fraud check against the fingerprint of the card it was made
+/// with. The fingerprint is generated here for payments which have not been checked against the
+/// blocklist yet.
+pub async fn record_fraudulent_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let fingerprint_id = match (
+        &payment_data.payment_intent.fingerprint_id,
+        &payment_data.payment_method_data,
+    ) {
+        (Some(fingerprint_id), _) => fingerprint_id.clone(),
+        (None, Some(api::PaymentMethodData::Card(card))) => {
+            get_card_fingerprint(db, merchant_id, &card.card_number).await?
+        }
+        (None, _) => return Ok(()),
+    };
+
+    record_fingerprint_event(db, merchant_id, &fingerprint_id, FingerprintEvent::Fraud).await
+}
+
+/// Records a newly raised dispute against the fingerprint of the card the payment was made with
+pub async fn record_disputed_payment(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    match payment_intent.fingerprint_id {
+        Some(fingerprint_id) => {
+            record_fingerprint_event(
+                db,
+                &merchant_account.merchant_id,
+                &fingerprint_id,
+                FingerprintEvent::Dispute,
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_auto_block_decision() {
+        let config = AutoBlockConfig {
+            dispute_threshold: Some(2),
+            fraud_threshold: None,
+        };
+
+        assert!(!config.should_block(1, 10));
+        assert!(config.should_block(2, 0));
+        assert!(!AutoBlockConfig::default().should_block(100, 100));
+    }
+
+    #[test]
+    fn test_fingerprint_generation() {
+        let fingerprint = generate_fingerprint("secret", "4242424242424242").unwrap();
+
+        assert_eq!(fingerprint.len(), 128);
+        assert_eq!(
+            fingerprint,
+            generate_fingerprint("secret", "4242424242424242").unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_fingerprint("another_secret", "4242424242424242").unwrap()
+        );
+    }
+}
@@ -1,13 +1,34 @@
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 
 use crate::{
-    core::errors::{self, utils::StorageErrorExt, RouterResponse},
+    core::{
+        audit_events::{self, AuditActor, AuditTarget},
+        errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
+        payments::retry,
+    },
     routes::AppState,
     services::ApplicationResponse,
-    types::{api, transformers::ForeignInto},
+    types::{api, storage::enums, transformers::ForeignInto},
+    utils::StringExt,
 };
 
+/// Configs which are read by the payments flows are validated before they are stored, since an
+/// invalid one would otherwise only be noticed when it is skipped while processing a payment
+fn validate_config(key: &str, value: &str) -> RouterResult<()> {
+    if key.starts_with(&format!("{}_", retry::AUTO_RETRY_CONFIG_KEY_PREFIX)) {
+        let auto_retry_config: retry::AutoRetryConfig = value
+            .to_owned()
+            .parse_struct("AutoRetryConfig")
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "value is not a valid auto retry config".to_string(),
+            })?;
+        auto_retry_config.validate().into_report()?;
+    }
+    Ok(())
+}
+
 pub async fn set_config(state: AppState, config: api::Config) -> RouterResponse<api::Config> {
+    validate_config(&config.key, &config.value)?;
     let store = state.store.as_ref();
     let config = store
         .insert_config(diesel_models::configs::ConfigNew {
@@ -18,7 +39,22 @@ pub async fn set_config(state: AppState, config: api::Config) -> RouterResponse<
         .to_duplicate_response(errors::ApiErrorResponse::DuplicateConfig)
         .attach_printable("Unknown error, while setting config key")?;
 
-    Ok(ApplicationResponse::Json(config.foreign_into()))
+    let config: api::Config = config.foreign_into();
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        enums::AuditAction::ConfigCreate,
+        AuditTarget {
+            merchant_id: None,
+            target_type: enums::AuditTargetType::Config,
+            target_id: &config.key,
+        },
+        None,
+        Some(&config),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(config))
 }
 
 pub async fn read_config(state: AppState, key: &str) -> RouterResponse<api::Config> {
@@ -34,10 +70,32 @@ pub async fn update_config(
     state: AppState,
     config_update: &api::ConfigUpdate,
 ) -> RouterResponse<api::Config> {
+    validate_config(&config_update.key, &config_update.value)?;
     let store = state.store.as_ref();
-    let config = store
+    let config_before: api::Config = store
+        .find_config_by_key(&config_update.key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?
+        .foreign_into();
+    let config: api::Config = store
         .update_config_by_key(&config_update.key, config_update.foreign_into())
         .await
-        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?;
-    Ok(ApplicationResponse::Json(config.foreign_into()))
+        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?
+        .foreign_into();
+
+    audit_events::record_audit_event(
+        &state,
+        AuditActor::AdminApiKey,
+        enums::AuditAction::ConfigUpdate,
+        AuditTarget {
+            merchant_id: None,
+            target_type: enums::AuditTargetType::Config,
+            target_id: &config.key,
+        },
+        Some(&config_before),
+        Some(&config),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(config))
 }
@@ -1,7 +1,4 @@
-use common_utils::{
-    crypto::{Encryptable, GcmAes256},
-    errors::ReportSwitchExt,
-};
+use common_utils::{crypto::Encryptable, errors::ReportSwitchExt};
 use error_stack::ResultExt;
 use masking::ExposeInterface;
 use router_env::{instrument, tracing};
@@ -19,7 +16,7 @@ use crate::{
         api::customers,
         domain::{
             self,
-            types::{self, AsyncLift, TypeEncryption},
+            types::{self, AsyncLift},
         },
         storage::{self, enums},
     },
@@ -40,7 +37,7 @@ pub async fn create_customer(
     let merchant_id = &merchant_account.merchant_id;
     customer_data.merchant_id = merchant_id.to_owned();
 
-    let key = key_store.key.get_inner().peek();
+    let key = key_store.encryption_key();
     if let Some(addr) = &customer_data.address {
         let customer_address: api_models::payments::AddressDetails = addr.clone();
 
@@ -94,7 +91,7 @@ pub async fn create_customer(
         .switch()
         .attach_printable("Failed while encrypting address")?;
 
-        db.insert_address(address, &key_store)
+        db.insert_address(address, &key_store, merchant_account.storage_scheme)
             .await
             .switch()
             .attach_printable("Failed while inserting new address")?;
@@ -129,16 +126,24 @@ pub async fn create_customer(
     .switch()
     .attach_printable("Failed while encrypting Customer")?;
 
-    let customer = match db.insert_customer(new_customer, &key_store).await {
+    let customer = match db
+        .insert_customer(new_customer, &key_store, merchant_account.storage_scheme)
+        .await
+    {
         Ok(customer) => customer,
         Err(error) => {
             if error.current_context().is_db_unique_violation() {
-                db.find_customer_by_customer_id_merchant_id(customer_id, merchant_id, &key_store)
-                    .await
-                    .switch()
-                    .attach_printable(format!(
-                        "Failed while fetching Customer, customer_id: {customer_id}",
-                    ))?
+                db.find_customer_by_customer_id_merchant_id(
+                    customer_id,
+                    merchant_id,
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .switch()
+                .attach_printable(format!(
+                    "Failed while fetching Customer, customer_id: {customer_id}",
+                ))?
             } else {
                 Err(error
                     .change_context(errors::CustomersErrorResponse::InternalServerError)
@@ -165,6 +170,7 @@ pub async fn retrieve_customer(
             &req.customer_id,
             &merchant_account.merchant_id,
             &key_store,
+            merchant_account.storage_scheme,
         )
         .await
         .switch()?;
@@ -185,6 +191,7 @@ pub async fn delete_customer(
         &req.customer_id,
         &merchant_account.merchant_id,
         &key_store,
+        merchant_account.storage_scheme,
     )
     .await
     .switch()?;
@@ -238,10 +245,10 @@ pub async fn delete_customer(
         }
     };
 
-    let key = key_store.key.get_inner().peek();
+    let key = key_store.encryption_key();
 
     let redacted_encrypted_value: Encryptable<masking::Secret<_>> =
-        Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
+        types::encrypt(REDACTED.to_string().into(), key)
             .await
             .switch()?;
 
@@ -265,6 +272,7 @@ pub async fn delete_customer(
             &merchant_account.merchant_id,
             update_address,
             &key_store,
+            merchant_account.storage_scheme,
         )
         .await
     {
@@ -283,7 +291,7 @@ pub async fn delete_customer(
     let updated_customer = storage::CustomerUpdate::Update {
         name: Some(redacted_encrypted_value.clone()),
         email: Some(
-            Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
+            types::encrypt(REDACTED.to_string().into(), key)
                 .await
                 .switch()?,
         ),
@@ -298,6 +306,7 @@ pub async fn delete_customer(
         merchant_account.merchant_id,
         updated_customer,
         &key_store,
+        merchant_account.storage_scheme,
     )
     .await
     .switch()?;
@@ -325,11 +334,12 @@ pub async fn update_customer(
         &update_customer.customer_id,
         &merchant_account.merchant_id,
         &key_store,
+        merchant_account.storage_scheme,
     )
     .await
     .switch()?;
 
-    let key = key_store.key.get_inner().peek();
+    let key = key_store.encryption_key();
 
     if let Some(addr) = &update_customer.address {
         let customer_address: api_models::payments::AddressDetails = addr.clone();
@@ -381,6 +391,7 @@ pub async fn update_customer(
             &merchant_account.merchant_id,
             update_address,
             &key_store,
+            merchant_account.storage_scheme,
         )
         .await
         .switch()
@@ -420,6 +431,7 @@ pub async fn update_customer(
             .switch()
             .attach_printable("Failed while encrypting while updating customer")?,
             &key_store,
+            merchant_account.storage_scheme,
         )
         .await
         .switch()?;
@@ -430,3 +442,116 @@ pub async fn update_customer(
         customer_update_response,
     ))
 }
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn list_customers(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    constraints: customers::CustomerListConstraints,
+) -> errors::RouterResponse<customers::CustomerListResponse> {
+    use std::collections::HashMap;
+
+    use common_utils::consts::{CUSTOMERS_LIST_DEFAULT_LIMIT, CUSTOMERS_LIST_MAX_LIMIT};
+    use error_stack::report;
+
+    use crate::core::errors::StorageErrorExt;
+
+    let db = state.store.as_ref();
+    let merchant_id = &merchant_account.merchant_id;
+    let limit = constraints.limit.unwrap_or(CUSTOMERS_LIST_DEFAULT_LIMIT);
+    crate::utils::when(!(1..=CUSTOMERS_LIST_MAX_LIMIT).contains(&limit), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be in between 1 and {CUSTOMERS_LIST_MAX_LIMIT}"),
+        }))
+    })?;
+
+    let blind_indexes = |field, value: &str| {
+        domain::generate_blind_indexes(&key_store, field, value)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while computing the blind index of the search value")
+    };
+    let blind_index =
+        |field, value: Option<&String>| value.map(|value| blind_indexes(field, value)).transpose();
+
+    // One more customer than the limit is fetched to find out whether there are more customers
+    let customer_list_constraints = storage::CustomerListConstraints {
+        limit: i64::from(limit) + 1,
+        starting_after: constraints.starting_after,
+        email_hash: blind_index(
+            domain::BlindIndexField::Email,
+            constraints.email.as_ref().map(|email| email.peek()),
+        )?,
+        phone_hash: blind_index(
+            domain::BlindIndexField::Phone,
+            constraints.phone.as_ref().map(|phone| phone.peek()),
+        )?,
+        name_hash: blind_index(
+            domain::BlindIndexField::Name,
+            constraints.name.as_ref().map(|name| name.peek()),
+        )?,
+        redacted_name_hash: blind_indexes(domain::BlindIndexField::Name, REDACTED)?,
+    };
+
+    let mut customers = db
+        .list_customers_by_merchant_id(merchant_id, customer_list_constraints, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let has_more = customers.len() > usize::try_from(limit).unwrap_or(usize::MAX);
+    customers.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+    let (payment_methods_count, mandates_count) = if constraints.include_counts {
+        let customer_ids = customers
+            .iter()
+            .map(|customer| customer.customer_id.clone())
+            .collect::<Vec<_>>();
+        let payment_methods_count = db
+            .get_count_of_payment_methods_by_merchant_id_customer_ids(
+                merchant_id,
+                customer_ids.clone(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while counting the payment methods of customers")?;
+        let mandates_count = db
+            .get_count_of_mandates_by_merchant_id_customer_ids(merchant_id, customer_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while counting the mandates of customers")?;
+        (
+            Some(payment_methods_count.into_iter().collect::<HashMap<_, _>>()),
+            Some(mandates_count.into_iter().collect::<HashMap<_, _>>()),
+        )
+    } else {
+        (None, None)
+    };
+
+    let data = customers
+        .into_iter()
+        .map(|customer| {
+            let count = |counts: &Option<HashMap<String, i64>>| {
+                counts.as_ref().map(|counts| {
+                    counts
+                        .get(&customer.customer_id)
+                        .copied()
+                        .unwrap_or_default()
+                })
+            };
+            customers::CustomerListItem {
+                payment_methods_count: count(&payment_methods_count),
+                mandates_count: count(&mandates_count),
+                customer: customers::CustomerResponse::from(customer).into_inner(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerListResponse {
+            size: data.len(),
+            has_more,
+            data,
+        },
+    ))
+}
@@ -1,5 +1,8 @@
 use api_models::{disputes as dispute_models, files as files_api_models};
-use common_utils::ext_traits::ValueExt;
+use common_utils::{
+    consts::{DISPUTES_LIST_DEFAULT_LIMIT, DISPUTES_LIST_MAX_LIMIT},
+    ext_traits::ValueExt,
+};
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 pub mod transformers;
@@ -15,7 +18,7 @@ use crate::{
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
@@ -46,12 +49,7 @@ pub async fn retrieve_disputes_list(
     merchant_account: domain::MerchantAccount,
     constraints: api_models::disputes::DisputeListConstraints,
 ) -> RouterResponse<Vec<api_models::disputes::DisputeResponse>> {
-    let disputes = state
-        .store
-        .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Unable to retrieve disputes")?;
+    let disputes = find_disputes(&state, &merchant_account.merchant_id, constraints).await?;
     let disputes_list = disputes
         .into_iter()
         .map(api_models::disputes::DisputeResponse::foreign_from)
@@ -59,6 +57,76 @@ pub async fn retrieve_disputes_list(
     Ok(services::ApplicationResponse::Json(disputes_list))
 }
 
+/// Lists a page of the disputes of the merchant, along with whether more disputes follow it, for
+/// the clients paginating with the `starting_after` cursor
+#[instrument(skip(state))]
+pub async fn retrieve_disputes_page(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: api_models::disputes::DisputeListConstraints,
+) -> RouterResponse<dispute_models::DisputeListPage> {
+    let limit = constraints
+        .limit
+        .unwrap_or_else(|| i64::from(DISPUTES_LIST_DEFAULT_LIMIT));
+    utils::when(
+        !(1..=i64::from(DISPUTES_LIST_MAX_LIMIT)).contains(&limit),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {DISPUTES_LIST_MAX_LIMIT}"),
+            })
+        },
+    )?;
+
+    // One more dispute than the limit is fetched to find out whether more disputes follow the page
+    let mut disputes = find_disputes(
+        &state,
+        &merchant_account.merchant_id,
+        dispute_models::DisputeListConstraints {
+            limit: Some(limit + 1),
+            ..constraints
+        },
+    )
+    .await?;
+
+    let page_size = usize::try_from(limit)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let has_more = disputes.len() > page_size;
+    disputes.truncate(page_size);
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeListPage {
+            data: disputes
+                .into_iter()
+                .map(dispute_models::DisputeResponse::foreign_from)
+                .collect(),
+            has_more,
+        },
+    ))
+}
+
+async fn find_disputes(
+    state: &AppState,
+    merchant_id: &str,
+    constraints: api_models::disputes::DisputeListConstraints,
+) -> errors::RouterResult<Vec<storage::Dispute>> {
+    let starting_after = constraints.starting_after.clone();
+    state
+        .store
+        .find_disputes_by_merchant_id(merchant_id, constraints)
+        .await
+        .map_err(|error| {
+            // The disputes are not found only when the dispute to list them after doesn't exist
+            match starting_after {
+                Some(dispute_id) if error.current_context().is_db_not_found() => {
+                    error.change_context(errors::ApiErrorResponse::DisputeNotFound { dispute_id })
+                }
+                _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+            }
+        })
+        .attach_printable("Unable to retrieve disputes")
+}
+
 #[instrument(skip(state))]
 pub async fn accept_dispute(
     state: AppState,
@@ -256,6 +256,8 @@ pub enum WebhooksFlowError {
     DisputeWebhookValidationFailed,
     #[error("Outgoing webhook body encoding failed")]
     OutgoingWebhookEncodingFailed,
+    #[error("Outgoing webhook body encryption failed")]
+    OutgoingWebhookEncryptionFailed,
     #[error("Missing required field: {field_name}")]
     MissingRequiredField { field_name: &'static str },
 }
@@ -276,6 +278,32 @@ pub enum ApplePayDecryptionError {
     DerivingSharedSecretKeyFailed,
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum GooglePayDecryptionError {
+    #[error("Failed to base64 decode input data")]
+    Base64DecodingFailed,
+    #[error("Failed to parse input data")]
+    ParsingFailed,
+    #[error("Unsupported protocol version of the payment token")]
+    UnsupportedProtocolVersion,
+    #[error("No valid root signing key found")]
+    RootSigningKeyNotFound,
+    #[error("Key Deserialization failure")]
+    KeyDeserializationFailed,
+    #[error("Failed to verify the signature of the payment token")]
+    SignatureVerificationFailed,
+    #[error("Payment token or its signing key has expired")]
+    TokenExpired,
+    #[error("Failed to Derive a shared secret key")]
+    DerivingSharedSecretKeyFailed,
+    #[error("Failed to Derive the symmetric keys")]
+    DerivingSymmetricKeysFailed,
+    #[error("Failed to verify the tag of the encrypted message")]
+    MessageAuthenticationFailed,
+    #[error("Failed to decrypt input data")]
+    DecryptionFailed,
+}
+
 impl ConnectorError {
     pub fn is_connector_timeout(&self) -> bool {
         self == &Self::RequestTimeoutReceived
@@ -143,6 +143,14 @@ pub enum ApiErrorResponse {
     DuplicatePayout { payout_id: String },
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The config with the specified key already exists in our records")]
     DuplicateConfig,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A request with the same idempotency key is still being processed")]
+    IdempotencyKeyInUse,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_24", message = "The idempotency key has already been used with a different request")]
+    IdempotencyKeyReused,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_25", message = "The tenant '{tenant_id}' is not configured")]
+    InvalidTenant { tenant_id: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_26", message = "The tenant of the request could not be resolved")]
+    TenantNotResolved,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer does not exist in our records")]
@@ -169,6 +177,10 @@ pub enum ApiErrorResponse {
     ApiKeyNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
     PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Event with the given id '{id}' does not exist in our records")]
+    EventNotFound { id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payment link with the given id '{id}' does not exist in our records")]
+    PaymentLinkNotFound { id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Invalid mandate id passed from connector")]
     MandateSerializationFailed,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Unable to parse the mandate identifier passed from connector")]
@@ -183,6 +195,8 @@ pub enum ApiErrorResponse {
     PaymentNotSucceeded,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "The specified merchant connector account is disabled")]
     MerchantConnectorAccountDisabled,
+    #[error(error_type = ErrorType::ProcessingError, code = "HE_03", message = "The payment has been blocked, as the payment method used is in the blocklist of the merchant")]
+    PaymentBlocked,
     #[error(error_type= ErrorType::ObjectNotFound, code = "HE_04", message = "Successful payment not found for the given payment id")]
     SuccessfulPaymentNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "The connector provided in the request is incorrect or not available")]
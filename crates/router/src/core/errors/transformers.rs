@@ -155,6 +155,18 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::DuplicateConfig => {
                 AER::BadRequest(ApiError::new("HE", 1, "The config with the specified key already exists in our records", None))
             }
+            Self::IdempotencyKeyInUse => {
+                AER::Conflict(ApiError::new("HE", 1, "A request with the same idempotency key is still being processed", None))
+            }
+            Self::IdempotencyKeyReused => {
+                AER::BadRequest(ApiError::new("IR", 24, "The idempotency key has already been used with a different request", None))
+            }
+            Self::InvalidTenant { tenant_id } => {
+                AER::BadRequest(ApiError::new("IR", 25, format!("The tenant '{tenant_id}' is not configured"), None))
+            }
+            Self::TenantNotResolved => {
+                AER::BadRequest(ApiError::new("IR", 26, "The tenant of the request could not be resolved", None))
+            }
             Self::PaymentNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Payment does not exist in our records", None))
             }
@@ -187,6 +199,7 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
                 AER::BadRequest(ApiError::new("HE", 3, "Mandate Validation Failed", Some(Extra { reason: Some(reason.clone()), ..Default::default() })))
             }
             Self::PaymentNotSucceeded => AER::BadRequest(ApiError::new("HE", 3, "The payment has not succeeded yet. Please pass a successful payment to initiate refund", None)),
+            Self::PaymentBlocked => AER::BadRequest(ApiError::new("HE", 3, "The payment has been blocked, as the payment method used is in the blocklist of the merchant", None)),
             Self::SuccessfulPaymentNotFound => {
                 AER::NotFound(ApiError::new("HE", 4, "Successful payment not found for the given payment id", None))
             }
@@ -216,6 +229,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::BusinessProfileNotFound { id } => {
                 AER::NotFound(ApiError::new("HE", 2, format!("Business profile with the given id {id} does not exist"), None))
             }
+            Self::EventNotFound { id } => {
+                AER::NotFound(ApiError::new("HE", 2, format!("Event with the given id {id} does not exist"), None))
+            }
+            Self::PaymentLinkNotFound { id } => {
+                AER::NotFound(ApiError::new("HE", 2, format!("Payment link with the given id {id} does not exist"), None))
+            }
             Self::FileNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "File does not exist in our records", None))
             }
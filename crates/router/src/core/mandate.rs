@@ -32,7 +32,11 @@ pub async fn get_mandate(
     let mandate = state
         .store
         .as_ref()
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
     Ok(services::ApplicationResponse::Json(
@@ -54,6 +58,7 @@ pub async fn revoke_mandate(
             storage::MandateUpdate::StatusUpdate {
                 mandate_status: storage::enums::MandateStatus::Revoked,
             },
+            merchant_account.storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
@@ -72,13 +77,14 @@ pub async fn update_connector_mandate_id(
     merchant_account: String,
     mandate_ids_opt: Option<api_models::payments::MandateIds>,
     resp: Result<types::PaymentsResponseData, types::ErrorResponse>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> RouterResponse<mandates::MandateResponse> {
     let connector_mandate_id = Option::foreign_try_from(resp)?;
     //Ignore updation if the payment_attempt mandate_id or connector_mandate_id is not present
     if let Some((mandate_ids, connector_id)) = mandate_ids_opt.zip(connector_mandate_id) {
         let mandate_id = &mandate_ids.mandate_id;
         let mandate = db
-            .find_mandate_by_merchant_id_mandate_id(&merchant_account, mandate_id)
+            .find_mandate_by_merchant_id_mandate_id(&merchant_account, mandate_id, storage_scheme)
             .await
             .change_context(errors::ApiErrorResponse::MandateNotFound)?;
         // only update the connector_mandate_id if existing is none
@@ -89,6 +95,7 @@ pub async fn update_connector_mandate_id(
                 storage::MandateUpdate::ConnectorReferenceUpdate {
                     connector_mandate_ids: Some(connector_id),
                 },
+                storage_scheme,
             )
             .await
             .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
@@ -143,6 +150,7 @@ pub async fn mandate_procedure<F, FData>(
     mut resp: types::RouterData<F, FData, types::PaymentsResponseData>,
     maybe_customer: &Option<domain::Customer>,
     pm_id: Option<String>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> errors::RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     FData: MandateBehaviour,
@@ -154,7 +162,11 @@ where
                 let mandate_id = &mandate_id.mandate_id;
                 let mandate = state
                     .store
-                    .find_mandate_by_merchant_id_mandate_id(resp.merchant_id.as_ref(), mandate_id)
+                    .find_mandate_by_merchant_id_mandate_id(
+                        resp.merchant_id.as_ref(),
+                        mandate_id,
+                        storage_scheme,
+                    )
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
                 let mandate = match mandate.mandate_type {
@@ -166,6 +178,7 @@ where
                             storage::MandateUpdate::StatusUpdate {
                                 mandate_status: storage_enums::MandateStatus::Revoked,
                             },
+                            storage_scheme,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
@@ -180,6 +193,7 @@ where
                                         + resp.request.get_amount(),
                                 ),
                             },
+                            storage_scheme,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
@@ -257,7 +271,7 @@ where
                         }));
                         state
                             .store
-                            .insert_mandate(new_mandate_data)
+                            .insert_mandate(new_mandate_data, storage_scheme)
                             .await
                             .to_duplicate_response(errors::ApiErrorResponse::DuplicateMandate)?;
                         metrics::MANDATE_COUNT.add(
@@ -0,0 +1,539 @@
+use api_models::{
+    admin::PaymentLinkConfig, payment_methods::ResponsePaymentMethodsEnabled,
+    payments as payment_types,
+};
+use common_utils::ext_traits::ValueExt;
+use error_stack::{IntoReport, ResultExt};
+use masking::ExposeInterface;
+use router_env::{instrument, tracing};
+use time::Duration;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services::{self, PaymentLinkPaymentMethod, RedirectForm},
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{self as router_utils, OptionExt},
+};
+
+const DEFAULT_PRIMARY_COLOR: &str = "#006DF9";
+const DEFAULT_BACKGROUND_COLOR: &str = "#F8F9FB";
+
+pub fn validate_payment_link_request(request: &api::PaymentsRequest) -> RouterResult<()> {
+    router_utils::when(request.confirm.unwrap_or(false), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message:
+                "A payment link cannot be generated for a payment which is confirmed on creation"
+                    .to_string(),
+        })
+    })?;
+
+    router_utils::when(request.payment_link_expiry == Some(0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payment_link_expiry must be greater than zero".to_string(),
+        })
+    })?;
+
+    Ok(())
+}
+
+/// The customer pays through the link by confirming the payment with its client secret, so the
+/// link cannot outlive the client secret, which expires after the intent fulfillment time
+pub fn make_payment_link(
+    base_url: &str,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+    money: (api::Amount, storage_enums::Currency),
+    payment_link_expiry: Option<u32>,
+) -> RouterResult<storage::PaymentLinkNew> {
+    let intent_fulfillment_time = merchant_account
+        .intent_fulfillment_time
+        .unwrap_or(consts::DEFAULT_FULFILLMENT_TIME);
+    let payment_link_expiry = payment_link_expiry
+        .map(i64::from)
+        .unwrap_or(intent_fulfillment_time);
+
+    router_utils::when(payment_link_expiry > intent_fulfillment_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payment_link_expiry cannot be greater than the intent fulfillment time of the merchant, which is {intent_fulfillment_time} seconds"
+            ),
+        })
+    })?;
+
+    let (amount, currency) = money;
+    let merchant_id = &merchant_account.merchant_id;
+
+    Ok(storage::PaymentLinkNew {
+        payment_link_id: router_utils::generate_id(consts::ID_LENGTH, "plink"),
+        payment_id: payment_id.to_string(),
+        link_to_pay: format!("{base_url}/payment_link/{merchant_id}/{payment_id}"),
+        merchant_id: merchant_id.to_string(),
+        amount: amount.into(),
+        currency: Some(currency),
+        fulfilment_time: common_utils::date_time::now()
+            .saturating_add(Duration::seconds(payment_link_expiry)),
+    })
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_payment_link(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<payment_types::RetrievePaymentLinkResponse> {
+    let db = &*state.store;
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(&payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound {
+            id: payment_link_id.clone(),
+        })?;
+
+    router_utils::when(
+        payment_link.merchant_id != merchant_account.merchant_id,
+        || {
+            Err(errors::ApiErrorResponse::PaymentLinkNotFound {
+                id: payment_link_id,
+            })
+        },
+    )?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_link.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let status = if is_payment_link_expired(&payment_link) {
+        payment_types::PaymentLinkStatus::Expired
+    } else {
+        payment_types::PaymentLinkStatus::Active
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::RetrievePaymentLinkResponse {
+            payment_link_id: payment_link.payment_link_id,
+            payment_id: payment_link.payment_id,
+            merchant_id: payment_link.merchant_id,
+            link_to_pay: payment_link.link_to_pay,
+            amount: payment_link.amount,
+            currency: payment_link.currency,
+            created_at: payment_link.created_at,
+            expiry: payment_link.fulfilment_time,
+            status,
+            payment_status: payment_intent.status,
+        },
+    ))
+}
+
+/// Renders the hosted checkout page of the payment, on which the customer can pay with one of the
+/// payment methods enabled for the merchant
+#[instrument(skip(state, merchant_account, key_store))]
+pub async fn initiate_payment_link_flow(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+) -> RouterResponse<()> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_link_id = payment_intent.payment_link_id.clone().ok_or(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "A payment link was not generated for this payment".to_string(),
+        },
+    )?;
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(&payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound {
+            id: payment_link_id,
+        })?;
+
+    let payment_link_config = get_payment_link_config(db, payment_intent.profile_id.as_deref())
+        .await?
+        .unwrap_or_default();
+
+    let message = get_payment_link_message(&payment_link, payment_intent.status);
+    let payment_methods = if message.is_none() {
+        get_payment_link_payment_methods(
+            &state,
+            &merchant_account,
+            &key_store,
+            payment_intent.client_secret.clone(),
+        )
+        .await?
+    } else {
+        vec![]
+    };
+
+    let currency = payment_intent
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let amount = currency
+        .to_currency_base_unit_with_zero_decimal_check(payment_intent.amount)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })?;
+
+    let merchant_name = payment_link_config
+        .display_name
+        .or_else(|| {
+            merchant_account
+                .merchant_name
+                .map(|merchant_name| merchant_name.into_inner().expose())
+        })
+        .unwrap_or_else(|| merchant_account.merchant_id.clone());
+
+    let redirect_form = RedirectForm::PaymentLink {
+        client_secret: payment_intent
+            .client_secret
+            .get_required_value("client_secret")?,
+        publishable_key: merchant_account
+            .publishable_key
+            .get_required_value("publishable_key")?,
+        merchant_name,
+        logo: payment_link_config.logo.map(|logo| logo.to_string()),
+        primary_color: payment_link_config
+            .primary_color
+            .unwrap_or_else(|| DEFAULT_PRIMARY_COLOR.to_string()),
+        background_color: payment_link_config
+            .background_color
+            .unwrap_or_else(|| DEFAULT_BACKGROUND_COLOR.to_string()),
+        description: payment_intent.description,
+        // The customer is redirected back to the link after authentication, if the merchant has
+        // not configured a return url, where the outcome of the payment is displayed
+        return_url: payment_intent
+            .return_url
+            .unwrap_or_else(|| payment_link.link_to_pay.clone()),
+        payment_id,
+        payment_methods,
+        message,
+    };
+
+    Ok(services::ApplicationResponse::Form(Box::new(
+        services::RedirectionFormData {
+            redirect_form,
+            payment_method_data: None,
+            amount,
+            currency: currency.to_string(),
+        },
+    )))
+}
+
+fn is_payment_link_expired(payment_link: &storage::PaymentLink) -> bool {
+    common_utils::date_time::now() > payment_link.fulfilment_time
+}
+
+/// The client secret of a payment made through a payment link expires along with the link, so
+/// that the payment cannot be confirmed after the link has expired
+pub async fn validate_client_secret_against_payment_link(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let payment_link_id = match payment_intent.payment_link_id.as_ref() {
+        Some(payment_link_id) => payment_link_id,
+        None => return Ok(()),
+    };
+
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound {
+            id: payment_link_id.clone(),
+        })?;
+
+    router_utils::when(is_payment_link_expired(&payment_link), || {
+        Err(errors::ApiErrorResponse::ClientSecretExpired)
+    })?;
+
+    Ok(())
+}
+
+/// The message displayed on the hosted checkout page instead of the checkout form, when the
+/// payment can no longer be paid through the link
+fn get_payment_link_message(
+    payment_link: &storage::PaymentLink,
+    status: storage_enums::IntentStatus,
+) -> Option<String> {
+    match status {
+        storage_enums::IntentStatus::RequiresPaymentMethod
+        | storage_enums::IntentStatus::RequiresConfirmation => {
+            is_payment_link_expired(payment_link)
+                .then(|| "This payment link has expired".to_string())
+        }
+        storage_enums::IntentStatus::Succeeded
+        | storage_enums::IntentStatus::PartiallyCaptured
+        | storage_enums::IntentStatus::RequiresCapture => {
+            Some("Your payment was successful".to_string())
+        }
+        storage_enums::IntentStatus::Processing => {
+            Some("Your payment is being processed".to_string())
+        }
+        storage_enums::IntentStatus::Failed => Some("Your payment has failed".to_string()),
+        storage_enums::IntentStatus::Cancelled => {
+            Some("This payment has been cancelled".to_string())
+        }
+        storage_enums::IntentStatus::RequiresCustomerAction
+        | storage_enums::IntentStatus::RequiresMerchantAction => {
+            Some("Your payment is pending".to_string())
+        }
+    }
+}
+
+async fn get_payment_link_config(
+    db: &dyn StorageInterface,
+    profile_id: Option<&str>,
+) -> RouterResult<Option<PaymentLinkConfig>> {
+    match profile_id {
+        Some(profile_id) => db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?
+            .payment_link_config
+            .map(|payment_link_config| {
+                payment_link_config
+                    .parse_value("PaymentLinkConfig")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse the payment link config of the profile")
+            })
+            .transpose(),
+        None => Ok(None),
+    }
+}
+
+async fn get_payment_link_payment_methods(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    client_secret: Option<String>,
+) -> RouterResult<Vec<PaymentLinkPaymentMethod>> {
+    let payment_methods_list = cards::list_payment_methods(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        api::PaymentMethodListRequest {
+            client_secret,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    match payment_methods_list {
+        services::ApplicationResponse::Json(payment_methods_list) => Ok(
+            get_payment_methods_payable_on_link(payment_methods_list.payment_methods),
+        ),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response while listing payment methods"),
+    }
+}
+
+/// Cards are entered on the hosted checkout page, the other payment methods are supported only if
+/// the customer can be redirected to pay without entering any details
+fn get_payment_methods_payable_on_link(
+    payment_methods: Vec<ResponsePaymentMethodsEnabled>,
+) -> Vec<PaymentLinkPaymentMethod> {
+    payment_methods
+        .into_iter()
+        .flat_map(
+            |payment_methods_enabled| match payment_methods_enabled.payment_method {
+                storage_enums::PaymentMethod::Card => vec![PaymentLinkPaymentMethod {
+                    payment_method: storage_enums::PaymentMethod::Card,
+                    payment_method_type: None,
+                    payment_method_data: None,
+                }],
+                storage_enums::PaymentMethod::Wallet => payment_methods_enabled
+                    .payment_method_types
+                    .into_iter()
+                    .filter_map(|payment_method_type| {
+                        get_redirect_wallet_data(payment_method_type.payment_method_type).map(
+                            |wallet_data| PaymentLinkPaymentMethod {
+                                payment_method: storage_enums::PaymentMethod::Wallet,
+                                payment_method_type: Some(payment_method_type.payment_method_type),
+                                payment_method_data: Some(api::PaymentMethodData::Wallet(
+                                    wallet_data,
+                                )),
+                            },
+                        )
+                    })
+                    .collect(),
+                _ => vec![],
+            },
+        )
+        .collect()
+}
+
+fn get_redirect_wallet_data(
+    payment_method_type: storage_enums::PaymentMethodType,
+) -> Option<api_models::payments::WalletData> {
+    match payment_method_type {
+        storage_enums::PaymentMethodType::Paypal => Some(
+            payment_types::WalletData::PaypalRedirect(payment_types::PaypalRedirection {}),
+        ),
+        storage_enums::PaymentMethodType::AliPay => Some(
+            payment_types::WalletData::AliPayRedirect(payment_types::AliPayRedirection {}),
+        ),
+        storage_enums::PaymentMethodType::AliPayHk => Some(
+            payment_types::WalletData::AliPayHkRedirect(payment_types::AliPayHkRedirection {}),
+        ),
+        storage_enums::PaymentMethodType::Momo => Some(payment_types::WalletData::MomoRedirect(
+            payment_types::MomoRedirection {},
+        )),
+        storage_enums::PaymentMethodType::KakaoPay => Some(
+            payment_types::WalletData::KakaoPayRedirect(payment_types::KakaoPayRedirection {}),
+        ),
+        storage_enums::PaymentMethodType::GoPay => Some(payment_types::WalletData::GoPayRedirect(
+            payment_types::GoPayRedirection {},
+        )),
+        storage_enums::PaymentMethodType::Gcash => Some(payment_types::WalletData::GcashRedirect(
+            payment_types::GcashRedirection {},
+        )),
+        storage_enums::PaymentMethodType::Dana => Some(payment_types::WalletData::DanaRedirect {}),
+        storage_enums::PaymentMethodType::MobilePay => {
+            Some(payment_types::WalletData::MobilePayRedirect(Box::new(
+                payment_types::MobilePayRedirection {},
+            )))
+        }
+        storage_enums::PaymentMethodType::Twint => {
+            Some(payment_types::WalletData::TwintRedirect {})
+        }
+        storage_enums::PaymentMethodType::Vipps => {
+            Some(payment_types::WalletData::VippsRedirect {})
+        }
+        storage_enums::PaymentMethodType::TouchNGo => {
+            Some(payment_types::WalletData::TouchNGoRedirect(Box::new(
+                payment_types::TouchNGoRedirection {},
+            )))
+        }
+        storage_enums::PaymentMethodType::WeChatPay => {
+            Some(payment_types::WalletData::WeChatPayRedirect(Box::new(
+                payment_types::WeChatPayRedirection {},
+            )))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::payment_methods::ResponsePaymentMethodTypes;
+
+    use super::*;
+
+    fn get_payment_method_types(
+        payment_method_types: Vec<storage_enums::PaymentMethodType>,
+    ) -> Vec<ResponsePaymentMethodTypes> {
+        payment_method_types
+            .into_iter()
+            .map(|payment_method_type| ResponsePaymentMethodTypes {
+                payment_method_type,
+                payment_experience: None,
+                card_networks: None,
+                bank_names: None,
+                bank_debits: None,
+                bank_transfers: None,
+                required_fields: None,
+                surcharge_details: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_payment_methods_payable_on_link() {
+        let payment_methods = get_payment_methods_payable_on_link(vec![
+            ResponsePaymentMethodsEnabled {
+                payment_method: storage_enums::PaymentMethod::Card,
+                payment_method_types: get_payment_method_types(vec![
+                    storage_enums::PaymentMethodType::Credit,
+                    storage_enums::PaymentMethodType::Debit,
+                ]),
+            },
+            ResponsePaymentMethodsEnabled {
+                payment_method: storage_enums::PaymentMethod::Wallet,
+                payment_method_types: get_payment_method_types(vec![
+                    storage_enums::PaymentMethodType::ApplePay,
+                    storage_enums::PaymentMethodType::Paypal,
+                ]),
+            },
+        ]);
+
+        assert_eq!(payment_methods.len(), 2);
+        assert_eq!(payment_methods[0].payment_method_type, None);
+        assert_eq!(
+            payment_methods[1].payment_method_type,
+            Some(storage_enums::PaymentMethodType::Paypal)
+        );
+        assert_eq!(
+            payment_methods[1].payment_method_data,
+            Some(api::PaymentMethodData::Wallet(
+                api_models::payments::WalletData::PaypalRedirect(
+                    api_models::payments::PaypalRedirection {}
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_payment_link_message() {
+        let now = common_utils::date_time::now();
+        let mut payment_link = storage::PaymentLink {
+            payment_link_id: "plink_1".to_string(),
+            payment_id: "pay_1".to_string(),
+            link_to_pay: "https://example.com/payment_link/merchant_1/pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            amount: 100,
+            currency: Some(storage_enums::Currency::USD),
+            created_at: now,
+            last_modified_at: now,
+            fulfilment_time: now.saturating_add(Duration::minutes(15)),
+        };
+
+        assert_eq!(
+            get_payment_link_message(
+                &payment_link,
+                storage_enums::IntentStatus::RequiresPaymentMethod
+            ),
+            None
+        );
+
+        payment_link.fulfilment_time = now.saturating_sub(Duration::minutes(1));
+        assert_eq!(
+            get_payment_link_message(
+                &payment_link,
+                storage_enums::IntentStatus::RequiresPaymentMethod
+            )
+            .unwrap(),
+            "This payment link has expired"
+        );
+        assert_eq!(
+            get_payment_link_message(&payment_link, storage_enums::IntentStatus::Succeeded)
+                .unwrap(),
+            "Your payment was successful"
+        );
+    }
+}
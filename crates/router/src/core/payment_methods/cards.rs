@@ -31,7 +31,7 @@ use crate::{
             transformers::{self as payment_methods},
             vault,
         },
-        payments::helpers,
+        payments::{helpers, surcharge},
     },
     db, logger,
     pii::prelude::*,
@@ -305,15 +305,13 @@ pub async fn decode_and_decrypt_locker_data(
     key_store: &domain::MerchantKeyStore,
     enc_card_data: String,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
-    // Fetch key
-    let key = key_store.key.get_inner().peek();
     // Decode
     let decoded_bytes = hex::decode(&enc_card_data)
         .into_report()
         .change_context(errors::VaultError::ResponseDeserializationFailed)
         .attach_printable("Failed to decode hex string into bytes")?;
     // Decrypt
-    decrypt(Some(Encryption::new(decoded_bytes.into())), key)
+    decrypt(Some(Encryption::new(decoded_bytes.into())), key_store)
         .await
         .change_context(errors::VaultError::FetchPaymentMethodFailed)?
         .map_or(
@@ -336,7 +334,20 @@ pub async fn get_payment_method_from_hs_locker<'a>(
     #[cfg(feature = "kms")]
     let jwekey = &state.kms_secrets;
 
-    let payment_method_data = if !locker.mock_locker {
+    let payment_method_data = if locker.builtin_vault {
+        let enc_card_data = vault::Vault::get_card_from_builtin_vault(
+            state,
+            merchant_id,
+            customer_id,
+            payment_method_reference,
+        )
+        .await
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)?
+        .enc_card_data
+        .get_required_value("enc_card_data")
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
+        decode_and_decrypt_locker_data(key_store, enc_card_data.peek().to_string()).await?
+    } else if !locker.mock_locker {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
             locker,
@@ -391,7 +402,9 @@ pub async fn call_to_locker_hs<'a>(
     #[cfg(feature = "kms")]
     let jwekey = &state.kms_secrets;
     let db = &*state.store;
-    let stored_card_response = if !locker.mock_locker {
+    let stored_card_response = if locker.builtin_vault {
+        vault::Vault::store_card_in_builtin_vault(state, payload, customer_id).await?
+    } else if !locker.mock_locker {
         let request = payment_methods::mk_add_locker_request_hs(jwekey, locker, payload).await?;
         let response = services::call_connector_api(state, request)
             .await
@@ -448,7 +461,13 @@ pub async fn get_card_from_hs_locker<'a>(
     #[cfg(feature = "kms")]
     let jwekey = &state.kms_secrets;
 
-    if !locker.mock_locker {
+    if locker.builtin_vault {
+        vault::Vault::get_card_from_builtin_vault(state, merchant_id, customer_id, card_reference)
+            .await?
+            .card
+            .get_required_value("Card")
+            .change_context(errors::VaultError::FetchCardFailed)
+    } else if !locker.mock_locker {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
             locker,
@@ -501,6 +520,13 @@ pub async fn delete_card_from_hs_locker<'a>(
     #[cfg(feature = "kms")]
     let jwekey = &state.kms_secrets;
 
+    if locker.builtin_vault {
+        return vault::Vault::delete_card_from_builtin_vault(state, merchant_id, card_reference)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while deleting card from the built-in vault");
+    }
+
     let request = payment_methods::mk_delete_card_request_hs(
         jwekey,
         locker,
@@ -796,7 +822,13 @@ pub async fn list_payment_methods(
     let shipping_address = payment_intent
         .as_ref()
         .async_map(|pi| async {
-            helpers::get_address_by_id(db, pi.shipping_address_id.clone(), &key_store).await
+            helpers::get_address_by_id(
+                db,
+                pi.shipping_address_id.clone(),
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
         })
         .await
         .transpose()?
@@ -805,7 +837,13 @@ pub async fn list_payment_methods(
     let billing_address = payment_intent
         .as_ref()
         .async_map(|pi| async {
-            helpers::get_address_by_id(db, pi.billing_address_id.clone(), &key_store).await
+            helpers::get_address_by_id(
+                db,
+                pi.billing_address_id.clone(),
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
         })
         .await
         .transpose()?
@@ -821,6 +859,7 @@ pub async fn list_payment_methods(
                         cust.as_str(),
                         &pi.merchant_id,
                         &key_store,
+                        merchant_account.storage_scheme,
                     )
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
@@ -870,6 +909,26 @@ pub async fn list_payment_methods(
         .await
         .transpose()?;
 
+    let surcharge_config = surcharge::get_surcharge_config(db, profile_id.as_deref()).await?;
+    let get_surcharge_details =
+        |payment_method_type: api_enums::PaymentMethodType,
+         card_network: Option<&api_enums::CardNetwork>| {
+            surcharge_config
+                .as_ref()
+                .zip(payment_intent.as_ref())
+                .and_then(|(surcharge_config, payment_intent)| {
+                    surcharge::get_surcharge_details(
+                        surcharge_config,
+                        payment_intent.amount,
+                        &surcharge::SurchargeCriteria {
+                            payment_method_type: Some(payment_method_type),
+                            card_network,
+                            country: billing_address.as_ref().and_then(|address| address.country),
+                        },
+                    )
+                })
+        };
+
     // filter out connectors based on the business country
     let filtered_mcas = helpers::filter_mca_based_on_business_profile(all_mcas, profile_id);
 
@@ -1126,6 +1185,7 @@ pub async fn list_payment_methods(
                     .get(key.0)
                     .and_then(|inner_hm| inner_hm.get(payment_method_types_hm.0))
                     .cloned(),
+                surcharge_details: get_surcharge_details(*payment_method_types_hm.0, None),
             })
         }
 
@@ -1143,6 +1203,10 @@ pub async fn list_payment_methods(
                 card_network_types.push(CardNetworkTypes {
                     card_network: card_network_type.0.clone(),
                     eligible_connectors: card_network_type.1.clone(),
+                    surcharge_details: get_surcharge_details(
+                        *payment_method_types_hm.0,
+                        Some(card_network_type.0),
+                    ),
                 })
             }
 
@@ -1158,6 +1222,7 @@ pub async fn list_payment_methods(
                     .get(key.0)
                     .and_then(|inner_hm| inner_hm.get(payment_method_types_hm.0))
                     .cloned(),
+                surcharge_details: None,
             })
         }
 
@@ -1186,6 +1251,7 @@ pub async fn list_payment_methods(
                     .get(&api_enums::PaymentMethod::BankRedirect)
                     .and_then(|inner_hm| inner_hm.get(key.0))
                     .cloned(),
+                surcharge_details: get_surcharge_details(payment_method_type, None),
             }
         })
     }
@@ -1217,6 +1283,7 @@ pub async fn list_payment_methods(
                     .get(&api_enums::PaymentMethod::BankDebit)
                     .and_then(|inner_hm| inner_hm.get(key.0))
                     .cloned(),
+                surcharge_details: get_surcharge_details(payment_method_type, None),
             }
         })
     }
@@ -1248,6 +1315,7 @@ pub async fn list_payment_methods(
                     .get(&api_enums::PaymentMethod::BankTransfer)
                     .and_then(|inner_hm| inner_hm.get(key.0))
                     .cloned(),
+                surcharge_details: get_surcharge_details(payment_method_type, None),
             }
         })
     }
@@ -1801,12 +1869,11 @@ pub async fn list_customer_payment_method(
         customer_id,
         &merchant_account.merchant_id,
         &key_store,
+        merchant_account.storage_scheme,
     )
     .await
     .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let key = key_store.key.get_inner().peek();
-
     let is_requires_cvv = db
         .find_config_by_key(format!("{}_requires_cvv", merchant_account.merchant_id).as_str())
         .await;
@@ -1840,7 +1907,7 @@ pub async fn list_customer_payment_method(
         let hyperswitch_token = generate_id(consts::ID_LENGTH, "token");
 
         let card = if pm.payment_method == enums::PaymentMethod::Card {
-            get_card_details(&pm, key, state, &hyperswitch_token).await?
+            get_card_details(&pm, &key_store, state, &hyperswitch_token).await?
         } else {
             None
         };
@@ -1932,7 +1999,7 @@ pub async fn list_customer_payment_method(
 
 async fn get_card_details(
     pm: &payment_method::PaymentMethod,
-    key: &[u8],
+    key: &domain::MerchantKeyStore,
     state: &routes::AppState,
     hyperswitch_token: &str,
 ) -> errors::RouterResult<Option<api::CardDetailFromLocker>> {
@@ -2278,7 +2345,7 @@ pub async fn create_encrypted_payment_method_data(
     key_store: &domain::MerchantKeyStore,
     pm_data: Option<PaymentMethodsData>,
 ) -> Option<Encryption> {
-    let key = key_store.key.get_inner().peek();
+    let key = key_store.encryption_key();
 
     let pm_data_encrypted: Option<Encryption> = pm_data
         .as_ref()
@@ -28,6 +28,8 @@ use crate::{core::payment_methods::transformers as payment_methods, services, ut
 #[cfg(feature = "basilisk")]
 use crate::{db, types::storage::ProcessTrackerExt};
 
+pub mod builtin;
+
 #[cfg(feature = "basilisk")]
 const VAULT_SERVICE_NAME: &str = "CARD";
 #[cfg(feature = "basilisk")]
@@ -622,6 +624,54 @@ pub struct MockTokenizeDBValue {
 
 pub struct Vault;
 
+/// Storage of the saved cards of customers in the built-in vault
+impl Vault {
+    pub async fn store_card_in_builtin_vault(
+        state: &routes::AppState,
+        payload: &super::transformers::StoreLockerReq<'_>,
+        customer_id: &str,
+    ) -> CustomResult<super::transformers::StoreCardResp, errors::VaultError> {
+        builtin::store_in_vault(state, payload, customer_id).await
+    }
+
+    pub async fn get_card_from_builtin_vault(
+        state: &routes::AppState,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<super::transformers::RetrieveCardRespPayload, errors::VaultError> {
+        builtin::retrieve_from_vault(state, merchant_id, customer_id, card_reference).await
+    }
+
+    pub async fn delete_card_from_builtin_vault(
+        state: &routes::AppState,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<super::transformers::DeleteCardResp, errors::VaultError> {
+        builtin::delete_from_vault(state, merchant_id, card_reference).await
+    }
+
+    /// Rotates the data key of the merchant in the built-in vault, returning the version of the
+    /// new data key
+    pub async fn rotate_builtin_vault_data_key(
+        state: &routes::AppState,
+        merchant_id: &str,
+    ) -> CustomResult<i32, errors::VaultError> {
+        builtin::rotate_data_key(state, merchant_id).await
+    }
+
+    /// Re-encrypts a batch of the cards of the merchant in the built-in vault with the given
+    /// version of the data key, returning the number of cards re-encrypted
+    pub async fn re_encrypt_builtin_vault_cards(
+        state: &routes::AppState,
+        merchant_id: &str,
+        key_version: i32,
+        limit: i64,
+    ) -> CustomResult<usize, errors::VaultError> {
+        builtin::re_encrypt_cards(state, merchant_id, key_version, limit).await
+    }
+}
+
 #[cfg(not(feature = "basilisk"))]
 impl Vault {
     #[instrument(skip_all)]
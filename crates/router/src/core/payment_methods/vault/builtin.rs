@@ -0,0 +1,400 @@
+//! Card vault built into the application, used instead of an external locker when
+//! `locker.builtin_vault` is enabled.
+//!
+//! The cards are stored in the `vault` schema of the application database. Each card is encrypted
+//! with a data key of the merchant, and the data keys are stored wrapped (encrypted) by the master
+//! key. Every retrieval of the card data is recorded in the vault access log.
+
+use std::collections::HashMap;
+
+use common_utils::{
+    crypto::{self, SignMessage},
+    date_time,
+    ext_traits::{ByteSliceExt, Encode},
+};
+use diesel_models::encryption::Encryption;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret, StrongSecret};
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult},
+        payment_methods::transformers as payment_methods,
+    },
+    db, routes, services,
+    types::{domain::types as domain_types, storage},
+    utils::generate_id,
+};
+
+/// Context with which the key of the card fingerprints is derived from the master key
+const FINGERPRINT_KEY_CONTEXT: &[u8] = b"vault_card_fingerprint";
+
+/// The data stored in the vault, in the shape of the payload returned when retrieving a card
+#[derive(Serialize)]
+struct VaultData<'a> {
+    card: Option<&'a payment_methods::Card>,
+    enc_card_data: Option<&'a str>,
+}
+
+async fn unwrap_data_key(
+    db: &dyn db::StorageInterface,
+    data_key: storage::VaultDataKey,
+) -> CustomResult<StrongSecret<Vec<u8>>, errors::VaultError> {
+    domain_types::decrypt::<Vec<u8>, masking::WithType>(
+        Some(data_key.wrapped_key),
+        db.get_master_key(),
+    )
+    .await
+    .change_context(errors::VaultError::FetchCardFailed)
+    .attach_printable("Failed to unwrap the vault data key")?
+    .map(|key| StrongSecret::new(key.into_inner().expose()))
+    .ok_or(report!(errors::VaultError::FetchCardFailed))
+}
+
+/// Generates a new data key for the merchant, wrapped with the master key
+async fn generate_data_key(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    key_version: i32,
+) -> CustomResult<storage::VaultDataKeyNew, errors::VaultError> {
+    let key = services::generate_aes256_key()
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to generate a vault data key")?;
+    let master_key = domain_types::EncryptionKey::unversioned(db.get_master_key())
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Invalid master key")?;
+    let wrapped_key = domain_types::encrypt(Secret::new(key.to_vec()), master_key)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to wrap the vault data key")?;
+
+    Ok(storage::VaultDataKeyNew {
+        merchant_id: merchant_id.to_owned(),
+        key_version,
+        wrapped_key: wrapped_key.into(),
+        is_active: true,
+        created_at: date_time::now(),
+    })
+}
+
+async fn create_data_key(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    key_version: i32,
+) -> CustomResult<storage::VaultDataKey, errors::VaultError> {
+    let data_key = generate_data_key(db, merchant_id, key_version).await?;
+    db.insert_vault_data_key(data_key)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to insert the vault data key")
+}
+
+/// Returns the active data key of the merchant, creating it for the first card of the merchant
+async fn get_active_data_key(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+) -> CustomResult<(i32, StrongSecret<Vec<u8>>), errors::VaultError> {
+    let data_key = match db
+        .find_active_vault_data_key_optional(merchant_id)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?
+    {
+        Some(data_key) => data_key,
+        None => match create_data_key(db, merchant_id, 1).await {
+            Ok(data_key) => data_key,
+            // The key was created by a concurrent request
+            Err(error) => db
+                .find_active_vault_data_key_optional(merchant_id)
+                .await
+                .change_context(errors::VaultError::SaveCardFailed)?
+                .ok_or(error)?,
+        },
+    };
+    let key_version = data_key.key_version;
+    Ok((key_version, unwrap_data_key(db, data_key).await?))
+}
+
+async fn get_data_key(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    key_version: i32,
+) -> CustomResult<StrongSecret<Vec<u8>>, errors::VaultError> {
+    let data_key = db
+        .find_vault_data_key_by_merchant_id_key_version(merchant_id, key_version)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to find the vault data key")?;
+    unwrap_data_key(db, data_key).await
+}
+
+/// Keyed hash of the stored data, used to find out whether a customer already stored a card.
+///
+/// The key of the hash is derived from the master key, so that the master key itself is used only
+/// to wrap the data keys, and the fingerprints remain stable across rotations of the data keys.
+fn generate_fingerprint(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+    data: &str,
+) -> CustomResult<String, errors::VaultError> {
+    crypto::HmacSha256
+        .sign_message(db.get_master_key(), FINGERPRINT_KEY_CONTEXT)
+        .and_then(|fingerprint_key| {
+            crypto::HmacSha256.sign_message(
+                &fingerprint_key,
+                format!("{merchant_id}:{customer_id}:{data}").as_bytes(),
+            )
+        })
+        .map(hex::encode)
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to generate the fingerprint of the card")
+}
+
+async fn encrypt_data(
+    data: &VaultData<'_>,
+    key: &StrongSecret<Vec<u8>>,
+) -> CustomResult<Encryption, errors::VaultError> {
+    let data = Encode::<VaultData<'_>>::encode_to_vec(data)
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+    let key = domain_types::EncryptionKey::unversioned(key.peek())
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Invalid vault data key")?;
+    domain_types::encrypt(Secret::new(data), key)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to encrypt the card data")
+        .map(Encryption::from)
+}
+
+async fn decrypt_data(
+    enc_data: Encryption,
+    key: &StrongSecret<Vec<u8>>,
+) -> CustomResult<Secret<Vec<u8>>, errors::VaultError> {
+    domain_types::decrypt::<Vec<u8>, masking::WithType>(Some(enc_data), key.peek())
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to decrypt the card data")?
+        .map(|data| data.into_inner())
+        .ok_or(report!(errors::VaultError::FetchCardFailed))
+}
+
+#[instrument(skip_all)]
+pub async fn store_in_vault(
+    state: &routes::AppState,
+    payload: &payment_methods::StoreLockerReq<'_>,
+    customer_id: &str,
+) -> CustomResult<payment_methods::StoreCardResp, errors::VaultError> {
+    let db = &*state.store;
+    let (merchant_id, data, fingerprint_data) = match payload {
+        payment_methods::StoreLockerReq::LockerCard(store_card_req) => (
+            store_card_req.merchant_id,
+            VaultData {
+                card: Some(&store_card_req.card),
+                enc_card_data: None,
+            },
+            store_card_req.card.card_number.peek().as_str(),
+        ),
+        payment_methods::StoreLockerReq::LockerGeneric(store_generic_req) => (
+            store_generic_req.merchant_id,
+            VaultData {
+                card: None,
+                enc_card_data: Some(store_generic_req.enc_data.as_str()),
+            },
+            store_generic_req.enc_data.as_str(),
+        ),
+    };
+    let fingerprint = generate_fingerprint(db, merchant_id, customer_id, fingerprint_data)?;
+    let (key_version, key) = get_active_data_key(db, merchant_id).await?;
+    let enc_data = encrypt_data(&data, &key).await?;
+
+    let existing_card = db
+        .find_vault_card_optional_by_fingerprint(merchant_id, customer_id, &fingerprint)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+
+    let (card_reference, duplicate) = match existing_card {
+        // The stored card is updated, as details like the expiry of the card may have changed
+        Some(card) => db
+            .update_vault_card(
+                card,
+                storage::VaultCardUpdate::EncryptedDataUpdate {
+                    key_version,
+                    enc_data,
+                },
+            )
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to update the card in the vault")
+            .map(|card| (card.card_reference, true))?,
+        None => {
+            let now = date_time::now();
+            db.insert_vault_card(storage::VaultCardNew {
+                card_reference: generate_id(consts::ID_LENGTH, "card"),
+                merchant_id: merchant_id.to_owned(),
+                customer_id: customer_id.to_owned(),
+                fingerprint,
+                key_version,
+                enc_data,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to insert the card in the vault")
+            .map(|card| (card.card_reference, false))?
+        }
+    };
+
+    Ok(payment_methods::StoreCardResp {
+        status: "SUCCESS".to_string(),
+        error_message: None,
+        error_code: None,
+        payload: Some(payment_methods::StoreCardRespPayload {
+            card_reference,
+            duplicate: Some(duplicate),
+        }),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_from_vault(
+    state: &routes::AppState,
+    merchant_id: &str,
+    customer_id: &str,
+    card_reference: &str,
+) -> CustomResult<payment_methods::RetrieveCardRespPayload, errors::VaultError> {
+    let db = &*state.store;
+    let card = db
+        .find_vault_card_by_merchant_id_card_reference(merchant_id, card_reference)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to find the card in the vault")?;
+    if card.customer_id != customer_id {
+        Err(report!(errors::VaultError::FetchCardFailed))
+            .attach_printable("The card in the vault belongs to another customer")?
+    }
+
+    // The access is recorded before the card data is decrypted, so that no retrieval of the card
+    // data goes unrecorded
+    db.insert_vault_access_log(storage::VaultAccessLogNew {
+        merchant_id: merchant_id.to_owned(),
+        customer_id: customer_id.to_owned(),
+        card_reference: card_reference.to_owned(),
+        flow: state.flow_name.clone(),
+        created_at: date_time::now(),
+    })
+    .await
+    .change_context(errors::VaultError::FetchCardFailed)
+    .attach_printable("Failed to record the access to the card in the vault")?;
+
+    let key = get_data_key(db, merchant_id, card.key_version).await?;
+    decrypt_data(card.enc_data, &key)
+        .await?
+        .peek()
+        .parse_struct("RetrieveCardRespPayload")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)
+}
+
+#[instrument(skip_all)]
+pub async fn delete_from_vault(
+    state: &routes::AppState,
+    merchant_id: &str,
+    card_reference: &str,
+) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+    state
+        .store
+        .delete_vault_card_by_merchant_id_card_reference(merchant_id, card_reference)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to delete the card from the vault")?;
+    Ok(payment_methods::DeleteCardResp {
+        status: "SUCCESS".to_string(),
+        error_message: None,
+        error_code: None,
+    })
+}
+
+/// Replaces the active data key of the merchant with a new version, returning the version of the
+/// new data key.
+///
+/// The cards of the merchant are re-encrypted with the new data key in batches by the scheduler,
+/// see [`re_encrypt_cards`]. Until then, they remain readable with the previous versions of the
+/// data key.
+#[instrument(skip(state))]
+pub async fn rotate_data_key(
+    state: &routes::AppState,
+    merchant_id: &str,
+) -> CustomResult<i32, errors::VaultError> {
+    let db = &*state.store;
+    let active_data_key = db
+        .find_active_vault_data_key_optional(merchant_id)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+    let key_version = active_data_key
+        .as_ref()
+        .map_or(1, |data_key| data_key.key_version + 1);
+
+    let data_key = generate_data_key(db, merchant_id, key_version).await?;
+    // The previous data key is deactivated in the same transaction, so that a single data key is
+    // active at any time
+    db.rotate_vault_data_key(data_key)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to rotate the vault data key")?;
+
+    logger::info!(merchant_id, key_version, "Rotated the vault data key");
+    Ok(key_version)
+}
+
+/// Re-encrypts a batch of at most `limit` cards of the merchant, which are encrypted with a
+/// version of the data key other than `key_version`, with the version `key_version` of the data
+/// key. Returns the number of cards re-encrypted.
+#[instrument(skip(state))]
+pub async fn re_encrypt_cards(
+    state: &routes::AppState,
+    merchant_id: &str,
+    key_version: i32,
+    limit: i64,
+) -> CustomResult<usize, errors::VaultError> {
+    let db = &*state.store;
+    let new_data_key = get_data_key(db, merchant_id, key_version).await?;
+    let new_key = domain_types::EncryptionKey::unversioned(new_data_key.peek())
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Invalid vault data key")?;
+    let cards = db
+        .find_vault_cards_by_merchant_id_key_version_not(merchant_id, key_version, limit)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?;
+    let re_encrypted_cards = cards.len();
+
+    let mut previous_keys = HashMap::new();
+    for card in cards {
+        if !previous_keys.contains_key(&card.key_version) {
+            let previous_key = get_data_key(db, merchant_id, card.key_version).await?;
+            previous_keys.insert(card.key_version, previous_key);
+        }
+        let previous_key = previous_keys
+            .get(&card.key_version)
+            .ok_or(report!(errors::VaultError::FetchCardFailed))?;
+        let data = decrypt_data(card.enc_data.clone(), previous_key).await?;
+        let enc_data = domain_types::encrypt(data, new_key)
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to re-encrypt the card data")?;
+        db.update_vault_card(
+            card,
+            storage::VaultCardUpdate::EncryptedDataUpdate {
+                key_version,
+                enc_data: enc_data.into(),
+            },
+        )
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to update the re-encrypted card in the vault")?;
+    }
+
+    Ok(re_encrypted_cards)
+}
@@ -1,13 +1,18 @@
 pub mod access_token;
+pub mod authentication;
 pub mod customers;
 pub mod flows;
+pub mod fraud_check;
 pub mod helpers;
 pub mod operations;
+pub mod retry;
+pub mod routing;
+pub mod surcharge;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
 
-use std::{fmt::Debug, marker::PhantomData, ops::Deref, time::Instant};
+use std::{fmt::Debug, marker::PhantomData, ops::Deref, str::FromStr, time::Instant};
 
 use api_models::payments::HeaderPayload;
 use common_utils::{ext_traits::AsyncExt, pii};
@@ -24,8 +29,8 @@ use time;
 
 pub use self::operations::{
     PaymentApprove, PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate,
-    PaymentMethodValidate, PaymentReject, PaymentResponse, PaymentSession, PaymentStatus,
-    PaymentUpdate,
+    PaymentIncrementalAuthorization, PaymentMethodValidate, PaymentReject, PaymentResponse,
+    PaymentSession, PaymentStatus, PaymentUpdate,
 };
 use self::{
     flows::{ConstructFlowSpecificData, Feature},
@@ -109,11 +114,17 @@ where
             &mut payment_data,
             customer_details,
             &key_store,
+            merchant_account.storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
         .attach_printable("Failed while fetching/creating customer")?;
 
+    operation
+        .to_domain()?
+        .guard_payment_against_blocklist(state, &merchant_account, &mut payment_data)
+        .await?;
+
     let connector = get_connector_choice(
         &operation,
         state,
@@ -157,36 +168,145 @@ where
     if let Some(connector_details) = connector {
         payment_data = match connector_details {
             api::ConnectorCallType::Single(connector) => {
-                let router_data = call_connector_service(
+                let frm_suggestion = fraud_check::pre_payment_frm_core(
                     state,
+                    &operation,
                     &merchant_account,
                     &key_store,
-                    connector,
-                    &operation,
+                    &connector,
                     &mut payment_data,
-                    &customer,
-                    call_connector_action,
-                    &validate_result,
-                    schedule_time,
-                    header_payload,
                 )
                 .await?;
 
-                let operation = Box::new(PaymentResponse);
-                let db = &*state.store;
-                connector_http_status_code = router_data.connector_http_status_code;
-                //add connector http status code metrics
-                add_connector_http_status_code_metrics(connector_http_status_code);
-                operation
-                    .to_post_update_tracker()?
-                    .update_tracker(
-                        db,
-                        &validate_result.payment_id,
-                        payment_data,
-                        router_data,
-                        merchant_account.storage_scheme,
+                if frm_suggestion.is_some() {
+                    // The fraud check has either cancelled the payment or held it for a manual
+                    // review, hence it is not authorized with the connector
+                    let (_, payment_data) = operation
+                        .to_update_tracker()?
+                        .update_trackers(
+                            &*state.store,
+                            payment_data,
+                            customer.clone(),
+                            validate_result.storage_scheme,
+                            None,
+                            &key_store,
+                            frm_suggestion,
+                            header_payload,
+                        )
+                        .await?;
+                    payment_data
+                } else if let Some(authentication_response) =
+                    authentication::pre_payment_authentication_core(
+                        state,
+                        &operation,
+                        &merchant_account,
+                        &key_store,
+                        &connector,
+                        &mut payment_data,
                     )
                     .await?
+                {
+                    // The customer is yet to be authenticated with the external 3DS server, hence
+                    // the payment is not authorized with the connector
+                    let router_data = construct_authentication_pending_router_data(
+                        state,
+                        &merchant_account,
+                        &key_store,
+                        &connector,
+                        &operation,
+                        &mut payment_data,
+                        &customer,
+                        authentication_response,
+                        header_payload,
+                    )
+                    .await?;
+
+                    Box::new(PaymentResponse)
+                        .to_post_update_tracker()?
+                        .update_tracker(
+                            &*state.store,
+                            &validate_result.payment_id,
+                            payment_data,
+                            router_data,
+                            merchant_account.storage_scheme,
+                        )
+                        .await?
+                } else {
+                    let mut connector = connector;
+                    loop {
+                        let router_data = match call_connector_service(
+                            state,
+                            &merchant_account,
+                            &key_store,
+                            connector,
+                            &operation,
+                            &mut payment_data,
+                            &customer,
+                            call_connector_action.clone(),
+                            &validate_result,
+                            schedule_time,
+                            header_payload,
+                        )
+                        .await
+                        {
+                            Ok(router_data) => router_data,
+                            // A payment whose request could not be delivered to the connector
+                            // is retried on the next fallback connector as well
+                            Err(error)
+                                if is_operation_confirm(&operation)
+                                    && retry::is_retryable_transport_error(&error) =>
+                            {
+                                match retry::get_connector_for_transport_error_retry(
+                                    state,
+                                    &mut payment_data,
+                                    merchant_account.storage_scheme,
+                                )
+                                .await?
+                                {
+                                    Some(retry_connector) => {
+                                        connector = retry_connector;
+                                        continue;
+                                    }
+                                    None => return Err(error),
+                                }
+                            }
+                            Err(error) => return Err(error),
+                        };
+
+                        let response_operation = Box::new(PaymentResponse);
+                        let db = &*state.store;
+                        connector_http_status_code = router_data.connector_http_status_code;
+                        //add connector http status code metrics
+                        add_connector_http_status_code_metrics(connector_http_status_code);
+                        payment_data = response_operation
+                            .to_post_update_tracker()?
+                            .update_tracker(
+                                db,
+                                &validate_result.payment_id,
+                                payment_data,
+                                router_data,
+                                merchant_account.storage_scheme,
+                            )
+                            .await?;
+
+                        if !is_operation_confirm(&operation) {
+                            break payment_data;
+                        }
+
+                        // A payment which failed with a retryable error is retried on the next
+                        // fallback connector as a new payment attempt
+                        match retry::get_connector_for_retry(
+                            state,
+                            &mut payment_data,
+                            merchant_account.storage_scheme,
+                        )
+                        .await?
+                        {
+                            Some(retry_connector) => connector = retry_connector,
+                            None => break payment_data,
+                        }
+                    }
+                }
             }
 
             api::ConnectorCallType::Multiple(connectors) => {
@@ -263,10 +383,10 @@ where
     // To perform router related operation for PaymentResponse
     PaymentResponse: Operation<F, FData>,
 {
-    let (payment_data, req, customer, connector_http_status_code) = payments_operation_core(
+    let (mut payment_data, req, customer, connector_http_status_code) = payments_operation_core(
         &state,
-        merchant_account,
-        key_store,
+        merchant_account.clone(),
+        key_store.clone(),
         operation.clone(),
         req,
         call_connector_action,
@@ -275,6 +395,16 @@ where
     )
     .await?;
 
+    if is_operation_confirm(&operation) {
+        fraud_check::post_payment_frm_core(
+            &state,
+            &merchant_account,
+            &key_store,
+            &mut payment_data,
+        )
+        .await?;
+    }
+
     Res::generate_response(
         Some(req),
         payment_data,
@@ -547,6 +677,79 @@ impl PaymentRedirectFlow for PaymentRedirectSync {
     }
 }
 
+/// Records the confirmation of a payment whose customer is yet to be authenticated with the
+/// external 3DS server, along with the redirection for the next step of the authentication or the
+/// error which failed the authentication
+#[allow(clippy::too_many_arguments)]
+async fn construct_authentication_pending_router_data<F, RouterDReq, ApiRequest>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector: &api::ConnectorData,
+    operation: &BoxedOperation<'_, F, ApiRequest>,
+    payment_data: &mut PaymentData<F>,
+    customer: &Option<domain::Customer>,
+    authentication_response: Result<
+        router_types::PaymentsResponseData,
+        router_types::ErrorResponse,
+    >,
+    header_payload: HeaderPayload,
+) -> RouterResult<router_types::RouterData<F, RouterDReq, router_types::PaymentsResponseData>>
+where
+    F: Send + Clone + Sync,
+    RouterDReq: Send + Sync,
+    PaymentData<F>: ConstructFlowSpecificData<F, RouterDReq, router_types::PaymentsResponseData>,
+{
+    let connector_name = payment_data
+        .payment_attempt
+        .connector
+        .clone()
+        .get_required_value("connector")?;
+
+    let merchant_connector_account = construct_profile_id_and_get_mca(
+        state,
+        merchant_account,
+        payment_data,
+        &connector_name,
+        key_store,
+    )
+    .await?;
+
+    let (_, updated_payment_data) = operation
+        .to_update_tracker()?
+        .update_trackers(
+            &*state.store,
+            payment_data.clone(),
+            customer.clone(),
+            merchant_account.storage_scheme,
+            None,
+            key_store,
+            None,
+            header_payload,
+        )
+        .await?;
+    *payment_data = updated_payment_data;
+
+    let mut router_data = payment_data
+        .construct_router_data(
+            state,
+            connector.connector.id(),
+            merchant_account,
+            key_store,
+            customer,
+            &merchant_connector_account,
+        )
+        .await?;
+
+    router_data.status = match authentication_response {
+        Ok(_) => storage_enums::AttemptStatus::AuthenticationPending,
+        Err(_) => storage_enums::AttemptStatus::Failure,
+    };
+    router_data.response = authentication_response;
+
+    Ok(router_data)
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub async fn call_connector_service<F, RouterDReq, ApiRequest>(
@@ -605,6 +808,11 @@ where
 
     *payment_data = pd;
 
+    operation
+        .to_domain()?
+        .populate_surcharge_details(state, payment_data)
+        .await?;
+
     let updated_customer = call_create_connector_customer_if_required(
         state,
         customer,
@@ -665,6 +873,26 @@ where
         ));
     }
 
+    // Tokenization Action will be DecryptGooglePayToken, only when payment method type is Google Pay
+    // and the merchant has configured the keys to decrypt the Google Pay payment tokens with
+    if matches!(
+        tokenization_action,
+        TokenizationAction::DecryptGooglePayToken
+            | TokenizationAction::TokenizeInConnectorAndGooglePayPreDecrypt
+    ) {
+        if let Some(api_models::payments::PaymentMethodData::Wallet(
+            api_models::payments::WalletData::GooglePay(wallet_data),
+        )) = &payment_data.payment_method_data
+        {
+            let google_pay_predecrypt =
+                decrypt_google_pay_token(state, &merchant_connector_account, wallet_data)?;
+
+            router_data.payment_method_token = Some(
+                router_types::PaymentMethodToken::GooglePayDecrypt(Box::new(google_pay_predecrypt)),
+            );
+        }
+    }
+
     let pm_token = router_data
         .add_payment_method_token(state, &connector, &tokenization_action)
         .await?;
@@ -1138,6 +1366,121 @@ fn check_apple_pay_metadata(
     Ok(apple_pay_predecrypt)
 }
 
+/// The Google Pay payment tokens are decrypted only for the connectors accepting the decrypted
+/// tokens, when the merchant has configured the keys to decrypt them with
+fn is_google_pay_predecrypt(
+    state: &AppState,
+    connector_name: &str,
+    payment_method_type: &Option<api_models::enums::PaymentMethodType>,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+) -> bool {
+    let is_connector_supported = api_models::enums::Connector::from_str(connector_name)
+        .map(|connector| {
+            state
+                .conf
+                .google_pay_decrypt_supported_connectors
+                .supported_connectors
+                .contains(&connector)
+        })
+        .unwrap_or(false);
+
+    is_connector_supported
+        && matches!(
+            payment_method_type,
+            Some(api_models::enums::PaymentMethodType::GooglePay)
+        )
+        && get_google_pay_decryption_details(merchant_connector_account).is_some()
+}
+
+fn get_google_pay_decryption_details(
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+) -> Option<api_models::admin::GooglePayDecryptionDetails> {
+    merchant_connector_account
+        .get_connector_wallets_details()
+        .and_then(|connector_wallets_details| {
+            connector_wallets_details
+                .parse_value::<api_models::admin::ConnectorWalletsDetails>(
+                    "ConnectorWalletsDetails",
+                )
+                .ok()
+        })
+        .and_then(|connector_wallets_details| connector_wallets_details.google_pay)
+}
+
+fn get_google_pay_metadata(
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+) -> Option<api_models::payments::GpayMetaData> {
+    merchant_connector_account
+        .get_metadata()
+        .and_then(|metadata| {
+            metadata
+                .parse_value::<api_models::payments::GpaySessionTokenData>("GpaySessionTokenData")
+                .ok()
+        })
+        .map(|session_token_data| session_token_data.data)
+}
+
+fn decrypt_google_pay_token(
+    state: &AppState,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    wallet_data: &api_models::payments::GooglePayWalletData,
+) -> RouterResult<router_types::GooglePayPredecryptData> {
+    let invalid_metadata_error = || errors::ApiErrorResponse::InvalidDataFormat {
+        field_name: "connector_metadata".to_string(),
+        expected_format: "gpay_metadata_format".to_string(),
+    };
+
+    let google_pay_metadata = get_google_pay_metadata(merchant_connector_account)
+        .ok_or_else(invalid_metadata_error)
+        .into_report()
+        .attach_printable("Failed to parse the Google Pay metadata")?;
+    let decryption_details = get_google_pay_decryption_details(merchant_connector_account)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Google Pay decryption details are not configured")?;
+    let google_pay_merchant_id = google_pay_metadata
+        .merchant_info
+        .merchant_id
+        .ok_or_else(invalid_metadata_error)
+        .into_report()
+        .attach_printable("Google Pay merchant ID is required to decrypt the payment tokens")?;
+
+    helpers::GooglePayTokenDecryptor::new(
+        &state
+            .conf
+            .google_pay_decrypt_keys
+            .google_pay_root_signing_keys,
+        &google_pay_merchant_id,
+        &decryption_details.private_key,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to initialize the Google Pay token decryptor")?
+    .decrypt_token(&wallet_data.tokenization_data.token)
+    .map_err(|error| {
+        let api_error = match error.current_context() {
+            errors::GooglePayDecryptionError::ParsingFailed
+            | errors::GooglePayDecryptionError::Base64DecodingFailed
+            | errors::GooglePayDecryptionError::UnsupportedProtocolVersion
+            | errors::GooglePayDecryptionError::SignatureVerificationFailed
+            | errors::GooglePayDecryptionError::MessageAuthenticationFailed
+            | errors::GooglePayDecryptionError::TokenExpired => {
+                errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_method_data.wallet.google_pay.tokenization_data.token",
+                }
+            }
+            errors::GooglePayDecryptionError::RootSigningKeyNotFound
+            | errors::GooglePayDecryptionError::KeyDeserializationFailed
+            | errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed
+            | errors::GooglePayDecryptionError::DerivingSymmetricKeysFailed
+            | errors::GooglePayDecryptionError::DecryptionFailed => {
+                errors::ApiErrorResponse::InternalServerError
+            }
+        };
+        error.change_context(api_error)
+    })
+    .attach_printable("Failed to decrypt the Google Pay payment token")
+}
+
 fn is_payment_method_type_allowed_for_connector(
     current_pm_type: &Option<storage::enums::PaymentMethodType>,
     pm_type_filter: Option<PaymentMethodTypeTokenFilter>,
@@ -1159,15 +1502,20 @@ async fn decide_payment_method_tokenize_action(
     pm_parent_token: Option<&String>,
     is_connector_tokenization_enabled: bool,
     is_apple_pay_predecrypt_supported: bool,
+    is_google_pay_predecrypt_supported: bool,
 ) -> RouterResult<TokenizationAction> {
     match pm_parent_token {
         None => {
             if is_connector_tokenization_enabled && is_apple_pay_predecrypt_supported {
                 Ok(TokenizationAction::TokenizeInConnectorAndApplepayPreDecrypt)
+            } else if is_connector_tokenization_enabled && is_google_pay_predecrypt_supported {
+                Ok(TokenizationAction::TokenizeInConnectorAndGooglePayPreDecrypt)
             } else if is_connector_tokenization_enabled {
                 Ok(TokenizationAction::TokenizeInConnectorAndRouter)
             } else if is_apple_pay_predecrypt_supported {
                 Ok(TokenizationAction::DecryptApplePayToken)
+            } else if is_google_pay_predecrypt_supported {
+                Ok(TokenizationAction::DecryptGooglePayToken)
             } else {
                 Ok(TokenizationAction::TokenizeInRouter)
             }
@@ -1197,10 +1545,16 @@ async fn decide_payment_method_tokenize_action(
                 None => {
                     if is_connector_tokenization_enabled && is_apple_pay_predecrypt_supported {
                         Ok(TokenizationAction::TokenizeInConnectorAndApplepayPreDecrypt)
+                    } else if is_connector_tokenization_enabled
+                        && is_google_pay_predecrypt_supported
+                    {
+                        Ok(TokenizationAction::TokenizeInConnectorAndGooglePayPreDecrypt)
                     } else if is_connector_tokenization_enabled {
                         Ok(TokenizationAction::TokenizeInConnectorAndRouter)
                     } else if is_apple_pay_predecrypt_supported {
                         Ok(TokenizationAction::DecryptApplePayToken)
+                    } else if is_google_pay_predecrypt_supported {
+                        Ok(TokenizationAction::DecryptGooglePayToken)
                     } else {
                         Ok(TokenizationAction::TokenizeInRouter)
                     }
@@ -1219,6 +1573,8 @@ pub enum TokenizationAction {
     SkipConnectorTokenization,
     DecryptApplePayToken,
     TokenizeInConnectorAndApplepayPreDecrypt,
+    DecryptGooglePayToken,
+    TokenizeInConnectorAndGooglePayPreDecrypt,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1269,6 +1625,13 @@ where
                 &Some(merchant_connector_account.clone()),
             )?;
 
+            let is_google_pay_predecrypt = is_google_pay_predecrypt(
+                state,
+                &connector,
+                payment_method_type,
+                merchant_connector_account,
+            );
+
             let payment_method_action = decide_payment_method_tokenize_action(
                 state,
                 &connector,
@@ -1276,6 +1639,7 @@ where
                 payment_data.token.as_ref(),
                 is_connector_tokenization_enabled,
                 is_apple_pay_predecrypt,
+                is_google_pay_predecrypt,
             )
             .await?;
 
@@ -1312,6 +1676,12 @@ where
                 TokenizationAction::TokenizeInConnectorAndApplepayPreDecrypt => {
                     TokenizationAction::TokenizeInConnectorAndApplepayPreDecrypt
                 }
+                TokenizationAction::DecryptGooglePayToken => {
+                    TokenizationAction::DecryptGooglePayToken
+                }
+                TokenizationAction::TokenizeInConnectorAndGooglePayPreDecrypt => {
+                    TokenizationAction::TokenizeInConnectorAndGooglePayPreDecrypt
+                }
             };
             (payment_data.to_owned(), connector_tokenization_action)
         }
@@ -1398,6 +1768,19 @@ where
     pub ephemeral_key: Option<ephemeral_key::EphemeralKey>,
     pub redirect_response: Option<api_models::payments::RedirectResponse>,
     pub frm_message: Option<FraudCheck>,
+    pub incremental_authorization_details: Option<IncrementalAuthorizationDetails>,
+    pub incremental_authorizations: Vec<storage::IncrementalAuthorization>,
+    pub payment_link_data: Option<api_models::payments::PaymentLinkResponse>,
+    /// The data of the successful external 3DS authentication of the payment attempt, if any
+    pub authentication: Option<router_types::AuthenticationData>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IncrementalAuthorizationDetails {
+    pub additional_amount: i64,
+    pub total_amount: i64,
+    pub reason: Option<String>,
+    pub authorization_id: String,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -1497,8 +1880,16 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
             ))
         }
         "CompleteAuthorize" => true,
-        "PaymentApprove" => true,
+        // A payment held for a manual review after its authorization only needs to be captured
+        "PaymentApprove" => matches!(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresMerchantAction
+        ),
         "PaymentSession" => true,
+        "PaymentIncrementalAuthorization" => matches!(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresCapture
+        ),
         _ => false,
     }
 }
@@ -1761,15 +2152,18 @@ where
                 api::ConnectorCallType::Multiple(session_connectors)
             }
 
-            api::ConnectorChoice::StraightThrough(straight_through) => connector_selection(
-                state,
-                merchant_account,
-                payment_data,
-                Some(straight_through),
-            )?,
+            api::ConnectorChoice::StraightThrough(straight_through) => {
+                connector_selection(
+                    state,
+                    merchant_account,
+                    payment_data,
+                    Some(straight_through),
+                )
+                .await?
+            }
 
             api::ConnectorChoice::Decide => {
-                connector_selection(state, merchant_account, payment_data, None)?
+                connector_selection(state, merchant_account, payment_data, None).await?
             }
         })
     } else if let api::ConnectorChoice::StraightThrough(val) = connector_choice {
@@ -1784,7 +2178,7 @@ where
     Ok(connector)
 }
 
-pub fn connector_selection<F>(
+pub async fn connector_selection<F>(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
     payment_data: &mut PaymentData<F>,
@@ -1815,6 +2209,7 @@ where
             .transpose()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Invalid straight through algorithm format in payment attempt")?,
+        fallback_connectors: None,
     };
 
     let request_straight_through: Option<api::StraightThroughAlgorithm> = request_straight_through
@@ -1823,9 +2218,13 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Invalid straight through routing rules format")?;
 
+    let routing_algorithm = get_routing_algorithm(state, merchant_account, payment_data).await?;
+    let routing_input = routing::RoutingInput::from(&*payment_data);
+
     let decided_connector = decide_connector(
         state,
-        merchant_account,
+        routing_algorithm,
+        &routing_input,
         request_straight_through,
         &mut routing_data,
     )?;
@@ -1837,15 +2236,53 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to serialize routing algorithm to serde value")?;
 
+    let encoded_fallback_connectors = routing_data
+        .fallback_connectors
+        .map(|connectors| {
+            Encode::<Vec<api_models::enums::RoutableConnectors>>::encode_to_value(&connectors)
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize fallback connectors to serde value")?;
+
     payment_data.payment_attempt.connector = routing_data.routed_through;
     payment_data.payment_attempt.straight_through_algorithm = encoded_algorithm;
+    payment_data.payment_attempt.fallback_connectors = encoded_fallback_connectors;
 
     Ok(decided_connector)
 }
 
-pub fn decide_connector(
+/// The routing algorithm configured on the business profile of the payment takes precedence over
+/// the one configured on the merchant account
+async fn get_routing_algorithm<F>(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<Option<serde_json::Value>>
+where
+    F: Send + Clone,
+{
+    let profile_routing_algorithm = match payment_data.payment_intent.profile_id {
+        Some(ref profile_id) => {
+            state
+                .store
+                .find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_owned(),
+                })?
+                .routing_algorithm
+        }
+        None => None,
+    };
+
+    Ok(profile_routing_algorithm.or_else(|| merchant_account.routing_algorithm.clone()))
+}
+
+pub fn decide_connector(
+    state: &AppState,
+    routing_algorithm: Option<serde_json::Value>,
+    routing_input: &routing::RoutingInput,
     request_straight_through: Option<api::StraightThroughAlgorithm>,
     routing_data: &mut storage::RoutingData,
 ) -> RouterResult<api::ConnectorCallType> {
@@ -1896,9 +2333,7 @@ pub fn decide_connector(
         return Ok(api::ConnectorCallType::Single(connector_data));
     }
 
-    let routing_algorithm = merchant_account
-        .routing_algorithm
-        .clone()
+    let routing_algorithm = routing_algorithm
         .get_required_value("RoutingAlgorithm")
         .change_context(errors::ApiErrorResponse::PreconditionFailed {
             message: "no routing algorithm has been configured".to_string(),
@@ -1907,9 +2342,15 @@ pub fn decide_connector(
         .change_context(errors::ApiErrorResponse::InternalServerError) // Deserialization failed
         .attach_printable("Unable to deserialize merchant routing algorithm")?;
 
-    let connector_name = match routing_algorithm {
-        api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-    };
+    let mut connectors = routing::perform_routing(&routing_algorithm, routing_input)?.into_iter();
+
+    let connector_name = connectors
+        .next()
+        .get_required_value("connector")
+        .change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "routing algorithm did not yield any connector".to_string(),
+        })?
+        .to_string();
 
     let connector_data = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
@@ -1920,6 +2361,7 @@ pub fn decide_connector(
     .attach_printable("Routing algorithm gave invalid connector")?;
 
     routing_data.routed_through = Some(connector_name);
+    routing_data.fallback_connectors = Some(connectors.collect());
 
     Ok(api::ConnectorCallType::Single(connector_data))
 }
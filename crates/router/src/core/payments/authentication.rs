@@ -0,0 +1,971 @@
+//! External 3DS authentication of card payments, performed through an authentication connector
+//! (3DS server) configured for the merchant before the payment is authorized with the processor.
+//!
+//! The authentication is driven by the confirm operation: the card is looked up with the 3DS
+//! server and the customer is redirected to run the issuer's 3DS method. Each step completed in the
+//! customer's browser resumes the confirmation of the payment, which moves the authentication
+//! forward until it either fails the payment or the payment is authorized with the authentication
+//! value.
+
+use std::{collections::HashMap, marker::PhantomData};
+
+use api_models::payments::HeaderPayload;
+use diesel_models::encryption::Encryption;
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+use router_env::{instrument, tracing};
+
+use super::{
+    helpers, operations::BoxedOperation, CallConnectorAction, PaymentData, PaymentRedirectFlow,
+};
+use crate::{
+    consts,
+    core::errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, authentication as authentication_api},
+        domain::{self, types as domain_types},
+        storage::{self, enums as storage_enums},
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+/// The form posted by the 3DS method page once the 3DS method has completed, failed to complete in
+/// time, or is not available for the card
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ThreeDsMethodCompletion {
+    #[serde(rename = "threeDSCompInd")]
+    pub three_ds_method_completion_ind: storage_enums::ThreeDsCompletionIndicator,
+}
+
+/// The authentication data to be passed to the processor when authorizing the payment, available
+/// only once the customer has been successfully authenticated
+pub async fn get_authentication_data(
+    authentication: &storage::Authentication,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<types::AuthenticationData>> {
+    if authentication.authentication_status != storage_enums::AuthenticationStatus::Success {
+        return Ok(None);
+    }
+
+    let authentication_value = domain_types::decrypt::<String, masking::WithType>(
+        authentication.authentication_value.clone(),
+        key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the authentication value")?;
+
+    Ok(authentication_value.and_then(|authentication_value| {
+        Some(types::AuthenticationData {
+            eci: authentication.eci.clone(),
+            cavv: authentication_value.into_inner(),
+            threeds_server_transaction_id: authentication.connector_authentication_id.clone()?,
+            message_version: authentication.message_version.clone()?,
+            ds_trans_id: authentication.ds_trans_id.clone(),
+        })
+    }))
+}
+
+/// Encrypts the authentication value (CAVV) received from the 3DS server with the key of the
+/// merchant, before it is stored with the authentication
+async fn encrypt_authentication_value(
+    authentication_value: Option<String>,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<Encryption>> {
+    domain_types::encrypt_optional(
+        authentication_value.map(Secret::new),
+        key_store.encryption_key(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the authentication value")
+    .map(|authentication_value| authentication_value.map(Into::into))
+}
+
+/// Authenticates the customer with the external 3DS server of the merchant, when a card payment
+/// requiring 3DS is being confirmed with a processor that accepts externally authenticated
+/// payments.
+///
+/// A response is returned when the payment must not be authorized with the connector yet, either
+/// the redirection of the customer for the next step of the authentication, or the error which
+/// failed the authentication.
+#[instrument(skip_all)]
+pub async fn pre_payment_authentication_core<F, Req>(
+    state: &AppState,
+    operation: &BoxedOperation<'_, F, Req>,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector: &api::ConnectorData,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<Option<Result<types::PaymentsResponseData, types::ErrorResponse>>>
+where
+    F: Send + Clone + Sync,
+{
+    if !super::is_operation_confirm(operation)
+        || payment_data.mandate_id.is_some()
+        || payment_data.payment_attempt.authentication_type
+            != Some(storage_enums::AuthenticationType::ThreeDs)
+        || payment_data.payment_attempt.payment_method != Some(storage_enums::PaymentMethod::Card)
+        || !connector.connector.is_external_three_ds_supported()
+    {
+        return Ok(None);
+    }
+
+    let authentication_connector_account =
+        match get_authentication_connector_account(state, merchant_account, key_store).await? {
+            Some(merchant_connector_account) => merchant_connector_account,
+            None => return Ok(None),
+        };
+
+    // The card is vaulted, so that it is available when the confirmation of the payment is
+    // resumed from the customer's browser
+    if payment_data.payment_method_data.is_none() || payment_data.token.is_none() {
+        let (_, payment_method_data) = operation
+            .to_domain()?
+            .make_pm_data(state, payment_data, merchant_account.storage_scheme)
+            .await?;
+        payment_data.payment_method_data = payment_method_data;
+    }
+
+    let payment_method_data = match payment_data.payment_method_data.clone() {
+        Some(payment_method_data @ api::PaymentMethodData::Card(_)) => payment_method_data,
+        _ => return Ok(None),
+    };
+
+    if payment_data.token.is_none() {
+        payment_data.token = Some(
+            helpers::store_in_vault_and_generate_ppmt(
+                state,
+                &payment_method_data,
+                &payment_data.payment_intent,
+                &payment_data.payment_attempt,
+                storage_enums::PaymentMethod::Card,
+            )
+            .await?,
+        );
+    }
+
+    let authentication_connector =
+        authentication_api::AuthenticationConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &authentication_connector_account.connector_name,
+        )?;
+    let merchant_connector_account =
+        helpers::MerchantConnectorAccountType::DbVal(authentication_connector_account);
+
+    let authentication = if payment_data.payment_attempt.status
+        == storage_enums::AttemptStatus::AuthenticationPending
+    {
+        state
+            .store
+            .find_latest_authentication_by_merchant_id_attempt_id(
+                &payment_data.payment_attempt.merchant_id,
+                &payment_data.payment_attempt.attempt_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the authentication of the payment attempt")?
+    } else {
+        None
+    };
+
+    let authentication = match authentication {
+        Some(authentication) => authentication,
+        None => {
+            let authentication = pre_authenticate(
+                state,
+                merchant_account,
+                &authentication_connector,
+                &merchant_connector_account,
+                payment_data,
+                &payment_method_data,
+            )
+            .await?;
+
+            return Ok(Some(get_authentication_response(state, &authentication)?));
+        }
+    };
+
+    let authentication = match (
+        authentication.authentication_status,
+        authentication.three_ds_method_completion_ind,
+    ) {
+        (storage_enums::AuthenticationStatus::Started, Some(three_ds_method_completion_ind)) => {
+            authenticate(
+                state,
+                merchant_account,
+                key_store,
+                &authentication_connector,
+                &merchant_connector_account,
+                payment_data,
+                payment_method_data,
+                authentication,
+                three_ds_method_completion_ind,
+            )
+            .await?
+        }
+        (storage_enums::AuthenticationStatus::Pending, _) => {
+            post_authenticate(
+                state,
+                merchant_account,
+                key_store,
+                &authentication_connector,
+                &merchant_connector_account,
+                payment_data,
+                authentication,
+            )
+            .await?
+        }
+        _ => authentication,
+    };
+
+    if authentication.authentication_status == storage_enums::AuthenticationStatus::Success {
+        payment_data.authentication = get_authentication_data(&authentication, key_store).await?;
+        Ok(None)
+    } else {
+        Ok(Some(get_authentication_response(state, &authentication)?))
+    }
+}
+
+/// The first authentication processor connector among the enabled connectors of the merchant
+async fn get_authentication_connector_account(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<domain::MerchantConnectorAccount>> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?;
+
+    Ok(merchant_connector_accounts
+        .into_iter()
+        .find(|merchant_connector_account| {
+            merchant_connector_account.connector_type
+                == storage_enums::ConnectorType::AuthenticationProcessor
+        }))
+}
+
+/// Looks up the card with the 3DS server, creating the authentication of the payment attempt
+async fn pre_authenticate<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    authentication_connector: &authentication_api::AuthenticationConnectorData,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    payment_data: &PaymentData<F>,
+    payment_method_data: &api::PaymentMethodData,
+) -> RouterResult<storage::Authentication> {
+    let card = match payment_method_data {
+        api::PaymentMethodData::Card(card) => card,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Only card payments can be authenticated with a 3DS server")?,
+    };
+
+    let authentication_id = utils::generate_id(consts::ID_LENGTH, "auth");
+    let request = types::PreAuthNRequestData {
+        card_holder_account_number: card.card_number.clone(),
+        three_ds_method_notification_url: get_authentication_url(
+            state,
+            payment_data,
+            &authentication_id,
+            "three_ds_method_response",
+        ),
+    };
+    let router_data: types::PreAuthNRouterData = construct_authentication_router_data(
+        merchant_account,
+        authentication_connector,
+        merchant_connector_account,
+        payment_data,
+        request,
+    )?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        authentication_api::PreAuthentication,
+        types::PreAuthNRequestData,
+        types::AuthenticationResponseData,
+    > = authentication_connector
+        .connector
+        .get_connector_integration();
+
+    let response = execute_authentication_step(state, connector_integration, &router_data).await?;
+
+    let mut authentication_new = storage::AuthenticationNew {
+        authentication_id,
+        merchant_id: payment_data.payment_attempt.merchant_id.clone(),
+        payment_id: payment_data.payment_attempt.payment_id.clone(),
+        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+        authentication_connector: authentication_connector.connector_name.clone(),
+        connector_authentication_id: None,
+        authentication_status: storage_enums::AuthenticationStatus::Started,
+        message_version: None,
+        three_ds_method_url: None,
+        three_ds_method_data: None,
+    };
+
+    let authentication_update = match response {
+        Ok(types::AuthenticationResponseData::PreAuthNResponse {
+            threeds_server_transaction_id,
+            message_version,
+            three_ds_method_url,
+            three_ds_method_data,
+        }) => {
+            authentication_new.connector_authentication_id = Some(threeds_server_transaction_id);
+            authentication_new.message_version = Some(message_version);
+            authentication_new.three_ds_method_url = three_ds_method_url;
+            authentication_new.three_ds_method_data = three_ds_method_data;
+            None
+        }
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response received for the pre authentication")?,
+        Err(error_response) => Some(get_error_update(error_response)),
+    };
+
+    let authentication = state
+        .store
+        .insert_authentication(authentication_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the authentication")?;
+
+    match authentication_update {
+        Some(authentication_update) => {
+            update_authentication(state, authentication, authentication_update).await
+        }
+        None => Ok(authentication),
+    }
+}
+
+/// Sends the authentication request to the issuer through the 3DS server, once the 3DS method has
+/// been run in the customer's browser
+#[allow(clippy::too_many_arguments)]
+async fn authenticate<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    authentication_connector: &authentication_api::AuthenticationConnectorData,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    payment_data: &PaymentData<F>,
+    payment_method_data: api::PaymentMethodData,
+    authentication: storage::Authentication,
+    three_ds_method_completion_ind: storage_enums::ThreeDsCompletionIndicator,
+) -> RouterResult<storage::Authentication> {
+    let browser_details = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| browser_info.parse_value("BrowserInformation"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?;
+
+    let request = types::ConnectorAuthenticationRequestData {
+        payment_method_data,
+        browser_details,
+        amount: payment_data.amount.into(),
+        currency: payment_data.currency,
+        email: payment_data.email.clone(),
+        threeds_server_transaction_id: authentication
+            .connector_authentication_id
+            .clone()
+            .get_required_value("connector_authentication_id")?,
+        message_version: authentication
+            .message_version
+            .clone()
+            .get_required_value("message_version")?,
+        three_ds_method_comp_ind: three_ds_method_completion_ind,
+        notification_url: get_authentication_url(
+            state,
+            payment_data,
+            &authentication.authentication_id,
+            "challenge_response",
+        ),
+    };
+    let router_data: types::ConnectorAuthenticationRouterData =
+        construct_authentication_router_data(
+            merchant_account,
+            authentication_connector,
+            merchant_connector_account,
+            payment_data,
+            request,
+        )?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        authentication_api::Authentication,
+        types::ConnectorAuthenticationRequestData,
+        types::AuthenticationResponseData,
+    > = authentication_connector
+        .connector
+        .get_connector_integration();
+
+    let response = execute_authentication_step(state, connector_integration, &router_data).await?;
+
+    let authentication_update = match response {
+        Ok(types::AuthenticationResponseData::AuthNResponse {
+            authn_flow_type,
+            trans_status,
+            authentication_value,
+            eci,
+            ds_trans_id,
+        }) => {
+            let (authentication_status, acs_url, challenge_request) = match authn_flow_type {
+                types::AuthNFlowType::Challenge(challenge_params) => (
+                    storage_enums::AuthenticationStatus::Pending,
+                    Some(challenge_params.acs_url),
+                    Some(challenge_params.challenge_request),
+                ),
+                types::AuthNFlowType::Frictionless => {
+                    (get_authentication_status(trans_status), None, None)
+                }
+            };
+            storage::AuthenticationUpdate::AuthenticationResponseUpdate {
+                authentication_status,
+                trans_status,
+                acs_url,
+                challenge_request,
+                authentication_value: encrypt_authentication_value(authentication_value, key_store)
+                    .await?,
+                eci,
+                ds_trans_id,
+            }
+        }
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response received for the authentication")?,
+        Err(error_response) => get_error_update(error_response),
+    };
+
+    update_authentication(state, authentication, authentication_update).await
+}
+
+/// Retrieves the result of the authentication from the 3DS server, once the customer has completed
+/// the challenge of the issuer
+async fn post_authenticate<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    authentication_connector: &authentication_api::AuthenticationConnectorData,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    payment_data: &PaymentData<F>,
+    authentication: storage::Authentication,
+) -> RouterResult<storage::Authentication> {
+    let request = types::ConnectorPostAuthenticationRequestData {
+        threeds_server_transaction_id: authentication
+            .connector_authentication_id
+            .clone()
+            .get_required_value("connector_authentication_id")?,
+    };
+    let router_data: types::ConnectorPostAuthenticationRouterData =
+        construct_authentication_router_data(
+            merchant_account,
+            authentication_connector,
+            merchant_connector_account,
+            payment_data,
+            request,
+        )?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        authentication_api::PostAuthentication,
+        types::ConnectorPostAuthenticationRequestData,
+        types::AuthenticationResponseData,
+    > = authentication_connector
+        .connector
+        .get_connector_integration();
+
+    let response = execute_authentication_step(state, connector_integration, &router_data).await?;
+
+    let authentication_update = match response {
+        Ok(types::AuthenticationResponseData::PostAuthNResponse {
+            trans_status,
+            authentication_value,
+            eci,
+        }) => storage::AuthenticationUpdate::PostAuthenticationUpdate {
+            authentication_status: get_authentication_status(trans_status),
+            trans_status,
+            authentication_value: encrypt_authentication_value(authentication_value, key_store)
+                .await?,
+            eci,
+        },
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response received for the post authentication")?,
+        Err(error_response) => get_error_update(error_response),
+    };
+
+    update_authentication(state, authentication, authentication_update).await
+}
+
+async fn execute_authentication_step<Flow, Req>(
+    state: &AppState,
+    connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        Flow,
+        Req,
+        types::AuthenticationResponseData,
+    >,
+    router_data: &types::RouterData<Flow, Req, types::AuthenticationResponseData>,
+) -> RouterResult<Result<types::AuthenticationResponseData, types::ErrorResponse>>
+where
+    Flow: Clone + std::fmt::Debug + Send + Sync + 'static,
+    Req: Clone + std::fmt::Debug + Send + Sync + 'static,
+{
+    services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        router_data,
+        CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payment_failed_response()
+    .map(|router_data| router_data.response)
+}
+
+async fn update_authentication(
+    state: &AppState,
+    authentication: storage::Authentication,
+    authentication_update: storage::AuthenticationUpdate,
+) -> RouterResult<storage::Authentication> {
+    state
+        .store
+        .update_authentication(authentication, authentication_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the authentication")
+}
+
+fn get_authentication_status(
+    trans_status: storage_enums::TransactionStatus,
+) -> storage_enums::AuthenticationStatus {
+    if trans_status.is_authenticated() {
+        storage_enums::AuthenticationStatus::Success
+    } else {
+        storage_enums::AuthenticationStatus::Failed
+    }
+}
+
+fn get_error_update(error_response: types::ErrorResponse) -> storage::AuthenticationUpdate {
+    storage::AuthenticationUpdate::ErrorUpdate {
+        authentication_status: storage_enums::AuthenticationStatus::Failed,
+        error_code: Some(error_response.code),
+        error_message: Some(error_response.message),
+    }
+}
+
+/// The response of the payment for an authentication which is yet to succeed: the redirection of
+/// the customer for the next step of the authentication, or the error which failed the payment
+fn get_authentication_response(
+    state: &AppState,
+    authentication: &storage::Authentication,
+) -> RouterResult<Result<types::PaymentsResponseData, types::ErrorResponse>> {
+    let redirection_data = match authentication.authentication_status {
+        storage_enums::AuthenticationStatus::Started => services::RedirectForm::ThreeDsMethod {
+            authentication_url: format!(
+                "{}/payments/{}/{}/authentication/{}/authenticate",
+                state.conf.server.base_url,
+                authentication.payment_id,
+                authentication.merchant_id,
+                authentication.authentication_id
+            ),
+            three_ds_method_url: authentication.three_ds_method_url.clone(),
+            three_ds_method_data: authentication.three_ds_method_data.clone(),
+        },
+        storage_enums::AuthenticationStatus::Pending => services::RedirectForm::Form {
+            endpoint: authentication
+                .acs_url
+                .clone()
+                .get_required_value("acs_url")?,
+            method: services::Method::Post,
+            form_fields: HashMap::from([(
+                "creq".to_string(),
+                authentication
+                    .challenge_request
+                    .clone()
+                    .get_required_value("challenge_request")?,
+            )]),
+        },
+        storage_enums::AuthenticationStatus::Success
+        | storage_enums::AuthenticationStatus::Failed => {
+            return Ok(Err(types::ErrorResponse {
+                code: authentication
+                    .error_code
+                    .clone()
+                    .or_else(|| authentication.trans_status.map(|status| status.to_string()))
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: authentication
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| "Customer authentication failed".to_string()),
+                reason: None,
+                status_code: http::StatusCode::BAD_REQUEST.as_u16(),
+            }))
+        }
+    };
+
+    Ok(Ok(types::PaymentsResponseData::TransactionResponse {
+        resource_id: types::ResponseId::NoResponseId,
+        redirection_data: Some(redirection_data),
+        mandate_reference: None,
+        connector_metadata: None,
+        network_txn_id: None,
+        connector_response_reference_id: None,
+    }))
+}
+
+fn get_authentication_url<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    authentication_id: &str,
+    step: &str,
+) -> String {
+    format!(
+        "{}/payments/{}/{}/authentication/{}/{}",
+        state.conf.server.base_url,
+        payment_data.payment_attempt.payment_id,
+        payment_data.payment_attempt.merchant_id,
+        authentication_id,
+        step
+    )
+}
+
+fn construct_authentication_router_data<F, Flow, Req>(
+    merchant_account: &domain::MerchantAccount,
+    authentication_connector: &authentication_api::AuthenticationConnectorData,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    payment_data: &PaymentData<F>,
+    request: Req,
+) -> RouterResult<types::RouterData<Flow, Req, types::AuthenticationResponseData>>
+where
+    F: Clone,
+{
+    let connector_auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: payment_data.payment_intent.customer_id.clone(),
+        connector_customer: None,
+        connector: authentication_connector.connector_name.clone(),
+        payment_id: payment_data.payment_attempt.payment_id.clone(),
+        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+        status: payment_data.payment_attempt.status,
+        payment_method: storage_enums::PaymentMethod::Card,
+        connector_auth_type,
+        description: payment_data.payment_intent.description.clone(),
+        return_url: payment_data.payment_intent.return_url.clone(),
+        address: payment_data.address.clone(),
+        auth_type: storage_enums::AuthenticationType::ThreeDs,
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        amount_captured: payment_data.payment_intent.amount_captured,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_api_version: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+        payment_method_id: None,
+        connector_request_reference_id: payment_data.payment_attempt.attempt_id.clone(),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: merchant_connector_account.is_test_mode_on(),
+        connector_http_status_code: None,
+    })
+}
+
+/// Records the outcome of the 3DS method run in the customer's browser, and resumes the
+/// confirmation of the payment to send the authentication request
+#[instrument(skip_all)]
+pub async fn three_ds_method_completion(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+    authentication_id: String,
+    req: ThreeDsMethodCompletion,
+) -> RouterResponse<api::RedirectionResponse> {
+    let authentication = find_payment_authentication(
+        &state,
+        &merchant_account.merchant_id,
+        &payment_id,
+        &authentication_id,
+    )
+    .await?;
+
+    let authentication = if authentication.authentication_status
+        == storage_enums::AuthenticationStatus::Started
+        && authentication.three_ds_method_completion_ind.is_none()
+    {
+        update_authentication(
+            &state,
+            authentication,
+            storage::AuthenticationUpdate::ThreeDsMethodUpdate {
+                three_ds_method_completion_ind: req.three_ds_method_completion_ind,
+            },
+        )
+        .await?
+    } else {
+        authentication
+    };
+
+    resume_payment(state, merchant_account, key_store, authentication).await
+}
+
+/// Resumes the confirmation of the payment once the ACS has posted the result of the challenge
+#[instrument(skip_all)]
+pub async fn challenge_completion(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+    authentication_id: String,
+) -> RouterResponse<api::RedirectionResponse> {
+    let authentication = find_payment_authentication(
+        &state,
+        &merchant_account.merchant_id,
+        &payment_id,
+        &authentication_id,
+    )
+    .await?;
+
+    resume_payment(state, merchant_account, key_store, authentication).await
+}
+
+/// The authentication of the payment in the path of the request, an authentication of another
+/// payment of the merchant is treated as not found
+async fn find_payment_authentication(
+    state: &AppState,
+    merchant_id: &str,
+    payment_id: &str,
+    authentication_id: &str,
+) -> RouterResult<storage::Authentication> {
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Authentication not found".to_string(),
+    };
+
+    let authentication = state
+        .store
+        .find_authentication_by_merchant_id_authentication_id(merchant_id, authentication_id)
+        .await
+        .to_not_found_response(not_found_error())?;
+
+    utils::when(authentication.payment_id != payment_id, || {
+        Err(not_found_error()).into_report()
+    })?;
+
+    Ok(authentication)
+}
+
+/// The page loaded in the 3DS method iframe once the issuer's 3DS method has completed, which
+/// notifies the 3DS method page to proceed with the authentication
+pub fn three_ds_method_notification() -> RouterResponse<()> {
+    let page = maud::html! {
+        (maud::DOCTYPE)
+        html {
+            body {
+                (maud::PreEscaped(format!(
+                    r#"<script>window.parent.postMessage("{}", "*");</script>"#,
+                    services::api::THREE_DS_METHOD_COMPLETED_MESSAGE
+                )))
+            }
+        }
+    };
+
+    Ok(services::ApplicationResponse::FileData((
+        page.into_string().into_bytes(),
+        mime::TEXT_HTML_UTF_8,
+    )))
+}
+
+async fn resume_payment(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication: storage::Authentication,
+) -> RouterResponse<api::RedirectionResponse> {
+    let payment_confirm_req = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(
+            authentication.payment_id.clone(),
+        )),
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        ..Default::default()
+    };
+
+    let response = super::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account.clone(),
+        key_store,
+        super::PaymentConfirm,
+        payment_confirm_req,
+        services::api::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        HeaderPayload::default(),
+    )
+    .await?;
+
+    let payments_response = match response {
+        services::ApplicationResponse::Json(response) => Ok(response),
+        services::ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Failed to get the response in json"),
+    }?;
+
+    let connector = payments_response
+        .connector
+        .clone()
+        .get_required_value("connector")?;
+
+    let result = super::PaymentRedirectCompleteAuthorize.generate_response(
+        payments_response,
+        merchant_account,
+        authentication.payment_id,
+        connector,
+    )?;
+
+    Ok(services::ApplicationResponse::JsonForRedirection(result))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use masking::PeekInterface;
+
+    use super::*;
+
+    fn get_key_store() -> domain::MerchantKeyStore {
+        domain::MerchantKeyStore {
+            merchant_id: "merchant_123".to_string(),
+            key: common_utils::crypto::Encryptable::new(
+                Secret::new(vec![7; 32]),
+                Secret::new(Vec::new()),
+            ),
+            created_at: common_utils::date_time::now(),
+            key_version: domain_types::INITIAL_KEY_VERSION,
+            previous_keys: Default::default(),
+        }
+    }
+
+    async fn get_authentication(
+        authentication_status: storage_enums::AuthenticationStatus,
+        key_store: &domain::MerchantKeyStore,
+    ) -> storage::Authentication {
+        let now = common_utils::date_time::now();
+        storage::Authentication {
+            authentication_id: "auth_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            payment_id: "pay_123".to_string(),
+            attempt_id: "pay_123_1".to_string(),
+            authentication_connector: "threedsecureio".to_string(),
+            connector_authentication_id: Some("3ds_server_trans_123".to_string()),
+            authentication_status,
+            trans_status: Some(storage_enums::TransactionStatus::Success),
+            message_version: Some("2.2.0".to_string()),
+            three_ds_method_url: None,
+            three_ds_method_data: None,
+            three_ds_method_completion_ind: Some(
+                storage_enums::ThreeDsCompletionIndicator::NotAvailable,
+            ),
+            acs_url: None,
+            challenge_request: None,
+            authentication_value: encrypt_authentication_value(
+                Some("AAABBIIFmAAAAAAAAAAAAAAAAAA=".to_string()),
+                key_store,
+            )
+            .await
+            .unwrap(),
+            eci: Some("05".to_string()),
+            ds_trans_id: Some("ds_trans_123".to_string()),
+            error_code: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authentication_data_of_successful_authentication() {
+        let key_store = get_key_store();
+        let authentication =
+            get_authentication(storage_enums::AuthenticationStatus::Success, &key_store).await;
+        assert_ne!(
+            authentication
+                .authentication_value
+                .as_ref()
+                .unwrap()
+                .get_inner()
+                .peek(),
+            b"AAABBIIFmAAAAAAAAAAAAAAAAAA="
+        );
+
+        let authentication_data = get_authentication_data(&authentication, &key_store)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            authentication_data.cavv.peek(),
+            "AAABBIIFmAAAAAAAAAAAAAAAAAA="
+        );
+        assert_eq!(authentication_data.eci.as_deref(), Some("05"));
+        assert_eq!(
+            authentication_data.threeds_server_transaction_id,
+            "3ds_server_trans_123"
+        );
+        assert_eq!(authentication_data.message_version, "2.2.0");
+    }
+
+    #[tokio::test]
+    async fn test_no_authentication_data_of_incomplete_authentication() {
+        let key_store = get_key_store();
+        let authentication =
+            get_authentication(storage_enums::AuthenticationStatus::Pending, &key_store).await;
+        assert!(get_authentication_data(&authentication, &key_store)
+            .await
+            .unwrap()
+            .is_none());
+
+        let mut authentication =
+            get_authentication(storage_enums::AuthenticationStatus::Success, &key_store).await;
+        authentication.authentication_value = None;
+        assert!(get_authentication_data(&authentication, &key_store)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_authentication_status_of_trans_status() {
+        assert_eq!(
+            get_authentication_status(storage_enums::TransactionStatus::NotVerified),
+            storage_enums::AuthenticationStatus::Success
+        );
+        assert_eq!(
+            get_authentication_status(storage_enums::TransactionStatus::Rejected),
+            storage_enums::AuthenticationStatus::Failed
+        );
+    }
+}
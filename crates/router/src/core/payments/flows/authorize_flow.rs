@@ -112,7 +112,14 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                 }
             }?;
 
-            Ok(mandate::mandate_procedure(state, resp, maybe_customer, pm_id).await?)
+            Ok(mandate::mandate_procedure(
+                state,
+                resp,
+                maybe_customer,
+                pm_id,
+                merchant_account.storage_scheme,
+            )
+            .await?)
         } else {
             Ok(self.clone())
         }
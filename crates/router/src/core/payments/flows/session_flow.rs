@@ -165,6 +165,7 @@ async fn create_applepay_session_token(
                     #[cfg(feature = "kms")]
                     let decrypted_apple_pay_merchant_cert = kms::get_kms_client(&state.conf.kms)
                         .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?
                         .decrypt(&state.conf.applepay_decrypt_keys.apple_pay_merchant_cert)
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -174,6 +175,7 @@ async fn create_applepay_session_token(
                     let decrypted_apple_pay_merchant_cert_key =
                         kms::get_kms_client(&state.conf.kms)
                             .await
+                            .change_context(errors::ApiErrorResponse::InternalServerError)?
                             .decrypt(&state.conf.applepay_decrypt_keys.apple_pay_merchant_cert_key)
                             .await
                             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -184,6 +186,7 @@ async fn create_applepay_session_token(
                     #[cfg(feature = "kms")]
                     let decrypted_merchant_identifier = kms::get_kms_client(&state.conf.kms)
                         .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?
                         .decrypt(
                             &state
                                 .conf
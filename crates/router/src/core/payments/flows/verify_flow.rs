@@ -79,7 +79,14 @@ impl Feature<api::Verify, types::VerifyRequestData> for types::VerifyRouterData
         )
         .await?;
 
-        mandate::mandate_procedure(state, resp, maybe_customer, pm_id).await
+        mandate::mandate_procedure(
+            state,
+            resp,
+            maybe_customer,
+            pm_id,
+            merchant_account.storage_scheme,
+        )
+        .await
     }
 
     async fn add_access_token<'a>(
@@ -204,7 +211,14 @@ impl types::VerifyRouterData {
                 )
                 .await?;
 
-                Ok(mandate::mandate_procedure(state, resp, maybe_customer, pm_id).await?)
+                Ok(mandate::mandate_procedure(
+                    state,
+                    resp,
+                    maybe_customer,
+                    pm_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?)
             }
             _ => Ok(self.clone()),
         }
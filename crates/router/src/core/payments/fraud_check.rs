@@ -0,0 +1,1034 @@
+use std::{fmt::Debug, marker::PhantomData, str::FromStr};
+
+use api_models::{
+    admin::FrmConfigs,
+    enums::{self as api_enums, FrmSuggestion},
+    payments::HeaderPayload,
+};
+use diesel_models::fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate};
+use error_stack::{report, ResultExt};
+use masking::ExposeInterface;
+use router_env::{instrument, logger, tracing};
+
+use super::{helpers, CallConnectorAction, PaymentData};
+use crate::{
+    consts,
+    core::{
+        blocklist::utils as blocklist_utils,
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        refunds,
+    },
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, fraud_check as frm_api},
+        domain,
+        storage::enums as storage_enums,
+    },
+    utils::{self, Encode, ValueExt},
+};
+
+/// The FRM connector to be used for the payments of a merchant, stored as the
+/// `frm_routing_algorithm` of the merchant account, e.g. `{"type": "single", "data": "signifyd"}`
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum FrmRoutingAlgorithm {
+    Single(String),
+}
+
+/// The FRM flow and action configured for the payment method and gateway of a payment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrmConfigsObject {
+    pub frm_preferred_flow_type: api_enums::FrmPreferredFlowTypes,
+    pub frm_action: api_enums::FrmAction,
+}
+
+#[derive(Clone)]
+pub struct FrmInfo {
+    pub connector: frm_api::FraudCheckConnectorData,
+    pub merchant_connector_account: helpers::MerchantConnectorAccountType,
+    pub frm_configs: FrmConfigsObject,
+}
+
+/// Details of the payment which are persisted along with the fraud check
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct FrmPaymentDetails {
+    /// The capture method of the payment, before the capture was held for the post authorization
+    /// fraud check
+    pub capture_method: Option<storage_enums::CaptureMethod>,
+}
+
+/// Picks the first FRM config which applies to the gateway, payment method, payment method type
+/// and card network of a payment, configs which do not specify one of these apply to all of them
+pub fn get_frm_config(
+    frm_configs: &[FrmConfigs],
+    gateway: Option<api_enums::Connector>,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    payment_method_type: Option<storage_enums::PaymentMethodType>,
+    card_network: Option<&api_enums::CardNetwork>,
+) -> Option<FrmConfigsObject> {
+    frm_configs
+        .iter()
+        .filter(|config| is_config_applicable(config.gateway, gateway))
+        .flat_map(|config| config.payment_methods.iter())
+        .filter(|frm_payment_method| {
+            is_config_applicable(frm_payment_method.payment_method, payment_method)
+        })
+        .flat_map(|frm_payment_method| frm_payment_method.payment_method_types.iter())
+        .find(|frm_payment_method_type| {
+            is_config_applicable(
+                frm_payment_method_type.payment_method_type,
+                payment_method_type,
+            ) && frm_payment_method_type
+                .card_networks
+                .as_ref()
+                .zip(card_network)
+                .map_or(true, |(card_networks, card_network)| {
+                    card_networks.contains(card_network)
+                })
+        })
+        .map(|frm_payment_method_type| FrmConfigsObject {
+            frm_preferred_flow_type: frm_payment_method_type.flow.clone(),
+            frm_action: frm_payment_method_type.action.clone(),
+        })
+}
+
+fn is_config_applicable<T: PartialEq>(configured: Option<T>, actual: Option<T>) -> bool {
+    configured.map_or(true, |configured| Some(configured) == actual)
+}
+
+/// Decides what is to be done with a payment, based on the outcome of its fraud check and the
+/// action configured for the merchant. `None` lets the payment go through.
+pub fn get_frm_suggestion(
+    frm_preferred_flow_type: &api_enums::FrmPreferredFlowTypes,
+    frm_action: &api_enums::FrmAction,
+    frm_status: storage_enums::FraudCheckStatus,
+) -> Option<FrmSuggestion> {
+    match (frm_status, frm_action) {
+        (storage_enums::FraudCheckStatus::ManualReview, _)
+        | (storage_enums::FraudCheckStatus::Fraud, api_enums::FrmAction::ManualReview) => {
+            Some(FrmSuggestion::FrmManualReview)
+        }
+        (storage_enums::FraudCheckStatus::Fraud, api_enums::FrmAction::CancelTxn) => {
+            Some(FrmSuggestion::FrmCancelTransaction)
+        }
+        // Nothing has been charged before the authorization, hence there is nothing to refund
+        (storage_enums::FraudCheckStatus::Fraud, api_enums::FrmAction::AutoRefund) => {
+            match frm_preferred_flow_type {
+                api_enums::FrmPreferredFlowTypes::Pre => Some(FrmSuggestion::FrmCancelTransaction),
+                api_enums::FrmPreferredFlowTypes::Post => Some(FrmSuggestion::FrmAutoRefund),
+            }
+        }
+        // Customer authentication can only be requested before the authorization, the payment
+        // is held for a review once it has been authorized
+        (storage_enums::FraudCheckStatus::Fraud, api_enums::FrmAction::ThreeDs) => {
+            match frm_preferred_flow_type {
+                api_enums::FrmPreferredFlowTypes::Pre => None,
+                api_enums::FrmPreferredFlowTypes::Post => Some(FrmSuggestion::FrmManualReview),
+            }
+        }
+        (
+            storage_enums::FraudCheckStatus::Legit
+            | storage_enums::FraudCheckStatus::Pending
+            | storage_enums::FraudCheckStatus::TransactionFailure,
+            _,
+        ) => None,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn get_frm_info<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &PaymentData<F>,
+    gateway: Option<api_enums::Connector>,
+) -> RouterResult<Option<FrmInfo>> {
+    let frm_routing_algorithm: FrmRoutingAlgorithm =
+        match merchant_account.frm_routing_algorithm.clone() {
+            Some(frm_routing_algorithm) => frm_routing_algorithm
+                .parse_value("FrmRoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid frm routing algorithm configured for the merchant")?,
+            None => return Ok(None),
+        };
+    let FrmRoutingAlgorithm::Single(frm_connector_name) = frm_routing_algorithm;
+
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_connector_name(
+            &merchant_account.merchant_id,
+            &frm_connector_name,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: frm_connector_name.clone(),
+        })?;
+
+    if merchant_connector_account.disabled.unwrap_or(false) {
+        return Ok(None);
+    }
+
+    let frm_configs = merchant_connector_account
+        .frm_configs
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|frm_config| frm_config.expose().parse_value("FrmConfigs"))
+        .collect::<Result<Vec<FrmConfigs>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid frm configs found for the merchant connector account")?;
+
+    let card_network = match payment_data.payment_method_data.as_ref() {
+        Some(api::PaymentMethodData::Card(card)) => card.card_network.as_ref(),
+        _ => None,
+    };
+
+    let frm_configs = match get_frm_config(
+        &frm_configs,
+        gateway,
+        payment_data.payment_attempt.payment_method,
+        payment_data.payment_attempt.payment_method_type,
+        card_network,
+    ) {
+        Some(frm_configs) => frm_configs,
+        None => return Ok(None),
+    };
+
+    let connector = frm_api::FraudCheckConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &frm_connector_name,
+    )?;
+
+    Ok(Some(FrmInfo {
+        connector,
+        merchant_connector_account: helpers::MerchantConnectorAccountType::DbVal(
+            merchant_connector_account,
+        ),
+        frm_configs,
+    }))
+}
+
+/// Runs the pre authorization fraud check of a payment being confirmed. For payments configured
+/// for a post authorization check, the capture is held until the check has been made.
+///
+/// A suggestion is returned when the payment must not be authorized with the connector.
+#[instrument(skip_all)]
+pub async fn pre_payment_frm_core<F: Clone + Send, Op: Debug>(
+    state: &AppState,
+    operation: &Op,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector: &api::ConnectorData,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<Option<FrmSuggestion>> {
+    if !super::is_operation_confirm(operation) {
+        return Ok(None);
+    }
+
+    // A payment resumed after its external 3DS authentication has already been checked when it
+    // was first confirmed
+    if payment_data.payment_attempt.status == storage_enums::AttemptStatus::AuthenticationPending {
+        payment_data.frm_message = state
+            .store
+            .find_fraud_check_by_payment_id_if_present(
+                payment_data.payment_attempt.payment_id.clone(),
+                merchant_account.merchant_id.clone(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the fraud check of the payment")?;
+        return Ok(None);
+    }
+
+    let frm_info = match get_frm_info(
+        state,
+        merchant_account,
+        key_store,
+        payment_data,
+        Some(connector.connector_name),
+    )
+    .await?
+    {
+        Some(frm_info) => frm_info,
+        None => return Ok(None),
+    };
+
+    match frm_info.frm_configs.frm_preferred_flow_type {
+        api_enums::FrmPreferredFlowTypes::Pre => {
+            let request = types::FraudCheckCheckoutData {
+                amount: payment_data.amount.into(),
+                currency: Some(payment_data.currency),
+                payment_method: payment_data.payment_attempt.payment_method,
+                payment_method_type: payment_data.payment_attempt.payment_method_type,
+                payment_method_data: payment_data.payment_method_data.clone(),
+                browser_info: payment_data
+                    .payment_attempt
+                    .browser_info
+                    .clone()
+                    .map(|browser_info| browser_info.parse_value("BrowserInformation"))
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse browser info")?,
+                email: payment_data.email.clone(),
+                gateway: Some(connector.connector_name.to_string()),
+            };
+            let router_data: types::FrmCheckoutRouterData =
+                construct_frm_router_data(merchant_account, &frm_info, payment_data, request)?;
+
+            let connector_integration: services::BoxedConnectorIntegration<
+                '_,
+                frm_api::Checkout,
+                types::FraudCheckCheckoutData,
+                types::FraudCheckResponseData,
+            > = frm_info.connector.connector.get_connector_integration();
+
+            // The payment is not authorized without a decision of the fraud check, a failure of
+            // the check is hence surfaced to the caller instead of letting the payment through
+            let response = services::execute_connector_processing_step(
+                state,
+                connector_integration,
+                &router_data,
+                CallConnectorAction::Trigger,
+                None,
+            )
+            .await
+            .to_payment_failed_response()
+            .attach_printable("Failed to run the pre authorization fraud check")?
+            .response;
+            let frm_error = response.as_ref().err().cloned();
+
+            let fraud_check = insert_fraud_check(
+                state,
+                &frm_info,
+                payment_data,
+                storage_enums::FraudCheckType::PreFrm,
+                storage_enums::FraudCheckLastStep::CheckoutOrSale,
+                response,
+                None,
+            )
+            .await?;
+
+            if let Some(frm_error) = frm_error {
+                return Err(report!(errors::ApiErrorResponse::ExternalConnectorError {
+                    code: frm_error.code,
+                    message: frm_error.message,
+                    connector: frm_info.connector.connector_name,
+                    status_code: frm_error.status_code,
+                    reason: frm_error.reason,
+                }))
+                .attach_printable("The pre authorization fraud check failed");
+            }
+
+            if fraud_check.frm_status == storage_enums::FraudCheckStatus::Fraud
+                && frm_info.frm_configs.frm_action == api_enums::FrmAction::ThreeDs
+            {
+                payment_data.payment_attempt.authentication_type =
+                    Some(storage_enums::AuthenticationType::ThreeDs);
+            }
+
+            if fraud_check.frm_status == storage_enums::FraudCheckStatus::Fraud {
+                blocklist_utils::record_fraudulent_payment(state, merchant_account, payment_data)
+                    .await
+                    .map_err(|error| logger::error!(blocklist_fraud_event_error=?error))
+                    .ok();
+            }
+
+            let frm_suggestion = get_frm_suggestion(
+                &frm_info.frm_configs.frm_preferred_flow_type,
+                &frm_info.frm_configs.frm_action,
+                fraud_check.frm_status,
+            );
+            payment_data.frm_message = Some(fraud_check);
+
+            Ok(frm_suggestion)
+        }
+        api_enums::FrmPreferredFlowTypes::Post => {
+            // The capture is only held when the decision of the check can require a manual
+            // review, payments found to be fraudulent are otherwise voided or refunded
+            let capture_method = payment_data.payment_attempt.capture_method;
+            let is_capture_to_be_held = matches!(
+                capture_method,
+                None | Some(storage_enums::CaptureMethod::Automatic)
+            ) && get_frm_suggestion(
+                &frm_info.frm_configs.frm_preferred_flow_type,
+                &frm_info.frm_configs.frm_action,
+                storage_enums::FraudCheckStatus::Fraud,
+            ) == Some(FrmSuggestion::FrmManualReview);
+            let fraud_check = insert_fraud_check(
+                state,
+                &frm_info,
+                payment_data,
+                storage_enums::FraudCheckType::PostFrm,
+                storage_enums::FraudCheckLastStep::Processing,
+                Ok(types::FraudCheckResponseData {
+                    transaction_id: None,
+                    status: storage_enums::FraudCheckStatus::Pending,
+                    score: None,
+                    reason: None,
+                    connector_metadata: None,
+                }),
+                is_capture_to_be_held.then_some(FrmPaymentDetails { capture_method }),
+            )
+            .await?;
+
+            if is_capture_to_be_held {
+                payment_data.payment_attempt.capture_method =
+                    Some(storage_enums::CaptureMethod::Manual);
+            }
+            payment_data.frm_message = Some(fraud_check);
+
+            Ok(None)
+        }
+    }
+}
+
+/// Runs the post authorization fraud check of a payment once the connector has responded to its
+/// authorization, and then captures, voids, refunds or holds the payment based on its outcome
+#[instrument(skip_all)]
+pub async fn post_payment_frm_core<F: Clone + Send + Sync>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let fraud_check = match payment_data.frm_message.clone() {
+        Some(fraud_check)
+            if fraud_check.frm_transaction_type == storage_enums::FraudCheckType::PostFrm
+                && fraud_check.last_step == storage_enums::FraudCheckLastStep::Processing =>
+        {
+            fraud_check
+        }
+        _ => return Ok(()),
+    };
+
+    let gateway = payment_data
+        .payment_attempt
+        .connector
+        .as_deref()
+        .and_then(|connector| api_enums::Connector::from_str(connector).ok());
+    let frm_info =
+        match get_frm_info(state, merchant_account, key_store, payment_data, gateway).await? {
+            Some(frm_info) => frm_info,
+            None => return Ok(()),
+        };
+
+    let request = types::FraudCheckTransactionData {
+        amount: payment_data.amount.into(),
+        currency: Some(payment_data.currency),
+        payment_method: payment_data.payment_attempt.payment_method,
+        payment_method_type: payment_data.payment_attempt.payment_method_type,
+        gateway: payment_data.payment_attempt.connector.clone(),
+        connector_transaction_id: payment_data
+            .payment_attempt
+            .connector_transaction_id
+            .clone(),
+        attempt_status: payment_data.payment_attempt.status,
+        error_code: payment_data.payment_attempt.error_code.clone(),
+        error_message: payment_data.payment_attempt.error_message.clone(),
+    };
+    let router_data: types::FrmTransactionRouterData =
+        construct_frm_router_data(merchant_account, &frm_info, payment_data, request)?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        frm_api::Transaction,
+        types::FraudCheckTransactionData,
+        types::FraudCheckResponseData,
+    > = frm_info.connector.connector.get_connector_integration();
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .map(|router_data| router_data.response)
+    .unwrap_or_else(|error| {
+        logger::error!(frm_transaction_error=?error);
+        Err(types::ErrorResponse::default())
+    });
+
+    let fraud_check_update = match response {
+        Ok(response) => FraudCheckUpdate::ResponseUpdate {
+            frm_status: response.status,
+            frm_transaction_id: response.transaction_id,
+            frm_reason: response.reason,
+            frm_score: response.score,
+            metadata: response.connector_metadata,
+            modified_at: common_utils::date_time::now(),
+            last_step: storage_enums::FraudCheckLastStep::TransactionOrRecordRefund,
+        },
+        Err(error) => FraudCheckUpdate::ErrorUpdate {
+            status: storage_enums::FraudCheckStatus::TransactionFailure,
+            error_message: Some(Some(error.message)),
+        },
+    };
+    let fraud_check = state
+        .store
+        .update_fraud_check_response_with_attempt_id(fraud_check, fraud_check_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the fraud check")?;
+
+    let frm_suggestion = get_frm_suggestion(
+        &frm_info.frm_configs.frm_preferred_flow_type,
+        &frm_info.frm_configs.frm_action,
+        fraud_check.frm_status,
+    );
+    let is_capture_held = is_capture_held_for_fraud_check(&fraud_check);
+    if fraud_check.frm_status == storage_enums::FraudCheckStatus::Fraud {
+        blocklist_utils::record_fraudulent_payment(state, merchant_account, payment_data)
+            .await
+            .map_err(|error| logger::error!(blocklist_fraud_event_error=?error))
+            .ok();
+    }
+    let is_check_failed =
+        fraud_check.frm_status == storage_enums::FraudCheckStatus::TransactionFailure;
+    payment_data.frm_message = Some(fraud_check);
+
+    match payment_data.payment_attempt.status {
+        // An authorized payment is captured, voided or held for a review
+        storage_enums::AttemptStatus::Authorized => {}
+        // A payment which has already been charged is refunded
+        storage_enums::AttemptStatus::Charged => {
+            return match frm_suggestion {
+                Some(FrmSuggestion::FrmCancelTransaction | FrmSuggestion::FrmAutoRefund) => {
+                    refund_payment(state, merchant_account, key_store, payment_data).await
+                }
+                Some(FrmSuggestion::FrmManualReview) | None => Ok(()),
+            };
+        }
+        _ => return Ok(()),
+    }
+
+    match frm_suggestion {
+        Some(FrmSuggestion::FrmCancelTransaction | FrmSuggestion::FrmAutoRefund) => {
+            void_payment(
+                state,
+                merchant_account,
+                key_store,
+                payment_data,
+                "Cancelled by the fraud check",
+            )
+            .await
+        }
+        // A payment is not captured without a decision of the fraud check, it is left for the
+        // merchant to approve or reject
+        None if is_capture_held && is_check_failed => {
+            logger::warn!(
+                "Payment {} held for a manual review as its fraud check failed",
+                payment_data.payment_attempt.payment_id
+            );
+            Ok(())
+        }
+        Some(FrmSuggestion::FrmManualReview) => {
+            logger::info!(
+                "Payment {} held for a manual review after the fraud check",
+                payment_data.payment_attempt.payment_id
+            );
+            Ok(())
+        }
+        None if is_capture_held => {
+            capture_payment(state, merchant_account, key_store, payment_data).await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Approves a payment held for a manual review by the fraud check. A payment held before its
+/// authorization is authorized, and one held after its authorization is captured.
+#[instrument(skip_all)]
+pub async fn approve_payment(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: api_models::payments::PaymentsApproveRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let payment_id = request.payment_id;
+    let response = super::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        super::PaymentApprove,
+        api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.clone())),
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        HeaderPayload::default(),
+    )
+    .await?;
+
+    let fraud_check = state
+        .store
+        .find_fraud_check_by_payment_id_if_present(
+            payment_id.clone(),
+            merchant_account.merchant_id.clone(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the fraud check")?;
+
+    if !fraud_check
+        .as_ref()
+        .map_or(false, is_capture_held_for_fraud_check)
+    {
+        return Ok(response);
+    }
+
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.status != storage_enums::IntentStatus::RequiresCapture {
+        return Ok(response);
+    }
+
+    super::payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account.clone(),
+        key_store,
+        super::PaymentCapture,
+        api::PaymentsCaptureRequest {
+            payment_id: Some(payment_id),
+            merchant_id: Some(merchant_account.merchant_id),
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        HeaderPayload::default(),
+    )
+    .await
+}
+
+/// Rejects a payment held for a manual review by the fraud check. The authorization of a payment
+/// held after its authorization is voided before the payment is marked as rejected.
+#[instrument(skip_all)]
+pub async fn reject_payment(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: api_models::payments::PaymentsRejectRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let fraud_check = state
+        .store
+        .find_fraud_check_by_payment_id_if_present(
+            request.payment_id.clone(),
+            merchant_account.merchant_id.clone(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the fraud check")?;
+
+    if matches!(
+        fraud_check,
+        Some(ref fraud_check)
+            if fraud_check.frm_transaction_type == storage_enums::FraudCheckType::PostFrm
+    ) {
+        let payment_intent = state
+            .store
+            .find_payment_intent_by_payment_id_merchant_id(
+                &request.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        if payment_intent.status == storage_enums::IntentStatus::RequiresCapture {
+            super::payments_operation_core::<api::Void, _, _, _>(
+                &state,
+                merchant_account.clone(),
+                key_store.clone(),
+                super::PaymentCancel,
+                api::PaymentsCancelRequest {
+                    payment_id: request.payment_id.clone(),
+                    cancellation_reason: Some("Rejected after a manual review".to_string()),
+                    merchant_connector_details: None,
+                },
+                CallConnectorAction::Trigger,
+                services::AuthFlow::Merchant,
+                HeaderPayload::default(),
+            )
+            .await?;
+        }
+    }
+
+    super::payments_core::<api::Reject, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account,
+        key_store,
+        super::PaymentReject,
+        request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        HeaderPayload::default(),
+    )
+    .await
+}
+
+/// Whether the capture of the payment was switched to manual for its post authorization fraud
+/// check, and is hence to be captured once the payment is found to be legitimate
+fn is_capture_held_for_fraud_check(fraud_check: &FraudCheck) -> bool {
+    fraud_check.frm_transaction_type == storage_enums::FraudCheckType::PostFrm
+        && fraud_check
+            .payment_details
+            .clone()
+            .and_then(|payment_details| {
+                payment_details
+                    .parse_value::<FrmPaymentDetails>("FrmPaymentDetails")
+                    .ok()
+            })
+            .map_or(false, |payment_details| {
+                matches!(
+                    payment_details.capture_method,
+                    None | Some(storage_enums::CaptureMethod::Automatic)
+                )
+            })
+}
+
+async fn capture_payment<F: Clone + Send + Sync>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let (capture_data, _, _, _) = super::payments_operation_core::<api::Capture, _, _, _>(
+        state,
+        merchant_account.clone(),
+        key_store.clone(),
+        super::PaymentCapture,
+        api::PaymentsCaptureRequest {
+            payment_id: Some(payment_data.payment_attempt.payment_id.clone()),
+            merchant_id: Some(merchant_account.merchant_id.clone()),
+            ..Default::default()
+        },
+        CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        HeaderPayload::default(),
+    )
+    .await?;
+
+    payment_data.payment_intent = capture_data.payment_intent;
+    payment_data.payment_attempt = capture_data.payment_attempt;
+    Ok(())
+}
+
+async fn refund_payment<F: Clone + Send + Sync>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    refunds::refund_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        api_models::refunds::RefundRequest {
+            payment_id: payment_data.payment_attempt.payment_id.clone(),
+            merchant_id: Some(merchant_account.merchant_id.clone()),
+            reason: Some("Refunded by the fraud check".to_string()),
+            ..Default::default()
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+async fn void_payment<F: Clone + Send + Sync>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut PaymentData<F>,
+    cancellation_reason: &str,
+) -> RouterResult<()> {
+    let (void_data, _, _, _) = super::payments_operation_core::<api::Void, _, _, _>(
+        state,
+        merchant_account.clone(),
+        key_store.clone(),
+        super::PaymentCancel,
+        api::PaymentsCancelRequest {
+            payment_id: payment_data.payment_attempt.payment_id.clone(),
+            cancellation_reason: Some(cancellation_reason.to_string()),
+            merchant_connector_details: None,
+        },
+        CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        HeaderPayload::default(),
+    )
+    .await?;
+
+    payment_data.payment_intent = void_data.payment_intent;
+    payment_data.payment_attempt = void_data.payment_attempt;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn insert_fraud_check<F: Clone>(
+    state: &AppState,
+    frm_info: &FrmInfo,
+    payment_data: &PaymentData<F>,
+    frm_transaction_type: storage_enums::FraudCheckType,
+    last_step: storage_enums::FraudCheckLastStep,
+    response: Result<types::FraudCheckResponseData, types::ErrorResponse>,
+    payment_details: Option<FrmPaymentDetails>,
+) -> RouterResult<FraudCheck> {
+    let payment_details = payment_details
+        .as_ref()
+        .map(Encode::<FrmPaymentDetails>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode frm payment details")?;
+
+    let (frm_status, frm_transaction_id, frm_score, frm_reason, metadata, frm_error) =
+        match response {
+            Ok(response) => (
+                response.status,
+                response.transaction_id,
+                response.score,
+                response.reason,
+                response.connector_metadata,
+                None,
+            ),
+            Err(error) => (
+                storage_enums::FraudCheckStatus::TransactionFailure,
+                None,
+                None,
+                None,
+                None,
+                Some(error.message),
+            ),
+        };
+
+    let now = common_utils::date_time::now();
+    let fraud_check_new = FraudCheckNew {
+        frm_id: utils::generate_id(consts::ID_LENGTH, "frm"),
+        payment_id: payment_data.payment_attempt.payment_id.clone(),
+        merchant_id: payment_data.payment_attempt.merchant_id.clone(),
+        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+        created_at: now,
+        frm_name: frm_info.connector.connector_name.clone(),
+        frm_transaction_id,
+        frm_transaction_type,
+        frm_status,
+        frm_score,
+        frm_reason,
+        frm_error,
+        payment_details,
+        metadata,
+        modified_at: now,
+        last_step,
+    };
+
+    state
+        .store
+        .insert_fraud_check_response(fraud_check_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the fraud check")
+}
+
+fn construct_frm_router_data<F, Flow, Req>(
+    merchant_account: &domain::MerchantAccount,
+    frm_info: &FrmInfo,
+    payment_data: &PaymentData<F>,
+    request: Req,
+) -> RouterResult<types::RouterData<Flow, Req, types::FraudCheckResponseData>>
+where
+    F: Clone,
+{
+    let connector_auth_type: types::ConnectorAuthType = frm_info
+        .merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: payment_data.payment_intent.customer_id.clone(),
+        connector_customer: None,
+        connector: frm_info.connector.connector_name.clone(),
+        payment_id: payment_data.payment_attempt.payment_id.clone(),
+        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+        status: payment_data.payment_attempt.status,
+        payment_method: payment_data
+            .payment_attempt
+            .payment_method
+            .unwrap_or_default(),
+        connector_auth_type,
+        description: payment_data.payment_intent.description.clone(),
+        return_url: payment_data.payment_intent.return_url.clone(),
+        address: payment_data.address.clone(),
+        auth_type: payment_data
+            .payment_attempt
+            .authentication_type
+            .unwrap_or_default(),
+        connector_meta_data: frm_info.merchant_connector_account.get_metadata(),
+        amount_captured: payment_data.payment_intent.amount_captured,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_api_version: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+        payment_method_id: None,
+        connector_request_reference_id: payment_data.payment_attempt.attempt_id.clone(),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: frm_info.merchant_connector_account.is_test_mode_on(),
+        connector_http_status_code: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::admin::{FrmPaymentMethod, FrmPaymentMethodType};
+
+    use super::*;
+
+    fn frm_config(
+        gateway: Option<api_enums::Connector>,
+        payment_method_type: Option<storage_enums::PaymentMethodType>,
+        flow: api_enums::FrmPreferredFlowTypes,
+        action: api_enums::FrmAction,
+    ) -> FrmConfigs {
+        FrmConfigs {
+            gateway,
+            payment_methods: vec![FrmPaymentMethod {
+                payment_method: Some(storage_enums::PaymentMethod::Card),
+                payment_method_types: vec![FrmPaymentMethodType {
+                    payment_method_type,
+                    card_networks: Some(vec![api_enums::CardNetwork::Visa]),
+                    flow,
+                    action,
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_get_frm_config() {
+        let frm_configs = vec![
+            frm_config(
+                Some(api_enums::Connector::Stripe),
+                Some(storage_enums::PaymentMethodType::Credit),
+                api_enums::FrmPreferredFlowTypes::Pre,
+                api_enums::FrmAction::CancelTxn,
+            ),
+            frm_config(
+                None,
+                None,
+                api_enums::FrmPreferredFlowTypes::Post,
+                api_enums::FrmAction::ManualReview,
+            ),
+        ];
+
+        let stripe_credit = get_frm_config(
+            &frm_configs,
+            Some(api_enums::Connector::Stripe),
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::PaymentMethodType::Credit),
+            Some(&api_enums::CardNetwork::Visa),
+        )
+        .unwrap();
+        assert_eq!(
+            stripe_credit.frm_preferred_flow_type,
+            api_enums::FrmPreferredFlowTypes::Pre
+        );
+
+        let adyen_debit = get_frm_config(
+            &frm_configs,
+            Some(api_enums::Connector::Adyen),
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::PaymentMethodType::Debit),
+            None,
+        )
+        .unwrap();
+        assert_eq!(adyen_debit.frm_action, api_enums::FrmAction::ManualReview);
+
+        let mastercard = get_frm_config(
+            &frm_configs,
+            Some(api_enums::Connector::Stripe),
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::PaymentMethodType::Credit),
+            Some(&api_enums::CardNetwork::Mastercard),
+        );
+        assert!(mastercard.is_none());
+
+        let wallet = get_frm_config(
+            &frm_configs,
+            Some(api_enums::Connector::Stripe),
+            Some(storage_enums::PaymentMethod::Wallet),
+            Some(storage_enums::PaymentMethodType::ApplePay),
+            None,
+        );
+        assert!(wallet.is_none());
+    }
+
+    #[test]
+    fn test_get_frm_suggestion() {
+        let pre = api_enums::FrmPreferredFlowTypes::Pre;
+        let post = api_enums::FrmPreferredFlowTypes::Post;
+
+        assert_eq!(
+            get_frm_suggestion(
+                &pre,
+                &api_enums::FrmAction::AutoRefund,
+                storage_enums::FraudCheckStatus::Fraud
+            ),
+            Some(FrmSuggestion::FrmCancelTransaction)
+        );
+        assert_eq!(
+            get_frm_suggestion(
+                &post,
+                &api_enums::FrmAction::AutoRefund,
+                storage_enums::FraudCheckStatus::Fraud
+            ),
+            Some(FrmSuggestion::FrmAutoRefund)
+        );
+        assert_eq!(
+            get_frm_suggestion(
+                &pre,
+                &api_enums::FrmAction::ThreeDs,
+                storage_enums::FraudCheckStatus::Fraud
+            ),
+            None
+        );
+        assert_eq!(
+            get_frm_suggestion(
+                &post,
+                &api_enums::FrmAction::CancelTxn,
+                storage_enums::FraudCheckStatus::ManualReview
+            ),
+            Some(FrmSuggestion::FrmManualReview)
+        );
+        assert_eq!(
+            get_frm_suggestion(
+                &post,
+                &api_enums::FrmAction::CancelTxn,
+                storage_enums::FraudCheckStatus::Legit
+            ),
+            None
+        );
+    }
+}
@@ -17,11 +17,8 @@ use external_services::kms;
 use josekit::jwe;
 use masking::{ExposeInterface, PeekInterface};
 #[cfg(feature = "kms")]
-use openssl::derive::Deriver;
-#[cfg(feature = "kms")]
-use openssl::pkey::PKey;
-#[cfg(feature = "kms")]
-use openssl::symm::{decrypt_aead, Cipher};
+use openssl::symm::decrypt_aead;
+use openssl::{derive::Deriver, pkey::PKey, symm::Cipher};
 use router_env::{instrument, logger, tracing};
 use time::Duration;
 use uuid::Uuid;
@@ -53,7 +50,7 @@ use crate::{
         },
         storage::{self, enums as storage_enums, ephemeral_key, CustomerUpdate::Update},
         transformers::{ForeignFrom, ForeignTryFrom},
-        ErrorResponse, RouterData,
+        ErrorResponse, GooglePayPredecryptData, RouterData,
     },
     utils::{
         self,
@@ -111,8 +108,9 @@ pub async fn get_address_for_payment_request(
     merchant_id: &str,
     customer_id: Option<&String>,
     merchant_key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
-    let key = merchant_key_store.key.get_inner().peek();
+    let key = merchant_key_store.encryption_key();
 
     Ok(match req_address {
         Some(address) => {
@@ -183,9 +181,14 @@ pub async fn get_address_for_payment_request(
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Failed while encrypting address")?;
                     Some(
-                        db.update_address(id.to_owned(), address_update, merchant_key_store)
-                            .await
-                            .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?,
+                        db.update_address(
+                            id.to_owned(),
+                            address_update,
+                            merchant_key_store,
+                            storage_scheme,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?,
                     )
                 }
                 None => {
@@ -249,6 +252,7 @@ pub async fn get_address_for_payment_request(
                             .change_context(errors::ApiErrorResponse::InternalServerError)
                             .attach_printable("Failed while encrypting address while insert")?,
                             merchant_key_store,
+                            storage_scheme,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -258,9 +262,12 @@ pub async fn get_address_for_payment_request(
             }
         }
         None => match address_id {
-            Some(id) => Some(db.find_address(id, merchant_key_store).await)
-                .transpose()
-                .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?,
+            Some(id) => Some(
+                db.find_address(id, merchant_key_store, storage_scheme)
+                    .await,
+            )
+            .transpose()
+            .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?,
             None => None,
         },
     })
@@ -270,10 +277,14 @@ pub async fn get_address_by_id(
     db: &dyn StorageInterface,
     address_id: Option<String>,
     merchant_key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
     match address_id {
         None => Ok(None),
-        Some(address_id) => Ok(db.find_address(&address_id, merchant_key_store).await.ok()),
+        Some(address_id) => Ok(db
+            .find_address(&address_id, merchant_key_store, storage_scheme)
+            .await
+            .ok()),
     }
 }
 
@@ -346,7 +357,11 @@ pub async fn get_token_for_recurring_mandate(
     let mandate_id = req.mandate_id.clone().get_required_value("mandate_id")?;
 
     let mandate = db
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, mandate_id.as_str())
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            mandate_id.as_str(),
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
 
@@ -891,6 +906,7 @@ pub async fn get_customer_from_details<F: Clone>(
     merchant_id: &str,
     payment_data: &mut PaymentData<F>,
     merchant_key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
     match customer_id {
         None => Ok(None),
@@ -900,6 +916,7 @@ pub async fn get_customer_from_details<F: Clone>(
                     &c_id,
                     merchant_id,
                     merchant_key_store,
+                    storage_scheme,
                 )
                 .await?;
             payment_data.email = payment_data.email.clone().or_else(|| {
@@ -1037,6 +1054,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     req: Option<CustomerDetails>,
     merchant_id: &str,
     key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<(BoxedOperation<'a, F, R>, Option<domain::Customer>), errors::StorageError> {
     let request_customer_details = req
         .get_required_value("customer")
@@ -1053,6 +1071,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                     &customer_id,
                     merchant_id,
                     key_store,
+                    storage_scheme,
                 )
                 .await?;
 
@@ -1064,7 +1083,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                         | request_customer_details.phone.is_some()
                         | request_customer_details.phone_country_code.is_some()
                     {
-                        let key = key_store.key.get_inner().peek();
+                        let key = key_store.encryption_key();
                         let customer_update = async {
                             Ok(Update {
                                 name: request_customer_details
@@ -1101,6 +1120,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                             merchant_id.to_string(),
                             customer_update,
                             key_store,
+                            storage_scheme,
                         )
                         .await
                     } else {
@@ -1109,7 +1129,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                 }
                 None => {
                     let new_customer = async {
-                        let key = key_store.key.get_inner().peek();
+                        let key = key_store.encryption_key();
                         Ok(domain::Customer {
                             customer_id: customer_id.to_string(),
                             merchant_id: merchant_id.to_string(),
@@ -1142,7 +1162,8 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                     .change_context(errors::StorageError::SerializationFailed)
                     .attach_printable("Failed while encrypting Customer while insert")?;
                     metrics::CUSTOMER_CREATED.add(&metrics::CONTEXT, 1, &[]);
-                    db.insert_customer(new_customer, key_store).await
+                    db.insert_customer(new_customer, key_store, storage_scheme)
+                        .await
                 }
             })
         }
@@ -1153,6 +1174,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                     customer_id,
                     merchant_id,
                     key_store,
+                    storage_scheme,
                 )
                 .await?
                 .map(Ok),
@@ -2275,6 +2297,9 @@ mod tests {
             profile_id: None,
             merchant_decision: None,
             payment_confirm_source: None,
+            request_incremental_authorization: None,
+            fingerprint_id: None,
+            payment_link_id: None,
         };
         let req_cs = Some("1".to_string());
         let merchant_fulfillment_time = Some(900);
@@ -2322,6 +2347,9 @@ mod tests {
             profile_id: None,
             merchant_decision: None,
             payment_confirm_source: None,
+            request_incremental_authorization: None,
+            fingerprint_id: None,
+            payment_link_id: None,
         };
         let req_cs = Some("1".to_string());
         let merchant_fulfillment_time = Some(10);
@@ -2369,6 +2397,9 @@ mod tests {
             profile_id: None,
             merchant_decision: None,
             payment_confirm_source: None,
+            request_incremental_authorization: None,
+            fingerprint_id: None,
+            payment_link_id: None,
         };
         let req_cs = Some("1".to_string());
         let merchant_fulfillment_time = Some(10);
@@ -2436,6 +2467,17 @@ impl MerchantConnectorAccountType {
         }
     }
 
+    pub fn get_connector_wallets_details(&self) -> Option<masking::Secret<serde_json::Value>> {
+        match self {
+            Self::DbVal(val) => val
+                .connector_wallets_details
+                .as_ref()
+                .map(|details| details.get_inner().to_owned()),
+            // The merchant connector details passed in the request don't hold wallets details
+            Self::CacheVal(_) => None,
+        }
+    }
+
     pub fn is_disabled(&self) -> bool {
         match self {
             Self::DbVal(ref inner) => inner.disabled.unwrap_or(false),
@@ -2758,6 +2800,7 @@ impl AttemptType {
             multiple_capture_count: None,
             connector_response_reference_id: None,
             amount_capturable: old_payment_attempt.amount,
+            fallback_connectors: None,
         }
     }
 
@@ -2949,6 +2992,238 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    mod google_pay {
+        use base64::Engine;
+        use openssl::{
+            bn::BigNumContext,
+            derive::Deriver,
+            ec::{EcGroup, EcKey, PointConversionForm},
+            hash::MessageDigest,
+            nid::Nid,
+            pkey::{PKey, Private},
+            sign::Signer,
+            symm::{encrypt, Cipher},
+        };
+
+        use super::super::{construct_google_pay_signed_data, GooglePayTokenDecryptor};
+        use crate::{
+            consts::BASE64_ENGINE,
+            core::errors,
+            types::{GooglePayAuthMethod, GooglePayPredecryptData},
+        };
+
+        const GOOGLE_PAY_MERCHANT_ID: &str = "12345678901234567890";
+
+        fn generate_key() -> PKey<Private> {
+            let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+            PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap()
+        }
+
+        fn public_key_value(key: &PKey<Private>) -> String {
+            BASE64_ENGINE.encode(key.public_key_to_der().unwrap())
+        }
+
+        fn sign(key: &PKey<Private>, parts: &[&[u8]]) -> String {
+            let mut signer = Signer::new(MessageDigest::sha256(), key).unwrap();
+            signer
+                .update(&construct_google_pay_signed_data(parts).unwrap())
+                .unwrap();
+            BASE64_ENGINE.encode(signer.sign_to_vec().unwrap())
+        }
+
+        fn expiration_from_now(seconds: i64) -> String {
+            ((time::OffsetDateTime::now_utc().unix_timestamp() + seconds) * 1000).to_string()
+        }
+
+        /// Encrypts and signs a payment token the way Google Pay does
+        fn encrypt_token(
+            root_signing_key: &PKey<Private>,
+            merchant_key: &PKey<Private>,
+            message: &serde_json::Value,
+        ) -> String {
+            let ephemeral_key = generate_key();
+            let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+            let ephemeral_public_key = ephemeral_key
+                .ec_key()
+                .unwrap()
+                .public_key()
+                .to_bytes(
+                    &group,
+                    PointConversionForm::UNCOMPRESSED,
+                    &mut BigNumContext::new().unwrap(),
+                )
+                .unwrap();
+
+            let merchant_public_key =
+                PKey::public_key_from_der(&merchant_key.public_key_to_der().unwrap()).unwrap();
+            let mut deriver = Deriver::new(&ephemeral_key).unwrap();
+            deriver.set_peer(&merchant_public_key).unwrap();
+            let shared_secret = deriver.derive_to_vec().unwrap();
+
+            let mut derived_keys = [0u8; 64];
+            ring::hkdf::Salt::new(ring::hkdf::HKDF_SHA256, &[0u8; 32])
+                .extract(&[ephemeral_public_key.as_slice(), &shared_secret].concat())
+                .expand(&[b"Google"], super::super::GooglePayDerivedKeysLength)
+                .unwrap()
+                .fill(&mut derived_keys)
+                .unwrap();
+            let (symmetric_encryption_key, mac_key) = derived_keys.split_at(32);
+
+            let encrypted_message = encrypt(
+                Cipher::aes_256_ctr(),
+                symmetric_encryption_key,
+                Some(&[0u8; 16][..]),
+                message.to_string().as_bytes(),
+            )
+            .unwrap();
+            let tag = ring::hmac::sign(
+                &ring::hmac::Key::new(ring::hmac::HMAC_SHA256, mac_key),
+                &encrypted_message,
+            );
+
+            let signed_message = serde_json::json!({
+                "encryptedMessage": BASE64_ENGINE.encode(&encrypted_message),
+                "ephemeralPublicKey": BASE64_ENGINE.encode(&ephemeral_public_key),
+                "tag": BASE64_ENGINE.encode(tag.as_ref()),
+            })
+            .to_string();
+
+            let intermediate_signing_key = generate_key();
+            let signed_key = serde_json::json!({
+                "keyValue": public_key_value(&intermediate_signing_key),
+                "keyExpiration": expiration_from_now(3600),
+            })
+            .to_string();
+
+            serde_json::json!({
+                "signature": sign(
+                    &intermediate_signing_key,
+                    &[
+                        b"Google",
+                        format!("merchant:{GOOGLE_PAY_MERCHANT_ID}").as_bytes(),
+                        b"ECv2",
+                        signed_message.as_bytes(),
+                    ],
+                ),
+                "intermediateSigningKey": {
+                    "signedKey": signed_key,
+                    "signatures": [sign(root_signing_key, &[b"Google", b"ECv2", signed_key.as_bytes()])],
+                },
+                "protocolVersion": "ECv2",
+                "signedMessage": signed_message,
+            })
+            .to_string()
+        }
+
+        fn get_message(message_expiration: String) -> serde_json::Value {
+            serde_json::json!({
+                "gatewayMerchantId": "merchant_1",
+                "messageExpiration": message_expiration,
+                "messageId": "AH2Ejtc8qBlP_MCAV0jJG7Er",
+                "paymentMethod": "CARD",
+                "paymentMethodDetails": {
+                    "authMethod": "CRYPTOGRAM_3DS",
+                    "pan": "4111111111111111",
+                    "expirationMonth": 12,
+                    "expirationYear": 2030,
+                    "cryptogram": "AAAAAA==",
+                    "eciIndicator": "05",
+                },
+            })
+        }
+
+        fn get_decryptor(
+            root_signing_key: &PKey<Private>,
+            merchant_key: &PKey<Private>,
+        ) -> GooglePayTokenDecryptor {
+            let root_signing_keys = serde_json::json!({
+                "keys": [{
+                    "keyValue": public_key_value(root_signing_key),
+                    "protocolVersion": "ECv2",
+                }],
+            })
+            .to_string();
+            let private_key = BASE64_ENGINE.encode(merchant_key.private_key_to_pkcs8().unwrap());
+
+            GooglePayTokenDecryptor::new(
+                &root_signing_keys,
+                GOOGLE_PAY_MERCHANT_ID,
+                &masking::Secret::new(private_key),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn test_google_pay_token_is_decrypted() {
+            let root_signing_key = generate_key();
+            let merchant_key = generate_key();
+            let token = encrypt_token(
+                &root_signing_key,
+                &merchant_key,
+                &get_message(expiration_from_now(600)),
+            );
+
+            let decrypted: GooglePayPredecryptData =
+                get_decryptor(&root_signing_key, &merchant_key)
+                    .decrypt_token(&token)
+                    .unwrap();
+
+            let payment_method_details = decrypted.payment_method_details;
+            assert_eq!(
+                payment_method_details.auth_method,
+                GooglePayAuthMethod::Cryptogram3ds
+            );
+            assert_eq!(
+                masking::PeekInterface::peek(&payment_method_details.pan),
+                "4111111111111111"
+            );
+            assert_eq!(
+                *masking::PeekInterface::peek(&payment_method_details.expiration_year),
+                2030
+            );
+            assert!(payment_method_details.cryptogram.is_some());
+        }
+
+        #[test]
+        fn test_expired_google_pay_token_is_rejected() {
+            let root_signing_key = generate_key();
+            let merchant_key = generate_key();
+            let token = encrypt_token(
+                &root_signing_key,
+                &merchant_key,
+                &get_message(expiration_from_now(-600)),
+            );
+
+            let error = get_decryptor(&root_signing_key, &merchant_key)
+                .decrypt_token(&token)
+                .unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                errors::GooglePayDecryptionError::TokenExpired
+            ));
+        }
+
+        #[test]
+        fn test_google_pay_token_signed_by_unknown_key_is_rejected() {
+            let merchant_key = generate_key();
+            let token = encrypt_token(
+                &generate_key(),
+                &merchant_key,
+                &get_message(expiration_from_now(600)),
+            );
+
+            let error = get_decryptor(&generate_key(), &merchant_key)
+                .decrypt_token(&token)
+                .unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                errors::GooglePayDecryptionError::SignatureVerificationFailed
+            ));
+        }
+    }
 }
 
 #[instrument(skip_all)]
@@ -3136,6 +3411,7 @@ impl ApplePayData {
     ) -> CustomResult<String, errors::ApplePayDecryptionError> {
         let cert_data = kms::get_kms_client(&state.conf.kms)
             .await
+            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?
             .decrypt(&state.conf.applepay_decrypt_keys.apple_pay_ppc)
             .await
             .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
@@ -3192,6 +3468,7 @@ impl ApplePayData {
 
         let decrypted_apple_pay_ppc_key = kms::get_kms_client(&state.conf.kms)
             .await
+            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?
             .decrypt(&state.conf.applepay_decrypt_keys.apple_pay_ppc_key)
             .await
             .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
@@ -3266,3 +3543,430 @@ impl ApplePayData {
         Ok(decrypted)
     }
 }
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayEncryptedToken {
+    signature: masking::Secret<String>,
+    intermediate_signing_key: GooglePayIntermediateSigningKey,
+    protocol_version: String,
+    signed_message: masking::Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayIntermediateSigningKey {
+    signed_key: masking::Secret<String>,
+    signatures: Vec<masking::Secret<String>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePaySignedKey {
+    key_value: masking::Secret<String>,
+    key_expiration: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePaySignedMessage {
+    encrypted_message: masking::Secret<String>,
+    ephemeral_public_key: masking::Secret<String>,
+    tag: masking::Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct GooglePayRootSigningKeys {
+    keys: Vec<GooglePayRootSigningKey>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayRootSigningKey {
+    key_value: masking::Secret<String>,
+    protocol_version: String,
+    key_expiration: Option<String>,
+}
+
+/// Length of the keys derived from the shared secret, the symmetric encryption key and the MAC
+/// key of 32 bytes each
+struct GooglePayDerivedKeysLength;
+
+impl ring::hkdf::KeyType for GooglePayDerivedKeysLength {
+    fn len(&self) -> usize {
+        64
+    }
+}
+
+/// Verifies and decrypts the Google Pay payment tokens of the `ECv2` protocol, encrypted for the
+/// merchant with the public key that the merchant has registered with Google Pay
+pub struct GooglePayTokenDecryptor {
+    root_signing_keys: Vec<GooglePayRootSigningKey>,
+    recipient_id: String,
+    private_key: PKey<openssl::pkey::Private>,
+}
+
+impl GooglePayTokenDecryptor {
+    pub fn new(
+        root_signing_keys: &str,
+        google_pay_merchant_id: &str,
+        private_key: &masking::Secret<String>,
+    ) -> CustomResult<Self, errors::GooglePayDecryptionError> {
+        let root_signing_keys: GooglePayRootSigningKeys = serde_json::from_str(root_signing_keys)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::ParsingFailed)
+            .attach_printable("Failed to parse the Google Pay root signing keys")?;
+
+        let private_key_bytes = BASE64_ENGINE
+            .decode(private_key.peek().as_bytes())
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+        let private_key = PKey::private_key_from_pkcs8(&private_key_bytes)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+            .attach_printable("Failed to deserialize the private key")?;
+
+        Ok(Self {
+            root_signing_keys: root_signing_keys.keys,
+            recipient_id: format!("merchant:{google_pay_merchant_id}"),
+            private_key,
+        })
+    }
+
+    pub fn decrypt_token(
+        &self,
+        token: &str,
+    ) -> CustomResult<GooglePayPredecryptData, errors::GooglePayDecryptionError> {
+        let encrypted_token: GooglePayEncryptedToken = serde_json::from_str(token)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::ParsingFailed)
+            .attach_printable("Failed to parse the Google Pay payment token")?;
+
+        if encrypted_token.protocol_version != consts::GOOGLE_PAY_PROTOCOL_VERSION {
+            Err(errors::GooglePayDecryptionError::UnsupportedProtocolVersion)
+                .into_report()
+                .attach_printable(format!(
+                    "Unsupported protocol version {}",
+                    encrypted_token.protocol_version
+                ))?;
+        }
+
+        let intermediate_signing_key = self.verify_intermediate_signing_key(&encrypted_token)?;
+        self.verify_message_signature(&encrypted_token, &intermediate_signing_key)?;
+
+        let signed_message: GooglePaySignedMessage =
+            serde_json::from_str(encrypted_token.signed_message.peek())
+                .into_report()
+                .change_context(errors::GooglePayDecryptionError::ParsingFailed)
+                .attach_printable("Failed to parse the signed message")?;
+        let decrypted = self.decrypt_message(&signed_message)?;
+
+        let decrypted_data: GooglePayPredecryptData = serde_json::from_slice(&decrypted)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::ParsingFailed)
+            .attach_printable("Failed to parse the decrypted message")?;
+
+        if is_google_pay_expiration_elapsed(&decrypted_data.message_expiration)? {
+            Err(errors::GooglePayDecryptionError::TokenExpired)
+                .into_report()
+                .attach_printable("The Google Pay payment token has expired")?;
+        }
+
+        Ok(decrypted_data)
+    }
+
+    /// Verifies the intermediate signing key with the root signing keys, and returns it
+    fn verify_intermediate_signing_key(
+        &self,
+        encrypted_token: &GooglePayEncryptedToken,
+    ) -> CustomResult<PKey<openssl::pkey::Public>, errors::GooglePayDecryptionError> {
+        let signed_key = encrypted_token.intermediate_signing_key.signed_key.peek();
+        let signed_data = construct_google_pay_signed_data(&[
+            consts::GOOGLE_PAY_SENDER_ID.as_bytes(),
+            consts::GOOGLE_PAY_PROTOCOL_VERSION.as_bytes(),
+            signed_key.as_bytes(),
+        ])?;
+
+        let mut root_signing_keys = self
+            .root_signing_keys
+            .iter()
+            .filter(|root_signing_key| {
+                root_signing_key.protocol_version == consts::GOOGLE_PAY_PROTOCOL_VERSION
+            })
+            .map(|root_signing_key| {
+                let is_expired = root_signing_key
+                    .key_expiration
+                    .as_deref()
+                    .map(is_google_pay_expiration_elapsed)
+                    .transpose()?
+                    .unwrap_or(false);
+                Ok((!is_expired).then_some(&root_signing_key.key_value))
+            })
+            .collect::<CustomResult<Vec<_>, errors::GooglePayDecryptionError>>()?
+            .into_iter()
+            .flatten()
+            .peekable();
+
+        if root_signing_keys.peek().is_none() {
+            Err(errors::GooglePayDecryptionError::RootSigningKeyNotFound)
+                .into_report()
+                .attach_printable("No unexpired ECv2 root signing key is configured")?;
+        }
+
+        let mut is_verified = false;
+        for root_signing_key in root_signing_keys {
+            let public_key = deserialize_google_pay_public_key(root_signing_key)?;
+            for signature in &encrypted_token.intermediate_signing_key.signatures {
+                if verify_google_pay_signature(&public_key, signature, &signed_data)? {
+                    is_verified = true;
+                }
+            }
+        }
+
+        if !is_verified {
+            Err(errors::GooglePayDecryptionError::SignatureVerificationFailed)
+                .into_report()
+                .attach_printable("Failed to verify the intermediate signing key")?;
+        }
+
+        let signed_key: GooglePaySignedKey = serde_json::from_str(signed_key)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::ParsingFailed)
+            .attach_printable("Failed to parse the intermediate signing key")?;
+
+        if is_google_pay_expiration_elapsed(&signed_key.key_expiration)? {
+            Err(errors::GooglePayDecryptionError::TokenExpired)
+                .into_report()
+                .attach_printable("The intermediate signing key has expired")?;
+        }
+
+        deserialize_google_pay_public_key(&signed_key.key_value)
+    }
+
+    fn verify_message_signature(
+        &self,
+        encrypted_token: &GooglePayEncryptedToken,
+        intermediate_signing_key: &PKey<openssl::pkey::Public>,
+    ) -> CustomResult<(), errors::GooglePayDecryptionError> {
+        let signed_data = construct_google_pay_signed_data(&[
+            consts::GOOGLE_PAY_SENDER_ID.as_bytes(),
+            self.recipient_id.as_bytes(),
+            consts::GOOGLE_PAY_PROTOCOL_VERSION.as_bytes(),
+            encrypted_token.signed_message.peek().as_bytes(),
+        ])?;
+
+        if !verify_google_pay_signature(
+            intermediate_signing_key,
+            &encrypted_token.signature,
+            &signed_data,
+        )? {
+            Err(errors::GooglePayDecryptionError::SignatureVerificationFailed)
+                .into_report()
+                .attach_printable("Failed to verify the signature of the signed message")?;
+        }
+
+        Ok(())
+    }
+
+    fn decrypt_message(
+        &self,
+        signed_message: &GooglePaySignedMessage,
+    ) -> CustomResult<Vec<u8>, errors::GooglePayDecryptionError> {
+        let ephemeral_public_key = BASE64_ENGINE
+            .decode(signed_message.ephemeral_public_key.peek().as_bytes())
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+        let encrypted_message = BASE64_ENGINE
+            .decode(signed_message.encrypted_message.peek().as_bytes())
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+        let tag = BASE64_ENGINE
+            .decode(signed_message.tag.peek().as_bytes())
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+
+        let shared_secret = self.shared_secret(&ephemeral_public_key)?;
+
+        // The symmetric encryption key and the MAC key are derived with HKDF-SHA256, from the
+        // ephemeral public key and the shared secret
+        let input_key_material = [ephemeral_public_key.as_slice(), &shared_secret].concat();
+        let mut derived_keys = [0u8; 64];
+        ring::hkdf::Salt::new(ring::hkdf::HKDF_SHA256, &[0u8; 32])
+            .extract(&input_key_material)
+            .expand(
+                &[consts::GOOGLE_PAY_SENDER_ID.as_bytes()],
+                GooglePayDerivedKeysLength,
+            )
+            .and_then(|okm| okm.fill(&mut derived_keys))
+            .map_err(|_| errors::GooglePayDecryptionError::DerivingSymmetricKeysFailed)
+            .into_report()?;
+        let (symmetric_encryption_key, mac_key) = derived_keys.split_at(32);
+
+        ring::hmac::verify(
+            &ring::hmac::Key::new(ring::hmac::HMAC_SHA256, mac_key),
+            &encrypted_message,
+            &tag,
+        )
+        .map_err(|_| errors::GooglePayDecryptionError::MessageAuthenticationFailed)
+        .into_report()?;
+
+        // The message is encrypted with AES-256-CTR, with an initialization vector of zeroes
+        openssl::symm::decrypt(
+            Cipher::aes_256_ctr(),
+            symmetric_encryption_key,
+            Some(&[0u8; 16][..]),
+            &encrypted_message,
+        )
+        .into_report()
+        .change_context(errors::GooglePayDecryptionError::DecryptionFailed)
+    }
+
+    fn shared_secret(
+        &self,
+        ephemeral_public_key: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::GooglePayDecryptionError> {
+        // The ephemeral public key is an uncompressed point on the NIST P-256 curve
+        let group = openssl::ec::EcGroup::from_curve_name(openssl::nid::Nid::X9_62_PRIME256V1)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)?;
+        let mut big_num_context = openssl::bn::BigNumContext::new()
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)?;
+        let point =
+            openssl::ec::EcPoint::from_bytes(&group, ephemeral_public_key, &mut big_num_context)
+                .into_report()
+                .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+                .attach_printable("Failed to deserialize the ephemeral public key")?;
+        let public_key = openssl::ec::EcKey::from_public_key(&group, &point)
+            .and_then(PKey::from_ec_key)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+            .attach_printable("Failed to deserialize the ephemeral public key")?;
+
+        let mut deriver = Deriver::new(&self.private_key)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)
+            .attach_printable("Failed to create a deriver for the private key")?;
+
+        deriver
+            .set_peer(&public_key)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)
+            .attach_printable("Failed to set the peer key for the secret derivation")?;
+
+        deriver
+            .derive_to_vec()
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)
+            .attach_printable("Final key derivation failed")
+    }
+}
+
+/// Constructs the data signed by Google Pay, where each of the parts is prefixed with its length
+/// as 4 bytes in little-endian order
+fn construct_google_pay_signed_data(
+    parts: &[&[u8]],
+) -> CustomResult<Vec<u8>, errors::GooglePayDecryptionError> {
+    parts.iter().try_fold(Vec::new(), |mut signed_data, part| {
+        let length = u32::try_from(part.len())
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::ParsingFailed)?;
+        signed_data.extend_from_slice(&length.to_le_bytes());
+        signed_data.extend_from_slice(part);
+        Ok(signed_data)
+    })
+}
+
+fn deserialize_google_pay_public_key(
+    key_value: &masking::Secret<String>,
+) -> CustomResult<PKey<openssl::pkey::Public>, errors::GooglePayDecryptionError> {
+    let public_key_bytes = BASE64_ENGINE
+        .decode(key_value.peek().as_bytes())
+        .into_report()
+        .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+    PKey::public_key_from_der(&public_key_bytes)
+        .into_report()
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+        .attach_printable("Failed to deserialize the public key")
+}
+
+fn verify_google_pay_signature(
+    public_key: &PKey<openssl::pkey::Public>,
+    signature: &masking::Secret<String>,
+    signed_data: &[u8],
+) -> CustomResult<bool, errors::GooglePayDecryptionError> {
+    let signature = BASE64_ENGINE
+        .decode(signature.peek().as_bytes())
+        .into_report()
+        .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+    let mut verifier =
+        openssl::sign::Verifier::new(openssl::hash::MessageDigest::sha256(), public_key)
+            .into_report()
+            .change_context(errors::GooglePayDecryptionError::SignatureVerificationFailed)?;
+    verifier
+        .update(signed_data)
+        .into_report()
+        .change_context(errors::GooglePayDecryptionError::SignatureVerificationFailed)?;
+    // A malformed signature fails the verification, rather than the decryption
+    Ok(verifier.verify(&signature).unwrap_or(false))
+}
+
+/// Checks whether an expiration of Google Pay, in milliseconds since the epoch, has elapsed
+fn is_google_pay_expiration_elapsed(
+    expiration: &str,
+) -> CustomResult<bool, errors::GooglePayDecryptionError> {
+    let expiration = expiration
+        .parse::<i128>()
+        .into_report()
+        .change_context(errors::GooglePayDecryptionError::ParsingFailed)
+        .attach_printable("Failed to parse the expiration")?;
+    let now = time::OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000;
+    Ok(expiration <= now)
+}
+
+/// Updates the amounts of a payment once an incremental authorization of it has been accepted by
+/// the connector. The update is rejected when the authorized amount of the payment is no longer
+/// the one the incremental authorization was made on.
+pub async fn update_amount_for_incremental_authorization(
+    db: &dyn StorageInterface,
+    payment_intent: PaymentIntent,
+    payment_attempt: PaymentAttempt,
+    incremental_authorization: &storage::IncrementalAuthorization,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<(PaymentIntent, PaymentAttempt)> {
+    utils::when(
+        payment_intent.amount != incremental_authorization.previously_authorized_amount,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The authorized amount of the payment has changed since the incremental \
+                          authorization was made"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    let total_amount = incremental_authorization.amount;
+    let payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount: total_amount,
+                amount_capturable: total_amount,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate {
+                amount: total_amount,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok((payment_intent, payment_attempt))
+}
@@ -4,6 +4,7 @@ pub mod payment_capture;
 pub mod payment_complete_authorize;
 pub mod payment_confirm;
 pub mod payment_create;
+pub mod payment_incremental_authorization;
 pub mod payment_method_validate;
 pub mod payment_reject;
 pub mod payment_response;
@@ -20,10 +21,11 @@ use router_env::{instrument, tracing};
 pub use self::{
     payment_approve::PaymentApprove, payment_cancel::PaymentCancel,
     payment_capture::PaymentCapture, payment_confirm::PaymentConfirm,
-    payment_create::PaymentCreate, payment_method_validate::PaymentMethodValidate,
-    payment_reject::PaymentReject, payment_response::PaymentResponse,
-    payment_session::PaymentSession, payment_start::PaymentStart, payment_status::PaymentStatus,
-    payment_update::PaymentUpdate,
+    payment_create::PaymentCreate,
+    payment_incremental_authorization::PaymentIncrementalAuthorization,
+    payment_method_validate::PaymentMethodValidate, payment_reject::PaymentReject,
+    payment_response::PaymentResponse, payment_session::PaymentSession,
+    payment_start::PaymentStart, payment_status::PaymentStatus, payment_update::PaymentUpdate,
 };
 use super::{helpers, CustomerDetails, PaymentData};
 use crate::{
@@ -112,6 +114,7 @@ pub trait Domain<F: Clone, R>: Send + Sync {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<(BoxedOperation<'a, F, R>, Option<domain::Customer>), errors::StorageError>;
 
     #[allow(clippy::too_many_arguments)]
@@ -140,6 +143,33 @@ pub trait Domain<F: Clone, R>: Send + Sync {
         payment_intent: &storage::PaymentIntent,
         mechant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse>;
+
+    /// Fails the payment if the payment method used is in the blocklist of the merchant, checked
+    /// before any call is made to a connector for the payment
+    async fn guard_payment_against_blocklist<'a>(
+        &'a self,
+        _state: &AppState,
+        _merchant_account: &domain::MerchantAccount,
+        _payment_data: &mut PaymentData<F>,
+    ) -> RouterResult<()>
+    where
+        F: Send,
+    {
+        Ok(())
+    }
+
+    /// Calculates the surcharge applicable on the payment method used for the payment, which is
+    /// added to the amount sent to the connector
+    async fn populate_surcharge_details<'a>(
+        &'a self,
+        _state: &AppState,
+        _payment_data: &mut PaymentData<F>,
+    ) -> RouterResult<()>
+    where
+        F: Send,
+    {
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -187,6 +217,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRetrieveRequest>,
@@ -202,6 +233,7 @@ where
                 &merchant_key_store.merchant_id,
                 payment_data,
                 merchant_key_store,
+                storage_scheme,
             )
             .await?,
         ))
@@ -245,6 +277,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsCaptureRequest>,
@@ -260,6 +293,7 @@ where
                 &merchant_key_store.merchant_id,
                 payment_data,
                 merchant_key_store,
+                storage_scheme,
             )
             .await?,
         ))
@@ -302,6 +336,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsCancelRequest>,
@@ -317,6 +352,7 @@ where
                 &merchant_key_store.merchant_id,
                 payment_data,
                 merchant_key_store,
+                storage_scheme,
             )
             .await?,
         ))
@@ -360,6 +396,7 @@ where
         _payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         _merchant_key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRejectRequest>,
@@ -394,3 +431,54 @@ where
         helpers::get_connector_default(state, None).await
     }
 }
+
+#[async_trait]
+impl<
+        F: Clone + Send,
+        Op: Send + Sync + Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+    > Domain<F, api::PaymentsIncrementalAuthorizationRequest> for Op
+where
+    for<'a> &'a Op: Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        _db: &dyn StorageInterface,
+        _payment_data: &mut PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+            Option<domain::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((Box::new(self), None))
+    }
+
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        _state: &'a AppState,
+        _payment_data: &mut PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        Option<api::PaymentMethodData>,
+    )> {
+        Ok((Box::new(self), None))
+    }
+
+    async fn get_connector<'a>(
+        &'a self,
+        _merchant_account: &domain::MerchantAccount,
+        state: &AppState,
+        _request: &api::PaymentsIncrementalAuthorizationRequest,
+        _payment_intent: &storage::PaymentIntent,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse> {
+        helpers::get_connector_default(state, None).await
+    }
+}
@@ -143,6 +143,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
@@ -152,6 +153,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -245,6 +247,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 multiple_capture_data: None,
                 redirect_response,
                 frm_message: frm_response.ok(),
+                incremental_authorization_details: None,
+                incremental_authorizations: vec![],
+                payment_link_data: None,
+                authentication: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -266,6 +272,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentApprove {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -280,6 +287,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentApprove {
             request,
             &key_store.merchant_id,
             key_store,
+            storage_scheme,
         )
         .await
     }
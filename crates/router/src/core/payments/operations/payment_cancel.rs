@@ -86,6 +86,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -96,6 +97,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -165,6 +167,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                 multiple_capture_data: None,
                 redirect_response: None,
                 frm_message: None,
+                incremental_authorization_details: None,
+                incremental_authorizations: vec![],
+                payment_link_data: None,
+                authentication: None,
             },
             None,
         ))
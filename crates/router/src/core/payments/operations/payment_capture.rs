@@ -81,7 +81,10 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_status_with_capture_method(payment_intent.status, capture_method)?;
 
-        helpers::validate_amount_to_capture(payment_intent.amount, request.amount_to_capture)?;
+        helpers::validate_amount_to_capture(
+            payment_attempt.get_total_amount(),
+            request.amount_to_capture,
+        )?;
 
         helpers::validate_capture_method(capture_method)?;
 
@@ -160,6 +163,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -170,6 +174,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -225,6 +230,10 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 multiple_capture_data,
                 redirect_response: None,
                 frm_message: None,
+                incremental_authorization_details: None,
+                incremental_authorizations: vec![],
+                payment_link_data: None,
+                authentication: None,
             },
             None,
         ))
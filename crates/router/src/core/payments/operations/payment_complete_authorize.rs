@@ -152,6 +152,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
@@ -161,6 +162,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -240,6 +242,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
                 multiple_capture_data: None,
                 redirect_response,
                 frm_message: None,
+                incremental_authorization_details: None,
+                incremental_authorizations: vec![],
+                payment_link_data: None,
+                authentication: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -261,6 +267,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for CompleteAuthorize {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -275,6 +282,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for CompleteAuthorize {
             request,
             &key_store.merchant_id,
             key_store,
+            storage_scheme,
         )
         .await
     }
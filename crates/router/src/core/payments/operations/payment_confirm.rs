@@ -11,7 +11,9 @@ use router_env::{instrument, tracing};
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
     core::{
+        blocklist::utils as blocklist_utils,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_link,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
     },
     db::StorageInterface,
@@ -21,7 +23,7 @@ use crate::{
         self,
         api::{self, PaymentIdTypeExt},
         domain,
-        storage::{self, enums as storage_enums},
+        storage::{self, enums as storage_enums, payment_attempt::PaymentAttemptExt},
     },
     utils::{self, OptionExt},
 };
@@ -91,6 +93,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             merchant_account.intent_fulfillment_time,
         )?;
 
+        if request.client_secret.is_some() {
+            payment_link::validate_client_secret_against_payment_link(db, &payment_intent).await?;
+        }
+
         let customer_details = helpers::get_customer_details_from_request(request);
 
         // Stage 2
@@ -114,6 +120,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 .as_ref()
                 .or(customer_details.customer_id.as_ref()),
             key_store,
+            merchant_account.storage_scheme,
         );
 
         let billing_address_fut = helpers::get_address_for_payment_request(
@@ -126,6 +133,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 .as_ref()
                 .or(customer_details.customer_id.as_ref()),
             key_store,
+            merchant_account.storage_scheme,
         );
 
         let config_update_fut = request
@@ -350,6 +358,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 multiple_capture_data: None,
                 redirect_response: None,
                 frm_message: None,
+                incremental_authorization_details: None,
+                incremental_authorizations: vec![],
+                payment_link_data: None,
+                authentication: None,
             },
             Some(customer_details),
         ))
@@ -365,6 +377,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -379,6 +392,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
             request,
             &key_store.merchant_id,
             key_store,
+            storage_scheme,
         )
         .await
     }
@@ -426,6 +440,42 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         // creating the payment or if none is passed then use the routing algorithm
         helpers::get_connector_default(state, request.routing.clone()).await
     }
+
+    #[instrument(skip_all)]
+    async fn guard_payment_against_blocklist<'a>(
+        &'a self,
+        state: &AppState,
+        merchant_account: &domain::MerchantAccount,
+        payment_data: &mut PaymentData<F>,
+    ) -> RouterResult<()>
+    where
+        F: Send,
+    {
+        // The card of a saved payment method is fetched from the locker to be checked as well
+        if payment_data.payment_method_data.is_none()
+            && payment_data.token.is_some()
+            && payment_data.mandate_id.is_none()
+        {
+            let (_operation, payment_method_data) = self
+                .make_pm_data(state, payment_data, merchant_account.storage_scheme)
+                .await?;
+            payment_data.payment_method_data = payment_method_data;
+        }
+
+        blocklist_utils::validate_data_for_blocklist(state, merchant_account, payment_data).await
+    }
+
+    #[instrument(skip_all)]
+    async fn populate_surcharge_details<'a>(
+        &'a self,
+        state: &AppState,
+        payment_data: &mut PaymentData<F>,
+    ) -> RouterResult<()>
+    where
+        F: Send,
+    {
+        payments::surcharge::populate_surcharge_details(&*state.store, payment_data).await
+    }
 }
 
 #[async_trait]
@@ -477,6 +527,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .payment_attempt
             .straight_through_algorithm
             .clone();
+        let fallback_connectors = payment_data.payment_attempt.fallback_connectors.clone();
         let payment_token = payment_data.token.clone();
         let payment_method_type = payment_data.payment_attempt.payment_method_type;
         let payment_experience = payment_data.payment_attempt.payment_experience;
@@ -495,6 +546,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let business_sub_label = payment_data.payment_attempt.business_sub_label.clone();
         let authentication_type = payment_data.payment_attempt.authentication_type;
+        let capture_method = payment_data.payment_attempt.capture_method;
 
         let (shipping_address, billing_address) = (
             payment_data.payment_intent.shipping_address_id.clone(),
@@ -515,7 +567,10 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .take();
         let order_details = payment_data.payment_intent.order_details.clone();
         let metadata = payment_data.payment_intent.metadata.clone();
-        let authorized_amount = payment_data.payment_attempt.amount;
+        let fingerprint_id = payment_data.payment_intent.fingerprint_id.clone();
+        let authorized_amount = payment_data.payment_attempt.get_total_amount();
+        let surcharge_amount = payment_data.payment_attempt.surcharge_amount;
+        let tax_amount = payment_data.payment_attempt.tax_amount;
         let payment_attempt_fut = db
             .update_payment_attempt_with_attempt_id(
                 payment_data.payment_attempt,
@@ -536,6 +591,10 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     error_code,
                     error_message,
                     amount_capturable: Some(authorized_amount),
+                    fallback_connectors,
+                    capture_method,
+                    surcharge_amount,
+                    tax_amount,
                 },
                 storage_scheme,
             )
@@ -561,6 +620,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     order_details,
                     metadata,
                     payment_confirm_source: header_payload.payment_confirm_source,
+                    fingerprint_id,
                 },
                 storage_scheme,
             )
@@ -573,6 +633,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     customer.merchant_id.to_owned(),
                     updated_customer,
                     key_store,
+                    storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
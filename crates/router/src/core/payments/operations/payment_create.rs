@@ -14,6 +14,7 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_link,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils::{self as core_utils},
     },
@@ -28,6 +29,7 @@ use crate::{
             self,
             enums::{self, IntentStatus},
         },
+        transformers::ForeignInto,
     },
     utils::{self, OptionExt},
 };
@@ -99,6 +101,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             merchant_id,
             customer_details.customer_id.as_ref(),
             merchant_key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -109,6 +112,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             merchant_id,
             customer_details.customer_id.as_ref(),
             merchant_key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -143,6 +147,20 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_id: payment_id.clone(),
             })?;
 
+        let payment_link_new = request
+            .payment_link
+            .unwrap_or(false)
+            .then(|| {
+                payment_link::make_payment_link(
+                    &state.conf.server.base_url,
+                    merchant_account,
+                    &payment_id,
+                    money,
+                    request.payment_link_expiry,
+                )
+            })
+            .transpose()?;
+
         payment_intent = db
             .insert_payment_intent(
                 Self::make_payment_intent(
@@ -153,6 +171,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     shipping_address.clone().map(|x| x.address_id),
                     billing_address.clone().map(|x| x.address_id),
                     payment_attempt.attempt_id.to_owned(),
+                    payment_link_new
+                        .as_ref()
+                        .map(|payment_link| payment_link.payment_link_id.clone()),
                     state,
                 )
                 .await?,
@@ -162,6 +183,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
                 payment_id: payment_id.clone(),
             })?;
+
+        let payment_link_data = match payment_link_new {
+            Some(payment_link_new) => Some(
+                db.insert_payment_link(payment_link_new)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to insert payment link")?
+                    .foreign_into(),
+            ),
+            None => None,
+        };
         connector_response = db
             .insert_connector_response(
                 Self::make_connector_response(&payment_attempt),
@@ -177,7 +209,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .as_ref()
             .async_and_then(|mandate_id| async {
                 let mandate = db
-                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
+                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::MandateNotFound);
                 Some(mandate.and_then(|mandate_obj| {
@@ -278,6 +310,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 multiple_capture_data: None,
                 redirect_response: None,
                 frm_message: None,
+                incremental_authorization_details: None,
+                incremental_authorizations: vec![],
+                payment_link_data,
+                authentication: None,
             },
             Some(customer_details),
         ))
@@ -293,6 +329,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentCreate {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -307,6 +344,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentCreate {
             request,
             &key_store.merchant_id,
             key_store,
+            storage_scheme,
         )
         .await
     }
@@ -386,6 +424,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .payment_attempt
             .straight_through_algorithm
             .clone();
+        let fallback_connectors = payment_data.payment_attempt.fallback_connectors.clone();
         let authorized_amount = payment_data.payment_attempt.amount;
 
         payment_data.payment_attempt = db
@@ -399,6 +438,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         true => Some(authorized_amount),
                         false => None,
                     },
+                    fallback_connectors,
                 },
                 storage_scheme,
             )
@@ -468,6 +508,10 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        if request.payment_link.unwrap_or(false) {
+            payment_link::validate_payment_link_request(request)?;
+        }
+
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
         let mandate_type =
@@ -590,6 +634,7 @@ impl PaymentCreate {
         shipping_address_id: Option<String>,
         billing_address_id: Option<String>,
         active_attempt_id: String,
+        payment_link_id: Option<String>,
         state: &AppState,
     ) -> RouterResult<storage::PaymentIntentNew> {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
@@ -662,6 +707,9 @@ impl PaymentCreate {
             profile_id: Some(profile_id),
             merchant_decision: None,
             payment_confirm_source: None,
+            request_incremental_authorization: request.request_incremental_authorization,
+            fingerprint_id: None,
+            payment_link_id,
         })
     }
 
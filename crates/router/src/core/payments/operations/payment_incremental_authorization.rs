@@ -0,0 +1,242 @@
+use std::marker::PhantomData;
+
+use api_models::enums::FrmSuggestion;
+use async_trait::async_trait;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{self, operations},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        domain,
+        storage::{self, enums},
+    },
+    utils::{self, OptionExt},
+};
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(ops = "all", flow = "incremental_authorization")]
+pub struct PaymentIncrementalAuthorization;
+
+#[async_trait]
+impl<F: Send + Clone>
+    GetTracker<F, payments::PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        _mandate_type: Option<api::MandateTransactionType>,
+        merchant_account: &domain::MerchantAccount,
+        _key_store: &domain::MerchantKeyStore,
+        _auth_flow: services::AuthFlow,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        payments::PaymentData<F>,
+        Option<payments::CustomerDetails>,
+    )> {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        utils::when(
+            payment_intent.status != enums::IntentStatus::RequiresCapture,
+            || {
+                Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                    field_name: "payment.status".to_string(),
+                    current_flow: "incremental_authorization".to_string(),
+                    current_value: payment_intent.status.to_string(),
+                    states: "requires_capture".to_string()
+                }))
+            },
+        )?;
+
+        utils::when(
+            !payment_intent
+                .request_incremental_authorization
+                .unwrap_or(false),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Incremental authorization was not requested for this payment"
+                        .to_string()
+                }))
+            },
+        )?;
+
+        utils::when(request.amount <= payment_intent.amount, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount should be greater than the amount authorized for the payment"
+                    .to_string()
+            }))
+        })?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                payment_intent.payment_id.as_str(),
+                merchant_id,
+                payment_intent.active_attempt_id.as_str(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let connector_response = db
+            .find_connector_response_by_payment_id_merchant_id_attempt_id(
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let mut incremental_authorizations = db
+            .find_incremental_authorizations_by_merchant_id_payment_id(merchant_id, &payment_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let incremental_authorization = db
+            .insert_incremental_authorization(storage::IncrementalAuthorizationNew {
+                authorization_id: utils::generate_id(consts::ID_LENGTH, "auth"),
+                merchant_id: merchant_id.to_owned(),
+                payment_id: payment_id.clone(),
+                amount: request.amount,
+                previously_authorized_amount: payment_intent.amount,
+                status: enums::AuthorizationStatus::Processing,
+                reason: request.reason.clone(),
+                error_code: None,
+                error_message: None,
+                connector_authorization_id: None,
+            })
+            .await
+            .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+                message: "An incremental authorization is already being processed for the payment"
+                    .to_string(),
+            })?;
+
+        let incremental_authorization_details = payments::IncrementalAuthorizationDetails {
+            additional_amount: request.amount - payment_intent.amount,
+            total_amount: request.amount,
+            reason: request.reason.clone(),
+            authorization_id: incremental_authorization.authorization_id.clone(),
+        };
+        incremental_authorizations.push(incremental_authorization);
+
+        let currency = payment_attempt.currency.get_required_value("currency")?;
+        let amount = payment_attempt.amount.into();
+
+        Ok((
+            Box::new(self),
+            payments::PaymentData {
+                flow: PhantomData,
+                payment_intent,
+                payment_attempt,
+                currency,
+                amount,
+                email: None,
+                mandate_id: None,
+                mandate_connector: None,
+                setup_mandate: None,
+                token: None,
+                address: payments::PaymentAddress::default(),
+                confirm: None,
+                payment_method_data: None,
+                force_sync: None,
+                refunds: vec![],
+                disputes: vec![],
+                attempts: None,
+                connector_response,
+                sessions_token: vec![],
+                card_cvc: None,
+                creds_identifier: None,
+                pm_token: None,
+                connector_customer_id: None,
+                recurring_mandate_payment_data: None,
+                ephemeral_key: None,
+                multiple_capture_data: None,
+                redirect_response: None,
+                frm_message: None,
+                incremental_authorization_details: Some(incremental_authorization_details),
+                incremental_authorizations,
+                payment_link_data: None,
+                authentication: None,
+            },
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl<F: Clone>
+    UpdateTracker<F, payments::PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _db: &dyn StorageInterface,
+        payment_data: payments::PaymentData<F>,
+        _customer: Option<domain::Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+        _updated_customer: Option<storage::CustomerUpdate>,
+        _mechant_key_store: &domain::MerchantKeyStore,
+        _frm_suggestion: Option<FrmSuggestion>,
+        _header_payload: api::HeaderPayload,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest>,
+        payments::PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        // The amounts of the payment are updated only after the connector accepts the
+        // incremental authorization
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone> ValidateRequest<F, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        merchant_account: &'a domain::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest>,
+        operations::ValidateResult<'a>,
+    )> {
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                mandate_type: None,
+                storage_scheme: merchant_account.storage_scheme,
+                requeue: false,
+            },
+        ))
+    }
+}
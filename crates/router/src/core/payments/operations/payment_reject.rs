@@ -84,6 +84,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsRejectRequest> for P
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -94,6 +95,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsRejectRequest> for P
             merchant_id,
             payment_intent.customer_id.as_ref(),
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -151,6 +153,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsRejectRequest> for P
                 multiple_capture_data: None,
                 redirect_response: None,
                 frm_message: frm_response.ok(),
+                incremental_authorization_details: None,
+                incremental_authorizations: vec![],
+                payment_link_data: None,
+                authentication: None,
             },
             None,
         ))
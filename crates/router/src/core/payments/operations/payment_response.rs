@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use futures::FutureExt;
 use router_derive;
 use router_env::{instrument, tracing};
@@ -11,7 +11,7 @@ use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         mandate,
-        payments::{types::MultipleCaptureData, PaymentData},
+        payments::{helpers, types::MultipleCaptureData, PaymentData},
     },
     db::StorageInterface,
     routes::metrics,
@@ -25,13 +25,13 @@ use crate::{
         transformers::ForeignTryFrom,
         CaptureSyncResponse,
     },
-    utils,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
 #[operation(
     ops = "post_tracker",
-    flow = "syncdata,authorizedata,canceldata,capturedata,completeauthorizedata,approvedata,rejectdata,verifydata,sessiondata"
+    flow = "syncdata,authorizedata,canceldata,capturedata,completeauthorizedata,approvedata,rejectdata,verifydata,sessiondata,incrementalauthorizationdata"
 )]
 pub struct PaymentResponse;
 
@@ -280,6 +280,96 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     }
 }
 
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAuthorizationData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        mut payment_data: PaymentData<F>,
+        router_data: types::RouterData<
+            F,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        let incremental_authorization_details = payment_data
+            .incremental_authorization_details
+            .clone()
+            .get_required_value("incremental_authorization_details")?;
+
+        let incremental_authorization_update = match router_data.response {
+            Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                status,
+                connector_authorization_id,
+                error_code,
+                error_message,
+            }) => storage::IncrementalAuthorizationUpdate::StatusUpdate {
+                status,
+                error_code,
+                error_message,
+                connector_authorization_id,
+            },
+            Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+                .into_report()
+                .attach_printable("Unexpected response in incremental authorization flow")?,
+            Err(err) => storage::IncrementalAuthorizationUpdate::StatusUpdate {
+                status: match err.status_code {
+                    500..=511 => enums::AuthorizationStatus::Unresolved,
+                    _ => enums::AuthorizationStatus::Failure,
+                },
+                error_code: Some(err.code),
+                error_message: Some(err.message),
+                connector_authorization_id: None,
+            },
+        };
+
+        let incremental_authorization = payment_data
+            .incremental_authorizations
+            .iter_mut()
+            .find(|incremental_authorization| {
+                incremental_authorization.authorization_id
+                    == incremental_authorization_details.authorization_id
+            })
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Incremental authorization not found in payment data")?;
+
+        *incremental_authorization = db
+            .update_incremental_authorization(
+                incremental_authorization.clone(),
+                incremental_authorization_update,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The authorized amount of the payment changes only when the connector accepts the
+        // incremental authorization
+        if incremental_authorization.status == enums::AuthorizationStatus::Success {
+            let (payment_intent, payment_attempt) =
+                helpers::update_amount_for_incremental_authorization(
+                    db,
+                    payment_data.payment_intent,
+                    payment_data.payment_attempt,
+                    incremental_authorization,
+                    storage_scheme,
+                )
+                .await?;
+            payment_data.payment_intent = payment_intent;
+            payment_data.payment_attempt = payment_attempt;
+            payment_data.amount = incremental_authorization_details.total_amount.into();
+        }
+
+        Ok(payment_data)
+    }
+}
+
 #[instrument(skip_all)]
 async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     db: &dyn StorageInterface,
@@ -495,6 +585,9 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             types::PaymentsResponseData::TokenizationResponse { .. } => (None, None, None),
             types::PaymentsResponseData::ConnectorCustomerResponse { .. } => (None, None, None),
             types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. } => (None, None, None),
+            types::PaymentsResponseData::IncrementalAuthorizationResponse { .. } => {
+                (None, None, None)
+            }
             types::PaymentsResponseData::MultipleCaptureResponse {
                 capture_sync_response_list,
             } => match payment_data.multiple_capture_data {
@@ -523,11 +616,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                 multiple_capture_data.update_capture(updated_capture);
             }
 
-            let authorized_amount = payment_data.payment_attempt.amount;
+            let authorized_amount = payment_data.payment_attempt.get_total_amount();
 
             payment_attempt_update = Some(storage::PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status: multiple_capture_data.get_attempt_status(authorized_amount),
-                amount_capturable: payment_data.payment_attempt.amount
+                amount_capturable: authorized_amount
                     - multiple_capture_data.get_total_blocked_amount(),
             });
             Some(multiple_capture_data)
@@ -608,6 +701,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         router_data.merchant_id,
         payment_data.mandate_id.clone(),
         router_data.response.clone(),
+        storage_scheme,
     );
 
     let (payment_intent, _) = futures::try_join!(payment_intent_fut, mandate_update_fut)?;
@@ -92,6 +92,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             merchant_id,
             payment_intent.customer_id.as_ref(),
             mechant_key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
@@ -101,6 +102,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             merchant_id,
             payment_intent.customer_id.as_ref(),
             mechant_key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
 
@@ -160,6 +162,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
                 multiple_capture_data: None,
                 redirect_response: None,
                 frm_message: None,
+                incremental_authorization_details: None,
+                incremental_authorizations: vec![],
+                payment_link_data: None,
+                authentication: None,
             },
             Some(customer_details),
         ))
@@ -235,6 +241,7 @@ where
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsStartRequest>,
@@ -249,6 +256,7 @@ where
             request,
             &key_store.merchant_id,
             key_store,
+            storage_scheme,
         )
         .await
     }
@@ -19,6 +19,7 @@ use crate::{
     types::{
         api, domain,
         storage::{self, enums},
+        transformers::ForeignInto,
     },
     utils::OptionExt,
 };
@@ -59,6 +60,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentStatus {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -73,6 +75,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentStatus {
             request,
             &key_store.merchant_id,
             key_store,
+            storage_scheme,
         )
         .await
     }
@@ -241,12 +244,14 @@ async fn get_tracker_for_sync<
         db,
         payment_intent.shipping_address_id.clone(),
         mechant_key_store,
+        merchant_account.storage_scheme,
     )
     .await?;
     let billing_address = helpers::get_address_by_id(
         db,
         payment_intent.billing_address_id.clone(),
         mechant_key_store,
+        merchant_account.storage_scheme,
     )
     .await?;
 
@@ -315,6 +320,29 @@ async fn get_tracker_for_sync<
             format!("Error while retrieving frm_response, merchant_id: {}, payment_id: {payment_id_str}", &merchant_account.merchant_id)
         });
 
+    let incremental_authorizations = db
+        .find_incremental_authorizations_by_merchant_id_payment_id(
+            &merchant_account.merchant_id,
+            &payment_id_str,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable_lazy(|| {
+            format!("Error while retrieving incremental authorization list for, merchant_id: {}, payment_id: {payment_id_str}", &merchant_account.merchant_id)
+        })?;
+
+    let payment_link_data = match payment_intent.payment_link_id.as_deref() {
+        Some(payment_link_id) => Some(
+            db.find_payment_link_by_payment_link_id(payment_link_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound {
+                    id: payment_link_id.to_string(),
+                })?
+                .foreign_into(),
+        ),
+        None => None,
+    };
+
     let contains_encoded_data = connector_response.encoded_data.is_some();
 
     let creds_identifier = request
@@ -377,6 +405,10 @@ async fn get_tracker_for_sync<
             multiple_capture_data,
             redirect_response: None,
             frm_message: frm_response.ok(),
+            incremental_authorization_details: None,
+            incremental_authorizations,
+            payment_link_data,
+            authentication: None,
         },
         None,
     ))
@@ -0,0 +1,407 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::PaymentData;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{self, Encode, ValueExt},
+};
+
+/// The prefix of the configs table keys the [`AutoRetryConfig`] of a merchant is stored against
+pub const AUTO_RETRY_CONFIG_KEY_PREFIX: &str = "auto_retry";
+
+/// The upper bound on the attempts of a payment which can be configured
+const MAX_AUTO_RETRY_ATTEMPTS: i16 = 5;
+
+/// The error code recorded on an attempt whose request could not be delivered to the connector
+const CONNECTOR_UNREACHABLE_ERROR_CODE: &str = "CONNECTOR_UNREACHABLE";
+const CONNECTOR_UNREACHABLE_ERROR_MESSAGE: &str = "Request could not be delivered to the connector";
+
+/// Automatic retries of the payments of a merchant, loaded with
+/// [`core_utils::get_merchant_config`], e.g.
+/// `{"max_attempts": 3, "retryable_error_codes": ["issuer_unavailable"]}`
+///
+/// Payments whose request could not be delivered to the connector are always retried. Payments
+/// which timed out are only retried when `TIMEOUT` is listed as retryable, since the connector
+/// may have processed them.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AutoRetryConfig {
+    /// The maximum number of attempts of a payment, including the first one
+    pub max_attempts: i16,
+    /// The error codes returned by the connectors for which the payment is retried
+    pub retryable_error_codes: Vec<String>,
+}
+
+impl AutoRetryConfig {
+    pub fn validate(&self) -> Result<(), errors::ApiErrorResponse> {
+        utils::when(
+            !(1..=MAX_AUTO_RETRY_ATTEMPTS).contains(&self.max_attempts),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "max_attempts should be in between 1 and {MAX_AUTO_RETRY_ATTEMPTS}"
+                    ),
+                })
+            },
+        )?;
+        utils::when(
+            self.retryable_error_codes
+                .iter()
+                .any(|error_code| error_code.trim().is_empty()),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "retryable_error_codes should not contain empty codes".to_string(),
+                })
+            },
+        )
+    }
+
+    fn should_retry(
+        &self,
+        status: storage_enums::AttemptStatus,
+        error_code: Option<&str>,
+        attempt_count: i16,
+    ) -> bool {
+        let is_retryable_error_code = |error_code: &str| {
+            self.retryable_error_codes
+                .iter()
+                .any(|retryable_error_code| retryable_error_code == error_code)
+        };
+        let is_retryable = match (status, error_code) {
+            (storage_enums::AttemptStatus::Failure, Some(CONNECTOR_UNREACHABLE_ERROR_CODE)) => true,
+            (storage_enums::AttemptStatus::Failure, Some(error_code)) => {
+                is_retryable_error_code(error_code)
+            }
+            // A connector timeout leaves the attempt pending
+            (storage_enums::AttemptStatus::Pending, Some(consts::REQUEST_TIMEOUT_ERROR_CODE)) => {
+                is_retryable_error_code(consts::REQUEST_TIMEOUT_ERROR_CODE)
+            }
+            _ => false,
+        };
+
+        is_retryable && attempt_count < self.max_attempts
+    }
+}
+
+/// Whether a connector call failed before its request reached the connector, in which case the
+/// payment can be retried on another connector. Requests whose response was cut off may have been
+/// processed by the connector, so their attempts are left pending to be synced instead.
+pub fn is_retryable_transport_error(error: &error_stack::Report<errors::ApiErrorResponse>) -> bool {
+    error
+        .downcast_ref::<errors::ApiClientError>()
+        .map_or(false, |error| {
+            matches!(error, errors::ApiClientError::ConnectionNotEstablished)
+        })
+}
+
+/// Decides whether a payment whose connector call failed with a transport error is to be retried.
+/// If so, the failed attempt is recorded as such before the payment is retried like any other
+/// failed attempt.
+#[instrument(skip_all)]
+pub async fn get_connector_for_transport_error_retry<F: Clone>(
+    state: &AppState,
+    payment_data: &mut PaymentData<F>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<Option<api::ConnectorData>> {
+    let failed_payment_attempt = payment_data.payment_attempt.clone();
+
+    let retry_connector = retry_on_fallback_connector(
+        state,
+        payment_data,
+        storage_enums::AttemptStatus::Failure,
+        Some(CONNECTOR_UNREACHABLE_ERROR_CODE),
+        storage_scheme,
+    )
+    .await?;
+    if retry_connector.is_some() {
+        state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                failed_payment_attempt,
+                storage::PaymentAttemptUpdate::ErrorUpdate {
+                    connector: None,
+                    status: storage_enums::AttemptStatus::Failure,
+                    error_code: Some(Some(CONNECTOR_UNREACHABLE_ERROR_CODE.to_string())),
+                    error_message: Some(Some(CONNECTOR_UNREACHABLE_ERROR_MESSAGE.to_string())),
+                    error_reason: None,
+                    amount_capturable: Some(0),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    Ok(retry_connector)
+}
+
+async fn get_auto_retry_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: Option<&str>,
+) -> Option<AutoRetryConfig> {
+    core_utils::get_merchant_config(db, AUTO_RETRY_CONFIG_KEY_PREFIX, merchant_id, profile_id).await
+}
+
+/// Decides whether the failed attempt of a payment is to be retried. If so, a new payment attempt
+/// is created for the next fallback connector obtained from routing, and made the active attempt
+/// of the payment.
+///
+/// The connector to retry the payment with is returned.
+#[instrument(skip_all)]
+pub async fn get_connector_for_retry<F: Clone>(
+    state: &AppState,
+    payment_data: &mut PaymentData<F>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<Option<api::ConnectorData>> {
+    let status = payment_data.payment_attempt.status;
+    let error_code = payment_data.payment_attempt.error_code.clone();
+    retry_on_fallback_connector(
+        state,
+        payment_data,
+        status,
+        error_code.as_deref(),
+        storage_scheme,
+    )
+    .await
+}
+
+/// Retries the payment on the next fallback connector, if the active attempt failing with
+/// `status` and `error_code` is to be retried
+async fn retry_on_fallback_connector<F: Clone>(
+    state: &AppState,
+    payment_data: &mut PaymentData<F>,
+    status: storage_enums::AttemptStatus,
+    error_code: Option<&str>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<Option<api::ConnectorData>> {
+    let db = &*state.store;
+
+    let mut fallback_connectors = match payment_data.payment_attempt.fallback_connectors.clone() {
+        Some(fallback_connectors) => fallback_connectors
+            .parse_value::<Vec<api_models::enums::RoutableConnectors>>("RoutableConnectors")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid fallback connectors format in payment attempt")?
+            .into_iter(),
+        None => return Ok(None),
+    };
+
+    let auto_retry_config = match get_auto_retry_config(
+        db,
+        &payment_data.payment_attempt.merchant_id,
+        payment_data.payment_intent.profile_id.as_deref(),
+    )
+    .await
+    {
+        Some(auto_retry_config) => auto_retry_config,
+        None => return Ok(None),
+    };
+
+    if !auto_retry_config.should_retry(
+        status,
+        error_code,
+        payment_data.payment_intent.attempt_count,
+    ) {
+        return Ok(None);
+    }
+
+    let connector_name = match fallback_connectors.next() {
+        Some(connector) => connector.to_string(),
+        None => return Ok(None),
+    };
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Invalid connector name received in fallback connectors")?;
+
+    let remaining_fallback_connectors =
+        Encode::<Vec<api_models::enums::RoutableConnectors>>::encode_to_value(
+            &fallback_connectors.collect::<Vec<_>>(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize fallback connectors to serde value")?;
+
+    logger::info!(
+        failed_attempt_id = %payment_data.payment_attempt.attempt_id,
+        error_code = ?error_code,
+        retry_connector = %connector_name,
+        "Retrying the payment on a fallback connector"
+    );
+
+    let new_attempt_count = payment_data.payment_intent.attempt_count + 1;
+    let new_payment_attempt = db
+        .insert_payment_attempt(
+            make_new_payment_attempt(
+                &payment_data.payment_attempt,
+                new_attempt_count,
+                connector_name,
+                remaining_fallback_connectors,
+            ),
+            storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+            payment_id: payment_data.payment_intent.payment_id.to_owned(),
+        })?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
+                active_attempt_id: new_payment_attempt.attempt_id.to_owned(),
+                attempt_count: new_attempt_count,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_data.payment_attempt = new_payment_attempt;
+
+    Ok(Some(connector_data))
+}
+
+/// The payment method details of the failed attempt are carried over, since the customer is not
+/// involved in the retry
+fn make_new_payment_attempt(
+    old_payment_attempt: &storage::PaymentAttempt,
+    new_attempt_count: i16,
+    connector: String,
+    fallback_connectors: serde_json::Value,
+) -> storage::PaymentAttemptNew {
+    let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
+
+    storage::PaymentAttemptNew {
+        attempt_id: utils::get_payment_attempt_id(
+            &old_payment_attempt.payment_id,
+            new_attempt_count,
+        ),
+        payment_id: old_payment_attempt.payment_id.clone(),
+        merchant_id: old_payment_attempt.merchant_id.clone(),
+        status: storage_enums::AttemptStatus::Started,
+        amount: old_payment_attempt.amount,
+        currency: old_payment_attempt.currency,
+        save_to_locker: old_payment_attempt.save_to_locker,
+        connector: Some(connector),
+        error_message: None,
+        offer_amount: old_payment_attempt.offer_amount,
+        surcharge_amount: old_payment_attempt.surcharge_amount,
+        tax_amount: old_payment_attempt.tax_amount,
+        payment_method_id: old_payment_attempt.payment_method_id.clone(),
+        payment_method: old_payment_attempt.payment_method,
+        capture_method: old_payment_attempt.capture_method,
+        capture_on: old_payment_attempt.capture_on,
+        confirm: old_payment_attempt.confirm,
+        authentication_type: old_payment_attempt.authentication_type,
+        created_at,
+        modified_at,
+        last_synced,
+        cancellation_reason: None,
+        amount_to_capture: old_payment_attempt.amount_to_capture,
+        mandate_id: old_payment_attempt.mandate_id.clone(),
+        browser_info: old_payment_attempt.browser_info.clone(),
+        error_code: None,
+        payment_token: old_payment_attempt.payment_token.clone(),
+        connector_metadata: None,
+        payment_experience: old_payment_attempt.payment_experience,
+        payment_method_type: old_payment_attempt.payment_method_type,
+        payment_method_data: old_payment_attempt.payment_method_data.clone(),
+        business_sub_label: old_payment_attempt.business_sub_label.clone(),
+        straight_through_algorithm: old_payment_attempt.straight_through_algorithm.clone(),
+        mandate_details: old_payment_attempt.mandate_details.clone(),
+        preprocessing_step_id: None,
+        error_reason: None,
+        multiple_capture_count: None,
+        connector_response_reference_id: None,
+        amount_capturable: old_payment_attempt.amount,
+        fallback_connectors: Some(fallback_connectors),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_auto_retry_decision() {
+        let config = AutoRetryConfig {
+            max_attempts: 2,
+            retryable_error_codes: vec!["issuer_unavailable".to_string()],
+        };
+        let failure = storage_enums::AttemptStatus::Failure;
+
+        assert!(config.should_retry(failure, Some("issuer_unavailable"), 1));
+        assert!(!config.should_retry(failure, Some("issuer_unavailable"), 2));
+        assert!(!config.should_retry(failure, Some("insufficient_funds"), 1));
+        assert!(!config.should_retry(failure, None, 1));
+        assert!(!config.should_retry(
+            storage_enums::AttemptStatus::Charged,
+            Some("issuer_unavailable"),
+            1
+        ));
+
+        // Transport errors are retried regardless of the configured error codes
+        assert!(config.should_retry(failure, Some(CONNECTOR_UNREACHABLE_ERROR_CODE), 1));
+        assert!(!config.should_retry(failure, Some(CONNECTOR_UNREACHABLE_ERROR_CODE), 2));
+
+        // Timeouts are only retried when configured
+        let pending = storage_enums::AttemptStatus::Pending;
+        assert!(!config.should_retry(pending, Some(consts::REQUEST_TIMEOUT_ERROR_CODE), 1));
+        let config = AutoRetryConfig {
+            max_attempts: 2,
+            retryable_error_codes: vec![consts::REQUEST_TIMEOUT_ERROR_CODE.to_string()],
+        };
+        assert!(config.should_retry(pending, Some(consts::REQUEST_TIMEOUT_ERROR_CODE), 1));
+    }
+
+    #[test]
+    fn test_retryable_transport_error() {
+        use error_stack::report;
+
+        let transport_error = |error: errors::ApiClientError| {
+            Err::<(), _>(report!(error))
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .unwrap_err()
+        };
+
+        assert!(is_retryable_transport_error(&transport_error(
+            errors::ApiClientError::ConnectionNotEstablished
+        )));
+        // The connector may have received these requests
+        assert!(!is_retryable_transport_error(&transport_error(
+            errors::ApiClientError::ConnectionClosed
+        )));
+        assert!(!is_retryable_transport_error(&transport_error(
+            errors::ApiClientError::RequestNotSent("error".to_string())
+        )));
+    }
+
+    #[test]
+    fn test_auto_retry_config_validation() {
+        let config = |max_attempts, retryable_error_code: &str| AutoRetryConfig {
+            max_attempts,
+            retryable_error_codes: vec![retryable_error_code.to_string()],
+        };
+
+        assert!(config(3, "issuer_unavailable").validate().is_ok());
+        assert!(config(0, "issuer_unavailable").validate().is_err());
+        assert!(config(MAX_AUTO_RETRY_ATTEMPTS + 1, "issuer_unavailable")
+            .validate()
+            .is_err());
+        assert!(config(3, " ").validate().is_err());
+    }
+}
@@ -0,0 +1,385 @@
+use api_models::{enums as api_enums, routing as api_routing};
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+
+use super::PaymentData;
+use crate::{
+    core::errors::{self, RouterResult},
+    types::{api, storage::enums as storage_enums},
+};
+
+/// The payment attributes against which routing rules are evaluated
+#[derive(Debug, Clone, Default)]
+pub struct RoutingInput {
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub billing_country: Option<api_enums::CountryAlpha2>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl<F: Clone> From<&PaymentData<F>> for RoutingInput {
+    fn from(payment_data: &PaymentData<F>) -> Self {
+        let card_network = match payment_data.payment_method_data {
+            Some(api::PaymentMethodData::Card(ref card)) => card.card_network.clone(),
+            _ => None,
+        };
+
+        Self {
+            amount: payment_data.payment_attempt.amount,
+            currency: Some(payment_data.currency),
+            payment_method: payment_data.payment_attempt.payment_method,
+            payment_method_type: payment_data.payment_attempt.payment_method_type,
+            card_network,
+            billing_country: payment_data
+                .address
+                .billing
+                .as_ref()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country),
+            metadata: payment_data
+                .payment_intent
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.peek().clone()),
+        }
+    }
+}
+
+/// Evaluates the routing algorithm and returns the eligible connectors, the first one being the
+/// connector to be used and the rest being the fallback order
+pub fn perform_routing(
+    algorithm: &api::RoutingAlgorithm,
+    input: &RoutingInput,
+) -> RouterResult<Vec<api_enums::RoutableConnectors>> {
+    perform_routing_with_rng(algorithm, input, &mut rand::thread_rng())
+}
+
+fn perform_routing_with_rng<R: Rng>(
+    algorithm: &api::RoutingAlgorithm,
+    input: &RoutingInput,
+    rng: &mut R,
+) -> RouterResult<Vec<api_enums::RoutableConnectors>> {
+    match algorithm {
+        api::RoutingAlgorithm::Single(connector) => Ok(vec![*connector]),
+        api::RoutingAlgorithm::Priority(connectors) => Ok(connectors.clone()),
+        api::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, rng),
+        api::RoutingAlgorithm::Advanced(routing_rules) => {
+            let selection = routing_rules
+                .rules
+                .iter()
+                .find(|rule| {
+                    rule.conditions
+                        .iter()
+                        .all(|condition| evaluate_condition(condition, input))
+                })
+                .map(|rule| &rule.connector_selection)
+                .unwrap_or(&routing_rules.default_selection);
+
+            match selection {
+                api_routing::ConnectorSelection::Priority(connectors) => Ok(connectors.clone()),
+                api_routing::ConnectorSelection::VolumeSplit(splits) => {
+                    perform_volume_split(splits, rng)
+                }
+            }
+        }
+    }
+}
+
+fn perform_volume_split<R: Rng>(
+    splits: &[api_routing::ConnectorVolumeSplit],
+    rng: &mut R,
+) -> RouterResult<Vec<api_enums::RoutableConnectors>> {
+    let weighted_index = WeightedIndex::new(splits.iter().map(|split| split.split))
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid volume split configuration")?;
+
+    let selected_index = weighted_index.sample(rng);
+    let mut connectors = Vec::with_capacity(splits.len());
+
+    if let Some(selected) = splits.get(selected_index) {
+        connectors.push(selected.connector);
+    }
+
+    for split in splits {
+        if !connectors.contains(&split.connector) {
+            connectors.push(split.connector);
+        }
+    }
+
+    Ok(connectors)
+}
+
+fn evaluate_condition(condition: &api_routing::RoutingCondition, input: &RoutingInput) -> bool {
+    match condition {
+        api_routing::RoutingCondition::Amount { comparison, value } => match comparison {
+            api_routing::NumberComparison::Equal => input.amount == *value,
+            api_routing::NumberComparison::NotEqual => input.amount != *value,
+            api_routing::NumberComparison::LessThan => input.amount < *value,
+            api_routing::NumberComparison::LessThanEqual => input.amount <= *value,
+            api_routing::NumberComparison::GreaterThan => input.amount > *value,
+            api_routing::NumberComparison::GreaterThanEqual => input.amount >= *value,
+        },
+        api_routing::RoutingCondition::Currency(condition) => {
+            evaluate_membership(condition, input.currency.as_ref())
+        }
+        api_routing::RoutingCondition::CardNetwork(condition) => {
+            evaluate_membership(condition, input.card_network.as_ref())
+        }
+        api_routing::RoutingCondition::PaymentMethod(condition) => {
+            evaluate_membership(condition, input.payment_method.as_ref())
+        }
+        api_routing::RoutingCondition::PaymentMethodType(condition) => {
+            evaluate_membership(condition, input.payment_method_type.as_ref())
+        }
+        api_routing::RoutingCondition::BillingCountry(condition) => {
+            evaluate_membership(condition, input.billing_country.as_ref())
+        }
+        api_routing::RoutingCondition::Metadata { key, value } => input
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(key))
+            .map(|metadata_value| match metadata_value {
+                serde_json::Value::String(metadata_value) => metadata_value == value,
+                other => other.to_string() == *value,
+            })
+            .unwrap_or(false),
+    }
+}
+
+/// A missing attribute never satisfies an `in` condition and always satisfies a `not_in`
+/// condition
+fn evaluate_membership<T: PartialEq>(
+    condition: &api_routing::MembershipCondition<T>,
+    value: Option<&T>,
+) -> bool {
+    let is_member = value
+        .map(|value| condition.values.contains(value))
+        .unwrap_or(false);
+
+    match condition.comparison {
+        api_routing::MembershipComparison::In => is_member,
+        api_routing::MembershipComparison::NotIn => !is_member,
+    }
+}
+
+pub fn validate_routing_algorithm(
+    algorithm: &api::RoutingAlgorithm,
+) -> Result<(), errors::ApiErrorResponse> {
+    match algorithm {
+        api::RoutingAlgorithm::Single(_) => Ok(()),
+        api::RoutingAlgorithm::Priority(connectors) => validate_priority(connectors),
+        api::RoutingAlgorithm::VolumeSplit(splits) => validate_volume_split(splits),
+        api::RoutingAlgorithm::Advanced(routing_rules) => {
+            validate_connector_selection(&routing_rules.default_selection)?;
+            routing_rules.rules.iter().try_for_each(|rule| {
+                rule.conditions.iter().try_for_each(validate_condition)?;
+                validate_connector_selection(&rule.connector_selection)
+            })
+        }
+    }
+}
+
+fn validate_connector_selection(
+    selection: &api_routing::ConnectorSelection,
+) -> Result<(), errors::ApiErrorResponse> {
+    match selection {
+        api_routing::ConnectorSelection::Priority(connectors) => validate_priority(connectors),
+        api_routing::ConnectorSelection::VolumeSplit(splits) => validate_volume_split(splits),
+    }
+}
+
+fn validate_priority(
+    connectors: &[api_enums::RoutableConnectors],
+) -> Result<(), errors::ApiErrorResponse> {
+    if connectors.is_empty() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "priority routing requires at least one connector".to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_volume_split(
+    splits: &[api_routing::ConnectorVolumeSplit],
+) -> Result<(), errors::ApiErrorResponse> {
+    let total = splits.iter().try_fold(0u16, |total, split| {
+        total.checked_add(u16::from(split.split))
+    });
+    match total {
+        Some(100) => Ok(()),
+        Some(total) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("volume split percentages must add up to 100, got {total}"),
+        }),
+        None => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "volume split percentages must add up to 100".to_string(),
+        }),
+    }
+}
+
+fn validate_condition(
+    condition: &api_routing::RoutingCondition,
+) -> Result<(), errors::ApiErrorResponse> {
+    let is_empty = match condition {
+        api_routing::RoutingCondition::Amount { .. }
+        | api_routing::RoutingCondition::Metadata { .. } => false,
+        api_routing::RoutingCondition::Currency(condition) => condition.values.is_empty(),
+        api_routing::RoutingCondition::CardNetwork(condition) => condition.values.is_empty(),
+        api_routing::RoutingCondition::PaymentMethod(condition) => condition.values.is_empty(),
+        api_routing::RoutingCondition::PaymentMethodType(condition) => condition.values.is_empty(),
+        api_routing::RoutingCondition::BillingCountry(condition) => condition.values.is_empty(),
+    };
+
+    if is_empty {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "routing rule conditions must specify at least one value".to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    fn advanced_algorithm() -> api::RoutingAlgorithm {
+        serde_json::from_value(serde_json::json!({
+            "type": "advanced",
+            "data": {
+                "rules": [
+                    {
+                        "name": "high_value_usd",
+                        "conditions": [
+                            { "field": "amount", "comparison": "greater_than_equal", "value": 10000 },
+                            { "field": "currency", "comparison": "in", "values": ["USD"] }
+                        ],
+                        "connector_selection": { "type": "priority", "data": ["adyen", "stripe"] }
+                    },
+                    {
+                        "name": "visa_cards",
+                        "conditions": [
+                            { "field": "card_network", "comparison": "in", "values": ["Visa"] }
+                        ],
+                        "connector_selection": { "type": "priority", "data": ["checkout"] }
+                    }
+                ],
+                "default_selection": { "type": "priority", "data": ["stripe"] }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_advanced_routing_picks_first_matching_rule() {
+        let algorithm = advanced_algorithm();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let input = RoutingInput {
+            amount: 15000,
+            currency: Some(storage_enums::Currency::USD),
+            card_network: Some(api_enums::CardNetwork::Visa),
+            ..Default::default()
+        };
+        assert_eq!(
+            perform_routing_with_rng(&algorithm, &input, &mut rng).unwrap(),
+            vec![
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Stripe
+            ]
+        );
+
+        let input = RoutingInput {
+            amount: 500,
+            currency: Some(storage_enums::Currency::USD),
+            card_network: Some(api_enums::CardNetwork::Visa),
+            ..Default::default()
+        };
+        assert_eq!(
+            perform_routing_with_rng(&algorithm, &input, &mut rng).unwrap(),
+            vec![api_enums::RoutableConnectors::Checkout]
+        );
+
+        let input = RoutingInput {
+            amount: 500,
+            currency: Some(storage_enums::Currency::EUR),
+            ..Default::default()
+        };
+        assert_eq!(
+            perform_routing_with_rng(&algorithm, &input, &mut rng).unwrap(),
+            vec![api_enums::RoutableConnectors::Stripe]
+        );
+    }
+
+    #[test]
+    fn test_volume_split_keeps_remaining_connectors_as_fallback() {
+        let algorithm = api::RoutingAlgorithm::VolumeSplit(vec![
+            api_routing::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 0,
+            },
+            api_routing::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 100,
+            },
+        ]);
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(
+            perform_routing_with_rng(&algorithm, &RoutingInput::default(), &mut rng).unwrap(),
+            vec![
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Stripe
+            ]
+        );
+    }
+
+    #[test]
+    fn test_metadata_condition() {
+        let condition = api_routing::RoutingCondition::Metadata {
+            key: "order_type".to_string(),
+            value: "subscription".to_string(),
+        };
+
+        let input = RoutingInput {
+            metadata: Some(serde_json::json!({ "order_type": "subscription" })),
+            ..Default::default()
+        };
+        assert!(evaluate_condition(&condition, &input));
+        assert!(!evaluate_condition(&condition, &RoutingInput::default()));
+    }
+
+    #[test]
+    fn test_validate_routing_algorithm() {
+        let invalid_split = api::RoutingAlgorithm::VolumeSplit(vec![
+            api_routing::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 30,
+            },
+            api_routing::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 30,
+            },
+        ]);
+        assert!(validate_routing_algorithm(&invalid_split).is_err());
+        let overflowing_split = api::RoutingAlgorithm::VolumeSplit(vec![
+            api_routing::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: u8::MAX,
+            };
+            300
+        ]);
+        assert!(validate_routing_algorithm(&overflowing_split).is_err());
+        assert!(validate_routing_algorithm(&api::RoutingAlgorithm::Priority(vec![])).is_err());
+        assert!(validate_routing_algorithm(&advanced_algorithm()).is_ok());
+    }
+}
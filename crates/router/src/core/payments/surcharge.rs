@@ -0,0 +1,212 @@
+use api_models::{
+    enums as api_enums,
+    payment_methods::{Surcharge, SurchargeConfig, SurchargeDetailsResponse, SurchargeRule},
+};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::PaymentData;
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    types::{api, storage::enums as storage_enums},
+};
+
+/// The number of basis points in a whole
+const BASIS_POINTS_PER_UNIT: i64 = 10_000;
+
+/// The payment method for which the surcharge is calculated
+#[derive(Debug, Clone, Copy)]
+pub struct SurchargeCriteria<'a> {
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub card_network: Option<&'a api_enums::CardNetwork>,
+    pub country: Option<api_enums::CountryAlpha2>,
+}
+
+fn is_rule_applicable(rule: &SurchargeRule, criteria: &SurchargeCriteria<'_>) -> bool {
+    fn matches<T: PartialEq>(rule_value: Option<&T>, value: Option<&T>) -> bool {
+        rule_value.map_or(true, |rule_value| Some(rule_value) == value)
+    }
+
+    matches(
+        rule.payment_method_type.as_ref(),
+        criteria.payment_method_type.as_ref(),
+    ) && matches(rule.card_network.as_ref(), criteria.card_network)
+        && matches(rule.country.as_ref(), criteria.country.as_ref())
+}
+
+fn calculate_surcharge(rule: &SurchargeRule, amount: i64) -> SurchargeDetailsResponse {
+    let surcharge_amount = match rule.surcharge {
+        Surcharge::Fixed(surcharge_amount) => surcharge_amount,
+        Surcharge::Rate(basis_points) => get_basis_points_of_amount(amount, basis_points),
+    };
+    let tax_on_surcharge_amount = rule.tax_on_surcharge.map_or(0, |basis_points| {
+        get_basis_points_of_amount(surcharge_amount, basis_points)
+    });
+
+    SurchargeDetailsResponse {
+        surcharge: rule.surcharge,
+        tax_on_surcharge: rule.tax_on_surcharge,
+        surcharge_amount,
+        tax_on_surcharge_amount,
+        final_amount: amount
+            .saturating_add(surcharge_amount)
+            .saturating_add(tax_on_surcharge_amount),
+    }
+}
+
+/// Calculates the surcharge with the first rule matching the payment method
+pub fn get_surcharge_details(
+    surcharge_config: &SurchargeConfig,
+    amount: i64,
+    criteria: &SurchargeCriteria<'_>,
+) -> Option<SurchargeDetailsResponse> {
+    surcharge_config
+        .rules
+        .iter()
+        .find(|rule| is_rule_applicable(rule, criteria))
+        .map(|rule| calculate_surcharge(rule, amount))
+}
+
+/// Rounded half away from zero to the lowest denomination of the currency
+fn get_basis_points_of_amount(amount: i64, basis_points: u32) -> i64 {
+    let product = amount.saturating_mul(i64::from(basis_points));
+    let half_unit = BASIS_POINTS_PER_UNIT / 2;
+    if product >= 0 {
+        product.saturating_add(half_unit) / BASIS_POINTS_PER_UNIT
+    } else {
+        product.saturating_sub(half_unit) / BASIS_POINTS_PER_UNIT
+    }
+}
+
+/// The surcharge rules configured on the business profile the payment is made under
+pub async fn get_surcharge_config(
+    db: &dyn StorageInterface,
+    profile_id: Option<&str>,
+) -> RouterResult<Option<SurchargeConfig>> {
+    match profile_id {
+        Some(profile_id) => db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?
+            .surcharge_config
+            .map(|surcharge_config| {
+                surcharge_config
+                    .parse_value("SurchargeConfig")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse the surcharge config of the profile")
+            })
+            .transpose(),
+        None => Ok(None),
+    }
+}
+
+/// Calculates the surcharge and the tax on it for the payment method used to confirm the payment,
+/// and sets them on the payment attempt. They are added to the amount sent to the connector.
+#[instrument(skip_all)]
+pub async fn populate_surcharge_details<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let surcharge_config =
+        get_surcharge_config(db, payment_data.payment_intent.profile_id.as_deref()).await?;
+
+    let card_network = match &payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => card.card_network.clone(),
+        _ => None,
+    };
+    let country = payment_data
+        .address
+        .billing
+        .as_ref()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country);
+    let criteria = SurchargeCriteria {
+        payment_method_type: payment_data.payment_attempt.payment_method_type,
+        card_network: card_network.as_ref(),
+        country,
+    };
+
+    let surcharge_details = surcharge_config.and_then(|surcharge_config| {
+        get_surcharge_details(
+            &surcharge_config,
+            payment_data.payment_attempt.amount,
+            &criteria,
+        )
+    });
+
+    payment_data.payment_attempt.surcharge_amount = surcharge_details
+        .as_ref()
+        .map(|surcharge_details| surcharge_details.surcharge_amount);
+    payment_data.payment_attempt.tax_amount = surcharge_details
+        .as_ref()
+        .map(|surcharge_details| surcharge_details.tax_on_surcharge_amount);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_rule(
+        surcharge: Surcharge,
+        card_network: Option<api_enums::CardNetwork>,
+    ) -> SurchargeRule {
+        SurchargeRule {
+            payment_method_type: None,
+            card_network,
+            country: None,
+            surcharge,
+            tax_on_surcharge: Some(1000),
+        }
+    }
+
+    #[test]
+    fn test_surcharge_calculation() {
+        let surcharge_details = calculate_surcharge(&get_rule(Surcharge::Rate(250), None), 1000);
+        assert_eq!(surcharge_details.surcharge_amount, 25);
+        assert_eq!(surcharge_details.tax_on_surcharge_amount, 3);
+        assert_eq!(surcharge_details.final_amount, 1028);
+
+        let surcharge_details = calculate_surcharge(&get_rule(Surcharge::Fixed(100), None), 1000);
+        assert_eq!(surcharge_details.surcharge_amount, 100);
+        assert_eq!(surcharge_details.final_amount, 1110);
+    }
+
+    #[test]
+    fn test_basis_points_rounding() {
+        assert_eq!(get_basis_points_of_amount(999, 150), 15);
+        assert_eq!(get_basis_points_of_amount(1, 4999), 0);
+        assert_eq!(get_basis_points_of_amount(1, 5000), 1);
+        assert_eq!(
+            get_basis_points_of_amount(i64::MAX, 10_000),
+            i64::MAX / 10_000
+        );
+    }
+
+    #[test]
+    fn test_surcharge_rule_matching() {
+        let config = SurchargeConfig {
+            rules: vec![
+                get_rule(Surcharge::Fixed(50), Some(api_enums::CardNetwork::Visa)),
+                get_rule(Surcharge::Fixed(10), None),
+            ],
+        };
+        let mastercard = api_enums::CardNetwork::Mastercard;
+        let criteria = SurchargeCriteria {
+            payment_method_type: Some(storage_enums::PaymentMethodType::Credit),
+            card_network: Some(&mastercard),
+            country: None,
+        };
+
+        assert_eq!(
+            get_surcharge_details(&config, 1000, &criteria)
+                .map(|surcharge_details| surcharge_details.surcharge_amount),
+            Some(10)
+        );
+    }
+}
@@ -55,6 +55,11 @@ where
                             message: "Apple Pay Decrypt token is not supported".to_string(),
                         })?
                     }
+                    types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                        Err(errors::ApiErrorResponse::NotSupported {
+                            message: "Google Pay Decrypt token is not supported".to_string(),
+                        })?
+                    }
                 };
                 Some((connector, token))
             } else {
@@ -244,7 +249,8 @@ pub async fn add_payment_method_token<F: Clone, T: types::Tokenizable + Clone>(
 ) -> RouterResult<Option<String>> {
     match tokenization_action {
         payments::TokenizationAction::TokenizeInConnector
-        | payments::TokenizationAction::TokenizeInConnectorAndApplepayPreDecrypt => {
+        | payments::TokenizationAction::TokenizeInConnectorAndApplepayPreDecrypt
+        | payments::TokenizationAction::TokenizeInConnectorAndGooglePayPreDecrypt => {
             let connector_integration: services::BoxedConnectorIntegration<
                 '_,
                 api::PaymentMethodToken,
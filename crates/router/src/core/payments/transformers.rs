@@ -20,7 +20,7 @@ use crate::{
     services::{self, RedirectForm},
     types::{
         self, api, domain,
-        storage::{self, enums},
+        storage::{self, enums, payment_attempt::PaymentAttemptExt},
         transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
         MultipleCaptureRequestData,
     },
@@ -219,6 +219,7 @@ where
             payment_data.disputes,
             payment_data.attempts,
             captures,
+            payment_data.incremental_authorizations,
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -230,6 +231,7 @@ where
             payment_data.sessions_token,
             payment_data.frm_message,
             payment_data.setup_mandate,
+            payment_data.payment_link_data,
             connector_request_reference_id_config,
             connector_http_status_code,
         )
@@ -335,6 +337,7 @@ pub fn payments_to_payments_response<R, Op>(
     disputes: Vec<storage::Dispute>,
     option_attempts: Option<Vec<storage::PaymentAttempt>>,
     captures: Option<Vec<storage::Capture>>,
+    incremental_authorizations: Vec<storage::IncrementalAuthorization>,
     payment_method_data: Option<api::PaymentMethodData>,
     customer: Option<domain::Customer>,
     auth_flow: services::AuthFlow,
@@ -346,6 +349,7 @@ pub fn payments_to_payments_response<R, Op>(
     session_tokens: Vec<api::SessionToken>,
     fraud_check: Option<payments::FraudCheck>,
     mandate_data: Option<MandateData>,
+    payment_link_data: Option<api_models::payments::PaymentLinkResponse>,
     connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
     connector_http_status_code: Option<u16>,
 ) -> RouterResponse<api::PaymentsResponse>
@@ -392,6 +396,17 @@ where
             .collect()
     });
 
+    let incremental_authorizations_response = if incremental_authorizations.is_empty() {
+        None
+    } else {
+        Some(
+            incremental_authorizations
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
+
     let merchant_id = payment_attempt.merchant_id.to_owned();
     let payment_method_type = payment_attempt
         .payment_method_type
@@ -651,6 +666,12 @@ where
                         .set_reference_id(payment_attempt.connector_response_reference_id)
                         .set_profile_id(payment_intent.profile_id)
                         .set_attempt_count(payment_intent.attempt_count)
+                        .set_incremental_authorization_allowed(
+                            payment_intent.request_incremental_authorization,
+                        )
+                        .set_incremental_authorizations(incremental_authorizations_response)
+                        .set_fingerprint(payment_intent.fingerprint_id)
+                        .set_payment_link(payment_link_data)
                         .to_owned(),
                     headers,
                 ))
@@ -709,6 +730,10 @@ where
                 allowed_payment_method_types: payment_intent.allowed_payment_method_types,
                 reference_id: payment_attempt.connector_response_reference_id,
                 attempt_count: payment_intent.attempt_count,
+                incremental_authorization_allowed: payment_intent.request_incremental_authorization,
+                incremental_authorizations: incremental_authorizations_response,
+                fingerprint: payment_intent.fingerprint_id,
+                payment_link: payment_link_data,
                 ..Default::default()
             },
             headers,
@@ -895,6 +920,12 @@ where
     payment_data: PaymentData<F>,
     state: &'a AppState,
 }
+/// The amount sent to the connector, which includes the surcharge applied on the payment and the
+/// tax on it
+fn get_amount_with_surcharge<F: Clone>(payment_data: &PaymentData<F>) -> i64 {
+    i64::from(payment_data.amount) + payment_data.payment_attempt.get_total_surcharge_amount()
+}
+
 impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthorizeData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
@@ -979,7 +1010,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
             statement_descriptor: payment_data.payment_intent.statement_descriptor_name,
             capture_method: payment_data.payment_attempt.capture_method,
-            amount: payment_data.amount.into(),
+            amount: get_amount_with_surcharge(&payment_data),
             currency: payment_data.currency,
             browser_info,
             email: payment_data.email,
@@ -994,6 +1025,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             webhook_url,
             complete_authorize_url,
             customer_id: None,
+            request_incremental_authorization: payment_data
+                .payment_intent
+                .request_incremental_authorization
+                .unwrap_or(false),
+            authentication_data: payment_data.authentication,
         })
     }
 }
@@ -1047,7 +1083,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
         let amount_to_capture: i64 = payment_data
             .payment_attempt
             .amount_to_capture
-            .map_or(payment_data.amount.into(), |capture_amount| capture_amount);
+            .unwrap_or_else(|| get_amount_with_surcharge(&payment_data));
         Ok(Self {
             amount_to_capture,
             currency: payment_data.currency,
@@ -1055,7 +1091,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
                 .connector
                 .connector_transaction_id(payment_data.payment_attempt.clone())?
                 .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
-            payment_amount: payment_data.amount.into(),
+            payment_amount: get_amount_with_surcharge(&payment_data),
             connector_meta: payment_data.payment_attempt.connector_metadata,
             multiple_capture_data: match payment_data.multiple_capture_data {
                 Some(multiple_capture_data) => Some(MultipleCaptureRequestData {
@@ -1082,7 +1118,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             api::GetToken::Connector,
         )?;
         Ok(Self {
-            amount: Some(payment_data.amount.into()),
+            amount: Some(get_amount_with_surcharge(&payment_data)),
             currency: Some(payment_data.currency),
             connector_transaction_id: connector
                 .connector
@@ -1094,6 +1130,35 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
     }
 }
 
+impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>>
+    for types::PaymentsIncrementalAuthorizationData
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
+        let payment_data = additional_data.payment_data;
+        let connector = api::ConnectorData::get_connector_by_name(
+            &additional_data.state.conf.connectors,
+            &additional_data.connector_name,
+            api::GetToken::Connector,
+        )?;
+        let incremental_authorization_details = payment_data
+            .incremental_authorization_details
+            .get_required_value("incremental_authorization_details")?;
+        Ok(Self {
+            total_amount: incremental_authorization_details.total_amount,
+            additional_amount: incremental_authorization_details.additional_amount,
+            currency: payment_data.currency,
+            reason: incremental_authorization_details.reason,
+            connector_transaction_id: connector
+                .connector
+                .connector_transaction_id(payment_data.payment_attempt.clone())?
+                .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
+            connector_meta: payment_data.payment_attempt.connector_metadata,
+        })
+    }
+}
+
 impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsApproveData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
@@ -1267,7 +1332,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::CompleteAuthoriz
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
             capture_method: payment_data.payment_attempt.capture_method,
-            amount: payment_data.amount.into(),
+            amount: get_amount_with_surcharge(&payment_data),
             currency: payment_data.currency,
             browser_info,
             email: payment_data.email,
@@ -1328,7 +1393,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPreProce
             payment_method_data,
             email: payment_data.email,
             currency: Some(payment_data.currency),
-            amount: Some(payment_data.amount.into()),
+            amount: Some(get_amount_with_surcharge(&payment_data)),
             payment_method_type: payment_data.payment_attempt.payment_method_type,
             setup_mandate_details: payment_data.setup_mandate,
             capture_method: payment_data.payment_attempt.capture_method,
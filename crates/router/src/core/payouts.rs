@@ -21,6 +21,7 @@ use crate::{
         self,
         api::{self, payouts},
         domain, storage,
+        transformers::ForeignFrom,
     },
     utils::{self, OptionExt},
 };
@@ -435,6 +436,54 @@ pub async fn payouts_fulfill_core(
     .await
 }
 
+#[cfg(all(feature = "payouts", feature = "olap"))]
+#[instrument(skip_all)]
+pub async fn payouts_list_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: payouts::PayoutListFilterConstraints,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    validator::validate_payout_list_request(constraints.limit)?;
+    let db = state.store.as_ref();
+
+    let data: Vec<payouts::PayoutCreateResponse> = db
+        .filter_payouts_and_attempts(&merchant_account.merchant_id, &constraints)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?
+        .into_iter()
+        .map(ForeignFrom::foreign_from)
+        .collect();
+
+    let total_count = db
+        .get_total_count_of_filtered_payouts(&merchant_account.merchant_id, &constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            count: data.len(),
+            total_count,
+            data,
+        },
+    ))
+}
+
+#[cfg(all(feature = "payouts", feature = "olap"))]
+#[instrument(skip_all)]
+pub async fn payouts_filter_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    time_range: api_models::payments::TimeRange,
+) -> RouterResponse<payouts::PayoutListFilters> {
+    let db = state.store.as_ref();
+    let filters = db
+        .get_filters_for_payouts(&merchant_account.merchant_id, &time_range)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(filters))
+}
+
 // ********************************************** HELPERS **********************************************
 #[cfg(feature = "payouts")]
 pub async fn call_connector_payout(
@@ -626,6 +675,7 @@ pub async fn create_recipient(
                             merchant_id,
                             updated_customer,
                             key_store,
+                            merchant_account.storage_scheme,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1165,6 +1215,7 @@ pub async fn payout_create_db_entries(
         merchant_id,
         Some(&customer_id.to_owned()),
         key_store,
+        merchant_account.storage_scheme,
     )
     .await?;
     let address_id = billing_address
@@ -1300,6 +1351,7 @@ pub async fn make_payout_data(
         merchant_id,
         Some(&payouts.customer_id.to_owned()),
         key_store,
+        merchant_account.storage_scheme,
     )
     .await?;
 
@@ -1308,6 +1360,7 @@ pub async fn make_payout_data(
             &payouts.customer_id.to_owned(),
             merchant_id,
             key_store,
+            merchant_account.storage_scheme,
         )
         .await
         .map_or(None, |c| c);
@@ -1321,3 +1374,37 @@ pub async fn make_payout_data(
         merchant_connector_account: None,
     })
 }
+
+#[cfg(feature = "payouts")]
+impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt)> for api::PayoutCreateResponse {
+    fn foreign_from(item: (storage::Payouts, storage::PayoutAttempt)) -> Self {
+        let (payout, payout_attempt) = item;
+        // Customer and billing details are stored encrypted and are not decrypted for lists
+        Self {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            amount: payout.amount,
+            currency: payout.destination_currency,
+            connector: Some(payout_attempt.connector),
+            payout_type: payout.payout_type,
+            billing: None,
+            customer_id: payout.customer_id,
+            auto_fulfill: payout.auto_fulfill,
+            email: None,
+            name: None,
+            phone: None,
+            phone_country_code: None,
+            client_secret: None,
+            return_url: payout.return_url,
+            business_country: payout_attempt.business_country,
+            business_label: payout_attempt.business_label,
+            description: payout.description,
+            entity_type: payout.entity_type,
+            recurring: payout.recurring,
+            metadata: payout.metadata,
+            status: payout_attempt.status,
+            error_message: payout_attempt.error_message,
+            error_code: payout_attempt.error_code,
+        }
+    }
+}
@@ -155,7 +155,7 @@ pub async fn save_payout_data_to_locker(
             )
         }
         api_models::payouts::PayoutMethodData::Bank(bank) => {
-            let key = key_store.key.get_inner().peek();
+            let key = key_store.encryption_key();
             let enc_data = async {
                 serde_json::to_value(payout_method_data.to_owned())
                     .into_report()
@@ -266,10 +266,15 @@ pub async fn get_or_create_customer_details(
     let customer_id =
         core_utils::get_or_generate_id("customer_id", &customer_details.customer_id, "cust")?;
     let merchant_id = &merchant_account.merchant_id;
-    let key = key_store.key.get_inner().peek();
+    let key = key_store.encryption_key();
 
     match db
-        .find_customer_optional_by_customer_id_merchant_id(&customer_id, merchant_id, key_store)
+        .find_customer_optional_by_customer_id_merchant_id(
+            &customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)?
     {
@@ -300,7 +305,7 @@ pub async fn get_or_create_customer_details(
             };
 
             Ok(Some(
-                db.insert_customer(customer, key_store)
+                db.insert_customer(customer, key_store, merchant_account.storage_scheme)
                     .await
                     .change_context(errors::ApiErrorResponse::InternalServerError)?,
             ))
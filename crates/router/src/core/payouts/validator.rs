@@ -111,3 +111,18 @@ pub async fn validate_create_request(
 
     Ok((payout_id, payout_method_data))
 }
+
+/// Validates that the number of payouts requested in a list request is within the allowed range
+#[cfg(all(feature = "payouts", feature = "olap"))]
+pub fn validate_payout_list_request(limit: u32) -> RouterResult<()> {
+    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT;
+
+    utils::when(!(1..=PAYOUTS_LIST_MAX_LIMIT).contains(&limit), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be in between 1 and {}",
+                PAYOUTS_LIST_MAX_LIMIT
+            ),
+        }))
+    })
+}
@@ -19,7 +19,7 @@ use crate::{
         self,
         api::{self, refunds},
         domain,
-        storage::{self, enums, ProcessTrackerExt},
+        storage::{self, enums, payment_attempt::PaymentAttemptExt, ProcessTrackerExt},
         transformers::{ForeignFrom, ForeignInto},
     },
     utils::{self, OptionExt},
@@ -160,7 +160,7 @@ pub async fn trigger_refund_to_gateway(
         &routed_through,
         merchant_account,
         key_store,
-        (payment_attempt.amount, currency),
+        (payment_attempt.get_total_amount(), currency),
         payment_intent,
         payment_attempt,
         refund,
@@ -388,7 +388,7 @@ pub async fn sync_refund_with_gateway(
         &connector_id,
         merchant_account,
         key_store,
-        (payment_attempt.amount, currency),
+        (payment_attempt.get_total_amount(), currency),
         payment_intent,
         payment_attempt,
         refund,
@@ -578,8 +578,12 @@ pub async fn validate_and_create_refund(
                 ),
             })?;
 
-            validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
-                .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
+            validator::validate_refund_amount(
+                payment_attempt.get_total_amount(),
+                &all_refunds,
+                refund_amount,
+            )
+            .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
             validator::validate_maximum_refund_against_payment_attempt(
                 &all_refunds,
@@ -603,7 +607,7 @@ pub async fn validate_and_create_refund(
                 .set_connector_transaction_id(connecter_transaction_id.to_string())
                 .set_connector(connector)
                 .set_refund_type(req.refund_type.unwrap_or_default().foreign_into())
-                .set_total_amount(payment_attempt.amount)
+                .set_total_amount(payment_attempt.get_total_amount())
                 .set_refund_amount(refund_amount)
                 .set_currency(currency)
                 .set_created_at(Some(common_utils::date_time::now()))
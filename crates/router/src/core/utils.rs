@@ -5,7 +5,7 @@ use api_models::enums::{DisputeStage, DisputeStatus};
 use common_utils::{crypto::Encryptable, pii::Email};
 use common_utils::{errors::CustomResult, ext_traits::AsyncExt};
 use error_stack::{report, IntoReport, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use uuid::Uuid;
 
 use super::payments::{helpers, PaymentAddress};
@@ -24,7 +24,7 @@ use crate::{
         storage::{self, enums},
         ErrorResponse,
     },
-    utils::{generate_id, generate_uuid, OptionExt, ValueExt},
+    utils::{generate_id, generate_uuid, OptionExt, StringExt, ValueExt},
 };
 
 pub const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_DISPUTE_FLOW: &str =
@@ -1025,3 +1025,40 @@ pub async fn get_profile_id_from_business_details(
         },
     }
 }
+
+/// Fetches a configuration of a merchant from the configs table, stored against the key
+/// `{key_prefix}_{merchant_id}` for all the payments of the merchant, or
+/// `{key_prefix}_{merchant_id}_{profile_id}` for those of a single business profile. The
+/// configuration of the business profile takes precedence over the one of the merchant.
+///
+/// A configuration which cannot be fetched or parsed is logged and treated as absent.
+pub async fn get_merchant_config<T: serde::de::DeserializeOwned>(
+    db: &dyn StorageInterface,
+    key_prefix: &str,
+    merchant_id: &str,
+    profile_id: Option<&str>,
+) -> Option<T> {
+    let keys = profile_id
+        .map(|profile_id| format!("{key_prefix}_{merchant_id}_{profile_id}"))
+        .into_iter()
+        .chain(std::iter::once(format!("{key_prefix}_{merchant_id}")));
+
+    for key in keys {
+        match db.find_config_by_key(&key).await {
+            Ok(config) => {
+                return config
+                    .config
+                    .parse_struct(std::any::type_name::<T>())
+                    .map_err(|error| logger::error!(merchant_config_error=?error, %key))
+                    .ok();
+            }
+            Err(error) if error.current_context().is_db_not_found() => continue,
+            Err(error) => {
+                logger::error!(merchant_config_error=?error, %key);
+                return None;
+            }
+        }
+    }
+
+    None
+}
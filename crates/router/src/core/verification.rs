@@ -7,7 +7,10 @@ use error_stack::ResultExt;
 use external_services::kms;
 
 use crate::{
-    core::errors::{self, api_error_response, utils::StorageErrorExt},
+    core::{
+        audit_events,
+        errors::{self, api_error_response, utils::StorageErrorExt},
+    },
     headers, logger,
     routes::AppState,
     services, types,
@@ -21,6 +24,7 @@ pub async fn verify_merchant_creds_for_applepay(
     body: web::Json<verifications::ApplepayMerchantVerificationRequest>,
     kms_config: &kms::KmsConfig,
     merchant_id: String,
+    actor: audit_events::AuditActor,
 ) -> CustomResult<
     services::ApplicationResponse<ApplepayMerchantResponse>,
     api_error_response::ApiErrorResponse,
@@ -35,18 +39,21 @@ pub async fn verify_merchant_creds_for_applepay(
 
     let applepay_internal_merchant_identifier = kms::get_kms_client(kms_config)
         .await
+        .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
         .decrypt(encrypted_merchant_identifier)
         .await
         .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
 
     let cert_data = kms::get_kms_client(kms_config)
         .await
+        .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
         .decrypt(encrypted_cert)
         .await
         .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
 
     let key_data = kms::get_kms_client(kms_config)
         .await
+        .change_context(api_error_response::ApiErrorResponse::InternalServerError)?
         .decrypt(encrypted_key)
         .await
         .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
@@ -92,6 +99,7 @@ pub async fn verify_merchant_creds_for_applepay(
                 merchant_id,
                 body.merchant_connector_account_id.clone(),
                 body.domain_names.clone(),
+                actor,
             )
             .await
             .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
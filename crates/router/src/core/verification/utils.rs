@@ -2,7 +2,10 @@ use common_utils::errors::CustomResult;
 use error_stack::{Report, ResultExt};
 
 use crate::{
-    core::errors::{self, utils::StorageErrorExt},
+    core::{
+        audit_events::{self, AuditTarget},
+        errors::{self, utils::StorageErrorExt},
+    },
     logger,
     routes::AppState,
     types,
@@ -14,6 +17,7 @@ pub async fn check_existence_and_add_domain_to_db(
     merchant_id: String,
     merchant_connector_id: String,
     domain_from_req: Vec<String>,
+    actor: audit_events::AuditActor,
 ) -> CustomResult<Vec<String>, errors::ApiErrorResponse> {
     let key_store = state
         .store
@@ -38,6 +42,8 @@ pub async fn check_existence_and_add_domain_to_db(
         .applepay_verified_domains
         .clone()
         .unwrap_or_default();
+    let verified_domains_before =
+        serde_json::json!({ "applepay_verified_domains": already_verified_domains });
 
     let mut new_verified_domains: Vec<String> = domain_from_req
         .into_iter()
@@ -58,6 +64,7 @@ pub async fn check_existence_and_add_domain_to_db(
         frm_configs: None,
         connector_webhook_details: None,
         applepay_verified_domains: Some(already_verified_domains.clone()),
+        connector_wallets_details: None,
     };
     state
         .store
@@ -72,7 +79,21 @@ pub async fn check_existence_and_add_domain_to_db(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
-    Ok(already_verified_domains.clone())
+    audit_events::record_audit_event(
+        state,
+        actor,
+        storage::enums::AuditAction::PaymentConnectorUpdate,
+        AuditTarget {
+            merchant_id: Some(merchant_id.as_str()),
+            target_type: storage::enums::AuditTargetType::MerchantConnectorAccount,
+            target_id: &merchant_connector_id,
+        },
+        Some(&verified_domains_before),
+        Some(&serde_json::json!({ "applepay_verified_domains": already_verified_domains })),
+    )
+    .await;
+
+    Ok(already_verified_domains)
 }
 
 pub fn log_applepay_verification_response_if_error(
@@ -1,11 +1,15 @@
 pub mod types;
 pub mod utils;
+pub mod webhook_events;
+
+use std::time::Instant;
 
 use api_models::payments::HeaderPayload;
 use common_utils::errors::ReportSwitchExt;
 use error_stack::{report, IntoReport, ResultExt};
-use masking::ExposeInterface;
+use masking::{ExposeInterface, Secret};
 use router_env::{instrument, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, errors as sch_errors, utils as pt_utils};
 
 use super::{errors::StorageErrorExt, metrics};
 #[cfg(feature = "stripe")]
@@ -13,9 +17,11 @@ use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     consts,
     core::{
+        blocklist::utils as blocklist_utils,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse},
         payments, refunds,
     },
+    db::StorageInterface,
     logger,
     routes::{metrics::request::add_attributes, AppState},
     services,
@@ -25,10 +31,15 @@ use crate::{
         transformers::{ForeignInto, ForeignTryInto},
     },
     utils::{self as helper_utils, generate_id, Encode, OptionExt, ValueExt},
+    workflows::outgoing_webhook_retry,
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+/// Maximum number of bytes of the response of the merchant recorded with a delivery attempt
+const OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_LENGTH: usize = 1024;
 const MERCHANT_ID: &str = "merchant_id";
+const OUTGOING_WEBHOOK_RETRY_RUNNER: &str = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
 
 #[instrument(skip_all)]
 pub async fn payments_incoming_webhook_flow<W: types::OutgoingWebhookType>(
@@ -377,6 +388,7 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+        let is_new_dispute = option_dispute.is_none();
         let dispute_object = get_or_update_dispute_object(
             state.clone(),
             option_dispute,
@@ -387,6 +399,16 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             connector.id(),
         )
         .await?;
+        if is_new_dispute {
+            blocklist_utils::record_disputed_payment(
+                &state,
+                &merchant_account,
+                &payment_attempt.payment_id,
+            )
+            .await
+            .map_err(|error| logger::error!(blocklist_dispute_event_error=?error))
+            .ok();
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -409,6 +431,94 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
     }
 }
 
+#[instrument(skip_all)]
+async fn incremental_authorization_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector: &(dyn api::Connector + Sync),
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if !source_verified {
+        return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed).into_report();
+    }
+
+    let db = &*state.store;
+    let authorization_details = connector
+        .get_incremental_authorization_details(request_details)
+        .switch()?;
+    let payment_attempt = get_payment_attempt_from_object_reference_id(
+        &state,
+        webhook_details.object_reference_id,
+        &merchant_account,
+    )
+    .await?;
+    let incremental_authorization = db
+        .find_incremental_authorizations_by_merchant_id_payment_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.payment_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?
+        .into_iter()
+        .find(|incremental_authorization| {
+            incremental_authorization
+                .connector_authorization_id
+                .as_ref()
+                == Some(&authorization_details.connector_authorization_id)
+        })
+        .get_required_value("incremental_authorization")
+        .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    // The outcome of the incremental authorization may already be known from the response of the
+    // connector, or from an earlier delivery of the same webhook
+    if matches!(
+        incremental_authorization.status,
+        enums::AuthorizationStatus::Success | enums::AuthorizationStatus::Failure
+    ) {
+        logger::info!(
+            "Incremental authorization {} is already resolved",
+            incremental_authorization.authorization_id
+        );
+        return Ok(());
+    }
+
+    let incremental_authorization = db
+        .update_incremental_authorization(
+            incremental_authorization,
+            storage::IncrementalAuthorizationUpdate::StatusUpdate {
+                status: authorization_details.status,
+                error_code: None,
+                error_message: authorization_details.error_message,
+                connector_authorization_id: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    if incremental_authorization.status == enums::AuthorizationStatus::Success {
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_attempt.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+        payments::helpers::update_amount_for_incremental_authorization(
+            db,
+            payment_intent,
+            payment_attempt,
+            &incremental_authorization,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
 async fn bank_transfer_webhook_flow<W: types::OutgoingWebhookType>(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -547,6 +657,7 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
     };
 
     let event_insert_result = state.store.insert_event(new_event).await;
@@ -597,9 +708,115 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     merchant_account: domain::MerchantAccount,
     webhook: api::OutgoingWebhook,
     state: &AppState,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let outgoing_webhook_event_id = webhook.event_id.clone();
+
+    let transformed_outgoing_webhook = W::from(webhook);
+
+    let outgoing_webhooks_signature = transformed_outgoing_webhook
+        .get_outgoing_webhooks_signature(merchant_account.payment_response_hash_key.clone())?;
+
+    let transformed_outgoing_webhook_string =
+        Encode::<serde_json::Value>::encode_to_string_of_json(&transformed_outgoing_webhook)
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("There was an issue when encoding the outgoing webhook body")?;
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &merchant_account.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)
+        .attach_printable("Failed to fetch merchant key store")?;
+
+    let request_body = domain::types::encrypt(
+        Secret::<_, masking::WithType>::new(transformed_outgoing_webhook_string),
+        key_store.encryption_key(),
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookEncryptionFailed)
+    .attach_printable("Failed to encrypt the outgoing webhook body")?;
+
+    let tracking_data = storage::OutgoingWebhookTrackingData {
+        merchant_id: merchant_account.merchant_id.clone(),
+        event_id: outgoing_webhook_event_id,
+        request_body: request_body.into(),
+        signature: outgoing_webhooks_signature,
+    };
+
+    let delivery_result = deliver_webhook_to_merchant::<W>(
+        state,
+        &merchant_account,
+        &key_store,
+        &tracking_data,
+        enums::WebhookDeliveryType::InitialAttempt,
+    )
+    .await;
+
+    if let Err(error) = &delivery_result {
+        if matches!(
+            error.current_context(),
+            errors::WebhooksFlowError::CallToMerchantFailed
+                | errors::WebhooksFlowError::NotReceivedByMerchant
+        ) {
+            add_outgoing_webhook_retry_task(&*state.store, tracking_data)
+                .await
+                .map_err(|error| {
+                    logger::error!(outgoing_webhook_retry_scheduling_failure=?error);
+                })
+                .ok();
+        }
+    }
+
+    delivery_result
+}
+
+/// Redelivers an outgoing webhook whose body and signature were persisted during an earlier
+/// delivery attempt
+pub async fn retry_webhook_delivery_to_merchant(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::OutgoingWebhookTrackingData,
+    delivery_type: enums::WebhookDeliveryType,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    match merchant_account.get_compatible_connector() {
+        #[cfg(feature = "stripe")]
+        Some(api_models::enums::Connector::Stripe) => {
+            deliver_webhook_to_merchant::<stripe_webhooks::StripeOutgoingWebhook>(
+                state,
+                merchant_account,
+                key_store,
+                tracking_data,
+                delivery_type,
+            )
+            .await
+        }
+        _ => {
+            deliver_webhook_to_merchant::<api_models::webhooks::OutgoingWebhook>(
+                state,
+                merchant_account,
+                key_store,
+                tracking_data,
+                delivery_type,
+            )
+            .await
+        }
+    }
+}
+
+async fn deliver_webhook_to_merchant<W: types::OutgoingWebhookType>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::OutgoingWebhookTrackingData,
+    delivery_type: enums::WebhookDeliveryType,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_details_json = merchant_account
         .webhook_details
+        .clone()
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -614,26 +831,25 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
         .map(ExposeInterface::expose)?;
 
-    let outgoing_webhook_event_id = webhook.event_id.clone();
-
-    let transformed_outgoing_webhook = W::from(webhook);
-
-    let outgoing_webhooks_signature = transformed_outgoing_webhook
-        .get_outgoing_webhooks_signature(merchant_account.payment_response_hash_key.clone())?;
-
-    let transformed_outgoing_webhook_string = router_types::RequestBody::log_and_get_request_body(
-        &transformed_outgoing_webhook,
-        Encode::<serde_json::Value>::encode_to_string_of_json,
+    let request_body = domain::types::decrypt_with_key_version::<String, masking::WithType>(
+        tracking_data.request_body.clone(),
+        key_store,
     )
-    .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-    .attach_printable("There was an issue when encoding the outgoing webhook body")?;
+    .await
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookEncryptionFailed)
+    .attach_printable("Failed to decrypt the outgoing webhook body")?
+    .into_inner()
+    .expose();
+
+    let request_body = router_types::RequestBody::log_and_get_request_body(request_body, Ok)
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
 
     let mut header = vec![(
         reqwest::header::CONTENT_TYPE.to_string(),
         "application/json".into(),
     )];
 
-    if let Some(signature) = outgoing_webhooks_signature {
+    if let Some(signature) = tracking_data.signature.clone() {
         W::add_webhook_header(&mut header, signature)
     }
 
@@ -642,13 +858,15 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
         .url(&webhook_url)
         .attach_default_headers()
         .headers(header)
-        .body(Some(transformed_outgoing_webhook_string))
+        .body(Some(request_body))
         .build();
 
+    let request_start_time = Instant::now();
     let response = state
         .api_client
         .send_request(state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
         .await;
+    let latency_ms = i64::try_from(request_start_time.elapsed().as_millis()).ok();
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         &metrics::CONTEXT,
@@ -660,13 +878,45 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     );
     logger::debug!(outgoing_webhook_response=?response);
 
-    match response {
+    let mut delivery_attempt = storage::WebhookDeliveryAttemptNew {
+        event_id: tracking_data.event_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        attempt_number: 0,
+        delivery_type,
+        is_delivered: false,
+        response_status_code: None,
+        latency_ms,
+        error_message: None,
+        request_body: Some(tracking_data.request_body.clone()),
+        request_signature: tracking_data.signature.clone(),
+        response_body: None,
+    };
+
+    let delivery_result = match response {
         Err(e) => {
-            // [#217]: Schedule webhook for retry.
-            Err(e).change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
+            delivery_attempt.error_message = Some(e.current_context().to_string());
+            Err(e).change_context(errors::WebhooksFlowError::CallToMerchantFailed)
         }
         Ok(res) => {
-            if res.status().is_success() {
+            let status = res.status();
+            delivery_attempt.response_status_code = i16::try_from(status.as_u16()).ok();
+            let response_body = read_response_body(res, OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_LENGTH)
+                .await
+                .map_err(|error| logger::warn!(outgoing_webhook_response_body_read_failure=?error))
+                .ok();
+            delivery_attempt.response_body = domain::types::encrypt_optional(
+                response_body.map(Secret::<_, masking::WithType>::new),
+                key_store.encryption_key(),
+            )
+            .await
+            .map_err(
+                |error| logger::warn!(outgoing_webhook_response_body_encryption_failure=?error),
+            )
+            .ok()
+            .flatten()
+            .map(Into::into);
+            if status.is_success() {
+                delivery_attempt.is_delivered = true;
                 metrics::WEBHOOK_OUTGOING_RECEIVED_COUNT.add(
                     &metrics::CONTEXT,
                     1,
@@ -675,14 +925,7 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
                         merchant_account.merchant_id.clone(),
                     )],
                 );
-                let update_event = storage::EventUpdate::UpdateWebhookNotified {
-                    is_webhook_notified: Some(true),
-                };
-                state
-                    .store
-                    .update_event(outgoing_webhook_event_id, update_event)
-                    .await
-                    .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+                Ok(())
             } else {
                 metrics::WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT.add(
                     &metrics::CONTEXT,
@@ -692,12 +935,91 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
                         merchant_account.merchant_id.clone(),
                     )],
                 );
-                // [#217]: Schedule webhook for retry.
-                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
+                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()
             }
         }
+    };
+
+    let is_delivered = delivery_attempt.is_delivered;
+
+    // Failing to record the attempt should not affect the outcome of the delivery itself
+    state
+        .store
+        .insert_webhook_delivery_attempt(delivery_attempt)
+        .await
+        .map_err(|error| {
+            logger::error!(webhook_delivery_attempt_insertion_failure=?error);
+        })
+        .ok();
+
+    if is_delivered {
+        let update_event = storage::EventUpdate::UpdateWebhookNotified {
+            is_webhook_notified: Some(true),
+        };
+        state
+            .store
+            .update_event(tracking_data.event_id.clone(), update_event)
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+    }
+
+    delivery_result
+}
+
+/// Reads at most `max_length` bytes of the body of the response, without buffering the rest of it
+async fn read_response_body(
+    mut response: reqwest::Response,
+    max_length: usize,
+) -> Result<String, reqwest::Error> {
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend(chunk.iter().take(max_length.saturating_sub(body.len())));
+        if body.len() >= max_length {
+            break;
+        }
     }
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
 
+/// The ID of the process tracker task which retries the delivery of the webhook of the event
+pub fn get_outgoing_webhook_retry_task_id(event_id: &str, merchant_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        OUTGOING_WEBHOOK_RETRY_RUNNER,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        event_id,
+        merchant_id,
+    )
+}
+
+async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::OutgoingWebhookTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let schedule_time = match outgoing_webhook_retry::get_webhook_delivery_retry_schedule_time(
+        db,
+        &tracking_data.merchant_id,
+        0,
+    )
+    .await
+    {
+        Some(schedule_time) => schedule_time,
+        None => {
+            logger::info!("Outgoing webhook retries are disabled for the merchant");
+            return Ok(());
+        }
+    };
+
+    let process_tracker_id =
+        get_outgoing_webhook_retry_task_id(&tracking_data.event_id, &tracking_data.merchant_id);
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        OUTGOING_WEBHOOK_RETRY_RUNNER,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
     Ok(())
 }
 
@@ -887,6 +1209,19 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType>(
             .await
             .attach_printable("Incoming webhook flow for disputes failed")?,
 
+            api::WebhookFlow::IncrementalAuthorization => {
+                incremental_authorization_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    webhook_details,
+                    source_verified,
+                    *connector,
+                    &request_details,
+                )
+                .await
+                .attach_printable("Incoming webhook flow for incremental authorizations failed")?
+            }
+
             api::WebhookFlow::BankTransfer => bank_transfer_webhook_flow::<W>(
                 state.clone(),
                 merchant_account,
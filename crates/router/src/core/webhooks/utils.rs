@@ -10,6 +10,8 @@ fn default_webhook_config() -> api::MerchantWebhookConfig {
         api::IncomingWebhookEvent::PaymentIntentProcessing,
         api::IncomingWebhookEvent::PaymentActionRequired,
         api::IncomingWebhookEvent::RefundSuccess,
+        api::IncomingWebhookEvent::PaymentIntentAuthorizationSuccess,
+        api::IncomingWebhookEvent::PaymentIntentAuthorizationFailure,
     ])
 }
 
@@ -0,0 +1,273 @@
+use api_models::webhook_events as api_webhook_events;
+use common_utils::consts::{EVENTS_LIST_DEFAULT_LIMIT, EVENTS_LIST_MAX_LIMIT};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::ExposeInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+#[instrument(skip(state))]
+pub async fn list_events(
+    state: AppState,
+    merchant_id: String,
+    constraints: api_webhook_events::EventListConstraints,
+) -> RouterResponse<Vec<api_webhook_events::EventListItemResponse>> {
+    let limit = get_events_list_limit(constraints.limit)?;
+    let events = find_events(&state, &merchant_id, constraints, limit).await?;
+
+    Ok(ApplicationResponse::Json(
+        events.into_iter().map(ForeignInto::foreign_into).collect(),
+    ))
+}
+
+/// Lists a page of the events of the merchant, along with whether more events follow it, for the
+/// clients paginating with the `starting_after` cursor
+#[instrument(skip(state))]
+pub async fn list_events_page(
+    state: AppState,
+    merchant_id: String,
+    constraints: api_webhook_events::EventListConstraints,
+) -> RouterResponse<api_webhook_events::EventListPage> {
+    let limit = get_events_list_limit(constraints.limit)?;
+    // One more event than the limit is fetched to find out whether more events follow the page
+    let mut events = find_events(&state, &merchant_id, constraints, limit + 1).await?;
+
+    let page_size = usize::try_from(limit)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let has_more = events.len() > page_size;
+    events.truncate(page_size);
+
+    Ok(ApplicationResponse::Json(
+        api_webhook_events::EventListPage {
+            data: events.into_iter().map(ForeignInto::foreign_into).collect(),
+            has_more,
+        },
+    ))
+}
+
+fn get_events_list_limit(limit: Option<i64>) -> RouterResult<i64> {
+    let limit = limit.unwrap_or_else(|| i64::from(EVENTS_LIST_DEFAULT_LIMIT));
+    utils::when(
+        !(1..=i64::from(EVENTS_LIST_MAX_LIMIT)).contains(&limit),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {EVENTS_LIST_MAX_LIMIT}"),
+            }))
+        },
+    )?;
+    Ok(limit)
+}
+
+async fn find_events(
+    state: &AppState,
+    merchant_id: &str,
+    constraints: api_webhook_events::EventListConstraints,
+    limit: i64,
+) -> RouterResult<Vec<storage::Event>> {
+    let store = state.store.as_ref();
+    let starting_after_id = match constraints.starting_after {
+        Some(starting_after) => Some(
+            find_merchant_event(store, merchant_id, &starting_after)
+                .await?
+                .id,
+        ),
+        None => None,
+    };
+
+    store
+        .list_events_by_merchant_id(
+            merchant_id,
+            constraints.object_id.as_deref(),
+            starting_after_id,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events")
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_event(
+    state: AppState,
+    merchant_id: String,
+    event_id: String,
+) -> RouterResponse<api_webhook_events::EventRetrieveResponse> {
+    let store = state.store.as_ref();
+    let event = find_merchant_event(store, &merchant_id, &event_id).await?;
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mut delivery_attempts = Vec::new();
+    for delivery_attempt in store
+        .find_webhook_delivery_attempts_by_event_id(&event.event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook delivery attempts")?
+    {
+        delivery_attempts.push(delivery_attempt_response(delivery_attempt, &key_store).await?);
+    }
+
+    Ok(ApplicationResponse::Json(
+        api_webhook_events::EventRetrieveResponse {
+            event: event.foreign_into(),
+            delivery_attempts,
+        },
+    ))
+}
+
+/// Sends the webhook of an event to the merchant once again, using the body and signature of the
+/// latest recorded delivery attempt. The attempt is numbered along with the automatic retries of
+/// the event when it is recorded.
+#[instrument(skip(state))]
+pub async fn retry_event_delivery(
+    state: AppState,
+    merchant_id: String,
+    event_id: String,
+) -> RouterResponse<api_webhook_events::WebhookDeliveryAttemptResponse> {
+    let store = state.store.as_ref();
+    let event = find_merchant_event(store, &merchant_id, &event_id).await?;
+
+    let delivery_attempts = store
+        .find_webhook_delivery_attempts_by_event_id(&event.event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook delivery attempts")?;
+
+    let tracking_data = delivery_attempts
+        .into_iter()
+        .rev()
+        .find_map(|attempt| {
+            attempt
+                .request_body
+                .map(|request_body| storage::OutgoingWebhookTrackingData {
+                    merchant_id: merchant_id.clone(),
+                    event_id: event.event_id.clone(),
+                    request_body,
+                    signature: attempt.request_signature,
+                })
+        })
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The webhook for this event was never sent, so it cannot be resent".into(),
+        })?;
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = store
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let delivery_result = super::retry_webhook_delivery_to_merchant(
+        &state,
+        &merchant_account,
+        &key_store,
+        &tracking_data,
+        enums::WebhookDeliveryType::ManualRetry,
+    )
+    .await;
+
+    // A failed delivery is recorded as an attempt and reported through the response, only errors
+    // which prevented the webhook from being sent are propagated
+    if let Err(error) = delivery_result {
+        match error.current_context() {
+            errors::WebhooksFlowError::CallToMerchantFailed
+            | errors::WebhooksFlowError::NotReceivedByMerchant => {
+                logger::info!(manual_webhook_retry_failure=?error);
+            }
+            _ => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+                    .attach_printable("Failed to resend webhook");
+            }
+        }
+    }
+
+    let delivery_attempt = store
+        .find_webhook_delivery_attempts_by_event_id(&event.event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook delivery attempts")?
+        .into_iter()
+        .filter(|attempt| attempt.delivery_type == enums::WebhookDeliveryType::ManualRetry)
+        .max_by_key(|attempt| attempt.attempt_number)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Webhook delivery attempt was not recorded")?;
+
+    Ok(ApplicationResponse::Json(
+        delivery_attempt_response(delivery_attempt, &key_store).await?,
+    ))
+}
+
+async fn delivery_attempt_response(
+    delivery_attempt: storage::WebhookDeliveryAttempt,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<api_webhook_events::WebhookDeliveryAttemptResponse> {
+    let decrypt_body = |body| async move {
+        domain::types::decrypt::<String, masking::WithType>(body, key_store)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to decrypt webhook delivery attempt body")
+            .map(|body| body.map(|body| body.into_inner().expose()))
+    };
+
+    Ok(api_webhook_events::WebhookDeliveryAttemptResponse {
+        attempt_number: delivery_attempt.attempt_number,
+        delivery_type: delivery_attempt.delivery_type,
+        is_delivered: delivery_attempt.is_delivered,
+        request_body: decrypt_body(delivery_attempt.request_body).await?,
+        response_status_code: delivery_attempt.response_status_code,
+        response_body: decrypt_body(delivery_attempt.response_body).await?,
+        latency_ms: delivery_attempt.latency_ms,
+        error_message: delivery_attempt.error_message,
+        created: delivery_attempt.created_at,
+    })
+}
+
+async fn find_merchant_event(
+    store: &dyn StorageInterface,
+    merchant_id: &str,
+    event_id: &str,
+) -> RouterResult<storage::Event> {
+    let event = store
+        .find_event_by_event_id(event_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound {
+            id: event_id.to_owned(),
+        })?;
+
+    // Events are looked up by their ID alone, so make sure the event belongs to the merchant
+    if event.merchant_id.as_deref() != Some(merchant_id) {
+        Err(errors::ApiErrorResponse::EventNotFound {
+            id: event_id.to_owned(),
+        })?;
+    }
+
+    Ok(event)
+}
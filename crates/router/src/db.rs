@@ -1,5 +1,9 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_event;
+pub mod authentication;
+pub mod blocklist;
+pub mod blocklist_fingerprint;
 pub mod business_profile;
 pub mod cache;
 pub mod capture;
@@ -12,16 +16,20 @@ pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod vault;
+pub mod webhook_delivery_attempt;
 
 use data_models::payments::{
     payment_attempt::PaymentAttemptInterface, payment_intent::PaymentIntentInterface,
@@ -45,6 +53,10 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + audit_event::AuditEventInterface
+    + authentication::AuthenticationInterface
+    + blocklist::BlocklistInterface
+    + blocklist_fingerprint::BlocklistFingerprintInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
     + connector_response::ConnectorResponseInterface
@@ -54,6 +66,7 @@ pub trait StorageInterface:
     + events::EventInterface
     + file::FileMetadataInterface
     + fraud_check::FraudCheckInterface
+    + incremental_authorization::IncrementalAuthorizationInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -61,17 +74,20 @@ pub trait StorageInterface:
     + merchant_connector_account::MerchantConnectorAccountInterface
     + PaymentAttemptInterface
     + PaymentIntentInterface
+    + payment_link::PaymentLinkInterface
     + payment_method::PaymentMethodInterface
     + scheduler::SchedulerInterface
     + payout_attempt::PayoutAttemptInterface
     + payouts::PayoutsInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
+    + vault::VaultInterface
     + cards_info::CardsInfoInterface
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
     + RedisConnInterface
     + business_profile::BusinessProfileInterface
+    + webhook_delivery_attempt::WebhookDeliveryAttemptInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
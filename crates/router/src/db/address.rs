@@ -1,150 +1,515 @@
-use common_utils::ext_traits::AsyncExt;
 use diesel_models::address::AddressUpdateInternal;
-use error_stack::{IntoReport, ResultExt};
+use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
     types::{
         domain::{
             self,
             behaviour::{Conversion, ReverseConversion},
         },
-        storage,
+        storage::{self as storage_types, enums},
     },
 };
 
 #[async_trait::async_trait]
 pub trait AddressInterface
 where
-    domain::Address: Conversion<DstType = storage::Address, NewDstType = storage::AddressNew>,
+    domain::Address:
+        Conversion<DstType = storage_types::Address, NewDstType = storage_types::AddressNew>,
 {
     async fn update_address(
         &self,
         address_id: String,
-        address: storage::AddressUpdate,
+        address: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError>;
 
     async fn insert_address(
         &self,
         address: domain::Address,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError>;
 
     async fn find_address(
         &self,
         address_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError>;
 
     async fn update_address_by_merchant_id_customer_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
-        address: storage::AddressUpdate,
+        address: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
-}
 
-#[async_trait::async_trait]
-impl AddressInterface for Store {
-    async fn find_address(
+    async fn find_addresses_by_merchant_id_after_id(
         &self,
-        address_id: &str,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
         key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Address, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Address::find_by_address_id(&conn, address_id)
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+}
+
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use common_utils::ext_traits::AsyncExt;
+    use error_stack::{IntoReport, ResultExt};
+    use router_env::{instrument, tracing};
+
+    use super::AddressInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::{
+            domain::{
+                self,
+                behaviour::{Conversion, ReverseConversion},
+            },
+            storage::{self as storage_types, enums},
+        },
+    };
+
+    #[async_trait::async_trait]
+    impl AddressInterface for Store {
+        async fn find_address(
+            &self,
+            address_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::find_by_address_id(&conn, address_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|address| async {
+                    address
+                        .convert(key_store)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        }
+
+        #[instrument(skip_all)]
+        async fn update_address(
+            &self,
+            address_id: String,
+            address: storage_types::AddressUpdate,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Address::update_by_address_id(&conn, address_id, address.into())
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|address| async {
+                    address
+                        .convert(key_store)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        }
+
+        #[instrument(skip_all)]
+        async fn insert_address(
+            &self,
+            address: domain::Address,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            address
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|address| async {
+                    address
+                        .convert(key_store)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        }
+
+        async fn update_address_by_merchant_id_customer_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            address: storage_types::AddressUpdate,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Address::update_by_merchant_id_customer_id(
+                &conn,
+                customer_id,
+                merchant_id,
+                address.into(),
+            )
             .await
             .map_err(Into::into)
             .into_report()
-            .async_and_then(|address| async {
-                address
-                    .convert(key_store.key.get_inner())
-                    .await
-                    .change_context(errors::StorageError::DecryptionError)
+            .async_and_then(|addresses| async {
+                let mut output = Vec::with_capacity(addresses.len());
+                for address in addresses.into_iter() {
+                    output.push(
+                        address
+                            .convert(key_store)
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    )
+                }
+                Ok(output)
             })
             .await
-    }
+        }
 
-    #[instrument(skip_all)]
-    async fn update_address(
-        &self,
-        address_id: String,
-        address: storage::AddressUpdate,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Address, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Address::update_by_address_id(&conn, address_id, address.into())
-            .await
-            .map_err(Into::into)
-            .into_report()
-            .async_and_then(|address| async {
-                address
-                    .convert(key_store.key.get_inner())
+        async fn find_addresses_by_merchant_id_after_id(
+            &self,
+            merchant_id: &str,
+            id: i32,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let addresses =
+                storage_types::Address::find_by_merchant_id_after_id(&conn, merchant_id, id, limit)
                     .await
-                    .change_context(errors::StorageError::DecryptionError)
-            })
-            .await
+                    .map_err(Into::into)
+                    .into_report()?;
+
+            let mut output = Vec::with_capacity(addresses.len());
+            for address in addresses.into_iter() {
+                output.push(
+                    address
+                        .convert(key_store)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                )
+            }
+            Ok(output)
+        }
     }
+}
 
-    #[instrument(skip_all)]
-    async fn insert_address(
-        &self,
-        address: domain::Address,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Address, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        address
-            .construct_new()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .insert(&conn)
+#[cfg(feature = "kv_store")]
+mod storage {
+    use diesel_models::address::AddressUpdateInternal;
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::RedisConnInterface;
+
+    use super::AddressInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::{
+            domain::{
+                self,
+                behaviour::{Conversion, ReverseConversion},
+            },
+            storage::{self as storage_types, enums, kv},
+        },
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    /// Redis hash key and field an address is stored at in KV mode
+    fn get_address_key_and_field(merchant_id: &str, address_id: &str) -> (String, String) {
+        (
+            format!("mid_{merchant_id}_add_{address_id}"),
+            format!("add_{address_id}"),
+        )
+    }
+
+    async fn find_storage_address(
+        store: &Store,
+        address_id: &str,
+        merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Address, errors::StorageError> {
+        let database_call = || async {
+            let conn = connection::pg_connection_read(store).await?;
+            storage_types::Address::find_by_address_id(&conn, address_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+        match storage_scheme {
+            enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+            enums::MerchantStorageScheme::RedisKv => {
+                let (key, field) = get_address_key_and_field(merchant_id, address_id);
+                db_utils::try_redis_get_else_try_database_get(
+                    store
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .get_hash_field_and_deserialize(&key, &field, "Address"),
+                    database_call,
+                )
+                .await
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl AddressInterface for Store {
+        async fn find_address(
+            &self,
+            address_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            find_storage_address(self, address_id, &key_store.merchant_id, storage_scheme)
+                .await?
+                .convert(key_store)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        #[instrument(skip_all)]
+        async fn update_address(
+            &self,
+            address_id: String,
+            address: storage_types::AddressUpdate,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let address_update = AddressUpdateInternal::from(address);
+            let updated_address = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::Address::update_by_address_id(&conn, address_id, address_update)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()?
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let address = find_storage_address(
+                        self,
+                        &address_id,
+                        &key_store.merchant_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                    let updated_address = address_update.clone().create_address(address.clone());
+                    let (key, field) =
+                        get_address_key_and_field(&address.merchant_id, &address.address_id);
+
+                    let redis_value =
+                        utils::Encode::<storage_types::Address>::encode_to_string_of_json(
+                            &updated_address,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::AddressUpdate(kv::AddressUpdateMems {
+                                orig: address,
+                                update_data: address_update,
+                            }),
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::Address>(
+                        redis_entry,
+                        PartitionKey::CombinationKey { combination: &key },
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?;
+
+                    updated_address
+                }
+            };
+
+            updated_address
+                .convert(key_store)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        #[instrument(skip_all)]
+        async fn insert_address(
+            &self,
+            address: domain::Address,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let address_new = address
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?;
+
+            let created_address = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    address_new
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()?
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_address_key_and_field(
+                        &address_new.merchant_id,
+                        &address_new.address_id,
+                    );
+                    let created_address = storage_types::Address {
+                        id: 0i32,
+                        address_id: address_new.address_id.clone(),
+                        city: address_new.city.clone(),
+                        country: address_new.country,
+                        line1: address_new.line1.clone(),
+                        line2: address_new.line2.clone(),
+                        line3: address_new.line3.clone(),
+                        state: address_new.state.clone(),
+                        zip: address_new.zip.clone(),
+                        first_name: address_new.first_name.clone(),
+                        last_name: address_new.last_name.clone(),
+                        phone_number: address_new.phone_number.clone(),
+                        country_code: address_new.country_code.clone(),
+                        created_at: address_new.created_at,
+                        modified_at: address_new.modified_at,
+                        customer_id: address_new.customer_id.clone(),
+                        merchant_id: address_new.merchant_id.clone(),
+                    };
+
+                    match self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_hash_field_if_not_exist(&key, &field, &created_address)
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "address",
+                            key: Some(created_address.address_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::Address(address_new),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage_types::Address>(
+                                redis_entry,
+                                PartitionKey::CombinationKey { combination: &key },
+                            )
+                            .await
+                            .change_context(errors::StorageError::KVError)?;
+                            Ok(created_address)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }?
+                }
+            };
+
+            created_address
+                .convert(key_store)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        async fn update_address_by_merchant_id_customer_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            address: storage_types::AddressUpdate,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            // Addresses cannot be looked up by customer in Redis, so the update is made in
+            // Postgres. Addresses which have not been drained to Postgres yet are not updated.
+            let conn = connection::pg_connection_write(self).await?;
+            let addresses = storage_types::Address::update_by_merchant_id_customer_id(
+                &conn,
+                customer_id,
+                merchant_id,
+                address.into(),
+            )
             .await
             .map_err(Into::into)
-            .into_report()
-            .async_and_then(|address| async {
-                address
-                    .convert(key_store.key.get_inner())
+            .into_report()?;
+
+            if storage_scheme == enums::MerchantStorageScheme::RedisKv {
+                // Replace the copies of the updated addresses in Redis, which would otherwise be
+                // read instead of the updated rows
+                let redis_conn = self
+                    .get_redis_conn()
+                    .map_err(Into::<errors::StorageError>::into)?;
+                for address in addresses.iter() {
+                    let (key, field) =
+                        get_address_key_and_field(&address.merchant_id, &address.address_id);
+                    let redis_value =
+                        utils::Encode::<storage_types::Address>::encode_to_string_of_json(address)
+                            .change_context(errors::StorageError::SerializationFailed)?;
+                    redis_conn
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+                }
+            }
+
+            let mut output = Vec::with_capacity(addresses.len());
+            for address in addresses.into_iter() {
+                output.push(
+                    address
+                        .convert(key_store)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                )
+            }
+            Ok(output)
+        }
+
+        async fn find_addresses_by_merchant_id_after_id(
+            &self,
+            merchant_id: &str,
+            id: i32,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let addresses =
+                storage_types::Address::find_by_merchant_id_after_id(&conn, merchant_id, id, limit)
                     .await
-                    .change_context(errors::StorageError::DecryptionError)
-            })
-            .await
-    }
+                    .map_err(Into::into)
+                    .into_report()?;
 
-    async fn update_address_by_merchant_id_customer_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-        address: storage::AddressUpdate,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Address::update_by_merchant_id_customer_id(
-            &conn,
-            customer_id,
-            merchant_id,
-            address.into(),
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-        .async_and_then(|addresses| async {
             let mut output = Vec::with_capacity(addresses.len());
             for address in addresses.into_iter() {
                 output.push(
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)?,
                 )
             }
             Ok(output)
-        })
-        .await
+        }
     }
 }
 
@@ -154,6 +519,7 @@ impl AddressInterface for MockDb {
         &self,
         address_id: &str,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError> {
         match self
             .addresses
@@ -164,7 +530,7 @@ impl AddressInterface for MockDb {
         {
             Some(address) => address
                 .clone()
-                .convert(key_store.key.get_inner())
+                .convert(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => {
@@ -179,8 +545,9 @@ impl AddressInterface for MockDb {
     async fn update_address(
         &self,
         address_id: String,
-        address_update: storage::AddressUpdate,
+        address_update: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError> {
         match self
             .addresses
@@ -195,7 +562,7 @@ impl AddressInterface for MockDb {
                 address_updated
             }) {
             Some(address_updated) => address_updated
-                .convert(key_store.key.get_inner())
+                .convert(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -210,6 +577,7 @@ impl AddressInterface for MockDb {
         &self,
         address_new: domain::Address,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError> {
         let mut addresses = self.addresses.lock().await;
 
@@ -220,7 +588,7 @@ impl AddressInterface for MockDb {
         addresses.push(address.clone());
 
         address
-            .convert(key_store.key.get_inner())
+            .convert(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -229,8 +597,9 @@ impl AddressInterface for MockDb {
         &self,
         customer_id: &str,
         merchant_id: &str,
-        address_update: storage::AddressUpdate,
+        address_update: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
         match self
             .addresses
@@ -248,7 +617,7 @@ impl AddressInterface for MockDb {
             }) {
             Some(address) => {
                 let address: domain::Address = address
-                    .convert(key_store.key.get_inner())
+                    .convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)?;
                 Ok(vec![address])
@@ -258,4 +627,33 @@ impl AddressInterface for MockDb {
             }
         }
     }
+
+    async fn find_addresses_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| address.merchant_id == merchant_id && address.id > id)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::with_capacity(addresses.len());
+        for address in addresses.into_iter() {
+            output.push(
+                address
+                    .convert(key_store)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
 }
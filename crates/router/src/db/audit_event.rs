@@ -0,0 +1,225 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, AuditEventDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait AuditEventInterface {
+    async fn insert_audit_event(
+        &self,
+        audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError>;
+
+    async fn filter_audit_events_by_constraints(
+        &self,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditEventInterface for Store {
+    async fn insert_audit_event(
+        &self,
+        audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit_event
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn filter_audit_events_by_constraints(
+        &self,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::AuditEvent as AuditEventDbExt>::filter_by_constraints(&conn, constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditEventInterface for MockDb {
+    async fn insert_audit_event(
+        &self,
+        audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError> {
+        let mut audit_events = self.audit_events.lock().await;
+
+        let audit_event = storage::AuditEvent {
+            id: audit_events
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            event_id: audit_event.event_id,
+            merchant_id: audit_event.merchant_id,
+            actor_type: audit_event.actor_type,
+            actor_id: audit_event.actor_id,
+            action: audit_event.action,
+            target_type: audit_event.target_type,
+            target_id: audit_event.target_id,
+            diff: audit_event.diff,
+            request_id: audit_event.request_id,
+            created_at: common_utils::date_time::now(),
+        };
+        audit_events.push(audit_event.clone());
+
+        Ok(audit_event)
+    }
+
+    async fn filter_audit_events_by_constraints(
+        &self,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError> {
+        let audit_events = self.audit_events.lock().await;
+
+        let offset: usize = constraints
+            .offset
+            .unwrap_or_default()
+            .try_into()
+            .into_report()
+            .change_context(errors::StorageError::MockDbError)?;
+        let limit: usize = constraints
+            .limit
+            .unwrap_or(storage::AUDIT_EVENT_LIST_DEFAULT_LIMIT)
+            .min(storage::AUDIT_EVENT_LIST_UPPER_LIMIT)
+            .try_into()
+            .into_report()
+            .change_context(errors::StorageError::MockDbError)?;
+
+        Ok(audit_events
+            .iter()
+            .rev()
+            .filter(|event| {
+                constraints
+                    .merchant_id
+                    .as_ref()
+                    .map_or(true, |merchant_id| {
+                        event.merchant_id.as_ref() == Some(merchant_id)
+                    })
+                    && constraints
+                        .actor_type
+                        .map_or(true, |actor_type| event.actor_type == actor_type)
+                    && constraints
+                        .actor_id
+                        .as_ref()
+                        .map_or(true, |actor_id| event.actor_id.as_ref() == Some(actor_id))
+                    && constraints
+                        .action
+                        .map_or(true, |action| event.action == action)
+                    && constraints
+                        .target_type
+                        .map_or(true, |target_type| event.target_type == target_type)
+                    && constraints
+                        .target_id
+                        .as_ref()
+                        .map_or(true, |target_id| &event.target_id == target_id)
+                    && constraints
+                        .start_time
+                        .map_or(true, |start_time| event.created_at >= start_time)
+                    && constraints
+                        .end_time
+                        .map_or(true, |end_time| event.created_at <= end_time)
+            })
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use api_models::audit_events::AuditEventListConstraints;
+    use diesel_models::enums;
+
+    use crate::{
+        db::{audit_event::AuditEventInterface, MockDb},
+        types::storage,
+    };
+
+    fn audit_event_new(
+        event_id: &str,
+        merchant_id: &str,
+        action: enums::AuditAction,
+    ) -> storage::AuditEventNew {
+        storage::AuditEventNew {
+            event_id: event_id.into(),
+            merchant_id: Some(merchant_id.into()),
+            actor_type: enums::AuditActorType::AdminApiKey,
+            actor_id: None,
+            action,
+            target_type: enums::AuditTargetType::MerchantConnectorAccount,
+            target_id: "mca_1".into(),
+            diff: None,
+            request_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mockdb_audit_event_interface() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        for (event_id, merchant_id, action) in [
+            (
+                "event_1",
+                "merchant_1",
+                enums::AuditAction::PaymentConnectorCreate,
+            ),
+            (
+                "event_2",
+                "merchant_1",
+                enums::AuditAction::PaymentConnectorUpdate,
+            ),
+            (
+                "event_3",
+                "merchant_2",
+                enums::AuditAction::PaymentConnectorUpdate,
+            ),
+        ] {
+            mockdb
+                .insert_audit_event(audit_event_new(event_id, merchant_id, action))
+                .await
+                .unwrap();
+        }
+
+        let merchant_events = mockdb
+            .filter_audit_events_by_constraints(&AuditEventListConstraints {
+                merchant_id: Some("merchant_1".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let event_ids = merchant_events
+            .iter()
+            .map(|event| event.event_id.as_str())
+            .collect::<Vec<_>>();
+        // Most recent events are listed first
+        assert_eq!(event_ids, vec!["event_2", "event_1"]);
+
+        let update_events = mockdb
+            .filter_audit_events_by_constraints(&AuditEventListConstraints {
+                action: Some(enums::AuditAction::PaymentConnectorUpdate),
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(update_events.len(), 1);
+        assert_eq!(update_events[0].event_id, "event_3");
+    }
+}
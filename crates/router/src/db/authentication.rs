@@ -0,0 +1,241 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AuthenticationInterface {
+    async fn insert_authentication(
+        &self,
+        authentication: storage::AuthenticationNew,
+    ) -> CustomResult<storage::Authentication, errors::StorageError>;
+
+    async fn find_authentication_by_merchant_id_authentication_id(
+        &self,
+        merchant_id: &str,
+        authentication_id: &str,
+    ) -> CustomResult<storage::Authentication, errors::StorageError>;
+
+    async fn find_latest_authentication_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::Authentication>, errors::StorageError>;
+
+    async fn find_authentications_by_merchant_id_after_authentication_id(
+        &self,
+        merchant_id: &str,
+        authentication_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Authentication>, errors::StorageError>;
+
+    async fn update_authentication(
+        &self,
+        this: storage::Authentication,
+        authentication: storage::AuthenticationUpdate,
+    ) -> CustomResult<storage::Authentication, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuthenticationInterface for Store {
+    async fn insert_authentication(
+        &self,
+        authentication: storage::AuthenticationNew,
+    ) -> CustomResult<storage::Authentication, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        authentication
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_authentication_by_merchant_id_authentication_id(
+        &self,
+        merchant_id: &str,
+        authentication_id: &str,
+    ) -> CustomResult<storage::Authentication, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Authentication::find_by_merchant_id_authentication_id(
+            &conn,
+            merchant_id,
+            authentication_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_latest_authentication_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::Authentication>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Authentication::find_latest_by_merchant_id_attempt_id(
+            &conn,
+            merchant_id,
+            attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_authentications_by_merchant_id_after_authentication_id(
+        &self,
+        merchant_id: &str,
+        authentication_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Authentication>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Authentication::find_by_merchant_id_after_authentication_id(
+            &conn,
+            merchant_id,
+            authentication_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_authentication(
+        &self,
+        this: storage::Authentication,
+        authentication: storage::AuthenticationUpdate,
+    ) -> CustomResult<storage::Authentication, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_by_merchant_id_authentication_id(&conn, authentication)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthenticationInterface for MockDb {
+    async fn insert_authentication(
+        &self,
+        authentication: storage::AuthenticationNew,
+    ) -> CustomResult<storage::Authentication, errors::StorageError> {
+        let mut authentications = self.authentications.lock().await;
+        let now = common_utils::date_time::now();
+
+        let stored_authentication = storage::Authentication {
+            authentication_id: authentication.authentication_id,
+            merchant_id: authentication.merchant_id,
+            payment_id: authentication.payment_id,
+            attempt_id: authentication.attempt_id,
+            authentication_connector: authentication.authentication_connector,
+            connector_authentication_id: authentication.connector_authentication_id,
+            authentication_status: authentication.authentication_status,
+            trans_status: None,
+            message_version: authentication.message_version,
+            three_ds_method_url: authentication.three_ds_method_url,
+            three_ds_method_data: authentication.three_ds_method_data,
+            three_ds_method_completion_ind: None,
+            acs_url: None,
+            challenge_request: None,
+            authentication_value: None,
+            eci: None,
+            ds_trans_id: None,
+            error_code: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        };
+
+        authentications.push(stored_authentication.clone());
+
+        Ok(stored_authentication)
+    }
+
+    async fn find_authentication_by_merchant_id_authentication_id(
+        &self,
+        merchant_id: &str,
+        authentication_id: &str,
+    ) -> CustomResult<storage::Authentication, errors::StorageError> {
+        self.authentications
+            .lock()
+            .await
+            .iter()
+            .find(|authentication| {
+                authentication.merchant_id == merchant_id
+                    && authentication.authentication_id == authentication_id
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No authentication available for authentication_id = {authentication_id}"
+            )))
+            .into_report()
+    }
+
+    async fn find_latest_authentication_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::Authentication>, errors::StorageError> {
+        Ok(self
+            .authentications
+            .lock()
+            .await
+            .iter()
+            .filter(|authentication| {
+                authentication.merchant_id == merchant_id && authentication.attempt_id == attempt_id
+            })
+            .max_by_key(|authentication| authentication.created_at)
+            .cloned())
+    }
+
+    async fn find_authentications_by_merchant_id_after_authentication_id(
+        &self,
+        merchant_id: &str,
+        authentication_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Authentication>, errors::StorageError> {
+        let mut authentications: Vec<_> = self
+            .authentications
+            .lock()
+            .await
+            .iter()
+            .filter(|authentication| {
+                authentication.merchant_id == merchant_id
+                    && authentication.authentication_id.as_str() > authentication_id
+            })
+            .cloned()
+            .collect();
+        authentications.sort_by(|a, b| a.authentication_id.cmp(&b.authentication_id));
+        authentications.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(authentications)
+    }
+
+    async fn update_authentication(
+        &self,
+        this: storage::Authentication,
+        authentication: storage::AuthenticationUpdate,
+    ) -> CustomResult<storage::Authentication, errors::StorageError> {
+        let mut authentications = self.authentications.lock().await;
+
+        let stored_authentication = authentications
+            .iter_mut()
+            .find(|stored| {
+                stored.authentication_id == this.authentication_id
+                    && stored.merchant_id == this.merchant_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No authentication available for authentication_id = {}",
+                this.authentication_id
+            )))
+            .into_report()?;
+
+        *stored_authentication = authentication.apply_changeset(this);
+
+        Ok(stored_authentication.clone())
+    }
+}
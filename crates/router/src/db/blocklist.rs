@@ -0,0 +1,193 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait BlocklistInterface {
+    async fn insert_blocklist_entry(
+        &self,
+        blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError>;
+
+    async fn find_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &str,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn list_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &str,
+        data_kind: enums::BlocklistDataKind,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl BlocklistInterface for Store {
+    async fn insert_blocklist_entry(
+        &self,
+        blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        blocklist_entry
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &str,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Blocklist::find_by_merchant_id_fingerprint_ids(&conn, merchant_id, fingerprint_ids)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &str,
+        data_kind: enums::BlocklistDataKind,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Blocklist::list_by_merchant_id_data_kind(
+            &conn,
+            merchant_id,
+            data_kind,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Blocklist::delete_by_merchant_id_fingerprint_id(&conn, merchant_id, fingerprint_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl BlocklistInterface for MockDb {
+    async fn insert_blocklist_entry(
+        &self,
+        blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let mut blocklist = self.blocklist.lock().await;
+
+        if blocklist.iter().any(|entry| {
+            entry.merchant_id == blocklist_entry.merchant_id
+                && entry.fingerprint_id == blocklist_entry.fingerprint_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "blocklist",
+                key: Some(blocklist_entry.fingerprint_id.clone()),
+            })?;
+        }
+
+        let stored_entry = storage::Blocklist {
+            id: blocklist
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            merchant_id: blocklist_entry.merchant_id,
+            fingerprint_id: blocklist_entry.fingerprint_id,
+            data_kind: blocklist_entry.data_kind,
+            metadata: blocklist_entry.metadata,
+            created_at: common_utils::date_time::now(),
+        };
+        blocklist.push(stored_entry.clone());
+
+        Ok(stored_entry)
+    }
+
+    async fn find_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &str,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        Ok(self
+            .blocklist
+            .lock()
+            .await
+            .iter()
+            .filter(|entry| {
+                entry.merchant_id == merchant_id && fingerprint_ids.contains(&entry.fingerprint_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn list_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &str,
+        data_kind: enums::BlocklistDataKind,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let blocklist = self.blocklist.lock().await;
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut entries: Vec<storage::Blocklist> = blocklist
+            .iter()
+            .filter(|entry| entry.merchant_id == merchant_id && entry.data_kind == data_kind)
+            .cloned()
+            .collect();
+        entries.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(entries.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let mut blocklist = self.blocklist.lock().await;
+
+        let position = blocklist
+            .iter()
+            .position(|entry| {
+                entry.merchant_id == merchant_id && entry.fingerprint_id == fingerprint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No blocklist entry available for fingerprint_id = {fingerprint_id}"
+            )))
+            .into_report()?;
+
+        Ok(blocklist.remove(position))
+    }
+}
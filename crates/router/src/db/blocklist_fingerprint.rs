@@ -0,0 +1,148 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait BlocklistFingerprintInterface {
+    async fn insert_blocklist_fingerprint(
+        &self,
+        blocklist_fingerprint: storage::BlocklistFingerprintNew,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError>;
+
+    async fn find_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError>;
+
+    async fn update_blocklist_fingerprint(
+        &self,
+        this: storage::BlocklistFingerprint,
+        blocklist_fingerprint: storage::BlocklistFingerprintUpdate,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl BlocklistFingerprintInterface for Store {
+    async fn insert_blocklist_fingerprint(
+        &self,
+        blocklist_fingerprint: storage::BlocklistFingerprintNew,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        blocklist_fingerprint
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BlocklistFingerprint::find_by_merchant_id_fingerprint_id(
+            &conn,
+            merchant_id,
+            fingerprint_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_blocklist_fingerprint(
+        &self,
+        this: storage::BlocklistFingerprint,
+        blocklist_fingerprint: storage::BlocklistFingerprintUpdate,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, blocklist_fingerprint)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl BlocklistFingerprintInterface for MockDb {
+    async fn insert_blocklist_fingerprint(
+        &self,
+        blocklist_fingerprint: storage::BlocklistFingerprintNew,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        let mut blocklist_fingerprints = self.blocklist_fingerprints.lock().await;
+
+        if blocklist_fingerprints.iter().any(|fingerprint| {
+            fingerprint.merchant_id == blocklist_fingerprint.merchant_id
+                && fingerprint.fingerprint_id == blocklist_fingerprint.fingerprint_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "blocklist_fingerprint",
+                key: Some(blocklist_fingerprint.fingerprint_id.clone()),
+            })?;
+        }
+
+        let now = common_utils::date_time::now();
+        let stored_fingerprint = storage::BlocklistFingerprint {
+            merchant_id: blocklist_fingerprint.merchant_id,
+            fingerprint_id: blocklist_fingerprint.fingerprint_id,
+            dispute_count: blocklist_fingerprint.dispute_count,
+            fraud_count: blocklist_fingerprint.fraud_count,
+            created_at: now,
+            modified_at: now,
+        };
+        blocklist_fingerprints.push(stored_fingerprint.clone());
+
+        Ok(stored_fingerprint)
+    }
+
+    async fn find_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        self.blocklist_fingerprints
+            .lock()
+            .await
+            .iter()
+            .find(|fingerprint| {
+                fingerprint.merchant_id == merchant_id
+                    && fingerprint.fingerprint_id == fingerprint_id
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No blocklist fingerprint available for fingerprint_id = {fingerprint_id}"
+            )))
+            .into_report()
+    }
+
+    async fn update_blocklist_fingerprint(
+        &self,
+        this: storage::BlocklistFingerprint,
+        blocklist_fingerprint: storage::BlocklistFingerprintUpdate,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        let mut blocklist_fingerprints = self.blocklist_fingerprints.lock().await;
+
+        let stored_fingerprint = blocklist_fingerprints
+            .iter_mut()
+            .find(|fingerprint| {
+                fingerprint.merchant_id == this.merchant_id
+                    && fingerprint.fingerprint_id == this.fingerprint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No blocklist fingerprint available for fingerprint_id = {}",
+                this.fingerprint_id
+            )))
+            .into_report()?;
+
+        *stored_fingerprint = blocklist_fingerprint.apply_changeset(stored_fingerprint.clone());
+
+        Ok(stored_fingerprint.clone())
+    }
+}
@@ -62,12 +62,21 @@ where
     F: FnOnce() -> Fut + Send,
     Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
 {
-    let cache_val = cache.get_val::<T>(key);
+    // The in-memory caches are shared by the stores of all tenants, so entries are keyed by the
+    // full redis key, which includes the key prefix of the store
+    let cache_key = store
+        .get_redis_conn()
+        .change_context(errors::StorageError::RedisError(
+            RedisError::RedisConnectionError.into(),
+        ))
+        .attach_printable("Failed to get redis connection")?
+        .add_prefix(key);
+    let cache_val = cache.get_val::<T>(&cache_key);
     if let Some(val) = cache_val {
         Ok(val)
     } else {
         let val = get_or_populate_redis(store, key, fun).await?;
-        cache.push(key.to_string(), val.clone()).await;
+        cache.push(cache_key, val.clone()).await;
         Ok(val)
     }
 }
@@ -83,7 +92,6 @@ where
     Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
 {
     let data = fun().await?;
-    in_memory.async_map(|cache| cache.invalidate(key)).await;
 
     let redis_conn = store
         .get_redis_conn()
@@ -92,6 +100,11 @@ where
         ))
         .attach_printable("Failed to get redis connection")?;
 
+    let cache_key = redis_conn.add_prefix(key);
+    in_memory
+        .async_map(|cache| cache.invalidate(cache_key.as_str()))
+        .await;
+
     redis_conn
         .delete_key(key)
         .await
@@ -110,6 +123,14 @@ pub async fn publish_into_redact_channel<'a>(
         ))
         .attach_printable("Failed to get redis connection")?;
 
+    // Subscribers invalidate the published key as is, so it is published with the key prefix of
+    // the store
+    let key = match key {
+        CacheKind::Config(key) => CacheKind::Config(redis_conn.add_prefix(&key).into()),
+        CacheKind::Accounts(key) => CacheKind::Accounts(redis_conn.add_prefix(&key).into()),
+        CacheKind::All(key) => CacheKind::All(redis_conn.add_prefix(&key).into()),
+    };
+
     redis_conn
         .publish(consts::PUB_SUB_CHANNEL, key)
         .await
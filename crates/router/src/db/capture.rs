@@ -32,48 +32,166 @@ pub trait CaptureInterface {
 
 #[cfg(feature = "kv_store")]
 mod storage {
-    use error_stack::IntoReport;
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use storage_impl::redis::kv_store::RedisConnInterface;
 
     use super::CaptureInterface;
     use crate::{
         connection,
         core::errors::{self, CustomResult},
         services::Store,
-        types::storage::{capture::*, enums},
+        types::storage::{capture::*, enums, kv},
+        utils::{self, storage_partitioning::PartitionKey},
     };
 
+    /// Redis hash key and field a capture is stored at in KV mode, next to the payment attempt it
+    /// was made for
+    fn get_capture_key_and_field(
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
+        capture_id: &str,
+    ) -> (String, String) {
+        (
+            format!("{merchant_id}_{payment_id}"),
+            format!("pa_{authorized_attempt_id}_capture_{capture_id}"),
+        )
+    }
+
     #[async_trait::async_trait]
     impl CaptureInterface for Store {
         async fn insert_capture(
             &self,
             capture: CaptureNew,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Capture, errors::StorageError> {
-            let db_call = || async {
-                let conn = connection::pg_connection_write(self).await?;
-                capture
-                    .insert(&conn)
-                    .await
-                    .map_err(Into::into)
-                    .into_report()
-            };
-            db_call().await
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    capture
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_capture_key_and_field(
+                        &capture.merchant_id,
+                        &capture.payment_id,
+                        &capture.authorized_attempt_id,
+                        &capture.capture_id,
+                    );
+                    let created_capture = Capture {
+                        capture_id: capture.capture_id.clone(),
+                        payment_id: capture.payment_id.clone(),
+                        merchant_id: capture.merchant_id.clone(),
+                        status: capture.status,
+                        amount: capture.amount,
+                        currency: capture.currency,
+                        connector: capture.connector.clone(),
+                        error_message: capture.error_message.clone(),
+                        error_code: capture.error_code.clone(),
+                        error_reason: capture.error_reason.clone(),
+                        tax_amount: capture.tax_amount,
+                        created_at: capture.created_at,
+                        modified_at: capture.modified_at,
+                        authorized_attempt_id: capture.authorized_attempt_id.clone(),
+                        capture_sequence: capture.capture_sequence,
+                        connector_capture_id: capture.connector_capture_id.clone(),
+                        connector_response_reference_id: capture
+                            .connector_response_reference_id
+                            .clone(),
+                    };
+
+                    match self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_hash_field_if_not_exist(&key, &field, &created_capture)
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "capture",
+                            key: Some(created_capture.capture_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::Capture(capture),
+                                },
+                            };
+                            self.push_to_drainer_stream::<Capture>(
+                                redis_entry,
+                                PartitionKey::MerchantIdPaymentId {
+                                    merchant_id: &created_capture.merchant_id,
+                                    payment_id: &created_capture.payment_id,
+                                },
+                            )
+                            .await
+                            .change_context(errors::StorageError::KVError)?;
+                            Ok(created_capture)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
         }
 
         async fn update_capture_with_capture_id(
             &self,
             this: Capture,
             capture: CaptureUpdate,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Capture, errors::StorageError> {
-            let db_call = || async {
-                let conn = connection::pg_connection_write(self).await?;
-                this.update_with_capture_id(&conn, capture)
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update_with_capture_id(&conn, capture)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_capture_key_and_field(
+                        &this.merchant_id,
+                        &this.payment_id,
+                        &this.authorized_attempt_id,
+                        &this.capture_id,
+                    );
+                    let updated_capture = capture.clone().apply_changeset(this.clone());
+
+                    let redis_value =
+                        utils::Encode::<Capture>::encode_to_string_of_json(&updated_capture)
+                            .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::CaptureUpdate(kv::CaptureUpdateMems {
+                                orig: this,
+                                update_data: capture,
+                            }),
+                        },
+                    };
+                    self.push_to_drainer_stream::<Capture>(
+                        redis_entry,
+                        PartitionKey::MerchantIdPaymentId {
+                            merchant_id: &updated_capture.merchant_id,
+                            payment_id: &updated_capture.payment_id,
+                        },
+                    )
                     .await
-                    .map_err(Into::into)
-                    .into_report()
-            };
-            db_call().await
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_capture)
+                }
+            }
         }
 
         async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
@@ -81,7 +199,7 @@ mod storage {
             merchant_id: &str,
             payment_id: &str,
             authorized_attempt_id: &str,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<Capture>, errors::StorageError> {
             let db_call = || async {
                 let conn = connection::pg_connection_read(self).await?;
@@ -95,7 +213,27 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
             };
-            db_call().await
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => db_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{payment_id}");
+                    let pattern = format!("pa_{authorized_attempt_id}_capture_*");
+
+                    let mut captures: Vec<Capture> = self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .hscan_and_deserialize(&key, &pattern, None)
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    // Captures of payments whose Redis entries have expired are only in Postgres
+                    if captures.is_empty() {
+                        return db_call().await;
+                    }
+                    captures.sort_by_key(|capture| capture.created_at);
+                    Ok(captures)
+                }
+            }
         }
     }
 }
@@ -113,9 +113,14 @@ impl ConfigInterface for Store {
             .map_err(Into::into)
             .into_report()?;
 
-        self.get_redis_conn()
-            .map_err(Into::<errors::StorageError>::into)?
-            .publish(consts::PUB_SUB_CHANNEL, CacheKind::Config(key.into()))
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        redis_conn
+            .publish(
+                consts::PUB_SUB_CHANNEL,
+                CacheKind::Config(redis_conn.add_prefix(key).into()),
+            )
             .await
             .map_err(Into::<errors::StorageError>::into)?;
 
@@ -1,33 +1,31 @@
 use common_utils::ext_traits::AsyncExt;
-use error_stack::{IntoReport, ResultExt};
+use error_stack::ResultExt;
 use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
-    core::{
-        customers::REDACTED,
-        errors::{self, CustomResult},
-    },
+    core::errors::{self, CustomResult},
     types::{
         domain::{
             self,
             behaviour::{Conversion, ReverseConversion},
         },
-        storage,
+        storage::{self as storage_types, enums},
     },
 };
 
 #[async_trait::async_trait]
 pub trait CustomerInterface
 where
-    domain::Customer: Conversion<DstType = storage::Customer, NewDstType = storage::CustomerNew>,
+    domain::Customer:
+        Conversion<DstType = storage_types::Customer, NewDstType = storage_types::CustomerNew>,
 {
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError>;
 
     async fn find_customer_optional_by_customer_id_merchant_id(
@@ -35,14 +33,16 @@ where
         customer_id: &str,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Option<domain::Customer>, errors::StorageError>;
 
     async fn update_customer_by_customer_id_merchant_id(
         &self,
         customer_id: String,
         merchant_id: String,
-        customer: storage::CustomerUpdate,
+        customer: storage_types::CustomerUpdate,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
 
     async fn find_customer_by_customer_id_merchant_id(
@@ -50,136 +50,699 @@ where
         customer_id: &str,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
 
     async fn insert_customer(
         &self,
         customer_data: domain::Customer,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
-}
 
-#[async_trait::async_trait]
-impl CustomerInterface for Store {
-    async fn find_customer_optional_by_customer_id_merchant_id(
+    #[cfg(feature = "olap")]
+    async fn list_customers_by_merchant_id(
         &self,
-        customer_id: &str,
         merchant_id: &str,
+        constraints: storage_types::CustomerListConstraints,
         key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        let maybe_customer: Option<domain::Customer> =
-            storage::Customer::find_optional_by_customer_id_merchant_id(
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
+    async fn find_customers_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
+}
+
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use common_utils::ext_traits::AsyncExt;
+    use error_stack::{IntoReport, ResultExt};
+    use masking::PeekInterface;
+    use router_env::{instrument, tracing};
+
+    use super::CustomerInterface;
+    use crate::{
+        connection,
+        core::{
+            customers::REDACTED,
+            errors::{self, CustomResult},
+        },
+        services::Store,
+        types::{
+            domain::{
+                self,
+                behaviour::{Conversion, ReverseConversion},
+            },
+            storage::{self as storage_types, enums},
+        },
+    };
+
+    #[async_trait::async_trait]
+    impl CustomerInterface for Store {
+        async fn find_customer_optional_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let maybe_customer: Option<domain::Customer> =
+                storage_types::Customer::find_optional_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()?
+                .async_map(|c| async {
+                    c.convert(key_store)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+                .transpose()?;
+            maybe_customer.map_or(Ok(None), |customer| {
+                // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
+                // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+                match customer.name {
+                    Some(ref name) if name.peek() == REDACTED => {
+                        Err(errors::StorageError::CustomerRedacted)?
+                    }
+                    _ => Ok(Some(customer)),
+                }
+            })
+        }
+
+        #[instrument(skip_all)]
+        async fn update_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: String,
+            merchant_id: String,
+            customer: storage_types::CustomerUpdate,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let blind_index = customer
+                .blind_index(key_store.key.get_inner().peek())
+                .change_context(errors::StorageError::EncryptionError)?;
+            let customer_update = storage_types::CustomerUpdateInternal {
+                email_hash: blind_index.email_hash,
+                phone_hash: blind_index.phone_hash,
+                name_hash: blind_index.name_hash,
+                ..customer.into()
+            };
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Customer::update_by_customer_id_merchant_id(
                 &conn,
                 customer_id,
                 merchant_id,
+                customer_update,
             )
             .await
             .map_err(Into::into)
-            .into_report()?
-            .async_map(|c| async {
-                c.convert(key_store.key.get_inner())
+            .into_report()
+            .async_and_then(|c| async {
+                c.convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
             .await
-            .transpose()?;
-        maybe_customer.map_or(Ok(None), |customer| {
-            // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
-            // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
-            match customer.name {
-                Some(ref name) if name.peek() == REDACTED => {
-                    Err(errors::StorageError::CustomerRedacted)?
-                }
-                _ => Ok(Some(customer)),
-            }
-        })
-    }
+        }
 
-    #[instrument(skip_all)]
-    async fn update_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: String,
-        merchant_id: String,
-        customer: storage::CustomerUpdate,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Customer, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Customer::update_by_customer_id_merchant_id(
-            &conn,
-            customer_id,
-            merchant_id,
-            customer.into(),
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-        .async_and_then(|c| async {
-            c.convert(key_store.key.get_inner())
-                .await
-                .change_context(errors::StorageError::DecryptionError)
-        })
-        .await
-    }
-
-    async fn find_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Customer, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        let customer: domain::Customer =
-            storage::Customer::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+        async fn find_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let customer: domain::Customer =
+                storage_types::Customer::find_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
                 .await
                 .map_err(Into::into)
                 .into_report()
                 .async_and_then(|c| async {
-                    c.convert(key_store.key.get_inner())
+                    c.convert(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
                 .await?;
-        match customer.name {
-            Some(ref name) if name.peek() == REDACTED => {
-                Err(errors::StorageError::CustomerRedacted)?
+            match customer.name {
+                Some(ref name) if name.peek() == REDACTED => {
+                    Err(errors::StorageError::CustomerRedacted)?
+                }
+                _ => Ok(customer),
             }
-            _ => Ok(customer),
         }
-    }
 
-    async fn insert_customer(
-        &self,
-        customer_data: domain::Customer,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Customer, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        customer_data
-            .construct_new()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
+        async fn insert_customer(
+            &self,
+            customer_data: domain::Customer,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let blind_index = customer_data
+                .blind_index(key_store.key.get_inner().peek())
+                .change_context(errors::StorageError::EncryptionError)?;
+            let customer_new = customer_data
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?;
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::CustomerNew {
+                email_hash: blind_index.email_hash,
+                phone_hash: blind_index.phone_hash,
+                name_hash: blind_index.name_hash,
+                ..customer_new
+            }
             .insert(&conn)
             .await
             .map_err(Into::into)
             .into_report()
             .async_and_then(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
             .await
+        }
+
+        #[cfg(feature = "olap")]
+        #[instrument(skip_all)]
+        async fn list_customers_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            constraints: storage_types::CustomerListConstraints,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Customer::list_by_merchant_id(&conn, merchant_id, constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|customers| async {
+                    let mut output = Vec::with_capacity(customers.len());
+                    for customer in customers.into_iter() {
+                        output.push(
+                            customer
+                                .convert(key_store)
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
+        async fn find_customers_by_merchant_id_after_id(
+            &self,
+            merchant_id: &str,
+            id: i32,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Customer::find_by_merchant_id_after_id(&conn, merchant_id, id, limit)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|customers| async {
+                    let mut output = Vec::with_capacity(customers.len());
+                    for customer in customers.into_iter() {
+                        output.push(
+                            customer
+                                .convert(key_store)
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
+        async fn delete_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Customer::delete_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
+}
 
-    async fn delete_customer_by_customer_id_merchant_id(
-        &self,
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::ext_traits::AsyncExt;
+    use error_stack::{IntoReport, ResultExt};
+    use masking::PeekInterface;
+    use redis_interface::HsetnxReply;
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::RedisConnInterface;
+
+    use super::CustomerInterface;
+    use crate::{
+        connection,
+        core::{
+            customers::REDACTED,
+            errors::{self, CustomResult},
+        },
+        services::Store,
+        types::{
+            domain::{
+                self,
+                behaviour::{Conversion, ReverseConversion},
+            },
+            storage::{self as storage_types, enums, kv},
+        },
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    /// Redis hash key and field a customer is stored at in KV mode
+    fn get_customer_key_and_field(merchant_id: &str, customer_id: &str) -> (String, String) {
+        (
+            format!("mid_{merchant_id}_cust_{customer_id}"),
+            format!("cust_{customer_id}"),
+        )
+    }
+
+    /// Written to the hash field of a customer deleted in KV mode, so that reads do not fall back
+    /// to the database row that is yet to be drained. Deserializes to `None`.
+    const CUSTOMER_TOMBSTONE: &str = "null";
+
+    async fn find_optional_storage_customer(
+        store: &Store,
         customer_id: &str,
         merchant_id: &str,
-    ) -> CustomResult<bool, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Customer>, errors::StorageError> {
+        let database_call = || async {
+            let conn = connection::pg_connection_read(store).await?;
+            storage_types::Customer::find_optional_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        };
+        match storage_scheme {
+            enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+            enums::MerchantStorageScheme::RedisKv => {
+                let (key, field) = get_customer_key_and_field(merchant_id, customer_id);
+                let redis_conn = store
+                    .get_redis_conn()
+                    .map_err(Into::<errors::StorageError>::into)?;
+                db_utils::try_redis_get_else_try_database_get(
+                    async {
+                        redis_conn
+                            .get_hash_field_and_deserialize::<Option<storage_types::Customer>>(
+                                &key, &field, "Customer",
+                            )
+                            .await
+                    },
+                    database_call,
+                )
+                .await
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CustomerInterface for Store {
+        async fn find_customer_optional_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
+            let maybe_customer: Option<domain::Customer> =
+                find_optional_storage_customer(self, customer_id, merchant_id, storage_scheme)
+                    .await?
+                    .async_map(|c| async {
+                        c.convert(key_store)
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)
+                    })
+                    .await
+                    .transpose()?;
+            maybe_customer.map_or(Ok(None), |customer| match customer.name {
+                Some(ref name) if name.peek() == REDACTED => {
+                    Err(errors::StorageError::CustomerRedacted)?
+                }
+                _ => Ok(Some(customer)),
+            })
+        }
+
+        #[instrument(skip_all)]
+        async fn update_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: String,
+            merchant_id: String,
+            customer: storage_types::CustomerUpdate,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let blind_index = customer
+                .blind_index(key_store.key.get_inner().peek())
+                .change_context(errors::StorageError::EncryptionError)?;
+            let customer_update = storage_types::CustomerUpdateInternal {
+                email_hash: blind_index.email_hash,
+                phone_hash: blind_index.phone_hash,
+                name_hash: blind_index.name_hash,
+                ..customer.into()
+            };
+            let updated_customer = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::Customer::update_by_customer_id_merchant_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                        customer_update,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()?
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let customer = find_optional_storage_customer(
+                        self,
+                        &customer_id,
+                        &merchant_id,
+                        storage_scheme,
+                    )
+                    .await?
+                    .ok_or(errors::StorageError::ValueNotFound(format!(
+                        "Customer with customer_id = {customer_id} not found"
+                    )))
+                    .into_report()?;
+                    let updated_customer =
+                        customer_update.clone().apply_changeset(customer.clone());
+                    let (key, field) = get_customer_key_and_field(&merchant_id, &customer_id);
+
+                    let redis_value =
+                        utils::Encode::<storage_types::Customer>::encode_to_string_of_json(
+                            &updated_customer,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::CustomerUpdate(kv::CustomerUpdateMems {
+                                orig: customer,
+                                update_data: customer_update,
+                            }),
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::Customer>(
+                        redis_entry,
+                        PartitionKey::CombinationKey { combination: &key },
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?;
+
+                    updated_customer
+                }
+            };
+
+            updated_customer
+                .convert(key_store)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        async fn find_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let customer: domain::Customer =
+                find_optional_storage_customer(self, customer_id, merchant_id, storage_scheme)
+                    .await?
+                    .ok_or(errors::StorageError::ValueNotFound(format!(
+                        "Customer with customer_id = {customer_id} not found"
+                    )))
+                    .into_report()?
+                    .convert(key_store)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?;
+            match customer.name {
+                Some(ref name) if name.peek() == REDACTED => {
+                    Err(errors::StorageError::CustomerRedacted)?
+                }
+                _ => Ok(customer),
+            }
+        }
+
+        async fn insert_customer(
+            &self,
+            customer_data: domain::Customer,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let blind_index = customer_data
+                .blind_index(key_store.key.get_inner().peek())
+                .change_context(errors::StorageError::EncryptionError)?;
+            let customer_new = storage_types::CustomerNew {
+                email_hash: blind_index.email_hash,
+                phone_hash: blind_index.phone_hash,
+                name_hash: blind_index.name_hash,
+                ..customer_data
+                    .construct_new()
+                    .await
+                    .change_context(errors::StorageError::EncryptionError)?
+            };
+
+            let created_customer = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    customer_new
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()?
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_customer_key_and_field(
+                        &customer_new.merchant_id,
+                        &customer_new.customer_id,
+                    );
+                    let created_customer = storage_types::Customer {
+                        id: 0i32,
+                        customer_id: customer_new.customer_id.clone(),
+                        merchant_id: customer_new.merchant_id.clone(),
+                        name: customer_new.name.clone(),
+                        email: customer_new.email.clone(),
+                        phone: customer_new.phone.clone(),
+                        phone_country_code: customer_new.phone_country_code.clone(),
+                        description: customer_new.description.clone(),
+                        created_at: customer_new.created_at,
+                        metadata: customer_new.metadata.clone(),
+                        connector_customer: customer_new.connector_customer.clone(),
+                        modified_at: customer_new.modified_at,
+                        email_hash: customer_new.email_hash.clone(),
+                        phone_hash: customer_new.phone_hash.clone(),
+                        name_hash: customer_new.name_hash.clone(),
+                    };
+
+                    let redis_conn = self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?;
+                    let reply = match redis_conn
+                        .serialize_and_set_hash_field_if_not_exist(&key, &field, &created_customer)
+                        .await
+                    {
+                        // A customer deleted in KV mode leaves a tombstone behind, which is
+                        // replaced by the customer being created with the same id
+                        Ok(HsetnxReply::KeyNotSet) => {
+                            let existing_customer = redis_conn
+                                .get_hash_field_and_deserialize::<Option<storage_types::Customer>>(
+                                    &key, &field, "Customer",
+                                )
+                                .await
+                                .change_context(errors::StorageError::KVError)?;
+                            match existing_customer {
+                                Some(_) => Ok(HsetnxReply::KeyNotSet),
+                                None => {
+                                    let redis_value = utils::Encode::<storage_types::Customer>::encode_to_string_of_json(
+                                        &created_customer,
+                                    )
+                                    .change_context(errors::StorageError::SerializationFailed)?;
+                                    redis_conn
+                                        .set_hash_fields(&key, (&field, redis_value))
+                                        .await
+                                        .map(|_| HsetnxReply::KeySet)
+                                }
+                            }
+                        }
+                        reply => reply,
+                    };
+
+                    match reply {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "customer",
+                            key: Some(created_customer.customer_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::Customer(customer_new),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage_types::Customer>(
+                                redis_entry,
+                                PartitionKey::CombinationKey { combination: &key },
+                            )
+                            .await
+                            .change_context(errors::StorageError::KVError)?;
+                            Ok(created_customer)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }?
+                }
+            };
+
+            created_customer
+                .convert(key_store)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        // Customers are listed from the database alone, so customers of merchants on the KV
+        // storage scheme are listed only once they have been drained to the database
+        #[cfg(feature = "olap")]
+        #[instrument(skip_all)]
+        async fn list_customers_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            constraints: storage_types::CustomerListConstraints,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Customer::list_by_merchant_id(&conn, merchant_id, constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|customers| async {
+                    let mut output = Vec::with_capacity(customers.len());
+                    for customer in customers.into_iter() {
+                        output.push(
+                            customer
+                                .convert(key_store)
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
+        async fn find_customers_by_merchant_id_after_id(
+            &self,
+            merchant_id: &str,
+            id: i32,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Customer::find_by_merchant_id_after_id(&conn, merchant_id, id, limit)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|customers| async {
+                    let mut output = Vec::with_capacity(customers.len());
+                    for customer in customers.into_iter() {
+                        output.push(
+                            customer
+                                .convert(key_store)
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
+        async fn delete_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<bool, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::Customer::delete_by_customer_id_merchant_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_customer_key_and_field(merchant_id, customer_id);
+                    self.get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_hash_fields(&key, (&field, CUSTOMER_TOMBSTONE))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    // Pushed to the same partition as the insert of the customer, so that the
+                    // delete is drained after it
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Delete {
+                            deletable: kv::Deletable::Customer {
+                                customer_id: customer_id.to_owned(),
+                                merchant_id: merchant_id.to_owned(),
+                            },
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::Customer>(
+                        redis_entry,
+                        PartitionKey::CombinationKey { combination: &key },
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?;
+                    Ok(true)
+                }
+            }
+        }
     }
 }
 
@@ -191,6 +754,7 @@ impl CustomerInterface for MockDb {
         customer_id: &str,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
         let customers = self.customers.lock().await;
         let customer = customers
@@ -201,7 +765,7 @@ impl CustomerInterface for MockDb {
             .cloned();
         customer
             .async_map(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -214,8 +778,9 @@ impl CustomerInterface for MockDb {
         &self,
         _customer_id: String,
         _merchant_id: String,
-        _customer: storage::CustomerUpdate,
+        _customer: storage_types::CustomerUpdate,
         _key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -226,6 +791,7 @@ impl CustomerInterface for MockDb {
         _customer_id: &str,
         _merchant_id: &str,
         _key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -236,25 +802,115 @@ impl CustomerInterface for MockDb {
         &self,
         customer_data: domain::Customer,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         let mut customers = self.customers.lock().await;
 
-        let customer = Conversion::convert(customer_data)
-            .await
+        let blind_index = customer_data
+            .blind_index(key_store.key.get_inner().peek())
             .change_context(errors::StorageError::EncryptionError)?;
+        let customer = storage_types::Customer {
+            email_hash: blind_index.email_hash,
+            phone_hash: blind_index.phone_hash,
+            name_hash: blind_index.name_hash,
+            ..Conversion::convert(customer_data)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?
+        };
 
         customers.push(customer.clone());
 
         customer
-            .convert(key_store.key.get_inner())
+            .convert(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[cfg(feature = "olap")]
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage_types::CustomerListConstraints,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        fn matches_any(hashes: &Option<Vec<String>>, hash: &Option<String>) -> bool {
+            hashes.as_ref().map_or(true, |hashes| {
+                hash.as_ref().map_or(false, |hash| hashes.contains(hash))
+            })
+        }
+
+        let customers = self.customers.lock().await;
+        let filtered_customers = customers
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == merchant_id
+                    && matches_any(&constraints.email_hash, &customer.email_hash)
+                    && matches_any(&constraints.phone_hash, &customer.phone_hash)
+                    && matches_any(&constraints.name_hash, &customer.name_hash)
+                    && customer.name_hash.as_ref().map_or(true, |name_hash| {
+                        !constraints.redacted_name_hash.contains(name_hash)
+                    })
+            })
+            .rev()
+            .skip_while(|customer| {
+                constraints
+                    .starting_after
+                    .as_ref()
+                    .map_or(false, |starting_after| {
+                        &customer.customer_id != starting_after
+                    })
+            })
+            .skip(usize::from(constraints.starting_after.is_some()))
+            .take(usize::try_from(constraints.limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::with_capacity(filtered_customers.len());
+        for customer in filtered_customers.into_iter() {
+            output.push(
+                customer
+                    .convert(key_store)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
+
+    async fn find_customers_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let customers = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .filter(|customer| customer.merchant_id == merchant_id && customer.id > id)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::with_capacity(customers.len());
+        for customer in customers.into_iter() {
+            output.push(
+                customer
+                    .convert(key_store)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
+
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
         _customer_id: &str,
         _merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -218,9 +218,15 @@ impl DisputeInterface for MockDb {
         dispute_constraints: api_models::disputes::DisputeListConstraints,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
+        let starting_after = dispute_constraints.starting_after.as_ref();
 
         Ok(locked_disputes
             .iter()
+            .filter(|d| d.merchant_id == merchant_id)
+            .skip_while(|d| {
+                starting_after.map_or(false, |starting_after| &d.dispute_id != starting_after)
+            })
+            .skip(usize::from(starting_after.is_some()))
             .filter(|d| {
                 d.merchant_id == merchant_id
                     && dispute_constraints
@@ -553,6 +559,7 @@ mod tests {
                     "merchant_1",
                     DisputeListConstraints {
                         limit: None,
+                        starting_after: None,
                         dispute_status: None,
                         dispute_stage: None,
                         reason: None,
@@ -570,6 +577,39 @@ mod tests {
             assert_eq!(1, found_disputes.len());
 
             assert_eq!(created_dispute, found_disputes.get(0).unwrap().clone());
+
+            let next_dispute = mockdb
+                .insert_dispute(create_dispute_new(DisputeNewIds {
+                    dispute_id: "dispute_3".into(),
+                    attempt_id: "attempt_2".into(),
+                    merchant_id: "merchant_1".into(),
+                    payment_id: "payment_2".into(),
+                    connector_dispute_id: "connector_dispute_2".into(),
+                }))
+                .await
+                .unwrap();
+
+            let found_disputes = mockdb
+                .find_disputes_by_merchant_id(
+                    "merchant_1",
+                    DisputeListConstraints {
+                        limit: None,
+                        starting_after: Some("dispute_1".into()),
+                        dispute_status: None,
+                        dispute_stage: None,
+                        reason: None,
+                        connector: None,
+                        received_time: None,
+                        received_time_lt: None,
+                        received_time_gt: None,
+                        received_time_lte: None,
+                        received_time_gte: None,
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(vec![next_dispute], found_disputes);
         }
 
         #[tokio::test]
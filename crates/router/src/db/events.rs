@@ -13,6 +13,24 @@ pub trait EventInterface {
         &self,
         event: storage::EventNew,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        primary_object_id: Option<&str>,
+        starting_after_id: Option<i32>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
+    async fn find_events_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
     async fn update_event(
         &self,
         event_id: String,
@@ -29,6 +47,49 @@ impl EventInterface for Store {
         let conn = connection::pg_connection_write(self).await?;
         event.insert(&conn).await.map_err(Into::into).into_report()
     }
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        primary_object_id: Option<&str>,
+        starting_after_id: Option<i32>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            primary_object_id,
+            starting_after_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+    async fn find_events_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::find_by_merchant_id_after_id(&conn, merchant_id, id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
     async fn update_event(
         &self,
         event_id: String,
@@ -65,12 +126,76 @@ impl EventInterface for MockDb {
             primary_object_id: event.primary_object_id,
             primary_object_type: event.primary_object_type,
             created_at: now,
+            merchant_id: event.merchant_id,
         };
 
         locked_events.push(stored_event.clone());
 
         Ok(stored_event)
     }
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        locked_events
+            .iter()
+            .find(|event| event.event_id == event_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No event available with event_id = {event_id}"
+                ))
+                .into(),
+            )
+    }
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        primary_object_id: Option<&str>,
+        starting_after_id: Option<i32>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut events: Vec<storage::Event> = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id.as_deref() == Some(merchant_id)
+                    && primary_object_id
+                        .map_or(true, |object_id| event.primary_object_id == object_id)
+                    && starting_after_id
+                        .map_or(true, |starting_after_id| event.id < starting_after_id)
+            })
+            .cloned()
+            .collect();
+        events.sort_by(|a, b| b.id.cmp(&a.id));
+
+        Ok(events.into_iter().skip(offset).take(limit).collect())
+    }
+    async fn find_events_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        Ok(self
+            .events
+            .lock()
+            .await
+            .iter()
+            .filter(|event| event.merchant_id.as_deref() == Some(merchant_id) && event.id > id)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
     async fn update_event(
         &self,
         event_id: String,
@@ -122,6 +247,7 @@ mod tests {
                 intent_reference_id: Some("test".into()),
                 primary_object_id: "primary_object_tet".into(),
                 primary_object_type: enums::EventObjectType::PaymentDetails,
+                merchant_id: Some("merchant_1".into()),
             })
             .await
             .unwrap();
@@ -141,5 +267,50 @@ mod tests {
         assert!(updated_event.is_webhook_notified);
         assert_eq!(updated_event.primary_object_id, "primary_object_tet");
         assert_eq!(updated_event.id, 0);
+
+        let listed_events = mockdb
+            .list_events_by_merchant_id("merchant_1", Some("primary_object_tet"), None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(listed_events.len(), 1);
+        assert!(mockdb
+            .list_events_by_merchant_id("merchant_2", None, None, None, None)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let event2 = mockdb
+            .insert_event(storage::EventNew {
+                event_id: "test_event_id_2".into(),
+                event_type: enums::EventType::RefundSucceeded,
+                event_class: enums::EventClass::Refunds,
+                is_webhook_notified: false,
+                intent_reference_id: None,
+                primary_object_id: "primary_object_2".into(),
+                primary_object_type: enums::EventObjectType::RefundDetails,
+                merchant_id: Some("merchant_1".into()),
+            })
+            .await
+            .unwrap();
+
+        let listed_events = mockdb
+            .list_events_by_merchant_id("merchant_1", None, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            listed_events
+                .iter()
+                .map(|event| event.event_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["test_event_id_2", "test_event_id"]
+        );
+
+        let listed_events = mockdb
+            .list_events_by_merchant_id("merchant_1", None, Some(event2.id), None, None)
+            .await
+            .unwrap();
+        assert_eq!(listed_events.len(), 1);
+        assert_eq!(listed_events[0].event_id, "test_event_id");
     }
 }
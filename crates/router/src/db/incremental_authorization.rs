@@ -0,0 +1,157 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums as storage_enums},
+};
+
+#[async_trait::async_trait]
+pub trait IncrementalAuthorizationInterface {
+    async fn insert_incremental_authorization(
+        &self,
+        incremental_authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        incremental_authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncrementalAuthorizationInterface for Store {
+    async fn insert_incremental_authorization(
+        &self,
+        incremental_authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        incremental_authorization
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        incremental_authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_with_authorization_id(&conn, incremental_authorization)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncrementalAuthorization::find_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl IncrementalAuthorizationInterface for MockDb {
+    async fn insert_incremental_authorization(
+        &self,
+        incremental_authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let mut incremental_authorizations = self.incremental_authorizations.lock().await;
+
+        // Emulates the partial unique index allowing a single processing authorization per payment
+        if incremental_authorization.status == storage_enums::AuthorizationStatus::Processing
+            && incremental_authorizations.iter().any(|authorization| {
+                authorization.merchant_id == incremental_authorization.merchant_id
+                    && authorization.payment_id == incremental_authorization.payment_id
+                    && authorization.status == storage_enums::AuthorizationStatus::Processing
+            })
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "incremental_authorization",
+                key: Some(incremental_authorization.payment_id.clone()),
+            })?;
+        }
+
+        let now = common_utils::date_time::now();
+
+        let stored_authorization = storage::IncrementalAuthorization {
+            authorization_id: incremental_authorization.authorization_id,
+            merchant_id: incremental_authorization.merchant_id,
+            payment_id: incremental_authorization.payment_id,
+            amount: incremental_authorization.amount,
+            previously_authorized_amount: incremental_authorization.previously_authorized_amount,
+            status: incremental_authorization.status,
+            reason: incremental_authorization.reason,
+            error_code: incremental_authorization.error_code,
+            error_message: incremental_authorization.error_message,
+            connector_authorization_id: incremental_authorization.connector_authorization_id,
+            created_at: now,
+            modified_at: now,
+        };
+
+        incremental_authorizations.push(stored_authorization.clone());
+
+        Ok(stored_authorization)
+    }
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        incremental_authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let mut incremental_authorizations = self.incremental_authorizations.lock().await;
+
+        let stored_authorization = incremental_authorizations
+            .iter_mut()
+            .find(|authorization| {
+                authorization.authorization_id == this.authorization_id
+                    && authorization.merchant_id == this.merchant_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No incremental authorization available for authorization_id = {}",
+                this.authorization_id
+            )))
+            .into_report()?;
+
+        *stored_authorization = incremental_authorization.apply_changeset(this);
+
+        Ok(stored_authorization.clone())
+    }
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError> {
+        Ok(self
+            .incremental_authorizations
+            .lock()
+            .await
+            .iter()
+            .filter(|authorization| {
+                authorization.merchant_id == merchant_id && authorization.payment_id == payment_id
+            })
+            .cloned()
+            .collect())
+    }
+}
@@ -1,10 +1,9 @@
 use error_stack::{IntoReport, ResultExt};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::{errors, errors::CustomResult},
-    types::storage::{self, MandateDbExt},
+    types::storage::{self as storage_types, enums},
 };
 
 #[async_trait::async_trait]
@@ -13,94 +12,390 @@ pub trait MandateInterface {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 
+    // Mandates cannot be listed from Redis, so the lookups of several mandates below read from
+    // Postgres, and do not include mandates which have not been drained to Postgres yet
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         merchant_id: &str,
         customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError>;
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
 
     async fn update_mandate_by_merchant_id_mandate_id(
         &self,
         merchant_id: &str,
         mandate_id: &str,
-        mandate: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        mandate: storage_types::MandateUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 
     async fn find_mandates_by_merchant_id(
         &self,
         merchant_id: &str,
         mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError>;
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_count_of_mandates_by_merchant_id_customer_ids(
+        &self,
+        merchant_id: &str,
+        customer_ids: Vec<String>,
+    ) -> CustomResult<Vec<(String, i64)>, errors::StorageError>;
 
     async fn insert_mandate(
         &self,
-        mandate: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        mandate: storage_types::MandateNew,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl MandateInterface for Store {
-    async fn find_mandate_by_merchant_id_mandate_id(
-        &self,
-        merchant_id: &str,
-        mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
 
-    async fn find_mandate_by_merchant_id_customer_id(
-        &self,
-        merchant_id: &str,
-        customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+    use super::MandateInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, MandateDbExt},
+    };
+
+    #[async_trait::async_trait]
+    impl MandateInterface for Store {
+        async fn find_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_mandate_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            mandate: storage_types::MandateUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Mandate::update_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                mandate,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn update_mandate_by_merchant_id_mandate_id(
-        &self,
-        merchant_id: &str,
-        mandate_id: &str,
-        mandate: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Mandate::update_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id, mandate)
+        async fn find_mandates_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            mandate_constraints: api_models::mandates::MandateListConstraints,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn get_count_of_mandates_by_merchant_id_customer_ids(
+            &self,
+            merchant_id: &str,
+            customer_ids: Vec<String>,
+        ) -> CustomResult<Vec<(String, i64)>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::get_count_by_merchant_id_customer_ids(
+                &conn,
+                merchant_id,
+                customer_ids,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
+
+        async fn insert_mandate(
+            &self,
+            mandate: storage_types::MandateNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            mandate
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
+}
 
-    async fn find_mandates_by_merchant_id(
-        &self,
-        merchant_id: &str,
-        mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
-            .await
-            .map_err(Into::into)
-            .into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::RedisConnInterface;
+
+    use super::MandateInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, kv, MandateDbExt},
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    /// Redis hash key and field a mandate is stored at in KV mode
+    fn get_mandate_key_and_field(merchant_id: &str, mandate_id: &str) -> (String, String) {
+        (
+            format!("mid_{merchant_id}_mandate_{mandate_id}"),
+            format!("mandate_{mandate_id}"),
+        )
     }
 
-    async fn insert_mandate(
-        &self,
-        mandate: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        mandate
-            .insert(&conn)
+    #[async_trait::async_trait]
+    impl MandateInterface for Store {
+        async fn find_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Mandate::find_by_merchant_id_mandate_id(
+                    &conn,
+                    merchant_id,
+                    mandate_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_mandate_key_and_field(merchant_id, mandate_id);
+                    db_utils::try_redis_get_else_try_database_get(
+                        self.get_redis_conn()
+                            .map_err(Into::<errors::StorageError>::into)?
+                            .get_hash_field_and_deserialize(&key, &field, "Mandate"),
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn find_mandate_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        #[instrument(skip_all)]
+        async fn update_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            mandate: storage_types::MandateUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::Mandate::update_by_merchant_id_mandate_id(
+                        &conn,
+                        merchant_id,
+                        mandate_id,
+                        mandate,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let orig = self
+                        .find_mandate_by_merchant_id_mandate_id(
+                            merchant_id,
+                            mandate_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    let updated_mandate = mandate.clone().apply_changeset(orig.clone());
+                    let (key, field) = get_mandate_key_and_field(merchant_id, mandate_id);
+
+                    let redis_value =
+                        utils::Encode::<storage_types::Mandate>::encode_to_string_of_json(
+                            &updated_mandate,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::MandateUpdate(kv::MandateUpdateMems {
+                                orig,
+                                update_data: mandate,
+                            }),
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::Mandate>(
+                        redis_entry,
+                        PartitionKey::CombinationKey { combination: &key },
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_mandate)
+                }
+            }
+        }
+
+        async fn find_mandates_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            mandate_constraints: api_models::mandates::MandateListConstraints,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn get_count_of_mandates_by_merchant_id_customer_ids(
+            &self,
+            merchant_id: &str,
+            customer_ids: Vec<String>,
+        ) -> CustomResult<Vec<(String, i64)>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::get_count_by_merchant_id_customer_ids(
+                &conn,
+                merchant_id,
+                customer_ids,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
+
+        #[instrument(skip_all)]
+        async fn insert_mandate(
+            &self,
+            mut mandate: storage_types::MandateNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    mandate
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    // The creation time is set here so that the copy in Redis and the row
+                    // drained to Postgres agree on it
+                    let created_at = mandate
+                        .created_at
+                        .unwrap_or_else(common_utils::date_time::now);
+                    mandate.created_at = Some(created_at);
+
+                    let (key, field) =
+                        get_mandate_key_and_field(&mandate.merchant_id, &mandate.mandate_id);
+                    let created_mandate = storage_types::Mandate {
+                        id: 0i32,
+                        mandate_id: mandate.mandate_id.clone(),
+                        customer_id: mandate.customer_id.clone(),
+                        merchant_id: mandate.merchant_id.clone(),
+                        payment_method_id: mandate.payment_method_id.clone(),
+                        mandate_status: mandate.mandate_status,
+                        mandate_type: mandate.mandate_type,
+                        customer_accepted_at: mandate.customer_accepted_at,
+                        customer_ip_address: mandate.customer_ip_address.clone(),
+                        customer_user_agent: mandate.customer_user_agent.clone(),
+                        network_transaction_id: mandate.network_transaction_id.clone(),
+                        previous_attempt_id: mandate.previous_attempt_id.clone(),
+                        created_at,
+                        mandate_amount: mandate.mandate_amount,
+                        mandate_currency: mandate.mandate_currency,
+                        amount_captured: mandate.amount_captured,
+                        connector: mandate.connector.clone(),
+                        connector_mandate_id: mandate.connector_mandate_id.clone(),
+                        start_date: mandate.start_date,
+                        end_date: mandate.end_date,
+                        metadata: mandate.metadata.clone(),
+                        connector_mandate_ids: mandate.connector_mandate_ids.clone(),
+                    };
+
+                    match self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_hash_field_if_not_exist(&key, &field, &created_mandate)
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "mandate",
+                            key: Some(created_mandate.mandate_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::Mandate(mandate),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage_types::Mandate>(
+                                redis_entry,
+                                PartitionKey::CombinationKey { combination: &key },
+                            )
+                            .await
+                            .change_context(errors::StorageError::KVError)?;
+                            Ok(created_mandate)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -110,7 +405,8 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         self.mandates
             .lock()
             .await
@@ -125,7 +421,7 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
         return Ok(self
             .mandates
             .lock()
@@ -142,8 +438,9 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-        mandate_update: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        mandate_update: storage_types::MandateUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         let mut mandates = self.mandates.lock().await;
         match mandates
             .iter_mut()
@@ -151,13 +448,13 @@ impl MandateInterface for MockDb {
         {
             Some(mandate) => {
                 match mandate_update {
-                    storage::MandateUpdate::StatusUpdate { mandate_status } => {
+                    storage_types::MandateUpdate::StatusUpdate { mandate_status } => {
                         mandate.mandate_status = mandate_status;
                     }
-                    storage::MandateUpdate::CaptureAmountUpdate { amount_captured } => {
+                    storage_types::MandateUpdate::CaptureAmountUpdate { amount_captured } => {
                         mandate.amount_captured = amount_captured;
                     }
-                    storage::MandateUpdate::ConnectorReferenceUpdate {
+                    storage_types::MandateUpdate::ConnectorReferenceUpdate {
                         connector_mandate_ids,
                     } => {
                         mandate.connector_mandate_ids = connector_mandate_ids;
@@ -175,7 +472,7 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
         let mandates = self.mandates.lock().await;
         let mandates_iter = mandates.iter().filter(|mandate| {
             let mut checker = mandate.merchant_id == merchant_id;
@@ -203,7 +500,7 @@ impl MandateInterface for MockDb {
             checker
         });
 
-        let mandates: Vec<storage::Mandate> = if let Some(limit) = mandate_constraints.limit {
+        let mandates: Vec<storage_types::Mandate> = if let Some(limit) = mandate_constraints.limit {
             #[allow(clippy::as_conversions)]
             mandates_iter
                 .take((if limit < 0 { 0 } else { limit }) as usize)
@@ -215,12 +512,34 @@ impl MandateInterface for MockDb {
         Ok(mandates)
     }
 
+    #[cfg(feature = "olap")]
+    async fn get_count_of_mandates_by_merchant_id_customer_ids(
+        &self,
+        merchant_id: &str,
+        customer_ids: Vec<String>,
+    ) -> CustomResult<Vec<(String, i64)>, errors::StorageError> {
+        let mandates = self.mandates.lock().await;
+        Ok(customer_ids
+            .into_iter()
+            .filter_map(|customer_id| {
+                let count = mandates
+                    .iter()
+                    .filter(|mandate| {
+                        mandate.merchant_id == merchant_id && mandate.customer_id == customer_id
+                    })
+                    .count();
+                (count > 0).then(|| (customer_id, i64::try_from(count).unwrap_or(i64::MAX)))
+            })
+            .collect())
+    }
+
     async fn insert_mandate(
         &self,
-        mandate_new: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        mandate_new: storage_types::MandateNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         let mut mandates = self.mandates.lock().await;
-        let mandate = storage::Mandate {
+        let mandate = storage_types::Mandate {
             id: mandates
                 .len()
                 .try_into()
@@ -77,7 +77,7 @@ impl MerchantAccountInterface for Store {
             .await
             .map_err(Into::into)
             .into_report()?
-            .convert(merchant_key_store.key.get_inner())
+            .convert(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -99,7 +99,7 @@ impl MerchantAccountInterface for Store {
         {
             fetch_func()
                 .await?
-                .convert(merchant_key_store.key.get_inner())
+                .convert(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -108,7 +108,7 @@ impl MerchantAccountInterface for Store {
         {
             super::cache::get_or_populate_in_memory(self, merchant_id, fetch_func, &ACCOUNTS_CACHE)
                 .await?
-                .convert(merchant_key_store.key.get_inner())
+                .convert(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -131,7 +131,7 @@ impl MerchantAccountInterface for Store {
                 .map_err(Into::into)
                 .into_report()
                 .async_and_then(|item| async {
-                    item.convert(merchant_key_store.key.get_inner())
+                    item.convert(merchant_key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -171,7 +171,7 @@ impl MerchantAccountInterface for Store {
             .map_err(Into::into)
             .into_report()
             .async_and_then(|item| async {
-                item.convert(merchant_key_store.key.get_inner())
+                item.convert(merchant_key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -212,10 +212,11 @@ impl MerchantAccountInterface for Store {
 
         Ok(authentication::AuthenticationData {
             merchant_account: merchant
-                .convert(key_store.key.get_inner())
+                .convert(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?,
             key_store,
+            api_key_id: None,
         })
     }
 
@@ -270,7 +271,7 @@ impl MerchantAccountInterface for MockDb {
         accounts.push(account.clone());
 
         account
-            .convert(merchant_key_store.key.get_inner())
+            .convert(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -287,7 +288,7 @@ impl MerchantAccountInterface for MockDb {
             .find(|account| account.merchant_id == merchant_id)
             .cloned()
             .async_map(|a| async {
-                a.convert(merchant_key_store.key.get_inner())
+                a.convert(merchant_key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -190,7 +190,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert(key_store)
                 .await
                 .change_context(errors::StorageError::DeserializationFailed)
         }
@@ -205,7 +205,7 @@ impl MerchantConnectorAccountInterface for Store {
             )
             .await
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -235,7 +235,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert(key_store)
                 .await
                 .change_context(errors::StorageError::DeserializationFailed)
         }
@@ -250,7 +250,7 @@ impl MerchantConnectorAccountInterface for Store {
             )
             .await
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -295,7 +295,7 @@ impl MerchantConnectorAccountInterface for Store {
             Ordering::Equal => match mca_list.first() {
                 Some(mca) => mca
                     .to_owned()
-                    .convert(key_store.key.get_inner())
+                    .convert(key_store)
                     .await
                     .change_context(errors::StorageError::DeserializationFailed),
                 None => Err(
@@ -321,7 +321,7 @@ impl MerchantConnectorAccountInterface for Store {
         .map_err(Into::into)
         .into_report()
         .async_and_then(|item| async {
-            item.convert(key_store.key.get_inner())
+            item.convert(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         })
@@ -342,7 +342,7 @@ impl MerchantConnectorAccountInterface for Store {
             .map_err(Into::into)
             .into_report()
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -364,7 +364,7 @@ impl MerchantConnectorAccountInterface for Store {
                 let mut output = Vec::with_capacity(items.len());
                 for item in items.into_iter() {
                     output.push(
-                        item.convert(key_store.key.get_inner())
+                        item.convert(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -398,7 +398,7 @@ impl MerchantConnectorAccountInterface for Store {
                 .map_err(Into::into)
                 .into_report()
                 .async_and_then(|item| async {
-                    item.convert(key_store.key.get_inner())
+                    item.convert(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -493,7 +493,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .cloned()
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -544,7 +544,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             Ordering::Equal => match mca_list.first() {
                 Some(mca) => mca
                     .to_owned()
-                    .convert(key_store.key.get_inner())
+                    .convert(key_store)
                     .await
                     .change_context(errors::StorageError::DeserializationFailed),
                 None => Err(
@@ -574,7 +574,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         match maybe_mca {
             Some(mca) => mca
                 .to_owned()
-                .convert(key_store.key.get_inner())
+                .convert(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -602,7 +602,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .cloned()
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -650,10 +650,11 @@ impl MerchantConnectorAccountInterface for MockDb {
             connector_webhook_details: t.connector_webhook_details,
             profile_id: t.profile_id,
             applepay_verified_domains: t.applepay_verified_domains,
+            connector_wallets_details: t.connector_wallets_details.map(Into::into),
         };
         accounts.push(account.clone());
         account
-            .convert(key_store.key.get_inner())
+            .convert(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -683,7 +684,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         for account in accounts.into_iter() {
             output.push(
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)?,
             )
@@ -711,7 +712,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             })
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -803,11 +804,13 @@ mod merchant_connector_account_cache_tests {
                 merchant_id: merchant_id.into(),
                 key: domain::types::encrypt(
                     services::generate_aes256_key().unwrap().to_vec().into(),
-                    master_key,
+                    domain::types::EncryptionKey::unversioned(master_key).unwrap(),
                 )
                 .await
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                key_version: domain::types::INITIAL_KEY_VERSION,
+                previous_keys: Default::default(),
             },
             &master_key.to_vec().into(),
         )
@@ -825,7 +828,7 @@ mod merchant_connector_account_cache_tests {
             connector_name: "stripe".to_string(),
             connector_account_details: domain::types::encrypt(
                 serde_json::Value::default().into(),
-                merchant_key.key.get_inner().peek(),
+                merchant_key.encryption_key(),
             )
             .await
             .unwrap(),
@@ -845,6 +848,7 @@ mod merchant_connector_account_cache_tests {
             connector_webhook_details: None,
             profile_id: Some(profile_id.to_string()),
             applepay_verified_domains: None,
+            connector_wallets_details: None,
         };
 
         db.insert_merchant_connector_account(mca.clone(), &merchant_key)
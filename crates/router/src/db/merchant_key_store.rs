@@ -1,16 +1,27 @@
+use std::collections::HashMap;
+
 use error_stack::{IntoReport, ResultExt};
+#[cfg(feature = "kms")]
+use masking::PeekInterface;
 use masking::Secret;
+use redis_interface::errors::RedisError;
+#[cfg(feature = "kms")]
+use router_env::logger;
 #[cfg(feature = "accounts_cache")]
-use storage_impl::redis::cache::ACCOUNTS_CACHE;
+use storage_impl::redis::cache::CacheKind;
+use storage_impl::redis::{cache::ACCOUNTS_CACHE, kv_store::RedisConnInterface};
 
 use crate::{
     connection,
     core::errors::{self, CustomResult},
     db::MockDb,
     services::Store,
-    types::domain::{
-        self,
-        behaviour::{Conversion, ReverseConversion},
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
     },
 };
 
@@ -27,6 +38,198 @@ pub trait MerchantKeyStoreInterface {
         merchant_id: &str,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
+
+    async fn update_merchant_key_store_by_merchant_id_key_version(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
+
+    async fn insert_merchant_key_version(
+        &self,
+        merchant_key_version: storage::MerchantKeyVersionNew,
+    ) -> CustomResult<storage::MerchantKeyVersion, errors::StorageError>;
+
+    async fn find_merchant_key_versions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantKeyVersion>, errors::StorageError>;
+}
+
+/// Decrypts the previous versions of the key of the key store.
+async fn get_previous_keys(
+    db: &dyn MerchantKeyStoreInterface,
+    merchant_key_store: &domain::MerchantKeyStore,
+    key: &Secret<Vec<u8>>,
+) -> CustomResult<HashMap<i32, Secret<Vec<u8>>>, errors::StorageError> {
+    let mut previous_keys = HashMap::new();
+    for key_version in db
+        .find_merchant_key_versions_by_merchant_id(&merchant_key_store.merchant_id)
+        .await?
+    {
+        let previous_key = domain::types::decrypt_with_key_version::<Vec<u8>, masking::WithType>(
+            key_version.key,
+            key,
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)?
+        .into_inner();
+        previous_keys.insert(key_version.key_version, previous_key);
+    }
+    Ok(previous_keys)
+}
+
+/// Adds the previous versions of the key to the key store, if the key was rotated.
+async fn with_previous_keys(
+    db: &dyn MerchantKeyStoreInterface,
+    mut merchant_key_store: domain::MerchantKeyStore,
+    key: &Secret<Vec<u8>>,
+) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+    if merchant_key_store.key_version == domain::types::INITIAL_KEY_VERSION {
+        return Ok(merchant_key_store);
+    }
+
+    merchant_key_store.previous_keys = get_previous_keys(db, &merchant_key_store, key).await?;
+    Ok(merchant_key_store)
+}
+
+/// Adds the previous versions of the key to the key store, if the key was rotated. The previous
+/// keys only change when the key is rotated again, so the decrypted keys are cached in memory for
+/// the current version of the key, instead of being fetched and decrypted on every request.
+async fn with_cached_previous_keys(
+    store: &Store,
+    mut merchant_key_store: domain::MerchantKeyStore,
+    key: &Secret<Vec<u8>>,
+) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+    if merchant_key_store.key_version == domain::types::INITIAL_KEY_VERSION {
+        return Ok(merchant_key_store);
+    }
+
+    let cache_key = store
+        .get_redis_conn()
+        .change_context(errors::StorageError::RedisError(
+            RedisError::RedisConnectionError.into(),
+        ))
+        .attach_printable("Failed to get redis connection")?
+        .add_prefix(&format!(
+            "merchant_previous_keys_{}_{}",
+            merchant_key_store.merchant_id, merchant_key_store.key_version
+        ));
+    let previous_keys = match ACCOUNTS_CACHE.get_val::<HashMap<i32, Secret<Vec<u8>>>>(&cache_key) {
+        Some(previous_keys) => previous_keys,
+        None => {
+            let previous_keys = get_previous_keys(store, &merchant_key_store, key).await?;
+            ACCOUNTS_CACHE.push(cache_key, previous_keys.clone()).await;
+            previous_keys
+        }
+    };
+
+    merchant_key_store.previous_keys = previous_keys;
+    Ok(merchant_key_store)
+}
+
+/// Wraps the master key encrypted data key of a merchant with the key manager, if the data keys of
+/// merchants are wrapped.
+#[cfg(feature = "kms")]
+async fn wrap_key(
+    store: &Store,
+    key: diesel_models::encryption::Encryption,
+) -> CustomResult<diesel_models::encryption::Encryption, errors::StorageError> {
+    match store.key_manager() {
+        Some(key_manager) => key_manager
+            .key_manager()
+            .encrypt(key.get_inner().peek())
+            .await
+            .change_context(errors::StorageError::EncryptionError)
+            .attach_printable("Failed to wrap the data key with the key manager")
+            .map(|wrapped_key| diesel_models::encryption::Encryption::new(wrapped_key.into())),
+        None => Ok(key),
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+async fn wrap_key(
+    _store: &Store,
+    key: diesel_models::encryption::Encryption,
+) -> CustomResult<diesel_models::encryption::Encryption, errors::StorageError> {
+    Ok(key)
+}
+
+/// Unwraps a data key wrapped with the key manager, returning the master key encrypted data key
+/// and whether the stored key was not wrapped with the key manager yet. Data keys stored before
+/// the data keys of merchants were wrapped are only encrypted with the master key.
+#[cfg(feature = "kms")]
+async fn unwrap_key(
+    store: &Store,
+    key: diesel_models::encryption::Encryption,
+) -> (diesel_models::encryption::Encryption, bool) {
+    let key_manager = match store.key_manager() {
+        Some(key_manager) => key_manager,
+        None => return (key, false),
+    };
+
+    match key_manager
+        .key_manager()
+        .decrypt(key.get_inner().peek())
+        .await
+    {
+        Ok(unwrapped_key) => (
+            diesel_models::encryption::Encryption::new(unwrapped_key.into()),
+            false,
+        ),
+        Err(error) => {
+            logger::warn!(?error, "Data key is not wrapped with the key manager");
+            (key, true)
+        }
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+async fn unwrap_key(
+    _store: &Store,
+    key: diesel_models::encryption::Encryption,
+) -> (diesel_models::encryption::Encryption, bool) {
+    (key, false)
+}
+
+/// Unwraps the data key of a merchant key store read from the database. A key store which is not
+/// wrapped with the key manager yet is wrapped in place, once its key is verified to be encrypted
+/// with the master key.
+async fn unwrap_merchant_key_store(
+    store: &Store,
+    mut merchant_key_store: storage::MerchantKeyStore,
+    key: &Secret<Vec<u8>>,
+) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+    let (unwrapped_key, is_not_wrapped) = unwrap_key(store, merchant_key_store.key).await;
+    merchant_key_store.key = unwrapped_key;
+
+    let is_encrypted_with_master_key = is_not_wrapped
+        && domain::types::decrypt_with_key_version::<Vec<u8>, masking::WithType>(
+            merchant_key_store.key.clone(),
+            key,
+        )
+        .await
+        .is_ok();
+    if is_encrypted_with_master_key {
+        let conn = connection::pg_connection_write(store).await?;
+        storage::MerchantKeyStore::update_by_merchant_id_key_version(
+            &conn,
+            &merchant_key_store.merchant_id,
+            merchant_key_store.key_version,
+            storage::MerchantKeyStoreUpdate::KeyRotated {
+                key: wrap_key(store, merchant_key_store.key.clone()).await?,
+                key_version: merchant_key_store.key_version,
+            },
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+        .attach_printable("Failed to wrap the data key with the key manager")?;
+    }
+
+    Ok(merchant_key_store)
 }
 
 #[async_trait::async_trait]
@@ -37,14 +240,20 @@ impl MerchantKeyStoreInterface for Store {
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
         let conn = connection::pg_connection_write(self).await?;
-        merchant_key_store
+        let mut merchant_key_store = merchant_key_store
             .construct_new()
             .await
-            .change_context(errors::StorageError::EncryptionError)?
+            .change_context(errors::StorageError::EncryptionError)?;
+        merchant_key_store.key = wrap_key(self, merchant_key_store.key).await?;
+
+        let mut merchant_key_store = merchant_key_store
             .insert(&conn)
             .await
             .map_err(Into::into)
-            .into_report()?
+            .into_report()?;
+        merchant_key_store.key = unwrap_key(self, merchant_key_store.key).await.0;
+
+        merchant_key_store
             .convert(key)
             .await
             .change_context(errors::StorageError::DecryptionError)
@@ -58,25 +267,25 @@ impl MerchantKeyStoreInterface for Store {
         let fetch_func = || async {
             let conn = connection::pg_connection_read(self).await?;
 
-            diesel_models::merchant_key_store::MerchantKeyStore::find_by_merchant_id(
-                &conn,
-                merchant_id,
-            )
-            .await
-            .map_err(Into::into)
-            .into_report()
+            let merchant_key_store =
+                diesel_models::merchant_key_store::MerchantKeyStore::find_by_merchant_id(
+                    &conn,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()?;
+            unwrap_merchant_key_store(self, merchant_key_store, key).await
         };
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            fetch_func()
-                .await?
-                .convert(key)
-                .await
-                .change_context(errors::StorageError::DecryptionError)
-        }
+        let merchant_key_store = fetch_func()
+            .await?
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
 
         #[cfg(feature = "accounts_cache")]
-        {
+        let merchant_key_store = {
             let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
             super::cache::get_or_populate_in_memory(
                 self,
@@ -87,8 +296,97 @@ impl MerchantKeyStoreInterface for Store {
             .await?
             .convert(key)
             .await
-            .change_context(errors::StorageError::DecryptionError)
+            .change_context(errors::StorageError::DecryptionError)?
+        };
+
+        with_cached_previous_keys(self, merchant_key_store, key).await
+    }
+
+    async fn update_merchant_key_store_by_merchant_id_key_version(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let merchant_key_store_update = match merchant_key_store_update {
+            storage::MerchantKeyStoreUpdate::KeyRotated { key, key_version } => {
+                storage::MerchantKeyStoreUpdate::KeyRotated {
+                    key: wrap_key(self, key).await?,
+                    key_version,
+                }
+            }
+        };
+        let update_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            let mut merchant_key_store =
+                storage::MerchantKeyStore::update_by_merchant_id_key_version(
+                    &conn,
+                    merchant_id,
+                    key_version,
+                    merchant_key_store_update,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()?;
+            merchant_key_store.key = unwrap_key(self, merchant_key_store.key).await.0;
+            Ok(merchant_key_store)
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        let merchant_key_store = update_func().await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let merchant_key_store = {
+            let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(key_store_cache_key.into()),
+                update_func,
+            )
+            .await?
+        };
+
+        let merchant_key_store = merchant_key_store
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
+        with_cached_previous_keys(self, merchant_key_store, key).await
+    }
+
+    async fn insert_merchant_key_version(
+        &self,
+        mut merchant_key_version: storage::MerchantKeyVersionNew,
+    ) -> CustomResult<storage::MerchantKeyVersion, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        merchant_key_version.key = wrap_key(self, merchant_key_version.key).await?;
+
+        let mut merchant_key_version = merchant_key_version
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+        merchant_key_version.key = unwrap_key(self, merchant_key_version.key).await.0;
+        Ok(merchant_key_version)
+    }
+
+    async fn find_merchant_key_versions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantKeyVersion>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let merchant_key_versions =
+            storage::MerchantKeyVersion::find_by_merchant_id(&conn, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()?;
+
+        let mut unwrapped_key_versions = Vec::with_capacity(merchant_key_versions.len());
+        for mut merchant_key_version in merchant_key_versions {
+            merchant_key_version.key = unwrap_key(self, merchant_key_version.key).await.0;
+            unwrapped_key_versions.push(merchant_key_version);
         }
+        Ok(unwrapped_key_versions)
     }
 }
 
@@ -127,7 +425,8 @@ impl MerchantKeyStoreInterface for MockDb {
         merchant_id: &str,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
-        self.merchant_key_store
+        let merchant_key_store = self
+            .merchant_key_store
             .lock()
             .await
             .iter()
@@ -138,12 +437,91 @@ impl MerchantKeyStoreInterface for MockDb {
             )))?
             .convert(key)
             .await
-            .change_context(errors::StorageError::DecryptionError)
+            .change_context(errors::StorageError::DecryptionError)?;
+
+        with_previous_keys(self, merchant_key_store, key).await
+    }
+
+    async fn update_merchant_key_store_by_merchant_id_key_version(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let merchant_key_store = {
+            let mut locked_merchant_key_store = self.merchant_key_store.lock().await;
+            let merchant_key_store = locked_merchant_key_store
+                .iter_mut()
+                .find(|merchant_key| {
+                    merchant_key.merchant_id == merchant_id
+                        && merchant_key.key_version == key_version
+                })
+                .ok_or(errors::StorageError::ValueNotFound(String::from(
+                    "merchant_key_store",
+                )))?;
+            let update = diesel_models::merchant_key_store::MerchantKeyStoreUpdateInternal::from(
+                merchant_key_store_update,
+            );
+            merchant_key_store.key = update.key;
+            merchant_key_store.key_version = update.key_version;
+            merchant_key_store.clone()
+        };
+
+        let merchant_key_store = merchant_key_store
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
+        with_previous_keys(self, merchant_key_store, key).await
+    }
+
+    async fn insert_merchant_key_version(
+        &self,
+        merchant_key_version: storage::MerchantKeyVersionNew,
+    ) -> CustomResult<storage::MerchantKeyVersion, errors::StorageError> {
+        let mut locked_merchant_key_versions = self.merchant_key_versions.lock().await;
+
+        if locked_merchant_key_versions.iter().any(|version| {
+            version.merchant_id == merchant_key_version.merchant_id
+                && version.key_version == merchant_key_version.key_version
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "merchant_key_versions",
+                key: Some(merchant_key_version.merchant_id.clone()),
+            })?;
+        }
+
+        let merchant_key_version = storage::MerchantKeyVersion {
+            merchant_id: merchant_key_version.merchant_id,
+            key_version: merchant_key_version.key_version,
+            key: merchant_key_version.key,
+            created_at: merchant_key_version.created_at,
+        };
+        locked_merchant_key_versions.push(merchant_key_version.clone());
+        Ok(merchant_key_version)
+    }
+
+    async fn find_merchant_key_versions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantKeyVersion>, errors::StorageError> {
+        let mut merchant_key_versions: Vec<_> = self
+            .merchant_key_versions
+            .lock()
+            .await
+            .iter()
+            .filter(|version| version.merchant_id == merchant_id)
+            .cloned()
+            .collect();
+        merchant_key_versions.sort_by_key(|version| version.key_version);
+        Ok(merchant_key_versions)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use time::macros::datetime;
 
     use crate::{
@@ -168,11 +546,13 @@ mod tests {
                     merchant_id: merchant_id.into(),
                     key: domain::types::encrypt(
                         services::generate_aes256_key().unwrap().to_vec().into(),
-                        master_key,
+                        domain::types::EncryptionKey::unversioned(master_key).unwrap(),
                     )
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: domain::types::INITIAL_KEY_VERSION,
+                    previous_keys: HashMap::new(),
                 },
                 &master_key.to_vec().into(),
             )
@@ -193,11 +573,13 @@ mod tests {
                     merchant_id: merchant_id.into(),
                     key: domain::types::encrypt(
                         services::generate_aes256_key().unwrap().to_vec().into(),
-                        master_key,
+                        domain::types::EncryptionKey::unversioned(master_key).unwrap(),
                     )
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: domain::types::INITIAL_KEY_VERSION,
+                    previous_keys: HashMap::new(),
                 },
                 &master_key.to_vec().into(),
             )
@@ -0,0 +1,99 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentLinkInterface {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn find_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for Store {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_link
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentLink::find_by_payment_link_id(&conn, payment_link_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for MockDb {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut payment_links = self.payment_links.lock().await;
+
+        if payment_links
+            .iter()
+            .any(|link| link.payment_link_id == payment_link.payment_link_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payment_link",
+                key: Some(payment_link.payment_link_id.clone()),
+            })?;
+        }
+
+        let now = common_utils::date_time::now();
+        let stored_link = storage::PaymentLink {
+            payment_link_id: payment_link.payment_link_id,
+            payment_id: payment_link.payment_id,
+            link_to_pay: payment_link.link_to_pay,
+            merchant_id: payment_link.merchant_id,
+            amount: payment_link.amount,
+            currency: payment_link.currency,
+            created_at: now,
+            last_modified_at: now,
+            fulfilment_time: payment_link.fulfilment_time,
+        };
+        payment_links.push(stored_link.clone());
+
+        Ok(stored_link)
+    }
+
+    async fn find_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        self.payment_links
+            .lock()
+            .await
+            .iter()
+            .find(|link| link.payment_link_id == payment_link_id)
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payment link available for payment_link_id = {payment_link_id}"
+            )))
+            .into_report()
+    }
+}
@@ -37,6 +37,20 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
         payment_method_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
+    async fn find_payment_methods_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_count_of_payment_methods_by_merchant_id_customer_ids(
+        &self,
+        merchant_id: &str,
+        customer_ids: Vec<String>,
+    ) -> CustomResult<Vec<(String, i64)>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -104,6 +118,36 @@ impl PaymentMethodInterface for Store {
         .map_err(Into::into)
         .into_report()
     }
+
+    async fn find_payment_methods_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_by_merchant_id_after_id(&conn, merchant_id, id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_count_of_payment_methods_by_merchant_id_customer_ids(
+        &self,
+        merchant_id: &str,
+        customer_ids: Vec<String>,
+    ) -> CustomResult<Vec<(String, i64)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::get_count_by_merchant_id_customer_ids(
+            &conn,
+            merchant_id,
+            customer_ids,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -232,4 +276,40 @@ impl PaymentMethodInterface for MockDb {
             .into()),
         }
     }
+
+    async fn find_payment_methods_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        Ok(self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|pm| pm.merchant_id == merchant_id && pm.id > id)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_count_of_payment_methods_by_merchant_id_customer_ids(
+        &self,
+        merchant_id: &str,
+        customer_ids: Vec<String>,
+    ) -> CustomResult<Vec<(String, i64)>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        Ok(customer_ids
+            .into_iter()
+            .filter_map(|customer_id| {
+                let count = payment_methods
+                    .iter()
+                    .filter(|pm| pm.merchant_id == merchant_id && pm.customer_id == customer_id)
+                    .count();
+                (count > 0).then(|| (customer_id, i64::try_from(count).unwrap_or(i64::MAX)))
+            })
+            .collect())
+    }
 }
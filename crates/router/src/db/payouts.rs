@@ -26,6 +26,27 @@ pub trait PayoutsInterface {
         &self,
         _payout: storage::PayoutsNew,
     ) -> CustomResult<storage::Payouts, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_filters_for_payouts(
+        &self,
+        _merchant_id: &str,
+        _time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -62,6 +83,57 @@ impl PayoutsInterface for Store {
         let conn = connection::pg_connection_write(self).await?;
         payout.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::filter_by_constraints(
+            &conn,
+            merchant_id,
+            constraints,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
+        &self,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::get_payouts_count(
+            &conn,
+            merchant_id,
+            constraints,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_filters_for_payouts(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::get_filters_for_payouts(
+            &conn,
+            merchant_id,
+            time_range,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -92,4 +164,34 @@ impl PayoutsInterface for MockDb {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<i64, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_filters_for_payouts(
+        &self,
+        _merchant_id: &str,
+        _time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -1,10 +1,10 @@
-use error_stack::IntoReport;
-
-use super::{cache, MockDb, Store};
+use super::{MockDb, Store};
 use crate::{
-    connection,
     errors::{self, CustomResult},
-    types::storage::reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    types::storage::{
+        enums,
+        reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    },
 };
 
 #[async_trait::async_trait]
@@ -12,6 +12,7 @@ pub trait ReverseLookupInterface {
     async fn insert_reverse_lookup(
         &self,
         _new: ReverseLookupNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<ReverseLookup, errors::StorageError>;
     async fn get_lookup_by_lookup_id(
         &self,
@@ -19,28 +20,139 @@ pub trait ReverseLookupInterface {
     ) -> CustomResult<ReverseLookup, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl ReverseLookupInterface for Store {
-    async fn insert_reverse_lookup(
-        &self,
-        new: ReverseLookupNew,
-    ) -> CustomResult<ReverseLookup, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        new.insert(&conn).await.map_err(Into::into).into_report()
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::{ReverseLookupInterface, Store};
+    use crate::{
+        connection,
+        db::cache,
+        errors::{self, CustomResult},
+        types::storage::{
+            enums,
+            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        },
+    };
+
+    #[async_trait::async_trait]
+    impl ReverseLookupInterface for Store {
+        async fn insert_reverse_lookup(
+            &self,
+            new: ReverseLookupNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            new.insert(&conn).await.map_err(Into::into).into_report()
+        }
+
+        async fn get_lookup_by_lookup_id(
+            &self,
+            id: &str,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                ReverseLookup::find_by_lookup_id(id, &conn)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            cache::get_or_populate_redis(self, id, database_call).await
+        }
     }
+}
 
-    async fn get_lookup_by_lookup_id(
-        &self,
-        id: &str,
-    ) -> CustomResult<ReverseLookup, errors::StorageError> {
-        let database_call = || async {
-            let conn = connection::pg_connection_read(self).await?;
-            ReverseLookup::find_by_lookup_id(id, &conn)
-                .await
-                .map_err(Into::into)
-                .into_report()
-        };
-        cache::get_or_populate_redis(self, id, database_call).await
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::SetnxReply;
+    use storage_impl::redis::kv_store::RedisConnInterface;
+
+    use super::{ReverseLookupInterface, Store};
+    use crate::{
+        connection,
+        db::cache,
+        errors::{self, CustomResult},
+        types::storage::{
+            enums, kv,
+            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        },
+        utils::storage_partitioning::PartitionKey,
+    };
+
+    #[async_trait::async_trait]
+    impl ReverseLookupInterface for Store {
+        async fn insert_reverse_lookup(
+            &self,
+            new: ReverseLookupNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    new.insert(&conn).await.map_err(Into::into).into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let created_rev_lookup = ReverseLookup::from(new.clone());
+                    let redis_value = serde_json::to_string(&created_rev_lookup)
+                        .into_report()
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    // Written under the same key that `get_lookup_by_lookup_id` caches lookups at
+                    match self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_key_if_not_exists_with_expiry(
+                            &created_rev_lookup.lookup_id,
+                            redis_value,
+                            None,
+                        )
+                        .await
+                    {
+                        Ok(SetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::ReverseLookUp(new),
+                                },
+                            };
+                            self.push_to_drainer_stream::<ReverseLookup>(
+                                redis_entry,
+                                PartitionKey::CombinationKey {
+                                    combination: &format!(
+                                        "reverse_lookup_{}",
+                                        &created_rev_lookup.lookup_id
+                                    ),
+                                },
+                            )
+                            .await
+                            .change_context(errors::StorageError::KVError)?;
+
+                            Ok(created_rev_lookup)
+                        }
+                        Ok(SetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "reverse_lookup",
+                            key: Some(created_rev_lookup.lookup_id),
+                        })
+                        .into_report(),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn get_lookup_by_lookup_id(
+            &self,
+            id: &str,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                ReverseLookup::find_by_lookup_id(id, &conn)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            cache::get_or_populate_redis(self, id, database_call).await
+        }
     }
 }
 
@@ -49,6 +161,7 @@ impl ReverseLookupInterface for MockDb {
     async fn insert_reverse_lookup(
         &self,
         new: ReverseLookupNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<ReverseLookup, errors::StorageError> {
         let reverse_lookup_insert = ReverseLookup::from(new);
         self.reverse_lookups
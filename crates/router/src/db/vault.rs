@@ -0,0 +1,479 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait VaultInterface {
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    async fn find_active_vault_data_key_optional(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError>;
+
+    async fn find_vault_data_key_by_merchant_id_key_version(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    /// Inserts `data_key` as the active data key of its merchant, deactivating the data key which
+    /// was active until now
+    async fn rotate_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    async fn insert_vault_card(
+        &self,
+        card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn find_vault_card_by_merchant_id_card_reference(
+        &self,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn find_vault_card_optional_by_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError>;
+
+    async fn find_vault_cards_by_merchant_id_key_version_not(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultCard>, errors::StorageError>;
+
+    async fn update_vault_card(
+        &self,
+        this: storage::VaultCard,
+        card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn delete_vault_card_by_merchant_id_card_reference(
+        &self,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn insert_vault_access_log(
+        &self,
+        access_log: storage::VaultAccessLogNew,
+    ) -> CustomResult<storage::VaultAccessLog, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultInterface for Store {
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data_key
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_active_vault_data_key_optional(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        // Read from the master database, so that a key that was just created or rotated is seen
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultDataKey::find_optional_active_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_vault_data_key_by_merchant_id_key_version(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultDataKey::find_by_merchant_id_key_version(&conn, merchant_id, key_version)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn rotate_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data_key
+            .insert_as_active(&conn, common_utils::date_time::now())
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_vault_card(
+        &self,
+        card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        card.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_vault_card_by_merchant_id_card_reference(
+        &self,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultCard::find_by_merchant_id_card_reference(&conn, merchant_id, card_reference)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_vault_card_optional_by_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultCard::find_optional_by_merchant_id_customer_id_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_vault_cards_by_merchant_id_key_version_not(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultCard>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultCard::find_by_merchant_id_key_version_not(
+            &conn,
+            merchant_id,
+            key_version,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_vault_card(
+        &self,
+        this: storage::VaultCard,
+        card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, card)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_vault_card_by_merchant_id_card_reference(
+        &self,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultCard::delete_by_merchant_id_card_reference(&conn, merchant_id, card_reference)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_vault_access_log(
+        &self,
+        access_log: storage::VaultAccessLogNew,
+    ) -> CustomResult<storage::VaultAccessLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        access_log
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultInterface for MockDb {
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let mut data_keys = self.vault_data_keys.lock().await;
+        if data_keys.iter().any(|key| {
+            key.merchant_id == data_key.merchant_id && key.key_version == data_key.key_version
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "vault data key",
+                key: Some(data_key.merchant_id.clone()),
+            })?
+        }
+
+        let data_key = storage::VaultDataKey {
+            id: data_keys
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            merchant_id: data_key.merchant_id,
+            key_version: data_key.key_version,
+            wrapped_key: data_key.wrapped_key,
+            is_active: data_key.is_active,
+            created_at: data_key.created_at,
+            rotated_at: None,
+        };
+        data_keys.push(data_key.clone());
+        Ok(data_key)
+    }
+
+    async fn find_active_vault_data_key_optional(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        Ok(self
+            .vault_data_keys
+            .lock()
+            .await
+            .iter()
+            .filter(|key| key.merchant_id == merchant_id && key.is_active)
+            .max_by_key(|key| key.key_version)
+            .cloned())
+    }
+
+    async fn find_vault_data_key_by_merchant_id_key_version(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        self.vault_data_keys
+            .lock()
+            .await
+            .iter()
+            .find(|key| key.merchant_id == merchant_id && key.key_version == key_version)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No vault data key found for merchant_id = {merchant_id} and key_version = {key_version}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn rotate_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let mut data_keys = self.vault_data_keys.lock().await;
+        if data_keys.iter().any(|key| {
+            key.merchant_id == data_key.merchant_id && key.key_version == data_key.key_version
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "vault data key",
+                key: Some(data_key.merchant_id.clone()),
+            })?
+        }
+
+        let rotated_at = common_utils::date_time::now();
+        for key in data_keys
+            .iter_mut()
+            .filter(|key| key.merchant_id == data_key.merchant_id && key.is_active)
+        {
+            key.is_active = false;
+            key.rotated_at = Some(rotated_at);
+        }
+
+        let data_key = storage::VaultDataKey {
+            id: data_keys
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            merchant_id: data_key.merchant_id,
+            key_version: data_key.key_version,
+            wrapped_key: data_key.wrapped_key,
+            is_active: data_key.is_active,
+            created_at: data_key.created_at,
+            rotated_at: None,
+        };
+        data_keys.push(data_key.clone());
+        Ok(data_key)
+    }
+
+    async fn insert_vault_card(
+        &self,
+        card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let mut cards = self.vault_cards.lock().await;
+        if cards.iter().any(|stored_card| {
+            stored_card.merchant_id == card.merchant_id
+                && stored_card.card_reference == card.card_reference
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "vault card",
+                key: Some(card.card_reference.clone()),
+            })?
+        }
+
+        let card = storage::VaultCard {
+            id: cards
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            card_reference: card.card_reference,
+            merchant_id: card.merchant_id,
+            customer_id: card.customer_id,
+            fingerprint: card.fingerprint,
+            key_version: card.key_version,
+            enc_data: card.enc_data,
+            created_at: card.created_at,
+            modified_at: card.modified_at,
+        };
+        cards.push(card.clone());
+        Ok(card)
+    }
+
+    async fn find_vault_card_by_merchant_id_card_reference(
+        &self,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        self.vault_cards
+            .lock()
+            .await
+            .iter()
+            .find(|card| card.merchant_id == merchant_id && card.card_reference == card_reference)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No card found in the vault for card_reference = {card_reference}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn find_vault_card_optional_by_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError> {
+        Ok(self
+            .vault_cards
+            .lock()
+            .await
+            .iter()
+            .find(|card| {
+                card.merchant_id == merchant_id
+                    && card.customer_id == customer_id
+                    && card.fingerprint == fingerprint
+            })
+            .cloned())
+    }
+
+    async fn find_vault_cards_by_merchant_id_key_version_not(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultCard>, errors::StorageError> {
+        Ok(self
+            .vault_cards
+            .lock()
+            .await
+            .iter()
+            .filter(|card| card.merchant_id == merchant_id && card.key_version != key_version)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    async fn update_vault_card(
+        &self,
+        this: storage::VaultCard,
+        card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let mut cards = self.vault_cards.lock().await;
+        let stored_card = cards
+            .iter_mut()
+            .find(|stored_card| {
+                stored_card.merchant_id == this.merchant_id
+                    && stored_card.card_reference == this.card_reference
+            })
+            .ok_or(errors::StorageError::MockDbError)?;
+        match card {
+            storage::VaultCardUpdate::EncryptedDataUpdate {
+                key_version,
+                enc_data,
+            } => {
+                stored_card.key_version = key_version;
+                stored_card.enc_data = enc_data;
+                stored_card.modified_at = common_utils::date_time::now();
+            }
+        }
+        Ok(stored_card.clone())
+    }
+
+    async fn delete_vault_card_by_merchant_id_card_reference(
+        &self,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let mut cards = self.vault_cards.lock().await;
+        let position = cards
+            .iter()
+            .position(|card| {
+                card.merchant_id == merchant_id && card.card_reference == card_reference
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No card found in the vault for card_reference = {card_reference}"
+            )))?;
+        Ok(cards.remove(position))
+    }
+
+    async fn insert_vault_access_log(
+        &self,
+        access_log: storage::VaultAccessLogNew,
+    ) -> CustomResult<storage::VaultAccessLog, errors::StorageError> {
+        let mut access_logs = self.vault_access_log.lock().await;
+        let access_log = storage::VaultAccessLog {
+            id: access_logs
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            merchant_id: access_log.merchant_id,
+            customer_id: access_log.customer_id,
+            card_reference: access_log.card_reference,
+            flow: access_log.flow,
+            created_at: access_log.created_at,
+        };
+        access_logs.push(access_log.clone());
+        Ok(access_log)
+    }
+}
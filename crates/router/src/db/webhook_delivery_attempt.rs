@@ -0,0 +1,222 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+/// Number of times a delivery attempt is numbered again, when a concurrent attempt of the same event
+/// was recorded with the same attempt number
+const MAX_ATTEMPT_NUMBERING_TRIES: u8 = 3;
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryAttemptInterface {
+    /// Records a delivery attempt, numbered after the attempts recorded for the event so far
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError>;
+
+    async fn find_webhook_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError>;
+
+    async fn find_webhook_delivery_attempts_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError>;
+
+    async fn update_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttempt,
+        delivery_attempt_update: storage::WebhookDeliveryAttemptUpdate,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryAttemptInterface for Store {
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        mut delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let mut remaining_tries = MAX_ATTEMPT_NUMBERING_TRIES;
+        loop {
+            delivery_attempt.attempt_number = storage::WebhookDeliveryAttempt::find_by_event_id(
+                &conn,
+                &delivery_attempt.event_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?
+            .iter()
+            .map(|attempt| attempt.attempt_number)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(1);
+
+            // The attempt number is unique for an event, so an attempt recorded concurrently with
+            // the same number is numbered again
+            match delivery_attempt.clone().insert(&conn).await {
+                Err(error)
+                    if remaining_tries > 1
+                        && matches!(
+                            error.current_context(),
+                            diesel_models::errors::DatabaseError::UniqueViolation
+                        ) =>
+                {
+                    remaining_tries -= 1;
+                }
+                result => return result.map_err(Into::into).into_report(),
+            }
+        }
+    }
+
+    async fn find_webhook_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookDeliveryAttempt::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_webhook_delivery_attempts_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookDeliveryAttempt::find_by_merchant_id_after_id(&conn, merchant_id, id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttempt,
+        delivery_attempt_update: storage::WebhookDeliveryAttemptUpdate,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        delivery_attempt
+            .update(&conn, delivery_attempt_update)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryAttemptInterface for MockDb {
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError> {
+        let mut locked_attempts = self.webhook_delivery_attempts.lock().await;
+        let attempt_number = locked_attempts
+            .iter()
+            .filter(|attempt| attempt.event_id == delivery_attempt.event_id)
+            .map(|attempt| attempt.attempt_number)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(1);
+
+        let stored_attempt = storage::WebhookDeliveryAttempt {
+            id: locked_attempts
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            event_id: delivery_attempt.event_id,
+            merchant_id: delivery_attempt.merchant_id,
+            attempt_number,
+            delivery_type: delivery_attempt.delivery_type,
+            is_delivered: delivery_attempt.is_delivered,
+            response_status_code: delivery_attempt.response_status_code,
+            latency_ms: delivery_attempt.latency_ms,
+            error_message: delivery_attempt.error_message,
+            created_at: common_utils::date_time::now(),
+            request_body: delivery_attempt.request_body,
+            request_signature: delivery_attempt.request_signature,
+            response_body: delivery_attempt.response_body,
+        };
+
+        locked_attempts.push(stored_attempt.clone());
+
+        Ok(stored_attempt)
+    }
+
+    async fn find_webhook_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError> {
+        let mut delivery_attempts: Vec<_> = self
+            .webhook_delivery_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|attempt| attempt.event_id == event_id)
+            .cloned()
+            .collect();
+        delivery_attempts.sort_by_key(|attempt| attempt.attempt_number);
+
+        Ok(delivery_attempts)
+    }
+
+    async fn find_webhook_delivery_attempts_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError> {
+        Ok(self
+            .webhook_delivery_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|attempt| attempt.merchant_id == merchant_id && attempt.id > id)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    async fn update_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttempt,
+        delivery_attempt_update: storage::WebhookDeliveryAttemptUpdate,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError> {
+        let mut locked_attempts = self.webhook_delivery_attempts.lock().await;
+        let attempt_to_update = locked_attempts
+            .iter_mut()
+            .find(|attempt| attempt.id == delivery_attempt.id)
+            .ok_or(errors::StorageError::ValueNotFound(
+                "cannot find webhook delivery attempt to update".to_string(),
+            ))
+            .into_report()?;
+
+        match delivery_attempt_update {
+            storage::WebhookDeliveryAttemptUpdate::BodiesUpdate {
+                request_body,
+                response_body,
+            } => {
+                if let Some(request_body) = request_body {
+                    attempt_to_update.request_body = Some(request_body);
+                }
+                if let Some(response_body) = response_body {
+                    attempt_to_update.response_body = Some(response_body);
+                }
+            }
+        }
+
+        Ok(attempt_to_update.clone())
+    }
+}
@@ -50,6 +50,8 @@ pub mod headers {
     pub const AUTHORIZATION: &str = "Authorization";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
+    pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
     pub const NONCE: &str = "nonce";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
@@ -65,6 +67,7 @@ pub mod headers {
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
+    pub const X_TENANT_ID: &str = "X-Tenant-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
 }
 
@@ -88,7 +91,8 @@ pub fn mk_app(
         InitError = (),
     >,
 > {
-    let mut server_app = get_application_builder(request_body_limit);
+    let mut server_app =
+        get_application_builder(request_body_limit, state.conf.multitenancy.clone());
 
     #[cfg(feature = "openapi")]
     {
@@ -127,7 +131,8 @@ pub fn mk_app(
         server_app = server_app
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::EphemeralKey::server(state.clone()))
-            .service(routes::Webhooks::server(state.clone()));
+            .service(routes::Webhooks::server(state.clone()))
+            .service(routes::PaymentLink::server(state.clone()));
     }
 
     #[cfg(feature = "olap")]
@@ -137,6 +142,9 @@ pub fn mk_app(
             .service(routes::ApiKeys::server(state.clone()))
             .service(routes::Files::server(state.clone()))
             .service(routes::Disputes::server(state.clone()))
+            .service(routes::WebhookEvents::server(state.clone()))
+            .service(routes::Blocklist::server(state.clone()))
+            .service(routes::AuditEvents::server(state.clone()))
     }
 
     #[cfg(all(feature = "olap", feature = "kms"))]
@@ -220,8 +228,18 @@ impl Stop for mpsc::Sender<()> {
     }
 }
 
+#[async_trait::async_trait]
+impl Stop for Vec<mpsc::Sender<()>> {
+    async fn stop_server(&mut self) {
+        for sender in self.iter_mut() {
+            sender.stop_server().await;
+        }
+    }
+}
+
 pub fn get_application_builder(
     request_body_limit: usize,
+    multitenancy: configs::settings::Multitenancy,
 ) -> actix_web::App<
     impl ServiceFactory<
         ServiceRequest,
@@ -248,6 +266,8 @@ pub fn get_application_builder(
         ))
         .wrap(middleware::default_response_headers())
         .wrap(middleware::RequestId)
+        .wrap(middleware::IdempotencyFingerprint::new(request_body_limit))
+        .wrap(middleware::TenantResolver::new(multitenancy))
         .wrap(cors::cors())
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
 }
@@ -60,6 +60,194 @@ where
     }
 }
 
+/// Middleware to fingerprint the raw body of requests carrying an idempotency key, before the body
+/// is consumed by the extractors of the route.
+pub struct IdempotencyFingerprint {
+    body_limit: usize,
+}
+
+impl IdempotencyFingerprint {
+    pub fn new(body_limit: usize) -> Self {
+        Self { body_limit }
+    }
+}
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for IdempotencyFingerprint
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = IdempotencyFingerprintMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(IdempotencyFingerprintMiddleware {
+            service: std::rc::Rc::new(service),
+            body_limit: self.body_limit,
+        }))
+    }
+}
+
+pub struct IdempotencyFingerprintMiddleware<S> {
+    service: std::rc::Rc<S>,
+    body_limit: usize,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for IdempotencyFingerprintMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use actix_web::HttpMessage;
+        use futures::StreamExt;
+
+        use crate::services::api::idempotency;
+
+        let service = self.service.clone();
+        let body_limit = self.body_limit;
+
+        Box::pin(async move {
+            let mut req = req;
+            if idempotency::is_idempotent_request(req.method(), req.headers()) {
+                let mut payload = req.take_payload();
+                let mut body = actix_web::web::BytesMut::new();
+                while let Some(chunk) = payload.next().await {
+                    let chunk = chunk?;
+                    if body.len() + chunk.len() > body_limit {
+                        return Err(actix_web::error::ErrorPayloadTooLarge(
+                            "Request body exceeds the allowed limit",
+                        ));
+                    }
+                    body.extend_from_slice(&chunk);
+                }
+                let body = body.freeze();
+
+                let fingerprint = idempotency::RequestFingerprint::new(req.request(), &body);
+                req.extensions_mut().insert(fingerprint);
+                req.set_payload(actix_web::dev::Payload::from(body));
+            }
+
+            service.call(req).await
+        })
+    }
+}
+
+/// Middleware to resolve the tenant a request is served for, from the tenant header or the
+/// hostname of the request, when multitenancy is enabled. Requests which resolve to no tenant are
+/// rejected, except for health checks which do not access any store.
+pub struct TenantResolver {
+    multitenancy: std::sync::Arc<crate::configs::settings::Multitenancy>,
+}
+
+impl TenantResolver {
+    pub fn new(multitenancy: crate::configs::settings::Multitenancy) -> Self {
+        Self {
+            multitenancy: std::sync::Arc::new(multitenancy),
+        }
+    }
+}
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for TenantResolver
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = TenantResolverMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(TenantResolverMiddleware {
+            service,
+            multitenancy: self.multitenancy.clone(),
+        }))
+    }
+}
+
+pub struct TenantResolverMiddleware<S> {
+    service: S,
+    multitenancy: std::sync::Arc<crate::configs::settings::Multitenancy>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for TenantResolverMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use actix_web::HttpMessage;
+
+        use crate::services::api::tenant;
+
+        if self.multitenancy.enabled {
+            let tenant_id = tenant::resolve_tenant(
+                &self.multitenancy,
+                req.headers(),
+                req.connection_info().host(),
+            )
+            .and_then(|tenant_id| match tenant_id {
+                Some(tenant_id) => Ok(Some(tenant_id)),
+                None if req.path() == "/health" => Ok(None),
+                None => Err(error_stack::report!(
+                    crate::core::errors::ApiErrorResponse::TenantNotResolved
+                )),
+            });
+            match tenant_id {
+                Ok(Some(tenant_id)) => {
+                    req.extensions_mut().insert(tenant_id);
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    router_env::logger::error!(?error);
+                    let error = error.current_context().clone();
+                    return Box::pin(async move { Err(actix_web::Error::from(error)) });
+                }
+            }
+        }
+
+        Box::pin(self.service.call(req))
+    }
+}
+
 /// Middleware for attaching default response headers. Headers with the same key already set in a
 /// response will not be overwritten.
 pub fn default_response_headers() -> actix_web::middleware::DefaultHeaders {
@@ -61,6 +61,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Disputes", description = "Manage disputes"),
         // (name = "API Key", description = "Create and manage API Keys"),
         (name = "Payouts", description = "Create and manage payouts"),
+        (name = "Payment Link", description = "Retrieve payment links"),
     ),
     paths(
         crate::routes::refunds::refunds_create,
@@ -88,7 +89,11 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payments::payments_connector_session,
     // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
+        crate::routes::payments::payments_approve,
+        crate::routes::payments::payments_reject,
+        crate::routes::payments::payments_incremental_authorization,
         crate::routes::payments::payments_list,
+        crate::routes::payment_link::retrieve_payment_link,
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
         crate::routes::payment_methods::list_customer_payment_method_api,
@@ -100,6 +105,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::customers::customers_retrieve,
         crate::routes::customers::customers_update,
         crate::routes::customers::customers_delete,
+        crate::routes::customers::customers_list,
         // crate::routes::api_keys::api_key_create,
         // crate::routes::api_keys::api_key_retrieve,
         // crate::routes::api_keys::api_key_update,
@@ -137,6 +143,8 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::payment_methods::CardDetailFromLocker,
         crate::types::api::payment_methods::CardDetail,
         api_models::customers::CustomerResponse,
+        api_models::customers::CustomerListResponse,
+        api_models::customers::CustomerListItem,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
         api_models::enums::RoutingAlgorithm,
@@ -150,6 +158,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
         api_models::enums::Connector,
+        api_models::enums::AuthenticationConnectors,
+        api_models::enums::MerchantConnectorName,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
@@ -165,6 +175,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::RetryAction,
         api_models::enums::AttemptStatus,
         api_models::enums::CaptureStatus,
+        api_models::enums::AuthorizationStatus,
         api_models::enums::ReconStatus,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::MerchantConnectorUpdate,
@@ -176,6 +187,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorDetailsWrap,
         api_models::admin::MerchantConnectorDetails,
         api_models::admin::MerchantConnectorWebhookDetails,
+        api_models::admin::ConnectorWalletsDetails,
+        api_models::admin::GooglePayDecryptionDetails,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::payments::AddressDetails,
@@ -235,6 +248,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsCreateRequest,
         api_models::payments::PaymentsResponse,
         api_models::payments::PaymentsStartRequest,
+        api_models::payments::PaymentLinkResponse,
+        api_models::payments::PaymentLinkStatus,
+        api_models::payments::RetrievePaymentLinkResponse,
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentIdType,
@@ -271,6 +287,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ApplePayWalletData,
         api_models::payments::ApplepayPaymentMethod,
         api_models::payments::PaymentsCancelRequest,
+        api_models::payments::PaymentsApproveRequest,
+        api_models::payments::PaymentsRejectRequest,
+        api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
         api_models::payments::CashappQr,
@@ -295,6 +314,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::CaptureResponse,
+        api_models::payments::IncrementalAuthorizationResponse,
         api_models::payment_methods::RequiredFieldInfo,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
@@ -1,6 +1,8 @@
 pub mod admin;
 pub mod api_keys;
 pub mod app;
+pub mod audit_events;
+pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
@@ -13,6 +15,7 @@ pub mod files;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
+pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
@@ -20,6 +23,7 @@ pub mod payouts;
 pub mod refunds;
 #[cfg(all(feature = "olap", feature = "kms"))]
 pub mod verification;
+pub mod webhook_events;
 pub mod webhooks;
 
 #[cfg(feature = "dummy_connector")]
@@ -29,9 +33,9 @@ pub use self::app::Payouts;
 #[cfg(all(feature = "olap", feature = "kms"))]
 pub use self::app::Verify;
 pub use self::app::{
-    ApiKeys, AppState, BusinessProfile, Cache, Cards, Configs, Customers, Disputes, EphemeralKey,
-    Files, Health, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentMethods, Payments,
-    Refunds, Webhooks,
+    ApiKeys, AppState, AuditEvents, Blocklist, BusinessProfile, Cache, Cards, Configs, Customers,
+    Disputes, EphemeralKey, Files, Health, Mandates, MerchantAccount, MerchantConnectorAccount,
+    PaymentLink, PaymentMethods, Payments, Refunds, WebhookEvents, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -379,6 +379,77 @@ pub async fn merchant_account_toggle_kv(
     .await
 }
 
+/// Merchant Account - Rotate Vault Data Key
+///
+/// Rotate the data key of the Merchant Account in the built-in vault
+#[instrument(skip_all, fields(flow = ?Flow::VaultDataKeyRotate))]
+pub async fn merchant_account_rotate_vault_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::VaultDataKeyRotate;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id| rotate_vault_data_key(state, merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Merchant Account - Rotate Data Key
+///
+/// Rotate the data key of the Merchant Account, with which its sensitive data is encrypted. The
+/// existing data is re-encrypted with the new data key in the background.
+#[instrument(skip_all, fields(flow = ?Flow::MerchantDataKeyRotate))]
+pub async fn merchant_account_rotate_data_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantDataKeyRotate;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id| rotate_merchant_data_key(state, merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Merchant Account - Data Key Rotation Status
+///
+/// Retrieve the progress of the re-encryption of the data of the Merchant Account with its
+/// current data key
+#[instrument(skip_all, fields(flow = ?Flow::MerchantDataKeyRotationStatus))]
+pub async fn merchant_account_data_key_rotation_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantDataKeyRotationStatus;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id| merchant_data_key_rotation_status(state, merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::BusinessProfileCreate))]
 pub async fn business_profile_create(
     state: web::Data<AppState>,
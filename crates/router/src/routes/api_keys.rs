@@ -1,7 +1,11 @@
 use actix_web::{web, HttpRequest, Responder};
+#[cfg(feature = "kms")]
+use error_stack::ResultExt;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "kms")]
+use crate::core::errors;
 use crate::{
     core::api_keys,
     services::{api, authentication as auth},
@@ -43,7 +47,9 @@ pub async fn api_key_create(
         payload,
         |state, _, payload| async {
             #[cfg(feature = "kms")]
-            let kms_client = external_services::kms::get_kms_client(&state.clone().conf.kms).await;
+            let kms_client = external_services::kms::get_kms_client(&state.clone().conf.kms)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
             api_keys::create_api_key(
                 state,
                 #[cfg(feature = "kms")]
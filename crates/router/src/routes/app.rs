@@ -1,12 +1,12 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use actix_web::{web, Scope};
 #[cfg(feature = "email")]
-use external_services::email::{AwsSes, EmailClient};
+use external_services::email::{self, EmailClient};
 #[cfg(feature = "kms")]
 use external_services::kms::{self, decrypt::KmsDecrypt};
 use scheduler::SchedulerInterface;
-use storage_impl::MockDb;
+use storage_impl::{redis::kv_store::RedisConnInterface, MockDb};
 use tokio::sync::oneshot;
 
 #[cfg(feature = "dummy_connector")]
@@ -16,17 +16,20 @@ use super::payouts::*;
 #[cfg(all(feature = "olap", feature = "kms"))]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*, disputes::*, files::*};
+use super::{
+    admin::*, api_keys::*, audit_events::*, blocklist::*, disputes::*, files::*, webhook_events::*,
+};
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{ephemeral_key::*, payment_link::*, payment_methods::*, webhooks::*};
 use crate::{
     configs::settings,
+    core::errors::{self, RouterResult},
     db::{StorageImpl, StorageInterface},
     routes::cards_info::card_iin_info,
-    services::get_store,
+    services::{get_store, get_tenant_store},
 };
 
 #[derive(Clone)]
@@ -39,6 +42,10 @@ pub struct AppState {
     #[cfg(feature = "kms")]
     pub kms_secrets: Arc<settings::ActiveKmsSecrets>,
     pub api_client: Box<dyn crate::services::ApiClient>,
+    /// Stores of the configured tenants, keyed by tenant ID
+    pub tenant_stores: Arc<HashMap<String, Box<dyn StorageInterface>>>,
+    /// Tenant the request is being served for, if any
+    pub tenant_id: Option<String>,
 }
 
 impl scheduler::SchedulerAppState for AppState {
@@ -55,6 +62,7 @@ pub trait AppStateInfo {
     fn add_request_id(&mut self, request_id: Option<String>);
     fn add_merchant_id(&mut self, merchant_id: Option<String>);
     fn add_flow_name(&mut self, flow_name: String);
+    fn add_tenant(&mut self, tenant_id: Option<String>) -> RouterResult<()>;
 }
 
 impl AppStateInfo for AppState {
@@ -77,6 +85,17 @@ impl AppStateInfo for AppState {
     fn add_flow_name(&mut self, flow_name: String) {
         self.flow_name = flow_name;
     }
+    fn add_tenant(&mut self, tenant_id: Option<String>) -> RouterResult<()> {
+        if let Some(tenant_id) = tenant_id {
+            self.store = self.tenant_stores.get(&tenant_id).cloned().ok_or_else(|| {
+                errors::ApiErrorResponse::InvalidTenant {
+                    tenant_id: tenant_id.clone(),
+                }
+            })?;
+            self.tenant_id = Some(tenant_id);
+        }
+        Ok(())
+    }
 }
 
 impl AsRef<Self> for AppState {
@@ -88,7 +107,8 @@ impl AsRef<Self> for AppState {
 impl AppState {
     /// # Panics
     ///
-    /// Panics if Store can't be created or JWE decryption fails
+    /// Panics if Store can't be created, the KMS client can't be initialized or JWE decryption
+    /// fails
     pub async fn with_storage(
         conf: settings::Settings,
         storage_impl: StorageImpl,
@@ -96,7 +116,10 @@ impl AppState {
         api_client: Box<dyn crate::services::ApiClient>,
     ) -> Self {
         #[cfg(feature = "kms")]
-        let kms_client = kms::get_kms_client(&conf.kms).await;
+        #[allow(clippy::expect_used)]
+        let kms_client = kms::get_kms_client(&conf.kms)
+            .await
+            .expect("Failed to initialize the KMS client");
         let testable = storage_impl == StorageImpl::PostgresqlTest;
         let store: Box<dyn StorageInterface> = match storage_impl {
             StorageImpl::Postgresql | StorageImpl::PostgresqlTest => Box::new(
@@ -113,6 +136,32 @@ impl AppState {
             ),
         };
 
+        let mut tenant_stores: HashMap<String, Box<dyn StorageInterface>> = HashMap::new();
+        if conf.multitenancy.enabled {
+            for (tenant_id, tenant) in &conf.multitenancy.tenants {
+                let tenant_store: Box<dyn StorageInterface> = match storage_impl {
+                    StorageImpl::Postgresql | StorageImpl::PostgresqlTest => {
+                        #[allow(clippy::expect_used)]
+                        let redis_conn = store
+                            .get_redis_conn()
+                            .expect("Failed to get redis connection");
+                        #[allow(clippy::expect_used)]
+                        let tenant_store = get_tenant_store(&conf, tenant, &redis_conn, testable)
+                            .await
+                            .expect("Failed to create tenant store");
+                        Box::new(tenant_store)
+                    }
+                    #[allow(clippy::expect_used)]
+                    StorageImpl::Mock => Box::new(
+                        MockDb::new(&conf.redis)
+                            .await
+                            .expect("Failed to create mock tenant store"),
+                    ),
+                };
+                tenant_stores.insert(tenant_id.clone(), tenant_store);
+            }
+        }
+
         #[cfg(feature = "kms")]
         #[allow(clippy::expect_used)]
         let kms_secrets = settings::ActiveKmsSecrets {
@@ -123,7 +172,10 @@ impl AppState {
         .expect("Failed while performing KMS decryption");
 
         #[cfg(feature = "email")]
-        let email_client = Arc::new(AwsSes::new(&conf.email).await);
+        #[allow(clippy::expect_used)]
+        let email_client = email::create_email_client(&conf.email)
+            .await
+            .expect("Failed to create email client");
         Self {
             flow_name: String::from("default"),
             store,
@@ -133,6 +185,8 @@ impl AppState {
             #[cfg(feature = "kms")]
             kms_secrets: Arc::new(kms_secrets),
             api_client,
+            tenant_stores: Arc::new(tenant_stores),
+            tenant_id: None,
         }
     }
 
@@ -237,6 +291,16 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/approve").route(web::post().to(payments_approve)),
+                )
+                .service(
+                    web::resource("/{payment_id}/reject").route(web::post().to(payments_reject)),
+                )
+                .service(
+                    web::resource("/{payment_id}/incremental_authorization")
+                        .route(web::post().to(payments_incremental_authorization)),
+                )
                 .service(
                     web::resource("/redirect/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -256,6 +320,24 @@ impl Payments {
                     web::resource("/{payment_id}/{merchant_id}/redirect/complete/{connector}")
                         .route(web::get().to(payments_complete_authorize))
                         .route(web::post().to(payments_complete_authorize)),
+                )
+                .service(
+                    web::resource(
+                        "/{payment_id}/{merchant_id}/authentication/{authentication_id}/authenticate",
+                    )
+                    .route(web::post().to(payments_external_authentication)),
+                )
+                .service(
+                    web::resource(
+                        "/{payment_id}/{merchant_id}/authentication/{authentication_id}/challenge_response",
+                    )
+                    .route(web::post().to(payments_authentication_challenge_response)),
+                )
+                .service(
+                    web::resource(
+                        "/{payment_id}/{merchant_id}/authentication/{authentication_id}/three_ds_method_response",
+                    )
+                    .route(web::post().to(payments_three_ds_method_response)),
                 );
         }
         route
@@ -271,10 +353,12 @@ impl Customers {
 
         #[cfg(feature = "olap")]
         {
-            route = route.service(
-                web::resource("/{customer_id}/mandates")
-                    .route(web::get().to(get_customer_mandates)),
-            );
+            route = route
+                .service(web::resource("/list").route(web::get().to(customers_list)))
+                .service(
+                    web::resource("/{customer_id}/mandates")
+                        .route(web::get().to(get_customer_mandates)),
+                );
         }
 
         #[cfg(feature = "oltp")]
@@ -334,7 +418,16 @@ pub struct Payouts;
 #[cfg(feature = "payouts")]
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
-        let route = web::scope("/payouts").app_data(web::Data::new(state));
+        let mut route = web::scope("/payouts").app_data(web::Data::new(state));
+
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(web::resource("/list").route(web::post().to(payouts_list)))
+                .service(
+                    web::resource("/filter").route(web::post().to(payouts_list_available_filters)),
+                );
+        }
         route
             .service(web::resource("/create").route(web::post().to(payouts_create)))
             .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
@@ -381,6 +474,15 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/vault/rotate_key")
+                    .route(web::post().to(merchant_account_rotate_vault_key)),
+            )
+            .service(
+                web::resource("/{id}/data_key/rotate")
+                    .route(web::post().to(merchant_account_rotate_data_key))
+                    .route(web::get().to(merchant_account_data_key_rotation_status)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -480,6 +582,67 @@ impl Webhooks {
     }
 }
 
+pub struct WebhookEvents;
+
+#[cfg(feature = "olap")]
+impl WebhookEvents {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/events/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(list_webhook_events)))
+            .service(web::resource("/{event_id}").route(web::get().to(retrieve_webhook_event)))
+            .service(
+                web::resource("/{event_id}/retry")
+                    .route(web::post().to(retry_webhook_event_delivery)),
+            )
+    }
+}
+
+pub struct AuditEvents;
+
+#[cfg(feature = "olap")]
+impl AuditEvents {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_events")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(list_audit_events)))
+            .service(web::resource("/admin").route(web::get().to(list_all_audit_events)))
+    }
+}
+
+pub struct PaymentLink;
+
+#[cfg(feature = "oltp")]
+impl PaymentLink {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/payment_link")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{payment_link_id}").route(web::get().to(retrieve_payment_link)),
+            )
+            .service(
+                web::resource("/{merchant_id}/{payment_id}")
+                    .route(web::get().to(initiate_payment_link)),
+            )
+    }
+}
+
+pub struct Blocklist;
+
+#[cfg(feature = "olap")]
+impl Blocklist {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/blocklist/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(list_blocked_payment_methods))
+                    .route(web::post().to(add_entry_to_blocklist))
+                    .route(web::delete().to(remove_entry_from_blocklist)),
+            )
+    }
+}
+
 pub struct Configs;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -0,0 +1,104 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::audit_events::AuditEventListConstraints;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::audit_events,
+    services::{api, authentication as auth},
+};
+
+/// Audit Events - List
+///
+/// List the administrative actions performed on the account of the authenticated merchant, most
+/// recent first.
+#[utoipa::path(
+    get,
+    path = "/audit_events",
+    params(
+        ("actor_type" = Option<AuditActorType>, Query, description = "Only list the actions performed by this kind of actor"),
+        ("actor_id" = Option<String>, Query, description = "Only list the actions performed using this API key"),
+        ("action" = Option<AuditAction>, Query, description = "Only list the events of this action"),
+        ("target_type" = Option<AuditTargetType>, Query, description = "Only list the actions that changed this kind of entity"),
+        ("target_id" = Option<String>, Query, description = "Only list the actions that changed this entity"),
+        ("start_time" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded at or after this time"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded at or before this time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of events to skip when retrieving the list of events"),
+    ),
+    responses(
+        (status = 200, description = "List of audit events retrieved successfully", body = Vec<AuditEventResponse>),
+    ),
+    tag = "Audit Events",
+    operation_id = "List the audit events of a merchant",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AuditEventsList))]
+pub async fn list_audit_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<AuditEventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::AuditEventsList;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth, constraints| {
+            audit_events::list_audit_events(
+                state,
+                Some(auth.merchant_account.merchant_id),
+                constraints,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Audit Events - List all
+///
+/// List the administrative actions performed on any merchant account or on the global
+/// configuration, most recent first.
+#[utoipa::path(
+    get,
+    path = "/audit_events/admin",
+    params(
+        ("merchant_id" = Option<String>, Query, description = "Only list the events of this merchant"),
+        ("actor_type" = Option<AuditActorType>, Query, description = "Only list the actions performed by this kind of actor"),
+        ("actor_id" = Option<String>, Query, description = "Only list the actions performed using this API key"),
+        ("action" = Option<AuditAction>, Query, description = "Only list the events of this action"),
+        ("target_type" = Option<AuditTargetType>, Query, description = "Only list the actions that changed this kind of entity"),
+        ("target_id" = Option<String>, Query, description = "Only list the actions that changed this entity"),
+        ("start_time" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded at or after this time"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded at or before this time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of events to skip when retrieving the list of events"),
+    ),
+    responses(
+        (status = 200, description = "List of audit events retrieved successfully", body = Vec<AuditEventResponse>),
+    ),
+    tag = "Audit Events",
+    operation_id = "List all audit events",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AuditEventsList))]
+pub async fn list_all_audit_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<AuditEventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::AuditEventsList;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints| audit_events::list_audit_events(state, None, constraints),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
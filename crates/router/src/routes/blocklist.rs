@@ -0,0 +1,133 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::blocklist as api_blocklist;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::blocklist,
+    services::{api, authentication as auth},
+};
+
+/// Blocklist - Add
+///
+/// Add a card bin, an extended card bin or a payment method fingerprint to the blocklist of a
+/// merchant. Payments made with blocked cards fail when they are confirmed.
+#[utoipa::path(
+    post,
+    path = "/blocklist/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    request_body = BlocklistRequest,
+    responses(
+        (status = 200, description = "Entry added to the blocklist", body = BlocklistResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Add an entry to the blocklist",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AddToBlocklist))]
+pub async fn add_entry_to_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_blocklist::BlocklistRequest>,
+) -> impl Responder {
+    let flow = Flow::AddToBlocklist;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, json_payload.into_inner()),
+        |state, _, (merchant_id, body)| blocklist::add_entry_to_blocklist(state, merchant_id, body),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Blocklist - Remove
+///
+/// Remove a card bin, an extended card bin or a payment method fingerprint from the blocklist of
+/// a merchant.
+#[utoipa::path(
+    delete,
+    path = "/blocklist/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    request_body = BlocklistRequest,
+    responses(
+        (status = 200, description = "Entry removed from the blocklist", body = BlocklistResponse),
+        (status = 404, description = "Entry not found in the blocklist")
+    ),
+    tag = "Blocklist",
+    operation_id = "Remove an entry from the blocklist",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeleteFromBlocklist))]
+pub async fn remove_entry_from_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_blocklist::BlocklistRequest>,
+) -> impl Responder {
+    let flow = Flow::DeleteFromBlocklist;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, json_payload.into_inner()),
+        |state, _, (merchant_id, body)| {
+            blocklist::remove_entry_from_blocklist(state, merchant_id, body)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Blocklist - List
+///
+/// List the entries of a kind in the blocklist of a merchant, most recent first.
+#[utoipa::path(
+    get,
+    path = "/blocklist/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("data_kind" = BlocklistDataKind, Query, description = "The kind of entries to list"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of entries to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of entries to skip when retrieving the list of entries"),
+    ),
+    responses(
+        (status = 200, description = "Blocklist entries retrieved successfully", body = Vec<BlocklistResponse>),
+    ),
+    tag = "Blocklist",
+    operation_id = "List the entries in the blocklist",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ListBlocklist))]
+pub async fn list_blocked_payment_methods(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<api_blocklist::ListBlocklistQuery>,
+) -> impl Responder {
+    let flow = Flow::ListBlocklist;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, query.into_inner()),
+        |state, _, (merchant_id, query)| {
+            blocklist::list_blocklist_entries(state, merchant_id, query)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
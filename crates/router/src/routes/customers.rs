@@ -68,11 +68,15 @@ pub async fn customers_retrieve(
     })
     .into_inner();
 
-    let auth =
-        match auth::is_ephemeral_auth(req.headers(), &*state.store, &payload.customer_id).await {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    // Ephemeral keys are looked up in the store of the tenant of the request
+    let store = match api::get_request_store(state.get_ref(), &req) {
+        Ok(store) => store,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    let auth = match auth::is_ephemeral_auth(req.headers(), &*store, &payload.customer_id).await {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     api::server_wrap(
         flow,
@@ -159,6 +163,47 @@ pub async fn customers_delete(
     .await
 }
 
+/// List Customers
+///
+/// Lists the customers of the merchant, most recently created first. The customers can be searched by their exact email, phone or name.
+#[utoipa::path(
+    get,
+    path = "/customers/list",
+    params(
+        ("limit" = Option<u32>, Query, description = "The maximum number of customers to return, 10 by default and at most 100"),
+        ("starting_after" = Option<String>, Query, description = "The customer ID of the customer after which the customers are listed"),
+        ("email" = Option<String>, Query, description = "Only list customers with this email"),
+        ("phone" = Option<String>, Query, description = "Only list customers with this phone number"),
+        ("name" = Option<String>, Query, description = "Only list customers with this name"),
+        ("include_counts" = Option<bool>, Query, description = "Whether to include the number of saved payment methods and mandates of each customer"),
+    ),
+    responses(
+        (status = 200, description = "Customers retrieved", body = CustomerListResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Customers",
+    operation_id = "List Customers",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersList))]
+pub async fn customers_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<customers::CustomerListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::CustomersList;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth, req| list_customers(state, auth.merchant_account, auth.key_store, req),
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::CustomersGetMandates))]
 pub async fn get_customer_mandates(
     state: web::Data<AppState>,
@@ -53,6 +53,7 @@ pub async fn retrieve_dispute(
     path = "/disputes/list",
     params(
         ("limit" = Option<i64>, Query, description = "The maximum number of Dispute Objects to include in the response"),
+        ("starting_after" = Option<String>, Query, description = "The dispute ID of the dispute after which the disputes are listed, from the previous page"),
         ("dispute_status" = Option<DisputeStatus>, Query, description = "The status of dispute"),
         ("dispute_stage" = Option<DisputeStage>, Query, description = "The stage of dispute"),
         ("reason" = Option<String>, Query, description = "The reason for dispute"),
@@ -0,0 +1,79 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::payment_link,
+    services::{api, authentication as auth},
+};
+
+/// Payment Link - Retrieve
+///
+/// Retrieve the details of a payment link, along with its expiry and the status of the payment
+/// it was created for.
+#[utoipa::path(
+    get,
+    path = "/payment_link/{payment_link_id}",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for the payment link")
+    ),
+    responses(
+        (status = 200, description = "Payment link retrieved successfully", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkRetrieve))]
+pub async fn retrieve_payment_link(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkRetrieve;
+    let payment_link_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth, payment_link_id| {
+            payment_link::retrieve_payment_link(state, auth.merchant_account, payment_link_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payment Link - Initiate
+///
+/// Render the hosted checkout page of a payment link, which the customer uses to pay for the
+/// payment the link was created for.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkInitiate))]
+pub async fn initiate_payment_link(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkInitiate;
+    let (merchant_id, payment_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth, payment_id| {
+            payment_link::initiate_payment_link_flow(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
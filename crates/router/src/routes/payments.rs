@@ -9,7 +9,7 @@ use crate::{
     self as app,
     core::{
         errors::http_not_implemented,
-        payments::{self, PaymentRedirectFlow},
+        payments::{self, authentication, PaymentRedirectFlow},
     },
     openapi::examples::{
         PAYMENTS_CREATE, PAYMENTS_CREATE_MINIMUM_FIELDS, PAYMENTS_CREATE_WITH_ADDRESS,
@@ -682,6 +682,82 @@ pub async fn payments_complete_authorize(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsExternalAuthentication))]
+pub async fn payments_external_authentication(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    form_payload: web::Form<authentication::ThreeDsMethodCompletion>,
+    path: web::Path<(String, String, String)>,
+) -> impl Responder {
+    let flow = Flow::PaymentsExternalAuthentication;
+    let (payment_id, merchant_id, authentication_id) = path.into_inner();
+    let payload = form_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            authentication::three_ds_method_completion(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id.clone(),
+                authentication_id.clone(),
+                req,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsExternalAuthentication))]
+pub async fn payments_authentication_challenge_response(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String, String)>,
+) -> impl Responder {
+    let flow = Flow::PaymentsExternalAuthentication;
+    let (payment_id, merchant_id, authentication_id) = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (payment_id, authentication_id),
+        |state, auth, (payment_id, authentication_id)| {
+            authentication::challenge_completion(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+                authentication_id,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsExternalAuthentication))]
+pub async fn payments_three_ds_method_response(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String, String)>,
+) -> impl Responder {
+    let flow = Flow::PaymentsExternalAuthentication;
+    let (_payment_id, merchant_id, _authentication_id) = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |_, _, _| async { authentication::three_ds_method_notification() },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
+
 /// Payments - Cancel
 ///
 /// A Payment could can be cancelled when it is in one of these statuses: requires_payment_method, requires_capture, requires_confirmation, requires_customer_action
@@ -734,6 +810,155 @@ pub async fn payments_cancel(
     .await
 }
 
+/// Payments - Approve
+///
+/// Approve a payment which was held for a manual review by the fraud check. The payment is authorized if it was held before its authorization, or captured if it was held after it
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/approve",
+    request_body=PaymentsApproveRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment approved", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Approve a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsApprove))]
+// #[post("/{payment_id}/approve")]
+pub async fn payments_approve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsApproveRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsApprove;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = payment_id;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            payments::fraud_check::approve_payment(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payments - Reject
+///
+/// Reject a payment which was held for a manual review by the fraud check. The authorization of a payment held after it was authorized is voided
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/reject",
+    request_body=PaymentsRejectRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment rejected", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Reject a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsReject))]
+// #[post("/{payment_id}/reject")]
+pub async fn payments_reject(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsRejectRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsReject;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = payment_id;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            payments::fraud_check::reject_payment(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payments - Incremental Authorization
+///
+/// Increase the authorized amount of a payment which is yet to be captured. The amount of the payment is updated only if the connector accepts the incremental authorization
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/incremental_authorization",
+    request_body=PaymentsIncrementalAuthorizationRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Incremental authorization processed", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Increment the authorized amount of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsIncrementalAuthorization))]
+// #[post("/{payment_id}/incremental_authorization")]
+pub async fn payments_incremental_authorization(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsIncrementalAuthorizationRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsIncrementalAuthorization;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = payment_id;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            payments::payments_core::<
+                api_types::IncrementalAuthorization,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payments::PaymentIncrementalAuthorization,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                HeaderPayload::default(),
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 /// Payments - List
 ///
 /// To list the payments
@@ -197,6 +197,50 @@ pub async fn payouts_fulfill(
     .await
 }
 
+/// Payouts - List
+///
+/// To list the payouts of the merchant, filtered by the given constraints
+#[cfg(all(feature = "payouts", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+pub async fn payouts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutListFilterConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsList;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| payouts_list_core(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payouts - Filter
+///
+/// To list the available filters for the payouts created in the given time range
+#[cfg(all(feature = "payouts", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsFilter))]
+pub async fn payouts_list_available_filters(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::payments::TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsFilter;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| payouts_filter_core(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::verification,
+    core::{audit_events, verification},
     services::{api, authentication as auth},
 };
 
@@ -23,13 +23,14 @@ pub async fn apple_pay_merchant_registration(
         state,
         &req,
         json_payload,
-        |state, _, body| {
+        |state, auth, body| {
             verification::verify_merchant_creds_for_applepay(
                 state.clone(),
                 &req,
                 body,
                 kms_conf,
                 merchant_id.clone(),
+                audit_events::AuditActor::from(&auth),
             )
         },
         auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
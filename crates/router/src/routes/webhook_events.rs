@@ -0,0 +1,134 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::webhook_events::EventListConstraints;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::webhooks::webhook_events,
+    services::{api, authentication as auth},
+};
+
+/// Events - List
+///
+/// List the outgoing webhook events raised for a merchant, most recent first.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("object_id" = Option<String>, Query, description = "Only list the events raised for this object, such as a payment ID or a refund ID"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of events to skip when retrieving the list of events"),
+        ("starting_after" = Option<String>, Query, description = "The event ID of the event after which the events are listed, from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "List of events retrieved successfully", body = Vec<EventListItemResponse>),
+    ),
+    tag = "Event",
+    operation_id = "List all events associated with a merchant account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventsList))]
+pub async fn list_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<EventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventsList;
+    let merchant_id = path.into_inner();
+    let constraints = query.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, constraints),
+        |state, _, (merchant_id, constraints)| {
+            webhook_events::list_events(state, merchant_id, constraints)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Events - Retrieve
+///
+/// Retrieve an outgoing webhook event along with the history of its delivery attempts.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/{event_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("event_id" = String, Path, description = "The unique identifier for the event"),
+    ),
+    responses(
+        (status = 200, description = "Event retrieved successfully", body = EventRetrieveResponse),
+        (status = 404, description = "Event not found")
+    ),
+    tag = "Event",
+    operation_id = "Retrieve an event",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRetrieve))]
+pub async fn retrieve_webhook_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventRetrieve;
+    let (merchant_id, event_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, event_id),
+        |state, _, (merchant_id, event_id)| {
+            webhook_events::retrieve_event(state, merchant_id, event_id)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Events - Retry Delivery
+///
+/// Send the outgoing webhook of an event to the merchant once again. The outcome is recorded as
+/// a new delivery attempt, which is returned in the response.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/{event_id}/retry",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("event_id" = String, Path, description = "The unique identifier for the event"),
+    ),
+    responses(
+        (status = 200, description = "Webhook resent", body = WebhookDeliveryAttemptResponse),
+        (status = 404, description = "Event not found")
+    ),
+    tag = "Event",
+    operation_id = "Retry the delivery of an event",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryRetry))]
+pub async fn retry_webhook_event_delivery(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryRetry;
+    let (merchant_id, event_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, event_id),
+        |state, _, (merchant_id, event_id)| {
+            webhook_events::retry_event_delivery(state, merchant_id, event_id)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
@@ -1,5 +1,7 @@
 pub mod api;
 pub mod authentication;
+#[cfg(feature = "email")]
+pub mod email;
 pub mod encryption;
 pub mod logger;
 
@@ -36,7 +38,10 @@ pub async fn get_store(
     test_transaction: bool,
 ) -> StorageResult<Store> {
     #[cfg(feature = "kms")]
-    let kms_client = kms::get_kms_client(&config.kms).await;
+    let kms_client = kms::get_kms_client(&config.kms)
+        .await
+        .change_context(StorageError::InitializationError)
+        .attach_printable("Failed to initialize the KMS client")?;
 
     #[cfg(feature = "kms")]
     let master_config = config
@@ -84,6 +89,8 @@ pub async fn get_store(
         )
         .await?
     };
+    #[cfg(feature = "kms")]
+    let store = with_merchant_key_wrapping(store, config, kms_client);
 
     #[cfg(feature = "kv_store")]
     let store = KVRouterStore::from_store(
@@ -95,7 +102,99 @@ pub async fn get_store(
     Ok(store)
 }
 
-#[allow(clippy::expect_used)]
+/// Create the store of a tenant, backed by the database schema of the tenant and sharing the redis
+/// connections of the application under the redis key prefix of the tenant.
+pub async fn get_tenant_store(
+    config: &settings::Settings,
+    tenant: &settings::Tenant,
+    redis_conn: &redis_interface::RedisConnectionPool,
+    test_transaction: bool,
+) -> StorageResult<Store> {
+    #[cfg(feature = "kms")]
+    let kms_client = kms::get_kms_client(&config.kms)
+        .await
+        .change_context(StorageError::InitializationError)
+        .attach_printable("Failed to initialize the KMS client")?;
+
+    let master_database = tenant
+        .master_database
+        .clone()
+        .unwrap_or_else(|| config.master_database.clone());
+    #[cfg(feature = "kms")]
+    let mut master_config: storage_impl::config::Database = master_database
+        .decrypt_inner(kms_client)
+        .await
+        .change_context(StorageError::InitializationError)
+        .attach_printable("Failed to decrypt tenant master database config")?;
+    #[cfg(not(feature = "kms"))]
+    let mut master_config: storage_impl::config::Database = master_database.into();
+    master_config.schema = Some(tenant.schema.clone());
+
+    #[cfg(feature = "olap")]
+    let replica_database = tenant
+        .replica_database
+        .clone()
+        .unwrap_or_else(|| config.replica_database.clone());
+    #[cfg(all(feature = "olap", feature = "kms"))]
+    let mut replica_config: storage_impl::config::Database = replica_database
+        .decrypt_inner(kms_client)
+        .await
+        .change_context(StorageError::InitializationError)
+        .attach_printable("Failed to decrypt tenant replica database config")?;
+    #[cfg(all(feature = "olap", not(feature = "kms")))]
+    let mut replica_config: storage_impl::config::Database = replica_database.into();
+    #[cfg(feature = "olap")]
+    {
+        replica_config.schema = Some(tenant.schema.clone());
+    }
+
+    let master_enc_key = get_master_enc_key(
+        config,
+        #[cfg(feature = "kms")]
+        kms_client,
+    )
+    .await;
+    #[cfg(not(feature = "olap"))]
+    let conf = master_config;
+    #[cfg(feature = "olap")]
+    let conf = (master_config, replica_config);
+
+    let store: RouterStore<StoreType> = RouterStore::from_shared_redis(
+        conf,
+        redis_conn,
+        &tenant.redis_key_prefix,
+        master_enc_key,
+        test_transaction,
+    )
+    .await?;
+    #[cfg(feature = "kms")]
+    let store = with_merchant_key_wrapping(store, config, kms_client);
+
+    #[cfg(feature = "kv_store")]
+    let store = KVRouterStore::from_store(
+        store,
+        config.drainer.stream_name.clone(),
+        config.drainer.num_partitions,
+    );
+
+    Ok(store)
+}
+
+/// Wrap the data keys of merchants with the key management backend, if enabled in the
+/// configuration.
+#[cfg(feature = "kms")]
+fn with_merchant_key_wrapping(
+    store: RouterStore<StoreType>,
+    config: &settings::Settings,
+    kms_client: &'static kms::KmsClient,
+) -> RouterStore<StoreType> {
+    if config.kms.wrap_merchant_keys {
+        store.with_key_manager(kms_client)
+    } else {
+        store
+    }
+}
+
 async fn get_master_enc_key(
     conf: &crate::configs::settings::Settings,
     #[cfg(feature = "kms")] kms_client: &kms::KmsClient,
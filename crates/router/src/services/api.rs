@@ -1,5 +1,7 @@
 pub mod client;
+pub mod idempotency;
 pub mod request;
+pub mod tenant;
 
 use std::{
     collections::HashMap,
@@ -12,7 +14,9 @@ use std::{
 
 use actix_web::{body, web, FromRequest, HttpRequest, HttpResponse, Responder, ResponseError};
 use api_models::enums::CaptureMethod;
-pub use client::{proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
+pub use client::{
+    proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient, RecordReplayClient, RecordReplayMode,
+};
 use common_utils::errors::ReportSwitchExt;
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
 use error_stack::{report, IntoReport, Report, ResultExt};
@@ -29,6 +33,7 @@ use crate::{
         errors::{self, CustomResult},
         payments,
     },
+    db::StorageInterface,
     logger,
     routes::{
         app::AppStateInfo,
@@ -87,6 +92,12 @@ pub trait ConnectorValidation: ConnectorCommon {
             .change_context(errors::ConnectorError::MissingConnectorTransactionID)
             .map(|_| ())
     }
+
+    /// Whether the connector accepts the result of a 3DS authentication performed through an
+    /// external 3DS server, instead of running 3DS itself
+    fn is_external_three_ds_supported(&self) -> bool {
+        false
+    }
 }
 
 #[async_trait::async_trait]
@@ -395,6 +406,20 @@ where
                                 router_data.response = Err(error_response);
                                 router_data.connector_http_status_code = Some(504);
                                 Ok(router_data)
+                            } else if error.current_context().is_connection_closed() {
+                                // The connector may have processed the request, so the attempt is
+                                // left pending to be synced
+                                let error_response = ErrorResponse {
+                                    code: consts::CONNECTION_CLOSED_ERROR_CODE.to_string(),
+                                    message: consts::CONNECTION_CLOSED_ERROR_MESSAGE.to_string(),
+                                    reason: Some(
+                                        consts::CONNECTION_CLOSED_ERROR_MESSAGE.to_string(),
+                                    ),
+                                    status_code: 502,
+                                };
+                                router_data.response = Err(error_response);
+                                router_data.connector_http_status_code = Some(502);
+                                Ok(router_data)
                             } else {
                                 Err(error.change_context(
                                     errors::ConnectorError::ProcessingStepFailed(None),
@@ -421,6 +446,14 @@ pub async fn call_connector_api(
         .send_request(state, request, None, true)
         .await;
 
+    // Requests which could not connect never reached the connector
+    let is_connector_reached = response.as_ref().err().map_or(true, |error| {
+        error.current_context() != &errors::ApiClientError::ConnectionNotEstablished
+    });
+    if is_connector_reached {
+        idempotency::record_connector_call();
+    }
+
     let elapsed_time = current_time.elapsed();
     logger::info!(request_time=?elapsed_time);
 
@@ -504,6 +537,9 @@ pub async fn send_request(
                 .put(url)
                 .body(request.payload.expose_option().unwrap_or_default()), // If payload needs processing the body cannot have default
             Method::Delete => client.delete(url),
+            Method::Patch => client
+                .patch(url)
+                .body(request.payload.expose_option().unwrap_or_default()),
         }
         .add_headers(headers)
         .timeout(Duration::from_secs(
@@ -520,6 +556,11 @@ pub async fn send_request(
                 metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
                 errors::ApiClientError::ConnectionClosed
             }
+            // The request never left the application when no connection could be established
+            error if error.is_connect() => {
+                metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
+                errors::ApiClientError::ConnectionNotEstablished
+            }
             _ => errors::ApiClientError::RequestNotSent(error.to_string()),
         })
         .into_report()
@@ -677,6 +718,37 @@ pub enum RedirectForm {
         bin: String,
         amount: i64,
     },
+    /// The hosted checkout page of a payment link
+    PaymentLink {
+        payment_id: String,
+        client_secret: String,
+        publishable_key: String,
+        merchant_name: String,
+        logo: Option<String>,
+        primary_color: String,
+        background_color: String,
+        description: Option<String>,
+        return_url: String,
+        payment_methods: Vec<PaymentLinkPaymentMethod>,
+        /// Displayed instead of the checkout form, once the payment can no longer be paid through
+        /// the link
+        message: Option<String>,
+    },
+    /// Runs the issuer's 3DS method in a hidden iframe, and then submits the authentication request
+    /// to the external 3DS server along with whether the 3DS method completed in time
+    ThreeDsMethod {
+        authentication_url: String,
+        three_ds_method_url: Option<String>,
+        three_ds_method_data: Option<String>,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentLinkPaymentMethod {
+    pub payment_method: api_models::enums::PaymentMethod,
+    pub payment_method_type: Option<api_models::enums::PaymentMethodType>,
+    /// The details of cards are entered by the customer on the page
+    pub payment_method_data: Option<api::PaymentMethodData>,
 }
 
 impl From<(url::Url, Method)> for RedirectForm {
@@ -734,10 +806,17 @@ where
 
     request_state.add_request_id(request_id);
 
-    let auth_out = api_auth
-        .authenticate_and_fetch(request.headers(), &request_state)
-        .await
-        .switch()?;
+    // The tenant is applied before authentication, as credentials are looked up in the store of
+    // the tenant
+    let auth_out = match request_state.add_tenant(tenant::get_tenant_id(request)) {
+        Ok(()) => {
+            api_auth
+                .authenticate_and_fetch(request.headers(), &request_state)
+                .await
+        }
+        Err(error) => Err(error),
+    }
+    .switch()?;
 
     let merchant_id = auth_out
         .get_merchant_id()
@@ -762,6 +841,47 @@ where
     output
 }
 
+/// Store of the tenant the request was resolved to, or the default store if there is none
+pub fn get_request_store<A: AppStateInfo + Clone>(
+    state: &A,
+    request: &HttpRequest,
+) -> errors::RouterResult<Box<dyn StorageInterface>> {
+    let mut tenant_state = state.clone();
+    tenant_state.add_tenant(tenant::get_tenant_id(request))?;
+    Ok(tenant_state.store())
+}
+
+/// Runs the handler of a request carrying an idempotency key while holding the key, and replays
+/// the response stored with the key to the retries of the request
+pub(crate) async fn run_with_idempotency<A, H, Fut>(
+    state: &A,
+    request: &HttpRequest,
+    handler: H,
+) -> errors::RouterResult<HttpResponse>
+where
+    A: AppStateInfo + Clone,
+    H: FnOnce() -> Fut,
+    Fut: Future<Output = HttpResponse>,
+{
+    let idempotency_key = match idempotency::IdempotencyKey::from_request(request)? {
+        Some(idempotency_key) => idempotency_key,
+        None => return Ok(handler().await),
+    };
+
+    let config = state.conf().idempotency;
+    // Idempotency keys are held in the store of the tenant of the request
+    let store = get_request_store(state, request)?;
+    match idempotency_key.start(&*store, &config).await? {
+        idempotency::IdempotencyStatus::New => {
+            let (res, connector_called) = idempotency::track_connector_calls(handler()).await;
+            Ok(idempotency_key
+                .complete(&*store, &config, res, connector_called)
+                .await)
+        }
+        idempotency::IdempotencyStatus::Completed(res) => Ok(res),
+    }
+}
+
 #[instrument(
     skip(request, state, func, api_auth, payload),
     fields(request_method, request_url_path)
@@ -793,9 +913,53 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest, payload = ?payload);
 
-    let res = match metrics::request::record_request_time_metric(
-        server_wrap_util(&flow, state.clone(), request, payload, func, api_auth),
-        &flow,
+    let request_state = state.clone();
+    let res: Result<_, Report<api_models::errors::types::ApiErrorResponse>> =
+        run_with_idempotency(request_state.get_ref(), request, || {
+            server_wrap_http_response(&flow, state, request, payload, func, api_auth)
+        })
+        .await
+        .switch();
+    let res = match res {
+        Ok(res) => res,
+        Err(error) => log_and_return_error_response(error),
+    };
+
+    let response_code = res.status().as_u16();
+    let end_instant = Instant::now();
+    let request_duration = end_instant.saturating_duration_since(start_instant);
+    logger::info!(
+        tag = ?Tag::EndRequest,
+        status_code = response_code,
+        time_taken_ms = request_duration.as_millis(),
+    );
+
+    res
+}
+
+/// Runs the request through [`server_wrap_util`] and renders its outcome as an HTTP response
+async fn server_wrap_http_response<'a, A, T, U, Q, F, Fut, E>(
+    flow: &impl router_env::types::FlowMetric,
+    state: web::Data<A>,
+    request: &'a HttpRequest,
+    payload: T,
+    func: F,
+    api_auth: &dyn auth::AuthenticateAndFetch<U, A>,
+) -> HttpResponse
+where
+    F: Fn(A, U, T) -> Fut,
+    Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
+    Q: Serialize + Debug + 'a,
+    T: Debug,
+    U: auth::AuthInfo,
+    A: AppStateInfo + Clone,
+    ApplicationResponse<Q>: Debug,
+    CustomResult<ApplicationResponse<Q>, E>:
+        ReportSwitchExt<ApplicationResponse<Q>, api_models::errors::types::ApiErrorResponse>,
+{
+    match metrics::request::record_request_time_metric(
+        server_wrap_util(flow, state.clone(), request, payload, func, api_auth),
+        flow,
     )
     .await
     .map(|response| {
@@ -854,18 +1018,7 @@ where
             }
         }
         Err(error) => log_and_return_error_response(error),
-    };
-
-    let response_code = res.status().as_u16();
-    let end_instant = Instant::now();
-    let request_duration = end_instant.saturating_duration_since(start_instant);
-    logger::info!(
-        tag = ?Tag::EndRequest,
-        status_code = response_code,
-        time_taken_ms = request_duration.as_millis(),
-    );
-
-    res
+    }
 }
 
 pub fn log_and_return_error_response<T>(error: Report<T>) -> HttpResponse
@@ -1005,6 +1158,9 @@ impl Authenticate for api_models::payments::PaymentsRetrieveRequest {}
 impl Authenticate for api_models::payments::PaymentsCancelRequest {}
 impl Authenticate for api_models::payments::PaymentsCaptureRequest {}
 impl Authenticate for api_models::payments::PaymentsStartRequest {}
+impl Authenticate for api_models::payments::PaymentsApproveRequest {}
+impl Authenticate for api_models::payments::PaymentsRejectRequest {}
+impl Authenticate for api_models::payments::PaymentsIncrementalAuthorizationRequest {}
 
 pub fn build_redirection_form(
     form: &RedirectForm,
@@ -1255,9 +1411,236 @@ pub fn build_redirection_form(
                                     )))
                 }}
         }
+        RedirectForm::PaymentLink {
+            payment_id,
+            client_secret,
+            publishable_key,
+            merchant_name,
+            logo,
+            primary_color,
+            background_color,
+            description,
+            return_url,
+            payment_methods,
+            message,
+        } => {
+            // Closing tags are escaped, as the details are embedded in a script
+            let payment_link_details = json!({
+                "payment_id": payment_id,
+                "client_secret": client_secret,
+                "publishable_key": publishable_key,
+                "return_url": return_url,
+                "base_url": config.server.base_url,
+                "payment_methods": payment_methods,
+            })
+            .to_string()
+            .replace("</", "<\\/");
+
+            maud::html! {
+            (maud::DOCTYPE)
+            html {
+                head {
+                    meta name="viewport" content="width=device-width, initial-scale=1";
+                    title { (merchant_name) }
+                    (PreEscaped(r#"
+                    <style>
+                        .checkout { max-width: 420px; margin: 40px auto; padding: 24px; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0, 0, 0, 0.08); }
+                        .logo { max-height: 48px; max-width: 160px; display: block; margin-bottom: 12px; }
+                        .amount { font-size: 28px; font-weight: bold; margin: 16px 0; }
+                        .payment-method { display: block; padding: 10px; margin-bottom: 8px; border: 1px solid #dddddd; border-radius: 4px; text-transform: capitalize; cursor: pointer; }
+                        .field { width: 100%; box-sizing: border-box; padding: 10px; margin-bottom: 8px; border: 1px solid #dddddd; border-radius: 4px; }
+                        .row { display: flex; gap: 8px; }
+                        .error { color: #d32f2f; }
+                        .pay-button { width: 100%; padding: 12px; border: none; border-radius: 4px; color: #ffffff; font-size: 16px; cursor: pointer; }
+                    </style>
+                    "#))
+                }
+                body style=(format!("background-color: {background_color}; font-family: Arial, Helvetica, Sans-Serif;")) {
+                    div class="checkout" {
+                        @if let Some(logo) = logo {
+                            img class="logo" src=(logo) alt=(merchant_name);
+                        }
+                        h3 { (merchant_name) }
+                        @if let Some(description) = description {
+                            p { (description) }
+                        }
+                        div class="amount" { (amount) " " (currency) }
+
+                        @if let Some(message) = message {
+                            h4 { (message) }
+                        } @else if payment_methods.is_empty() {
+                            h4 { "No payment methods are available for this payment" }
+                        } @else {
+                            form id="payment_link_form" {
+                                @for (index, payment_method) in payment_methods.iter().enumerate() {
+                                    label class="payment-method" {
+                                        input type="radio" name="payment_method" value=(index) checked[index == 0];
+                                        " "
+                                        (payment_method.payment_method_type.map_or("card".to_string(), |payment_method_type| payment_method_type.to_string().replace('_', " ")))
+                                    }
+                                }
+                                div id="card_fields" {
+                                    input class="field" id="card_holder_name" placeholder="Name on card" autocomplete="cc-name";
+                                    input class="field" id="card_number" placeholder="Card number" autocomplete="cc-number" inputmode="numeric";
+                                    div class="row" {
+                                        input class="field" id="card_exp_month" placeholder="MM" autocomplete="cc-exp-month" inputmode="numeric";
+                                        input class="field" id="card_exp_year" placeholder="YYYY" autocomplete="cc-exp-year" inputmode="numeric";
+                                        input class="field" id="card_cvc" placeholder="CVC" autocomplete="cc-csc" inputmode="numeric";
+                                    }
+                                }
+                                p id="error_message" class="error" {}
+                                button type="submit" id="pay_button" class="pay-button" style=(format!("background-color: {primary_color};")) {
+                                    "Pay " (amount) " " (currency)
+                                }
+                            }
+                        }
+                    }
+
+                    (PreEscaped(format!("<script>var paymentLinkDetails = {payment_link_details};</script>")))
+                    (PreEscaped(r#"
+                    <script>
+                    var form = document.getElementById("payment_link_form");
+
+                    function getSelectedPaymentMethod() {
+                        var selected = form.querySelector('input[name="payment_method"]:checked');
+                        return paymentLinkDetails.payment_methods[selected ? selected.value : 0];
+                    }
+
+                    function getValue(id) {
+                        return document.getElementById(id).value.trim();
+                    }
+
+                    function toggleCardFields() {
+                        var isCard = getSelectedPaymentMethod().payment_method === "card";
+                        document.getElementById("card_fields").style.display = isCard ? "block" : "none";
+                    }
+
+                    function showError(message) {
+                        document.getElementById("error_message").innerText = message;
+                        document.getElementById("pay_button").disabled = false;
+                    }
+
+                    if (form) {
+                        form.querySelectorAll('input[name="payment_method"]').forEach(function (input) {
+                            input.addEventListener("change", toggleCardFields);
+                        });
+                        toggleCardFields();
+
+                        form.addEventListener("submit", function (event) {
+                            event.preventDefault();
+                            document.getElementById("pay_button").disabled = true;
+                            document.getElementById("error_message").innerText = "";
+
+                            var paymentMethod = getSelectedPaymentMethod();
+                            var paymentMethodData = paymentMethod.payment_method_data;
+                            if (paymentMethod.payment_method === "card") {
+                                paymentMethodData = {
+                                    card: {
+                                        card_number: getValue("card_number").replace(/\s/g, ""),
+                                        card_exp_month: getValue("card_exp_month"),
+                                        card_exp_year: getValue("card_exp_year"),
+                                        card_holder_name: getValue("card_holder_name"),
+                                        card_cvc: getValue("card_cvc")
+                                    }
+                                };
+                            }
+
+                            fetch(paymentLinkDetails.base_url + "/payments/" + paymentLinkDetails.payment_id + "/confirm", {
+                                method: "POST",
+                                headers: {
+                                    "Content-Type": "application/json",
+                                    "api-key": paymentLinkDetails.publishable_key
+                                },
+                                body: JSON.stringify({
+                                    client_secret: paymentLinkDetails.client_secret,
+                                    payment_method: paymentMethod.payment_method,
+                                    payment_method_type: paymentMethod.payment_method_type,
+                                    payment_method_data: paymentMethodData,
+                                    return_url: paymentLinkDetails.return_url
+                                })
+                            })
+                            .then(function (response) { return response.json(); })
+                            .then(function (response) {
+                                if (response.error) {
+                                    showError(response.error.message);
+                                } else if (response.next_action && response.next_action.redirect_to_url) {
+                                    window.location.href = response.next_action.redirect_to_url;
+                                } else {
+                                    window.location.reload();
+                                }
+                            })
+                            .catch(function () {
+                                showError("Something went wrong, please try again");
+                            });
+                        });
+                    }
+                    </script>
+                    "#))
+                }
+            }
+            }
+        }
+        RedirectForm::ThreeDsMethod {
+            authentication_url,
+            three_ds_method_url,
+            three_ds_method_data,
+        } => maud::html! {
+            (maud::DOCTYPE)
+            html {
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                body style="background-color: #ffffff; padding: 20px; font-family: Arial, Helvetica, Sans-Serif;" {
+                    h3 style="text-align: center;" { "Please wait while we process your payment..." }
+
+                    @if let (Some(three_ds_method_url), Some(three_ds_method_data)) = (three_ds_method_url, three_ds_method_data) {
+                        iframe name="three_ds_method_iframe" style="display: none;" {}
+                        form action=(three_ds_method_url) method="POST" target="three_ds_method_iframe" id="three_ds_method_form" {
+                            input type="hidden" name="threeDSMethodData" value=(three_ds_method_data);
+                        }
+                    }
+
+                    form action=(authentication_url) method="POST" id="authentication_form" {
+                        input type="hidden" name="threeDSCompInd" id="three_ds_comp_ind" value="U";
+                    }
+
+                    (PreEscaped(format!(r#"
+                    <script>
+                    var submitted = false;
+
+                    function authenticate(threeDsCompInd) {{
+                        if (submitted) {{
+                            return;
+                        }}
+                        submitted = true;
+                        document.getElementById("three_ds_comp_ind").value = threeDsCompInd;
+                        document.getElementById("authentication_form").submit();
+                    }}
+
+                    var threeDsMethodForm = document.getElementById("three_ds_method_form");
+                    if (threeDsMethodForm) {{
+                        window.addEventListener("message", function (event) {{
+                            if (event.data === "{THREE_DS_METHOD_COMPLETED_MESSAGE}") {{
+                                authenticate("Y");
+                            }}
+                        }});
+                        threeDsMethodForm.submit();
+                        window.setTimeout(function () {{ authenticate("N"); }}, {THREE_DS_METHOD_TIMEOUT_MS});
+                    }} else {{
+                        authenticate("U");
+                    }}
+                    </script>
+                    "#)))
+                }
+            }
+        },
     }
 }
 
+/// Posted by the 3DS method notification page to the page running the 3DS method
+pub const THREE_DS_METHOD_COMPLETED_MESSAGE: &str = "three_ds_method_completed";
+
+/// The 3DS protocol allows the 3DS method up to 10 seconds to complete
+const THREE_DS_METHOD_TIMEOUT_MS: u64 = 10000;
+
 #[cfg(test)]
 mod tests {
     #[test]
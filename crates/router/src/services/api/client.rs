@@ -1,3 +1,5 @@
+mod record_replay;
+
 use std::time::Duration;
 
 use error_stack::{IntoReport, ResultExt};
@@ -6,6 +8,9 @@ use masking::PeekInterface;
 use once_cell::sync::OnceCell;
 use reqwest::multipart::Form;
 
+pub use self::record_replay::{
+    RecordReplayClient, RecordReplayMode, RecordedInteraction, RecordedRequest, RecordedResponse,
+};
 use super::{request::Maskable, Request};
 use crate::{
     configs::settings::{Locker, Proxy},
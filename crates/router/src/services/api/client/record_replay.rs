@@ -0,0 +1,735 @@
+//! Recording of connector requests and responses to fixture files, and their replay, so that the
+//! connector integration tests can be run without network access or connector credentials.
+//!
+//! A fixture file holds the interactions of a single test, in the order in which the requests were
+//! sent. The requests are replayed in the same order, and each of them must be sent with the method
+//! and URL it was recorded with, ignoring the identifiers generated afresh by each run of the test.
+//!
+//! The connector credentials are masked wherever they appear in the recorded interactions, and the
+//! personal data (card details, names, contact details and addresses) in the JSON and form encoded
+//! bodies and in the query strings.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
+use http::Method;
+use masking::{PeekInterface, Secret};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+use super::{ApiClient, RequestBuilder};
+use crate::{
+    consts,
+    core::errors::{ApiClientError, CustomResult},
+    routes::AppState,
+    services::{request::Maskable, Request},
+};
+
+/// Replaces the secrets and the personal data in the recorded requests and responses
+const MASKED_VALUE: &str = "*** masked ***";
+
+/// Replaces the email addresses, so that the masked responses are still parsed by the connectors
+const MASKED_EMAIL: &str = "masked@example.com";
+
+/// Replaces the personal data held in JSON numbers, such as the card expiry month, so that the
+/// masked values keep their JSON type
+const MASKED_NUMBER: &str = "0";
+
+/// Replaces the identifiers generated afresh by each run of a test in the URLs being matched
+const URL_ID_PLACEHOLDER: &str = "{id}";
+
+/// Names of the fields holding personal data, compared in lowercase without separators, so that
+/// `card_number`, `cardNumber` and `card-number` are all matched by `cardnumber`
+const PII_FIELDS: &[&str] = &[
+    "number",
+    "pan",
+    "cvc",
+    "cvv",
+    "cvv2",
+    "securitycode",
+    "expiry",
+    "expirymonth",
+    "expiryyear",
+    "expmonth",
+    "expyear",
+    "expirationdate",
+    "expirationmonth",
+    "expirationyear",
+    "name",
+    "holdername",
+    "cardholdername",
+    "firstname",
+    "lastname",
+    "fullname",
+    "line1",
+    "line2",
+    "line3",
+    "street",
+    "address1",
+    "address2",
+    "zip",
+    "zipcode",
+    "postalcode",
+    "postcode",
+    "ipaddress",
+    "iban",
+    "accountnumber",
+    "routingnumber",
+    "sortcode",
+    "dateofbirth",
+    "dob",
+];
+
+/// Fragments of the names of the fields holding personal data, such as `billing_email`
+const PII_FIELD_FRAGMENTS: &[&str] = &["cardnumber", "email", "phone"];
+
+/// A JSON field with a string value, the name and the value being captured along with the
+/// separator between them
+#[allow(clippy::expect_used)]
+static JSON_STRING_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""((?:[^"\\]|\\.)*)"(\s*:\s*)"((?:[^"\\]|\\.)*)""#)
+        .expect("Invalid JSON string field regex")
+});
+
+/// A JSON field with a number value, the name and the value being captured along with the
+/// separator between them
+#[allow(clippy::expect_used)]
+static JSON_NUMBER_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""((?:[^"\\]|\\.)*)"(\s*:\s*)(-?[0-9]+(?:\.[0-9]+)?(?:[eE][+-]?[0-9]+)?)"#)
+        .expect("Invalid JSON number field regex")
+});
+
+/// The fixtures being recorded or replayed in the current process, by fixture file
+static FIXTURES: Lazy<Mutex<HashMap<PathBuf, Fixture>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordReplayMode {
+    /// Requests are sent to the connector, and recorded along with their responses
+    Record,
+    /// Responses are served from the recorded fixture, without sending any request
+    Replay,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RecordedResponse {
+    pub status_code: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: RecordedBody,
+}
+
+/// The body of a recorded response, kept as text when it is valid UTF-8 so that the fixture can be
+/// reviewed, and base64 encoded otherwise, so that it is replayed byte for byte
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedBody {
+    Text(String),
+    Base64(String),
+}
+
+impl RecordedBody {
+    fn new(body: Vec<u8>) -> Self {
+        match String::from_utf8(body) {
+            Ok(body) => Self::Text(body),
+            Err(error) => Self::Base64(consts::BASE64_ENGINE.encode(error.into_bytes())),
+        }
+    }
+
+    fn into_bytes(self) -> CustomResult<Vec<u8>, ApiClientError> {
+        match self {
+            Self::Text(body) => Ok(body.into_bytes()),
+            Self::Base64(body) => consts::BASE64_ENGINE
+                .decode(body)
+                .into_report()
+                .change_context(ApiClientError::ResponseDecodingFailed)
+                .attach_printable("Failed to decode the recorded response body"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RecordedInteraction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Default)]
+struct Fixture {
+    interactions: Vec<RecordedInteraction>,
+    /// The number of interactions which have been replayed
+    replayed: usize,
+}
+
+/// Api client recording the connector interactions to a fixture file, or replaying them from it
+#[derive(Clone)]
+pub struct RecordReplayClient {
+    mode: RecordReplayMode,
+    fixture_path: PathBuf,
+    /// Values masked wherever they appear in the recorded interactions, such as the connector
+    /// credentials
+    masked_values: Vec<Secret<String>>,
+    request_id: Option<String>,
+}
+
+impl RecordReplayClient {
+    pub fn new(
+        mode: RecordReplayMode,
+        fixture_path: PathBuf,
+        masked_values: Vec<Secret<String>>,
+    ) -> Self {
+        Self {
+            mode,
+            fixture_path,
+            masked_values: masked_values
+                .into_iter()
+                .filter(|value| !value.peek().is_empty())
+                .collect(),
+            request_id: None,
+        }
+    }
+
+    fn mask(&self, value: &str) -> String {
+        self.masked_values
+            .iter()
+            .fold(value.to_string(), |value, masked_value| {
+                value.replace(masked_value.peek(), MASKED_VALUE)
+            })
+    }
+
+    /// Masks the secrets and the personal data in a request or response body. The body is
+    /// otherwise left untouched, so that the recorded response is replayed as it was received.
+    fn mask_body(&self, body: &[u8]) -> Vec<u8> {
+        match std::str::from_utf8(body) {
+            Ok(body) => {
+                let body = self.mask(body);
+                let trimmed_body = body.trim_start();
+                if trimmed_body.starts_with('{') || trimmed_body.starts_with('[') {
+                    mask_json_pii(&body)
+                } else if !body.contains(char::is_whitespace) && body.contains('=') {
+                    mask_form_pii(&body)
+                } else {
+                    body
+                }
+                .into_bytes()
+            }
+            Err(_) => self
+                .masked_values
+                .iter()
+                .fold(body.to_vec(), |body, value| {
+                    replace_bytes(&body, value.peek().as_bytes(), MASKED_VALUE.as_bytes())
+                }),
+        }
+    }
+
+    /// Masks the secrets in the URL, and the personal data in its query string
+    fn mask_url(&self, url: &str) -> String {
+        let url = self.mask(url);
+        match url.split_once('?') {
+            Some((path, query)) => format!("{path}?{}", mask_form_pii(query)),
+            None => url,
+        }
+    }
+
+    fn record_request(&self, request: &Request) -> RecordedRequest {
+        let headers = request
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    Maskable::Masked(_) => MASKED_VALUE.to_string(),
+                    Maskable::Normal(value) => self.mask(value),
+                };
+                (name.to_lowercase(), value)
+            })
+            .collect();
+
+        RecordedRequest {
+            method: request.method.to_string(),
+            url: self.mask_url(&request.url),
+            headers,
+            body: request.payload.as_ref().map(|payload| {
+                String::from_utf8_lossy(&self.mask_body(payload.peek().as_bytes())).into_owned()
+            }),
+        }
+    }
+
+    async fn record(
+        &self,
+        state: &AppState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let recorded_request = self.record_request(&request);
+        let response = crate::services::send_request(state, request, option_timeout_secs).await?;
+
+        let status_code = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), self.mask(value)))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let body = response
+            .bytes()
+            .await
+            .into_report()
+            .change_context(ApiClientError::ResponseDecodingFailed)?;
+
+        let interaction = RecordedInteraction {
+            request: recorded_request,
+            response: RecordedResponse {
+                status_code,
+                headers: headers.clone(),
+                body: RecordedBody::new(self.mask_body(&body)),
+            },
+        };
+
+        {
+            let mut fixtures = FIXTURES
+                .lock()
+                .map_err(|_| ApiClientError::UnexpectedState)
+                .into_report()
+                .attach_printable("Fixtures lock is poisoned")?;
+            // The fixture is recorded afresh by the first request of the test in the process
+            let fixture = fixtures.entry(self.fixture_path.clone()).or_default();
+            fixture.interactions.push(interaction);
+            write_fixture(&self.fixture_path, &fixture.interactions)?;
+        }
+
+        // The connector is handed the actual response, rather than the masked one
+        build_response(status_code, &headers, body.to_vec())
+    }
+
+    fn replay(&self, request: &Request) -> CustomResult<reqwest::Response, ApiClientError> {
+        let recorded_request = self.record_request(request);
+
+        let interaction = {
+            let mut fixtures = FIXTURES
+                .lock()
+                .map_err(|_| ApiClientError::UnexpectedState)
+                .into_report()
+                .attach_printable("Fixtures lock is poisoned")?;
+            let fixture = match fixtures.entry(self.fixture_path.clone()) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => entry.insert(Fixture {
+                    interactions: read_fixture(&self.fixture_path)?,
+                    replayed: 0,
+                }),
+            };
+
+            let interaction = fixture
+                .interactions
+                .get(fixture.replayed)
+                .cloned()
+                .ok_or(ApiClientError::UnexpectedState)
+                .into_report()
+                .attach_printable_lazy(|| {
+                    format!(
+                        "No recorded response left in {} for {} {}",
+                        self.fixture_path.display(),
+                        recorded_request.method,
+                        recorded_request.url
+                    )
+                })?;
+            fixture.replayed += 1;
+            interaction
+        };
+
+        if interaction.request.method != recorded_request.method
+            || get_url_template(&interaction.request.url) != get_url_template(&recorded_request.url)
+        {
+            return Err(ApiClientError::UnexpectedState)
+                .into_report()
+                .attach_printable(format!(
+                    "Request {} {} does not match the recorded request {} {} in {}",
+                    recorded_request.method,
+                    recorded_request.url,
+                    interaction.request.method,
+                    interaction.request.url,
+                    self.fixture_path.display()
+                ));
+        }
+
+        build_response(
+            interaction.response.status_code,
+            &interaction.response.headers,
+            interaction.response.body.into_bytes()?,
+        )
+    }
+}
+
+fn is_pii_field(name: &str) -> bool {
+    // The last segment of the nested form field names, such as `card[number]`
+    let name = name
+        .rsplit('[')
+        .next()
+        .unwrap_or(name)
+        .trim_end_matches(']')
+        .chars()
+        .filter(|character| !matches!(character, '_' | '-'))
+        .collect::<String>()
+        .to_lowercase();
+
+    PII_FIELDS.contains(&name.as_str())
+        || PII_FIELD_FRAGMENTS
+            .iter()
+            .any(|fragment| name.contains(fragment))
+}
+
+fn get_masked_pii(name: &str) -> &'static str {
+    if name.to_lowercase().contains("email") {
+        MASKED_EMAIL
+    } else {
+        MASKED_VALUE
+    }
+}
+
+/// Masks the string and number values of the personal data fields of a JSON body, without
+/// reformatting it
+fn mask_json_pii(body: &str) -> String {
+    let body = JSON_STRING_FIELD.replace_all(body, |captures: &Captures<'_>| {
+        let name = &captures[1];
+        if is_pii_field(name) {
+            format!("\"{name}\"{}\"{}\"", &captures[2], get_masked_pii(name))
+        } else {
+            captures[0].to_string()
+        }
+    });
+    JSON_NUMBER_FIELD
+        .replace_all(&body, |captures: &Captures<'_>| {
+            let name = &captures[1];
+            if is_pii_field(name) {
+                format!("\"{name}\"{}{MASKED_NUMBER}", &captures[2])
+            } else {
+                captures[0].to_string()
+            }
+        })
+        .into_owned()
+}
+
+/// Masks the values of the personal data fields of a form encoded body or query string
+fn mask_form_pii(body: &str) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            url::form_urlencoded::parse(body.as_bytes()).map(|(name, value)| {
+                let value = if is_pii_field(&name) {
+                    get_masked_pii(&name).into()
+                } else {
+                    value
+                };
+                (name, value)
+            }),
+        )
+        .finish()
+}
+
+fn replace_bytes(haystack: &[u8], needle: &[u8], replacement: &[u8]) -> Vec<u8> {
+    if needle.is_empty() {
+        return haystack.to_vec();
+    }
+
+    let mut replaced = Vec::with_capacity(haystack.len());
+    let mut rest = haystack;
+    while let Some((byte, remaining)) = rest.split_first() {
+        match rest.strip_prefix(needle) {
+            Some(after_needle) => {
+                replaced.extend_from_slice(replacement);
+                rest = after_needle;
+            }
+            None => {
+                replaced.push(*byte);
+                rest = remaining;
+            }
+        }
+    }
+    replaced
+}
+
+/// The URL with the identifiers generated afresh by each run of a test, such as the UUIDs used as
+/// payment references and the timestamps, replaced by a placeholder in its path and query string
+fn get_url_template(url: &str) -> String {
+    let is_generated_id = |value: &str| {
+        value.len() >= 8
+            && value.chars().all(|character| {
+                character.is_ascii_alphanumeric() || matches!(character, '-' | '_')
+            })
+            && value.chars().any(|character| character.is_ascii_digit())
+    };
+    let template = |value: &str| {
+        if is_generated_id(value) {
+            URL_ID_PLACEHOLDER.to_string()
+        } else {
+            value.to_string()
+        }
+    };
+
+    let (path, query) = match url.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (url, None),
+    };
+    let path = path.split('/').map(template).collect::<Vec<_>>().join("/");
+
+    match query {
+        Some(query) => {
+            let query = query
+                .split('&')
+                .map(|pair| match pair.split_once('=') {
+                    Some((name, value)) => format!("{name}={}", template(value)),
+                    None => pair.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("&");
+            format!("{path}?{query}")
+        }
+        None => path,
+    }
+}
+
+fn read_fixture(path: &Path) -> CustomResult<Vec<RecordedInteraction>, ApiClientError> {
+    let contents = std::fs::read_to_string(path)
+        .into_report()
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable_lazy(|| format!("Failed to read the fixture {}", path.display()))?;
+
+    serde_json::from_str(&contents)
+        .into_report()
+        .change_context(ApiClientError::ResponseDecodingFailed)
+        .attach_printable_lazy(|| format!("Failed to parse the fixture {}", path.display()))
+}
+
+fn write_fixture(
+    path: &Path,
+    interactions: &[RecordedInteraction],
+) -> CustomResult<(), ApiClientError> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)
+            .into_report()
+            .change_context(ApiClientError::UnexpectedState)
+            .attach_printable("Failed to create the fixtures directory")?;
+    }
+
+    let contents = serde_json::to_string_pretty(interactions)
+        .into_report()
+        .change_context(ApiClientError::BodySerializationFailed)?;
+
+    std::fs::write(path, contents)
+        .into_report()
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable_lazy(|| format!("Failed to write the fixture {}", path.display()))
+}
+
+fn build_response(
+    status_code: u16,
+    headers: &BTreeMap<String, String>,
+    body: Vec<u8>,
+) -> CustomResult<reqwest::Response, ApiClientError> {
+    let response = headers
+        .iter()
+        // The body is recorded decoded, and hence without its original framing
+        .filter(|(name, _)| {
+            !matches!(
+                name.as_str(),
+                "content-length" | "content-encoding" | "transfer-encoding"
+            )
+        })
+        .fold(
+            http::Response::builder().status(status_code),
+            |builder, (name, value)| builder.header(name, value),
+        )
+        .body(body)
+        .into_report()
+        .change_context(ApiClientError::ResponseDecodingFailed)
+        .attach_printable("Failed to construct the recorded response")?;
+
+    Ok(reqwest::Response::from(response))
+}
+
+#[async_trait::async_trait]
+impl ApiClient for RecordReplayClient {
+    fn request(
+        &self,
+        _method: Method,
+        _url: String,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        Err(ApiClientError::UnexpectedState.into())
+    }
+
+    fn request_with_certificate(
+        &self,
+        _method: Method,
+        _url: String,
+        _certificate: Option<String>,
+        _certificate_key: Option<String>,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        Err(ApiClientError::UnexpectedState.into())
+    }
+
+    async fn send_request(
+        &self,
+        state: &AppState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        _forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        match self.mode {
+            RecordReplayMode::Record => self.record(state, request, option_timeout_secs).await,
+            RecordReplayMode::Replay => self.replay(&request),
+        }
+    }
+
+    fn add_request_id(&mut self, request_id: Option<String>) {
+        self.request_id = request_id
+    }
+
+    fn get_request_id(&self) -> Option<String> {
+        self.request_id.clone()
+    }
+
+    fn add_merchant_id(&mut self, _merchant_id: Option<String>) {}
+
+    fn add_flow_name(&mut self, _flow_name: String) {}
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_client() -> RecordReplayClient {
+        RecordReplayClient::new(
+            RecordReplayMode::Replay,
+            PathBuf::from("fixture.json"),
+            vec![
+                Secret::new("sk_test_123".to_string()),
+                Secret::new(String::new()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_secrets_are_masked_in_recorded_request() {
+        let mut request = Request::new(
+            crate::services::Method::Post,
+            "https://connector.test/v1/payments?key=sk_test_123",
+        );
+        request.add_header(
+            "Authorization",
+            Maskable::new_masked(Secret::new("Bearer sk_test_123".to_string())),
+        );
+        request.add_header(
+            "Content-Type",
+            Maskable::new_normal("application/json".to_string()),
+        );
+        request.set_body(r#"{"amount":1000,"api_key":"sk_test_123"}"#.to_string());
+
+        let recorded_request = get_client().record_request(&request);
+
+        assert_eq!(recorded_request.method, "POST");
+        assert_eq!(
+            recorded_request.url,
+            "https://connector.test/v1/payments?key=*** masked ***"
+        );
+        assert_eq!(
+            recorded_request.headers.get("authorization").unwrap(),
+            MASKED_VALUE
+        );
+        assert_eq!(
+            recorded_request.headers.get("content-type").unwrap(),
+            "application/json"
+        );
+        assert_eq!(
+            recorded_request.body.unwrap(),
+            r#"{"amount":1000,"api_key":"*** masked ***"}"#
+        );
+    }
+
+    #[test]
+    fn test_personal_data_is_masked() {
+        let client = get_client();
+
+        let body = client.mask_body(
+            br#"{"source": {"number": "4242424242424242", "cvv": "123"}, "billing_email": "jane@example.com", "amount": 100, "id": "pay_123"}"#,
+        );
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            r#"{"source": {"number": "*** masked ***", "cvv": "*** masked ***"}, "billing_email": "masked@example.com", "amount": 100, "id": "pay_123"}"#
+        );
+
+        let body = client.mask_body(
+            br#"{"source":{"type":"card","expiry_month":6,"expiry_year":2025,"last4":"4242"},"amount":100}"#,
+        );
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            r#"{"source":{"type":"card","expiry_month":0,"expiry_year":0,"last4":"4242"},"amount":100}"#
+        );
+
+        let body = client.mask_body(
+            b"amount=100&payment_method_data%5Bcard%5D%5Bnumber%5D=4242424242424242&billing_details%5Bname%5D=Jane",
+        );
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "amount=100&payment_method_data%5Bcard%5D%5Bnumber%5D=***+masked+***&billing_details%5Bname%5D=***+masked+***"
+        );
+
+        assert_eq!(
+            client
+                .mask_url("https://connector.test/v1/customers?email=jane%40example.com&limit=10"),
+            "https://connector.test/v1/customers?email=masked%40example.com&limit=10"
+        );
+    }
+
+    #[test]
+    fn test_binary_response_body_is_recorded_raw() {
+        let body = vec![0x25, 0x50, 0x44, 0x46, 0xff, 0x00];
+
+        let recorded_body = RecordedBody::new(get_client().mask_body(&body));
+
+        assert!(matches!(recorded_body, RecordedBody::Base64(_)));
+        assert_eq!(recorded_body.into_bytes().unwrap(), body);
+    }
+
+    #[test]
+    fn test_generated_ids_are_ignored_in_url_template() {
+        assert_eq!(
+            get_url_template(
+                "https://connector.test/v1/payments/2b7e1b52-5d4b-4c1e-9c6a-2f3b1e6f0a11/captures?ts=1697625600&mode=test"
+            ),
+            "https://connector.test/v1/payments/{id}/captures?ts={id}&mode=test"
+        );
+        assert_eq!(
+            get_url_template("https://connector.test/v1/payments"),
+            "https://connector.test/v1/payments"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recorded_response_is_rebuilt() {
+        let headers = BTreeMap::from([
+            ("content-type".to_string(), "application/json".to_string()),
+            ("content-length".to_string(), "1".to_string()),
+        ]);
+
+        let response = build_response(201, &headers, br#"{"id":"pay_123"}"#.to_vec()).unwrap();
+
+        assert_eq!(response.status().as_u16(), 201);
+        assert!(response.headers().get("content-length").is_none());
+        assert_eq!(response.text().await.unwrap(), r#"{"id":"pay_123"}"#);
+    }
+}
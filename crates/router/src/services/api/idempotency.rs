@@ -0,0 +1,471 @@
+use std::{cell::Cell, future::Future};
+
+use actix_web::{body, http, HttpMessage, HttpRequest, HttpResponse};
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage},
+    ext_traits::{ByteSliceExt, Encode},
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+use redis_interface::SetnxReply;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use storage_impl::redis::kv_store::RedisConnInterface;
+
+use crate::{
+    configs::settings::IdempotencyConfig,
+    core::errors::{self, CustomResult},
+    db::{MasterKeyInterface, StorageInterface},
+    headers, utils,
+};
+
+/// Maximum length of an idempotency key accepted in a request
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// Request headers which identify the caller of a request, used to scope idempotency keys
+const CREDENTIAL_HEADERS: [&str; 2] = [headers::API_KEY, headers::AUTHORIZATION];
+
+tokio::task_local! {
+    /// Whether the request being processed has sent a request to a connector
+    static CONNECTOR_CALLED: Cell<bool>;
+}
+
+/// Records that the request being processed has sent a request to a connector, after which its
+/// server errors are stored with its idempotency key rather than releasing the key, since a retry
+/// could otherwise repeat the connector call
+pub fn record_connector_call() {
+    // Outside of an idempotent request there is nothing to record
+    let _ = CONNECTOR_CALLED.try_with(|connector_called| connector_called.set(true));
+}
+
+/// Runs `future`, returning its output along with whether it sent a request to a connector
+pub async fn track_connector_calls<F: Future>(future: F) -> (F::Output, bool) {
+    CONNECTOR_CALLED
+        .scope(Cell::new(false), async move {
+            let output = future.await;
+            (output, CONNECTOR_CALLED.with(Cell::get))
+        })
+        .await
+}
+
+/// SHA-256 fingerprint of the method, path, query and raw body of a request carrying an
+/// idempotency key. It is computed by the [`crate::middleware::IdempotencyFingerprint`]
+/// middleware, as the raw body is no longer available once the route has deserialized it.
+#[derive(Clone, Debug)]
+pub struct RequestFingerprint(String);
+
+impl RequestFingerprint {
+    pub fn new(request: &HttpRequest, body: &[u8]) -> Self {
+        let mut message = Vec::with_capacity(body.len() + 256);
+        for part in [
+            request.method().as_str(),
+            request.path(),
+            request.query_string(),
+        ] {
+            message.extend_from_slice(part.as_bytes());
+            message.push(b'\n');
+        }
+        message.extend_from_slice(body);
+
+        Self(sha256_hex(&message))
+    }
+}
+
+/// Whether the idempotency key of a request applies to it, that is, whether it is a `POST`
+/// request carrying the `Idempotency-Key` header
+pub fn is_idempotent_request(method: &http::Method, headers: &http::header::HeaderMap) -> bool {
+    method == http::Method::POST && headers.contains_key(headers::IDEMPOTENCY_KEY)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IdempotencyRecord {
+    fingerprint: String,
+    /// The hex encoded response of the first request, encrypted with the master key as it can
+    /// hold secrets such as API keys and client secrets, or `None` while it is being processed
+    response: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredResponse {
+    status_code: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl StoredResponse {
+    fn encrypt(&self, key: &[u8]) -> CustomResult<String, errors::ApiErrorResponse> {
+        let response = Encode::<Self>::encode_to_vec(self)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let encrypted_response = crypto::GcmAes256
+            .encode_message(key, &response)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the response of the idempotent request")?;
+        Ok(hex::encode(encrypted_response))
+    }
+
+    fn decrypt(
+        encrypted_response: &str,
+        key: &[u8],
+    ) -> CustomResult<Self, errors::ApiErrorResponse> {
+        let encrypted_response = hex::decode(encrypted_response)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        crypto::GcmAes256
+            .decode_message(key, Secret::new(encrypted_response))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to decrypt the response of the idempotent request")?
+            .parse_struct::<Self>("StoredResponse")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    }
+
+    fn to_http_response(&self) -> HttpResponse {
+        let mut response_builder = HttpResponse::build(
+            http::StatusCode::from_u16(self.status_code)
+                .unwrap_or(http::StatusCode::INTERNAL_SERVER_ERROR),
+        );
+        for header in &self.headers {
+            response_builder.append_header(header.clone());
+        }
+        response_builder
+            .append_header((headers::IDEMPOTENT_REPLAYED, "true"))
+            .body(self.body.clone())
+    }
+}
+
+#[derive(Debug)]
+pub enum IdempotencyStatus {
+    /// No request has been made with the key yet, and the key is now held by this request
+    New,
+    /// A request has already completed with the key, and its response is to be replayed
+    Completed(HttpResponse),
+}
+
+#[derive(Debug)]
+pub struct IdempotencyKey {
+    redis_key: String,
+    fingerprint: RequestFingerprint,
+}
+
+impl IdempotencyKey {
+    /// Reads the idempotency key of a request, if the key applies to the request. Keys are
+    /// scoped to the credentials the request is made with, so that callers cannot observe the
+    /// responses to each other's requests.
+    pub fn from_request(
+        request: &HttpRequest,
+    ) -> CustomResult<Option<Self>, errors::ApiErrorResponse> {
+        if !is_idempotent_request(request.method(), request.headers()) {
+            return Ok(None);
+        }
+
+        let key = request
+            .headers()
+            .get(headers::IDEMPOTENCY_KEY)
+            .map(|value| value.to_str())
+            .transpose()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Idempotency-Key header contains invalid characters".to_string(),
+            })?
+            .unwrap_or_default()
+            .trim();
+
+        utils::when(
+            key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LENGTH,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Idempotency-Key must be between 1 and {MAX_IDEMPOTENCY_KEY_LENGTH} characters long"
+                    ),
+                })
+            },
+        )?;
+
+        // Ignoring the key would silently disable the guarantee the caller asked for
+        let fingerprint = request
+            .extensions()
+            .get::<RequestFingerprint>()
+            .cloned()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Request body of the idempotent request was not fingerprinted")?;
+
+        let credentials = CREDENTIAL_HEADERS
+            .iter()
+            .filter_map(|header| request.headers().get(*header))
+            .map(|value| value.as_bytes())
+            .collect::<Vec<_>>()
+            .join(&b'\n');
+
+        Ok(Some(Self {
+            redis_key: format!("idempotency_{}_{key}", sha256_hex(&credentials)),
+            fingerprint,
+        }))
+    }
+
+    /// Holds the key for the current request, unless a request has already been made with it.
+    /// A request made earlier with the key fails this request if it is still being processed, or
+    /// if it was made with a different method, path or body.
+    #[instrument(skip_all)]
+    pub async fn start(
+        &self,
+        db: &dyn StorageInterface,
+        config: &IdempotencyConfig,
+    ) -> CustomResult<IdempotencyStatus, errors::ApiErrorResponse> {
+        let redis_conn = db
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+
+        let in_progress =
+            Encode::<IdempotencyRecord>::encode_to_string_of_json(&IdempotencyRecord {
+                fingerprint: self.fingerprint.0.clone(),
+                response: None,
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        let reply = redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &self.redis_key,
+                in_progress,
+                Some(config.lock_ttl_in_secs),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to hold the idempotency key")?;
+
+        if reply == SetnxReply::KeySet {
+            return Ok(IdempotencyStatus::New);
+        }
+
+        let record = redis_conn
+            .get_key::<Option<Vec<u8>>>(&self.redis_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the idempotency key")?
+            // The key expired after it was found to exist, and is treated as still held
+            .ok_or(errors::ApiErrorResponse::IdempotencyKeyInUse)
+            .into_report()?
+            .parse_struct::<IdempotencyRecord>("IdempotencyRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        utils::when(record.fingerprint != self.fingerprint.0, || {
+            Err(errors::ApiErrorResponse::IdempotencyKeyReused)
+        })?;
+
+        match record.response {
+            Some(response) => Ok(IdempotencyStatus::Completed(
+                StoredResponse::decrypt(&response, db.get_master_key())?.to_http_response(),
+            )),
+            None => Err(errors::ApiErrorResponse::IdempotencyKeyInUse).into_report(),
+        }
+    }
+
+    /// Stores the response of the request holding the key, for it to be replayed to retries.
+    /// Server errors of requests which did not call a connector are not stored, and release the
+    /// key instead, so that the request can be retried. Failing to store the response is logged,
+    /// and the key is released when its hold expires.
+    #[instrument(skip_all)]
+    pub async fn complete(
+        &self,
+        db: &dyn StorageInterface,
+        config: &IdempotencyConfig,
+        response: HttpResponse,
+        connector_called: bool,
+    ) -> HttpResponse {
+        let (response, response_body) = response.into_parts();
+        let response_body = match body::to_bytes(response_body).await {
+            Ok(response_body) => response_body,
+            Err(error) => {
+                logger::error!(?error, "Failed to read the response body");
+                self.release(db).await;
+                return HttpResponse::InternalServerError().finish();
+            }
+        };
+
+        if response.status().is_server_error() && !connector_called {
+            self.release(db).await;
+            return response.set_body(response_body).map_into_boxed_body();
+        }
+
+        match String::from_utf8(response_body.to_vec()) {
+            Ok(body) => {
+                let stored_response = StoredResponse {
+                    status_code: response.status().as_u16(),
+                    headers: response
+                        .headers()
+                        .iter()
+                        .filter(|(name, _)| !is_request_specific_header(name))
+                        .filter_map(|(name, value)| {
+                            value
+                                .to_str()
+                                .ok()
+                                .map(|value| (name.to_string(), value.to_string()))
+                        })
+                        .collect(),
+                    body,
+                };
+
+                if let Err(error) = self.store_response(db, config, stored_response).await {
+                    logger::error!(
+                        ?error,
+                        "Failed to store the response of the idempotent request"
+                    );
+                }
+            }
+            Err(_) => {
+                logger::warn!("Response body is not valid UTF-8, not storing it");
+                self.release(db).await;
+            }
+        }
+
+        response.set_body(response_body).map_into_boxed_body()
+    }
+
+    /// Releases the key held by the current request, for the request to be retried with it
+    async fn release(&self, db: &dyn StorageInterface) {
+        let result = match db.get_redis_conn() {
+            Ok(redis_conn) => redis_conn.delete_key(&self.redis_key).await.map(|_| ()),
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            logger::error!(?error, "Failed to release the idempotency key");
+        }
+    }
+
+    async fn store_response(
+        &self,
+        db: &dyn StorageInterface,
+        config: &IdempotencyConfig,
+        response: StoredResponse,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        let completed = Encode::<IdempotencyRecord>::encode_to_string_of_json(&IdempotencyRecord {
+            fingerprint: self.fingerprint.0.clone(),
+            response: Some(response.encrypt(db.get_master_key())?),
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        db.get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .set_key_with_expiry(&self.redis_key, completed, config.ttl_in_secs)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    }
+}
+
+/// Headers of a response which identify the request it was made to, and are hence not replayed
+fn is_request_specific_header(name: &http::header::HeaderName) -> bool {
+    name.as_str().eq_ignore_ascii_case(headers::X_REQUEST_ID)
+}
+
+fn sha256_hex(message: &[u8]) -> String {
+    hex::encode(ring::digest::digest(&ring::digest::SHA256, message))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    #[test]
+    fn test_idempotency_key_applies_to_post_requests_only() {
+        let request = TestRequest::post()
+            .insert_header((headers::IDEMPOTENCY_KEY, "key_1"))
+            .to_http_request();
+        assert!(is_idempotent_request(request.method(), request.headers()));
+
+        let request = TestRequest::get()
+            .insert_header((headers::IDEMPOTENCY_KEY, "key_1"))
+            .to_http_request();
+        assert!(!is_idempotent_request(request.method(), request.headers()));
+
+        let request = TestRequest::post().to_http_request();
+        assert!(!is_idempotent_request(request.method(), request.headers()));
+    }
+
+    #[test]
+    fn test_idempotency_key_is_scoped_to_credentials() {
+        let make_key = |api_key: &str, body: &[u8]| {
+            let request = TestRequest::post()
+                .uri("/customers")
+                .insert_header((headers::IDEMPOTENCY_KEY, "key_1"))
+                .insert_header((headers::API_KEY, api_key))
+                .to_http_request();
+            request
+                .extensions_mut()
+                .insert(RequestFingerprint::new(&request, body));
+            IdempotencyKey::from_request(&request).unwrap().unwrap()
+        };
+
+        let key = make_key("api_key_1", b"{}");
+        assert_eq!(key.redis_key, make_key("api_key_1", b"{}").redis_key);
+        assert_ne!(key.redis_key, make_key("api_key_2", b"{}").redis_key);
+        assert_eq!(
+            key.fingerprint.0,
+            make_key("api_key_2", b"{}").fingerprint.0
+        );
+        assert_ne!(
+            key.fingerprint.0,
+            make_key("api_key_1", b"{\"a\":1}").fingerprint.0
+        );
+    }
+
+    #[test]
+    fn test_request_id_is_not_replayed() {
+        assert!(is_request_specific_header(
+            &http::header::HeaderName::from_static("x-request-id")
+        ));
+        assert!(!is_request_specific_header(&http::header::CONTENT_TYPE));
+    }
+
+    #[test]
+    fn test_stored_response_is_encrypted() {
+        let key = [1u8; 32];
+        let response = StoredResponse {
+            status_code: 200,
+            headers: vec![],
+            body: r#"{"api_key":"snd_secret"}"#.to_string(),
+        };
+
+        let encrypted_response = response.encrypt(&key).unwrap();
+        assert!(
+            !String::from_utf8_lossy(&hex::decode(&encrypted_response).unwrap())
+                .contains("snd_secret")
+        );
+
+        let decrypted_response = StoredResponse::decrypt(&encrypted_response, &key).unwrap();
+        assert_eq!(decrypted_response.body, response.body);
+        assert!(StoredResponse::decrypt(&encrypted_response, &[2u8; 32]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connector_calls_are_tracked() {
+        let (_, connector_called) = track_connector_calls(async {}).await;
+        assert!(!connector_called);
+
+        let (_, connector_called) = track_connector_calls(async { record_connector_call() }).await;
+        assert!(connector_called);
+    }
+
+    #[test]
+    fn test_request_without_fingerprint_is_rejected() {
+        let request = TestRequest::post()
+            .insert_header((headers::IDEMPOTENCY_KEY, "key_1"))
+            .to_http_request();
+
+        assert!(IdempotencyKey::from_request(&request).is_err());
+    }
+
+    #[test]
+    fn test_idempotency_key_length_is_validated() {
+        let request = TestRequest::post()
+            .insert_header((headers::IDEMPOTENCY_KEY, "a".repeat(256)))
+            .to_http_request();
+        request
+            .extensions_mut()
+            .insert(RequestFingerprint::new(&request, b"{}"));
+
+        assert!(IdempotencyKey::from_request(&request).is_err());
+    }
+}
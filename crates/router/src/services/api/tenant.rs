@@ -0,0 +1,127 @@
+use actix_web::{http, HttpMessage, HttpRequest};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{
+    configs::settings::Multitenancy,
+    core::errors::{self, RouterResult},
+    headers,
+};
+
+/// ID of the tenant a request is served for. It is resolved by the
+/// [`crate::middleware::TenantResolver`] middleware and stored in the request extensions.
+#[derive(Clone, Debug)]
+pub struct TenantId(String);
+
+/// Resolve the tenant of a request from the `X-Tenant-Id` header, falling back to the hostname the
+/// request was sent to.
+pub fn resolve_tenant(
+    multitenancy: &Multitenancy,
+    request_headers: &http::header::HeaderMap,
+    host: &str,
+) -> RouterResult<Option<TenantId>> {
+    if let Some(tenant_id) = request_headers.get(headers::X_TENANT_ID) {
+        let tenant_id = tenant_id.to_str().into_report().change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`{}` header is invalid", headers::X_TENANT_ID),
+            },
+        )?;
+
+        return if multitenancy.tenants.contains_key(tenant_id) {
+            Ok(Some(TenantId(tenant_id.to_owned())))
+        } else {
+            Err(errors::ApiErrorResponse::InvalidTenant {
+                tenant_id: tenant_id.to_owned(),
+            })
+            .into_report()
+        };
+    }
+
+    let hostname = host
+        .parse::<http::uri::Authority>()
+        .map(|authority| authority.host().to_owned())
+        .unwrap_or_else(|_| host.to_owned());
+
+    Ok(multitenancy
+        .tenants
+        .iter()
+        .find(|(_, tenant)| {
+            tenant
+                .hostnames
+                .iter()
+                .any(|tenant_hostname| tenant_hostname.eq_ignore_ascii_case(&hostname))
+        })
+        .map(|(tenant_id, _)| TenantId(tenant_id.clone())))
+}
+
+/// ID of the tenant the request was resolved to, if any
+pub fn get_tenant_id(request: &HttpRequest) -> Option<String> {
+    request
+        .extensions()
+        .get::<TenantId>()
+        .map(|tenant_id| tenant_id.0.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::test::TestRequest;
+
+    use super::*;
+    use crate::configs::settings::Tenant;
+
+    fn multitenancy() -> Multitenancy {
+        Multitenancy {
+            enabled: true,
+            tenants: [(
+                "acme".to_string(),
+                Tenant {
+                    hostnames: vec!["acme.example.com".to_string()],
+                    schema: "acme".to_string(),
+                    redis_key_prefix: "acme".to_string(),
+                    ..Default::default()
+                },
+            )]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn test_tenant_is_resolved_from_header() {
+        let request = TestRequest::default()
+            .insert_header((headers::X_TENANT_ID, "acme"))
+            .to_http_request();
+        let tenant_id = resolve_tenant(&multitenancy(), request.headers(), "localhost:8080")
+            .unwrap()
+            .unwrap();
+        assert_eq!(tenant_id.0, "acme");
+    }
+
+    #[test]
+    fn test_unknown_tenant_header_is_rejected() {
+        let request = TestRequest::default()
+            .insert_header((headers::X_TENANT_ID, "unknown"))
+            .to_http_request();
+        let error =
+            resolve_tenant(&multitenancy(), request.headers(), "acme.example.com").unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidTenant { tenant_id } if tenant_id == "unknown"
+        ));
+    }
+
+    #[test]
+    fn test_tenant_is_resolved_from_hostname() {
+        let request = TestRequest::default().to_http_request();
+        let tenant_id = resolve_tenant(&multitenancy(), request.headers(), "ACME.example.com:443")
+            .unwrap()
+            .unwrap();
+        assert_eq!(tenant_id.0, "acme");
+
+        assert!(
+            resolve_tenant(&multitenancy(), request.headers(), "other.example.com")
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -24,6 +24,8 @@ use crate::{
 pub struct AuthenticationData {
     pub merchant_account: domain::MerchantAccount,
     pub key_store: domain::MerchantKeyStore,
+    /// The identifier of the API key the request was authenticated with, if any
+    pub api_key_id: Option<String>,
 }
 
 pub trait AuthInfo {
@@ -99,7 +101,9 @@ where
             api_keys::get_hash_key(
                 &config.api_keys,
                 #[cfg(feature = "kms")]
-                kms::get_kms_client(&config.kms).await,
+                kms::get_kms_client(&config.kms)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
             )
             .await?
         };
@@ -142,6 +146,7 @@ where
         Ok(AuthenticationData {
             merchant_account: merchant,
             key_store,
+            api_key_id: Some(stored_api_key.key_id),
         })
     }
 }
@@ -191,7 +196,9 @@ where
         let admin_api_key = get_admin_api_key(
             &conf.secrets,
             #[cfg(feature = "kms")]
-            kms::get_kms_client(&conf.kms).await,
+            kms::get_kms_client(&conf.kms)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
         )
         .await?;
 
@@ -248,6 +255,7 @@ where
         Ok(AuthenticationData {
             merchant_account: merchant,
             key_store,
+            api_key_id: None,
         })
     }
 }
@@ -346,6 +354,7 @@ where
         Ok(AuthenticationData {
             merchant_account: merchant,
             key_store,
+            api_key_id: None,
         })
     }
 }
@@ -484,7 +493,9 @@ where
     let secret = get_jwt_secret(
         &conf.secrets,
         #[cfg(feature = "kms")]
-        kms::get_kms_client(&conf.kms).await,
+        kms::get_kms_client(&conf.kms)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
     )
     .await?
     .peek()
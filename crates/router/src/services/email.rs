@@ -0,0 +1,68 @@
+//! Templates of the emails sent to merchants, rendered using
+//! [`external_services::email::render_template`].
+
+use common_utils::pii;
+use external_services::email::{self, EmailContents, EmailData, EmailResult};
+
+const API_KEY_EXPIRY_REMINDER_SUBJECT: &str = "API Key Expiry Notice";
+const API_KEY_EXPIRY_REMINDER_BODY: &str = include_str!("email/assets/api_key_expiry_reminder.txt");
+
+/// Reminder sent to a merchant before one of its API keys expires.
+#[derive(Debug, Clone)]
+pub struct ApiKeyExpiryReminder {
+    pub recipient_email: pii::Email,
+    pub key_id: String,
+    pub expires_in: u8,
+}
+
+impl EmailData for ApiKeyExpiryReminder {
+    fn get_email_data(&self) -> EmailResult<EmailContents> {
+        let expires_in = self.expires_in.to_string();
+        let body = email::render_template(
+            API_KEY_EXPIRY_REMINDER_BODY,
+            &[
+                ("key_id", self.key_id.as_str()),
+                ("expires_in", expires_in.as_str()),
+            ],
+        )?;
+
+        Ok(EmailContents {
+            subject: API_KEY_EXPIRY_REMINDER_SUBJECT.to_string(),
+            body,
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_api_key_expiry_reminder_is_rendered() {
+        let reminder = ApiKeyExpiryReminder {
+            recipient_email: pii::Email::from_str("merchant@example.com").unwrap(),
+            key_id: "dev_abc123".to_string(),
+            expires_in: 7,
+        };
+
+        let contents = reminder.get_email_data().unwrap();
+
+        assert_eq!(contents.subject, API_KEY_EXPIRY_REMINDER_SUBJECT);
+        assert!(contents
+            .body
+            .contains("your API key dev_abc123 will expire in 7 days"));
+        assert!(!contents.body.contains("{{"));
+    }
+
+    #[test]
+    fn test_missing_template_variable_fails() {
+        let rendered = email::render_template(API_KEY_EXPIRY_REMINDER_BODY, &[("key_id", "id")]);
+
+        assert!(rendered.is_err());
+    }
+}
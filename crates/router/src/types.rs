@@ -58,6 +58,11 @@ pub type PaymentsApproveRouterData =
     RouterData<api::Approve, PaymentsApproveData, PaymentsResponseData>;
 pub type PaymentsSessionRouterData =
     RouterData<api::Session, PaymentsSessionData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationRouterData = RouterData<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
@@ -83,6 +88,12 @@ pub type PaymentsInitResponseRouterData<R> =
     ResponseRouterData<api::InitPayment, R, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsCaptureResponseRouterData<R> =
     ResponseRouterData<api::Capture, R, PaymentsCaptureData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationResponseRouterData<R> = ResponseRouterData<
+    api::IncrementalAuthorization,
+    R,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type TokenizationResponseRouterData<R> = ResponseRouterData<
     api::PaymentMethodToken,
     R,
@@ -133,6 +144,11 @@ pub type PaymentsSessionType =
     dyn services::ConnectorIntegration<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsVoidType =
     dyn services::ConnectorIntegration<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationType = dyn services::ConnectorIntegration<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type TokenizationType = dyn services::ConnectorIntegration<
     api::PaymentMethodToken,
     PaymentMethodTokenizationData,
@@ -222,6 +238,60 @@ pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
 pub type PayoutsResponseRouterData<F, R> =
     ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
 
+pub type FrmCheckoutRouterData =
+    RouterData<api::fraud_check::Checkout, FraudCheckCheckoutData, FraudCheckResponseData>;
+
+pub type FrmTransactionRouterData =
+    RouterData<api::fraud_check::Transaction, FraudCheckTransactionData, FraudCheckResponseData>;
+
+pub type FrmCheckoutType = dyn services::ConnectorIntegration<
+    api::fraud_check::Checkout,
+    FraudCheckCheckoutData,
+    FraudCheckResponseData,
+>;
+
+pub type FrmTransactionType = dyn services::ConnectorIntegration<
+    api::fraud_check::Transaction,
+    FraudCheckTransactionData,
+    FraudCheckResponseData,
+>;
+
+pub type PreAuthNRouterData = RouterData<
+    api::authentication::PreAuthentication,
+    PreAuthNRequestData,
+    AuthenticationResponseData,
+>;
+
+pub type ConnectorAuthenticationRouterData = RouterData<
+    api::authentication::Authentication,
+    ConnectorAuthenticationRequestData,
+    AuthenticationResponseData,
+>;
+
+pub type ConnectorPostAuthenticationRouterData = RouterData<
+    api::authentication::PostAuthentication,
+    ConnectorPostAuthenticationRequestData,
+    AuthenticationResponseData,
+>;
+
+pub type PreAuthNType = dyn services::ConnectorIntegration<
+    api::authentication::PreAuthentication,
+    PreAuthNRequestData,
+    AuthenticationResponseData,
+>;
+
+pub type ConnectorAuthenticationType = dyn services::ConnectorIntegration<
+    api::authentication::Authentication,
+    ConnectorAuthenticationRequestData,
+    AuthenticationResponseData,
+>;
+
+pub type ConnectorPostAuthenticationType = dyn services::ConnectorIntegration<
+    api::authentication::PostAuthentication,
+    ConnectorPostAuthenticationRequestData,
+    AuthenticationResponseData,
+>;
+
 #[derive(Debug, Clone)]
 pub struct RouterData<Flow, Request, Response> {
     pub flow: PhantomData<Flow>,
@@ -280,6 +350,7 @@ pub struct RouterData<Flow, Request, Response> {
 pub enum PaymentMethodToken {
     Token(String),
     ApplePayDecrypt(Box<ApplePayPredecryptData>),
+    GooglePayDecrypt(Box<GooglePayPredecryptData>),
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -301,6 +372,38 @@ pub struct ApplePayCryptogramData {
     pub eci_indicator: Option<Secret<String>>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayPredecryptData {
+    /// Expiration of the payment token, in milliseconds since the epoch
+    pub message_expiration: String,
+    pub message_id: String,
+    pub payment_method: String,
+    pub payment_method_details: GooglePayPaymentMethodDetails,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayPaymentMethodDetails {
+    pub auth_method: GooglePayAuthMethod,
+    /// The card number, or the network token when authenticated with a cryptogram
+    pub pan: Secret<String>,
+    pub expiration_month: Secret<u8>,
+    pub expiration_year: Secret<u16>,
+    pub cryptogram: Option<Secret<String>>,
+    pub eci_indicator: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+pub enum GooglePayAuthMethod {
+    /// The card stored in the Google account, without a cryptogram
+    #[serde(rename = "PAN_ONLY")]
+    PanOnly,
+    /// A network token bound to the device, along with its cryptogram
+    #[serde(rename = "CRYPTOGRAM_3DS")]
+    Cryptogram3ds,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentMethodBalance {
     pub amount: i64,
@@ -335,6 +438,102 @@ pub struct PayoutsFulfillResponseData {
     pub reference_id: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct FraudCheckCheckoutData {
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub payment_method_data: Option<payments::PaymentMethodData>,
+    pub browser_info: Option<BrowserInformation>,
+    pub email: Option<Email>,
+    pub gateway: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckTransactionData {
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub gateway: Option<String>,
+    pub connector_transaction_id: Option<String>,
+    pub attempt_status: storage_enums::AttemptStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreAuthNRequestData {
+    pub card_holder_account_number: cards::CardNumber,
+    /// The URL the ACS notifies once the 3DS method has completed in the browser
+    pub three_ds_method_notification_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectorAuthenticationRequestData {
+    pub payment_method_data: payments::PaymentMethodData,
+    pub browser_details: Option<BrowserInformation>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub email: Option<Email>,
+    pub threeds_server_transaction_id: String,
+    pub message_version: String,
+    pub three_ds_method_comp_ind: storage_enums::ThreeDsCompletionIndicator,
+    /// The URL the ACS posts the challenge result (`CRes`) to
+    pub notification_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectorPostAuthenticationRequestData {
+    pub threeds_server_transaction_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum AuthenticationResponseData {
+    PreAuthNResponse {
+        threeds_server_transaction_id: String,
+        message_version: String,
+        three_ds_method_url: Option<String>,
+        three_ds_method_data: Option<String>,
+    },
+    AuthNResponse {
+        authn_flow_type: AuthNFlowType,
+        trans_status: storage_enums::TransactionStatus,
+        authentication_value: Option<String>,
+        eci: Option<String>,
+        ds_trans_id: Option<String>,
+    },
+    PostAuthNResponse {
+        trans_status: storage_enums::TransactionStatus,
+        authentication_value: Option<String>,
+        eci: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum AuthNFlowType {
+    Challenge(Box<ChallengeParams>),
+    Frictionless,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChallengeParams {
+    pub acs_url: String,
+    /// The base64url encoded challenge request (`CReq`)
+    pub challenge_request: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckResponseData {
+    /// The identifier of the check at the fraud and risk management provider
+    pub transaction_id: Option<String>,
+    pub status: storage_enums::FraudCheckStatus,
+    pub score: Option<i32>,
+    pub reason: Option<serde_json::Value>,
+    pub connector_metadata: Option<serde_json::Value>,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentsAuthorizeData {
     pub payment_method_data: payments::PaymentMethodData,
@@ -362,6 +561,19 @@ pub struct PaymentsAuthorizeData {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub customer_id: Option<String>,
+    pub request_incremental_authorization: bool,
+    /// The result of a 3DS authentication performed through an external 3DS server
+    pub authentication_data: Option<AuthenticationData>,
+}
+
+/// The outcome of a successful external 3DS authentication, to be passed on to the processor
+#[derive(Debug, Clone)]
+pub struct AuthenticationData {
+    pub eci: Option<String>,
+    pub cavv: Secret<String>,
+    pub threeds_server_transaction_id: String,
+    pub message_version: String,
+    pub ds_trans_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -486,6 +698,18 @@ pub struct PaymentsApproveData {
     pub currency: Option<storage_enums::Currency>,
 }
 
+#[derive(Debug, Default, Clone)]
+pub struct PaymentsIncrementalAuthorizationData {
+    /// The total amount to be authorized for the payment
+    pub total_amount: i64,
+    /// The amount by which the authorized amount of the payment is increased
+    pub additional_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub reason: Option<String>,
+    pub connector_transaction_id: String,
+    pub connector_meta: Option<serde_json::Value>,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentsSessionData {
     pub amount: i64,
@@ -548,6 +772,7 @@ impl Capturable for PaymentsApproveData {}
 impl Capturable for PaymentsRejectData {}
 impl Capturable for PaymentsSessionData {}
 impl Capturable for PaymentsSyncData {}
+impl Capturable for PaymentsIncrementalAuthorizationData {}
 
 pub struct AddAccessTokenResult {
     pub access_token_result: Result<Option<AccessToken>, ErrorResponse>,
@@ -644,6 +869,12 @@ pub enum PaymentsResponseData {
         session_token: Option<api::SessionToken>,
         connector_response_reference_id: Option<String>,
     },
+    IncrementalAuthorizationResponse {
+        status: storage_enums::AuthorizationStatus,
+        connector_authorization_id: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -1033,6 +1264,8 @@ impl From<&VerifyRouterData> for PaymentsAuthorizeData {
             payment_experience: None,
             payment_method_type: None,
             customer_id: None,
+            request_incremental_authorization: false,
+            authentication_data: None,
         }
     }
 }
@@ -1,11 +1,13 @@
 pub mod admin;
 pub mod api_keys;
+pub mod authentication;
 pub mod configs;
 pub mod customers;
 pub mod disputes;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod files;
+pub mod fraud_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
@@ -3,10 +3,11 @@ pub use api_models::admin::{
     BusinessProfileUpdate, MerchantAccountCreate, MerchantAccountDeleteResponse,
     MerchantAccountResponse, MerchantAccountUpdate, MerchantConnectorCreate,
     MerchantConnectorDeleteResponse, MerchantConnectorDetails, MerchantConnectorDetailsWrap,
-    MerchantConnectorId, MerchantConnectorResponse, MerchantDetails, MerchantId,
+    MerchantConnectorId, MerchantConnectorResponse, MerchantDetails, MerchantId, PaymentLinkConfig,
     PaymentMethodsEnabled, PayoutRoutingAlgorithm, PayoutStraightThroughAlgorithm,
     RoutingAlgorithm, StraightThroughAlgorithm, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
 };
+use api_models::payment_methods::SurchargeConfig;
 use common_utils::ext_traits::ValueExt;
 use error_stack::ResultExt;
 use masking::Secret;
@@ -71,6 +72,14 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
             frm_routing_algorithm: item.frm_routing_algorithm,
             payout_routing_algorithm: item.payout_routing_algorithm,
             applepay_verified_domains: item.applepay_verified_domains,
+            payment_link_config: item
+                .payment_link_config
+                .map(|payment_link_config| payment_link_config.parse_value("PaymentLinkConfig"))
+                .transpose()?,
+            surcharge_config: item
+                .surcharge_config
+                .map(|surcharge_config| surcharge_config.parse_value("SurchargeConfig"))
+                .transpose()?,
         })
     }
 }
@@ -99,6 +108,32 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
             })
             .transpose()?;
 
+        let payment_link_config = request
+            .payment_link_config
+            .as_ref()
+            .map(|payment_link_config| {
+                common_utils::ext_traits::Encode::<PaymentLinkConfig>::encode_to_value(
+                    payment_link_config,
+                )
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_link_config",
+                })
+            })
+            .transpose()?;
+
+        let surcharge_config = request
+            .surcharge_config
+            .as_ref()
+            .map(|surcharge_config| {
+                common_utils::ext_traits::Encode::<SurchargeConfig>::encode_to_value(
+                    surcharge_config,
+                )
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "surcharge_config",
+                })
+            })
+            .transpose()?;
+
         let payment_response_hash_key = request
             .payment_response_hash_key
             .or(merchant_account.payment_response_hash_key)
@@ -138,6 +173,8 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 .or(merchant_account.payout_routing_algorithm),
             is_recon_enabled: merchant_account.is_recon_enabled,
             applepay_verified_domains: request.applepay_verified_domains,
+            payment_link_config,
+            surcharge_config,
         })
     }
 }
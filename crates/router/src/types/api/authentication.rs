@@ -0,0 +1,108 @@
+use std::str::FromStr;
+
+use api_models::enums as api_enums;
+use error_stack::{report, ResultExt};
+
+use super::ConnectorCommon;
+use crate::{
+    configs::settings::Connectors,
+    connector,
+    core::errors::{self, CustomResult},
+    services::api,
+    types,
+};
+
+/// Versioning and 3DS method discovery for the card, made before the authentication request
+#[derive(Debug, Clone)]
+pub struct PreAuthentication;
+
+/// The authentication request (`AReq`) sent to the directory server through the 3DS server
+#[derive(Debug, Clone)]
+pub struct Authentication;
+
+/// Retrieval of the authentication result once the challenge has been completed
+#[derive(Debug, Clone)]
+pub struct PostAuthentication;
+
+pub trait ConnectorPreAuthentication:
+    api::ConnectorIntegration<
+    PreAuthentication,
+    types::PreAuthNRequestData,
+    types::AuthenticationResponseData,
+>
+{
+}
+
+pub trait ConnectorAuthentication:
+    api::ConnectorIntegration<
+    Authentication,
+    types::ConnectorAuthenticationRequestData,
+    types::AuthenticationResponseData,
+>
+{
+}
+
+pub trait ConnectorPostAuthentication:
+    api::ConnectorIntegration<
+    PostAuthentication,
+    types::ConnectorPostAuthenticationRequestData,
+    types::AuthenticationResponseData,
+>
+{
+}
+
+/// Customer authentication providers such as 3DS servers, which are not payment processors and
+/// hence do not implement the [`super::Connector`] trait
+pub trait ExternalAuthentication:
+    ConnectorCommon + ConnectorPreAuthentication + ConnectorAuthentication + ConnectorPostAuthentication
+{
+}
+
+impl<
+        T: ConnectorCommon
+            + ConnectorPreAuthentication
+            + ConnectorAuthentication
+            + ConnectorPostAuthentication,
+    > ExternalAuthentication for T
+{
+}
+
+type BoxedAuthenticationConnector = Box<&'static (dyn ExternalAuthentication + Sync)>;
+
+#[derive(Clone)]
+pub struct AuthenticationConnectorData {
+    pub connector: BoxedAuthenticationConnector,
+    pub connector_name: String,
+}
+
+impl AuthenticationConnectorData {
+    pub fn get_connector_by_name(
+        connectors: &Connectors,
+        name: &str,
+    ) -> CustomResult<Self, errors::ApiErrorResponse> {
+        let connector = Self::convert_connector(connectors, name)?;
+        Ok(Self {
+            connector,
+            connector_name: name.to_string(),
+        })
+    }
+
+    fn convert_connector(
+        _connectors: &Connectors,
+        connector_name: &str,
+    ) -> CustomResult<BoxedAuthenticationConnector, errors::ApiErrorResponse> {
+        match api_enums::AuthenticationConnectors::from_str(connector_name) {
+            Ok(name) => match name {
+                api_enums::AuthenticationConnectors::Threedsecureio => {
+                    Ok(Box::new(&connector::Threedsecureio))
+                }
+            },
+            Err(_) => Err(
+                report!(errors::ConnectorError::InvalidConnectorName).attach_printable(format!(
+                    "invalid authentication connector name: {connector_name}"
+                )),
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        }
+    }
+}
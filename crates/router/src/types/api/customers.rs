@@ -1,5 +1,8 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
+pub use api_models::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerListConstraints, CustomerListItem,
+    CustomerListResponse, CustomerRequest,
+};
 use serde::Serialize;
 
 use crate::{core::errors::RouterResult, newtype, types::domain};
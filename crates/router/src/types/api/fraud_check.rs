@@ -0,0 +1,71 @@
+use error_stack::{report, ResultExt};
+
+use super::ConnectorCommon;
+use crate::{
+    configs::settings::Connectors,
+    connector,
+    core::errors::{self, CustomResult},
+    services::api,
+    types,
+};
+
+/// Check made before the payment is authorized with the payment processor
+#[derive(Debug, Clone)]
+pub struct Checkout;
+
+/// Check made after the payment processor has responded to the authorization
+#[derive(Debug, Clone)]
+pub struct Transaction;
+
+pub trait FraudCheckCheckout:
+    api::ConnectorIntegration<Checkout, types::FraudCheckCheckoutData, types::FraudCheckResponseData>
+{
+}
+
+pub trait FraudCheckTransaction:
+    api::ConnectorIntegration<
+    Transaction,
+    types::FraudCheckTransactionData,
+    types::FraudCheckResponseData,
+>
+{
+}
+
+/// Fraud and risk management providers, which are not payment processors and hence do not
+/// implement the [`super::Connector`] trait
+pub trait FraudCheck: ConnectorCommon + FraudCheckCheckout + FraudCheckTransaction {}
+
+impl<T: ConnectorCommon + FraudCheckCheckout + FraudCheckTransaction> FraudCheck for T {}
+
+type BoxedFrmConnector = Box<&'static (dyn FraudCheck + Sync)>;
+
+#[derive(Clone)]
+pub struct FraudCheckConnectorData {
+    pub connector: BoxedFrmConnector,
+    pub connector_name: String,
+}
+
+impl FraudCheckConnectorData {
+    pub fn get_connector_by_name(
+        connectors: &Connectors,
+        name: &str,
+    ) -> CustomResult<Self, errors::ApiErrorResponse> {
+        let connector = Self::convert_connector(connectors, name)?;
+        Ok(Self {
+            connector,
+            connector_name: name.to_string(),
+        })
+    }
+
+    fn convert_connector(
+        _connectors: &Connectors,
+        connector_name: &str,
+    ) -> CustomResult<BoxedFrmConnector, errors::ApiErrorResponse> {
+        match connector_name {
+            "signifyd" => Ok(Box::new(&connector::Signifyd)),
+            _ => Err(report!(errors::ConnectorError::InvalidConnectorName)
+                .attach_printable(format!("invalid frm connector name: {connector_name}")))
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        }
+    }
+}
@@ -1,7 +1,8 @@
 pub use api_models::payouts::{
     AchBankTransfer, BacsBankTransfer, Bank as BankPayout, Card as CardPayout, PayoutActionRequest,
-    PayoutCreateRequest, PayoutCreateResponse, PayoutMethodData, PayoutRequest, PayoutRetrieveBody,
-    PayoutRetrieveRequest, SepaBankTransfer,
+    PayoutCreateRequest, PayoutCreateResponse, PayoutListFilterConstraints, PayoutListFilters,
+    PayoutListResponse, PayoutMethodData, PayoutRequest, PayoutRetrieveBody, PayoutRetrieveRequest,
+    SepaBankTransfer,
 };
 
 #[cfg(feature = "payouts")]
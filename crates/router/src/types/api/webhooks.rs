@@ -13,9 +13,20 @@ use crate::{
     db::StorageInterface,
     services,
     types::domain,
+    types::storage::enums,
     utils::crypto,
 };
 
+/// The outcome of an incremental authorization, notified by the connector
+#[derive(Debug, Clone)]
+pub struct IncrementalAuthorizationPayload {
+    pub connector_authorization_id: String,
+    /// The total amount authorized for the payment
+    pub amount: i64,
+    pub status: enums::AuthorizationStatus,
+    pub error_message: Option<String>,
+}
+
 pub struct IncomingWebhookRequestDetails<'a> {
     pub method: actix_web::http::Method,
     pub uri: actix_web::http::Uri,
@@ -203,4 +214,14 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
     ) -> CustomResult<super::disputes::DisputePayload, errors::ConnectorError> {
         Err(errors::ConnectorError::NotImplemented("get_dispute_details method".to_string()).into())
     }
+
+    fn get_incremental_authorization_details(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<IncrementalAuthorizationPayload, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented(
+            "get_incremental_authorization_details method".to_string(),
+        )
+        .into())
+    }
 }
@@ -5,7 +5,6 @@ use common_utils::{
 };
 use diesel_models::{address::AddressUpdateInternal, encryption::Encryption, enums};
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
 use time::{OffsetDateTime, PrimitiveDateTime};
 
 use super::{
@@ -71,10 +70,10 @@ impl behaviour::Conversion for Address {
 
     async fn convert_back(
         other: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &dyn types::DecryptionKey,
     ) -> CustomResult<Self, ValidationError> {
         async {
-            let inner_decrypt = |inner| types::decrypt(inner, key.peek());
+            let inner_decrypt = |inner| types::decrypt(inner, key);
             Ok(Self {
                 id: Some(other.id),
                 address_id: other.address_id,
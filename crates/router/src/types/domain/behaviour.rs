@@ -1,6 +1,6 @@
 use common_utils::errors::{CustomResult, ValidationError};
 
-use crate::pii::Secret;
+use super::types::DecryptionKey;
 
 /// Trait for converting domain types to storage models
 #[async_trait::async_trait]
@@ -11,7 +11,7 @@ pub trait Conversion {
 
     async fn convert_back(
         item: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &dyn DecryptionKey,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized;
@@ -21,12 +21,12 @@ pub trait Conversion {
 
 #[async_trait::async_trait]
 pub trait ReverseConversion<SrcType: Conversion> {
-    async fn convert(self, key: &Secret<Vec<u8>>) -> CustomResult<SrcType, ValidationError>;
+    async fn convert(self, key: &dyn DecryptionKey) -> CustomResult<SrcType, ValidationError>;
 }
 
 #[async_trait::async_trait]
 impl<T: Send, U: Conversion<DstType = T>> ReverseConversion<U> for T {
-    async fn convert(self, key: &Secret<Vec<u8>>) -> CustomResult<U, ValidationError> {
+    async fn convert(self, key: &dyn DecryptionKey) -> CustomResult<U, ValidationError> {
         U::convert_back(self, key).await
     }
 }
@@ -1,4 +1,7 @@
-use common_utils::{crypto, date_time, pii};
+use common_utils::{
+    crypto::{self, SignMessage},
+    date_time, errors as common_errors, pii,
+};
 use diesel_models::{customers::CustomerUpdateInternal, encryption::Encryption};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
@@ -43,19 +46,22 @@ impl super::behaviour::Conversion for Customer {
             metadata: self.metadata,
             modified_at: self.modified_at,
             connector_customer: self.connector_customer,
+            email_hash: None,
+            phone_hash: None,
+            name_hash: None,
         })
     }
 
     async fn convert_back(
         item: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &dyn types::DecryptionKey,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
     {
         async {
-            let inner_decrypt = |inner| types::decrypt(inner, key.peek());
-            let inner_decrypt_email = |inner| types::decrypt(inner, key.peek());
+            let inner_decrypt = |inner| types::decrypt(inner, key);
+            let inner_decrypt_email = |inner| types::decrypt(inner, key);
             Ok(Self {
                 id: Some(item.id),
                 customer_id: item.customer_id,
@@ -91,10 +97,23 @@ impl super::behaviour::Conversion for Customer {
             created_at: now,
             modified_at: now,
             connector_customer: self.connector_customer,
+            email_hash: None,
+            phone_hash: None,
+            name_hash: None,
         })
     }
 }
 
+impl Customer {
+    /// Blind index of the searchable details of the customer
+    pub fn blind_index(
+        &self,
+        key: &[u8],
+    ) -> CustomResult<CustomerBlindIndex, common_errors::CryptoError> {
+        CustomerBlindIndex::new(key, &self.name, &self.email, &self.phone)
+    }
+}
+
 #[derive(Debug)]
 pub enum CustomerUpdate {
     Update {
@@ -131,6 +150,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 metadata,
                 connector_customer,
                 modified_at: Some(date_time::now()),
+                ..Default::default()
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
@@ -140,3 +160,165 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
         }
     }
 }
+
+impl CustomerUpdate {
+    /// Blind index of the searchable details being updated, if any
+    pub fn blind_index(
+        &self,
+        key: &[u8],
+    ) -> CustomResult<CustomerBlindIndex, common_errors::CryptoError> {
+        match self {
+            Self::Update {
+                name, email, phone, ..
+            } => CustomerBlindIndex::new(key, name, email, phone),
+            Self::ConnectorCustomer { .. } => Ok(CustomerBlindIndex::default()),
+        }
+    }
+}
+
+/// Customer details that can be searched for through a blind index
+#[derive(Clone, Copy, Debug)]
+pub enum BlindIndexField {
+    Email,
+    Phone,
+    Name,
+}
+
+impl BlindIndexField {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Phone => "phone",
+            Self::Name => "name",
+        }
+    }
+
+    /// Normalizes the value, so that searches are not affected by the way it was entered
+    fn normalize(self, value: &str) -> String {
+        match self {
+            Self::Email | Self::Name => value.trim().to_lowercase(),
+            Self::Phone => value.chars().filter(char::is_ascii_digit).collect(),
+        }
+    }
+}
+
+/// Context with which the blind index key is derived from the data key of the merchant, so that
+/// the data key itself is used only for encryption
+const BLIND_INDEX_KEY_CONTEXT: &[u8] = b"customer_blind_index";
+
+fn get_blind_index_key(data_key: &[u8]) -> CustomResult<Vec<u8>, common_errors::CryptoError> {
+    crypto::HmacSha256.sign_message(data_key, BLIND_INDEX_KEY_CONTEXT)
+}
+
+fn sign_blind_index(
+    blind_index_key: &[u8],
+    field: BlindIndexField,
+    value: &str,
+) -> CustomResult<String, common_errors::CryptoError> {
+    let message = format!("{}:{}", field.as_str(), field.normalize(value));
+    crypto::HmacSha256
+        .sign_message(blind_index_key, message.as_bytes())
+        .map(hex::encode)
+}
+
+/// Computes the blind index of a customer detail, which is a keyed hash of the normalized value.
+/// The key of the hash is derived from the data key of the merchant.
+///
+/// The customer details are stored encrypted, the blind index allows looking up customers by an
+/// exact value of these details without decrypting all of them.
+pub fn generate_blind_index(
+    data_key: &[u8],
+    field: BlindIndexField,
+    value: &str,
+) -> CustomResult<String, common_errors::CryptoError> {
+    sign_blind_index(&get_blind_index_key(data_key)?, field, value)
+}
+
+/// Computes the blind index of a customer detail with the current and the previous versions of
+/// the data key of the merchant, since customers are indexed with the current version only once
+/// they are re-encrypted after the data key is rotated
+pub fn generate_blind_indexes(
+    key_store: &super::MerchantKeyStore,
+    field: BlindIndexField,
+    value: &str,
+) -> CustomResult<Vec<String>, common_errors::CryptoError> {
+    std::iter::once(key_store.key.get_inner().peek().as_slice())
+        .chain(
+            key_store
+                .previous_keys
+                .values()
+                .map(|previous_key| previous_key.peek().as_slice()),
+        )
+        .map(|data_key| generate_blind_index(data_key, field, value))
+        .collect()
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CustomerBlindIndex {
+    pub email_hash: Option<String>,
+    pub phone_hash: Option<String>,
+    pub name_hash: Option<String>,
+}
+
+impl CustomerBlindIndex {
+    fn new(
+        data_key: &[u8],
+        name: &crypto::OptionalEncryptableName,
+        email: &crypto::OptionalEncryptableEmail,
+        phone: &crypto::OptionalEncryptablePhone,
+    ) -> CustomResult<Self, common_errors::CryptoError> {
+        let blind_index_key = get_blind_index_key(data_key)?;
+        let key = blind_index_key.as_slice();
+        Ok(Self {
+            email_hash: email
+                .as_ref()
+                .map(|email| {
+                    sign_blind_index(key, BlindIndexField::Email, email.get_inner().peek())
+                })
+                .transpose()?,
+            phone_hash: phone
+                .as_ref()
+                .map(|phone| {
+                    sign_blind_index(key, BlindIndexField::Phone, phone.get_inner().peek())
+                })
+                .transpose()?,
+            name_hash: name
+                .as_ref()
+                .map(|name| sign_blind_index(key, BlindIndexField::Name, name.get_inner().peek()))
+                .transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_blind_index_is_normalized() {
+        let key = [1u8; 32];
+        let blind_index = |field, value| generate_blind_index(&key, field, value).unwrap();
+
+        assert_eq!(
+            blind_index(BlindIndexField::Email, "JonTest@test.com "),
+            blind_index(BlindIndexField::Email, "jontest@test.com")
+        );
+        assert_eq!(
+            blind_index(BlindIndexField::Phone, "999-999 9999"),
+            blind_index(BlindIndexField::Phone, "9999999999")
+        );
+        assert_ne!(
+            blind_index(BlindIndexField::Name, "9999999999"),
+            blind_index(BlindIndexField::Phone, "9999999999")
+        );
+        assert_ne!(
+            sign_blind_index(&key, BlindIndexField::Email, "jontest@test.com").unwrap(),
+            blind_index(BlindIndexField::Email, "jontest@test.com")
+        );
+        assert_ne!(
+            generate_blind_index(&[2u8; 32], BlindIndexField::Email, "jontest@test.com").unwrap(),
+            blind_index(BlindIndexField::Email, "jontest@test.com")
+        );
+    }
+}
@@ -7,7 +7,6 @@ use common_utils::{
 use data_models::MerchantStorageScheme;
 use diesel_models::{encryption::Encryption, merchant_account::MerchantAccountUpdateInternal};
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
 use router_env::logger;
 use storage_impl::DataModelExt;
 
@@ -178,7 +177,7 @@ impl super::behaviour::Conversion for MerchantAccount {
 
     async fn convert_back(
         item: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &dyn types::DecryptionKey,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
@@ -193,11 +192,11 @@ impl super::behaviour::Conversion for MerchantAccount {
                 redirect_to_merchant_with_http_post: item.redirect_to_merchant_with_http_post,
                 merchant_name: item
                     .merchant_name
-                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .async_lift(|inner| types::decrypt(inner, key))
                     .await?,
                 merchant_details: item
                     .merchant_details
-                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .async_lift(|inner| types::decrypt(inner, key))
                     .await?,
                 webhook_details: item.webhook_details,
                 sub_merchants_enabled: item.sub_merchants_enabled,
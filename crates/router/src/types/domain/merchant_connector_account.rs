@@ -1,5 +1,5 @@
 use common_utils::{
-    crypto::{Encryptable, GcmAes256},
+    crypto::Encryptable,
     date_time,
     errors::{CustomResult, ValidationError},
     pii,
@@ -9,9 +9,9 @@ use diesel_models::{
     merchant_connector_account::MerchantConnectorAccountUpdateInternal,
 };
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
+use masking::Secret;
 
-use super::{behaviour, types::TypeEncryption};
+use super::{behaviour, types};
 #[derive(Clone, Debug)]
 pub struct MerchantConnectorAccount {
     pub id: Option<i32>,
@@ -34,6 +34,7 @@ pub struct MerchantConnectorAccount {
     pub connector_webhook_details: Option<pii::SecretSerdeValue>,
     pub profile_id: Option<String>,
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub connector_wallets_details: Option<Encryptable<Secret<serde_json::Value>>>,
 }
 
 #[derive(Debug)]
@@ -51,6 +52,7 @@ pub enum MerchantConnectorAccountUpdate {
         frm_configs: Option<Vec<Secret<serde_json::Value>>>,
         connector_webhook_details: Option<pii::SecretSerdeValue>,
         applepay_verified_domains: Option<Vec<String>>,
+        connector_wallets_details: Option<Encryptable<Secret<serde_json::Value>>>,
     },
 }
 
@@ -85,22 +87,22 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 connector_webhook_details: self.connector_webhook_details,
                 profile_id: self.profile_id,
                 applepay_verified_domains: self.applepay_verified_domains,
+                connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             },
         )
     }
 
     async fn convert_back(
         other: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &dyn types::DecryptionKey,
     ) -> CustomResult<Self, ValidationError> {
         Ok(Self {
             id: Some(other.id),
             merchant_id: other.merchant_id,
             connector_name: other.connector_name,
-            connector_account_details: Encryptable::decrypt(
+            connector_account_details: types::decrypt_with_key_version(
                 other.connector_account_details,
-                key.peek(),
-                GcmAes256,
+                key,
             )
             .await
             .change_context(ValidationError::InvalidValue {
@@ -123,6 +125,11 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_webhook_details: other.connector_webhook_details,
             profile_id: other.profile_id,
             applepay_verified_domains: other.applepay_verified_domains,
+            connector_wallets_details: types::decrypt(other.connector_wallets_details, key)
+                .await
+                .change_context(ValidationError::InvalidValue {
+                message: "Failed while decrypting connector wallets details".to_string(),
+            })?,
         })
     }
 
@@ -149,6 +156,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_webhook_details: self.connector_webhook_details,
             profile_id: self.profile_id,
             applepay_verified_domains: self.applepay_verified_domains,
+            connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
         })
     }
 }
@@ -169,6 +177,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 frm_configs,
                 connector_webhook_details,
                 applepay_verified_domains,
+                connector_wallets_details,
             } => Self {
                 merchant_id,
                 connector_type,
@@ -184,6 +193,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 modified_at: Some(common_utils::date_time::now()),
                 connector_webhook_details,
                 applepay_verified_domains,
+                connector_wallets_details: connector_wallets_details.map(Encryption::from),
             },
         }
     }
@@ -1,14 +1,13 @@
-use common_utils::{
-    crypto::{Encryptable, GcmAes256},
-    custom_serde, date_time,
-};
+use std::collections::HashMap;
+
+use common_utils::{crypto::Encryptable, custom_serde, date_time};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
 use crate::{
     errors::{CustomResult, ValidationError},
-    types::domain::types::TypeEncryption,
+    types::domain::types::{self, DecryptionKey, EncryptionKey},
 };
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -17,6 +16,30 @@ pub struct MerchantKeyStore {
     pub key: Encryptable<Secret<Vec<u8>>>,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    /// The previous versions of the key, used to decrypt the values which are not re-encrypted
+    /// with the current version of the key yet
+    #[serde(skip)]
+    pub previous_keys: HashMap<i32, Secret<Vec<u8>>>,
+}
+
+impl MerchantKeyStore {
+    /// Returns the current version of the key, with which the values of the merchant are encrypted.
+    pub fn encryption_key(&self) -> EncryptionKey<'_> {
+        EncryptionKey::new(self.key.get_inner().peek(), self.key_version)
+    }
+}
+
+impl DecryptionKey for MerchantKeyStore {
+    fn get_decryption_key(&self, key_version: i32) -> Option<&[u8]> {
+        if key_version == self.key_version {
+            Some(self.key.get_inner().peek())
+        } else {
+            self.previous_keys
+                .get(&key_version)
+                .map(|key| key.peek().as_slice())
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -28,24 +51,27 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             key: self.key.into(),
             merchant_id: self.merchant_id,
             created_at: self.created_at,
+            key_version: self.key_version,
         })
     }
 
     async fn convert_back(
         item: Self::DstType,
-        key: &Secret<Vec<u8>>,
+        key: &dyn DecryptionKey,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
     {
         Ok(Self {
-            key: Encryptable::decrypt(item.key, key.peek(), GcmAes256)
+            key: types::decrypt_with_key_version(item.key, key)
                 .await
                 .change_context(ValidationError::InvalidValue {
                     message: "Failed while decrypting customer data".to_string(),
                 })?,
             merchant_id: item.merchant_id,
             created_at: item.created_at,
+            key_version: item.key_version,
+            previous_keys: HashMap::new(),
         })
     }
 
@@ -54,6 +80,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             merchant_id: self.merchant_id,
             key: self.key.into(),
             created_at: date_time::now(),
+            key_version: self.key_version,
         })
     }
 }
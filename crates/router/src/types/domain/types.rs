@@ -180,27 +180,147 @@ impl<U, V: Lift<U> + Lift<U, SelfWrapper<U> = V> + Send> AsyncLift<U> for V {
     }
 }
 
+/// Header prefixed to the values encrypted with a merchant data key version other than the
+/// initial one, followed by the key version as a big-endian 32-bit integer.
+const KEY_VERSION_HEADER: &[u8] = b"\0hs_key_version\0";
+
+/// Version of the merchant data key the values without a key version header are encrypted with.
+pub const INITIAL_KEY_VERSION: i32 = 1;
+
+/// Keys with which encrypted values can be decrypted, looked up by the version of the key the
+/// value was encrypted with.
+pub trait DecryptionKey: Sync {
+    fn get_decryption_key(&self, key_version: i32) -> Option<&[u8]>;
+}
+
+/// A single key is used to decrypt values, irrespective of the key version recorded with them.
+impl DecryptionKey for [u8] {
+    fn get_decryption_key(&self, _key_version: i32) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+impl DecryptionKey for Vec<u8> {
+    fn get_decryption_key(&self, _key_version: i32) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+impl DecryptionKey for Secret<Vec<u8>> {
+    fn get_decryption_key(&self, _key_version: i32) -> Option<&[u8]> {
+        Some(self.peek())
+    }
+}
+
+/// The key values are encrypted with, along with its version, which is recorded with each
+/// encrypted value.
+#[derive(Clone, Copy)]
+pub struct EncryptionKey<'a> {
+    key: &'a [u8],
+    key_version: i32,
+}
+
+/// Length in bytes of the AES-256 keys values are encrypted with.
+const ENCRYPTION_KEY_LENGTH: usize = 32;
+
+impl<'a> EncryptionKey<'a> {
+    pub fn new(key: &'a [u8], key_version: i32) -> Self {
+        Self { key, key_version }
+    }
+
+    /// A key which is never rotated, such as the master key, with which values are encrypted
+    /// without a key version header. Fails if the key is not a 256-bit key.
+    pub fn unversioned(key: &'a [u8]) -> CustomResult<Self, errors::CryptoError> {
+        if key.len() != ENCRYPTION_KEY_LENGTH {
+            return Err(errors::CryptoError::EncodingFailed)
+                .into_report()
+                .attach_printable("The encryption key must be 256 bits long");
+        }
+
+        Ok(Self::new(key, INITIAL_KEY_VERSION))
+    }
+}
+
+/// Returns the version of the key the value was encrypted with, and the encrypted value without
+/// the key version header.
+pub fn split_key_version(encrypted: &[u8]) -> (i32, &[u8]) {
+    encrypted
+        .strip_prefix(KEY_VERSION_HEADER)
+        .and_then(|rest| {
+            let (version, ciphertext) = rest.split_at(rest.len().min(4));
+            <[u8; 4]>::try_from(version)
+                .ok()
+                .map(|version| (i32::from_be_bytes(version), ciphertext))
+        })
+        .unwrap_or((INITIAL_KEY_VERSION, encrypted))
+}
+
+fn add_key_version<T: Clone, S: masking::Strategy<T>>(
+    encrypted: crypto::Encryptable<Secret<T, S>>,
+    key_version: i32,
+) -> crypto::Encryptable<Secret<T, S>> {
+    if key_version == INITIAL_KEY_VERSION {
+        return encrypted;
+    }
+
+    let (inner, ciphertext) = encrypted.deconstruct();
+    let ciphertext = [
+        KEY_VERSION_HEADER,
+        key_version.to_be_bytes().as_slice(),
+        ciphertext.peek().as_slice(),
+    ]
+    .concat();
+    crypto::Encryptable::new(inner, ciphertext.into())
+}
+
+/// Decrypts the value with the version of the key it was encrypted with.
+pub async fn decrypt_with_key_version<T: Clone, S: masking::Strategy<T>>(
+    encrypted: Encryption,
+    key: &(impl DecryptionKey + ?Sized),
+) -> CustomResult<crypto::Encryptable<Secret<T, S>>, errors::CryptoError>
+where
+    crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
+{
+    let encrypted = encrypted.into_inner();
+    let (key_version, ciphertext) = split_key_version(encrypted.peek());
+    let key = key
+        .get_decryption_key(key_version)
+        .ok_or(errors::CryptoError::DecodingFailed)
+        .into_report()
+        .attach_printable_lazy(|| format!("Key version {key_version} is not available"))?;
+
+    let (inner, _) = crypto::Encryptable::decrypt(
+        Encryption::new(ciphertext.to_vec().into()),
+        key,
+        crypto::GcmAes256,
+    )
+    .await?
+    .deconstruct();
+    Ok(crypto::Encryptable::new(inner, encrypted))
+}
+
 #[inline]
-pub async fn encrypt<E: Clone, S>(
+pub async fn encrypt<'a, E: Clone, S>(
     inner: Secret<E, S>,
-    key: &[u8],
+    key: EncryptionKey<'a>,
 ) -> CustomResult<crypto::Encryptable<Secret<E, S>>, errors::CryptoError>
 where
     S: masking::Strategy<E>,
     crypto::Encryptable<Secret<E, S>>: TypeEncryption<E, crypto::GcmAes256, S>,
 {
     request::record_operation_time(
-        crypto::Encryptable::encrypt(inner, key, crypto::GcmAes256),
+        crypto::Encryptable::encrypt(inner, key.key, crypto::GcmAes256),
         &ENCRYPTION_TIME,
         &[],
     )
     .await
+    .map(|encrypted| add_key_version(encrypted, key.key_version))
 }
 
 #[inline]
-pub async fn encrypt_optional<E: Clone, S>(
+pub async fn encrypt_optional<'a, E: Clone, S>(
     inner: Option<Secret<E, S>>,
-    key: &[u8],
+    key: EncryptionKey<'a>,
 ) -> CustomResult<Option<crypto::Encryptable<Secret<E, S>>>, errors::CryptoError>
 where
     Secret<E, S>: Send,
@@ -213,16 +333,85 @@ where
 #[inline]
 pub async fn decrypt<T: Clone, S: masking::Strategy<T>>(
     inner: Option<Encryption>,
-    key: &[u8],
+    key: &(impl DecryptionKey + ?Sized),
 ) -> CustomResult<Option<crypto::Encryptable<Secret<T, S>>>, errors::CryptoError>
 where
     crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
 {
     request::record_operation_time(
-        inner.async_map(|item| crypto::Encryptable::decrypt(item, key, crypto::GcmAes256)),
+        inner.async_map(|item| decrypt_with_key_version(item, key)),
         &DECRYPTION_TIME,
         &[],
     )
     .await
     .transpose()
 }
+
+/// Re-encrypts the value with the key, if it was encrypted with a different version of the key.
+pub async fn re_encrypt<'a, T: Clone, S: masking::Strategy<T>>(
+    encrypted: crypto::Encryptable<Secret<T, S>>,
+    key: EncryptionKey<'a>,
+) -> CustomResult<crypto::Encryptable<Secret<T, S>>, errors::CryptoError>
+where
+    crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
+{
+    if split_key_version(encrypted.get_encrypted().peek()).0 == key.key_version {
+        return Ok(encrypted);
+    }
+    encrypt(encrypted.into_inner(), key).await
+}
+
+/// Re-encrypts the optional value with the key, if it was encrypted with a different version of
+/// the key.
+pub async fn re_encrypt_optional<'a, T: Clone, S: masking::Strategy<T>>(
+    encrypted: Option<crypto::Encryptable<Secret<T, S>>>,
+    key: EncryptionKey<'a>,
+) -> CustomResult<Option<crypto::Encryptable<Secret<T, S>>>, errors::CryptoError>
+where
+    crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
+{
+    encrypted
+        .async_map(|encrypted| re_encrypt(encrypted, key))
+        .await
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[tokio::test]
+    async fn test_re_encrypt_with_key_version() {
+        let previous_key = [1u8; 32];
+        let current_key = [2u8; 32];
+
+        let legacy: crypto::Encryptable<Secret<String>> = encrypt(
+            Secret::new("value".to_string()),
+            EncryptionKey::unversioned(previous_key.as_slice()).unwrap(),
+        )
+        .await
+        .unwrap();
+        let (key_version, _) = split_key_version(legacy.get_encrypted().peek());
+        assert_eq!(key_version, INITIAL_KEY_VERSION);
+
+        let re_encrypted = re_encrypt(legacy, EncryptionKey::new(current_key.as_slice(), 2))
+            .await
+            .unwrap();
+        let (key_version, _) = split_key_version(re_encrypted.get_encrypted().peek());
+        assert_eq!(key_version, 2);
+
+        let decrypted: crypto::Encryptable<Secret<String>> =
+            decrypt_with_key_version(re_encrypted.into(), current_key.as_slice())
+                .await
+                .unwrap();
+        assert_eq!(decrypted.into_inner().peek(), "value");
+    }
+
+    #[test]
+    fn test_unversioned_encryption_key_length() {
+        assert!(EncryptionKey::unversioned([1u8; 32].as_slice()).is_ok());
+        assert!(EncryptionKey::unversioned([1u8; 16].as_slice()).is_err());
+        assert!(EncryptionKey::unversioned(&[]).is_err());
+    }
+}
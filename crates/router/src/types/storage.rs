@@ -1,5 +1,9 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_event;
+pub mod authentication;
+pub mod blocklist;
+pub mod blocklist_fingerprint;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
@@ -11,6 +15,7 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod incremental_authorization;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -19,6 +24,7 @@ pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod payment_attempt;
+pub mod payment_link;
 pub mod payment_method;
 pub use diesel_models::{ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate};
 pub use scheduler::db::process_tracker;
@@ -28,6 +34,8 @@ pub mod payout_attempt;
 pub mod payouts;
 mod query;
 pub mod refund;
+pub mod vault;
+pub mod webhook_delivery_attempt;
 
 pub use data_models::payments::{
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
@@ -35,14 +43,17 @@ pub use data_models::payments::{
 };
 
 pub use self::{
-    address::*, api_keys::*, capture::*, cards_info::*, configs::*, connector_response::*,
-    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_method::*,
-    payout_attempt::*, payouts::*, process_tracker::*, refund::*, reverse_lookup::*,
+    address::*, api_keys::*, audit_event::*, authentication::*, blocklist::*,
+    blocklist_fingerprint::*, capture::*, cards_info::*, configs::*, connector_response::*,
+    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, incremental_authorization::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_link::*, payment_method::*, payout_attempt::*, payouts::*,
+    process_tracker::*, refund::*, reverse_lookup::*, vault::*, webhook_delivery_attempt::*,
 };
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RoutingData {
     pub routed_through: Option<String>,
     pub algorithm: Option<api_models::admin::StraightThroughAlgorithm>,
+    pub fallback_connectors: Option<Vec<api_models::enums::RoutableConnectors>>,
 }
@@ -0,0 +1,78 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+pub use diesel_models::audit_event::{AuditEvent, AuditEventNew};
+use diesel_models::{errors, query::generics::db_metrics, schema::audit_event::dsl};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{connection::PgPooledConn, logger};
+
+/// The number of audit events listed when no limit is specified
+pub const AUDIT_EVENT_LIST_DEFAULT_LIMIT: i64 = 10;
+/// The maximum number of audit events listed by a single query
+pub const AUDIT_EVENT_LIST_UPPER_LIMIT: i64 = 100;
+
+#[async_trait::async_trait]
+pub trait AuditEventDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl AuditEventDbExt for AuditEvent {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(merchant_id) = &constraints.merchant_id {
+            filter = filter.filter(dsl::merchant_id.eq(merchant_id.to_owned()));
+        }
+        if let Some(actor_type) = constraints.actor_type {
+            filter = filter.filter(dsl::actor_type.eq(actor_type));
+        }
+        if let Some(actor_id) = &constraints.actor_id {
+            filter = filter.filter(dsl::actor_id.eq(actor_id.to_owned()));
+        }
+        if let Some(action) = constraints.action {
+            filter = filter.filter(dsl::action.eq(action));
+        }
+        if let Some(target_type) = constraints.target_type {
+            filter = filter.filter(dsl::target_type.eq(target_type));
+        }
+        if let Some(target_id) = &constraints.target_id {
+            filter = filter.filter(dsl::target_id.eq(target_id.to_owned()));
+        }
+        if let Some(start_time) = constraints.start_time {
+            filter = filter.filter(dsl::created_at.ge(start_time));
+        }
+        if let Some(end_time) = constraints.end_time {
+            filter = filter.filter(dsl::created_at.le(end_time));
+        }
+        filter = filter.limit(
+            constraints
+                .limit
+                .unwrap_or(AUDIT_EVENT_LIST_DEFAULT_LIMIT)
+                .min(AUDIT_EVENT_LIST_UPPER_LIMIT),
+        );
+        if let Some(offset) = constraints.offset {
+            filter = filter.offset(offset);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering audit events by constraints")
+    }
+}
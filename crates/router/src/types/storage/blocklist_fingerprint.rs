@@ -0,0 +1,3 @@
+pub use diesel_models::blocklist_fingerprint::{
+    BlocklistFingerprint, BlocklistFingerprintNew, BlocklistFingerprintUpdate,
+};
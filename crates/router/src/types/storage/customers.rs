@@ -1,3 +1,6 @@
-pub use diesel_models::customers::{Customer, CustomerNew, CustomerUpdateInternal};
+pub use diesel_models::customers::{
+    Customer, CustomerBlindIndexBackfillTrackingData, CustomerListConstraints, CustomerNew,
+    CustomerUpdateInternal,
+};
 
 pub use crate::types::domain::CustomerUpdate;
@@ -1,6 +1,6 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
-use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
 pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
 use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
 use error_stack::{IntoReport, ResultExt};
@@ -23,11 +23,23 @@ impl DisputeDbExt for Dispute {
         merchant_id: &str,
         dispute_list_constraints: api_models::disputes::DisputeListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        // The ID breaks the ties between the disputes modified at the same time, so that the
+        // disputes are listed in the same order on every page
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::modified_at.desc(), dsl::id.desc()))
             .into_boxed();
 
+        if let Some(starting_after) = dispute_list_constraints.starting_after {
+            let cursor =
+                Self::find_by_merchant_id_dispute_id(conn, merchant_id, &starting_after).await?;
+            filter = filter.filter(
+                dsl::modified_at.lt(cursor.modified_at).or(dsl::modified_at
+                    .eq(cursor.modified_at)
+                    .and(dsl::id.lt(cursor.id))),
+            );
+        }
+
         if let Some(received_time) = dispute_list_constraints.received_time {
             filter = filter.filter(dsl::created_at.eq(received_time));
         }
@@ -0,0 +1,3 @@
+pub use diesel_models::incremental_authorization::{
+    IncrementalAuthorization, IncrementalAuthorizationNew, IncrementalAuthorizationUpdate,
+};
@@ -1,4 +1,5 @@
 pub use diesel_models::kv::{
-    DBOperation, Insertable, PaymentAttemptUpdateMems, PaymentIntentUpdateMems, RefundUpdateMems,
-    TypedSql, Updateable,
+    AddressUpdateMems, CaptureUpdateMems, ConnectorResponseUpdateMems, CustomerUpdateMems,
+    DBOperation, Deletable, Insertable, MandateUpdateMems, PaymentAttemptUpdateMems,
+    PaymentIntentUpdateMems, RefundUpdateMems, TypedSql, Updateable,
 };
@@ -1 +1,4 @@
-pub use diesel_models::merchant_key_store::MerchantKeyStore;
+pub use diesel_models::merchant_key_store::{
+    KeyRotationStage, MerchantKeyRotationTrackingData, MerchantKeyStore, MerchantKeyStoreUpdate,
+    MerchantKeyVersion, MerchantKeyVersionNew,
+};
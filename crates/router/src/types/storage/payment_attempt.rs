@@ -17,6 +17,12 @@ pub trait PaymentAttemptExt {
 
     fn get_next_capture_id(&self) -> String;
     fn get_intent_status(&self, amount_captured: Option<i64>) -> enums::IntentStatus;
+
+    /// The surcharge levied on the payment along with the tax on it
+    fn get_total_surcharge_amount(&self) -> i64;
+
+    /// The amount of the payment along with the surcharge and the tax on it
+    fn get_total_amount(&self) -> i64;
 }
 
 impl PaymentAttemptExt for PaymentAttempt {
@@ -67,6 +73,14 @@ impl PaymentAttemptExt for PaymentAttempt {
             intent_status
         }
     }
+
+    fn get_total_surcharge_amount(&self) -> i64 {
+        self.surcharge_amount.unwrap_or_default() + self.tax_amount.unwrap_or_default()
+    }
+
+    fn get_total_amount(&self) -> i64 {
+        self.amount + self.get_total_surcharge_amount()
+    }
 }
 
 pub trait AttemptStatusExt {
@@ -220,4 +234,64 @@ mod tests {
         // checking it after fetch
         assert_eq!(response.mandate_id, Some("man_121212".to_string()));
     }
+
+    #[actix_rt::test]
+    /// A capture held for a post authorization fraud check switches the attempt to a manual
+    /// capture, which must also be applied to the attempt stored in redis
+    async fn test_payment_attempt_capture_method_update_in_kv() {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let api_client = Box::new(services::MockApiClient);
+        let state = routes::AppState::with_storage(conf, StorageImpl::Mock, tx, api_client).await;
+
+        let current_time = common_utils::date_time::now();
+        let payment_id = Uuid::new_v4().to_string();
+        let payment_attempt = PaymentAttemptNew {
+            payment_id: payment_id.clone(),
+            merchant_id: "1".to_string(),
+            attempt_id: payment_id.clone(),
+            capture_method: Some(enums::CaptureMethod::Automatic),
+            created_at: current_time.into(),
+            modified_at: current_time.into(),
+            ..PaymentAttemptNew::default()
+        };
+        let payment_attempt = state
+            .store
+            .insert_payment_attempt(payment_attempt, enums::MerchantStorageScheme::RedisKv)
+            .await
+            .unwrap();
+
+        let response = state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt.clone(),
+                PaymentAttemptUpdate::ConfirmUpdate {
+                    amount: payment_attempt.amount,
+                    currency: enums::Currency::USD,
+                    status: enums::AttemptStatus::Authorized,
+                    authentication_type: None,
+                    payment_method: None,
+                    browser_info: None,
+                    connector: None,
+                    payment_token: None,
+                    payment_method_data: None,
+                    payment_method_type: None,
+                    payment_experience: None,
+                    business_sub_label: None,
+                    straight_through_algorithm: None,
+                    error_code: None,
+                    error_message: None,
+                    amount_capturable: None,
+                    fallback_connectors: None,
+                    capture_method: Some(enums::CaptureMethod::Manual),
+                    surcharge_amount: None,
+                    tax_amount: None,
+                },
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.capture_method, Some(enums::CaptureMethod::Manual));
+    }
 }
@@ -1 +1,215 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{
+    associations::HasTable, pg::Pg, BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl,
+};
 pub use diesel_models::payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal};
+use diesel_models::{
+    enums::{Currency, PayoutStatus, PayoutType},
+    errors,
+    payout_attempt::PayoutAttempt,
+    query::generics::db_metrics,
+    schema::{
+        payout_attempt::{self, dsl as poa_dsl},
+        payouts::{self, dsl as po_dsl},
+    },
+};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{connection::PgPooledConn, logger};
+
+type PayoutsWithAttempts = diesel::dsl::IntoBoxed<
+    'static,
+    diesel::dsl::InnerJoinOn<
+        payouts::table,
+        payout_attempt::table,
+        diesel::dsl::And<
+            diesel::dsl::Eq<poa_dsl::payout_id, po_dsl::payout_id>,
+            diesel::dsl::Eq<poa_dsl::merchant_id, po_dsl::merchant_id>,
+        >,
+    >,
+    Pg,
+>;
+
+/// Payouts of the merchant joined with their attempts, created within the time range if any
+fn get_payouts_with_attempts_query(
+    merchant_id: &str,
+    time_range: Option<&api_models::payments::TimeRange>,
+) -> PayoutsWithAttempts {
+    let mut filter = payouts::table
+        .inner_join(
+            payout_attempt::table.on(poa_dsl::payout_id
+                .eq(po_dsl::payout_id)
+                .and(poa_dsl::merchant_id.eq(po_dsl::merchant_id))),
+        )
+        .into_boxed()
+        .filter(po_dsl::merchant_id.eq(merchant_id.to_owned()));
+
+    if let Some(time_range) = time_range {
+        filter = filter.filter(po_dsl::created_at.ge(time_range.start_time));
+
+        if let Some(end_time) = time_range.end_time {
+            filter = filter.filter(po_dsl::created_at.le(end_time));
+        }
+    }
+
+    filter
+}
+
+/// Shared by the payouts list and its total count, so that both apply the same constraints
+fn get_filtered_payouts_query(
+    merchant_id: &str,
+    constraints: &api_models::payouts::PayoutListFilterConstraints,
+) -> PayoutsWithAttempts {
+    let mut filter = get_payouts_with_attempts_query(merchant_id, constraints.time_range.as_ref());
+
+    if let Some(payout_id) = &constraints.payout_id {
+        filter = filter.filter(po_dsl::payout_id.eq(payout_id.to_owned()));
+    }
+
+    if let Some(customer_id) = &constraints.customer_id {
+        filter = filter.filter(po_dsl::customer_id.eq(customer_id.to_owned()));
+    }
+
+    if let Some(connector) = &constraints.connector {
+        let connectors = connector
+            .iter()
+            .map(|connector| connector.to_string())
+            .collect::<Vec<String>>();
+        filter = filter.filter(poa_dsl::connector.eq_any(connectors));
+    }
+
+    if let Some(currency) = &constraints.currency {
+        filter = filter.filter(po_dsl::destination_currency.eq_any(currency.clone()));
+    }
+
+    if let Some(status) = &constraints.status {
+        filter = filter.filter(poa_dsl::status.eq_any(status.clone()));
+    }
+
+    if let Some(payout_type) = &constraints.payout_type {
+        filter = filter.filter(po_dsl::payout_type.eq_any(payout_type.clone()));
+    }
+
+    filter
+}
+
+#[async_trait::async_trait]
+pub trait PayoutsDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<Vec<(Self, PayoutAttempt)>, errors::DatabaseError>;
+
+    async fn get_payouts_count(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<i64, errors::DatabaseError>;
+
+    async fn get_filters_for_payouts(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutsDbExt for Payouts {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<Vec<(Self, PayoutAttempt)>, errors::DatabaseError> {
+        let filter = get_filtered_payouts_query(merchant_id, constraints)
+            .order(po_dsl::created_at.desc())
+            .limit(constraints.limit.into())
+            .offset(constraints.offset.unwrap_or_default().into());
+
+        logger::debug!(query = %diesel::debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async::<(Self, PayoutAttempt)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering payout records")
+    }
+
+    async fn get_payouts_count(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<i64, errors::DatabaseError> {
+        let filter = get_filtered_payouts_query(merchant_id, constraints).count();
+
+        logger::debug!(query = %diesel::debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering count of payouts")
+    }
+
+    async fn get_filters_for_payouts(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::DatabaseError> {
+        let filter = || get_payouts_with_attempts_query(merchant_id, Some(time_range));
+
+        let filter_connector: Vec<String> = filter()
+            .select(poa_dsl::connector)
+            .distinct()
+            .order_by(poa_dsl::connector.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by connector")?;
+
+        let filter_currency: Vec<Currency> = filter()
+            .select(po_dsl::destination_currency)
+            .distinct()
+            .order_by(po_dsl::destination_currency.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by currency")?;
+
+        let filter_status: Vec<PayoutStatus> = filter()
+            .select(poa_dsl::status)
+            .distinct()
+            .order_by(poa_dsl::status.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by payout status")?;
+
+        let filter_payout_type: Vec<PayoutType> = filter()
+            .select(po_dsl::payout_type)
+            .distinct()
+            .order_by(po_dsl::payout_type.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by payout type")?;
+
+        Ok(api_models::payouts::PayoutListFilters {
+            connector: filter_connector,
+            currency: filter_currency,
+            status: filter_status,
+            payout_type: filter_payout_type,
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub use diesel_models::vault::{
+    VaultAccessLog, VaultAccessLogNew, VaultCard, VaultCardNew, VaultCardUpdate, VaultDataKey,
+    VaultDataKeyNew, VaultKeyRotationTrackingData,
+};
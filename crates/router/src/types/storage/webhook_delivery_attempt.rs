@@ -0,0 +1,3 @@
+pub use diesel_models::webhook_delivery_attempt::{
+    WebhookDeliveryAttempt, WebhookDeliveryAttemptNew, WebhookDeliveryAttemptUpdate,
+};
@@ -425,6 +425,35 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+impl ForeignFrom<storage::KeyRotationStage> for api_models::admin::DataKeyRotationStage {
+    fn foreign_from(stage: storage::KeyRotationStage) -> Self {
+        match stage {
+            storage::KeyRotationStage::MerchantAccount => Self::MerchantAccount,
+            storage::KeyRotationStage::MerchantConnectorAccounts => Self::MerchantConnectorAccounts,
+            storage::KeyRotationStage::Customers => Self::Customers,
+            storage::KeyRotationStage::Addresses => Self::Addresses,
+            storage::KeyRotationStage::PaymentMethods => Self::PaymentMethods,
+            storage::KeyRotationStage::Authentications => Self::Authentications,
+            storage::KeyRotationStage::Events => Self::Events,
+            storage::KeyRotationStage::WebhookDeliveryAttempts => Self::WebhookDeliveryAttempts,
+            storage::KeyRotationStage::Completed => Self::Completed,
+        }
+    }
+}
+
+impl ForeignFrom<storage::MerchantKeyRotationTrackingData>
+    for api_models::admin::DataKeyRotationResponse
+{
+    fn foreign_from(tracking_data: storage::MerchantKeyRotationTrackingData) -> Self {
+        Self {
+            merchant_id: tracking_data.merchant_id,
+            key_version: tracking_data.key_version,
+            stage: tracking_data.stage.foreign_into(),
+            re_encrypted_count: tracking_data.re_encrypted_count,
+        }
+    }
+}
+
 impl ForeignFrom<storage::Config> for api_types::Config {
     fn foreign_from(config: storage::Config) -> Self {
         let config = config;
@@ -508,6 +537,62 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
     }
 }
 
+impl ForeignFrom<diesel_models::events::Event>
+    for api_models::webhook_events::EventListItemResponse
+{
+    fn foreign_from(event: diesel_models::events::Event) -> Self {
+        Self {
+            event_id: event.event_id,
+            merchant_id: event.merchant_id.unwrap_or_default(),
+            event_type: event.event_type,
+            object_id: event.primary_object_id,
+            is_webhook_notified: event.is_webhook_notified,
+            created: event.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::audit_event::AuditEvent>
+    for api_models::audit_events::AuditEventResponse
+{
+    fn foreign_from(audit_event: diesel_models::audit_event::AuditEvent) -> Self {
+        Self {
+            event_id: audit_event.event_id,
+            merchant_id: audit_event.merchant_id,
+            actor_type: audit_event.actor_type,
+            actor_id: audit_event.actor_id,
+            action: audit_event.action,
+            target_type: audit_event.target_type,
+            target_id: audit_event.target_id,
+            diff: audit_event.diff,
+            request_id: audit_event.request_id,
+            created_at: audit_event.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::blocklist::Blocklist> for api_models::blocklist::BlocklistResponse {
+    fn foreign_from(blocklist_entry: diesel_models::blocklist::Blocklist) -> Self {
+        Self {
+            fingerprint_id: blocklist_entry.fingerprint_id,
+            data_kind: blocklist_entry.data_kind,
+            created_at: blocklist_entry.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::payment_link::PaymentLink>
+    for api_models::payments::PaymentLinkResponse
+{
+    fn foreign_from(payment_link: diesel_models::payment_link::PaymentLink) -> Self {
+        Self {
+            payment_link_id: payment_link.payment_link_id,
+            link: payment_link.link_to_pay,
+            expiry: payment_link.fulfilment_time,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
     for diesel_models::api_keys::ApiKeyUpdate
 {
@@ -716,6 +801,21 @@ impl ForeignFrom<storage::Capture> for api_models::payments::CaptureResponse {
     }
 }
 
+impl ForeignFrom<storage::IncrementalAuthorization>
+    for api_models::payments::IncrementalAuthorizationResponse
+{
+    fn foreign_from(incremental_authorization: storage::IncrementalAuthorization) -> Self {
+        Self {
+            authorization_id: incremental_authorization.authorization_id,
+            amount: incremental_authorization.amount,
+            status: incremental_authorization.status,
+            error_code: incremental_authorization.error_code,
+            error_message: incremental_authorization.error_message,
+            previously_authorized_amount: incremental_authorization.previously_authorized_amount,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::payouts::Bank> for api_enums::PaymentMethodType {
     fn foreign_from(value: api_models::payouts::Bank) -> Self {
         match value {
@@ -1,3 +1,9 @@
+#[cfg(feature = "email")]
+pub mod api_key_expiry;
+pub mod customer_blind_index_backfill;
+pub mod merchant_key_rotation;
+pub mod outgoing_webhook_retry;
 pub mod payment_sync;
 pub mod refund_router;
 pub mod tokenized_data;
+pub mod vault_key_rotation;
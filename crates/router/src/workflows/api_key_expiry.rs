@@ -1,36 +1,41 @@
 use common_utils::ext_traits::ValueExt;
 use diesel_models::enums::{self as storage_enums};
+use masking::ExposeInterface;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
 
-use super::{ApiKeyExpiryWorkflow, ProcessTrackerWorkflow};
 use crate::{
+    db::StorageInterface,
     errors,
-    logger::error,
     routes::AppState,
-    types::{
-        api,
-        storage::{self, ProcessTrackerExt},
-    },
-    utils::OptionExt,
+    services::email::ApiKeyExpiryReminder,
+    types::{api, storage},
 };
 
+/// Sends a reminder to the merchant before an API key expires, once for each of the configured
+/// `expiry_reminder_days`.
+pub struct ApiKeyExpiryWorkflow;
+
 #[async_trait::async_trait]
-impl ProcessTrackerWorkflow for ApiKeyExpiryWorkflow {
+impl ProcessTrackerWorkflow<AppState> for ApiKeyExpiryWorkflow {
     async fn execute_workflow<'a>(
         &'a self,
         state: &'a AppState,
         process: storage::ProcessTracker,
-    ) -> Result<(), errors::ProcessTrackerError> {
-        let db = &*state.store;
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
         let tracking_data: storage::ApiKeyExpiryWorkflow = process
             .tracking_data
             .clone()
             .parse_value("ApiKeyExpiryWorkflow")?;
 
-        let key_store = state
-            .store
+        let key_store = db
             .get_merchant_key_store_by_merchant_id(
                 tracking_data.merchant_id.as_str(),
-                &state.store.get_master_key().to_vec().into(),
+                &db.get_master_key().to_vec().into(),
             )
             .await?;
 
@@ -40,8 +45,12 @@ impl ProcessTrackerWorkflow for ApiKeyExpiryWorkflow {
 
         let email_id = merchant_account
             .merchant_details
+            .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?
+            .into_inner()
+            .expose()
             .parse_value::<api::MerchantDetails>("MerchantDetails")?
-            .primary_email;
+            .primary_email
+            .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
 
         let task_id = process.id.clone();
 
@@ -51,38 +60,36 @@ impl ProcessTrackerWorkflow for ApiKeyExpiryWorkflow {
             .expiry_reminder_days
             .get(
                 usize::try_from(retry_count)
-                    .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+                    .map_err(|_| sch_errors::ProcessTrackerError::TypeConversionError)?,
             )
-            .ok_or(errors::ProcessTrackerError::EApiErrorResponse(
-                errors::ApiErrorResponse::InvalidDataValue {
-                    field_name: "index",
-                }
-                .into(),
-            ))?;
+            .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "index",
+            })?;
+
+        let reminder = ApiKeyExpiryReminder {
+            recipient_email: email_id,
+            key_id: tracking_data.key_id.clone(),
+            expires_in: *expires_in,
+        };
 
         state
             .email_client
-            .clone()
-            .send_email(
-                email_id.ok_or_else(|| errors::ProcessTrackerError::MissingRequiredField)?,
-                "API Key Expiry Notice".to_string(),
-                format!("Dear Merchant,\n
-It has come to our attention that your API key will expire in {expires_in} days. To ensure uninterrupted access to our platform and continued smooth operation of your services, we kindly request that you take the necessary actions as soon as possible.\n\n
-Thanks,\n
-Team Hyperswitch"),
-            )
+            .compose_and_send_email(Box::new(reminder))
             .await
-            .map_err(|_| errors::ProcessTrackerError::FlowExecutionError {
+            .map_err(|_| sch_errors::ProcessTrackerError::FlowExecutionError {
                 flow: "ApiKeyExpiryWorkflow",
             })?;
 
         // If all the mails have been sent, then retry_count would be equal to length of the expiry_reminder_days vector
         if retry_count
             == i32::try_from(tracking_data.expiry_reminder_days.len() - 1)
-                .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?
+                .map_err(|_| sch_errors::ProcessTrackerError::TypeConversionError)?
         {
             process
-                .finish_with_status(db, format!("COMPLETED_BY_PT_{task_id}"))
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{task_id}"),
+                )
                 .await?
         }
         // If tasks are remaining that has to be scheduled
@@ -91,14 +98,11 @@ Team Hyperswitch"),
                 .expiry_reminder_days
                 .get(
                     usize::try_from(retry_count + 1)
-                        .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+                        .map_err(|_| sch_errors::ProcessTrackerError::TypeConversionError)?,
                 )
-                .ok_or(errors::ProcessTrackerError::EApiErrorResponse(
-                    errors::ApiErrorResponse::InvalidDataValue {
-                        field_name: "index",
-                    }
-                    .into(),
-                ))?;
+                .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "index",
+                })?;
 
             let updated_schedule_time = tracking_data.api_key_expiry.map(|api_key_expiry| {
                 api_key_expiry.saturating_sub(time::Duration::days(i64::from(*expiry_reminder_day)))
@@ -122,11 +126,10 @@ Team Hyperswitch"),
 
     async fn error_handler<'a>(
         &'a self,
-        _state: &'a AppState,
+        state: &'a AppState,
         process: storage::ProcessTracker,
-        _error: errors::ProcessTrackerError,
-    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
-        error!(%process.id, "Failed while executing workflow");
-        Ok(())
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
     }
 }
@@ -0,0 +1,123 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+    utils::Encode,
+};
+
+/// Number of customers indexed in each run of the workflow
+const BACKFILL_BATCH_SIZE: i64 = 100;
+
+/// Computes the blind indexes of the customers of a merchant created before customers were
+/// indexed. Each run of the workflow indexes one batch of customers and reschedules itself,
+/// recording its progress in the tracking data of the process.
+pub struct CustomerBlindIndexBackfillWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for CustomerBlindIndexBackfillWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: storage::CustomerBlindIndexBackfillTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerBlindIndexBackfillTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let customers = db
+            .find_customers_by_merchant_id_after_id(
+                &tracking_data.merchant_id,
+                tracking_data.last_processed_id.unwrap_or_default(),
+                BACKFILL_BATCH_SIZE,
+                &key_store,
+            )
+            .await?;
+        let is_completed = i64::try_from(customers.len())
+            .map_or(false, |customers| customers < BACKFILL_BATCH_SIZE);
+        if let Some(last_id) = customers.last().and_then(|customer| customer.id) {
+            tracking_data.last_processed_id = Some(last_id);
+        }
+
+        for customer in customers {
+            // The blind indexes of the customer are computed on update, the encrypted details
+            // are written back as they are
+            let customer_update = storage::CustomerUpdate::Update {
+                name: customer.name,
+                email: customer.email,
+                phone: customer.phone,
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+            };
+            db.update_customer_by_customer_id_merchant_id(
+                customer.customer_id,
+                customer.merchant_id,
+                customer_update,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+            tracking_data.backfilled_count += 1;
+        }
+
+        let now = common_utils::date_time::now();
+        let process_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(now),
+            tracking_data: Some(
+                Encode::<storage::CustomerBlindIndexBackfillTrackingData>::encode_to_value(
+                    &tracking_data,
+                )?,
+            ),
+            business_status: None,
+            status: (!is_completed).then_some(enums::ProcessTrackerStatus::New),
+            updated_at: Some(now),
+        };
+        let process = db
+            .as_scheduler()
+            .update_process(process, process_update)
+            .await?;
+
+        if is_completed {
+            let id = process.id.clone();
+            process
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{id}"),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
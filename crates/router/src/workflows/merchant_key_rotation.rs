@@ -0,0 +1,585 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::encryption::Encryption;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::webhooks,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::{
+        domain::{self, types as domain_types},
+        storage::{self, enums},
+    },
+    utils::Encode,
+};
+
+/// Number of records re-encrypted in each run of the workflow
+const RE_ENCRYPTION_BATCH_SIZE: i64 = 100;
+
+/// Re-encrypts the data of a merchant with the current version of its data key, after the key is
+/// rotated. Each run of the workflow re-encrypts one batch of records and reschedules itself,
+/// recording its progress in the tracking data of the process.
+pub struct MerchantKeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for MerchantKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: storage::MerchantKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantKeyRotationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let last_processed_id = tracking_data.last_processed_id.unwrap_or_default();
+        let batch = match tracking_data.stage {
+            storage::KeyRotationStage::MerchantAccount => {
+                re_encrypt_merchant_account(db, merchant_account, &key_store).await?
+            }
+            storage::KeyRotationStage::MerchantConnectorAccounts => {
+                re_encrypt_merchant_connector_accounts(db, &key_store).await?
+            }
+            storage::KeyRotationStage::Customers => {
+                re_encrypt_customers(db, &merchant_account, &key_store, last_processed_id).await?
+            }
+            storage::KeyRotationStage::Addresses => {
+                re_encrypt_addresses(db, &merchant_account, &key_store, last_processed_id).await?
+            }
+            storage::KeyRotationStage::PaymentMethods => {
+                re_encrypt_payment_methods(db, &key_store, last_processed_id).await?
+            }
+            storage::KeyRotationStage::Authentications => {
+                re_encrypt_authentications(
+                    db,
+                    &key_store,
+                    tracking_data
+                        .last_processed_authentication_id
+                        .as_deref()
+                        .unwrap_or_default(),
+                )
+                .await?
+            }
+            storage::KeyRotationStage::Events => {
+                re_encrypt_outgoing_webhook_retry_tasks(db, &key_store, last_processed_id).await?
+            }
+            storage::KeyRotationStage::WebhookDeliveryAttempts => {
+                re_encrypt_webhook_delivery_attempts(db, &key_store, last_processed_id).await?
+            }
+            storage::KeyRotationStage::Completed => ReEncryptedBatch::default(),
+        };
+
+        tracking_data.re_encrypted_count += batch.count;
+        if !batch.has_more_records() {
+            tracking_data.stage = next_stage(tracking_data.stage);
+        }
+        tracking_data.last_processed_id = batch.last_id;
+        tracking_data.last_processed_authentication_id = batch.last_authentication_id;
+
+        let is_completed = tracking_data.stage == storage::KeyRotationStage::Completed;
+        let now = common_utils::date_time::now();
+        let process_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(now),
+            tracking_data: Some(
+                Encode::<storage::MerchantKeyRotationTrackingData>::encode_to_value(
+                    &tracking_data,
+                )?,
+            ),
+            business_status: None,
+            status: (!is_completed).then_some(enums::ProcessTrackerStatus::New),
+            updated_at: Some(now),
+        };
+        let process = db
+            .as_scheduler()
+            .update_process(process, process_update)
+            .await?;
+
+        if is_completed {
+            let id = process.id.clone();
+            process
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{id}"),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// A batch of records re-encrypted in a stage of the workflow
+#[derive(Default)]
+struct ReEncryptedBatch {
+    count: u64,
+    /// The `id` of the last record in the batch, if more records may be left in the stage
+    last_id: Option<i32>,
+    /// The `authentication_id` of the last authentication in the batch, if more authentications
+    /// may be left in the stage
+    last_authentication_id: Option<String>,
+}
+
+impl ReEncryptedBatch {
+    fn new(records: usize, last_id: Option<i32>) -> Self {
+        Self {
+            count: u64::try_from(records).unwrap_or(u64::MAX),
+            last_id: last_id.filter(|_| Self::is_full_batch(records)),
+            last_authentication_id: None,
+        }
+    }
+
+    fn new_for_authentications(records: usize, last_authentication_id: Option<String>) -> Self {
+        Self {
+            count: u64::try_from(records).unwrap_or(u64::MAX),
+            last_id: None,
+            last_authentication_id: last_authentication_id.filter(|_| Self::is_full_batch(records)),
+        }
+    }
+
+    fn is_full_batch(records: usize) -> bool {
+        i64::try_from(records).map_or(true, |records| records >= RE_ENCRYPTION_BATCH_SIZE)
+    }
+
+    fn has_more_records(&self) -> bool {
+        self.last_id.is_some() || self.last_authentication_id.is_some()
+    }
+}
+
+fn next_stage(stage: storage::KeyRotationStage) -> storage::KeyRotationStage {
+    match stage {
+        storage::KeyRotationStage::MerchantAccount => {
+            storage::KeyRotationStage::MerchantConnectorAccounts
+        }
+        storage::KeyRotationStage::MerchantConnectorAccounts => {
+            storage::KeyRotationStage::Customers
+        }
+        storage::KeyRotationStage::Customers => storage::KeyRotationStage::Addresses,
+        storage::KeyRotationStage::Addresses => storage::KeyRotationStage::PaymentMethods,
+        storage::KeyRotationStage::PaymentMethods => storage::KeyRotationStage::Authentications,
+        storage::KeyRotationStage::Authentications => storage::KeyRotationStage::Events,
+        storage::KeyRotationStage::Events => storage::KeyRotationStage::WebhookDeliveryAttempts,
+        storage::KeyRotationStage::WebhookDeliveryAttempts
+        | storage::KeyRotationStage::Completed => storage::KeyRotationStage::Completed,
+    }
+}
+
+async fn re_encrypt_merchant_account(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> Result<ReEncryptedBatch, sch_errors::ProcessTrackerError> {
+    let key = key_store.encryption_key();
+    let merchant_account_update = storage::MerchantAccountUpdate::Update {
+        merchant_name: domain_types::re_encrypt_optional(
+            merchant_account.merchant_name.clone(),
+            key,
+        )
+        .await
+        .change_context(errors::StorageError::EncryptionError)?,
+        merchant_details: domain_types::re_encrypt_optional(
+            merchant_account.merchant_details.clone(),
+            key,
+        )
+        .await
+        .change_context(errors::StorageError::EncryptionError)?,
+        return_url: None,
+        webhook_details: None,
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        enable_payment_response_hash: None,
+        payment_response_hash_key: None,
+        redirect_to_merchant_with_http_post: None,
+        publishable_key: None,
+        locker_id: None,
+        metadata: None,
+        routing_algorithm: None,
+        primary_business_details: None,
+        intent_fulfillment_time: None,
+        frm_routing_algorithm: None,
+        payout_routing_algorithm: None,
+        default_profile: None,
+    };
+    db.update_merchant(merchant_account, merchant_account_update, key_store)
+        .await?;
+
+    Ok(ReEncryptedBatch::new(1, None))
+}
+
+async fn re_encrypt_merchant_connector_accounts(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+) -> Result<ReEncryptedBatch, sch_errors::ProcessTrackerError> {
+    let key = key_store.encryption_key();
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &key_store.merchant_id,
+            true,
+            key_store,
+        )
+        .await?;
+    let count = merchant_connector_accounts.len();
+
+    for merchant_connector_account in merchant_connector_accounts {
+        let merchant_connector_account_update = storage::MerchantConnectorAccountUpdate::Update {
+            merchant_id: None,
+            connector_type: None,
+            connector_name: None,
+            connector_account_details: Some(
+                domain_types::re_encrypt(
+                    merchant_connector_account.connector_account_details.clone(),
+                    key,
+                )
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            ),
+            test_mode: None,
+            disabled: None,
+            merchant_connector_id: None,
+            payment_methods_enabled: None,
+            metadata: None,
+            frm_configs: None,
+            connector_webhook_details: None,
+            applepay_verified_domains: None,
+            connector_wallets_details: domain_types::re_encrypt_optional(
+                merchant_connector_account.connector_wallets_details.clone(),
+                key,
+            )
+            .await
+            .change_context(errors::StorageError::EncryptionError)?,
+        };
+        db.update_merchant_connector_account(
+            merchant_connector_account,
+            merchant_connector_account_update.into(),
+            key_store,
+        )
+        .await?;
+    }
+
+    Ok(ReEncryptedBatch::new(count, None))
+}
+
+async fn re_encrypt_customers(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    last_processed_id: i32,
+) -> Result<ReEncryptedBatch, sch_errors::ProcessTrackerError> {
+    let key = key_store.encryption_key();
+    let customers = db
+        .find_customers_by_merchant_id_after_id(
+            &merchant_account.merchant_id,
+            last_processed_id,
+            RE_ENCRYPTION_BATCH_SIZE,
+            key_store,
+        )
+        .await?;
+    let count = customers.len();
+    let last_id = customers.last().and_then(|customer| customer.id);
+
+    for customer in customers {
+        // The blind indices of the customer are recomputed with the current key on update
+        let customer_update = storage::CustomerUpdate::Update {
+            name: domain_types::re_encrypt_optional(customer.name, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            email: domain_types::re_encrypt_optional(customer.email, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            phone: domain_types::re_encrypt_optional(customer.phone, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            description: None,
+            phone_country_code: None,
+            metadata: None,
+            connector_customer: None,
+        };
+        db.update_customer_by_customer_id_merchant_id(
+            customer.customer_id,
+            customer.merchant_id,
+            customer_update,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    }
+
+    Ok(ReEncryptedBatch::new(count, last_id))
+}
+
+async fn re_encrypt_addresses(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    last_processed_id: i32,
+) -> Result<ReEncryptedBatch, sch_errors::ProcessTrackerError> {
+    let key = key_store.encryption_key();
+    let addresses = db
+        .find_addresses_by_merchant_id_after_id(
+            &merchant_account.merchant_id,
+            last_processed_id,
+            RE_ENCRYPTION_BATCH_SIZE,
+            key_store,
+        )
+        .await?;
+    let count = addresses.len();
+    let last_id = addresses.last().and_then(|address| address.id);
+
+    for address in addresses {
+        let address_update = storage::AddressUpdate::Update {
+            city: None,
+            country: None,
+            line1: domain_types::re_encrypt_optional(address.line1, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            line2: domain_types::re_encrypt_optional(address.line2, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            line3: domain_types::re_encrypt_optional(address.line3, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            state: domain_types::re_encrypt_optional(address.state, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            zip: domain_types::re_encrypt_optional(address.zip, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            first_name: domain_types::re_encrypt_optional(address.first_name, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            last_name: domain_types::re_encrypt_optional(address.last_name, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            phone_number: domain_types::re_encrypt_optional(address.phone_number, key)
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+            country_code: None,
+        };
+        db.update_address(
+            address.address_id,
+            address_update,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    }
+
+    Ok(ReEncryptedBatch::new(count, last_id))
+}
+
+async fn re_encrypt_payment_methods(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    last_processed_id: i32,
+) -> Result<ReEncryptedBatch, sch_errors::ProcessTrackerError> {
+    let payment_methods = db
+        .find_payment_methods_by_merchant_id_after_id(
+            &key_store.merchant_id,
+            last_processed_id,
+            RE_ENCRYPTION_BATCH_SIZE,
+        )
+        .await?;
+    let count = payment_methods.len();
+    let last_id = payment_methods
+        .last()
+        .map(|payment_method| payment_method.id);
+
+    for payment_method in payment_methods {
+        let payment_method_data = domain_types::decrypt::<serde_json::Value, masking::WithType>(
+            payment_method.payment_method_data.clone(),
+            key_store,
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)?;
+        let payment_method_update = storage::PaymentMethodUpdate::PaymentMethodDataUpdate {
+            payment_method_data: domain_types::re_encrypt_optional(
+                payment_method_data,
+                key_store.encryption_key(),
+            )
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .map(Into::into),
+        };
+        db.update_payment_method(payment_method, payment_method_update)
+            .await?;
+    }
+
+    Ok(ReEncryptedBatch::new(count, last_id))
+}
+
+async fn re_encrypt_authentications(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    last_processed_authentication_id: &str,
+) -> Result<ReEncryptedBatch, sch_errors::ProcessTrackerError> {
+    let authentications = db
+        .find_authentications_by_merchant_id_after_authentication_id(
+            &key_store.merchant_id,
+            last_processed_authentication_id,
+            RE_ENCRYPTION_BATCH_SIZE,
+        )
+        .await?;
+    let count = authentications.len();
+    let last_authentication_id = authentications
+        .last()
+        .map(|authentication| authentication.authentication_id.clone());
+
+    for authentication in authentications {
+        let authentication_value = domain_types::decrypt::<String, masking::WithType>(
+            authentication.authentication_value.clone(),
+            key_store,
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)?;
+        let authentication_update = storage::AuthenticationUpdate::AuthenticationValueUpdate {
+            authentication_value: domain_types::re_encrypt_optional(
+                authentication_value,
+                key_store.encryption_key(),
+            )
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .map(Into::into),
+        };
+        db.update_authentication(authentication, authentication_update)
+            .await?;
+    }
+
+    Ok(ReEncryptedBatch::new_for_authentications(
+        count,
+        last_authentication_id,
+    ))
+}
+
+async fn re_encrypt_outgoing_webhook_retry_tasks(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    last_processed_id: i32,
+) -> Result<ReEncryptedBatch, sch_errors::ProcessTrackerError> {
+    let events = db
+        .find_events_by_merchant_id_after_id(
+            &key_store.merchant_id,
+            last_processed_id,
+            RE_ENCRYPTION_BATCH_SIZE,
+        )
+        .await?;
+    let count = events.len();
+    let last_id = events.last().map(|event| event.id);
+
+    for event in events {
+        let process_tracker_id =
+            webhooks::get_outgoing_webhook_retry_task_id(&event.event_id, &key_store.merchant_id);
+        let process = match db
+            .as_scheduler()
+            .find_process_by_id(&process_tracker_id)
+            .await?
+        {
+            Some(process) if process.status != enums::ProcessTrackerStatus::Finish => process,
+            _ => continue,
+        };
+
+        let mut tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+        if let Some(request_body) =
+            re_encrypt_webhook_body(Some(tracking_data.request_body.clone()), key_store).await?
+        {
+            tracking_data.request_body = request_body;
+        }
+
+        let process_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: None,
+            tracking_data: Some(
+                Encode::<storage::OutgoingWebhookTrackingData>::encode_to_value(&tracking_data)?,
+            ),
+            business_status: None,
+            status: None,
+            updated_at: Some(common_utils::date_time::now()),
+        };
+        db.as_scheduler()
+            .update_process(process, process_update)
+            .await?;
+    }
+
+    Ok(ReEncryptedBatch::new(count, last_id))
+}
+
+async fn re_encrypt_webhook_delivery_attempts(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    last_processed_id: i32,
+) -> Result<ReEncryptedBatch, sch_errors::ProcessTrackerError> {
+    let delivery_attempts = db
+        .find_webhook_delivery_attempts_by_merchant_id_after_id(
+            &key_store.merchant_id,
+            last_processed_id,
+            RE_ENCRYPTION_BATCH_SIZE,
+        )
+        .await?;
+    let count = delivery_attempts.len();
+    let last_id = delivery_attempts
+        .last()
+        .map(|delivery_attempt| delivery_attempt.id);
+
+    for delivery_attempt in delivery_attempts {
+        let delivery_attempt_update = storage::WebhookDeliveryAttemptUpdate::BodiesUpdate {
+            request_body: re_encrypt_webhook_body(delivery_attempt.request_body.clone(), key_store)
+                .await?,
+            response_body: re_encrypt_webhook_body(
+                delivery_attempt.response_body.clone(),
+                key_store,
+            )
+            .await?,
+        };
+        db.update_webhook_delivery_attempt(delivery_attempt, delivery_attempt_update)
+            .await?;
+    }
+
+    Ok(ReEncryptedBatch::new(count, last_id))
+}
+
+/// Re-encrypts the body of an outgoing webhook, or of the response of the merchant to it, with the
+/// current version of the data key of the merchant
+async fn re_encrypt_webhook_body(
+    body: Option<Encryption>,
+    key_store: &domain::MerchantKeyStore,
+) -> Result<Option<Encryption>, sch_errors::ProcessTrackerError> {
+    let body = domain_types::decrypt::<String, masking::WithType>(body, key_store)
+        .await
+        .change_context(errors::StorageError::DecryptionError)?;
+    Ok(
+        domain_types::re_encrypt_optional(body, key_store.encryption_key())
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .map(Into::into),
+    )
+}
@@ -0,0 +1,197 @@
+use common_utils::ext_traits::{StringExt, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, utils, SchedulerAppState,
+};
+
+use crate::{
+    core::webhooks,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+pub struct OutgoingWebhookRetryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let event = db.find_event_by_event_id(&tracking_data.event_id).await?;
+        if event.is_webhook_notified {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{id}"),
+                )
+                .await;
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let delivery_result = webhooks::retry_webhook_delivery_to_merchant(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data,
+            enums::WebhookDeliveryType::AutomaticRetry,
+        )
+        .await;
+
+        match delivery_result {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(
+                        state.get_db().as_scheduler(),
+                        format!("COMPLETED_BY_PT_{id}"),
+                    )
+                    .await?
+            }
+            Err(error) => {
+                logger::warn!(outgoing_webhook_retry_failure=?error);
+                retry_webhook_delivery_task(db, &tracking_data.merchant_id, process).await?
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+pub async fn get_webhook_delivery_retry_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let mapping: common_utils::errors::CustomResult<
+        process_data::OutgoingWebhookRetryPTMapping,
+        errors::StorageError,
+    > = db
+        .find_config_by_key("pt_mapping_outgoing_webhooks")
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("OutgoingWebhookRetryPTMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+    let mapping = match mapping {
+        Ok(x) => x,
+        Err(err) => {
+            logger::info!("Outgoing webhook retry mapping error: {}", err);
+            process_data::OutgoingWebhookRetryPTMapping::default()
+        }
+    };
+    let time_delta =
+        utils::get_outgoing_webhook_retry_schedule_time(mapping, merchant_id, retry_count);
+
+    utils::get_time_from_delta(time_delta)
+}
+
+pub async fn retry_webhook_delivery_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    pt: storage::ProcessTracker,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let schedule_time =
+        get_webhook_delivery_retry_schedule_time(db, merchant_id, pt.retry_count + 1).await;
+
+    match schedule_time {
+        Some(s_time) => pt.retry(db.as_scheduler(), s_time).await,
+        None => {
+            pt.finish_with_status(db.as_scheduler(), "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_outgoing_webhook_retry_schedule_time() {
+        let delays: Vec<Option<i32>> = (0..10)
+            .map(|retry_count| {
+                utils::get_outgoing_webhook_retry_schedule_time(
+                    process_data::OutgoingWebhookRetryPTMapping::default(),
+                    "merchant",
+                    retry_count,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            delays,
+            vec![
+                Some(60),
+                Some(120),
+                Some(240),
+                Some(480),
+                Some(960),
+                Some(1920),
+                Some(3600),
+                Some(3600),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_outgoing_webhook_retry_custom_merchant_policy() {
+        let mut mapping = process_data::OutgoingWebhookRetryPTMapping::default();
+        mapping.custom_merchant_mapping.insert(
+            "merchant".to_string(),
+            process_data::OutgoingWebhookRetryPolicy {
+                start_after: 10,
+                backoff_factor: 3,
+                max_interval: 100,
+                max_retries_count: 3,
+            },
+        );
+
+        let schedule = |retry_count| {
+            let mapping: process_data::OutgoingWebhookRetryPTMapping =
+                serde_json::from_value(serde_json::to_value(&mapping).unwrap()).unwrap();
+            utils::get_outgoing_webhook_retry_schedule_time(mapping, "merchant", retry_count)
+        };
+
+        assert_eq!(schedule(0), Some(10));
+        assert_eq!(schedule(1), Some(30));
+        assert_eq!(schedule(2), Some(90));
+        assert_eq!(schedule(3), None);
+    }
+}
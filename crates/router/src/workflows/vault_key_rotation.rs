@@ -0,0 +1,102 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::payment_methods::vault,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+    utils::Encode,
+};
+
+/// Number of cards re-encrypted in each run of the workflow
+const RE_ENCRYPTION_BATCH_SIZE: i64 = 100;
+
+/// Re-encrypts the cards of a merchant in the built-in vault with the current version of its
+/// vault data key, after the key is rotated. Each run of the workflow re-encrypts one batch of
+/// cards and reschedules itself, recording its progress in the tracking data of the process.
+pub struct VaultKeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for VaultKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: storage::VaultKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("VaultKeyRotationTrackingData")?;
+
+        // The cards are re-encrypted by the task of the latest rotation if the key was rotated
+        // again in the meantime
+        let is_superseded = db
+            .find_active_vault_data_key_optional(&tracking_data.merchant_id)
+            .await?
+            .map_or(true, |data_key| {
+                data_key.key_version != tracking_data.key_version
+            });
+        let re_encrypted_cards = if is_superseded {
+            0
+        } else {
+            vault::Vault::re_encrypt_builtin_vault_cards(
+                state,
+                &tracking_data.merchant_id,
+                tracking_data.key_version,
+                RE_ENCRYPTION_BATCH_SIZE,
+            )
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+        };
+
+        tracking_data.re_encrypted_count += u64::try_from(re_encrypted_cards).unwrap_or(u64::MAX);
+        let is_completed = is_superseded
+            || i64::try_from(re_encrypted_cards)
+                .map_or(false, |cards| cards < RE_ENCRYPTION_BATCH_SIZE);
+
+        let now = common_utils::date_time::now();
+        let process_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(now),
+            tracking_data: Some(
+                Encode::<storage::VaultKeyRotationTrackingData>::encode_to_value(&tracking_data)?,
+            ),
+            business_status: None,
+            status: (!is_completed).then_some(enums::ProcessTrackerStatus::New),
+            updated_at: Some(now),
+        };
+        let process = db
+            .as_scheduler()
+            .update_process(process, process_update)
+            .await?;
+
+        if is_completed {
+            let id = process.id.clone();
+            process
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{id}"),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -68,6 +68,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             webhook_url: None,
             complete_authorize_url: None,
             customer_id: None,
+            request_incremental_authorization: false,
+            authentication_data: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
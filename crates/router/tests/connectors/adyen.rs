@@ -153,6 +153,8 @@ impl AdyenTest {
             webhook_url: None,
             complete_authorize_url: None,
             customer_id: None,
+            request_incremental_authorization: false,
+            authentication_data: None,
         })
     }
 }
@@ -91,6 +91,8 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
         complete_authorize_url: None,
         capture_method: None,
         customer_id: None,
+        request_incremental_authorization: false,
+        authentication_data: None,
     })
 }
 
@@ -11,7 +11,7 @@ use router::{
     routes, services,
     types::{self, api, storage::enums, AccessToken, PaymentAddress, RouterData},
 };
-use test_utils::connector_auth::ConnectorAuthType;
+use test_utils::connector_auth::{ConnectorAuthType, ConnectorTestMode};
 use tokio::sync::oneshot;
 use wiremock::{Mock, MockServer};
 
@@ -63,14 +63,7 @@ pub trait ConnectorActions: Connector {
             },
             payment_info,
         );
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         integration.execute_pretasks(&mut request, &state).await?;
         call_connector(request, integration).await
     }
@@ -87,14 +80,7 @@ pub trait ConnectorActions: Connector {
             },
             payment_info,
         );
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         integration.execute_pretasks(&mut request, &state).await?;
         call_connector(request, integration).await
     }
@@ -111,14 +97,7 @@ pub trait ConnectorActions: Connector {
             },
             payment_info,
         );
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         integration.execute_pretasks(&mut request, &state).await?;
         call_connector(request, integration).await
     }
@@ -139,14 +118,7 @@ pub trait ConnectorActions: Connector {
             },
             payment_info,
         );
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         integration.execute_pretasks(&mut request, &state).await?;
         call_connector(request, integration).await
     }
@@ -532,6 +504,7 @@ pub trait ConnectorActions: Connector {
             Ok(types::PaymentsResponseData::PreProcessingResponse { .. }) => None,
             Ok(types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. }) => None,
             Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => None,
+            Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse { .. }) => None,
             Err(_) => None,
         }
     }
@@ -552,14 +525,7 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let mut request = self.get_payout_request(None, payout_type, payment_info);
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
@@ -591,14 +557,7 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let mut request = self.get_payout_request(connector_payout_id, payout_type, payment_info);
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
@@ -631,14 +590,7 @@ pub trait ConnectorActions: Connector {
             .get_connector_integration();
         let mut request = self.get_payout_request(None, payout_type, payment_info);
         request.connector_customer = connector_customer;
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
@@ -671,14 +623,7 @@ pub trait ConnectorActions: Connector {
             .get_connector_integration();
         let mut request =
             self.get_payout_request(Some(connector_payout_id), payout_type, payment_info);
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
@@ -755,14 +700,7 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let mut request = self.get_payout_request(None, payout_type, payment_info);
-        let tx = oneshot::channel().0;
-        let state = routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        )
-        .await;
+        let state = get_app_state(&request.connector_auth_type).await;
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
@@ -778,6 +716,66 @@ pub trait ConnectorActions: Connector {
     }
 }
 
+/// The application state through which the connector requests of the test are sent, live or
+/// through the recorded fixtures as per the `CONNECTOR_TEST_MODE` of the test run
+async fn get_app_state(connector_auth_type: &types::ConnectorAuthType) -> routes::AppState {
+    let conf = Settings::new().unwrap();
+    let api_client: Box<dyn services::ApiClient> = match ConnectorTestMode::from_env() {
+        ConnectorTestMode::Live => {
+            Box::new(services::ProxyClient::new(conf.proxy.clone(), vec![]).unwrap())
+        }
+        ConnectorTestMode::Record => Box::new(services::RecordReplayClient::new(
+            services::RecordReplayMode::Record,
+            get_fixture_path(),
+            get_connector_secrets(connector_auth_type),
+        )),
+        ConnectorTestMode::Replay => Box::new(services::RecordReplayClient::new(
+            services::RecordReplayMode::Replay,
+            get_fixture_path(),
+            get_connector_secrets(connector_auth_type),
+        )),
+    };
+    let tx: oneshot::Sender<()> = oneshot::channel().0;
+    routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest, tx, api_client).await
+}
+
+/// The fixture of the running test, named after the test, e.g.
+/// `tests/connectors/fixtures/aci/should_make_payment.json` for `aci::should_make_payment`.
+/// The fixtures directory can be overridden with `CONNECTOR_FIXTURES_PATH`.
+fn get_fixture_path() -> std::path::PathBuf {
+    let test_name = std::thread::current()
+        .name()
+        .filter(|name| *name != "main")
+        .expect("Connector fixtures are named after the test, which must run on its own thread")
+        .replace("::", "/");
+    let fixtures_path = std::env::var("CONNECTOR_FIXTURES_PATH").unwrap_or_else(|_| {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/connectors/fixtures").to_string()
+    });
+    std::path::Path::new(&fixtures_path).join(format!("{test_name}.json"))
+}
+
+/// The connector credentials, masked in the recorded fixtures
+fn get_connector_secrets(connector_auth_type: &types::ConnectorAuthType) -> Vec<Secret<String>> {
+    match connector_auth_type.clone() {
+        types::ConnectorAuthType::HeaderKey { api_key } => vec![api_key],
+        types::ConnectorAuthType::BodyKey { api_key, key1 } => vec![api_key, key1],
+        types::ConnectorAuthType::SignatureKey {
+            api_key,
+            key1,
+            api_secret,
+        } => vec![api_key, key1, api_secret],
+        types::ConnectorAuthType::MultiAuthKey {
+            api_key,
+            key1,
+            api_secret,
+            key2,
+        } => vec![api_key, key1, api_secret, key2],
+        types::ConnectorAuthType::CurrencyAuthKey { .. } | types::ConnectorAuthType::NoKey => {
+            vec![]
+        }
+    }
+}
+
 async fn call_connector<
     T: Debug + Clone + 'static,
     Req: Debug + Clone + 'static,
@@ -786,15 +784,7 @@ async fn call_connector<
     request: RouterData<T, Req, Resp>,
     integration: services::BoxedConnectorIntegration<'_, T, Req, Resp>,
 ) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
-    let conf = Settings::new().unwrap();
-    let tx: oneshot::Sender<()> = oneshot::channel().0;
-    let state = routes::AppState::with_storage(
-        conf,
-        StorageImpl::PostgresqlTest,
-        tx,
-        Box::new(services::MockApiClient),
-    )
-    .await;
+    let state = get_app_state(&request.connector_auth_type).await;
     services::api::execute_connector_processing_step(
         &state,
         integration,
@@ -884,6 +874,8 @@ impl Default for PaymentAuthorizeType {
             complete_authorize_url: None,
             webhook_url: None,
             customer_id: None,
+            request_incremental_authorization: false,
+            authentication_data: None,
         };
         Self(data)
     }
@@ -1002,6 +994,7 @@ pub fn get_connector_transaction_id(
         Ok(types::PaymentsResponseData::ConnectorCustomerResponse { .. }) => None,
         Ok(types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. }) => None,
         Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => None,
+        Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse { .. }) => None,
         Err(_) => None,
     }
 }
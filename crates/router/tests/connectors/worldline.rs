@@ -100,6 +100,8 @@ impl WorldlineTest {
             webhook_url: None,
             complete_authorize_url: None,
             customer_id: None,
+            request_incremental_authorization: false,
+            authentication_data: None,
         })
     }
 }
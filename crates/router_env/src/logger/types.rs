@@ -78,6 +78,12 @@ pub enum Flow {
     ConfigKeyFetch,
     /// ConfigKey Update flow.
     ConfigKeyUpdate,
+    /// Vault data key rotation flow.
+    VaultDataKeyRotate,
+    /// Merchant data key rotation flow.
+    MerchantDataKeyRotate,
+    /// Merchant data key rotation status flow.
+    MerchantDataKeyRotationStatus,
     /// Customers create flow.
     CustomersCreate,
     /// Customers retrieve flow.
@@ -86,6 +92,8 @@ pub enum Flow {
     CustomersUpdate,
     /// Customers delete flow.
     CustomersDelete,
+    /// Customers list flow.
+    CustomersList,
     /// Customers get mandates flow.
     CustomersGetMandates,
     /// Create an Ephemeral Key.
@@ -110,10 +118,14 @@ pub enum Flow {
     PaymentMethodsUpdate,
     /// Payment methods delete flow.
     PaymentMethodsDelete,
+    /// Payment methods attach flow.
+    PaymentMethodsAttach,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
     PaymentsRetrieve,
+    /// Payments verify microdeposits flow.
+    PaymentsVerifyMicrodeposits,
     /// Payments update flow.
     PaymentsUpdate,
     /// Payments confirm flow.
@@ -126,6 +138,8 @@ pub enum Flow {
     PaymentsApprove,
     /// Payments reject flow.
     PaymentsReject,
+    /// Payments incremental authorization flow.
+    PaymentsIncrementalAuthorization,
     /// Payments Session Token flow
     PaymentsSessionToken,
     /// Payments start flow.
@@ -147,10 +161,18 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts fulfill flow.
     PayoutsFulfill,
+    #[cfg(feature = "payouts")]
+    /// Payouts list flow.
+    PayoutsList,
+    #[cfg(feature = "payouts")]
+    /// Payouts filter flow.
+    PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payments Redirect flow.
     PaymentsRedirect,
+    /// Payments external 3DS authentication flow.
+    PaymentsExternalAuthentication,
     /// Refunds create flow.
     RefundsCreate,
     /// Refunds retrieve flow.
@@ -207,6 +229,24 @@ pub enum Flow {
     BusinessProfileList,
     /// Different verification flows
     Verification,
+    /// List the outgoing webhook events of a merchant
+    WebhookEventsList,
+    /// Retrieve an outgoing webhook event along with its delivery attempts
+    WebhookEventRetrieve,
+    /// Resend the outgoing webhook of an event
+    WebhookEventDeliveryRetry,
+    /// List the audit events of administrative actions
+    AuditEventsList,
+    /// Add an entry to the blocklist of a merchant
+    AddToBlocklist,
+    /// Remove an entry from the blocklist of a merchant
+    DeleteFromBlocklist,
+    /// List the entries in the blocklist of a merchant
+    ListBlocklist,
+    /// Payment link retrieve flow
+    PaymentLinkRetrieve,
+    /// Payment link initiate flow
+    PaymentLinkInitiate,
 }
 
 ///
@@ -0,0 +1,256 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_utils::errors::CustomResult;
+use diesel_models::enums::ProcessTrackerStatus;
+use error_stack::{report, IntoReport, ResultExt};
+use redis_interface::{RedisConnectionPool, RedisEntryId};
+use router_env::{instrument, tracing};
+use time::Duration;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use super::{
+    env::logger::{self, debug, error},
+    metrics,
+};
+use crate::{
+    configs::settings::SchedulerSettings, consumer::types::ProcessTrackerBatch, errors,
+    flow::SchedulerFlow, scheduler::SchedulerInterface, utils::*, SchedulerAppState,
+};
+
+#[instrument(skip_all)]
+pub async fn start_cleaner<T>(
+    state: &T,
+    scheduler_settings: Arc<SchedulerSettings>,
+    (tx, mut rx): (mpsc::Sender<()>, mpsc::Receiver<()>),
+) -> CustomResult<(), errors::ProcessTrackerError>
+where
+    T: SchedulerAppState,
+{
+    let mut interval = tokio::time::interval(std::time::Duration::from_millis(
+        scheduler_settings.cleaner.loop_interval,
+    ));
+
+    let mut shutdown_interval = tokio::time::interval(std::time::Duration::from_millis(
+        scheduler_settings.graceful_shutdown_interval,
+    ));
+
+    let signal = common_utils::signals::get_allowed_signals()
+        .map_err(|error| {
+            logger::error!("Signal Handler Error: {:?}", error);
+            errors::ProcessTrackerError::ConfigurationError
+        })
+        .into_report()
+        .attach_printable("Failed while creating a signals handler")?;
+    let handle = signal.handle();
+    let task_handle = tokio::spawn(common_utils::signals::signal_handler(signal, tx));
+
+    loop {
+        match rx.try_recv() {
+            Err(mpsc::error::TryRecvError::Empty) => {
+                interval.tick().await;
+                match run_cleaner_flow(state, &scheduler_settings).await {
+                    Ok(_) => (),
+                    Err(error) => {
+                        // Intentionally not propagating error to caller.
+                        // Any errors that occur in the cleaner flow must be handled here only, as
+                        // this is the topmost level function which is concerned with the cleaner flow.
+                        error!(%error);
+                    }
+                }
+            }
+            Ok(()) | Err(mpsc::error::TryRecvError::Disconnected) => {
+                logger::debug!("Awaiting shutdown!");
+                rx.close();
+                shutdown_interval.tick().await;
+                logger::info!("Terminating cleaner");
+                break;
+            }
+        }
+    }
+    handle.close();
+    task_handle
+        .await
+        .into_report()
+        .change_context(errors::ProcessTrackerError::UnexpectedFlow)?;
+
+    Ok(())
+}
+
+/// Runs a single cleanup of the process tracker, under a lock so that only one cleaner instance
+/// cleans up at a time. Each step of the cleanup is attempted even if an earlier one fails.
+#[instrument(skip_all)]
+pub async fn run_cleaner_flow<T>(
+    state: &T,
+    settings: &SchedulerSettings,
+) -> CustomResult<(), errors::ProcessTrackerError>
+where
+    T: SchedulerAppState,
+{
+    lock_acquire_release::<_, _, _>(
+        state.get_db().as_scheduler(),
+        "CLEANER_LOCK",
+        &settings.cleaner.lock_key,
+        settings.cleaner.lock_ttl,
+        move || async {
+            let db = state.get_db();
+
+            let results = [
+                delete_finished_processes(db.as_scheduler(), settings).await,
+                reset_stuck_processes(db.as_scheduler(), settings).await,
+                db.reclaim_idle_stream_entries(
+                    &settings.stream,
+                    &settings.consumer.consumer_group,
+                    settings.cleaner.stream_min_idle_time,
+                    settings.cleaner.batch_size.unsigned_abs(),
+                )
+                .await
+                .map(|count| debug!("Reclaimed {count} idle entries from the stream")),
+            ];
+
+            for error in results.into_iter().filter_map(Result::err) {
+                error!(%error);
+            }
+
+            Ok(())
+        },
+    )
+    .await
+}
+
+/// Deletes the processes which finished before the retention period
+#[instrument(skip_all)]
+pub async fn delete_finished_processes(
+    db: &dyn SchedulerInterface,
+    settings: &SchedulerSettings,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let updated_before = common_utils::date_time::now()
+        .checked_sub(Duration::days(settings.cleaner.retention_period_in_days))
+        .ok_or_else(|| {
+            report!(errors::ProcessTrackerError::ConfigurationError)
+                .attach_printable("Error obtaining the retention limit of finished processes")
+        })?;
+
+    let count = db
+        .delete_finished_processes_before(updated_before, settings.cleaner.batch_size)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+        .attach_printable("Failed while deleting finished processes")?;
+    debug!("Deleted {count} finished processes");
+
+    // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
+    #[allow(clippy::as_conversions)]
+    metrics::TASKS_DELETED_COUNT.add(&metrics::CONTEXT, count as u64, &[]);
+    Ok(())
+}
+
+/// Requeues the processes picked by a consumer which have not been updated within the stuck
+/// process timeout, as the consumer which picked them is assumed to have died
+#[instrument(skip_all)]
+pub async fn reset_stuck_processes(
+    db: &dyn SchedulerInterface,
+    settings: &SchedulerSettings,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let updated_before = common_utils::date_time::now()
+        .checked_sub(Duration::seconds(settings.cleaner.stuck_process_timeout))
+        .ok_or_else(|| {
+            report!(errors::ProcessTrackerError::ConfigurationError)
+                .attach_printable("Error obtaining the timeout of stuck processes")
+        })?;
+
+    let tasks = db
+        .find_processes_by_status_updated_before(
+            ProcessTrackerStatus::ProcessStarted,
+            updated_before,
+            settings.cleaner.batch_size,
+        )
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
+    debug!("Cleaner count of stuck tasks {}", tasks.len());
+
+    // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
+    #[allow(clippy::as_conversions)]
+    metrics::STUCK_TASKS_RESET_COUNT.add(&metrics::CONTEXT, tasks.len() as u64, &[]);
+
+    divide_and_append_tasks(db, SchedulerFlow::Cleaner, tasks, settings).await
+}
+
+/// Hands the stream entries which were delivered to a consumer but not acknowledged within
+/// `min_idle_time` milliseconds over to other consumers, by appending them to the stream again.
+/// Returns the number of batches requeued.
+#[instrument(skip_all)]
+pub async fn reclaim_idle_stream_entries(
+    redis_conn: &RedisConnectionPool,
+    stream_name: &str,
+    group_name: &str,
+    min_idle_time: u64,
+    count: u64,
+) -> CustomResult<usize, errors::ProcessTrackerError> {
+    let entry_ids = redis_conn
+        .consumer_group_get_pending_entries(stream_name, group_name, min_idle_time, count)
+        .await
+        .change_context(errors::ProcessTrackerError::BatchNotFound)
+        .attach_printable("Failed while fetching pending entries from stream")?
+        .into_iter()
+        .map(|(entry_id, consumer_name, idle_time, _delivery_count)| {
+            debug!(%entry_id, %consumer_name, idle_time, "Found idle entry in stream");
+            entry_id
+        })
+        .collect::<Vec<_>>();
+
+    if entry_ids.is_empty() {
+        return Ok(0);
+    }
+
+    // Claiming the entries first ensures that a consumer which picks them up concurrently
+    // does not have them requeued underneath it
+    let cleaner_name = format!("cleaner_{}", Uuid::new_v4());
+    let entries = redis_conn
+        .consumer_group_set_message_owner::<_, Vec<(String, HashMap<String, Option<String>>)>>(
+            stream_name,
+            group_name,
+            &cleaner_name,
+            min_idle_time,
+            entry_ids,
+        )
+        .await
+        .change_context(errors::ProcessTrackerError::BatchUpdateFailed)
+        .attach_printable("Failed while claiming idle entries in stream")?;
+
+    let mut reclaimed_count = 0;
+    for (entry_id, entry) in entries {
+        // Entries deleted from the stream while pending are claimed without any fields
+        if !entry.is_empty() {
+            match ProcessTrackerBatch::from_redis_stream_entry(entry) {
+                Ok(batch) => {
+                    redis_conn
+                        .stream_append_entry(
+                            stream_name,
+                            &RedisEntryId::AutoGeneratedID,
+                            batch.to_redis_field_value_pairs()?,
+                        )
+                        .await
+                        .change_context(errors::ProcessTrackerError::BatchInsertionFailed)?;
+                    reclaimed_count += 1;
+                }
+                Err(error) => {
+                    logger::error!(%entry_id, ?error, "Dropping malformed entry from stream");
+                }
+            }
+        }
+
+        redis_conn
+            .stream_acknowledge_entries(stream_name, group_name, vec![entry_id.clone()])
+            .await
+            .change_context(errors::ProcessTrackerError::BatchUpdateFailed)?;
+        redis_conn
+            .stream_delete_entries(stream_name, vec![entry_id])
+            .await
+            .change_context(errors::ProcessTrackerError::BatchDeleteFailed)?;
+    }
+
+    // Safety: Assuming we won't deal with more than `u64::MAX` batches at once
+    #[allow(clippy::as_conversions)]
+    metrics::BATCHES_RECLAIMED.add(&metrics::CONTEXT, reclaimed_count as u64, &[]);
+    Ok(reclaimed_count)
+}
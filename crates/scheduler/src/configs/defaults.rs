@@ -4,6 +4,7 @@ impl Default for super::settings::SchedulerSettings {
             stream: "SCHEDULER_STREAM".into(),
             producer: super::settings::ProducerSettings::default(),
             consumer: super::settings::ConsumerSettings::default(),
+            cleaner: super::settings::CleanerSettings::default(),
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
         }
@@ -30,3 +31,17 @@ impl Default for super::settings::ConsumerSettings {
         }
     }
 }
+
+impl Default for super::settings::CleanerSettings {
+    fn default() -> Self {
+        Self {
+            loop_interval: 60000,
+            retention_period_in_days: 30,
+            stuck_process_timeout: 1800,
+            stream_min_idle_time: 600000,
+            batch_size: 1000,
+            lock_key: "CLEANER_LOCKING_KEY".into(),
+            lock_ttl: 160,
+        }
+    }
+}
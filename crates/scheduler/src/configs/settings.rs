@@ -13,6 +13,7 @@ pub struct SchedulerSettings {
     pub stream: String,
     pub producer: ProducerSettings,
     pub consumer: ConsumerSettings,
+    pub cleaner: CleanerSettings,
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
 }
@@ -34,3 +35,16 @@ pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CleanerSettings {
+    pub loop_interval: u64,
+    pub retention_period_in_days: i64,
+    pub stuck_process_timeout: i64,
+    pub stream_min_idle_time: u64,
+    pub batch_size: i64,
+
+    pub lock_key: String,
+    pub lock_ttl: i64,
+}
@@ -19,6 +19,8 @@ impl super::settings::SchedulerSettings {
 
         self.producer.validate()?;
 
+        self.cleaner.validate()?;
+
         Ok(())
     }
 }
@@ -32,3 +34,27 @@ impl super::settings::ProducerSettings {
         })
     }
 }
+
+impl super::settings::CleanerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.lock_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cleaner lock key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.retention_period_in_days <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cleaner retention period must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cleaner batch size must be greater than zero".into(),
+            ))
+        })
+    }
+}
@@ -53,3 +53,36 @@ impl Default for PaymentMethodsPTMapping {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OutgoingWebhookRetryPolicy {
+    /// Delay in seconds before the first retry
+    pub start_after: i32,
+    /// Factor by which the delay grows after every retry
+    pub backoff_factor: i32,
+    /// Upper bound in seconds for the delay between two retries
+    pub max_interval: i32,
+    pub max_retries_count: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutgoingWebhookRetryPTMapping {
+    pub default_mapping: OutgoingWebhookRetryPolicy,
+    pub custom_merchant_mapping: HashMap<String, OutgoingWebhookRetryPolicy>,
+}
+
+impl Default for OutgoingWebhookRetryPTMapping {
+    fn default() -> Self {
+        Self {
+            default_mapping: OutgoingWebhookRetryPolicy {
+                start_after: 60,
+                backoff_factor: 2,
+                max_interval: 3600,
+                max_retries_count: 8,
+            },
+            custom_merchant_mapping: HashMap::new(),
+        }
+    }
+}
@@ -50,6 +50,19 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_status_updated_before(
+        &self,
+        status: storage_enums::ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn delete_finished_processes_before(
+        &self,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -143,6 +156,36 @@ impl ProcessTrackerInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn find_processes_by_status_updated_before(
+        &self,
+        status: storage_enums::ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_status_updated_before(
+            &conn,
+            status,
+            updated_before,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn delete_finished_processes_before(
+        &self,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::delete_finished_processes_before(&conn, updated_before, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -232,6 +275,47 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_processes_by_status_updated_before(
+        &self,
+        status: storage_enums::ProcessTrackerStatus,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| process.status == status && process.updated_at < updated_before)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+
+        Ok(processes)
+    }
+
+    async fn delete_finished_processes_before(
+        &self,
+        updated_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        let mut remaining = usize::try_from(limit).unwrap_or(usize::MAX);
+        let count = processes.len();
+        processes.retain(|process| {
+            let is_expired = process.status == storage_enums::ProcessTrackerStatus::Finish
+                && process.updated_at < updated_before;
+            if is_expired && remaining > 0 {
+                remaining -= 1;
+                false
+            } else {
+                true
+            }
+        });
+
+        Ok(count - processes.len())
+    }
 }
 
 #[async_trait::async_trait]
@@ -40,6 +40,14 @@ pub trait QueueInterface {
     ) -> CustomResult<(), RedisError>;
 
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError>;
+
+    async fn reclaim_idle_stream_entries(
+        &self,
+        stream_name: &str,
+        group_name: &str,
+        min_idle_time: u64,
+        count: u64,
+    ) -> CustomResult<usize, ProcessTrackerError>;
 }
 
 #[async_trait::async_trait]
@@ -132,6 +140,25 @@ impl QueueInterface for Store {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.get_redis_conn()?.get_key::<Vec<u8>>(key).await
     }
+
+    async fn reclaim_idle_stream_entries(
+        &self,
+        stream_name: &str,
+        group_name: &str,
+        min_idle_time: u64,
+        count: u64,
+    ) -> CustomResult<usize, ProcessTrackerError> {
+        crate::cleaner::reclaim_idle_stream_entries(
+            &self
+                .get_redis_conn()
+                .map_err(ProcessTrackerError::ERedisError)?,
+            stream_name,
+            group_name,
+            min_idle_time,
+            count,
+        )
+        .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -187,4 +214,17 @@ impl QueueInterface for MockDb {
     async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
         Err(RedisError::RedisConnectionError.into())
     }
+
+    async fn reclaim_idle_stream_entries(
+        &self,
+        _stream_name: &str,
+        _group_name: &str,
+        _min_idle_time: u64,
+        _count: u64,
+    ) -> CustomResult<usize, ProcessTrackerError> {
+        // [#172]: Implement function for `MockDb`
+        Err(ProcessTrackerError::ResourceFetchingFailed {
+            resource_name: "idle_stream_entries",
+        })?
+    }
 }
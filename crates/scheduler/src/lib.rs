@@ -1,3 +1,4 @@
+pub mod cleaner;
 pub mod configs;
 pub mod consumer;
 pub mod db;
@@ -15,3 +15,6 @@ counter_metric!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 counter_metric!(TASK_PROCESSED, PT_METER); // Tasks completed processing
 counter_metric!(TASK_FINISHED, PT_METER); // Tasks finished
 counter_metric!(TASK_RETRIED, PT_METER); // Tasks added for retries
+counter_metric!(TASKS_DELETED_COUNT, PT_METER); // Finished tasks deleted by cleaner
+counter_metric!(STUCK_TASKS_RESET_COUNT, PT_METER); // Stuck tasks requeued by cleaner
+counter_metric!(BATCHES_RECLAIMED, PT_METER); // Idle batches requeued in stream by cleaner
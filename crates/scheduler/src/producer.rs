@@ -93,22 +93,28 @@ pub async fn run_producer_flow<T>(
 where
     T: SchedulerAppState,
 {
-    lock_acquire_release::<_, _, _>(state.get_db().as_scheduler(), settings, move || async {
-        let tasks = fetch_producer_tasks(state.get_db().as_scheduler(), settings).await?;
-        debug!("Producer count of tasks {}", tasks.len());
-
-        // [#268]: Allow task based segregation of tasks
-
-        divide_and_append_tasks(
-            state.get_db().as_scheduler(),
-            SchedulerFlow::Producer,
-            tasks,
-            settings,
-        )
-        .await?;
-
-        Ok(())
-    })
+    lock_acquire_release::<_, _, _>(
+        state.get_db().as_scheduler(),
+        "PRODUCER_LOCK",
+        &settings.producer.lock_key,
+        settings.producer.lock_ttl,
+        move || async {
+            let tasks = fetch_producer_tasks(state.get_db().as_scheduler(), settings).await?;
+            debug!("Producer count of tasks {}", tasks.len());
+
+            // [#268]: Allow task based segregation of tasks
+
+            divide_and_append_tasks(
+                state.get_db().as_scheduler(),
+                SchedulerFlow::Producer,
+                tasks,
+                settings,
+            )
+            .await?;
+
+            Ok(())
+        },
+    )
     .await?;
 
     Ok(())
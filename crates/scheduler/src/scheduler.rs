@@ -8,8 +8,8 @@ use storage_impl::KVRouterStore;
 use storage_impl::RouterStore;
 use tokio::sync::mpsc;
 
-use super::env::logger::error;
 pub use crate::{
+    cleaner,
     configs::settings::SchedulerSettings,
     consumer::{self, workflows},
     db::{process_tracker::ProcessTrackerInterface, queue::QueueInterface},
@@ -70,7 +70,7 @@ pub async fn start_process_tracker<T: SchedulerAppState + 'static>(
             consumer::start_consumer(state, scheduler_settings, runner_from_task, channel).await?
         }
         SchedulerFlow::Cleaner => {
-            error!("This flow has not been implemented yet!");
+            cleaner::start_cleaner(state, scheduler_settings, channel).await?
         }
     }
     Ok(())
@@ -328,6 +328,29 @@ pub fn get_pm_schedule_time(
     }
 }
 
+/// Returns the delay before the next delivery attempt of an outgoing webhook, the delay grows
+/// exponentially with the number of retries and is capped at `max_interval`
+pub fn get_outgoing_webhook_retry_schedule_time(
+    mapping: process_data::OutgoingWebhookRetryPTMapping,
+    merchant_name: &str,
+    retry_count: i32,
+) -> Option<i32> {
+    let retry_policy = match mapping.custom_merchant_mapping.get(merchant_name) {
+        Some(policy) => policy.clone(),
+        None => mapping.default_mapping,
+    };
+
+    if retry_count >= retry_policy.max_retries_count {
+        return None;
+    }
+
+    let delay = (0..retry_count).fold(retry_policy.start_after, |delay, _| {
+        delay.saturating_mul(retry_policy.backoff_factor)
+    });
+
+    Some(delay.min(retry_policy.max_interval))
+}
+
 fn get_delay<'a>(
     retry_count: i32,
     mut array: impl Iterator<Item = (&'a i32, &'a i32)>,
@@ -347,7 +370,9 @@ fn get_delay<'a>(
 
 pub(crate) async fn lock_acquire_release<T, F, Fut>(
     state: &T,
-    settings: &SchedulerSettings,
+    tag: &str,
+    lock_key: &str,
+    ttl: i64,
     callback: F,
 ) -> CustomResult<(), errors::ProcessTrackerError>
 where
@@ -355,10 +380,7 @@ where
     T: SchedulerInterface + Send + Sync + ?Sized,
     Fut: futures::Future<Output = CustomResult<(), errors::ProcessTrackerError>>,
 {
-    let tag = "PRODUCER_LOCK";
-    let lock_key = &settings.producer.lock_key;
     let lock_val = "LOCKED";
-    let ttl = settings.producer.lock_ttl;
 
     if state
         .acquire_pt_lock(tag, lock_key, lock_val, ttl)
@@ -9,4 +9,7 @@ pub struct Database {
     pub dbname: String,
     pub pool_size: u32,
     pub connection_timeout: u64,
+    /// Schema set as the `search_path` of the connections in the pool, the server default is
+    /// used when not set
+    pub schema: Option<String>,
 }
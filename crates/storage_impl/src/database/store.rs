@@ -77,7 +77,7 @@ pub async fn diesel_make_pg_pool(
     database: &Database,
     test_transaction: bool,
 ) -> StorageResult<PgPool> {
-    let database_url = format!(
+    let mut database_url = format!(
         "postgres://{}:{}@{}:{}/{}",
         database.username,
         database.password.peek(),
@@ -85,6 +85,10 @@ pub async fn diesel_make_pg_pool(
         database.port,
         database.dbname
     );
+    if let Some(schema) = &database.schema {
+        // URL encoded form of `-c search_path=<schema>`, applied to every connection of the pool
+        database_url.push_str(&format!("?options=-c%20search_path%3D{schema}"));
+    }
     let manager = async_bb8_diesel::ConnectionManager::<PgConnection>::new(database_url);
     let mut pool = bb8::Pool::builder()
         .max_size(database.pool_size)
@@ -245,6 +245,8 @@ pub enum ApiClientError {
     UrlEncodingFailed,
     #[error("Failed to send request to connector {0}")]
     RequestNotSent(String),
+    #[error("Failed to establish a connection with the connector")]
+    ConnectionNotEstablished,
     #[error("Failed to decode response")]
     ResponseDecodingFailed,
 
@@ -28,6 +28,8 @@ pub struct RouterStore<T: DatabaseStore> {
     db_store: T,
     cache_store: RedisStore,
     master_encryption_key: StrongSecret<Vec<u8>>,
+    #[cfg(feature = "kms")]
+    key_manager: Option<&'static external_services::kms::KmsClient>,
 }
 
 #[async_trait::async_trait]
@@ -100,6 +102,28 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store,
             master_encryption_key: encryption_key,
+            #[cfg(feature = "kms")]
+            key_manager: None,
+        })
+    }
+
+    /// Create a store with its own database pools, which shares the connections of `redis_conn`
+    /// with all of its redis keys namespaced under `key_prefix`. This is used to isolate tenants
+    /// which are served by the same deployment.
+    pub async fn from_shared_redis(
+        db_conf: T::Config,
+        redis_conn: &redis_interface::RedisConnectionPool,
+        key_prefix: &str,
+        encryption_key: StrongSecret<Vec<u8>>,
+        test_transaction: bool,
+    ) -> StorageResult<Self> {
+        let db_store = T::new(db_conf, test_transaction).await?;
+        Ok(Self {
+            db_store,
+            cache_store: RedisStore::from_shared_connection(redis_conn, key_prefix),
+            master_encryption_key: encryption_key,
+            #[cfg(feature = "kms")]
+            key_manager: None,
         })
     }
 
@@ -107,6 +131,23 @@ impl<T: DatabaseStore> RouterStore<T> {
         &self.master_encryption_key
     }
 
+    /// Wrap the data keys of merchants with the key management backend of `key_manager`, in
+    /// addition to the master key, before they are stored.
+    #[cfg(feature = "kms")]
+    pub fn with_key_manager(
+        mut self,
+        key_manager: &'static external_services::kms::KmsClient,
+    ) -> Self {
+        self.key_manager = Some(key_manager);
+        self
+    }
+
+    /// The key manager wrapping the data keys of merchants, if they are wrapped.
+    #[cfg(feature = "kms")]
+    pub fn key_manager(&self) -> Option<&'static external_services::kms::KmsClient> {
+        self.key_manager
+    }
+
     /// # Panics
     ///
     /// Will panic if `CONNECTOR_AUTH_FILE_PATH` is not set
@@ -125,6 +166,8 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store,
             master_encryption_key: encryption_key,
+            #[cfg(feature = "kms")]
+            key_manager: None,
         })
     }
 }
@@ -184,6 +227,11 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         self.router_store.master_key()
     }
 
+    #[cfg(feature = "kms")]
+    pub fn key_manager(&self) -> Option<&'static external_services::kms::KmsClient> {
+        self.router_store.key_manager()
+    }
+
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
         format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
     }
@@ -239,6 +287,13 @@ impl DataModelExt for data_models::MerchantStorageScheme {
     }
 }
 
+// Tables whose KV mode is implemented by the router, which cannot implement this trait itself
+impl redis::kv_store::KvStorePartition for store::Address {}
+impl redis::kv_store::KvStorePartition for store::Customer {}
+impl redis::kv_store::KvStorePartition for store::ConnectorResponse {}
+impl redis::kv_store::KvStorePartition for store::capture::Capture {}
+impl redis::kv_store::KvStorePartition for store::Mandate {}
+
 pub(crate) fn diesel_error_to_data_error(
     diesel_error: &diesel_models::errors::DatabaseError,
 ) -> StorageError {
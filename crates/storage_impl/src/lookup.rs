@@ -1,17 +1,28 @@
 use common_utils::errors::CustomResult;
-use data_models::errors;
-use diesel_models::reverse_lookup::{
-    ReverseLookup as DieselReverseLookup, ReverseLookupNew as DieselReverseLookupNew,
+use data_models::{errors, MerchantStorageScheme};
+use diesel_models::{
+    kv,
+    reverse_lookup::{
+        ReverseLookup as DieselReverseLookup, ReverseLookupNew as DieselReverseLookupNew,
+    },
 };
 use error_stack::{IntoReport, ResultExt};
+use redis_interface::SetnxReply;
 
-use crate::{redis::cache::get_or_populate_redis, DatabaseStore, KVRouterStore, RouterStore};
+use crate::{
+    redis::{
+        cache::get_or_populate_redis,
+        kv_store::{KvStorePartition, PartitionKey, RedisConnInterface},
+    },
+    DatabaseStore, KVRouterStore, RouterStore,
+};
 
 #[async_trait::async_trait]
 pub trait ReverseLookupInterface {
     async fn insert_reverse_lookup(
         &self,
         _new: DieselReverseLookupNew,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<DieselReverseLookup, errors::StorageError>;
     async fn get_lookup_by_lookup_id(
         &self,
@@ -24,6 +35,7 @@ impl<T: DatabaseStore> ReverseLookupInterface for RouterStore<T> {
     async fn insert_reverse_lookup(
         &self,
         new: DieselReverseLookupNew,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<DieselReverseLookup, errors::StorageError> {
         let conn = self
             .get_master_pool()
@@ -59,8 +71,67 @@ impl<T: DatabaseStore> ReverseLookupInterface for KVRouterStore<T> {
     async fn insert_reverse_lookup(
         &self,
         new: DieselReverseLookupNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<DieselReverseLookup, errors::StorageError> {
-        self.router_store.insert_reverse_lookup(new).await
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .insert_reverse_lookup(new, storage_scheme)
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                let created_rev_lookup = DieselReverseLookup {
+                    lookup_id: new.lookup_id.clone(),
+                    sk_id: new.sk_id.clone(),
+                    pk_id: new.pk_id.clone(),
+                    source: new.source.clone(),
+                };
+                let redis_value = serde_json::to_string(&created_rev_lookup)
+                    .into_report()
+                    .change_context(errors::StorageError::SerializationFailed)?;
+
+                // Written under the same key that `get_lookup_by_lookup_id` caches lookups at
+                match self
+                    .get_redis_conn()
+                    .map_err(|er| {
+                        let error = format!("{}", er);
+                        er.change_context(errors::StorageError::RedisError(error))
+                    })?
+                    .set_key_if_not_exists_with_expiry(
+                        &created_rev_lookup.lookup_id,
+                        redis_value,
+                        None,
+                    )
+                    .await
+                {
+                    Ok(SetnxReply::KeySet) => {
+                        let redis_entry = kv::TypedSql {
+                            op: kv::DBOperation::Insert {
+                                insertable: kv::Insertable::ReverseLookUp(new),
+                            },
+                        };
+                        self.push_to_drainer_stream::<DieselReverseLookup>(
+                            redis_entry,
+                            PartitionKey::CombinationKey {
+                                combination: &format!(
+                                    "reverse_lookup_{}",
+                                    &created_rev_lookup.lookup_id
+                                ),
+                            },
+                        )
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+                        Ok(created_rev_lookup)
+                    }
+                    Ok(SetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                        entity: "reverse_lookup",
+                        key: Some(created_rev_lookup.lookup_id),
+                    })
+                    .into_report(),
+                    Err(er) => Err(er.change_context(errors::StorageError::KVError)),
+                }
+            }
+        }
     }
 
     async fn get_lookup_by_lookup_id(
@@ -70,3 +141,5 @@ impl<T: DatabaseStore> ReverseLookupInterface for KVRouterStore<T> {
         self.router_store.get_lookup_by_lookup_id(id).await
     }
 }
+
+impl KvStorePartition for DieselReverseLookup {}
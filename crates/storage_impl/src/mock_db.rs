@@ -38,8 +38,20 @@ pub struct MockDb {
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<crate::store::capture::Capture>>>,
     pub merchant_key_store: Arc<Mutex<Vec<crate::store::merchant_key_store::MerchantKeyStore>>>,
+    pub merchant_key_versions:
+        Arc<Mutex<Vec<crate::store::merchant_key_store::MerchantKeyVersion>>>,
     pub business_profiles: Arc<Mutex<Vec<crate::store::business_profile::BusinessProfile>>>,
     pub reverse_lookups: Arc<Mutex<Vec<store::ReverseLookup>>>,
+    pub webhook_delivery_attempts: Arc<Mutex<Vec<store::WebhookDeliveryAttempt>>>,
+    pub incremental_authorizations: Arc<Mutex<Vec<store::IncrementalAuthorization>>>,
+    pub blocklist: Arc<Mutex<Vec<store::Blocklist>>>,
+    pub blocklist_fingerprints: Arc<Mutex<Vec<store::BlocklistFingerprint>>>,
+    pub audit_events: Arc<Mutex<Vec<store::AuditEvent>>>,
+    pub authentications: Arc<Mutex<Vec<store::Authentication>>>,
+    pub payment_links: Arc<Mutex<Vec<store::PaymentLink>>>,
+    pub vault_data_keys: Arc<Mutex<Vec<store::vault::VaultDataKey>>>,
+    pub vault_cards: Arc<Mutex<Vec<store::vault::VaultCard>>>,
+    pub vault_access_log: Arc<Mutex<Vec<store::vault::VaultAccessLog>>>,
 }
 
 impl MockDb {
@@ -70,8 +82,19 @@ impl MockDb {
             mandates: Default::default(),
             captures: Default::default(),
             merchant_key_store: Default::default(),
+            merchant_key_versions: Default::default(),
             business_profiles: Default::default(),
             reverse_lookups: Default::default(),
+            webhook_delivery_attempts: Default::default(),
+            incremental_authorizations: Default::default(),
+            blocklist: Default::default(),
+            blocklist_fingerprints: Default::default(),
+            audit_events: Default::default(),
+            authentications: Default::default(),
+            payment_links: Default::default(),
+            vault_data_keys: Default::default(),
+            vault_cards: Default::default(),
+            vault_access_log: Default::default(),
         })
     }
 }
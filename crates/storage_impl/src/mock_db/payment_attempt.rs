@@ -138,6 +138,7 @@ impl PaymentAttemptInterface for MockDb {
             multiple_capture_count: payment_attempt.multiple_capture_count,
             connector_response_reference_id: None,
             amount_capturable: payment_attempt.amount_capturable,
+            fallback_connectors: payment_attempt.fallback_connectors,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -106,6 +106,9 @@ impl PaymentIntentInterface for MockDb {
             profile_id: new.profile_id,
             merchant_decision: new.merchant_decision,
             payment_confirm_source: new.payment_confirm_source,
+            request_incremental_authorization: new.request_incremental_authorization,
+            fingerprint_id: new.fingerprint_id,
+            payment_link_id: new.payment_link_id,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
@@ -347,6 +347,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     multiple_capture_count: payment_attempt.multiple_capture_count,
                     connector_response_reference_id: None,
                     amount_capturable: payment_attempt.amount_capturable,
+                    fallback_connectors: payment_attempt.fallback_connectors.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -365,10 +366,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     })
                     .into_report(),
                     Ok(HsetnxReply::KeySet) => {
-                        let conn = pg_connection_write(self).await?;
-
                         //Reverse lookup for attempt_id
-                        ReverseLookupNew {
+                        let reverse_lookup = ReverseLookupNew {
                             lookup_id: format!(
                                 "{}_{}",
                                 &created_attempt.merchant_id, &created_attempt.attempt_id,
@@ -376,13 +375,9 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                             pk_id: key,
                             sk_id: field,
                             source: "payment_attempt".to_string(),
-                        }
-                        .insert(&conn)
-                        .await
-                        .map_err(|er| {
-                            let new_err = crate::diesel_error_to_data_error(er.current_context());
-                            er.change_context(new_err)
-                        })?;
+                        };
+                        self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                            .await?;
 
                         let redis_entry = kv::TypedSql {
                             op: kv::DBOperation::Insert {
@@ -453,22 +448,24 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                 ) {
                     (None, Some(connector_transaction_id)) => {
                         add_connector_txn_id_to_reverse_lookup(
-                            &self.router_store,
+                            self,
                             key.as_str(),
                             this.merchant_id.as_str(),
                             updated_attempt.attempt_id.as_str(),
                             connector_transaction_id.as_str(),
+                            storage_scheme,
                         )
                         .await?;
                     }
                     (Some(old_connector_transaction_id), Some(connector_transaction_id)) => {
                         if old_connector_transaction_id.ne(connector_transaction_id) {
                             add_connector_txn_id_to_reverse_lookup(
-                                &self.router_store,
+                                self,
                                 key.as_str(),
                                 this.merchant_id.as_str(),
                                 updated_attempt.attempt_id.as_str(),
                                 connector_transaction_id.as_str(),
+                                storage_scheme,
                             )
                             .await?;
                         }
@@ -479,22 +476,24 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                 match (old_preprocessing_id, &updated_attempt.preprocessing_step_id) {
                     (None, Some(preprocessing_id)) => {
                         add_preprocessing_id_to_reverse_lookup(
-                            &self.router_store,
+                            self,
                             key.as_str(),
                             this.merchant_id.as_str(),
                             updated_attempt.attempt_id.as_str(),
                             preprocessing_id.as_str(),
+                            storage_scheme,
                         )
                         .await?;
                     }
                     (Some(old_preprocessing_id), Some(preprocessing_id)) => {
                         if old_preprocessing_id.ne(preprocessing_id) {
                             add_preprocessing_id_to_reverse_lookup(
-                                &self.router_store,
+                                self,
                                 key.as_str(),
                                 this.merchant_id.as_str(),
                                 updated_attempt.attempt_id.as_str(),
                                 preprocessing_id.as_str(),
+                                storage_scheme,
                             )
                             .await?;
                         }
@@ -914,6 +913,7 @@ impl DataModelExt for PaymentAttempt {
             multiple_capture_count: self.multiple_capture_count,
             connector_response_reference_id: self.connector_response_reference_id,
             amount_capturable: self.amount_capturable,
+            fallback_connectors: self.fallback_connectors,
         }
     }
 
@@ -962,6 +962,7 @@ impl DataModelExt for PaymentAttempt {
             multiple_capture_count: storage_model.multiple_capture_count,
             connector_response_reference_id: storage_model.connector_response_reference_id,
             amount_capturable: storage_model.amount_capturable,
+            fallback_connectors: storage_model.fallback_connectors,
         }
     }
 }
@@ -1010,6 +1011,7 @@ impl DataModelExt for PaymentAttemptNew {
             connector_response_reference_id: self.connector_response_reference_id,
             multiple_capture_count: self.multiple_capture_count,
             amount_capturable: self.amount_capturable,
+            fallback_connectors: self.fallback_connectors,
         }
     }
 
@@ -1056,6 +1058,7 @@ impl DataModelExt for PaymentAttemptNew {
             connector_response_reference_id: storage_model.connector_response_reference_id,
             multiple_capture_count: storage_model.multiple_capture_count,
             amount_capturable: storage_model.amount_capturable,
+            fallback_connectors: storage_model.fallback_connectors,
         }
     }
 }
@@ -1097,11 +1100,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector,
                 straight_through_algorithm,
                 amount_capturable,
+                fallback_connectors,
             } => DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
                 straight_through_algorithm,
                 amount_capturable,
+                fallback_connectors,
             },
             Self::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1125,6 +1130,10 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_code,
                 error_message,
                 amount_capturable,
+                fallback_connectors,
+                capture_method,
+                surcharge_amount,
+                tax_amount,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
                 currency,
@@ -1142,6 +1151,10 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_code,
                 error_message,
                 amount_capturable,
+                fallback_connectors,
+                capture_method,
+                surcharge_amount,
+                tax_amount,
             },
             Self::VoidUpdate {
                 status,
@@ -1250,6 +1263,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 status,
                 amount_capturable,
             },
+            Self::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+            } => DieselPaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+            },
         }
     }
 
@@ -1287,11 +1307,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector,
                 straight_through_algorithm,
                 amount_capturable,
+                fallback_connectors,
             } => Self::UpdateTrackers {
                 payment_token,
                 connector,
                 straight_through_algorithm,
                 amount_capturable,
+                fallback_connectors,
             },
             DieselPaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1315,6 +1337,10 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_code,
                 error_message,
                 amount_capturable,
+                fallback_connectors,
+                capture_method,
+                surcharge_amount,
+                tax_amount,
             } => Self::ConfirmUpdate {
                 amount,
                 currency,
@@ -1332,6 +1358,10 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_code,
                 error_message,
                 amount_capturable,
+                fallback_connectors,
+                capture_method,
+                surcharge_amount,
+                tax_amount,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -1440,54 +1470,55 @@ impl DataModelExt for PaymentAttemptUpdate {
                 status,
                 amount_capturable,
             },
+            DieselPaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+            } => Self::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+            },
         }
     }
 }
 
 #[inline]
 async fn add_connector_txn_id_to_reverse_lookup<T: DatabaseStore>(
-    store: &RouterStore<T>,
+    store: &KVRouterStore<T>,
     key: &str,
     merchant_id: &str,
     updated_attempt_attempt_id: &str,
     connector_transaction_id: &str,
+    storage_scheme: MerchantStorageScheme,
 ) -> CustomResult<ReverseLookup, errors::StorageError> {
-    let conn = pg_connection_write(store).await?;
     let field = format!("pa_{}", updated_attempt_attempt_id);
-    ReverseLookupNew {
+    let reverse_lookup_new = ReverseLookupNew {
         lookup_id: format!("{}_{}", merchant_id, connector_transaction_id),
         pk_id: key.to_owned(),
         sk_id: field.clone(),
         source: "payment_attempt".to_string(),
-    }
-    .insert(&conn)
-    .await
-    .map_err(|err| {
-        let new_err = crate::diesel_error_to_data_error(err.current_context());
-        err.change_context(new_err)
-    })
+    };
+    store
+        .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
+        .await
 }
 
 #[inline]
 async fn add_preprocessing_id_to_reverse_lookup<T: DatabaseStore>(
-    store: &RouterStore<T>,
+    store: &KVRouterStore<T>,
     key: &str,
     merchant_id: &str,
     updated_attempt_attempt_id: &str,
     preprocessing_id: &str,
+    storage_scheme: MerchantStorageScheme,
 ) -> CustomResult<ReverseLookup, errors::StorageError> {
-    let conn = pg_connection_write(store).await?;
     let field = format!("pa_{}", updated_attempt_attempt_id);
-    ReverseLookupNew {
+    let reverse_lookup_new = ReverseLookupNew {
         lookup_id: format!("{}_{}", merchant_id, preprocessing_id),
         pk_id: key.to_owned(),
         sk_id: field.clone(),
         source: "payment_attempt".to_string(),
-    }
-    .insert(&conn)
-    .await
-    .map_err(|er| {
-        let new_err = crate::diesel_error_to_data_error(er.current_context());
-        er.change_context(new_err)
-    })
+    };
+    store
+        .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
+        .await
 }
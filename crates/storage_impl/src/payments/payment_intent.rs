@@ -90,6 +90,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     profile_id: new.profile_id.clone(),
                     merchant_decision: new.merchant_decision.clone(),
                     payment_confirm_source: new.payment_confirm_source,
+                    request_incremental_authorization: new.request_incremental_authorization,
+                    fingerprint_id: new.fingerprint_id.clone(),
+                    payment_link_id: new.payment_link_id.clone(),
                 };
 
                 match self
@@ -704,6 +707,9 @@ impl DataModelExt for PaymentIntentNew {
             profile_id: self.profile_id,
             merchant_decision: self.merchant_decision,
             payment_confirm_source: self.payment_confirm_source,
+            request_incremental_authorization: self.request_incremental_authorization,
+            fingerprint_id: self.fingerprint_id,
+            payment_link_id: self.payment_link_id,
         }
     }
 
@@ -741,6 +747,9 @@ impl DataModelExt for PaymentIntentNew {
             profile_id: storage_model.profile_id,
             merchant_decision: storage_model.merchant_decision,
             payment_confirm_source: storage_model.payment_confirm_source,
+            request_incremental_authorization: storage_model.request_incremental_authorization,
+            fingerprint_id: storage_model.fingerprint_id,
+            payment_link_id: storage_model.payment_link_id,
         }
     }
 }
@@ -783,6 +792,9 @@ impl DataModelExt for PaymentIntent {
             profile_id: self.profile_id,
             merchant_decision: self.merchant_decision,
             payment_confirm_source: self.payment_confirm_source,
+            request_incremental_authorization: self.request_incremental_authorization,
+            fingerprint_id: self.fingerprint_id,
+            payment_link_id: self.payment_link_id,
         }
     }
 
@@ -821,6 +833,9 @@ impl DataModelExt for PaymentIntent {
             profile_id: storage_model.profile_id,
             merchant_decision: storage_model.merchant_decision,
             payment_confirm_source: storage_model.payment_confirm_source,
+            request_incremental_authorization: storage_model.request_incremental_authorization,
+            fingerprint_id: storage_model.fingerprint_id,
+            payment_link_id: storage_model.payment_link_id,
         }
     }
 }
@@ -882,6 +897,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 order_details,
                 metadata,
                 payment_confirm_source,
+                fingerprint_id,
             } => DieselPaymentIntentUpdate::Update {
                 amount,
                 currency,
@@ -899,6 +915,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 order_details,
                 metadata,
                 payment_confirm_source,
+                fingerprint_id,
             },
             Self::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -926,6 +943,9 @@ impl DataModelExt for PaymentIntentUpdate {
                 status,
                 merchant_decision,
             },
+            Self::IncrementalAuthorizationAmountUpdate { amount } => {
+                DieselPaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount }
+            }
         }
     }
 
@@ -983,6 +1003,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 order_details,
                 metadata,
                 payment_confirm_source,
+                fingerprint_id,
             } => Self::Update {
                 amount,
                 currency,
@@ -1000,6 +1021,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 order_details,
                 metadata,
                 payment_confirm_source,
+                fingerprint_id,
             },
             DieselPaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -1027,6 +1049,9 @@ impl DataModelExt for PaymentIntentUpdate {
                 status,
                 merchant_decision,
             },
+            DieselPaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => {
+                Self::IncrementalAuthorizationAmountUpdate { amount }
+            }
         }
     }
 }
@@ -33,6 +33,17 @@ impl RedisStore {
         })
     }
 
+    /// Create a store sharing the connections of `redis_conn`, with all keys namespaced under
+    /// `key_prefix`
+    pub fn from_shared_connection(
+        redis_conn: &redis_interface::RedisConnectionPool,
+        key_prefix: &str,
+    ) -> Self {
+        Self {
+            redis_conn: Arc::new(redis_conn.with_key_prefix(key_prefix)),
+        }
+    }
+
     pub fn set_error_callback(&self, callback: tokio::sync::oneshot::Sender<()>) {
         let redis_clone = self.redis_conn.clone();
         tokio::spawn(async move {
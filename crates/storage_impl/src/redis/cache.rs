@@ -187,12 +187,22 @@ where
     F: FnOnce() -> Fut + Send,
     Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
 {
-    let cache_val = cache.get_val::<T>(key);
+    // The in-memory caches are shared by all the stores of the application, so entries are keyed
+    // by the full redis key, which includes the key prefix of the store
+    let cache_key = store
+        .get_redis_conn()
+        .map_err(|er| {
+            let error = format!("{}", er);
+            er.change_context(StorageError::RedisError(error))
+        })
+        .attach_printable("Failed to get redis connection")?
+        .add_prefix(key);
+    let cache_val = cache.get_val::<T>(&cache_key);
     if let Some(val) = cache_val {
         Ok(val)
     } else {
         let val = get_or_populate_redis(store, key, fun).await?;
-        cache.push(key.to_string(), val.clone()).await;
+        cache.push(cache_key, val.clone()).await;
         Ok(val)
     }
 }
@@ -208,7 +218,6 @@ where
     Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
 {
     let data = fun().await?;
-    in_memory.async_map(|cache| cache.invalidate(key)).await;
 
     let redis_conn = store
         .get_redis_conn()
@@ -218,6 +227,11 @@ where
         })
         .attach_printable("Failed to get redis connection")?;
 
+    let cache_key = redis_conn.add_prefix(key);
+    in_memory
+        .async_map(|cache| cache.invalidate(cache_key.as_str()))
+        .await;
+
     redis_conn
         .delete_key(key)
         .await
@@ -237,6 +251,14 @@ pub async fn publish_into_redact_channel<'a>(
         })
         .attach_printable("Failed to get redis connection")?;
 
+    // Subscribers invalidate the published key as is, so it is published with the key prefix of
+    // the store
+    let key = match key {
+        CacheKind::Config(key) => CacheKind::Config(redis_conn.add_prefix(&key).into()),
+        CacheKind::Accounts(key) => CacheKind::Accounts(redis_conn.add_prefix(&key).into()),
+        CacheKind::All(key) => CacheKind::All(redis_conn.add_prefix(&key).into()),
+    };
+
     redis_conn
         .publish(PUB_SUB_CHANNEL, key)
         .await
@@ -3,6 +3,57 @@ use std::{collections::HashMap, env};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 
+/// How the connector integration tests reach the connectors, set through the
+/// `CONNECTOR_TEST_MODE` environment variable (`live`, `record` or `replay`)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectorTestMode {
+    /// Requests are sent to the connector sandboxes
+    #[default]
+    Live,
+    /// Requests are sent to the connector sandboxes, and recorded along with their responses to
+    /// fixture files, with the connector credentials masked
+    Record,
+    /// Responses are served from the recorded fixture files, without network access, using the
+    /// sample credentials unless `CONNECTOR_AUTH_FILE_PATH` is set
+    Replay,
+}
+
+impl ConnectorTestMode {
+    /// # Panics
+    ///
+    /// Will panic if `CONNECTOR_TEST_MODE` env is set to an unknown mode
+    #[allow(clippy::panic)]
+    pub fn from_env() -> Self {
+        match env::var("CONNECTOR_TEST_MODE").as_deref() {
+            Err(_) | Ok("live") => Self::Live,
+            Ok("record") => Self::Record,
+            Ok("replay") => Self::Replay,
+            Ok(mode) => panic!("Unknown connector test mode: {mode}"),
+        }
+    }
+}
+
+/// The connector authentication file, defaulting to the sample credentials when replaying the
+/// recorded fixtures
+///
+/// # Panics
+///
+/// Will panic if `CONNECTOR_AUTH_FILE_PATH` env is not set, outside of the replay mode
+#[allow(clippy::expect_used)]
+fn get_connector_auth_file_path() -> String {
+    match ConnectorTestMode::from_env() {
+        ConnectorTestMode::Replay => env::var("CONNECTOR_AUTH_FILE_PATH").unwrap_or_else(|_| {
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../router/tests/connectors/sample_auth.toml"
+            )
+            .to_string()
+        }),
+        ConnectorTestMode::Live | ConnectorTestMode::Record => env::var("CONNECTOR_AUTH_FILE_PATH")
+            .expect("Connector authentication file path not set"),
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ConnectorAuthentication {
     pub aci: Option<BodyKey>,
@@ -79,8 +130,7 @@ impl ConnectorAuthentication {
     pub fn new() -> Self {
         // Do `export CONNECTOR_AUTH_FILE_PATH="/hyperswitch/crates/router/tests/connectors/sample_sample_auth.toml"`
         // before running tests in shell
-        let path = env::var("CONNECTOR_AUTH_FILE_PATH")
-            .expect("Connector authentication file path not set");
+        let path = get_connector_auth_file_path();
         toml::from_str(
             &std::fs::read_to_string(path).expect("connector authentication config file not found"),
         )
@@ -111,8 +161,7 @@ impl ConnectorAuthenticationMap {
     pub fn new() -> Self {
         // Do `export CONNECTOR_AUTH_FILE_PATH="/hyperswitch/crates/router/tests/connectors/sample_sample_auth.toml"`
         // before running tests in shell
-        let path = env::var("CONNECTOR_AUTH_FILE_PATH")
-            .expect("connector authentication file path not set");
+        let path = get_connector_auth_file_path();
 
         // Read the file contents to a JsonString
         let contents =
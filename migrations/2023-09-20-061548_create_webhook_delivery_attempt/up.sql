@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS webhook_delivery_attempt (
+    id SERIAL PRIMARY KEY,
+    event_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    attempt_number SMALLINT NOT NULL,
+    delivery_type VARCHAR(32) NOT NULL,
+    is_delivered BOOLEAN NOT NULL,
+    response_status_code SMALLINT,
+    latency_ms BIGINT,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS webhook_delivery_attempt_event_id_attempt_number_index ON webhook_delivery_attempt (event_id, attempt_number);
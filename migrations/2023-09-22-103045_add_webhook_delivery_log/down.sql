@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE webhook_delivery_attempt
+DROP COLUMN IF EXISTS request_body,
+DROP COLUMN IF EXISTS request_signature,
+DROP COLUMN IF EXISTS response_body;
+
+DROP INDEX IF EXISTS events_merchant_id_primary_object_id_index;
+
+ALTER TABLE events DROP COLUMN IF EXISTS merchant_id;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE events ADD COLUMN IF NOT EXISTS merchant_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS events_merchant_id_primary_object_id_index ON events (merchant_id, primary_object_id);
+
+ALTER TABLE webhook_delivery_attempt
+ADD COLUMN IF NOT EXISTS request_body BYTEA,
+ADD COLUMN IF NOT EXISTS request_signature TEXT,
+ADD COLUMN IF NOT EXISTS response_body BYTEA;
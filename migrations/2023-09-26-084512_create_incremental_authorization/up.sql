@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS incremental_authorization (
+    authorization_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    previously_authorized_amount BIGINT NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    reason VARCHAR(255),
+    error_code VARCHAR(255),
+    error_message TEXT,
+    connector_authorization_id VARCHAR(128),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS incremental_authorization_merchant_id_payment_id_index ON incremental_authorization (merchant_id, payment_id);
+
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS request_incremental_authorization BOOLEAN;
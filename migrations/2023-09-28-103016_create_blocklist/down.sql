@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS fingerprint_id;
+
+DROP TABLE IF EXISTS blocklist_fingerprint;
+
+DROP TABLE IF EXISTS blocklist;
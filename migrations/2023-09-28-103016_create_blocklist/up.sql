@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS blocklist (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    fingerprint_id VARCHAR(128) NOT NULL,
+    data_kind VARCHAR(32) NOT NULL,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS blocklist_merchant_id_fingerprint_id_index ON blocklist (merchant_id, fingerprint_id);
+
+CREATE TABLE IF NOT EXISTS blocklist_fingerprint (
+    merchant_id VARCHAR(64) NOT NULL,
+    fingerprint_id VARCHAR(128) NOT NULL,
+    dispute_count INTEGER NOT NULL DEFAULT 0,
+    fraud_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, fingerprint_id)
+);
+
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS fingerprint_id VARCHAR(128);
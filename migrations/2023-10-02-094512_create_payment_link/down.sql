@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS payment_link_config;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS payment_link_id;
+
+DROP TABLE IF EXISTS payment_link;
@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_link (
+    payment_link_id VARCHAR(255) PRIMARY KEY,
+    payment_id VARCHAR(64) NOT NULL,
+    link_to_pay VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    amount INT8 NOT NULL,
+    currency "Currency",
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    fulfilment_time TIMESTAMP NOT NULL
+);
+
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS payment_link_id VARCHAR(255);
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS payment_link_config JSONB;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS process_tracker_status_updated_at_index ON process_tracker (status, updated_at);
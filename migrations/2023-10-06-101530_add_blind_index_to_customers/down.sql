@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS customers_merchant_id_id_index;
+
+DROP INDEX IF EXISTS customers_merchant_id_name_hash_index;
+
+DROP INDEX IF EXISTS customers_merchant_id_phone_hash_index;
+
+DROP INDEX IF EXISTS customers_merchant_id_email_hash_index;
+
+ALTER TABLE customers
+DROP COLUMN IF EXISTS email_hash,
+DROP COLUMN IF EXISTS phone_hash,
+DROP COLUMN IF EXISTS name_hash;
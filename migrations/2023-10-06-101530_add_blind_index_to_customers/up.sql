@@ -0,0 +1,14 @@
+-- Your SQL goes here
+-- Keyed hashes of the encrypted email, phone and name of a customer, used to search customers
+ALTER TABLE customers
+ADD COLUMN IF NOT EXISTS email_hash VARCHAR(64),
+ADD COLUMN IF NOT EXISTS phone_hash VARCHAR(64),
+ADD COLUMN IF NOT EXISTS name_hash VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS customers_merchant_id_email_hash_index ON customers (merchant_id, email_hash);
+
+CREATE INDEX IF NOT EXISTS customers_merchant_id_phone_hash_index ON customers (merchant_id, phone_hash);
+
+CREATE INDEX IF NOT EXISTS customers_merchant_id_name_hash_index ON customers (merchant_id, name_hash);
+
+CREATE INDEX IF NOT EXISTS customers_merchant_id_id_index ON customers (merchant_id, id);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS vault.access_log;
+
+DROP TABLE IF EXISTS vault.cards;
+
+DROP TABLE IF EXISTS vault.data_keys;
+
+DROP SCHEMA IF EXISTS vault;
@@ -0,0 +1,43 @@
+-- Your SQL goes here
+CREATE SCHEMA IF NOT EXISTS vault;
+
+CREATE TABLE IF NOT EXISTS vault.data_keys (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    key_version INTEGER NOT NULL,
+    wrapped_key BYTEA NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    rotated_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS data_keys_merchant_id_key_version_index ON vault.data_keys (merchant_id, key_version);
+
+CREATE TABLE IF NOT EXISTS vault.cards (
+    id SERIAL PRIMARY KEY,
+    card_reference VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    fingerprint VARCHAR(64) NOT NULL,
+    key_version INTEGER NOT NULL,
+    enc_data BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS cards_merchant_id_card_reference_index ON vault.cards (merchant_id, card_reference);
+
+CREATE INDEX IF NOT EXISTS cards_merchant_id_customer_id_fingerprint_index ON vault.cards (merchant_id, customer_id, fingerprint);
+
+CREATE INDEX IF NOT EXISTS cards_merchant_id_key_version_index ON vault.cards (merchant_id, key_version);
+
+CREATE TABLE IF NOT EXISTS vault.access_log (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    card_reference VARCHAR(64) NOT NULL,
+    flow VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS access_log_merchant_id_card_reference_index ON vault.access_log (merchant_id, card_reference);
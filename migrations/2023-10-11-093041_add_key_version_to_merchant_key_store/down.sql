@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS merchant_key_versions;
+
+ALTER TABLE merchant_key_store DROP COLUMN IF EXISTS key_version;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+ALTER TABLE merchant_key_store
+ADD COLUMN IF NOT EXISTS key_version INTEGER NOT NULL DEFAULT 1;
+
+CREATE TABLE IF NOT EXISTS merchant_key_versions (
+    merchant_id VARCHAR(64) NOT NULL,
+    key_version INTEGER NOT NULL,
+    key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, key_version)
+);
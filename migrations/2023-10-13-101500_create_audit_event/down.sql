@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS audit_event;
+
+DROP FUNCTION IF EXISTS audit_event_reject_modification;
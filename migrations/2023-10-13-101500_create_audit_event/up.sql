@@ -0,0 +1,29 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS audit_event (
+    id SERIAL PRIMARY KEY,
+    event_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64),
+    actor_type VARCHAR(32) NOT NULL,
+    actor_id VARCHAR(64),
+    action VARCHAR(64) NOT NULL,
+    target_type VARCHAR(64) NOT NULL,
+    target_id VARCHAR(255) NOT NULL,
+    diff JSONB,
+    request_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS audit_event_event_id_index ON audit_event (event_id);
+
+CREATE INDEX IF NOT EXISTS audit_event_merchant_id_created_at_index ON audit_event (merchant_id, created_at);
+
+-- Audit events are append-only, reject any attempt to modify or remove them
+CREATE OR REPLACE FUNCTION audit_event_reject_modification() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_event is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_event_append_only
+    BEFORE UPDATE OR DELETE ON audit_event
+    FOR EACH ROW EXECUTE FUNCTION audit_event_reject_modification();
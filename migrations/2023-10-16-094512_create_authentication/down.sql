@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS authentication_merchant_id_attempt_id_index;
+
+DROP TABLE IF EXISTS authentication;
@@ -0,0 +1,28 @@
+-- Your SQL goes here
+ALTER TYPE "ConnectorType" ADD VALUE IF NOT EXISTS 'authentication_processor';
+
+CREATE TABLE IF NOT EXISTS authentication (
+    authentication_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    authentication_connector VARCHAR(64) NOT NULL,
+    connector_authentication_id VARCHAR(64),
+    authentication_status VARCHAR(32) NOT NULL,
+    trans_status VARCHAR(8),
+    message_version VARCHAR(16),
+    three_ds_method_url TEXT,
+    three_ds_method_data TEXT,
+    three_ds_method_completion_ind VARCHAR(8),
+    acs_url TEXT,
+    challenge_request TEXT,
+    authentication_value BYTEA,
+    eci VARCHAR(8),
+    ds_trans_id VARCHAR(64),
+    error_code VARCHAR(255),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS authentication_merchant_id_attempt_id_index ON authentication (merchant_id, attempt_id);
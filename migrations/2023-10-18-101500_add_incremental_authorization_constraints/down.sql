@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS incremental_authorization_connector_authorization_id_index;
+
+DROP INDEX IF EXISTS incremental_authorization_processing_payment_index;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE UNIQUE INDEX IF NOT EXISTS incremental_authorization_processing_payment_index ON incremental_authorization (merchant_id, payment_id) WHERE status = 'processing';
+
+CREATE INDEX IF NOT EXISTS incremental_authorization_connector_authorization_id_index ON incremental_authorization (merchant_id, connector_authorization_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM process_tracker
+WHERE runner = 'CUSTOMER_BLIND_INDEX_BACKFILL_WORKFLOW'
+    AND name = 'BLIND_INDEX_BACKFILL';
@@ -0,0 +1,39 @@
+-- Your SQL goes here
+-- Schedules the computation of the blind indexes of the customers created before customers were
+-- indexed, one task for each merchant. The blind indexes are keyed with the data key of the
+-- merchant, so they are computed by the scheduler instead of in this migration.
+INSERT INTO process_tracker (
+    id,
+    name,
+    tag,
+    runner,
+    retry_count,
+    schedule_time,
+    rule,
+    tracking_data,
+    business_status,
+    status,
+    event,
+    created_at,
+    updated_at
+)
+SELECT
+    'CUSTOMER_BLIND_INDEX_BACKFILL_WORKFLOW_BLIND_INDEX_BACKFILL_1_' || merchant_id,
+    'BLIND_INDEX_BACKFILL',
+    ARRAY['CUSTOMER', 'BLIND_INDEX'],
+    'CUSTOMER_BLIND_INDEX_BACKFILL_WORKFLOW',
+    0,
+    now(),
+    '',
+    json_build_object('merchant_id', merchant_id, 'last_processed_id', NULL, 'backfilled_count', 0),
+    'Pending',
+    'new',
+    ARRAY[]::TEXT[],
+    now(),
+    now()
+FROM customers
+WHERE email_hash IS NULL
+    AND phone_hash IS NULL
+    AND name_hash IS NULL
+GROUP BY merchant_id
+ON CONFLICT (id) DO NOTHING;
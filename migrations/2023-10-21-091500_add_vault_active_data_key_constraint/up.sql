@@ -0,0 +1,14 @@
+-- Your SQL goes here
+-- Only the latest version of the data key of a merchant is kept active
+UPDATE vault.data_keys
+SET is_active = FALSE,
+    rotated_at = now()
+WHERE is_active
+    AND id NOT IN (
+        SELECT DISTINCT ON (merchant_id) id
+        FROM vault.data_keys
+        WHERE is_active
+        ORDER BY merchant_id, key_version DESC
+    );
+
+CREATE UNIQUE INDEX IF NOT EXISTS data_keys_merchant_id_active_index ON vault.data_keys (merchant_id) WHERE is_active;